@@ -20,8 +20,10 @@ static GLOBAL: MiMalloc = MiMalloc;
 
 // Bring runner types & our per-module DB factory
 use modkit::runtime::{
-    DbOptions, OopModuleSpawnConfig, OopSpawnOptions, RunOptions, ShutdownOptions, run, shutdown,
+    ClientRegistration, DbOptions, OopModuleSpawnConfig, OopSpawnOptions, RunOptions,
+    ShutdownOptions, run, shutdown,
 };
+use modkit_security::{PolicyEngine, RuleBasedPolicyEngine, SecCtxCodec};
 
 /// `HyperSpot` Server - modular platform for AI services
 #[derive(Parser)]
@@ -123,6 +125,15 @@ async fn main() -> Result<()> {
         tracing::error!(error = %e, "OTLP connectivity probe failed");
     }
 
+    // OTLP metrics export (the `/metrics` endpoint is served regardless)
+    #[cfg(feature = "otel")]
+    let meter_provider = config
+        .metrics
+        .as_ref()
+        .filter(|m| m.enabled)
+        .map(modkit::telemetry::init_metrics)
+        .transpose()?;
+
     // Smoke test span to confirm traces flow to Jaeger
     tracing::info_span!("startup_check", app = "hyperspot").in_scope(|| {
         tracing::info!("startup span alive - traces should be visible in Jaeger");
@@ -282,6 +293,19 @@ async fn run_server(config: AppConfig, args: Cli) -> Result<()> {
     // Build OoP spawn configuration
     let oop_options = build_oop_spawn_options(&config, &args, oop_backend)?;
 
+    // Build the rule-based policy engine from the `policy` section. Modules resolve it from
+    // the ClientHub as `dyn PolicyEngine`; the concrete type is registered too so rules can be
+    // hot-swapped via `RuleBasedPolicyEngine::reload`.
+    let policy_engine = Arc::new(RuleBasedPolicyEngine::new(
+        config.policy.clone().unwrap_or_default(),
+    )?);
+
+    // Build the secctx codec from the `secctx` section. gRPC clients and servers resolve it
+    // from the ClientHub to sign and verify `SecurityContext` envelopes.
+    let secctx_codec = Arc::new(SecCtxCodec::new(
+        &config.secctx.clone().unwrap_or_default(),
+    )?);
+
     // Run the ModKit runtime with the root cancellation token.
     // Shutdown is driven by the signal handler spawned above, not by ShutdownOptions::Signals.
     // OoP modules are spawned after the start phase (once grpc_hub has bound its port).
@@ -289,16 +313,26 @@ async fn run_server(config: AppConfig, args: Cli) -> Result<()> {
         modules_cfg: Arc::new(config),
         db: db_options,
         shutdown: ShutdownOptions::Token(cancel.clone()),
-        clients: vec![],
+        clients: vec![
+            ClientRegistration::new::<dyn PolicyEngine>(policy_engine.clone()),
+            ClientRegistration::new::<RuleBasedPolicyEngine>(policy_engine),
+            ClientRegistration::new::<SecCtxCodec>(secctx_codec),
+        ],
         instance_id,
         oop: oop_options,
     };
 
     let result = run(run_options).await;
 
-    // Graceful shutdown - flush any remaining traces
+    // Graceful shutdown - flush any remaining traces and metrics
     #[cfg(feature = "otel")]
     modkit::telemetry::init::shutdown_tracing();
+    #[cfg(feature = "otel")]
+    if let Some(provider) = meter_provider
+        && let Err(e) = provider.shutdown()
+    {
+        tracing::warn!(error = %e, "Failed to flush OTLP metrics");
+    }
 
     result
 }
@@ -350,6 +384,8 @@ fn build_oop_spawn_options(
                 env,
                 working_directory: exec_cfg.working_directory.clone(),
                 rendered_config_json: rendered_json,
+                restart: exec_cfg.restart.clone(),
+                replicas: exec_cfg.replicas,
             });
         }
     }
@@ -360,7 +396,7 @@ fn build_oop_spawn_options(
         tracing::info!(count = modules.len(), "Prepared OoP modules for spawning");
         Ok(Some(OopSpawnOptions {
             modules,
-            backend: Box::new(backend),
+            backend: Arc::new(backend),
         }))
     }
 }
@@ -6,6 +6,7 @@
 use api_gateway as _;
 use file_parser as _;
 use grpc_hub as _;
+use license_resolver_gw as _;
 use module_orchestrator as _;
 use nodes_registry as _;
 use simple_user_settings as _;
@@ -18,6 +19,12 @@ use single_tenant_tr_plugin as _;
 #[cfg(feature = "static-tenants")]
 use static_tr_plugin as _;
 
+#[cfg(feature = "db-tenants")]
+use db_tr_plugin as _;
+
+#[cfg(feature = "static-license")]
+use static_license_plugin as _;
+
 // === Example Features ===
 
 #[cfg(feature = "users-info-example")]
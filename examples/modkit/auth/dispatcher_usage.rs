@@ -56,6 +56,7 @@ fn setup_keycloak_auth() -> Result<(), String> {
             refresh_interval_seconds: 300,
             max_backoff_seconds: 3600,
         }),
+        introspection: None,
         plugins,
     };
 
@@ -99,6 +100,7 @@ fn setup_oidc_auth() -> Result<(), String> {
             refresh_interval_seconds: 600, // 10 minutes
             max_backoff_seconds: 7200,     // 2 hours
         }),
+        introspection: None,
         plugins,
     };
 
@@ -52,6 +52,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "read")
             .prepare()
             .await?;
 
@@ -73,6 +74,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "read")
             .prepare()
             .await?;
 
@@ -94,6 +96,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "read")
             .prepare()
             .await?;
 
@@ -128,6 +131,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "update")
             .prepare()
             .await?;
 
@@ -194,6 +198,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "delete")
             .prepare()
             .await?;
 
@@ -222,6 +227,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "create")
             .prepare()
             .await?;
 
@@ -261,6 +267,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "update")
             .prepare()
             .await?;
 
@@ -296,6 +303,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("addresses", "delete")
             .prepare()
             .await?;
 
@@ -45,6 +45,7 @@ impl<R: CitiesRepository> CitiesService<R> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("cities", "read")
             .prepare()
             .await?;
 
@@ -65,6 +66,7 @@ impl<R: CitiesRepository> CitiesService<R> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("cities", "read")
             .prepare()
             .await?;
 
@@ -86,6 +88,7 @@ impl<R: CitiesRepository> CitiesService<R> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("cities", "create")
             .prepare()
             .await?;
 
@@ -123,6 +126,7 @@ impl<R: CitiesRepository> CitiesService<R> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("cities", "update")
             .prepare()
             .await?;
 
@@ -155,6 +159,7 @@ impl<R: CitiesRepository> CitiesService<R> {
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("cities", "delete")
             .prepare()
             .await?;
 
@@ -134,10 +134,9 @@ where
         events: Arc<dyn EventPublisher<UserDomainEvent>>,
         audit: Arc<dyn AuditPort>,
         resolver: Arc<dyn TenantResolverGatewayClient>,
+        policy_engine: PolicyEngineRef,
         config: ServiceConfig,
     ) -> Self {
-        let policy_engine: PolicyEngineRef = Arc::new(modkit_security::NoopPolicyEngine);
-
         let users_repo = Arc::new(users_repo);
         let cities_repo = Arc::new(cities_repo);
         let addresses_repo = Arc::new(addresses_repo);
@@ -162,7 +161,7 @@ where
                 db,
                 events,
                 audit,
-                policy_engine.clone(),
+                policy_engine,
                 resolver,
                 config,
                 cities.clone(),
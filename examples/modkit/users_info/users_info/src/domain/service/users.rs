@@ -102,6 +102,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("users", "read")
             .prepare()
             .await?;
 
@@ -126,6 +127,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("users", "read")
             .prepare()
             .await?;
 
@@ -162,6 +164,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("users", "create")
             .prepare()
             .await?;
 
@@ -227,6 +230,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("users", "update")
             .prepare()
             .await?;
 
@@ -283,6 +287,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         let scope = ctx
             .scope(self.policy_engine.clone())
             .include_accessible_tenants(tenant_ids)
+            .for_operation("users", "delete")
             .prepare()
             .await?;
 
@@ -5,6 +5,7 @@ use modkit::api::OpenApiRegistry;
 use modkit::{
     DatabaseCapability, Module, ModuleCtx, RestApiCapability, SseBroadcaster, TracedClient,
 };
+use modkit_security::{PolicyEngine, PolicyEngineRef, RuleBasedPolicyEngine};
 use sea_orm_migration::MigratorTrait;
 use tracing::{debug, info};
 use url::Url;
@@ -103,6 +104,12 @@ impl Module for UsersInfo {
             .get::<dyn TenantResolverGatewayClient>()
             .map_err(|e| anyhow::anyhow!("failed to get tenant resolver: {e}"))?;
 
+        // Policy engine configured by the host (falls back to an empty rule set)
+        let policy_engine: PolicyEngineRef = ctx
+            .client_hub()
+            .get::<dyn PolicyEngine>()
+            .unwrap_or_else(|_| Arc::new(RuleBasedPolicyEngine::default()));
+
         let service_config = ServiceConfig {
             max_display_name_length: 100,
             default_page_size: cfg.default_page_size,
@@ -124,6 +131,7 @@ impl Module for UsersInfo {
             publisher,
             audit_adapter,
             resolver,
+            policy_engine,
             service_config,
         ));
 
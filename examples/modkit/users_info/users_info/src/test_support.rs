@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use modkit_db::secure::SecureConn;
-use modkit_security::SecurityContext;
+use modkit_security::{RuleBasedPolicyEngine, SecurityContext};
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::MigratorTrait;
 use tenant_resolver_sdk::{
@@ -100,6 +100,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
@@ -130,6 +131,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {tenant_id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         }])
     }
 }
@@ -149,6 +151,7 @@ pub fn build_services(sec: SecureConn, config: ServiceConfig) -> Arc<ConcreteApp
         Arc::new(MockEventPublisher),
         Arc::new(MockAuditPort),
         Arc::new(MockTenantResolver),
+        Arc::new(RuleBasedPolicyEngine::default()),
         config,
     ))
 }
@@ -32,6 +32,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
@@ -59,6 +60,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {tenant_id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         }])
     }
 }
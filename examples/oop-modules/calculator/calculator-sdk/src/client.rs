@@ -6,10 +6,13 @@ use anyhow::Result;
 use async_trait::async_trait;
 use tonic::transport::Channel;
 
-use modkit_security::SecurityContext;
-use modkit_transport_grpc::attach_secctx;
+use std::sync::Arc;
+
+use modkit_security::{SecCtxCodec, SecurityContext};
+use modkit_transport_grpc::attach_secctx_signed;
 use modkit_transport_grpc::client::{GrpcClientConfig, connect_with_retry};
 
+use crate::SERVICE_NAME;
 use crate::api::{CalculatorClient, CalculatorError};
 use crate::proto::AddRequest;
 use crate::proto::calculator_service_client::CalculatorServiceClient;
@@ -17,15 +20,19 @@ use crate::proto::calculator_service_client::CalculatorServiceClient;
 /// gRPC client implementation of CalculatorClient
 pub(crate) struct CalculatorGrpcClient {
     inner: CalculatorServiceClient<Channel>,
+    secctx_codec: Arc<SecCtxCodec>,
 }
 
 impl CalculatorGrpcClient {
     /// Connect to the CalculatorService using default configuration with retries.
-    pub async fn connect(uri: impl Into<String>) -> Result<Self> {
+    ///
+    /// `secctx_codec` signs the SecurityContext attached to every call.
+    pub async fn connect(uri: impl Into<String>, secctx_codec: Arc<SecCtxCodec>) -> Result<Self> {
         let cfg = GrpcClientConfig::new("calculator");
         let channel: Channel = connect_with_retry(uri, &cfg).await?;
         Ok(Self {
             inner: CalculatorServiceClient::new(channel),
+            secctx_codec,
         })
     }
 }
@@ -39,9 +46,14 @@ impl CalculatorClient for CalculatorGrpcClient {
         let proto_req = AddRequest { a, b };
         let mut request = tonic::Request::new(proto_req);
 
-        // Attach SecurityContext to metadata
-        attach_secctx(request.metadata_mut(), ctx)
-            .map_err(|e| CalculatorError::Internal(e.to_string()))?;
+        // Attach SecurityContext to metadata, addressed to the calculator service
+        attach_secctx_signed(
+            request.metadata_mut(),
+            ctx,
+            &self.secctx_codec,
+            SERVICE_NAME,
+        )
+        .map_err(|e| CalculatorError::Internal(e.to_string()))?;
 
         // Make the gRPC call
         let response = client
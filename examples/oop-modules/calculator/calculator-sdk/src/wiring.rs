@@ -7,6 +7,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use cf_system_sdks::directory::DirectoryClient;
 use modkit::client_hub::ClientHub;
+use modkit_security::SecCtxCodec;
 
 use crate::SERVICE_NAME;
 use crate::api::CalculatorClient;
@@ -16,7 +17,8 @@ use crate::client::CalculatorGrpcClient;
 ///
 /// This function:
 /// 1. Resolves the CalculatorService endpoint from the DirectoryClient
-/// 2. Creates a gRPC client
+/// 2. Creates a gRPC client that signs SecurityContext with the `SecCtxCodec`
+///    from the ClientHub (unsigned if none is registered)
 /// 3. Registers it in the ClientHub as `dyn CalculatorClient`
 ///
 /// # Example
@@ -28,7 +30,10 @@ use crate::client::CalculatorGrpcClient;
 /// ```
 pub async fn wire_client(hub: &ClientHub, resolver: &dyn DirectoryClient) -> Result<()> {
     let endpoint = resolver.resolve_grpc_service(SERVICE_NAME).await?;
-    let client = CalculatorGrpcClient::connect(&endpoint.uri).await?;
+    let secctx_codec = hub
+        .get::<SecCtxCodec>()
+        .unwrap_or_else(|_| Arc::new(SecCtxCodec::default()));
+    let client = CalculatorGrpcClient::connect(&endpoint.uri, secctx_codec).await?;
     hub.register::<dyn CalculatorClient>(Arc::new(client));
     tracing::info!(service = SERVICE_NAME, "CalculatorClient client wired");
     Ok(())
@@ -7,8 +7,9 @@ use std::sync::Arc;
 
 use tonic::{Request, Response, Status};
 
-use calculator_sdk::{AddRequest, AddResponse, CalculatorService};
-use modkit_transport_grpc::extract_secctx;
+use calculator_sdk::{AddRequest, AddResponse, CalculatorService, SERVICE_NAME};
+use modkit_security::SecCtxCodec;
+use modkit_transport_grpc::extract_secctx_verified;
 
 use crate::domain::Service;
 
@@ -16,12 +17,18 @@ use crate::domain::Service;
 #[derive(Clone)]
 pub struct CalculatorServiceImpl {
     service: Arc<Service>,
+    secctx_codec: Arc<SecCtxCodec>,
 }
 
 impl CalculatorServiceImpl {
     /// Create a new CalculatorService implementation with the given Service.
-    pub fn new(service: Arc<Service>) -> Self {
-        Self { service }
+    ///
+    /// `secctx_codec` verifies the SecurityContext envelope of every request.
+    pub fn new(service: Arc<Service>, secctx_codec: Arc<SecCtxCodec>) -> Self {
+        Self {
+            service,
+            secctx_codec,
+        }
     }
 }
 
@@ -29,7 +36,7 @@ impl CalculatorServiceImpl {
 impl CalculatorService for CalculatorServiceImpl {
     async fn add(&self, request: Request<AddRequest>) -> Result<Response<AddResponse>, Status> {
         // Extract SecurityCtx from gRPC metadata (for authorization)
-        let _ctx = extract_secctx(request.metadata())?;
+        let _ctx = extract_secctx_verified(request.metadata(), &self.secctx_codec, SERVICE_NAME)?;
 
         let req = request.into_inner();
 
@@ -12,6 +12,7 @@ use modkit::context::ModuleCtx;
 use modkit::contracts::{GrpcServiceCapability, RegisterGrpcServiceFn};
 
 use calculator_sdk::{CalculatorServiceServer, SERVICE_NAME};
+use modkit_security::SecCtxCodec;
 
 use crate::api::grpc::CalculatorServiceImpl;
 use crate::domain::Service;
@@ -57,8 +58,14 @@ impl GrpcServiceCapability for CalculatorModule {
             .get::<Service>()
             .map_err(|e| anyhow::anyhow!("Service not available: {}", e))?;
 
+        // Secctx codec registered by the OoP bootstrap; unsigned envelopes if absent
+        let secctx_codec = ctx
+            .client_hub()
+            .get::<SecCtxCodec>()
+            .unwrap_or_else(|_| Arc::new(SecCtxCodec::default()));
+
         // Build CalculatorService with our domain service
-        let svc = CalculatorServiceServer::new(CalculatorServiceImpl::new(service));
+        let svc = CalculatorServiceServer::new(CalculatorServiceImpl::new(service, secctx_codec));
 
         Ok(vec![RegisterGrpcServiceFn {
             service_name: SERVICE_NAME,
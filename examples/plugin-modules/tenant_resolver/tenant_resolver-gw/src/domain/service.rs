@@ -1,7 +1,8 @@
 //! Domain service for Tenant Resolver Gateway.
 //!
 //! Plugin discovery is lazy: the plugin is resolved on the first API call,
-//! after `types_registry` has switched to ready mode.
+//! after `types_registry` has switched to ready mode. It is resolved again
+//! on the next call after plugin instances are registered, updated or deprecated.
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -34,8 +35,8 @@ const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
 pub struct Service {
     hub: Arc<ClientHub>,
     vendor: String,
-    /// Shared selector for plugin instance IDs.
-    selector: GtsPluginSelector,
+    /// Shared selector for plugin instance IDs, reset when plugin instances change.
+    selector: Arc<GtsPluginSelector>,
     /// Throttle for plugin unavailable warnings.
     unavailable_log_throttle: ThrottledLog,
 }
@@ -47,7 +48,7 @@ impl Service {
         Self {
             hub,
             vendor,
-            selector: GtsPluginSelector::new(),
+            selector: Arc::new(GtsPluginSelector::new()),
             unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
         }
     }
@@ -92,16 +93,17 @@ impl Service {
 
         let plugin_type_id = TenantResolverPluginSpecV1::gts_schema_id().clone();
 
-        let instances = registry
-            .list(
-                ListQuery::new()
-                    .with_pattern(format!("{plugin_type_id}*"))
-                    .with_is_type(false),
-            )
-            .await?;
+        let query = ListQuery::new()
+            .with_pattern(format!("{plugin_type_id}*"))
+            .with_is_type(false);
+
+        // Subscribe first so that no change made after the listing is missed
+        let changes = registry.watch(query.clone());
+        let instances = registry.list_all(query).await?;
 
         let gts_id = choose_plugin_instance(&self.vendor, &instances)?;
         info!(plugin_gts_id = %gts_id, "Selected tenant resolver plugin instance");
+        self.selector.reset_on_change(changes);
 
         Ok(gts_id)
     }
@@ -65,6 +65,14 @@ where
     }
 }
 
+/// Claims established by the transport before `AuthPolicyLayer` runs, e.g. from a
+/// verified mTLS client certificate.
+///
+/// Insert it as a request extension in an outer layer; it is used only when the
+/// request carries no bearer token. RBAC requirements still apply.
+#[derive(Debug, Clone)]
+pub struct TransportIdentity(pub Claims);
+
 /// Shared state for authentication policy middleware.
 struct AuthPolicyState {
     validator: Arc<dyn TokenValidator>,
@@ -156,16 +164,21 @@ where
                     ready_inner.call(request).await
                 }
                 AuthRequirement::Required(sec_requirement) => {
-                    // 4. For required routes: validates JWT, enforces RBAC if needed, inserts SecurityContext
-                    let Some(token) = extract_bearer_token(request.headers()) else {
-                        return Ok(AuthError::Unauthenticated.into_response());
-                    };
-
-                    let claims = match state.validator.validate_and_parse(token).await {
-                        Ok(claims) => claims,
-                        Err(err) => {
-                            return Ok(err.into_response());
+                    // 4. For required routes: validates JWT (or takes the transport identity when
+                    // no token is sent), enforces RBAC if needed, inserts SecurityContext
+                    let claims = if let Some(token) = extract_bearer_token(request.headers()) {
+                        match state.validator.validate_and_parse(token).await {
+                            Ok(claims) => claims,
+                            Err(err) => {
+                                return Ok(err.into_response());
+                            }
                         }
+                    } else if let Some(TransportIdentity(claims)) =
+                        request.extensions_mut().remove::<TransportIdentity>()
+                    {
+                        claims
+                    } else {
+                        return Ok(AuthError::Unauthenticated.into_response());
                     };
 
                     // Optional RBAC requirement
@@ -176,25 +189,20 @@ where
                     }
 
                     // Build SecurityContext from validated claims
-                    let sec_context = SecurityContext::builder()
-                        .tenant_id(claims.tenant_id)
-                        .subject_id(claims.subject)
-                        .build();
+                    let sec_context = security_context_from_claims(&claims);
 
                     request.extensions_mut().insert(claims);
                     request.extensions_mut().insert(sec_context);
                     ready_inner.call(request).await
                 }
                 AuthRequirement::Optional => {
-                    // 5. For optional routes: validates JWT if present, otherwise inserts anonymous SecurityContext
+                    // 5. For optional routes: validates JWT if present, else uses the transport identity,
+                    // otherwise inserts anonymous SecurityContext
                     if let Some(token) = extract_bearer_token(request.headers()) {
                         match state.validator.validate_and_parse(token).await {
                             Ok(claims) => {
                                 // Build SecurityContext from validated claims
-                                let sec_context = SecurityContext::builder()
-                                    .tenant_id(claims.tenant_id)
-                                    .subject_id(claims.subject)
-                                    .build();
+                                let sec_context = security_context_from_claims(&claims);
 
                                 request.extensions_mut().insert(claims);
                                 request.extensions_mut().insert(sec_context);
@@ -206,6 +214,13 @@ where
                                     .insert(SecurityContext::anonymous());
                             }
                         }
+                    } else if let Some(TransportIdentity(claims)) =
+                        request.extensions_mut().remove::<TransportIdentity>()
+                    {
+                        let sec_context = security_context_from_claims(&claims);
+
+                        request.extensions_mut().insert(claims);
+                        request.extensions_mut().insert(sec_context);
                     } else {
                         request
                             .extensions_mut()
@@ -218,6 +233,33 @@ where
     }
 }
 
+/// Build a `SecurityContext` from validated claims, carrying the granted permissions
+/// so the policy engine can narrow access scopes.
+fn security_context_from_claims(claims: &Claims) -> SecurityContext {
+    claims
+        .permissions
+        .iter()
+        .filter_map(|perm| {
+            let mut builder = modkit_security::Permission::builder()
+                .resource_pattern(perm.resource_pattern())
+                .action(perm.action());
+            if let Some(tenant_id) = perm.tenant_id() {
+                builder = builder.tenant_id(tenant_id);
+            }
+            if let Some(resource_id) = perm.resource_id() {
+                builder = builder.resource_id(resource_id);
+            }
+            builder.build().ok()
+        })
+        .fold(
+            SecurityContext::builder()
+                .tenant_id(claims.tenant_id)
+                .subject_id(claims.subject),
+            modkit_security::context::SecurityContextBuilder::add_permission,
+        )
+        .build()
+}
+
 /// Extract Bearer token from Authorization header
 fn extract_bearer_token(headers: &HeaderMap) -> Option<&str> {
     headers
@@ -3,7 +3,7 @@ use crate::{
     config_error::ConfigError,
     dispatcher::AuthDispatcher,
     plugins::{GenericOidcPlugin, KeycloakClaimsPlugin},
-    providers::JwksKeyProvider,
+    providers::{HttpIntrospectionProvider, JwksKeyProvider},
     validation::ValidationConfig,
 };
 use serde::{Deserialize, Serialize};
@@ -34,6 +34,10 @@ pub struct AuthConfig {
     #[serde(default)]
     pub jwks: Option<JwksConfig>,
 
+    /// RFC 7662 token introspection configuration (for opaque tokens)
+    #[serde(default)]
+    pub introspection: Option<IntrospectionConfig>,
+
     /// Available plugins (named configurations)
     #[serde(default)]
     pub plugins: HashMap<String, PluginConfig>,
@@ -51,6 +55,7 @@ impl Default for AuthConfig {
             issuers: Vec::new(),
             audiences: Vec::new(),
             jwks: None,
+            introspection: None,
             plugins: HashMap::default(),
         }
     }
@@ -92,6 +97,70 @@ fn default_max_backoff() -> u64 {
     3600
 }
 
+/// RFC 7662 introspection endpoint configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntrospectionConfig {
+    /// Introspection endpoint URL
+    pub endpoint: String,
+
+    /// How the gateway authenticates to the introspection endpoint
+    pub auth: IntrospectionAuth,
+
+    /// Upper bound in seconds for caching an active result; results never outlive the
+    /// token's `exp` (default: 300)
+    #[serde(default = "default_introspection_cache_ttl")]
+    pub cache_ttl_seconds: u64,
+
+    /// How long an inactive result is cached in seconds (default: 30)
+    #[serde(default = "default_introspection_negative_cache_ttl")]
+    pub negative_cache_ttl_seconds: u64,
+
+    /// Maximum number of cached results, 0 disables caching (default: 10000)
+    #[serde(default = "default_introspection_cache_max_entries")]
+    pub cache_max_entries: usize,
+
+    /// HTTP request timeout in seconds (default: 10)
+    #[serde(default = "default_introspection_timeout")]
+    pub timeout_seconds: u64,
+}
+
+/// Client authentication for the introspection endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IntrospectionAuth {
+    /// HTTP Basic authentication with the client credentials
+    Basic {
+        client_id: String,
+        client_secret: String,
+    },
+    /// Bearer access token obtained with the `client_credentials` grant
+    ClientCredentials {
+        /// Token endpoint URL
+        token_endpoint: String,
+        client_id: String,
+        client_secret: String,
+        /// Optional scope to request
+        #[serde(default)]
+        scope: Option<String>,
+    },
+}
+
+fn default_introspection_cache_ttl() -> u64 {
+    300
+}
+
+fn default_introspection_negative_cache_ttl() -> u64 {
+    30
+}
+
+fn default_introspection_cache_max_entries() -> usize {
+    10_000
+}
+
+fn default_introspection_timeout() -> u64 {
+    10
+}
+
 /// Plugin-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -186,6 +255,14 @@ pub fn build_auth_dispatcher(config: &AuthConfig) -> Result<AuthDispatcher, Conf
         dispatcher
     };
 
+    let dispatcher = if let Some(introspection_config) = &config.introspection {
+        let provider = HttpIntrospectionProvider::from_config(introspection_config)?;
+
+        dispatcher.with_introspection_provider(Arc::new(provider))
+    } else {
+        dispatcher
+    };
+
     tracing::info!(
         plugin = %config.mode.provider,
         "Authentication dispatcher initialized (single mode)"
@@ -227,6 +304,7 @@ mod tests {
             issuers: vec!["https://auth.example.com".to_owned()],
             audiences: vec!["api".to_owned()],
             jwks: None,
+            introspection: None,
             plugins,
         };
 
@@ -273,6 +351,7 @@ mod tests {
                 refresh_interval_seconds: 300,
                 max_backoff_seconds: 3600,
             }),
+            introspection: None,
             plugins,
         };
 
@@ -307,6 +386,7 @@ mod tests {
                 refresh_interval_seconds: 300,
                 max_backoff_seconds: 3600,
             }),
+            introspection: None,
             plugins,
         };
 
@@ -19,6 +19,16 @@ fn truncate_uuid(uuid: &Uuid) -> String {
     s.chars().take(8).collect()
 }
 
+/// Whether a token has the three-segment compact JWS shape of a JWT
+fn is_jwt(token: &str) -> bool {
+    token
+        .trim_start_matches("Bearer ")
+        .trim()
+        .split('.')
+        .count()
+        == 3
+}
+
 /// Central dispatcher for JWT and opaque token validation
 ///
 /// Orchestrates key providers and claims plugins to validate tokens
@@ -368,10 +378,15 @@ impl AuthDispatcher {
 #[async_trait]
 impl TokenValidator for AuthDispatcher {
     async fn validate_and_parse(&self, token: &str) -> Result<Claims, AuthError> {
-        // All JWT validation errors should result in 401 Unauthenticated
-        self.validate_jwt(token)
-            .await
-            .map_err(|_| AuthError::Unauthenticated)
+        // Tokens that are not JWTs go through introspection when a provider is configured
+        let result = if !self.introspection_providers.is_empty() && !is_jwt(token) {
+            self.validate_opaque(token).await
+        } else {
+            self.validate_jwt(token).await
+        };
+
+        // All validation errors should result in 401 Unauthenticated
+        result.map_err(|_| AuthError::Unauthenticated)
     }
 }
 
@@ -797,6 +812,38 @@ mod tests {
         assert_eq!(normalized.issuer, claims.issuer);
     }
 
+    #[tokio::test]
+    async fn test_token_validator_routes_opaque_tokens_to_introspection() {
+        // Given: A dispatcher with an introspection provider but no key providers
+        let introspection_response = json!({
+            "active": true,
+            "iss": "https://test.example.com",
+            "sub": "user-123"
+        });
+
+        let dispatcher = AuthDispatcher {
+            key_providers: Vec::new(),
+            introspection_providers: vec![Arc::new(MockIntrospectionProvider::success(
+                introspection_response,
+            ))],
+            plugin: Arc::new(MockClaimsPlugin::success(test_claims())),
+            validation_config: ValidationConfig {
+                allowed_issuers: vec!["https://test.example.com".to_owned()],
+                allowed_audiences: vec!["test-api".to_owned()],
+                leeway_seconds: 60,
+                require_uuid_subject: true,
+                require_uuid_tenants: true,
+            },
+        };
+
+        // When/Then: An opaque token is introspected, a JWT-shaped token is not
+        assert!(dispatcher.validate_and_parse("opaque-token").await.is_ok());
+        assert!(matches!(
+            dispatcher.validate_and_parse("a.b.c").await,
+            Err(AuthError::Unauthenticated)
+        ));
+    }
+
     #[tokio::test]
     async fn test_validate_opaque_inactive_token() {
         // Given: A response with active=false
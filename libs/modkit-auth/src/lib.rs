@@ -34,10 +34,15 @@ pub use types::{AuthRequirement, RoutePolicy, SecRequirement};
 // Plugin system exports
 pub use auth_mode::{AuthModeConfig, PluginRegistry};
 pub use claims_error::ClaimsError;
-pub use config::{AuthConfig, JwksConfig, PluginConfig, build_auth_dispatcher};
+pub use config::{
+    AuthConfig, IntrospectionAuth, IntrospectionConfig, JwksConfig, PluginConfig,
+    build_auth_dispatcher,
+};
 pub use config_error::ConfigError;
 pub use dispatcher::AuthDispatcher;
-pub use metrics::{AuthEvent, AuthMetricLabels, AuthMetrics, LoggingMetrics, NoOpMetrics};
+pub use metrics::{
+    AuthEvent, AuthMetricLabels, AuthMetrics, LoggingMetrics, NoOpMetrics, RegistryMetrics,
+};
 pub use plugin_traits::{ClaimsPlugin, IntrospectionProvider, KeyProvider};
 pub use standard_claims::StandardClaim;
 pub use validation::ValidationConfig;
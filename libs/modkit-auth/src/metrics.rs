@@ -1,7 +1,10 @@
-/// Metrics tracking for auth events
-///
-/// This module provides a trait-based approach to metrics that can be
-/// implemented with various backends (Prometheus, `StatsD`, etc.)
+//! Metrics tracking for auth events
+//!
+//! This module provides a trait-based approach to metrics that can be
+//! implemented with various backends (Prometheus, `StatsD`, etc.)
+
+use modkit_metrics::{Counter, DURATION_BUCKETS, Histogram, MetricsRegistry};
+
 /// Auth event types for metrics tracking
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AuthEvent {
@@ -128,6 +131,61 @@ impl AuthMetrics for LoggingMetrics {
     }
 }
 
+/// Metrics implementation backed by a [`MetricsRegistry`], exposed on `/metrics`.
+///
+/// Records `auth_events_total{event, provider, error_type}` and
+/// `auth_validation_duration_seconds{provider}`; issuer and key id are left out to
+/// keep label cardinality bounded.
+#[derive(Debug, Clone)]
+pub struct RegistryMetrics {
+    events: Counter,
+    duration: Histogram,
+}
+
+impl RegistryMetrics {
+    /// Record into `registry`.
+    #[must_use]
+    pub fn new(registry: &MetricsRegistry) -> Self {
+        Self {
+            events: registry.counter(
+                "auth_events_total",
+                "Auth events by event, provider and error type",
+            ),
+            duration: registry.histogram(
+                "auth_validation_duration_seconds",
+                "Token validation latency by provider",
+                DURATION_BUCKETS,
+            ),
+        }
+    }
+}
+
+impl Default for RegistryMetrics {
+    /// Record into [`MetricsRegistry::global`].
+    fn default() -> Self {
+        Self::new(MetricsRegistry::global())
+    }
+}
+
+impl AuthMetrics for RegistryMetrics {
+    fn record_event(&self, event: AuthEvent, labels: &AuthMetricLabels) {
+        self.events.inc(&[
+            ("event", event.metric_name()),
+            ("provider", labels.provider.as_deref().unwrap_or("")),
+            ("error_type", labels.error_type.as_deref().unwrap_or("")),
+        ]);
+    }
+
+    fn record_duration(&self, duration_ms: u64, labels: &AuthMetricLabels) {
+        #[allow(clippy::cast_precision_loss)]
+        let seconds = duration_ms as f64 / 1000.0;
+        self.duration.observe(
+            &[("provider", labels.provider.as_deref().unwrap_or(""))],
+            seconds,
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -181,4 +239,24 @@ mod tests {
         metrics.record_event(AuthEvent::JwtValid, &labels);
         metrics.record_duration(50, &labels);
     }
+
+    #[test]
+    fn test_registry_metrics() {
+        let registry = MetricsRegistry::new();
+        let metrics = RegistryMetrics::new(&registry);
+        let labels = AuthMetricLabels::default()
+            .with_provider("keycloak")
+            .with_error_type("expired");
+
+        metrics.record_event(AuthEvent::JwtInvalid, &labels);
+        metrics.record_duration(12, &labels);
+
+        let encoded = registry.encode();
+        assert!(encoded.contains(
+            r#"auth_events_total{event="auth.jwt.invalid",provider="keycloak",error_type="expired"} 1"#
+        ));
+        assert!(
+            encoded.contains(r#"auth_validation_duration_seconds_count{provider="keycloak"} 1"#)
+        );
+    }
 }
@@ -0,0 +1,396 @@
+use crate::{
+    claims_error::ClaimsError,
+    config::{IntrospectionAuth, IntrospectionConfig},
+    plugin_traits::IntrospectionProvider,
+};
+use async_trait::async_trait;
+use lru::LruCache;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::Instant;
+
+/// Access tokens for the introspection endpoint are refreshed this long before they expire
+const CLIENT_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Fallback lifetime for client-credentials tokens without `expires_in`
+const DEFAULT_CLIENT_TOKEN_LIFETIME: Duration = Duration::from_mins(1);
+
+#[derive(Debug, Clone)]
+struct CachedResult {
+    response: Value,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Clone)]
+struct ClientToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+/// RFC 7662 token introspection provider
+///
+/// Posts opaque tokens to an OAuth 2.0 introspection endpoint, authenticating either with
+/// HTTP Basic client credentials or with an access token obtained via the client-credentials
+/// grant. Results are cached per token (keyed by SHA-256): active tokens until their `exp`
+/// (capped by the configured TTL), inactive tokens for a short negative TTL. Transport and
+/// server errors are never cached. A full cache evicts its least recently used result.
+#[must_use]
+pub struct HttpIntrospectionProvider {
+    /// Introspection endpoint URL
+    endpoint: String,
+
+    /// How to authenticate to the endpoint
+    auth: IntrospectionAuth,
+
+    /// HTTP client for introspection and token requests
+    client: reqwest::Client,
+
+    /// Upper bound for caching an active result (default: 5 minutes)
+    cache_ttl: Duration,
+
+    /// How long an inactive result is cached (default: 30 seconds)
+    negative_cache_ttl: Duration,
+
+    /// Maximum number of cached results (default: 10000)
+    cache_max_entries: usize,
+
+    /// Cached introspection results keyed by token hash
+    cache: Mutex<LruCache<[u8; 32], CachedResult>>,
+
+    /// Access token for the client-credentials mode
+    client_token: RwLock<Option<ClientToken>>,
+}
+
+impl HttpIntrospectionProvider {
+    /// Create a new introspection provider for `endpoint`
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP client fails to build.
+    pub fn new(
+        endpoint: impl Into<String>,
+        auth: IntrospectionAuth,
+    ) -> Result<Self, reqwest::Error> {
+        Ok(Self {
+            endpoint: endpoint.into(),
+            auth,
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()?,
+            cache_ttl: Duration::from_mins(5),
+            negative_cache_ttl: Duration::from_secs(30),
+            cache_max_entries: 10_000,
+            cache: Mutex::new(LruCache::new(cache_capacity(10_000))),
+            client_token: RwLock::new(None),
+        })
+    }
+
+    /// Create from `AuthConfig.introspection`
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP client fails to build.
+    pub fn from_config(config: &IntrospectionConfig) -> Result<Self, reqwest::Error> {
+        let mut provider = Self::new(&config.endpoint, config.auth.clone())?
+            .with_cache_ttl(Duration::from_secs(config.cache_ttl_seconds))
+            .with_negative_cache_ttl(Duration::from_secs(config.negative_cache_ttl_seconds))
+            .with_cache_max_entries(config.cache_max_entries);
+        provider.client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()?;
+        Ok(provider)
+    }
+
+    /// Create with custom upper bound for caching active results
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Create with custom TTL for inactive results
+    pub fn with_negative_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_cache_ttl = ttl;
+        self
+    }
+
+    /// Create with custom cache capacity (0 disables caching)
+    pub fn with_cache_max_entries(mut self, max_entries: usize) -> Self {
+        self.cache_max_entries = max_entries;
+        self.cache = Mutex::new(LruCache::new(cache_capacity(max_entries)));
+        self
+    }
+
+    fn cache_key(token: &str) -> [u8; 32] {
+        Sha256::digest(token.as_bytes()).into()
+    }
+
+    /// Look up a cached, non-expired result; an expired one is dropped
+    async fn cached(&self, key: &[u8; 32]) -> Option<Value> {
+        let mut cache = self.cache.lock().await;
+        let entry = cache.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            cache.pop(key);
+            return None;
+        }
+        Some(entry.response.clone())
+    }
+
+    /// How long a response may be cached: inactive results use the negative TTL, active ones
+    /// live until `exp` but never longer than the configured TTL
+    fn cache_lifetime(&self, response: &Value) -> Option<Duration> {
+        let active = response
+            .get("active")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if !active {
+            return Some(self.negative_cache_ttl);
+        }
+
+        let Some(exp) = response.get("exp").and_then(Value::as_i64) else {
+            return Some(self.cache_ttl);
+        };
+        let remaining = exp - time::OffsetDateTime::now_utc().unix_timestamp();
+        u64::try_from(remaining)
+            .ok()
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::from_secs(secs).min(self.cache_ttl))
+    }
+
+    async fn store(&self, key: [u8; 32], response: &Value) {
+        if self.cache_max_entries == 0 {
+            return;
+        }
+        let Some(lifetime) = self.cache_lifetime(response) else {
+            return;
+        };
+        if lifetime.is_zero() {
+            return;
+        }
+
+        self.cache.lock().await.put(
+            key,
+            CachedResult {
+                response: response.clone(),
+                expires_at: Instant::now() + lifetime,
+            },
+        );
+    }
+
+    /// Get an access token for the client-credentials mode, fetching a new one when needed
+    async fn client_access_token(
+        &self,
+        token_endpoint: &str,
+        client_id: &str,
+        client_secret: &str,
+        scope: Option<&str>,
+    ) -> Result<String, ClaimsError> {
+        if let Some(token) = self.client_token.read().await.as_ref()
+            && token.expires_at > Instant::now()
+        {
+            return Ok(token.access_token.clone());
+        }
+
+        let mut form = vec![("grant_type", "client_credentials")];
+        if let Some(scope) = scope {
+            form.push(("scope", scope));
+        }
+
+        let response = self
+            .client
+            .post(token_endpoint)
+            .basic_auth(client_id, Some(client_secret))
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| ClaimsError::Provider(format!("Token request failed: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ClaimsError::Provider(format!(
+                "Token endpoint returned HTTP {}",
+                response.status()
+            )));
+        }
+
+        let token: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| ClaimsError::Provider(format!("Invalid token response: {e}")))?;
+
+        let lifetime = token
+            .expires_in
+            .map_or(DEFAULT_CLIENT_TOKEN_LIFETIME, Duration::from_secs);
+        *self.client_token.write().await = Some(ClientToken {
+            access_token: token.access_token.clone(),
+            expires_at: Instant::now() + lifetime.saturating_sub(CLIENT_TOKEN_EXPIRY_MARGIN),
+        });
+
+        Ok(token.access_token)
+    }
+
+    /// Call the introspection endpoint
+    async fn fetch(&self, token: &str) -> Result<Value, ClaimsError> {
+        let request = self
+            .client
+            .post(&self.endpoint)
+            .header(reqwest::header::ACCEPT, "application/json");
+
+        let request = match &self.auth {
+            IntrospectionAuth::Basic {
+                client_id,
+                client_secret,
+            } => request.basic_auth(client_id, Some(client_secret)),
+            IntrospectionAuth::ClientCredentials {
+                token_endpoint,
+                client_id,
+                client_secret,
+                scope,
+            } => {
+                let access_token = self
+                    .client_access_token(token_endpoint, client_id, client_secret, scope.as_deref())
+                    .await?;
+                request.bearer_auth(access_token)
+            }
+        };
+
+        let response = request
+            .form(&[("token", token), ("token_type_hint", "access_token")])
+            .send()
+            .await
+            .map_err(|e| ClaimsError::Provider(format!("Introspection request failed: {e}")))?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            // Our access token may have been revoked; fetch a new one next time
+            *self.client_token.write().await = None;
+        }
+        if !response.status().is_success() {
+            return Err(ClaimsError::Provider(format!(
+                "Introspection endpoint returned HTTP {}",
+                response.status()
+            )));
+        }
+
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| ClaimsError::Provider(format!("Invalid introspection response: {e}")))?;
+        if !body.get("active").is_some_and(Value::is_boolean) {
+            return Err(ClaimsError::Provider(
+                "Introspection response is missing the 'active' field".into(),
+            ));
+        }
+
+        Ok(body)
+    }
+}
+
+#[async_trait]
+impl IntrospectionProvider for HttpIntrospectionProvider {
+    fn name(&self) -> &'static str {
+        "rfc7662"
+    }
+
+    async fn introspect(&self, token: &str) -> Result<Value, ClaimsError> {
+        let token = token.trim_start_matches("Bearer ").trim();
+        let key = Self::cache_key(token);
+
+        if let Some(cached) = self.cached(&key).await {
+            tracing::trace!("Introspection cache hit");
+            return Ok(cached);
+        }
+
+        let response = self.fetch(token).await?;
+        self.store(key, &response).await;
+        Ok(response)
+    }
+}
+
+/// Capacity of the result cache; with `0` caching is off and the cache stays empty
+fn cache_capacity(max_entries: usize) -> NonZeroUsize {
+    NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn provider() -> HttpIntrospectionProvider {
+        HttpIntrospectionProvider::new(
+            "https://example.com/introspect",
+            IntrospectionAuth::Basic {
+                client_id: "gateway".to_owned(),
+                client_secret: "secret".to_owned(),
+            },
+        )
+        .unwrap()
+        .with_cache_ttl(Duration::from_mins(5))
+        .with_negative_cache_ttl(Duration::from_secs(30))
+    }
+
+    #[test]
+    fn test_cache_lifetime_is_bounded_by_exp() {
+        let p = provider();
+        let now = time::OffsetDateTime::now_utc().unix_timestamp();
+
+        let soon = p
+            .cache_lifetime(&json!({"active": true, "exp": now + 60}))
+            .unwrap();
+        assert!(soon <= Duration::from_mins(1) && soon >= Duration::from_secs(58));
+
+        assert_eq!(
+            p.cache_lifetime(&json!({"active": true, "exp": now + 3600})),
+            Some(Duration::from_mins(5))
+        );
+        assert_eq!(
+            p.cache_lifetime(&json!({"active": true})),
+            Some(Duration::from_mins(5))
+        );
+        assert_eq!(
+            p.cache_lifetime(&json!({"active": true, "exp": now - 1})),
+            None
+        );
+        assert_eq!(
+            p.cache_lifetime(&json!({"active": false})),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_least_recently_used_when_full() {
+        let p = provider().with_cache_max_entries(2);
+        let active = json!({"active": true});
+        let key = HttpIntrospectionProvider::cache_key;
+
+        p.store(key("a"), &active).await;
+        p.store(key("b"), &active).await;
+        // Using "a" makes "b" the least recently used result
+        assert!(p.cached(&key("a")).await.is_some());
+        p.store(key("c"), &active).await;
+
+        assert_eq!(p.cache.lock().await.len(), 2);
+        assert!(p.cached(&key("a")).await.is_some());
+        assert!(p.cached(&key("b")).await.is_none());
+        assert!(p.cached(&key("c")).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expired_result_is_dropped_on_lookup() {
+        let p = provider().with_negative_cache_ttl(Duration::from_millis(10));
+        let key = HttpIntrospectionProvider::cache_key("inactive");
+
+        p.store(key, &json!({"active": false})).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert!(p.cached(&key).await.is_none());
+        assert!(p.cache.lock().await.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod introspection;
 pub mod jwks;
 
+pub use introspection::HttpIntrospectionProvider;
 pub use jwks::JwksKeyProvider;
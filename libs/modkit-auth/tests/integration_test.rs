@@ -29,6 +29,7 @@ async fn test_dispatcher_single_mode() {
         issuers: vec!["https://keycloak.example.com/realms/test".to_owned()],
         audiences: vec!["modkit-api".to_owned()],
         jwks: None,
+        introspection: None,
         plugins,
     };
 
@@ -152,6 +153,7 @@ fn test_config_serialization_roundtrip() {
             refresh_interval_seconds: 300,
             max_backoff_seconds: 3600,
         }),
+        introspection: None,
         plugins,
     };
 
@@ -7,7 +7,7 @@ use axum::{
     response::IntoResponse,
     routing::get,
 };
-use modkit_auth::axum_ext::AuthPolicyLayer;
+use modkit_auth::axum_ext::{AuthPolicyLayer, TransportIdentity};
 use modkit_auth::{
     AuthConfig, AuthModeConfig, Claims, PluginConfig, ValidationConfig,
     authorizer::RoleAuthorizer,
@@ -61,6 +61,7 @@ fn create_test_config() -> AuthConfig {
         issuers: vec!["https://test.example.com".to_owned()],
         audiences: vec!["test-api".to_owned()],
         jwks: None,
+        introspection: None,
         plugins,
     }
 }
@@ -132,6 +133,44 @@ async fn test_middleware_returns_401_for_invalid_token() {
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
 
+#[tokio::test]
+async fn test_transport_identity_authenticates_without_token() {
+    let config = create_test_config();
+    let dispatcher = Arc::new(build_auth_dispatcher(&config).unwrap());
+
+    let validator: Arc<dyn TokenValidator> = dispatcher;
+    let authorizer: Arc<dyn PrimaryAuthorizer> = Arc::new(RoleAuthorizer);
+    let policy: Arc<dyn RoutePolicy> = Arc::new(AlwaysRequiredPolicy);
+
+    let app = create_app(policy, validator, authorizer);
+
+    let subject = uuid::Uuid::new_v4();
+    let mut request = Request::builder()
+        .uri("/protected")
+        .body(Body::empty())
+        .unwrap();
+    request.extensions_mut().insert(TransportIdentity(Claims {
+        issuer: "mtls".to_owned(),
+        subject,
+        audiences: vec![],
+        expires_at: None,
+        not_before: None,
+        issued_at: None,
+        jwt_id: None,
+        tenant_id: uuid::Uuid::new_v4(),
+        permissions: vec![],
+        extras: serde_json::Map::new(),
+    }));
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(body, format!("Protected: {subject}"));
+}
+
 #[tokio::test]
 async fn test_middleware_allows_options_preflight() {
     let config = create_test_config();
@@ -208,6 +247,7 @@ async fn test_single_mode_dispatcher_created() {
         issuers: vec!["https://test.example.com".to_owned()],
         audiences: vec!["api".to_owned()],
         jwks: None,
+        introspection: None,
         plugins,
     };
 
@@ -77,6 +77,7 @@ pub mod config;
 pub mod manager;
 pub mod odata;
 pub mod options;
+pub mod outbox;
 pub mod secure;
 
 // Internal modules
@@ -279,6 +280,17 @@ impl DbTransaction<'_> {
     }
 }
 
+/// Connection pool usage, see [`DbHandle::pool_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Open connections waiting to be acquired.
+    pub idle: u32,
+    /// Connections currently acquired.
+    pub in_use: u32,
+    /// Configured maximum number of connections.
+    pub max: u32,
+}
+
 /// Main handle.
 #[derive(Debug, Clone)]
 pub struct DbHandle {
@@ -443,6 +455,33 @@ impl DbHandle {
         &self.dsn
     }
 
+    /// Current usage of the connection pool.
+    #[must_use]
+    pub fn pool_stats(&self) -> PoolStats {
+        let (size, idle, max): (u32, usize, u32) = match self.pool {
+            #[cfg(feature = "pg")]
+            DbPool::Postgres(ref p) => (p.size(), p.num_idle(), p.options().get_max_connections()),
+            #[cfg(feature = "mysql")]
+            DbPool::MySql(ref p) => (p.size(), p.num_idle(), p.options().get_max_connections()),
+            #[cfg(feature = "sqlite")]
+            DbPool::Sqlite(ref p) => (p.size(), p.num_idle(), p.options().get_max_connections()),
+        };
+        let idle = u32::try_from(idle).unwrap_or(size);
+        PoolStats {
+            idle,
+            in_use: size.saturating_sub(idle),
+            max,
+        }
+    }
+
+    /// Check that the database is reachable with a round-trip on a pooled connection.
+    ///
+    /// # Errors
+    /// Returns an error if no connection can be acquired or the server does not respond.
+    pub async fn ping(&self) -> Result<()> {
+        Ok(self.sea.ping().await?)
+    }
+
     // --- sqlx accessors ---
     #[cfg(feature = "pg")]
     #[must_use]
@@ -10,9 +10,42 @@ use crate::options::build_db_handle;
 use crate::{DbError, DbHandle, Result};
 use dashmap::DashMap;
 use figment::Figment;
+use modkit_metrics::MetricsRegistry;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Expose the pool usage of a module's handle as `db_pool_connections{pool,state}` and
+/// `db_pool_max_connections{pool}`, refreshed at scrape time while the handle lives.
+fn export_pool_metrics(module: &str, handle: &Arc<DbHandle>) {
+    let metrics = MetricsRegistry::global();
+    let connections = metrics.gauge(
+        "db_pool_connections",
+        "Open database connections per module pool and state",
+    );
+    let max = metrics.gauge(
+        "db_pool_max_connections",
+        "Configured maximum of database connections per module pool",
+    );
+    let module = module.to_owned();
+    let handle = Arc::downgrade(handle);
+    metrics.on_collect(move || {
+        let pool = module.as_str();
+        let idle = [("pool", pool), ("state", "idle")];
+        let in_use = [("pool", pool), ("state", "in_use")];
+        let Some(handle) = handle.upgrade() else {
+            connections.remove(&idle);
+            connections.remove(&in_use);
+            max.remove(&[("pool", pool)]);
+            return false;
+        };
+        let stats = handle.pool_stats();
+        connections.set(&idle, i64::from(stats.idle));
+        connections.set(&in_use, i64::from(stats.in_use));
+        max.set(&[("pool", pool)], i64::from(stats.max));
+        true
+    });
+}
+
 /// Central database manager that handles per-module database connections.
 pub struct DbManager {
     /// Global database configuration loaded from Figment
@@ -70,6 +103,7 @@ impl DbManager {
                     }
                     dashmap::mapref::entry::Entry::Vacant(entry) => {
                         // We're first, insert our handle
+                        export_pool_metrics(module, &handle);
                         entry.insert(handle.clone());
                         Ok(Some(handle))
                     }
@@ -79,6 +113,15 @@ impl DbManager {
         }
     }
 
+    /// Handles built so far, by module name.
+    #[must_use]
+    pub fn handles(&self) -> Vec<(String, Arc<DbHandle>)> {
+        self.cache
+            .iter()
+            .map(|e| (e.key().clone(), Arc::clone(e.value())))
+            .collect()
+    }
+
     /// Build a database handle for the specified module.
     async fn build_for_module(&self, module: &str) -> Result<Option<Arc<DbHandle>>> {
         // Read module database configuration from Figment
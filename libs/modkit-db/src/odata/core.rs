@@ -953,7 +953,7 @@ fn build_cursor<E: EntityTrait>(
                 q.filter_hash.clone(),
                 direction,
             )
-            .and_then(|c| c.encode().map_err(|_| ODataError::InvalidCursor))
+            .and_then(|c| q.encode_cursor(&c))
         })
         .transpose()
 }
@@ -540,37 +540,19 @@ where
 
     // Build cursors
     let next_cursor = if is_backward || has_more {
-        build_cursor_from_rows::<E, F, M>(
-            &rows,
-            &effective_order,
-            query.filter_hash.as_deref(),
-            "fwd",
-            true,
-        )?
+        build_cursor_from_rows::<E, F, M>(&rows, &effective_order, query, "fwd", true)?
     } else {
         None
     };
 
     let prev_cursor = if is_backward {
         if has_more {
-            build_cursor_from_rows::<E, F, M>(
-                &rows,
-                &effective_order,
-                query.filter_hash.as_deref(),
-                "bwd",
-                false,
-            )?
+            build_cursor_from_rows::<E, F, M>(&rows, &effective_order, query, "bwd", false)?
         } else {
             None
         }
     } else if query.cursor.is_some() {
-        build_cursor_from_rows::<E, F, M>(
-            &rows,
-            &effective_order,
-            query.filter_hash.as_deref(),
-            "bwd",
-            false,
-        )?
+        build_cursor_from_rows::<E, F, M>(&rows, &effective_order, query, "bwd", false)?
     } else {
         None
     };
@@ -591,7 +573,7 @@ where
 fn build_cursor_from_rows<E, F, M: ODataFieldMapping<F, Entity = E>>(
     rows: &[<E as EntityTrait>::Model],
     effective_order: &ODataOrderBy,
-    filter_hash: Option<&str>,
+    query: &modkit_odata::ODataQuery,
     direction: &str,
     use_last: bool,
 ) -> Result<Option<String>, ODataError>
@@ -601,12 +583,11 @@ where
 {
     let row = if use_last { rows.last() } else { rows.first() };
 
-    row.map(|m| build_cursor_from_model::<F, M>(m, effective_order, filter_hash, direction))
-        .transpose()
-        .and_then(|opt| match opt {
-            Some(c) => c.encode().map(Some).map_err(|_| ODataError::InvalidCursor),
-            None => Ok(None),
-        })
+    row.map(|m| {
+        build_cursor_from_model::<F, M>(m, effective_order, query.filter_hash.as_deref(), direction)
+    })
+    .transpose()
+    .and_then(|opt| opt.map(|c| query.encode_cursor(&c)).transpose())
 }
 
 /// Build a cursor predicate for pagination
@@ -0,0 +1,31 @@
+//! `SeaORM` entity for pending outbox messages.
+
+use sea_orm::entity::prelude::*;
+
+/// A message waiting to be delivered by the relay.
+///
+/// Rows are deleted once delivered, or kept with `dead_at` set once the relay
+/// gives up on them. Timestamps are Unix epoch milliseconds so that due-time
+/// comparisons behave the same on every backend.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "modkit_outbox")]
+pub struct Model {
+    /// Enqueue order; messages of a topic are delivered in this order.
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub topic: String,
+    pub payload: Vec<u8>,
+    /// Failed delivery attempts so far.
+    pub attempts: i32,
+    pub next_attempt_at: i64,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    /// When the message was dead-lettered; `None` while it is pending.
+    pub dead_at: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
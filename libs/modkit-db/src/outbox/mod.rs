@@ -0,0 +1,194 @@
+//! Transactional outbox for reliable event publication.
+//!
+//! A module that changes data and publishes an event about it must not lose the
+//! event when the process dies between the commit and the publish. Instead of
+//! publishing directly, the event is written to the `modkit_outbox` table in the
+//! same transaction as the business change:
+//!
+//! ```ignore
+//! use modkit_db::outbox;
+//!
+//! db.in_transaction(move |tx| Box::pin(async move {
+//!     let user = repo.create(tx, user).await?;
+//!     outbox::enqueue_json(tx, "users_info.user_created", &UserCreated { id: user.id })
+//!         .await
+//!         .map_err(DomainError::database)?;
+//!     Ok(user)
+//! }))
+//! .await
+//! ```
+//!
+//! An [`OutboxRelay`] then delivers committed messages to an [`OutboxSink`]:
+//!
+//! - delivery is at-least-once: a message is removed only after the sink accepted it
+//! - messages of a topic are delivered in enqueue order; a failing message holds back
+//!   the later messages of its topic until it is delivered
+//! - failed deliveries are retried with exponential backoff; after
+//!   [`RelayConfig::max_attempts`] the message is dead-lettered (kept with `dead_at`
+//!   set) and stops holding back its topic
+//! - only one relay per database and relay name is active at a time (advisory lock)
+//! - relays sharing the table only read the topics of their sink ([`OutboxSink::topics`])
+//!
+//! The table is created by [`create_table`], which modules call from their migrations.
+
+mod entity;
+mod relay;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue, ConnectionTrait, DbBackend, DbErr, EntityTrait};
+use serde::Serialize;
+
+use crate::{DbError, Result};
+
+pub use relay::{OutboxRelay, RelayConfig};
+
+/// Name of the outbox table.
+pub const OUTBOX_TABLE: &str = "modkit_outbox";
+
+/// A committed message handed to an [`OutboxSink`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxMessage {
+    pub id: i64,
+    pub topic: String,
+    pub payload: Vec<u8>,
+    /// Failed delivery attempts before this one.
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<entity::Model> for OutboxMessage {
+    fn from(row: entity::Model) -> Self {
+        Self {
+            id: row.id,
+            topic: row.topic,
+            payload: row.payload,
+            attempts: u32::try_from(row.attempts).unwrap_or_default(),
+            created_at: DateTime::from_timestamp_millis(row.created_at).unwrap_or_default(),
+        }
+    }
+}
+
+/// Destination of relayed outbox messages, e.g. an event bus.
+#[async_trait::async_trait]
+pub trait OutboxSink: Send + Sync + 'static {
+    /// Deliver one message.
+    ///
+    /// # Errors
+    /// Returning an error leaves the message in the outbox to be retried later.
+    async fn deliver(&self, message: &OutboxMessage) -> anyhow::Result<()>;
+
+    /// Topics this sink delivers; its relay leaves the messages of other topics alone.
+    ///
+    /// `None` (the default) delivers every topic, which is only correct for the one
+    /// relay of a database.
+    fn topics(&self) -> Option<Vec<String>> {
+        None
+    }
+}
+
+/// Create the outbox table if it does not exist yet.
+///
+/// Call it from a module migration; it is safe to run from several modules sharing a database.
+///
+/// # Errors
+/// Returns an error if the DDL statement fails.
+pub async fn create_table<C: ConnectionTrait>(conn: &C) -> std::result::Result<(), DbErr> {
+    let sql = match conn.get_database_backend() {
+        DbBackend::Postgres => {
+            r"
+CREATE TABLE IF NOT EXISTS modkit_outbox (
+    id BIGSERIAL PRIMARY KEY,
+    topic TEXT NOT NULL,
+    payload BYTEA NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at BIGINT NOT NULL,
+    last_error TEXT NULL,
+    created_at BIGINT NOT NULL,
+    dead_at BIGINT NULL
+);
+            "
+        }
+        DbBackend::MySql => {
+            r"
+CREATE TABLE IF NOT EXISTS modkit_outbox (
+    id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    topic VARCHAR(512) NOT NULL,
+    payload LONGBLOB NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    next_attempt_at BIGINT NOT NULL,
+    last_error TEXT NULL,
+    created_at BIGINT NOT NULL,
+    dead_at BIGINT NULL,
+    INDEX modkit_outbox_topic_id (topic, id)
+);
+            "
+        }
+        DbBackend::Sqlite => {
+            r"
+CREATE TABLE IF NOT EXISTS modkit_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    topic TEXT NOT NULL,
+    payload BLOB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at BIGINT NOT NULL,
+    last_error TEXT NULL,
+    created_at BIGINT NOT NULL,
+    dead_at BIGINT NULL
+);
+            "
+        }
+    };
+
+    conn.execute_unprepared(sql).await?;
+    if conn.get_database_backend() != DbBackend::MySql {
+        // Lets the relay find the earlier messages of a topic
+        conn.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS modkit_outbox_topic_id ON modkit_outbox (topic, id);",
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Add a message to the outbox.
+///
+/// Pass the transaction of the business change (e.g. from `SecureConn::in_transaction`)
+/// so the message is committed or rolled back together with it.
+///
+/// # Errors
+/// Returns an error if the insert fails.
+pub async fn enqueue<C: ConnectionTrait>(
+    conn: &C,
+    topic: &str,
+    payload: impl Into<Vec<u8>>,
+) -> Result<i64> {
+    let now = Utc::now().timestamp_millis();
+    let row = entity::ActiveModel {
+        id: ActiveValue::NotSet,
+        topic: ActiveValue::Set(topic.to_owned()),
+        payload: ActiveValue::Set(payload.into()),
+        attempts: ActiveValue::Set(0),
+        next_attempt_at: ActiveValue::Set(now),
+        last_error: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(now),
+        dead_at: ActiveValue::Set(None),
+    };
+
+    let result = entity::Entity::insert(row).exec(conn).await?;
+    Ok(result.last_insert_id)
+}
+
+/// Add a JSON-encoded message to the outbox; see [`enqueue`].
+///
+/// # Errors
+/// Returns an error if the value cannot be encoded or the insert fails.
+pub async fn enqueue_json<C, T>(conn: &C, topic: &str, value: &T) -> Result<i64>
+where
+    C: ConnectionTrait,
+    T: Serialize + ?Sized,
+{
+    let payload = serde_json::to_vec(value).map_err(|e| {
+        DbError::Other(anyhow::Error::new(e).context("failed to encode outbox message"))
+    })?;
+    enqueue(conn, topic, payload).await
+}
@@ -0,0 +1,308 @@
+//! Background delivery of outbox messages.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveValue, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use tokio_util::sync::CancellationToken;
+
+use super::{OUTBOX_TABLE, OutboxMessage, OutboxSink, entity};
+use crate::{DbHandle, LockConfig, Result};
+
+/// Advisory lock namespace of relay leader election.
+const LOCK_NAMESPACE: &str = "outbox";
+
+/// Polling and retry settings of an [`OutboxRelay`].
+#[derive(Debug, Clone)]
+pub struct RelayConfig {
+    /// Pause between polls when the outbox is drained, and between leadership attempts.
+    pub poll_interval: Duration,
+    /// Maximum number of messages read per poll.
+    pub batch_size: u64,
+    /// Delay before the first retry of a failed message.
+    pub initial_backoff: Duration,
+    /// Upper bound of the retry delay.
+    pub max_backoff: Duration,
+    /// Factor applied to the retry delay after each failed attempt.
+    pub backoff_multiplier: f64,
+    /// Failed attempts after which a message is dead-lettered and stops holding back its topic.
+    pub max_attempts: u32,
+}
+
+impl Default for RelayConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            batch_size: 100,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_mins(5),
+            backoff_multiplier: 2.0,
+            max_attempts: 16,
+        }
+    }
+}
+
+impl RelayConfig {
+    /// Retry delay after the given number of failed attempts (at least 1).
+    fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = i32::try_from(attempts.saturating_sub(1)).unwrap_or(i32::MAX);
+        let factor = self.backoff_multiplier.powi(exponent);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+}
+
+/// Delivers committed outbox messages to an [`OutboxSink`].
+///
+/// Several processes may run a relay with the same name against one database;
+/// an advisory lock elects the one that delivers, the others stand by. Relays
+/// with different names share the outbox table and only read the topics of
+/// their sink (see [`OutboxSink::topics`]).
+pub struct OutboxRelay {
+    db: Arc<DbHandle>,
+    name: String,
+    sink: Arc<dyn OutboxSink>,
+    config: RelayConfig,
+}
+
+impl OutboxRelay {
+    /// A relay with the default [`RelayConfig`]. `name` identifies the relay for leader election.
+    pub fn new(db: Arc<DbHandle>, name: impl Into<String>, sink: Arc<dyn OutboxSink>) -> Self {
+        Self {
+            db,
+            name: name.into(),
+            sink,
+            config: RelayConfig::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_config(mut self, config: RelayConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Deliver the due messages of one batch, regardless of leadership.
+    ///
+    /// Only messages of the sink's topics are read, and only those that are due
+    /// and not behind a message of their topic that is still backing off, so a
+    /// failing topic does not take up the batch of the others.
+    ///
+    /// Returns the number of delivered messages. Delivery failures are recorded
+    /// on the message and scheduled for retry, or dead-lettered after
+    /// [`RelayConfig::max_attempts`]; they are not returned as errors.
+    ///
+    /// # Errors
+    /// Returns an error if the outbox cannot be read or updated.
+    pub async fn relay_once(&self) -> Result<usize> {
+        let secure = self.db.sea_secure();
+        let conn = secure.conn();
+        let scope = self.scope();
+        let now = Utc::now().timestamp_millis();
+        let rows = entity::Entity::find()
+            .filter(scope.clone())
+            .filter(entity::Column::DeadAt.is_null())
+            .filter(entity::Column::NextAttemptAt.lte(now))
+            .filter(Expr::cust(format!(
+                "NOT EXISTS (SELECT 1 FROM {OUTBOX_TABLE} AS earlier \
+                 WHERE earlier.topic = {OUTBOX_TABLE}.topic AND earlier.id < {OUTBOX_TABLE}.id \
+                 AND earlier.dead_at IS NULL AND earlier.next_attempt_at > {now})"
+            )))
+            .order_by_asc(entity::Column::Id)
+            .limit(self.config.batch_size)
+            .all(conn)
+            .await?;
+
+        // Topics whose message failed in this batch: later ones must wait to keep the order
+        let mut held_back = HashSet::new();
+        let mut delivered = 0;
+
+        for row in rows {
+            if held_back.contains(&row.topic) {
+                continue;
+            }
+
+            let message = OutboxMessage::from(row);
+            match self.sink.deliver(&message).await {
+                Ok(()) => {
+                    entity::Entity::delete_many()
+                        .filter(entity::Column::Id.eq(message.id))
+                        .filter(scope.clone())
+                        .exec(conn)
+                        .await?;
+                    delivered += 1;
+                }
+                Err(e) => {
+                    let update = self.record_failure(&message, &e);
+                    if update.dead_at.is_not_set() {
+                        held_back.insert(message.topic);
+                    }
+                    entity::Entity::update_many()
+                        .set(update)
+                        .filter(entity::Column::Id.eq(message.id))
+                        .filter(scope.clone())
+                        .exec(conn)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Rows this relay is responsible for.
+    fn scope(&self) -> Condition {
+        match self.sink.topics() {
+            Some(topics) => Condition::all().add(entity::Column::Topic.is_in(topics)),
+            None => Condition::all(),
+        }
+    }
+
+    /// Log a failed delivery and build the update that schedules its retry or dead-letters it.
+    fn record_failure(&self, message: &OutboxMessage, e: &anyhow::Error) -> entity::ActiveModel {
+        let attempts = message.attempts.saturating_add(1);
+        let error = format!("{e:#}");
+        let mut update = entity::ActiveModel {
+            attempts: ActiveValue::Set(i32::try_from(attempts).unwrap_or(i32::MAX)),
+            last_error: ActiveValue::Set(Some(error.clone())),
+            ..Default::default()
+        };
+
+        let now = Utc::now().timestamp_millis();
+        if attempts >= self.config.max_attempts {
+            tracing::error!(
+                relay = %self.name,
+                topic = %message.topic,
+                id = message.id,
+                attempts,
+                error = %error,
+                "Outbox delivery failed, message moved to dead letters"
+            );
+            update.dead_at = ActiveValue::Set(Some(now));
+            return update;
+        }
+
+        let retry_in = self.config.backoff(attempts);
+        tracing::warn!(
+            relay = %self.name,
+            topic = %message.topic,
+            id = message.id,
+            attempts,
+            ?retry_in,
+            error = %error,
+            "Outbox delivery failed"
+        );
+        update.next_attempt_at = ActiveValue::Set(
+            now.saturating_add(i64::try_from(retry_in.as_millis()).unwrap_or(i64::MAX)),
+        );
+        update
+    }
+
+    /// Relay messages until `cancel` fires.
+    ///
+    /// The loop keeps trying to become the leader and, while it is, polls the outbox.
+    /// Database errors are logged and retried on the next poll.
+    pub async fn run(&self, cancel: CancellationToken) {
+        let lock_config = LockConfig {
+            max_wait: None,
+            max_attempts: Some(1),
+            ..LockConfig::default()
+        };
+        let mut leadership = None;
+
+        loop {
+            if leadership.is_none() {
+                match self
+                    .db
+                    .try_lock(LOCK_NAMESPACE, &self.name, lock_config.clone())
+                    .await
+                {
+                    Ok(Some(guard)) => {
+                        tracing::info!(relay = %self.name, "Outbox relay became leader");
+                        leadership = Some(guard);
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!(
+                            relay = %self.name,
+                            error = %e,
+                            "Outbox leader election failed"
+                        );
+                    }
+                }
+            }
+
+            let mut drained = true;
+            if leadership.is_some() {
+                match self.relay_once().await {
+                    Ok(delivered) => {
+                        drained =
+                            u64::try_from(delivered).unwrap_or(u64::MAX) < self.config.batch_size;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            relay = %self.name,
+                            error = %e,
+                            "Outbox relay poll failed"
+                        );
+                    }
+                }
+            }
+
+            if cancel.is_cancelled() {
+                break;
+            }
+            if drained {
+                tokio::select! {
+                    () = cancel.cancelled() => break,
+                    () = tokio::time::sleep(self.config.poll_interval) => {}
+                }
+            }
+        }
+
+        if let Some(guard) = leadership {
+            guard.release().await;
+        }
+    }
+}
+
+impl std::fmt::Debug for OutboxRelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxRelay")
+            .field("name", &self.name)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let config = RelayConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            backoff_multiplier: 2.0,
+            ..RelayConfig::default()
+        };
+
+        assert_eq!(config.backoff(1), Duration::from_secs(1));
+        assert_eq!(config.backoff(2), Duration::from_secs(2));
+        assert_eq!(config.backoff(4), Duration::from_secs(8));
+        assert_eq!(config.backoff(5), Duration::from_secs(10));
+        assert_eq!(config.backoff(u32::MAX), Duration::from_secs(10));
+    }
+}
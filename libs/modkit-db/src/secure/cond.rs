@@ -11,6 +11,8 @@ use crate::secure::{AccessScope, ScopableEntity};
 ///    - If entity has no `tenant_col` but `tenant_ids` provided → deny all
 /// 3. **Resources only** → filter by `resource_col IN resource_ids`
 /// 4. **Both present** → AND them: `(tenant_col IN ...) AND (resource_col IN ...)`
+/// 5. **Types present** → additionally AND `type_col IN types`
+///    - If entity has no `type_col` but types provided → deny all
 ///
 /// # Provider Pattern
 ///
@@ -51,13 +53,21 @@ where
         }
     }
 
+    if scope.has_types() {
+        if let Some(type_col) = E::type_col() {
+            parts.push(Condition::all().add(Expr::col(type_col).is_in(scope.types().to_vec())));
+        } else {
+            // Entity has no type_col but scope requires type filtering → deny all
+            return deny_all();
+        }
+    }
+
     match parts.as_slice() {
         [only] => only.clone(),
-        [a, b] => Condition::all().add(a.clone()).add(b.clone()),
         // No filters = deny all (this case is handled by is_empty check above,
         // but included for completeness)
         [] => deny_all(),
-        _ => unreachable!(),
+        _ => parts.into_iter().fold(Condition::all(), Condition::add),
     }
 }
 
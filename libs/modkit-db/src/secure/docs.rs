@@ -101,6 +101,8 @@
 //! | Tenants only + entity has no `tenant_col` | `WHERE 1=0` (deny all) |
 //! | Resources only | `WHERE resource_col IN (...)` |
 //! | Both tenants and resources | `WHERE tenant_col IN (...) AND resource_col IN (...)` |
+//! | Types (with tenants and/or resources) | additionally `AND type_col IN (...)` |
+//! | Types + entity has no `type_col` | `WHERE 1=0` (deny all) |
 //!
 //! ## Usage Examples
 //!
@@ -99,6 +99,7 @@
 //! | Tenants only | Filter by tenant column |
 //! | Resources only | Filter by ID column |
 //! | Both | AND them together |
+//! | Types | Additionally filter by type column |
 //!
 //! See the [docs module](docs) for comprehensive examples and usage patterns.
 
@@ -139,3 +139,56 @@ async fn test_dbmanager_missing_server_reference() {
             .contains("Referenced server 'nonexistent_server' not found")
     );
 }
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_dbmanager_exports_pool_metrics() {
+    let figment = Figment::new().merge(Serialized::defaults(serde_json::json!({
+        "modules": {
+            "pool_metrics_module": {
+                "database": {
+                    "file": "pool_metrics.db",
+                    "pool": { "max_conns": 3 }
+                }
+            }
+        }
+    })));
+
+    let temp_dir = TempDir::new().unwrap();
+    let manager = DbManager::from_figment(figment, temp_dir.path().to_path_buf()).unwrap();
+    let handle = manager.get("pool_metrics_module").await.unwrap().unwrap();
+    assert_eq!(handle.pool_stats().max, 3);
+
+    let text = modkit_metrics::MetricsRegistry::global().encode();
+    assert!(
+        text.contains(r#"db_pool_max_connections{pool="pool_metrics_module"} 3"#),
+        "{text}"
+    );
+    assert!(
+        text.contains(r#"db_pool_connections{pool="pool_metrics_module",state="in_use"} 0"#),
+        "{text}"
+    );
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn test_dbmanager_lists_handles_and_pings() {
+    let figment = Figment::new().merge(Serialized::defaults(serde_json::json!({
+        "modules": {
+            "ping_module": {
+                "database": { "file": "ping.db" }
+            }
+        }
+    })));
+
+    let temp_dir = TempDir::new().unwrap();
+    let manager = DbManager::from_figment(figment, temp_dir.path().to_path_buf()).unwrap();
+    assert!(manager.handles().is_empty());
+
+    let handle = manager.get("ping_module").await.unwrap().unwrap();
+    handle.ping().await.unwrap();
+
+    let handles = manager.handles();
+    assert_eq!(handles.len(), 1);
+    assert_eq!(handles[0].0, "ping_module");
+}
@@ -99,6 +99,7 @@ async fn apply_odata_filter_filters_results() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let results: Vec<i64> = ent::Entity::find()
@@ -259,6 +260,7 @@ async fn apply_odata_query_combines_filter_cursor_order() {
         cursor: Some(cursor),
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let results: Vec<String> = ent::Entity::find()
@@ -295,6 +297,7 @@ async fn apply_odata_query_with_tiebreaker() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let results: Vec<i64> = ent::Entity::find()
@@ -137,6 +137,7 @@ async fn pager_applies_security_scope() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -186,6 +187,7 @@ async fn pager_respects_custom_tiebreaker() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -235,6 +237,7 @@ async fn pager_respects_custom_limits() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -289,6 +292,7 @@ async fn pager_applies_odata_filter() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -338,6 +342,7 @@ async fn pager_applies_odata_order() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -388,6 +393,7 @@ async fn pager_supports_cursor_pagination() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -419,6 +425,7 @@ async fn pager_supports_cursor_pagination() {
         cursor: Some(next_cursor),
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page2 = OPager::<ent::Entity, _>::new(&secure, &scope, &conn, &fmap)
@@ -458,6 +465,7 @@ async fn pager_empty_scope_returns_empty_results() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -514,6 +522,7 @@ async fn pager_combines_security_and_filter() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let secure = db.sea_secure();
@@ -96,6 +96,7 @@ async fn paginate_with_odata_forward_first_page() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page = paginate_with_odata(
@@ -148,6 +149,7 @@ async fn paginate_with_odata_forward_second_page_has_both_cursors() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page1 = paginate_with_odata(
@@ -175,6 +177,7 @@ async fn paginate_with_odata_forward_second_page_has_both_cursors() {
         cursor: Some(cursor),
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page2 = paginate_with_odata(
@@ -227,6 +230,7 @@ async fn paginate_with_odata_backward_from_end() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page1 = paginate_with_odata(
@@ -254,6 +258,7 @@ async fn paginate_with_odata_backward_from_end() {
         cursor: Some(cursor),
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page2 = paginate_with_odata(
@@ -281,6 +286,7 @@ async fn paginate_with_odata_backward_from_end() {
         cursor: Some(cursor_bwd),
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page3 = paginate_with_odata(
@@ -326,6 +332,7 @@ async fn paginate_with_odata_respects_limit_clamp() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page = paginate_with_odata(
@@ -382,6 +389,7 @@ async fn paginate_with_odata_applies_filter() {
         cursor: None,
         filter_hash: None,
         select: None,
+        limits: None,
     };
 
     let page = paginate_with_odata(
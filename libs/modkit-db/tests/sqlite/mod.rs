@@ -8,6 +8,7 @@
 mod concurrency_tests;
 mod manager;
 mod options;
+mod outbox;
 mod pooling_tests;
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sqlite_tests;
@@ -0,0 +1,285 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use modkit_db::outbox::{self, OutboxMessage, OutboxRelay, OutboxSink, RelayConfig};
+use modkit_db::{ConnectOpts, DbHandle};
+use tokio_util::sync::CancellationToken;
+
+/// Records delivered messages; fails the first `failures` deliveries.
+#[derive(Default)]
+struct RecordingSink {
+    delivered: Mutex<Vec<(String, Vec<u8>)>>,
+    failures: Mutex<usize>,
+    topics: Option<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl OutboxSink for RecordingSink {
+    async fn deliver(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                anyhow::bail!("sink unavailable");
+            }
+        }
+        self.delivered
+            .lock()
+            .unwrap()
+            .push((message.topic.clone(), message.payload.clone()));
+        Ok(())
+    }
+
+    fn topics(&self) -> Option<Vec<String>> {
+        self.topics.clone()
+    }
+}
+
+impl RecordingSink {
+    fn failing(failures: usize) -> Arc<Self> {
+        let sink = Self::default();
+        *sink.failures.lock().unwrap() = failures;
+        Arc::new(sink)
+    }
+
+    fn for_topics(topics: &[&str]) -> Arc<Self> {
+        Arc::new(Self {
+            topics: Some(topics.iter().map(|&topic| topic.to_owned()).collect()),
+            ..Self::default()
+        })
+    }
+
+    fn payloads(&self) -> Vec<String> {
+        self.delivered
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(topic, payload)| format!("{topic}:{}", String::from_utf8_lossy(payload)))
+            .collect()
+    }
+}
+
+async fn setup(name: &str) -> Arc<DbHandle> {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let dsn = format!("sqlite:file:{name}_memdb?mode=memory&cache=shared");
+    let db = DbHandle::connect(&dsn, opts).await.unwrap();
+    outbox::create_table(db.sea_secure().conn()).await.unwrap();
+    // Idempotent, as modules sharing a database may all call it
+    outbox::create_table(db.sea_secure().conn()).await.unwrap();
+    Arc::new(db)
+}
+
+async fn enqueue_all(db: &DbHandle, messages: &[(&str, &str)]) {
+    for (topic, payload) in messages {
+        outbox::enqueue(db.sea_secure().conn(), topic, payload.as_bytes())
+            .await
+            .unwrap();
+    }
+}
+
+fn no_backoff() -> RelayConfig {
+    RelayConfig {
+        initial_backoff: Duration::ZERO,
+        ..RelayConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn outbox_messages_follow_the_transaction_outcome() {
+    let db = setup("outbox_tx").await;
+    let conn = db.sea_secure();
+
+    conn.in_transaction(|tx| {
+        Box::pin(async move {
+            outbox::enqueue_json(tx, "users.created", &serde_json::json!({ "id": 1 })).await
+        })
+    })
+    .await
+    .unwrap();
+
+    let rolled_back = conn
+        .in_transaction(|tx| {
+            Box::pin(async move {
+                outbox::enqueue_json(tx, "users.created", &serde_json::json!({ "id": 2 })).await?;
+                Err::<(), _>(modkit_db::DbError::InvalidParameter(
+                    "domain failure".to_owned(),
+                ))
+            })
+        })
+        .await;
+    assert!(rolled_back.is_err());
+
+    let sink = Arc::new(RecordingSink::default());
+    let relay = OutboxRelay::new(Arc::clone(&db), "outbox_tx", sink.clone());
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(sink.payloads(), vec![r#"users.created:{"id":1}"#]);
+
+    // Delivered messages are removed
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn failed_delivery_is_retried_and_holds_back_its_topic() {
+    let db = setup("outbox_retry").await;
+    enqueue_all(&db, &[("a", "1"), ("b", "1"), ("a", "2")]).await;
+
+    let sink = RecordingSink::failing(1);
+    let relay =
+        OutboxRelay::new(Arc::clone(&db), "outbox_retry", sink.clone()).with_config(RelayConfig {
+            initial_backoff: Duration::from_mins(1),
+            ..RelayConfig::default()
+        });
+
+    // a:1 fails, so a:2 must wait; b is unaffected
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(sink.payloads(), vec!["b:1"]);
+
+    // Still backing off
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+
+    let pending = sea_orm::ConnectionTrait::execute_unprepared(
+        db.sea_secure().conn(),
+        "UPDATE modkit_outbox SET next_attempt_at = 0",
+    )
+    .await
+    .unwrap();
+    assert_eq!(pending.rows_affected(), 2);
+
+    assert_eq!(relay.relay_once().await.unwrap(), 2);
+    assert_eq!(sink.payloads(), vec!["b:1", "a:1", "a:2"]);
+}
+
+#[tokio::test]
+async fn backing_off_topic_does_not_take_up_the_batch() {
+    let db = setup("outbox_starvation").await;
+    enqueue_all(&db, &[("a", "1"), ("a", "2"), ("a", "3"), ("b", "1")]).await;
+
+    let sink = RecordingSink::failing(1);
+    let relay = OutboxRelay::new(Arc::clone(&db), "outbox_starvation", sink.clone()).with_config(
+        RelayConfig {
+            batch_size: 2,
+            initial_backoff: Duration::from_mins(1),
+            ..RelayConfig::default()
+        },
+    );
+
+    // a:1 fails and a:2 waits behind it
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+    // a:2 and a:3 are no longer read, so b:1 fits in the batch
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(sink.payloads(), vec!["b:1"]);
+}
+
+#[tokio::test]
+async fn message_is_dead_lettered_after_max_attempts() {
+    let db = setup("outbox_dead").await;
+    enqueue_all(&db, &[("a", "1"), ("a", "2")]).await;
+
+    let sink = RecordingSink::failing(2);
+    let relay =
+        OutboxRelay::new(Arc::clone(&db), "outbox_dead", sink.clone()).with_config(RelayConfig {
+            max_attempts: 2,
+            ..no_backoff()
+        });
+
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+    // The second failure dead-letters a:1, so a:2 is delivered right away
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(sink.payloads(), vec!["a:2"]);
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+
+    let conn = db.sea_secure();
+    let dead = sea_orm::ConnectionTrait::query_one(
+        conn.conn(),
+        sea_orm::Statement::from_string(
+            sea_orm::DbBackend::Sqlite,
+            "SELECT attempts FROM modkit_outbox WHERE dead_at IS NOT NULL",
+        ),
+    )
+    .await
+    .unwrap()
+    .expect("a:1 should be kept as a dead letter");
+    assert_eq!(dead.try_get::<i32>("", "attempts").unwrap(), 2);
+}
+
+#[tokio::test]
+async fn relays_only_read_their_topics() {
+    let db = setup("outbox_topics").await;
+    enqueue_all(&db, &[("a", "1"), ("b", "1")]).await;
+
+    let a_sink = RecordingSink::for_topics(&["a"]);
+    let b_sink = RecordingSink::for_topics(&["b"]);
+    let a_relay = OutboxRelay::new(Arc::clone(&db), "outbox_topics_a", a_sink.clone());
+    let b_relay = OutboxRelay::new(Arc::clone(&db), "outbox_topics_b", b_sink.clone());
+
+    assert_eq!(a_relay.relay_once().await.unwrap(), 1);
+    assert_eq!(a_sink.payloads(), vec!["a:1"]);
+    assert_eq!(b_relay.relay_once().await.unwrap(), 1);
+    assert_eq!(b_sink.payloads(), vec!["b:1"]);
+}
+
+#[tokio::test]
+async fn only_the_leader_relays() {
+    let db = setup("outbox_leader").await;
+    enqueue_all(&db, &[("a", "1"), ("a", "2")]).await;
+
+    let config = RelayConfig {
+        poll_interval: Duration::from_millis(20),
+        ..no_backoff()
+    };
+    let leader_sink = Arc::new(RecordingSink::default());
+    let standby_sink = Arc::new(RecordingSink::default());
+    let leader = OutboxRelay::new(Arc::clone(&db), "outbox_leader", leader_sink.clone())
+        .with_config(config.clone());
+    let standby = OutboxRelay::new(Arc::clone(&db), "outbox_leader", standby_sink.clone())
+        .with_config(config);
+
+    let cancel = CancellationToken::new();
+    let leader_task = {
+        let cancel = cancel.clone();
+        tokio::spawn(async move { leader.run(cancel).await })
+    };
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while leader_sink.payloads().len() < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("leader should deliver the outbox");
+
+    let standby_cancel = CancellationToken::new();
+    let standby_task = {
+        let cancel = standby_cancel.clone();
+        tokio::spawn(async move { standby.run(cancel).await })
+    };
+    enqueue_all(&db, &[("a", "3")]).await;
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while leader_sink.payloads().len() < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("leader should keep delivering");
+    assert!(standby_sink.payloads().is_empty());
+
+    // Once the leader stops, the standby takes over
+    cancel.cancel();
+    leader_task.await.unwrap();
+    enqueue_all(&db, &[("a", "4")]).await;
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while standby_sink.payloads().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("standby should take over");
+    assert_eq!(standby_sink.payloads(), vec!["a:4"]);
+
+    standby_cancel.cancel();
+    standby_task.await.unwrap();
+}
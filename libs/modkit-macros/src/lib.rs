@@ -31,6 +31,8 @@ enum Capability {
     System,
     GrpcHub,
     Grpc,
+    Events,
+    Health,
 }
 
 impl Capability {
@@ -42,6 +44,8 @@ impl Capability {
         "system",
         "grpc_hub",
         "grpc",
+        "events",
+        "health",
     ];
 
     fn suggest_similar(input: &str) -> Vec<&'static str> {
@@ -69,11 +73,13 @@ impl Capability {
             "system" => Ok(Capability::System),
             "grpc_hub" => Ok(Capability::GrpcHub),
             "grpc" => Ok(Capability::Grpc),
+            "events" => Ok(Capability::Events),
+            "health" => Ok(Capability::Health),
             other => {
                 let suggestions = Self::suggest_similar(other);
                 let error_msg = if suggestions.is_empty() {
                     format!(
-                        "unknown capability '{other}', expected one of: db, rest, rest_host, stateful, system, grpc_hub, grpc"
+                        "unknown capability '{other}', expected one of: db, rest, rest_host, stateful, system, grpc_hub, grpc, events, health"
                     )
                 } else {
                     format!(
@@ -96,11 +102,13 @@ impl Capability {
             "system" => Ok(Capability::System),
             "grpc_hub" => Ok(Capability::GrpcHub),
             "grpc" => Ok(Capability::Grpc),
+            "events" => Ok(Capability::Events),
+            "health" => Ok(Capability::Health),
             other => {
                 let suggestions = Self::suggest_similar(other);
                 let error_msg = if suggestions.is_empty() {
                     format!(
-                        "unknown capability '{other}', expected one of: db, rest, rest_host, stateful, system, grpc_hub, grpc"
+                        "unknown capability '{other}', expected one of: db, rest, rest_host, stateful, system, grpc_hub, grpc, events, health"
                     )
                 } else {
                     format!(
@@ -521,6 +529,24 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                     {}
                 };
             },
+            Capability::Events => quote! {
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn __modkit_require_EventsCapability_impl()
+                    where
+                        #struct_ident #ty_generics: ::modkit::contracts::EventsCapability,
+                    {}
+                };
+            },
+            Capability::Health => quote! {
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn __modkit_require_HealthCapability_impl()
+                    where
+                        #struct_ident #ty_generics: ::modkit::contracts::HealthCapability,
+                    {}
+                };
+            },
         };
         cap_asserts.push(q);
     }
@@ -670,6 +696,14 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                 b.register_grpc_service_with_meta(#name_lit,
                     module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::GrpcServiceCapability>);
             },
+            Capability::Events => quote! {
+                b.register_events_with_meta(#name_lit,
+                    module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::EventsCapability>);
+            },
+            Capability::Health => quote! {
+                b.register_health_with_meta(#name_lit,
+                    module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::HealthCapability>);
+            },
         }
     });
 
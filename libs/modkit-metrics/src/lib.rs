@@ -0,0 +1,565 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+//! Process-wide metrics for `ModKit` applications.
+//!
+//! [`MetricsRegistry`] holds counter, gauge and histogram families keyed by label sets and
+//! renders them in the `OpenMetrics` text format that the API gateway serves on `/metrics`.
+//! The framework records HTTP, gRPC, database pool and lifecycle metrics into
+//! [`MetricsRegistry::global`]; modules add their own through the same registry:
+//!
+//! ```
+//! use modkit_metrics::MetricsRegistry;
+//!
+//! let metrics = MetricsRegistry::new();
+//! let sent = metrics.counter("mail_sent", "Mails handed to the relay");
+//! sent.inc(&[("transport", "smtp")]);
+//!
+//! assert!(metrics.encode().contains(r#"mail_sent_total{transport="smtp"} 1"#));
+//! ```
+//!
+//! Asking for a name twice returns the same family, so call sites can look their
+//! metrics up lazily instead of threading handles around.
+//!
+//! Gauges mirroring external state (pool sizes, statuses) are refreshed by collectors
+//! registered with [`MetricsRegistry::on_collect`], which run before every scrape.
+//! With the `otel` feature, [`MetricsRegistry::export_to`] also forwards every
+//! measurement to an OpenTelemetry meter for OTLP export.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::Mutex;
+use prometheus_client::metrics::counter::Counter as CounterMetric;
+use prometheus_client::metrics::family::{Family, MetricConstructor};
+use prometheus_client::metrics::gauge::Gauge as GaugeMetric;
+use prometheus_client::metrics::histogram::Histogram as HistogramMetric;
+use prometheus_client::registry::Registry;
+
+/// Content type of the [`MetricsRegistry::encode`] output.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Default histogram buckets for durations, in seconds.
+pub const DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Label pairs identifying one series, e.g. `&[("method", "GET"), ("status", "200")]`.
+pub type Labels<'a> = &'a [(&'a str, &'a str)];
+
+type LabelSet = Vec<(String, String)>;
+
+fn label_set(labels: Labels<'_>) -> LabelSet {
+    labels
+        .iter()
+        .map(|(k, v)| ((*k).to_owned(), escape(v)))
+        .collect()
+}
+
+/// Escape a label value for the text exposition format.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Monotonic counter family; exposed with a `_total` suffix.
+#[derive(Clone)]
+pub struct Counter {
+    name: Arc<str>,
+    help: Arc<str>,
+    family: Family<LabelSet, CounterMetric>,
+    #[cfg(feature = "otel")]
+    otel: Arc<std::sync::OnceLock<opentelemetry::metrics::Counter<u64>>>,
+}
+
+impl Counter {
+    fn new(name: &str, help: &str) -> Self {
+        Self {
+            name: name.into(),
+            help: help.into(),
+            family: Family::default(),
+            #[cfg(feature = "otel")]
+            otel: Arc::default(),
+        }
+    }
+
+    /// Increment the series by one.
+    pub fn inc(&self, labels: Labels<'_>) {
+        self.inc_by(labels, 1);
+    }
+
+    /// Increment the series by `value`.
+    pub fn inc_by(&self, labels: Labels<'_>, value: u64) {
+        self.family.get_or_create(&label_set(labels)).inc_by(value);
+        #[cfg(feature = "otel")]
+        if let Some(counter) = self.otel.get() {
+            counter.add(value, &otel::attributes(labels));
+        }
+    }
+
+    /// Current value of the series; zero if it was never incremented.
+    #[must_use]
+    pub fn get(&self, labels: Labels<'_>) -> u64 {
+        self.family
+            .get(&label_set(labels))
+            .map_or(0, |counter| counter.get())
+    }
+}
+
+impl fmt::Debug for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Counter")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Gauge family holding integer values that go up and down.
+#[derive(Clone)]
+pub struct Gauge {
+    name: Arc<str>,
+    help: Arc<str>,
+    family: Family<LabelSet, GaugeMetric>,
+    #[cfg(feature = "otel")]
+    otel: Arc<std::sync::OnceLock<opentelemetry::metrics::Gauge<i64>>>,
+}
+
+impl Gauge {
+    fn new(name: &str, help: &str) -> Self {
+        Self {
+            name: name.into(),
+            help: help.into(),
+            family: Family::default(),
+            #[cfg(feature = "otel")]
+            otel: Arc::default(),
+        }
+    }
+
+    /// Set the series to `value`.
+    pub fn set(&self, labels: Labels<'_>, value: i64) {
+        self.family.get_or_create(&label_set(labels)).set(value);
+        self.mirror(labels, value);
+    }
+
+    /// Increment the series by one.
+    pub fn inc(&self, labels: Labels<'_>) {
+        let previous = self.family.get_or_create(&label_set(labels)).inc();
+        self.mirror(labels, previous.saturating_add(1));
+    }
+
+    /// Decrement the series by one.
+    pub fn dec(&self, labels: Labels<'_>) {
+        let previous = self.family.get_or_create(&label_set(labels)).dec();
+        self.mirror(labels, previous.saturating_sub(1));
+    }
+
+    /// Current value of the series; zero if it was never set.
+    #[must_use]
+    pub fn get(&self, labels: Labels<'_>) -> i64 {
+        self.family
+            .get(&label_set(labels))
+            .map_or(0, |gauge| gauge.get())
+    }
+
+    /// Drop the series so it is no longer exposed.
+    pub fn remove(&self, labels: Labels<'_>) {
+        self.family.remove(&label_set(labels));
+    }
+
+    #[cfg(feature = "otel")]
+    fn mirror(&self, labels: Labels<'_>, value: i64) {
+        if let Some(gauge) = self.otel.get() {
+            gauge.record(value, &otel::attributes(labels));
+        }
+    }
+
+    #[cfg(not(feature = "otel"))]
+    #[allow(clippy::unused_self)]
+    fn mirror(&self, _labels: Labels<'_>, _value: i64) {}
+}
+
+impl fmt::Debug for Gauge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gauge")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds the histograms of a family with the buckets it was declared with.
+#[derive(Debug, Clone)]
+struct Buckets(Arc<[f64]>);
+
+impl MetricConstructor<HistogramMetric> for Buckets {
+    fn new_metric(&self) -> HistogramMetric {
+        HistogramMetric::new(self.0.iter().copied())
+    }
+}
+
+/// Histogram family with fixed buckets.
+#[derive(Clone)]
+pub struct Histogram {
+    name: Arc<str>,
+    help: Arc<str>,
+    #[cfg(feature = "otel")]
+    buckets: Arc<[f64]>,
+    family: Family<LabelSet, HistogramMetric, Buckets>,
+    #[cfg(feature = "otel")]
+    otel: Arc<std::sync::OnceLock<opentelemetry::metrics::Histogram<f64>>>,
+}
+
+impl Histogram {
+    fn new(name: &str, help: &str, buckets: &[f64]) -> Self {
+        let buckets: Arc<[f64]> = buckets.into();
+        Self {
+            name: name.into(),
+            help: help.into(),
+            #[cfg(feature = "otel")]
+            buckets: buckets.clone(),
+            family: Family::new_with_constructor(Buckets(buckets)),
+            #[cfg(feature = "otel")]
+            otel: Arc::default(),
+        }
+    }
+
+    /// Record one observation.
+    pub fn observe(&self, labels: Labels<'_>, value: f64) {
+        self.family.get_or_create(&label_set(labels)).observe(value);
+        #[cfg(feature = "otel")]
+        if let Some(histogram) = self.otel.get() {
+            histogram.record(value, &otel::attributes(labels));
+        }
+    }
+}
+
+impl fmt::Debug for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Histogram")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone)]
+enum Instrument {
+    Counter(Counter),
+    Gauge(Gauge),
+    Histogram(Histogram),
+}
+
+impl Instrument {
+    fn kind(&self) -> &'static str {
+        match self {
+            Instrument::Counter(_) => "counter",
+            Instrument::Gauge(_) => "gauge",
+            Instrument::Histogram(_) => "histogram",
+        }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        match self {
+            Instrument::Counter(c) => registry.register(&*c.name, &*c.help, c.family.clone()),
+            Instrument::Gauge(g) => registry.register(&*g.name, &*g.help, g.family.clone()),
+            Instrument::Histogram(h) => registry.register(&*h.name, &*h.help, h.family.clone()),
+        }
+    }
+}
+
+type Collector = Box<dyn Fn() -> bool + Send + Sync>;
+
+#[derive(Default)]
+struct State {
+    registry: Registry,
+    instruments: HashMap<String, Instrument>,
+    #[cfg(feature = "otel")]
+    exporter: Option<otel::Exporter>,
+}
+
+#[derive(Default)]
+struct Inner {
+    state: Mutex<State>,
+    collectors: Mutex<Vec<Collector>>,
+}
+
+/// Registry of metric families with Prometheus/OpenMetrics text exposition.
+///
+/// Cheap to clone; clones share the same families.
+#[derive(Clone, Default)]
+pub struct MetricsRegistry {
+    inner: Arc<Inner>,
+}
+
+static GLOBAL: LazyLock<MetricsRegistry> = LazyLock::new(MetricsRegistry::new);
+
+impl MetricsRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide registry used by the framework and served on `/metrics`.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// Get or register a counter family. A trailing `_total` in `name` is optional.
+    #[must_use]
+    pub fn counter(&self, name: &str, help: &str) -> Counter {
+        let name = name.strip_suffix("_total").unwrap_or(name);
+        match self.instrument(name, || Instrument::Counter(Counter::new(name, help))) {
+            Some(Instrument::Counter(counter)) => counter,
+            _ => Counter::new(name, help),
+        }
+    }
+
+    /// Get or register a gauge family.
+    #[must_use]
+    pub fn gauge(&self, name: &str, help: &str) -> Gauge {
+        match self.instrument(name, || Instrument::Gauge(Gauge::new(name, help))) {
+            Some(Instrument::Gauge(gauge)) => gauge,
+            _ => Gauge::new(name, help),
+        }
+    }
+
+    /// Get or register a histogram family; `buckets` only apply on first registration.
+    #[must_use]
+    pub fn histogram(&self, name: &str, help: &str, buckets: &[f64]) -> Histogram {
+        match self.instrument(name, || {
+            Instrument::Histogram(Histogram::new(name, help, buckets))
+        }) {
+            Some(Instrument::Histogram(histogram)) => histogram,
+            _ => Histogram::new(name, help, buckets),
+        }
+    }
+
+    /// Look up `name`, registering the instrument built by `make` if it is new.
+    ///
+    /// Returns `None` when the name is taken by another kind of instrument; the caller
+    /// then hands out a detached instrument so recording still works but is never exposed.
+    fn instrument(&self, name: &str, make: impl FnOnce() -> Instrument) -> Option<Instrument> {
+        let mut state = self.inner.state.lock();
+        if let Some(existing) = state.instruments.get(name) {
+            let wanted = make();
+            if existing.kind() == wanted.kind() {
+                return Some(existing.clone());
+            }
+            tracing::error!(
+                metric = name,
+                registered = existing.kind(),
+                requested = wanted.kind(),
+                "Metric name already registered with another type; measurements are dropped"
+            );
+            return None;
+        }
+
+        let instrument = make();
+        instrument.register(&mut state.registry);
+        #[cfg(feature = "otel")]
+        if let Some(exporter) = &state.exporter {
+            exporter.bind(&instrument);
+        }
+        state
+            .instruments
+            .insert(name.to_owned(), instrument.clone());
+        Some(instrument)
+    }
+
+    /// Register a collector run before every scrape, typically to refresh gauges that
+    /// mirror external state. The collector is dropped once it returns `false`.
+    ///
+    /// Collectors must not register other collectors.
+    pub fn on_collect(&self, collector: impl Fn() -> bool + Send + Sync + 'static) {
+        self.inner.collectors.lock().push(Box::new(collector));
+    }
+
+    /// Run the registered collectors.
+    pub fn collect(&self) {
+        self.inner.collectors.lock().retain(|collector| collector());
+    }
+
+    /// Run the collectors and render all families in the `OpenMetrics` text format.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        self.collect();
+        let mut out = String::new();
+        let state = self.inner.state.lock();
+        // Writing into a String cannot fail
+        let _ = prometheus_client::encoding::text::encode(&mut out, &state.registry);
+        out
+    }
+
+    /// Forward every measurement, past and future families alike, to `meter`.
+    ///
+    /// Collectors also run at each collection of the meter's provider, so OTLP export
+    /// sees refreshed gauges without anyone scraping `/metrics`.
+    #[cfg(feature = "otel")]
+    pub fn export_to(&self, meter: opentelemetry::metrics::Meter) {
+        let weak: std::sync::Weak<Inner> = Arc::downgrade(&self.inner);
+        let exporter = otel::Exporter::new(meter, move || {
+            if let Some(inner) = weak.upgrade() {
+                Self { inner }.collect();
+            }
+        });
+
+        let mut state = self.inner.state.lock();
+        for instrument in state.instruments.values() {
+            exporter.bind(instrument);
+        }
+        state.exporter = Some(exporter);
+    }
+}
+
+impl fmt::Debug for MetricsRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MetricsRegistry")
+            .field("families", &self.inner.state.lock().instruments.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use opentelemetry::KeyValue;
+    use opentelemetry::metrics::{Meter, ObservableGauge};
+
+    use super::{Instrument, Labels};
+
+    pub fn attributes(labels: Labels<'_>) -> Vec<KeyValue> {
+        labels
+            .iter()
+            .map(|(k, v)| KeyValue::new((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    }
+
+    /// Meter the registry forwards to, plus the hook running collectors on collection.
+    pub struct Exporter {
+        meter: Meter,
+        _collect: ObservableGauge<u64>,
+    }
+
+    impl Exporter {
+        pub fn new(meter: Meter, collect: impl Fn() + Send + Sync + 'static) -> Self {
+            // Observable callbacks run before synchronous instruments are read;
+            // this one observes nothing and only refreshes the collected gauges.
+            let hook = meter
+                .u64_observable_gauge("modkit.metrics.collect")
+                .with_callback(move |_| collect())
+                .build();
+            Self {
+                meter,
+                _collect: hook,
+            }
+        }
+
+        pub fn bind(&self, instrument: &Instrument) {
+            match instrument {
+                Instrument::Counter(c) => {
+                    c.otel.get_or_init(|| {
+                        self.meter
+                            .u64_counter(c.name.to_string())
+                            .with_description(c.help.to_string())
+                            .build()
+                    });
+                }
+                Instrument::Gauge(g) => {
+                    g.otel.get_or_init(|| {
+                        self.meter
+                            .i64_gauge(g.name.to_string())
+                            .with_description(g.help.to_string())
+                            .build()
+                    });
+                }
+                Instrument::Histogram(h) => {
+                    h.otel.get_or_init(|| {
+                        self.meter
+                            .f64_histogram(h.name.to_string())
+                            .with_description(h.help.to_string())
+                            .with_boundaries(h.buckets.to_vec())
+                            .build()
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn encodes_families_in_openmetrics_format() {
+        let metrics = MetricsRegistry::new();
+        metrics
+            .counter("jobs_runs_total", "Job runs")
+            .inc_by(&[("job", "purge")], 3);
+        metrics.gauge("queue_depth", "Queued items").set(&[], 7);
+        metrics
+            .histogram("latency_seconds", "Latency", &[0.1, 1.0])
+            .observe(&[("op", "get")], 0.5);
+
+        let text = metrics.encode();
+        assert!(text.contains("# TYPE jobs_runs counter"), "{text}");
+        assert!(text.contains(r#"jobs_runs_total{job="purge"} 3"#), "{text}");
+        assert!(text.contains("queue_depth{} 7"), "{text}");
+        assert!(
+            text.contains(r#"latency_seconds_bucket{le="1.0",op="get"} 1"#),
+            "{text}"
+        );
+        assert!(
+            text.contains(r#"latency_seconds_bucket{le="0.1",op="get"} 0"#),
+            "{text}"
+        );
+        assert!(text.trim_end().ends_with("# EOF"), "{text}");
+    }
+
+    #[test]
+    fn same_name_returns_same_family_and_escapes_labels() {
+        let metrics = MetricsRegistry::new();
+        metrics.counter("hits", "Hits").inc(&[("path", "a\"b\\c")]);
+        metrics
+            .counter("hits_total", "Hits")
+            .inc(&[("path", "a\"b\\c")]);
+
+        assert_eq!(
+            metrics.counter("hits", "Hits").get(&[("path", "a\"b\\c")]),
+            2
+        );
+        assert!(metrics.encode().contains(r#"hits_total{path="a\"b\\c"} 2"#));
+
+        // Another kind under a taken name is detached, not exposed twice
+        metrics.gauge("hits", "Hits").set(&[], 5);
+        assert_eq!(metrics.encode().matches("# TYPE hits").count(), 1);
+    }
+
+    #[test]
+    fn collectors_run_on_encode_until_they_decline() {
+        let metrics = MetricsRegistry::new();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let gauge = metrics.gauge("pool_size", "Pool size");
+        metrics.on_collect({
+            let runs = runs.clone();
+            move || {
+                let n = runs.fetch_add(1, Ordering::SeqCst) + 1;
+                gauge.set(&[], i64::try_from(n).unwrap());
+                n < 2
+            }
+        });
+
+        assert!(metrics.encode().contains("pool_size{} 1"));
+        assert!(metrics.encode().contains("pool_size{} 2"));
+        assert!(metrics.encode().contains("pool_size{} 2"));
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+}
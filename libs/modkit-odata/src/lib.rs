@@ -318,6 +318,9 @@ pub enum Error {
     #[error("invalid cursor: invalid sort direction")]
     CursorInvalidDirection,
 
+    #[error("invalid cursor: signature verification failed")]
+    CursorInvalidSignature,
+
     // Database and low-level errors
     #[error("database error: {0}")]
     Db(String),
@@ -389,14 +392,53 @@ impl CursorV1 {
         serde_json::to_vec(&w).map(|x| base64_url::encode(&x))
     }
 
+    /// Encode cursor and sign it when `limits` carries a cursor HMAC key.
+    ///
+    /// Signed cursors have the form `<payload>.<signature>` (both base64url).
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCursor` if encoding fails.
+    pub fn encode_with(&self, limits: &ODataLimits) -> Result<String, Error> {
+        let payload = self.encode().map_err(|_| Error::InvalidCursor)?;
+        Ok(match limits.sign_cursor(&payload) {
+            Some(signature) => format!("{payload}.{signature}"),
+            None => payload,
+        })
+    }
+
+    /// Decode cursor from a token after verifying its signature against `limits`.
+    ///
+    /// # Errors
+    /// Returns `Error::CursorInvalidSignature` if the signature does not match any configured
+    /// key, or if the cursor is unsigned while `require_signed_cursors` is set.
+    /// Otherwise returns the same errors as [`CursorV1::decode`].
+    pub fn decode_with(token: &str, limits: &ODataLimits) -> Result<Self, Error> {
+        let (payload, signature) = Self::split_signature(token);
+        limits.verify_cursor(payload, signature)?;
+        Self::decode_payload(payload)
+    }
+
+    fn split_signature(token: &str) -> (&str, Option<&str>) {
+        match token.split_once('.') {
+            Some((payload, signature)) => (payload, Some(signature)),
+            None => (token, None),
+        }
+    }
+
     /// Decode cursor from base64url token.
     ///
+    /// A signature suffix is ignored, not verified; use [`CursorV1::decode_with`] for that.
+    ///
     /// # Errors
     /// Returns `Error::CursorInvalidBase64` if base64 decoding fails.
     /// Returns `Error::CursorInvalidJson` if JSON parsing fails.
     /// Returns `Error::CursorInvalidVersion` if the version is unsupported.
     /// Returns `Error::CursorInvalidDirection` if the direction field is invalid.
     pub fn decode(token: &str) -> Result<Self, Error> {
+        Self::decode_payload(Self::split_signature(token).0)
+    }
+
+    fn decode_payload(token: &str) -> Result<Self, Error> {
         #[derive(serde::Deserialize)]
         struct Wire {
             v: u8,
@@ -466,6 +508,8 @@ pub struct ODataQuery {
     pub cursor: Option<CursorV1>,
     pub filter_hash: Option<String>,
     pub select: Option<Vec<String>>,
+    /// Limits the query was extracted under; cursors for further pages are signed with them.
+    pub limits: Option<std::sync::Arc<ODataLimits>>,
 }
 
 impl ODataQuery {
@@ -503,6 +547,22 @@ impl ODataQuery {
         self
     }
 
+    pub fn with_limits(mut self, limits: std::sync::Arc<ODataLimits>) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Encode a cursor for a further page, signing it if the query carries a signing key.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidCursor` if encoding fails.
+    pub fn encode_cursor(&self, cursor: &CursorV1) -> Result<String, Error> {
+        match &self.limits {
+            Some(limits) => cursor.encode_with(limits),
+            None => cursor.encode().map_err(|_| Error::InvalidCursor),
+        }
+    }
+
     /// Get filter as AST
     #[must_use]
     pub fn filter(&self) -> Option<&ast::Expr> {
@@ -7,9 +7,15 @@
 //! - Cursor integrity checks (HMAC signing)
 
 use crate::Error;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
 
 /// Default configuration for `OData` input limits
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 #[must_use]
 pub struct ODataLimits {
     /// Maximum value for $top (default: 1000)
@@ -22,6 +28,27 @@ pub struct ODataLimits {
     pub require_signed_cursors: bool,
     /// HMAC key for cursor signing (if enabled)
     pub cursor_hmac_key: Option<Vec<u8>>,
+    /// Retired HMAC keys still accepted when verifying cursors (key rotation)
+    pub cursor_verification_keys: Vec<Vec<u8>>,
+}
+
+impl std::fmt::Debug for ODataLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ODataLimits")
+            .field("max_top", &self.max_top)
+            .field("max_orderby_fields", &self.max_orderby_fields)
+            .field("max_filter_length", &self.max_filter_length)
+            .field("require_signed_cursors", &self.require_signed_cursors)
+            .field(
+                "cursor_hmac_key",
+                &self.cursor_hmac_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field(
+                "cursor_verification_keys",
+                &self.cursor_verification_keys.len(),
+            )
+            .finish()
+    }
 }
 
 impl Default for ODataLimits {
@@ -32,6 +59,7 @@ impl Default for ODataLimits {
             max_filter_length: 2000,
             require_signed_cursors: false,
             cursor_hmac_key: None,
+            cursor_verification_keys: Vec::new(),
         }
     }
 }
@@ -67,6 +95,65 @@ impl ODataLimits {
         self
     }
 
+    /// Accept cursors signed with a retired key in addition to the current signing key
+    pub fn with_cursor_verification_key(mut self, key: Vec<u8>) -> Self {
+        self.cursor_verification_keys.push(key);
+        self
+    }
+
+    /// Sign an encoded cursor payload with the current key, if one is configured.
+    pub(crate) fn sign_cursor(&self, payload: &str) -> Option<String> {
+        let mut mac = HmacSha256::new_from_slice(self.cursor_hmac_key.as_deref()?).ok()?;
+        mac.update(payload.as_bytes());
+        Some(URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+
+    /// Verify the signature of an encoded cursor payload against the current and retired keys.
+    ///
+    /// Unsigned cursors are accepted unless `require_signed_cursors` is set.
+    pub(crate) fn verify_cursor(
+        &self,
+        payload: &str,
+        signature: Option<&str>,
+    ) -> Result<(), Error> {
+        let Some(signature) = signature else {
+            return if self.require_signed_cursors {
+                Err(Error::CursorInvalidSignature)
+            } else {
+                Ok(())
+            };
+        };
+
+        let mut keys = self
+            .cursor_hmac_key
+            .iter()
+            .chain(&self.cursor_verification_keys)
+            .peekable();
+        if keys.peek().is_none() {
+            // Signing is not configured here, so the signature cannot be checked
+            return if self.require_signed_cursors {
+                Err(Error::CursorInvalidSignature)
+            } else {
+                Ok(())
+            };
+        }
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| Error::CursorInvalidSignature)?;
+        let valid = keys.any(|key| {
+            HmacSha256::new_from_slice(key).is_ok_and(|mut mac| {
+                mac.update(payload.as_bytes());
+                mac.verify_slice(&signature).is_ok()
+            })
+        });
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::CursorInvalidSignature)
+        }
+    }
+
     /// Validate a $top value against limits.
     ///
     /// # Errors
@@ -171,4 +258,22 @@ mod tests {
         assert_eq!(limits.max_orderby_fields, 3);
         assert_eq!(limits.max_filter_length, 500);
     }
+
+    #[test]
+    fn test_debug_redacts_cursor_keys() {
+        let keys: [&[u8]; 2] = [b"super-secret", b"retired-secret"];
+        let limits = ODataLimits::new()
+            .with_signed_cursors(keys[0].to_vec())
+            .with_cursor_verification_key(keys[1].to_vec());
+
+        let debug = format!("{limits:?}");
+        assert!(debug.contains(r#"cursor_hmac_key: Some("<redacted>")"#));
+        assert!(debug.contains("cursor_verification_keys: 1"));
+        for key in keys {
+            // Neither the key text, its bytes as `Vec<u8>` prints them, nor its base64 form
+            assert!(!debug.contains(std::str::from_utf8(key).unwrap()));
+            assert!(!debug.contains(format!("{key:?}").trim_matches(['[', ']'])));
+            assert!(!debug.contains(&URL_SAFE_NO_PAD.encode(key)));
+        }
+    }
 }
@@ -12,9 +12,9 @@ impl From<Error> for Problem {
     fn from(err: Error) -> Self {
         use Error::{
             CursorInvalidBase64, CursorInvalidDirection, CursorInvalidFields, CursorInvalidJson,
-            CursorInvalidKeys, CursorInvalidVersion, Db, FilterMismatch, InvalidCursor,
-            InvalidFilter, InvalidLimit, InvalidOrderByField, OrderMismatch, OrderWithCursor,
-            ParsingUnavailable,
+            CursorInvalidKeys, CursorInvalidSignature, CursorInvalidVersion, Db, FilterMismatch,
+            InvalidCursor, InvalidFilter, InvalidLimit, InvalidOrderByField, OrderMismatch,
+            OrderWithCursor, ParsingUnavailable,
         };
 
         match err {
@@ -37,6 +37,11 @@ impl From<Error> for Problem {
                 ErrorCode::odata_errors_invalid_cursor_v1().as_problem(err.to_string())
             }
 
+            // Tampered or unsigned cursor when signing is enforced → 422 (distinct code)
+            CursorInvalidSignature => {
+                ErrorCode::odata_errors_invalid_cursor_signature_v1().as_problem(err.to_string())
+            }
+
             // Pagination validation errors → 422
             OrderMismatch => ErrorCode::odata_errors_invalid_orderby_v1()
                 .as_problem("Order mismatch between cursor and query"),
@@ -109,4 +114,14 @@ mod tests {
         assert!(problem.code.contains("odata"));
         assert!(problem.code.contains("invalid_cursor"));
     }
+
+    #[test]
+    fn test_cursor_signature_error_has_distinct_code() {
+        use http::StatusCode;
+
+        let problem: Problem = Error::CursorInvalidSignature.into();
+
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(problem.code.contains("invalid_cursor_signature"));
+    }
 }
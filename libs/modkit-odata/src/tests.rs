@@ -2,7 +2,9 @@
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::{CursorV1, Error, ODataOrderBy, ODataQuery, OrderKey, SortDir, base64_url};
+    use crate::{
+        CursorV1, Error, ODataLimits, ODataOrderBy, ODataQuery, OrderKey, SortDir, base64_url,
+    };
 
     #[test]
     fn test_cursor_v1_encode_decode_round_trip() {
@@ -382,4 +384,93 @@ mod tests {
             "unsupported $orderby field: unknown_field"
         );
     }
+
+    fn sample_cursor() -> CursorV1 {
+        CursorV1 {
+            k: vec!["2023-11-14T12:00:00Z".to_owned(), "42".to_owned()],
+            o: SortDir::Desc,
+            s: "-created_at,-id".to_owned(),
+            f: None,
+            d: "fwd".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_signed_cursor_round_trip() {
+        let limits = ODataLimits::default().with_signed_cursors(b"current-key".to_vec());
+        let token = sample_cursor().encode_with(&limits).unwrap();
+
+        assert!(token.contains('.'));
+        let decoded = CursorV1::decode_with(&token, &limits).unwrap();
+        assert_eq!(decoded.k, sample_cursor().k);
+        // Unverified decode ignores the signature
+        assert_eq!(CursorV1::decode(&token).unwrap().s, "-created_at,-id");
+    }
+
+    #[test]
+    fn test_signed_cursor_rejects_tampering() {
+        let limits = ODataLimits::default().with_signed_cursors(b"current-key".to_vec());
+        let token = sample_cursor().encode_with(&limits).unwrap();
+        let (_, signature) = token.split_once('.').unwrap();
+
+        let mut forged = sample_cursor();
+        forged.k[1] = "43".to_owned();
+        let forged_token = format!("{}.{signature}", forged.encode().unwrap());
+        assert!(matches!(
+            CursorV1::decode_with(&forged_token, &limits),
+            Err(Error::CursorInvalidSignature)
+        ));
+
+        let other = ODataLimits::default().with_signed_cursors(b"other-key".to_vec());
+        assert!(matches!(
+            CursorV1::decode_with(&token, &other),
+            Err(Error::CursorInvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_signed_cursor_accepts_rotated_keys() {
+        let old = ODataLimits::default().with_signed_cursors(b"old-key".to_vec());
+        let token = sample_cursor().encode_with(&old).unwrap();
+
+        let rotated = ODataLimits::default()
+            .with_signed_cursors(b"new-key".to_vec())
+            .with_cursor_verification_key(b"old-key".to_vec());
+        assert!(CursorV1::decode_with(&token, &rotated).is_ok());
+
+        // New cursors are signed with the current key only
+        let fresh = sample_cursor().encode_with(&rotated).unwrap();
+        assert!(CursorV1::decode_with(&fresh, &old).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_cursor_requires_opt_out() {
+        let token = sample_cursor().encode().unwrap();
+
+        let required = ODataLimits::default().with_signed_cursors(b"key".to_vec());
+        assert!(matches!(
+            CursorV1::decode_with(&token, &required),
+            Err(Error::CursorInvalidSignature)
+        ));
+
+        let mut optional = required;
+        optional.require_signed_cursors = false;
+        assert!(CursorV1::decode_with(&token, &optional).is_ok());
+        assert!(CursorV1::decode_with(&token, &ODataLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn test_query_encode_cursor_uses_limits() {
+        let cursor = sample_cursor();
+        let plain = ODataQuery::default();
+        assert_eq!(
+            plain.encode_cursor(&cursor).unwrap(),
+            cursor.encode().unwrap()
+        );
+
+        let limits = std::sync::Arc::new(ODataLimits::default().with_signed_cursors(b"k".to_vec()));
+        let signed = ODataQuery::default().with_limits(limits.clone());
+        let token = signed.encode_cursor(&cursor).unwrap();
+        assert!(CursorV1::decode_with(&token, &limits).is_ok());
+    }
 }
@@ -4,6 +4,9 @@ use uuid::Uuid;
 ///
 /// An empty scope (no tenants, no resources) is considered a "deny all" scope.
 /// To access data, the scope must contain at least one tenant ID or resource ID.
+///
+/// Type IDs never grant access on their own; when present they further narrow
+/// the rows selected by the tenant/resource constraints to the listed types.
 #[derive(Clone, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct AccessScope {
     pub(crate) tenant_ids: Vec<Uuid>,
@@ -24,6 +27,12 @@ impl AccessScope {
         &self.resource_ids
     }
 
+    #[inline]
+    #[must_use]
+    pub fn types(&self) -> &[Uuid] {
+        &self.types
+    }
+
     /// Returns true if this scope is empty (no tenants, no resources).
     /// An empty scope results in a "deny all" condition in queries.
     #[must_use]
@@ -41,6 +50,11 @@ impl AccessScope {
         !self.resource_ids.is_empty()
     }
 
+    #[must_use]
+    pub fn has_types(&self) -> bool {
+        !self.types.is_empty()
+    }
+
     #[must_use]
     pub fn tenants_only(tenant_ids: Vec<Uuid>) -> Self {
         Self {
@@ -79,4 +93,11 @@ impl AccessScope {
             resource_ids,
         }
     }
+
+    /// Restrict this scope to the given type IDs (AND with tenant/resource constraints).
+    #[must_use]
+    pub fn with_types(mut self, types: Vec<Uuid>) -> Self {
+        self.types = types;
+        self
+    }
 }
@@ -23,7 +23,8 @@ pub enum SecCtxDecodeError {
 }
 
 /// Encode `SecurityContext` into a versioned binary blob using `postcard`.
-/// This does not do any signing or encryption, it is just a transport format.
+/// This does not do any signing or encryption, it is just a transport format;
+/// use [`SecCtxCodec`](crate::SecCtxCodec) for authenticated propagation.
 ///
 /// # Errors
 /// Returns `SecCtxEncodeError` if postcard serialization fails.
@@ -1,4 +1,5 @@
 use crate::permission::Permission;
+use crate::policy_engine::PolicyRequest;
 use crate::{AccessScope, PolicyEngineRef};
 use uuid::Uuid;
 
@@ -50,23 +51,52 @@ impl SecurityContext {
         self.environment.clone()
     }
 
+    /// Get the subject type (e.g. "user", "service") associated with the security context
+    #[must_use]
+    pub fn subject_type(&self) -> Option<&str> {
+        self.subject_type.as_deref()
+    }
+
+    /// Look up a single environmental attribute by key
+    #[must_use]
+    pub fn environment_attribute(&self, key: &str) -> Option<&str> {
+        self.environment
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
     pub fn scope(&self, policy_engine: PolicyEngineRef) -> AccessScopeResolver {
         AccessScopeResolver {
-            _policy_engine: policy_engine,
+            policy_engine,
             context: self.clone(),
             accessible_tenants: None,
+            resource_ids: Vec::new(),
+            types: Vec::new(),
+            operation: None,
         }
     }
 }
 
 pub struct AccessScopeResolver {
-    _policy_engine: PolicyEngineRef,
+    policy_engine: PolicyEngineRef,
     context: SecurityContext,
     /// Accessible tenant IDs (set via `include_accessible_tenants`).
     accessible_tenants: Option<Vec<Uuid>>,
+    /// Candidate resource IDs (set via `with_resource_ids`).
+    resource_ids: Vec<Uuid>,
+    /// Candidate type IDs (set via `include_types`).
+    types: Vec<Uuid>,
+    /// Resource and action evaluated against the policy engine (set via `for_operation`).
+    operation: Option<(String, String)>,
 }
 
 impl AccessScopeResolver {
+    /// Resource evaluated by [`AccessScopeResolver::prepare`] when no operation is set.
+    pub const DEFAULT_RESOURCE: &'static str = "*";
+    /// Action evaluated by [`AccessScopeResolver::prepare`] when no operation is set.
+    pub const DEFAULT_ACTION: &'static str = "*";
+
     /// Include a list of accessible tenant IDs in the scope.
     ///
     /// Use this method when the caller has already resolved which tenants
@@ -83,6 +113,7 @@ impl AccessScopeResolver {
     /// let scope = ctx
     ///     .scope(policy_engine)
     ///     .include_accessible_tenants(tenant_ids)
+    ///     .for_operation("users_info.users", "read")
     ///     .prepare()
     ///     .await?;
     /// ```
@@ -92,33 +123,163 @@ impl AccessScopeResolver {
         self
     }
 
+    /// Kept for compatibility; does not change the scope. Use
+    /// [`AccessScopeResolver::with_resource_ids`] to restrict the scope to resource IDs.
     #[must_use]
     pub fn include_resource_ids(&self) -> &Self {
         self
     }
 
+    /// Restrict the scope to the given resource IDs.
+    #[must_use]
+    pub fn with_resource_ids(mut self, resource_ids: Vec<Uuid>) -> Self {
+        self.resource_ids = resource_ids;
+        self
+    }
+
+    /// Restrict the scope to the given type IDs.
+    #[must_use]
+    pub fn include_types(mut self, types: Vec<Uuid>) -> Self {
+        self.types = types;
+        self
+    }
+
+    /// Evaluate the policy engine for `action` on `resource` while preparing the scope.
+    ///
+    /// Without an operation the engine is asked about
+    /// [`AccessScopeResolver::DEFAULT_RESOURCE`] and [`AccessScopeResolver::DEFAULT_ACTION`].
+    #[must_use]
+    pub fn for_operation(mut self, resource: &str, action: &str) -> Self {
+        self.operation = Some((resource.to_owned(), action.to_owned()));
+        self
+    }
+
     /// Prepare and build the final `AccessScope` based on the resolver configuration
     ///
+    /// The policy engine is always consulted, for the operation set via
+    /// [`AccessScopeResolver::for_operation`] or else for
+    /// [`AccessScopeResolver::DEFAULT_RESOURCE`]/[`AccessScopeResolver::DEFAULT_ACTION`]:
+    /// 1. Context permissions matching the operation narrow the candidates: if every
+    ///    matching permission is bound to a tenant (or resource), only those tenants
+    ///    (or resources) remain.
+    /// 2. The policy engine is asked about the operation as a whole and then about every
+    ///    remaining tenant, resource and type; denied candidates are dropped.
+    /// 3. If a dimension had candidates and all of them were dropped, the result is the
+    ///    empty ("deny all") scope so that a denied dimension never widens access.
+    ///
     /// # Errors
     /// This function may return an error if the scope preparation fails
     pub async fn prepare(&self) -> Result<AccessScope, Box<dyn std::error::Error>> {
-        // Keep this async to allow future policy-engine / IO-backed resolution without
+        // Keep this async to allow future IO-backed resolution without
         // changing the public API. This no-op await also satisfies clippy::unused_async.
         std::future::ready(()).await;
 
-        // If accessible tenants were provided, use them
-        if let Some(ref tenants) = self.accessible_tenants {
-            return Ok(AccessScope::tenants_only(tenants.clone()));
-        }
+        let tenants = match self.accessible_tenants {
+            // If accessible tenants were provided, use them
+            Some(ref tenants) => tenants.clone(),
+            // Fallback: single tenant from context
+            None if self.context.tenant_id != Uuid::default() => vec![self.context.tenant_id],
+            None => Vec::new(),
+        };
+
+        let (resource, action) = self.operation.as_ref().map_or(
+            (Self::DEFAULT_RESOURCE, Self::DEFAULT_ACTION),
+            |(resource, action)| (resource.as_str(), action.as_str()),
+        );
+
+        Ok(self.evaluate(resource, action, tenants))
+    }
 
-        // Fallback: single tenant from context
-        if self.context.tenant_id != Uuid::default() {
-            return Ok(AccessScope::tenants_only(vec![self.context.tenant_id]));
+    fn evaluate(&self, resource: &str, action: &str, tenants: Vec<Uuid>) -> AccessScope {
+        let engine = self.policy_engine.as_ref();
+        let ctx = &self.context;
+        let request = PolicyRequest::new(resource, action);
+
+        if !engine.evaluate(ctx, &request) {
+            return AccessScope::default();
         }
 
-        // Empty scope = deny all
-        Ok(AccessScope::default())
+        let matching: Vec<&Permission> = self
+            .context
+            .permissions
+            .iter()
+            .filter(|p| p.matches(resource, action))
+            .collect();
+
+        let Some(tenants) = narrow_by_permissions(tenants, &matching, Permission::tenant_id) else {
+            return AccessScope::default();
+        };
+        let Some(resource_ids) = narrow_by_permissions(
+            self.resource_ids.clone(),
+            &matching,
+            Permission::resource_id,
+        ) else {
+            return AccessScope::default();
+        };
+
+        let Some(tenants) =
+            retain_allowed(tenants, |id| engine.evaluate(ctx, &request.with_tenant(id)))
+        else {
+            return AccessScope::default();
+        };
+        let Some(resource_ids) = retain_allowed(resource_ids, |id| {
+            engine.evaluate(ctx, &request.with_resource_id(id))
+        }) else {
+            return AccessScope::default();
+        };
+        let Some(types) = retain_allowed(self.types.clone(), |id| {
+            engine.evaluate(ctx, &request.with_type(id))
+        }) else {
+            return AccessScope::default();
+        };
+
+        AccessScope::both(tenants, resource_ids).with_types(types)
+    }
+}
+
+/// Narrow `candidates` to the IDs granted by `matching` permissions.
+///
+/// A matching permission without a binding (`None`) grants every ID, so narrowing
+/// only happens when all matching permissions are bound. With no candidates the
+/// granted IDs themselves become the candidates.
+///
+/// Returns `None` when there were candidates and none of them was granted.
+fn narrow_by_permissions(
+    candidates: Vec<Uuid>,
+    matching: &[&Permission],
+    binding: fn(&Permission) -> Option<Uuid>,
+) -> Option<Vec<Uuid>> {
+    if matching.is_empty() {
+        return Some(candidates);
+    }
+    let Some(mut granted) = matching
+        .iter()
+        .map(|p| binding(p))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Some(candidates);
+    };
+    if candidates.is_empty() {
+        granted.sort_unstable();
+        granted.dedup();
+        return Some(granted);
+    }
+    let kept: Vec<Uuid> = candidates
+        .into_iter()
+        .filter(|id| granted.contains(id))
+        .collect();
+    if kept.is_empty() { None } else { Some(kept) }
+}
+
+/// Keep the candidates accepted by `allowed`.
+///
+/// Returns `None` when there were candidates and every one of them was rejected.
+fn retain_allowed(candidates: Vec<Uuid>, allowed: impl Fn(Uuid) -> bool) -> Option<Vec<Uuid>> {
+    if candidates.is_empty() {
+        return Some(candidates);
     }
+    let kept: Vec<Uuid> = candidates.into_iter().filter(|id| allowed(*id)).collect();
+    if kept.is_empty() { None } else { Some(kept) }
 }
 
 #[derive(Default)]
@@ -6,12 +6,19 @@ pub mod context;
 pub mod permission;
 pub mod policy_engine;
 pub mod prelude;
+pub mod rule_engine;
+pub mod signed_codec;
 
 pub use access_scope::AccessScope;
 pub use context::SecurityContext;
 pub use permission::Permission;
-pub use policy_engine::{NoopPolicyEngine, PolicyEngine, PolicyEngineRef};
+pub use policy_engine::{NoopPolicyEngine, PolicyEngine, PolicyEngineRef, PolicyRequest};
+pub use rule_engine::{PolicyConfig, PolicyDecision, RuleBasedPolicyEngine, RuleEffect};
 
 pub use bin_codec::{
     SECCTX_BIN_VERSION, SecCtxDecodeError, SecCtxEncodeError, decode_bin, encode_bin,
 };
+pub use signed_codec::{
+    SECCTX_SIGNED_BIN_VERSION, SecCtxAlgorithm, SecCtxCodec, SecCtxCodecConfig, SecCtxCodecError,
+    SecCtxConfigError, SecCtxKeyConfig,
+};
@@ -117,6 +117,25 @@ impl Permission {
     pub fn action(&self) -> &str {
         &self.action
     }
+
+    /// Returns true if this permission grants `action` on `resource`.
+    ///
+    /// The resource pattern matches exactly, as `*` (any resource), or as a
+    /// prefix when it ends with `*` (e.g. `gts.x.core.events.topic.v1~vendor.*`).
+    /// An action of `*` matches any action.
+    #[must_use]
+    pub fn matches(&self, resource: &str, action: &str) -> bool {
+        (self.action == "*" || self.action == action)
+            && resource_pattern_matches(&self.resource_pattern, resource)
+    }
+}
+
+/// Match a resource name against a pattern: exact, `*`, or a prefix ending with `*`.
+pub(crate) fn resource_pattern_matches(pattern: &str, resource: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => resource.starts_with(prefix),
+        None => pattern == resource,
+    }
 }
 
 #[derive(Default)]
@@ -528,4 +547,32 @@ mod tests {
             r#""*:gts.x.core.events.topic.v1~vendor.*:660e8400-e29b-41d4-a716-446655440002:publish""#
         );
     }
+
+    #[test]
+    fn test_permission_matches_exact_and_prefix_patterns() {
+        let exact = Permission::builder()
+            .resource_pattern("file_parser")
+            .action("edit")
+            .build()
+            .unwrap();
+        assert!(exact.matches("file_parser", "edit"));
+        assert!(!exact.matches("file_parser", "read"));
+        assert!(!exact.matches("file_parser_v2", "edit"));
+
+        let prefix = Permission::builder()
+            .resource_pattern("gts.x.core.events.topic.v1~vendor.*")
+            .action("publish")
+            .build()
+            .unwrap();
+        assert!(prefix.matches("gts.x.core.events.topic.v1~vendor.orders.v1", "publish"));
+        assert!(!prefix.matches("gts.x.core.events.topic.v1~other.orders.v1", "publish"));
+
+        let any = Permission::builder()
+            .resource_pattern("*")
+            .action("read")
+            .build()
+            .unwrap();
+        assert!(any.matches("users_info.users", "read"));
+        assert!(!any.matches("users_info.users", "write"));
+    }
 }
@@ -1,11 +1,65 @@
 use crate::SecurityContext;
+use uuid::Uuid;
 
 /// Type alias for a reference-counted Policy Engine
 pub type PolicyEngineRef = std::sync::Arc<dyn PolicyEngine>;
 
+/// A single access question asked of a [`PolicyEngine`].
+///
+/// `resource` and `action` are always set. At most one of `tenant_id`,
+/// `resource_id` or `type_id` is set when the `AccessScopeResolver` narrows a
+/// candidate scope; all of them are `None` for the coarse, scope-wide check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicyRequest<'a> {
+    pub resource: &'a str,
+    pub action: &'a str,
+    pub tenant_id: Option<Uuid>,
+    pub resource_id: Option<Uuid>,
+    pub type_id: Option<Uuid>,
+}
+
+impl<'a> PolicyRequest<'a> {
+    #[must_use]
+    pub fn new(resource: &'a str, action: &'a str) -> Self {
+        Self {
+            resource,
+            action,
+            tenant_id: None,
+            resource_id: None,
+            type_id: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_tenant(mut self, tenant_id: Uuid) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_resource_id(mut self, resource_id: Uuid) -> Self {
+        self.resource_id = Some(resource_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_type(mut self, type_id: Uuid) -> Self {
+        self.type_id = Some(type_id);
+        self
+    }
+}
+
 /// Policy Engine - Zero Trust Policy Engine, responsible for evaluating and enforcing policies or rules
 pub trait PolicyEngine: Send + Sync {
     fn allows(&self, ctx: &SecurityContext, resource: &str, action: &str) -> bool;
+
+    /// Evaluate a request that may be bound to a specific tenant, resource or type.
+    ///
+    /// The default implementation ignores the bindings and delegates to [`PolicyEngine::allows`],
+    /// so engines that only reason about `resource`/`action` keep working unchanged.
+    fn evaluate(&self, ctx: &SecurityContext, request: &PolicyRequest<'_>) -> bool {
+        self.allows(ctx, request.resource, request.action)
+    }
 }
 
 pub struct NoopPolicyEngine;
@@ -0,0 +1,434 @@
+//! Declarative, rule-based [`PolicyEngine`] implementation.
+//!
+//! Rules are loaded from configuration (typically the `policy` section of the server
+//! YAML) and can be swapped at runtime with [`RuleBasedPolicyEngine::reload`].
+//!
+//! # Semantics
+//!
+//! Every rule whose conditions match the request is collected. Evaluation is
+//! **deny-overrides**: a single matching `deny` rule denies the request, otherwise a
+//! matching `allow` rule allows it, otherwise `default_effect` applies.
+//!
+//! An empty condition list matches anything, so a rule with only `effect: deny`
+//! denies everything.
+//!
+//! ```yaml
+//! policy:
+//!   default_effect: deny
+//!   rules:
+//!     - id: users-read
+//!       effect: allow
+//!       subject_types: ["user"]
+//!       resources: ["users", "cities"]
+//!       actions: ["read"]
+//!     - id: no-deletes-from-mobile
+//!       effect: deny
+//!       actions: ["delete"]
+//!       environment:
+//!         device: mobile
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SecurityContext;
+use crate::permission::resource_pattern_matches;
+use crate::policy_engine::{PolicyEngine, PolicyRequest};
+
+/// Outcome of a rule or of the whole evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleEffect {
+    #[default]
+    Allow,
+    Deny,
+}
+
+/// A single allow/deny rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRule {
+    /// Unique rule identifier, reported in decision traces.
+    pub id: String,
+    pub effect: RuleEffect,
+    /// Subject types (`SecurityContext::subject_type`) the rule applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subject_types: Vec<String>,
+    /// Tenants the rule applies to. Matches the tenant bound to the request,
+    /// or the context tenant for scope-wide checks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tenants: Vec<Uuid>,
+    /// Resource patterns: exact name, `*`, or a prefix ending with `*`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<String>,
+    /// Actions the rule applies to; `*` matches any action.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<String>,
+    /// Required environment attributes (`SecurityContext::environment`).
+    /// A value of `*` only requires the attribute to be present.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment: BTreeMap<String, String>,
+}
+
+/// Rule set loaded from configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Effect applied when no rule matches.
+    ///
+    /// Defaults to `allow` so that an empty configuration behaves like
+    /// `NoopPolicyEngine`; set it to `deny` for zero-trust deployments.
+    #[serde(default)]
+    pub default_effect: RuleEffect,
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+
+/// Errors raised when loading a [`PolicyConfig`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PolicyConfigError {
+    #[error("policy rule at index {0} has an empty id")]
+    EmptyRuleId(usize),
+    #[error("duplicate policy rule id '{0}'")]
+    DuplicateRuleId(String),
+}
+
+impl PolicyConfig {
+    /// Validate the rule set.
+    ///
+    /// # Errors
+    /// Returns an error if a rule id is empty or used more than once.
+    pub fn validate(&self) -> Result<(), PolicyConfigError> {
+        let mut seen = HashSet::new();
+        for (idx, rule) in self.rules.iter().enumerate() {
+            if rule.id.trim().is_empty() {
+                return Err(PolicyConfigError::EmptyRuleId(idx));
+            }
+            if !seen.insert(rule.id.as_str()) {
+                return Err(PolicyConfigError::DuplicateRuleId(rule.id.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Why a rule did or did not match a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleMatch {
+    Matched,
+    SubjectTypeMismatch,
+    TenantMismatch,
+    ResourceMismatch,
+    ActionMismatch,
+    EnvironmentMismatch,
+}
+
+/// Per-rule entry of a [`PolicyDecision`] trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleTrace {
+    pub rule_id: String,
+    pub effect: RuleEffect,
+    pub outcome: RuleMatch,
+}
+
+/// Result of evaluating a request, with the full trace of rule evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PolicyDecision {
+    pub effect: RuleEffect,
+    /// Rule that determined the effect; `None` when `default_effect` applied.
+    pub decisive_rule: Option<String>,
+    pub trace: Vec<RuleTrace>,
+}
+
+impl PolicyDecision {
+    #[must_use]
+    pub fn is_allowed(&self) -> bool {
+        self.effect == RuleEffect::Allow
+    }
+}
+
+/// Rule-based policy engine with deny-overrides semantics and hot-swappable rules.
+pub struct RuleBasedPolicyEngine {
+    config: ArcSwap<PolicyConfig>,
+}
+
+impl Default for RuleBasedPolicyEngine {
+    fn default() -> Self {
+        Self {
+            config: ArcSwap::from_pointee(PolicyConfig::default()),
+        }
+    }
+}
+
+impl RuleBasedPolicyEngine {
+    /// Create an engine from a validated rule set.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid (see [`PolicyConfig::validate`]).
+    pub fn new(config: PolicyConfig) -> Result<Self, PolicyConfigError> {
+        config.validate()?;
+        Ok(Self {
+            config: ArcSwap::from_pointee(config),
+        })
+    }
+
+    /// Atomically replace the rule set. In-flight evaluations finish with the old rules.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid; the current rules are kept.
+    pub fn reload(&self, config: PolicyConfig) -> Result<(), PolicyConfigError> {
+        config.validate()?;
+        self.config.store(Arc::new(config));
+        Ok(())
+    }
+
+    /// Snapshot of the active rule set.
+    #[must_use]
+    pub fn config(&self) -> Arc<PolicyConfig> {
+        self.config.load_full()
+    }
+
+    /// Evaluate a request and return the decision with its trace.
+    #[must_use]
+    pub fn decide(&self, ctx: &SecurityContext, request: &PolicyRequest<'_>) -> PolicyDecision {
+        let config = self.config.load();
+        let trace: Vec<RuleTrace> = config
+            .rules
+            .iter()
+            .map(|rule| RuleTrace {
+                rule_id: rule.id.clone(),
+                effect: rule.effect,
+                outcome: match_rule(rule, ctx, request),
+            })
+            .collect();
+
+        let first_matching = |effect: RuleEffect| {
+            trace
+                .iter()
+                .find(|t| t.outcome == RuleMatch::Matched && t.effect == effect)
+                .map(|t| t.rule_id.clone())
+        };
+
+        let (effect, decisive_rule) = if let Some(id) = first_matching(RuleEffect::Deny) {
+            (RuleEffect::Deny, Some(id))
+        } else if let Some(id) = first_matching(RuleEffect::Allow) {
+            (RuleEffect::Allow, Some(id))
+        } else {
+            (config.default_effect, None)
+        };
+
+        PolicyDecision {
+            effect,
+            decisive_rule,
+            trace,
+        }
+    }
+}
+
+impl PolicyEngine for RuleBasedPolicyEngine {
+    fn allows(&self, ctx: &SecurityContext, resource: &str, action: &str) -> bool {
+        self.evaluate(ctx, &PolicyRequest::new(resource, action))
+    }
+
+    fn evaluate(&self, ctx: &SecurityContext, request: &PolicyRequest<'_>) -> bool {
+        let decision = self.decide(ctx, request);
+        tracing::trace!(
+            resource = request.resource,
+            action = request.action,
+            tenant_id = ?request.tenant_id,
+            effect = ?decision.effect,
+            decisive_rule = ?decision.decisive_rule,
+            "policy decision"
+        );
+        decision.is_allowed()
+    }
+}
+
+fn match_rule(rule: &PolicyRule, ctx: &SecurityContext, request: &PolicyRequest<'_>) -> RuleMatch {
+    if !rule.subject_types.is_empty()
+        && !ctx
+            .subject_type()
+            .is_some_and(|st| rule.subject_types.iter().any(|s| s == st))
+    {
+        return RuleMatch::SubjectTypeMismatch;
+    }
+
+    if !rule.tenants.is_empty() {
+        let tenant = request.tenant_id.unwrap_or_else(|| ctx.tenant_id());
+        if !rule.tenants.contains(&tenant) {
+            return RuleMatch::TenantMismatch;
+        }
+    }
+
+    if !rule.resources.is_empty()
+        && !rule
+            .resources
+            .iter()
+            .any(|pattern| resource_pattern_matches(pattern, request.resource))
+    {
+        return RuleMatch::ResourceMismatch;
+    }
+
+    if !rule.actions.is_empty() && !rule.actions.iter().any(|a| a == "*" || a == request.action) {
+        return RuleMatch::ActionMismatch;
+    }
+
+    let env_ok = rule.environment.iter().all(|(key, expected)| {
+        ctx.environment_attribute(key)
+            .is_some_and(|actual| expected == "*" || expected == actual)
+    });
+    if !env_ok {
+        return RuleMatch::EnvironmentMismatch;
+    }
+
+    RuleMatch::Matched
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, effect: RuleEffect) -> PolicyRule {
+        PolicyRule {
+            id: id.to_owned(),
+            effect,
+            ..PolicyRule::default()
+        }
+    }
+
+    fn user_ctx() -> SecurityContext {
+        SecurityContext::builder()
+            .tenant_id(Uuid::from_u128(1))
+            .subject_type("user")
+            .add_environment_attribute("device", "mobile")
+            .build()
+    }
+
+    #[test]
+    fn empty_config_allows_everything() {
+        let engine = RuleBasedPolicyEngine::default();
+        assert!(engine.allows(&user_ctx(), "users", "delete"));
+    }
+
+    #[test]
+    fn default_effect_applies_when_nothing_matches() {
+        let engine = RuleBasedPolicyEngine::new(PolicyConfig {
+            default_effect: RuleEffect::Deny,
+            rules: vec![PolicyRule {
+                resources: vec!["cities".to_owned()],
+                ..rule("cities", RuleEffect::Allow)
+            }],
+        })
+        .unwrap();
+
+        let decision = engine.decide(&user_ctx(), &PolicyRequest::new("users", "read"));
+        assert_eq!(decision.effect, RuleEffect::Deny);
+        assert_eq!(decision.decisive_rule, None);
+        assert_eq!(decision.trace[0].outcome, RuleMatch::ResourceMismatch);
+    }
+
+    #[test]
+    fn deny_overrides_allow() {
+        let engine = RuleBasedPolicyEngine::new(PolicyConfig {
+            default_effect: RuleEffect::Deny,
+            rules: vec![
+                PolicyRule {
+                    resources: vec!["users*".to_owned()],
+                    ..rule("allow-users", RuleEffect::Allow)
+                },
+                PolicyRule {
+                    actions: vec!["delete".to_owned()],
+                    environment: BTreeMap::from([("device".to_owned(), "mobile".to_owned())]),
+                    ..rule("no-mobile-deletes", RuleEffect::Deny)
+                },
+            ],
+        })
+        .unwrap();
+
+        let ctx = user_ctx();
+        assert!(engine.allows(&ctx, "users", "read"));
+
+        let decision = engine.decide(&ctx, &PolicyRequest::new("users", "delete"));
+        assert_eq!(decision.effect, RuleEffect::Deny);
+        assert_eq!(decision.decisive_rule.as_deref(), Some("no-mobile-deletes"));
+
+        let desktop = SecurityContext::builder()
+            .subject_type("user")
+            .add_environment_attribute("device", "desktop")
+            .build();
+        assert!(engine.allows(&desktop, "users", "delete"));
+    }
+
+    #[test]
+    fn subject_type_and_tenant_conditions() {
+        let tenant = Uuid::from_u128(1);
+        let other = Uuid::from_u128(2);
+        let engine = RuleBasedPolicyEngine::new(PolicyConfig {
+            default_effect: RuleEffect::Deny,
+            rules: vec![PolicyRule {
+                subject_types: vec!["user".to_owned()],
+                tenants: vec![tenant],
+                ..rule("own-tenant", RuleEffect::Allow)
+            }],
+        })
+        .unwrap();
+
+        let ctx = user_ctx();
+        let request = PolicyRequest::new("users", "read");
+        assert!(engine.evaluate(&ctx, &request));
+        assert!(engine.evaluate(&ctx, &request.with_tenant(tenant)));
+        assert!(!engine.evaluate(&ctx, &request.with_tenant(other)));
+
+        let service = SecurityContext::builder()
+            .tenant_id(tenant)
+            .subject_type("service")
+            .build();
+        let decision = engine.decide(&service, &request);
+        assert_eq!(decision.trace[0].outcome, RuleMatch::SubjectTypeMismatch);
+        assert!(!decision.is_allowed());
+    }
+
+    #[test]
+    fn reload_swaps_rules_and_rejects_invalid_config() {
+        let engine = RuleBasedPolicyEngine::default();
+        let ctx = user_ctx();
+        assert!(engine.allows(&ctx, "users", "read"));
+
+        engine
+            .reload(PolicyConfig {
+                default_effect: RuleEffect::Deny,
+                rules: vec![],
+            })
+            .unwrap();
+        assert!(!engine.allows(&ctx, "users", "read"));
+
+        let err = engine
+            .reload(PolicyConfig {
+                default_effect: RuleEffect::Allow,
+                rules: vec![rule("a", RuleEffect::Allow), rule("a", RuleEffect::Deny)],
+            })
+            .unwrap_err();
+        assert_eq!(err, PolicyConfigError::DuplicateRuleId("a".to_owned()));
+        // Previous rules are kept after a failed reload
+        assert!(!engine.allows(&ctx, "users", "read"));
+    }
+
+    #[test]
+    fn config_deserializes_from_yaml_shaped_json() {
+        let config: PolicyConfig = serde_json::from_value(serde_json::json!({
+            "default_effect": "deny",
+            "rules": [
+                { "id": "r1", "effect": "allow", "resources": ["users"], "actions": ["*"] }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(config.default_effect, RuleEffect::Deny);
+        assert_eq!(config.rules[0].actions, vec!["*".to_owned()]);
+    }
+}
@@ -0,0 +1,723 @@
+//! Signed (and optionally encrypted) `SecurityContext` envelope.
+//!
+//! [`encode_bin`](crate::encode_bin) produces an unauthenticated v1 blob: anyone who can
+//! reach a gRPC endpoint can forge a tenant or permissions. The v2 envelope produced by
+//! [`SecCtxCodec`] wraps the same postcard payload with:
+//!
+//! - a signature (`HMAC-SHA256` or `Ed25519`) identified by a key id (`kid`),
+//! - issued-at / expiry timestamps,
+//! - an audience that binds the blob to the receiving service,
+//! - optional `ChaCha20-Poly1305` encryption of the payload (encrypt-then-sign).
+//!
+//! Keys are loaded from configuration. Every configured key is accepted for
+//! verification while only `signing_key` is used to sign, so keys are rotated by
+//! adding the new key, switching `signing_key`, and removing the old key once all
+//! peers have picked up the change. [`SecCtxCodec::reload`] swaps the key ring at runtime.
+//!
+//! With `strict: true` unsigned v1 blobs are rejected.
+//!
+//! ```yaml
+//! secctx:
+//!   strict: true
+//!   ttl_secs: 30
+//!   signing_key: k2
+//!   encrypt: true
+//!   keys:
+//!     - kid: k2
+//!       alg: hmac_sha256
+//!       secret: "<base64, at least 32 bytes>"
+//!       encryption_key: "<base64, 32 bytes>"
+//!     - kid: k1
+//!       alg: ed25519
+//!       public_key: "<base64, 32 bytes>"
+//! ```
+//!
+//! Wire format: `[SECCTX_SIGNED_BIN_VERSION] || postcard(SignedEnvelope)`. The signature
+//! covers the version byte, the header and the (possibly encrypted) payload.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use arc_swap::ArcSwap;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::SecurityContext;
+use crate::bin_codec::{
+    SECCTX_BIN_VERSION, SecCtxDecodeError, SecCtxEncodeError, decode_bin, encode_bin,
+};
+
+pub const SECCTX_SIGNED_BIN_VERSION: u8 = 2;
+
+/// Minimum HMAC secret length in bytes.
+const MIN_HMAC_SECRET_LEN: usize = 32;
+const ED25519_KEY_LEN: usize = 32;
+const ENCRYPTION_KEY_LEN: usize = 32;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signature algorithm of a configured key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecCtxAlgorithm {
+    HmacSha256,
+    Ed25519,
+}
+
+/// A single key of the secctx key ring.
+///
+/// All key material is base64 (standard alphabet) encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecCtxKeyConfig {
+    pub kid: String,
+    pub alg: SecCtxAlgorithm,
+    /// Shared secret for `hmac_sha256`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Ed25519 private key seed; required to sign with this key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    /// Ed25519 public key; derived from `private_key` when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    /// `ChaCha20-Poly1305` key used when payload encryption is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<String>,
+}
+
+/// Configuration of the signed secctx envelope (the `secctx` config section).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct SecCtxCodecConfig {
+    /// Reject unsigned v1 blobs.
+    pub strict: bool,
+    /// Lifetime of an issued envelope.
+    pub ttl_secs: u64,
+    /// Tolerated clock difference between peers when checking `iat`/`exp`.
+    pub clock_skew_secs: u64,
+    /// Key id used to sign outgoing envelopes; `None` sends unsigned v1 blobs.
+    pub signing_key: Option<String>,
+    /// Encrypt the payload with the signing key's `encryption_key`.
+    pub encrypt: bool,
+    pub keys: Vec<SecCtxKeyConfig>,
+}
+
+impl Default for SecCtxCodecConfig {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            ttl_secs: 60,
+            clock_skew_secs: 5,
+            signing_key: None,
+            encrypt: false,
+            keys: Vec::new(),
+        }
+    }
+}
+
+/// Errors raised when loading a [`SecCtxCodecConfig`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SecCtxConfigError {
+    #[error("secctx key #{0} has an empty kid")]
+    EmptyKid(usize),
+
+    #[error("duplicate secctx key id '{0}'")]
+    DuplicateKid(String),
+
+    #[error("invalid secctx key '{kid}': {reason}")]
+    InvalidKey { kid: String, reason: String },
+
+    #[error("secctx signing key '{0}' is not configured")]
+    UnknownSigningKey(String),
+
+    #[error("secctx signing key '{0}' cannot sign (missing private key)")]
+    VerifyOnlySigningKey(String),
+
+    #[error("secctx encryption requires an encryption_key on signing key '{0}'")]
+    MissingEncryptionKey(String),
+
+    #[error("secctx encryption requires a signing_key")]
+    EncryptWithoutSigningKey,
+
+    #[error("secctx strict mode requires at least one key")]
+    StrictWithoutKeys,
+}
+
+/// Errors raised while encoding or decoding a secctx envelope.
+#[derive(Debug, Error)]
+pub enum SecCtxCodecError {
+    #[error(transparent)]
+    Encode(#[from] SecCtxEncodeError),
+
+    #[error(transparent)]
+    Decode(#[from] SecCtxDecodeError),
+
+    #[error("unsigned secctx rejected in strict mode")]
+    UnsignedRejected,
+
+    #[error("unknown secctx key id '{0}'")]
+    UnknownKey(String),
+
+    #[error("secctx algorithm does not match key '{0}'")]
+    AlgorithmMismatch(String),
+
+    #[error("invalid secctx signature")]
+    InvalidSignature,
+
+    #[error("secctx expired")]
+    Expired,
+
+    #[error("secctx issued in the future")]
+    NotYetValid,
+
+    #[error("secctx audience mismatch: expected '{expected}', got '{actual}'")]
+    AudienceMismatch { expected: String, actual: String },
+
+    #[error("secctx payload is encrypted but key '{0}' has no encryption key")]
+    MissingEncryptionKey(String),
+
+    #[error("secctx payload encryption failed")]
+    Encryption,
+
+    #[error("secctx payload decryption failed")]
+    Decryption,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EnvelopeHeader {
+    kid: String,
+    alg: SecCtxAlgorithm,
+    aud: String,
+    iat: u64,
+    exp: u64,
+    /// Present iff the payload is encrypted.
+    nonce: Option<[u8; 12]>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SignedEnvelope {
+    header: EnvelopeHeader,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+struct Ed25519Keys {
+    verifying: VerifyingKey,
+    signing: Option<SigningKey>,
+}
+
+enum KeyMaterial {
+    Hmac(Vec<u8>),
+    Ed25519(Box<Ed25519Keys>),
+}
+
+struct KeyEntry {
+    material: KeyMaterial,
+    cipher: Option<ChaCha20Poly1305>,
+}
+
+impl KeyEntry {
+    fn algorithm(&self) -> SecCtxAlgorithm {
+        match self.material {
+            KeyMaterial::Hmac(_) => SecCtxAlgorithm::HmacSha256,
+            KeyMaterial::Ed25519(_) => SecCtxAlgorithm::Ed25519,
+        }
+    }
+
+    fn can_sign(&self) -> bool {
+        match &self.material {
+            KeyMaterial::Hmac(_) => true,
+            KeyMaterial::Ed25519(keys) => keys.signing.is_some(),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match &self.material {
+            KeyMaterial::Hmac(secret) => hmac_sha256(secret, message)
+                .map_or_else(Vec::new, |mac| mac.finalize().into_bytes().to_vec()),
+            KeyMaterial::Ed25519(keys) => keys
+                .signing
+                .as_ref()
+                .map(|key| key.sign(message).to_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        match &self.material {
+            KeyMaterial::Hmac(secret) => {
+                hmac_sha256(secret, message).is_some_and(|mac| mac.verify_slice(signature).is_ok())
+            }
+            KeyMaterial::Ed25519(keys) => Signature::from_slice(signature)
+                .is_ok_and(|sig| keys.verifying.verify_strict(message, &sig).is_ok()),
+        }
+    }
+}
+
+fn hmac_sha256(secret: &[u8], message: &[u8]) -> Option<HmacSha256> {
+    // HMAC accepts keys of any length, so this only fails on a broken `hmac` build.
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(secret).ok()?;
+    mac.update(message);
+    Some(mac)
+}
+
+struct KeyRing {
+    strict: bool,
+    ttl_secs: u64,
+    clock_skew_secs: u64,
+    signing_kid: Option<String>,
+    encrypt: bool,
+    keys: HashMap<String, KeyEntry>,
+}
+
+impl KeyRing {
+    fn from_config(config: &SecCtxCodecConfig) -> Result<Self, SecCtxConfigError> {
+        let mut keys = HashMap::with_capacity(config.keys.len());
+        for (idx, key) in config.keys.iter().enumerate() {
+            if key.kid.is_empty() {
+                return Err(SecCtxConfigError::EmptyKid(idx));
+            }
+            if keys.contains_key(&key.kid) {
+                return Err(SecCtxConfigError::DuplicateKid(key.kid.clone()));
+            }
+            keys.insert(key.kid.clone(), parse_key(key)?);
+        }
+
+        if config.strict && keys.is_empty() {
+            return Err(SecCtxConfigError::StrictWithoutKeys);
+        }
+
+        match &config.signing_key {
+            Some(kid) => {
+                let entry = keys
+                    .get(kid)
+                    .ok_or_else(|| SecCtxConfigError::UnknownSigningKey(kid.clone()))?;
+                if !entry.can_sign() {
+                    return Err(SecCtxConfigError::VerifyOnlySigningKey(kid.clone()));
+                }
+                if config.encrypt && entry.cipher.is_none() {
+                    return Err(SecCtxConfigError::MissingEncryptionKey(kid.clone()));
+                }
+            }
+            None if config.encrypt => return Err(SecCtxConfigError::EncryptWithoutSigningKey),
+            None => {}
+        }
+
+        Ok(Self {
+            strict: config.strict,
+            ttl_secs: config.ttl_secs,
+            clock_skew_secs: config.clock_skew_secs,
+            signing_kid: config.signing_key.clone(),
+            encrypt: config.encrypt,
+            keys,
+        })
+    }
+}
+
+fn decode_key(kid: &str, field: &str, value: &str) -> Result<Vec<u8>, SecCtxConfigError> {
+    BASE64
+        .decode(value.trim())
+        .map_err(|e| SecCtxConfigError::InvalidKey {
+            kid: kid.to_owned(),
+            reason: format!("{field} is not valid base64: {e}"),
+        })
+}
+
+fn decode_fixed_key(
+    kid: &str,
+    field: &str,
+    value: &str,
+) -> Result<[u8; ED25519_KEY_LEN], SecCtxConfigError> {
+    decode_key(kid, field, value)?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| SecCtxConfigError::InvalidKey {
+            kid: kid.to_owned(),
+            reason: format!(
+                "{field} must be {ED25519_KEY_LEN} bytes, got {}",
+                bytes.len()
+            ),
+        })
+}
+
+fn parse_key(key: &SecCtxKeyConfig) -> Result<KeyEntry, SecCtxConfigError> {
+    let invalid = |reason: &str| SecCtxConfigError::InvalidKey {
+        kid: key.kid.clone(),
+        reason: reason.to_owned(),
+    };
+
+    let material = match key.alg {
+        SecCtxAlgorithm::HmacSha256 => {
+            let secret = key
+                .secret
+                .as_deref()
+                .ok_or_else(|| invalid("hmac_sha256 requires `secret`"))?;
+            let secret = decode_key(&key.kid, "secret", secret)?;
+            if secret.len() < MIN_HMAC_SECRET_LEN {
+                return Err(invalid(&format!(
+                    "secret must be at least {MIN_HMAC_SECRET_LEN} bytes"
+                )));
+            }
+            KeyMaterial::Hmac(secret)
+        }
+        SecCtxAlgorithm::Ed25519 => {
+            let signing = key
+                .private_key
+                .as_deref()
+                .map(|v| decode_fixed_key(&key.kid, "private_key", v))
+                .transpose()?
+                .map(|seed| SigningKey::from_bytes(&seed));
+            let verifying = match (&key.public_key, &signing) {
+                (Some(public), _) => {
+                    let bytes = decode_fixed_key(&key.kid, "public_key", public)?;
+                    VerifyingKey::from_bytes(&bytes)
+                        .map_err(|_| invalid("public_key is not a valid Ed25519 point"))?
+                }
+                (None, Some(signing)) => signing.verifying_key(),
+                (None, None) => {
+                    return Err(invalid("ed25519 requires `public_key` or `private_key`"));
+                }
+            };
+            if signing
+                .as_ref()
+                .is_some_and(|s| s.verifying_key() != verifying)
+            {
+                return Err(invalid("public_key does not match private_key"));
+            }
+            KeyMaterial::Ed25519(Box::new(Ed25519Keys { verifying, signing }))
+        }
+    };
+
+    let cipher = key
+        .encryption_key
+        .as_deref()
+        .map(|v| {
+            let bytes = decode_key(&key.kid, "encryption_key", v)?;
+            if bytes.len() != ENCRYPTION_KEY_LEN {
+                return Err(invalid(&format!(
+                    "encryption_key must be {ENCRYPTION_KEY_LEN} bytes"
+                )));
+            }
+            ChaCha20Poly1305::new_from_slice(&bytes)
+                .map_err(|_| invalid("encryption_key has an invalid length"))
+        })
+        .transpose()?;
+
+    Ok(KeyEntry { material, cipher })
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn signing_input(header: &EnvelopeHeader, payload: &[u8]) -> Result<Vec<u8>, SecCtxEncodeError> {
+    let mut buf = vec![SECCTX_SIGNED_BIN_VERSION];
+    buf.extend_from_slice(&postcard::to_allocvec(&(header, payload))?);
+    Ok(buf)
+}
+
+/// Encoder/decoder for `SecurityContext` blobs backed by a reloadable key ring.
+///
+/// The default codec has no keys: it emits unsigned v1 blobs and accepts them,
+/// which matches the behaviour of [`encode_bin`]/[`decode_bin`].
+pub struct SecCtxCodec {
+    ring: ArcSwap<KeyRing>,
+}
+
+impl Default for SecCtxCodec {
+    fn default() -> Self {
+        Self {
+            ring: ArcSwap::from_pointee(KeyRing {
+                strict: false,
+                ttl_secs: 0,
+                clock_skew_secs: 0,
+                signing_kid: None,
+                encrypt: false,
+                keys: HashMap::new(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Debug for SecCtxCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ring = self.ring.load();
+        f.debug_struct("SecCtxCodec")
+            .field("strict", &ring.strict)
+            .field("signing_kid", &ring.signing_kid)
+            .field("encrypt", &ring.encrypt)
+            .field("keys", &ring.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SecCtxCodec {
+    /// Build a codec from configuration.
+    ///
+    /// # Errors
+    /// Returns `SecCtxConfigError` if a key is malformed or the config is inconsistent.
+    pub fn new(config: &SecCtxCodecConfig) -> Result<Self, SecCtxConfigError> {
+        Ok(Self {
+            ring: ArcSwap::from_pointee(KeyRing::from_config(config)?),
+        })
+    }
+
+    /// Atomically replace the key ring (key rotation).
+    ///
+    /// # Errors
+    /// Returns `SecCtxConfigError` if the new config is invalid; the current keys stay active.
+    pub fn reload(&self, config: &SecCtxCodecConfig) -> Result<(), SecCtxConfigError> {
+        self.ring.store(Arc::new(KeyRing::from_config(config)?));
+        Ok(())
+    }
+
+    /// Whether unsigned v1 blobs are rejected.
+    #[must_use]
+    pub fn is_strict(&self) -> bool {
+        self.ring.load().strict
+    }
+
+    /// Encode `ctx` for the service identified by `audience`.
+    ///
+    /// Produces a signed v2 envelope when a signing key is configured and an
+    /// unsigned v1 blob otherwise.
+    ///
+    /// # Errors
+    /// Returns `SecCtxCodecError` if serialization or encryption fails.
+    pub fn encode(
+        &self,
+        ctx: &SecurityContext,
+        audience: &str,
+    ) -> Result<Vec<u8>, SecCtxCodecError> {
+        self.encode_at(ctx, audience, unix_now())
+    }
+
+    /// Decode a blob addressed to `audience`, verifying signature, expiry and audience.
+    ///
+    /// # Errors
+    /// Returns `SecCtxCodecError` if the blob is malformed, unsigned in strict mode,
+    /// signed with an unknown key, tampered with, expired or addressed elsewhere.
+    pub fn decode(
+        &self,
+        bytes: &[u8],
+        audience: &str,
+    ) -> Result<SecurityContext, SecCtxCodecError> {
+        self.decode_at(bytes, audience, unix_now())
+    }
+
+    fn encode_at(
+        &self,
+        ctx: &SecurityContext,
+        audience: &str,
+        now: u64,
+    ) -> Result<Vec<u8>, SecCtxCodecError> {
+        let ring = self.ring.load();
+        let Some((kid, key)) = ring
+            .signing_kid
+            .as_ref()
+            .and_then(|kid| ring.keys.get(kid).map(|key| (kid, key)))
+        else {
+            return Ok(encode_bin(ctx)?);
+        };
+
+        let plaintext = postcard::to_allocvec(ctx).map_err(SecCtxEncodeError::from)?;
+        let mut header = EnvelopeHeader {
+            kid: kid.clone(),
+            alg: key.algorithm(),
+            aud: audience.to_owned(),
+            iat: now,
+            exp: now.saturating_add(ring.ttl_secs),
+            nonce: None,
+        };
+
+        let payload = match (&key.cipher, ring.encrypt) {
+            (Some(cipher), true) => {
+                let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+                header.nonce = Some(nonce.into());
+                let aad = postcard::to_allocvec(&header).map_err(SecCtxEncodeError::from)?;
+                cipher
+                    .encrypt(
+                        &nonce,
+                        Payload {
+                            msg: &plaintext,
+                            aad: &aad,
+                        },
+                    )
+                    .map_err(|_| SecCtxCodecError::Encryption)?
+            }
+            _ => plaintext,
+        };
+
+        let signature = key.sign(&signing_input(&header, &payload)?);
+        let envelope = SignedEnvelope {
+            header,
+            payload,
+            signature,
+        };
+
+        let mut buf = vec![SECCTX_SIGNED_BIN_VERSION];
+        buf.extend_from_slice(&postcard::to_allocvec(&envelope).map_err(SecCtxEncodeError::from)?);
+        Ok(buf)
+    }
+
+    fn decode_at(
+        &self,
+        bytes: &[u8],
+        audience: &str,
+        now: u64,
+    ) -> Result<SecurityContext, SecCtxCodecError> {
+        let ring = self.ring.load();
+        match bytes.first() {
+            None => Err(SecCtxDecodeError::Empty.into()),
+            Some(&SECCTX_BIN_VERSION) if ring.strict => Err(SecCtxCodecError::UnsignedRejected),
+            Some(&SECCTX_BIN_VERSION) => Ok(decode_bin(bytes)?),
+            Some(&SECCTX_SIGNED_BIN_VERSION) => {
+                Self::decode_signed(&ring, &bytes[1..], audience, now)
+            }
+            Some(&other) => Err(SecCtxDecodeError::UnsupportedVersion(other).into()),
+        }
+    }
+
+    fn decode_signed(
+        ring: &KeyRing,
+        bytes: &[u8],
+        audience: &str,
+        now: u64,
+    ) -> Result<SecurityContext, SecCtxCodecError> {
+        let envelope: SignedEnvelope =
+            postcard::from_bytes(bytes).map_err(SecCtxDecodeError::from)?;
+        let header = &envelope.header;
+
+        let key = ring
+            .keys
+            .get(&header.kid)
+            .ok_or_else(|| SecCtxCodecError::UnknownKey(header.kid.clone()))?;
+        if key.algorithm() != header.alg {
+            return Err(SecCtxCodecError::AlgorithmMismatch(header.kid.clone()));
+        }
+
+        let message = signing_input(header, &envelope.payload)?;
+        if !key.verify(&message, &envelope.signature) {
+            return Err(SecCtxCodecError::InvalidSignature);
+        }
+
+        if now > header.exp.saturating_add(ring.clock_skew_secs) {
+            return Err(SecCtxCodecError::Expired);
+        }
+        if header.iat > now.saturating_add(ring.clock_skew_secs) {
+            return Err(SecCtxCodecError::NotYetValid);
+        }
+        if header.aud != audience {
+            return Err(SecCtxCodecError::AudienceMismatch {
+                expected: audience.to_owned(),
+                actual: header.aud.clone(),
+            });
+        }
+
+        let plaintext = match header.nonce {
+            Some(nonce) => {
+                let cipher = key
+                    .cipher
+                    .as_ref()
+                    .ok_or_else(|| SecCtxCodecError::MissingEncryptionKey(header.kid.clone()))?;
+                let aad = postcard::to_allocvec(header).map_err(SecCtxEncodeError::from)?;
+                cipher
+                    .decrypt(
+                        Nonce::from_slice(&nonce),
+                        Payload {
+                            msg: &envelope.payload,
+                            aad: &aad,
+                        },
+                    )
+                    .map_err(|_| SecCtxCodecError::Decryption)?
+            }
+            None => envelope.payload,
+        };
+
+        Ok(postcard::from_bytes(&plaintext).map_err(SecCtxDecodeError::from)?)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn hmac_config() -> SecCtxCodecConfig {
+        SecCtxCodecConfig {
+            ttl_secs: 30,
+            clock_skew_secs: 5,
+            signing_key: Some("k1".to_owned()),
+            keys: vec![SecCtxKeyConfig {
+                kid: "k1".to_owned(),
+                alg: SecCtxAlgorithm::HmacSha256,
+                secret: Some(BASE64.encode([7u8; 32])),
+                private_key: None,
+                public_key: None,
+                encryption_key: None,
+            }],
+            ..SecCtxCodecConfig::default()
+        }
+    }
+
+    #[test]
+    fn expiry_and_issue_time_respect_clock_skew() {
+        let codec = SecCtxCodec::new(&hmac_config()).unwrap();
+        let ctx = SecurityContext::anonymous();
+        let blob = codec.encode_at(&ctx, "svc", 1_000).unwrap();
+
+        assert!(codec.decode_at(&blob, "svc", 1_035).is_ok());
+        assert!(matches!(
+            codec.decode_at(&blob, "svc", 1_036),
+            Err(SecCtxCodecError::Expired)
+        ));
+        assert!(codec.decode_at(&blob, "svc", 995).is_ok());
+        assert!(matches!(
+            codec.decode_at(&blob, "svc", 994),
+            Err(SecCtxCodecError::NotYetValid)
+        ));
+    }
+
+    #[test]
+    fn config_validation_rejects_inconsistent_settings() {
+        let mut cfg = hmac_config();
+        cfg.signing_key = Some("missing".to_owned());
+        assert_eq!(
+            SecCtxCodec::new(&cfg).unwrap_err(),
+            SecCtxConfigError::UnknownSigningKey("missing".to_owned())
+        );
+
+        let mut cfg = hmac_config();
+        cfg.encrypt = true;
+        assert_eq!(
+            SecCtxCodec::new(&cfg).unwrap_err(),
+            SecCtxConfigError::MissingEncryptionKey("k1".to_owned())
+        );
+
+        let mut cfg = hmac_config();
+        cfg.keys[0].secret = Some(BASE64.encode([7u8; 8]));
+        assert!(matches!(
+            SecCtxCodec::new(&cfg).unwrap_err(),
+            SecCtxConfigError::InvalidKey { .. }
+        ));
+
+        let cfg = SecCtxCodecConfig {
+            strict: true,
+            ..SecCtxCodecConfig::default()
+        };
+        assert_eq!(
+            SecCtxCodec::new(&cfg).unwrap_err(),
+            SecCtxConfigError::StrictWithoutKeys
+        );
+    }
+}
@@ -0,0 +1,276 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_security::context::AccessScopeResolver;
+use modkit_security::{
+    AccessScope, NoopPolicyEngine, Permission, PolicyEngine, PolicyRequest, SecurityContext,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+const TENANT_A: Uuid = Uuid::from_u128(0xA);
+const TENANT_B: Uuid = Uuid::from_u128(0xB);
+const TENANT_C: Uuid = Uuid::from_u128(0xC);
+const RESOURCE_1: Uuid = Uuid::from_u128(0x1);
+const RESOURCE_2: Uuid = Uuid::from_u128(0x2);
+const TYPE_X: Uuid = Uuid::from_u128(0x10);
+const TYPE_Y: Uuid = Uuid::from_u128(0x20);
+
+/// Engine that denies a fixed set of tenants/types and everything for `deny_action`.
+struct DenyListEngine {
+    denied_tenants: Vec<Uuid>,
+    denied_types: Vec<Uuid>,
+    deny_action: &'static str,
+}
+
+impl PolicyEngine for DenyListEngine {
+    fn allows(&self, _ctx: &SecurityContext, _resource: &str, action: &str) -> bool {
+        action != self.deny_action
+    }
+
+    fn evaluate(&self, ctx: &SecurityContext, request: &PolicyRequest<'_>) -> bool {
+        if request
+            .tenant_id
+            .is_some_and(|t| self.denied_tenants.contains(&t))
+        {
+            return false;
+        }
+        if request
+            .type_id
+            .is_some_and(|t| self.denied_types.contains(&t))
+        {
+            return false;
+        }
+        self.allows(ctx, request.resource, request.action)
+    }
+}
+
+fn deny_list(denied_tenants: Vec<Uuid>, denied_types: Vec<Uuid>) -> Arc<DenyListEngine> {
+    Arc::new(DenyListEngine {
+        denied_tenants,
+        denied_types,
+        deny_action: "delete",
+    })
+}
+
+#[tokio::test]
+async fn without_operation_keeps_candidates() {
+    let ctx = SecurityContext::builder().tenant_id(TENANT_A).build();
+
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .include_types(vec![TYPE_X])
+        .prepare()
+        .await
+        .unwrap();
+
+    assert_eq!(scope.tenant_ids(), &[TENANT_A]);
+    assert_eq!(scope.types(), &[TYPE_X]);
+}
+
+#[tokio::test]
+async fn without_operation_still_consults_engine() {
+    let ctx = SecurityContext::builder().tenant_id(TENANT_A).build();
+
+    let scope = ctx
+        .scope(deny_list(vec![TENANT_A], vec![]))
+        .prepare()
+        .await
+        .unwrap();
+    assert!(scope.is_empty());
+
+    let engine = Arc::new(DenyListEngine {
+        denied_tenants: vec![],
+        denied_types: vec![],
+        deny_action: AccessScopeResolver::DEFAULT_ACTION,
+    });
+    let scope = ctx.scope(engine).prepare().await.unwrap();
+    assert!(scope.is_empty());
+}
+
+#[tokio::test]
+async fn denied_operation_yields_deny_all() {
+    let ctx = SecurityContext::builder().tenant_id(TENANT_A).build();
+
+    let scope = ctx
+        .scope(deny_list(vec![], vec![]))
+        .for_operation("users_info.users", "delete")
+        .prepare()
+        .await
+        .unwrap();
+
+    assert!(scope.is_empty());
+}
+
+#[tokio::test]
+async fn engine_filters_tenants_and_types() {
+    let ctx = SecurityContext::builder().tenant_id(TENANT_A).build();
+
+    let scope = ctx
+        .scope(deny_list(vec![TENANT_B], vec![TYPE_Y]))
+        .include_accessible_tenants(vec![TENANT_A, TENANT_B, TENANT_C])
+        .include_types(vec![TYPE_X, TYPE_Y])
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+
+    assert_eq!(scope.tenant_ids(), &[TENANT_A, TENANT_C]);
+    assert_eq!(scope.types(), &[TYPE_X]);
+}
+
+#[tokio::test]
+async fn fully_denied_dimension_never_widens_scope() {
+    let ctx = SecurityContext::builder().tenant_id(TENANT_A).build();
+
+    // All tenants denied while resources remain: must not degrade to "resources only".
+    let scope = ctx
+        .scope(deny_list(vec![TENANT_A], vec![]))
+        .with_resource_ids(vec![RESOURCE_1])
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+    assert_eq!(scope, AccessScope::default());
+
+    // All types denied: must not drop the type constraint.
+    let scope = ctx
+        .scope(deny_list(vec![], vec![TYPE_X]))
+        .include_types(vec![TYPE_X])
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+    assert!(scope.is_empty());
+}
+
+#[tokio::test]
+async fn tenant_bound_permissions_narrow_tenants() {
+    let ctx = SecurityContext::builder()
+        .tenant_id(TENANT_A)
+        .add_permission(
+            Permission::builder()
+                .tenant_id(TENANT_B)
+                .resource_pattern("users_info.*")
+                .action("read")
+                .build()
+                .unwrap(),
+        )
+        .add_permission(
+            Permission::builder()
+                .tenant_id(TENANT_C)
+                .resource_pattern("users_info.users")
+                .action("read")
+                .build()
+                .unwrap(),
+        )
+        .build();
+
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .include_accessible_tenants(vec![TENANT_A, TENANT_B, TENANT_C])
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+    assert_eq!(scope.tenant_ids(), &[TENANT_B, TENANT_C]);
+
+    // Permissions for another action do not narrow anything.
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .include_accessible_tenants(vec![TENANT_A, TENANT_B])
+        .for_operation("users_info.users", "write")
+        .prepare()
+        .await
+        .unwrap();
+    assert_eq!(scope.tenant_ids(), &[TENANT_A, TENANT_B]);
+}
+
+#[tokio::test]
+async fn unbound_permission_keeps_all_tenants() {
+    let ctx = SecurityContext::builder()
+        .tenant_id(TENANT_A)
+        .add_permission(
+            Permission::builder()
+                .tenant_id(TENANT_B)
+                .resource_pattern("users_info.users")
+                .action("read")
+                .build()
+                .unwrap(),
+        )
+        .add_permission(
+            Permission::builder()
+                .resource_pattern("*")
+                .action("read")
+                .build()
+                .unwrap(),
+        )
+        .build();
+
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .include_accessible_tenants(vec![TENANT_A, TENANT_B])
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+
+    assert_eq!(scope.tenant_ids(), &[TENANT_A, TENANT_B]);
+}
+
+#[tokio::test]
+async fn resource_bound_permissions_produce_resource_constraints() {
+    let ctx = SecurityContext::builder()
+        .tenant_id(TENANT_A)
+        .add_permission(
+            Permission::builder()
+                .resource_pattern("file_parser")
+                .resource_id(RESOURCE_2)
+                .action("edit")
+                .build()
+                .unwrap(),
+        )
+        .build();
+
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .for_operation("file_parser", "edit")
+        .prepare()
+        .await
+        .unwrap();
+    assert_eq!(scope.tenant_ids(), &[TENANT_A]);
+    assert_eq!(scope.resource_ids(), &[RESOURCE_2]);
+
+    // Requested resources outside the granted set are denied entirely.
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .with_resource_ids(vec![RESOURCE_1])
+        .for_operation("file_parser", "edit")
+        .prepare()
+        .await
+        .unwrap();
+    assert!(scope.is_empty());
+}
+
+#[tokio::test]
+async fn tenant_mismatch_with_bound_permissions_denies_all() {
+    let ctx = SecurityContext::builder()
+        .tenant_id(TENANT_A)
+        .add_permission(
+            Permission::builder()
+                .tenant_id(TENANT_B)
+                .resource_pattern("users_info.users")
+                .action("read")
+                .build()
+                .unwrap(),
+        )
+        .build();
+
+    let scope = ctx
+        .scope(Arc::new(NoopPolicyEngine))
+        .for_operation("users_info.users", "read")
+        .prepare()
+        .await
+        .unwrap();
+
+    assert!(scope.is_empty());
+}
@@ -0,0 +1,191 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use modkit_security::{
+    SECCTX_SIGNED_BIN_VERSION, SecCtxAlgorithm, SecCtxCodec, SecCtxCodecConfig, SecCtxCodecError,
+    SecCtxKeyConfig, SecurityContext, encode_bin,
+};
+use uuid::Uuid;
+
+const TENANT: Uuid = Uuid::from_u128(0xA);
+const AUDIENCE: &str = "calculator";
+
+fn ctx() -> SecurityContext {
+    SecurityContext::builder()
+        .tenant_id(TENANT)
+        .subject_id(Uuid::from_u128(0x5))
+        .build()
+}
+
+fn hmac_key(kid: &str, fill: u8) -> SecCtxKeyConfig {
+    SecCtxKeyConfig {
+        kid: kid.to_owned(),
+        alg: SecCtxAlgorithm::HmacSha256,
+        secret: Some(BASE64.encode([fill; 32])),
+        private_key: None,
+        public_key: None,
+        encryption_key: None,
+    }
+}
+
+fn signing_config(signing_key: &str, keys: Vec<SecCtxKeyConfig>) -> SecCtxCodecConfig {
+    SecCtxCodecConfig {
+        strict: true,
+        signing_key: Some(signing_key.to_owned()),
+        keys,
+        ..SecCtxCodecConfig::default()
+    }
+}
+
+#[test]
+fn hmac_envelope_round_trips() {
+    let codec = SecCtxCodec::new(&signing_config("k1", vec![hmac_key("k1", 1)])).unwrap();
+
+    let blob = codec.encode(&ctx(), AUDIENCE).unwrap();
+    assert_eq!(blob[0], SECCTX_SIGNED_BIN_VERSION);
+
+    let decoded = codec.decode(&blob, AUDIENCE).unwrap();
+    assert_eq!(decoded.tenant_id(), TENANT);
+}
+
+#[test]
+fn ed25519_receiver_verifies_with_public_key_only() {
+    let seed = [9u8; 32];
+    let public = ed25519_dalek::SigningKey::from_bytes(&seed)
+        .verifying_key()
+        .to_bytes();
+
+    let sender = SecCtxCodec::new(&signing_config(
+        "ed",
+        vec![SecCtxKeyConfig {
+            kid: "ed".to_owned(),
+            alg: SecCtxAlgorithm::Ed25519,
+            secret: None,
+            private_key: Some(BASE64.encode(seed)),
+            public_key: None,
+            encryption_key: None,
+        }],
+    ))
+    .unwrap();
+    let receiver = SecCtxCodec::new(&SecCtxCodecConfig {
+        strict: true,
+        keys: vec![SecCtxKeyConfig {
+            kid: "ed".to_owned(),
+            alg: SecCtxAlgorithm::Ed25519,
+            secret: None,
+            private_key: None,
+            public_key: Some(BASE64.encode(public)),
+            encryption_key: None,
+        }],
+        ..SecCtxCodecConfig::default()
+    })
+    .unwrap();
+
+    let blob = sender.encode(&ctx(), AUDIENCE).unwrap();
+    assert_eq!(
+        receiver.decode(&blob, AUDIENCE).unwrap().tenant_id(),
+        TENANT
+    );
+}
+
+#[test]
+fn encrypted_envelope_hides_payload() {
+    let mut key = hmac_key("k1", 1);
+    key.encryption_key = Some(BASE64.encode([3u8; 32]));
+    let mut cfg = signing_config("k1", vec![key]);
+    cfg.encrypt = true;
+    let codec = SecCtxCodec::new(&cfg).unwrap();
+
+    let blob = codec.encode(&ctx(), AUDIENCE).unwrap();
+    let plain = encode_bin(&ctx()).unwrap();
+    assert!(!blob.windows(16).any(|w| w == TENANT.as_bytes()));
+    assert!(!blob.windows(plain.len() - 1).any(|w| w == &plain[1..]));
+
+    assert_eq!(codec.decode(&blob, AUDIENCE).unwrap().tenant_id(), TENANT);
+}
+
+#[test]
+fn tampered_envelope_is_rejected() {
+    let codec = SecCtxCodec::new(&signing_config("k1", vec![hmac_key("k1", 1)])).unwrap();
+    let mut blob = codec.encode(&ctx(), AUDIENCE).unwrap();
+
+    let pos = blob
+        .windows(16)
+        .position(|w| w == TENANT.as_bytes())
+        .unwrap();
+    blob[pos + 15] ^= 0xFF;
+
+    assert!(matches!(
+        codec.decode(&blob, AUDIENCE),
+        Err(SecCtxCodecError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn envelope_is_bound_to_audience() {
+    let codec = SecCtxCodec::new(&signing_config("k1", vec![hmac_key("k1", 1)])).unwrap();
+    let blob = codec.encode(&ctx(), AUDIENCE).unwrap();
+
+    assert!(matches!(
+        codec.decode(&blob, "another-service"),
+        Err(SecCtxCodecError::AudienceMismatch { .. })
+    ));
+}
+
+#[test]
+fn strict_mode_rejects_unsigned_blobs() {
+    let v1 = encode_bin(&ctx()).unwrap();
+
+    let strict = SecCtxCodec::new(&signing_config("k1", vec![hmac_key("k1", 1)])).unwrap();
+    assert!(matches!(
+        strict.decode(&v1, AUDIENCE),
+        Err(SecCtxCodecError::UnsignedRejected)
+    ));
+
+    let lenient = SecCtxCodec::default();
+    assert!(!lenient.is_strict());
+    assert_eq!(lenient.decode(&v1, AUDIENCE).unwrap().tenant_id(), TENANT);
+    assert_eq!(lenient.encode(&ctx(), AUDIENCE).unwrap(), v1);
+}
+
+#[test]
+fn key_rotation_keeps_old_key_valid_until_removed() {
+    let receiver = SecCtxCodec::new(&SecCtxCodecConfig {
+        strict: true,
+        keys: vec![hmac_key("k1", 1)],
+        ..SecCtxCodecConfig::default()
+    })
+    .unwrap();
+    let old_sender = SecCtxCodec::new(&signing_config("k1", vec![hmac_key("k1", 1)])).unwrap();
+    let old_blob = old_sender.encode(&ctx(), AUDIENCE).unwrap();
+
+    // Roll out the new key alongside the old one, then switch the signer.
+    receiver
+        .reload(&SecCtxCodecConfig {
+            strict: true,
+            keys: vec![hmac_key("k1", 1), hmac_key("k2", 2)],
+            ..SecCtxCodecConfig::default()
+        })
+        .unwrap();
+    old_sender
+        .reload(&signing_config(
+            "k2",
+            vec![hmac_key("k1", 1), hmac_key("k2", 2)],
+        ))
+        .unwrap();
+    let new_blob = old_sender.encode(&ctx(), AUDIENCE).unwrap();
+
+    assert!(receiver.decode(&old_blob, AUDIENCE).is_ok());
+    assert!(receiver.decode(&new_blob, AUDIENCE).is_ok());
+
+    // Retire the old key.
+    receiver
+        .reload(&signing_config("k2", vec![hmac_key("k2", 2)]))
+        .unwrap();
+    assert!(matches!(
+        receiver.decode(&old_blob, AUDIENCE),
+        Err(SecCtxCodecError::UnknownKey(kid)) if kid == "k1"
+    ));
+    assert!(receiver.decode(&new_blob, AUDIENCE).is_ok());
+}
@@ -8,10 +8,12 @@
 //! **Note:** This module is responsible only for transport-level configuration.
 //! For RPC-level retry logic with exponential backoff, see the [`crate::rpc_retry`] module.
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tonic::transport::{Channel, Endpoint};
 use tracing::Instrument;
 
+use crate::metrics::ClientMetrics;
+
 fn duration_to_i64_ms(duration: Duration) -> i64 {
     i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
 }
@@ -146,6 +148,7 @@ fn build_endpoint(
 /// - Configurable connect and RPC timeouts
 /// - HTTP/2 keepalive for connection health
 /// - A tracing span around the connection attempt
+/// - Connection metrics (`grpc_client_connects_total`), unless disabled
 ///
 /// **Note:** This function does **not** perform retries or backoff at the transport level.
 /// For RPC-level retry logic, use [`crate::rpc_retry::call_with_retry`] after obtaining
@@ -193,7 +196,16 @@ where
 
     async move {
         let endpoint = build_endpoint(uri_string, cfg)?;
-        let channel = endpoint.connect().await?;
+        let started = Instant::now();
+        let connected = endpoint.connect().await;
+        if cfg.enable_metrics {
+            ClientMetrics::global().record_connect(
+                cfg.service_name,
+                connected.is_ok(),
+                started.elapsed(),
+            );
+        }
+        let channel = connected?;
 
         if cfg.enable_tracing {
             let connect_timeout_ms = duration_to_i64_ms(cfg.connect_timeout);
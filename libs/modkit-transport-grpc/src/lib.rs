@@ -1,5 +1,6 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 pub mod client;
+pub mod metrics;
 pub mod rpc_retry;
 
 #[cfg(windows)]
@@ -10,14 +11,17 @@ pub use windows_named_pipe::{NamedPipeConnection, NamedPipeIncoming, create_name
 
 pub const SECCTX_METADATA_KEY: &str = "x-secctx-bin";
 
-use modkit_security::{SecurityContext, decode_bin, encode_bin};
+use modkit_security::{SecCtxCodec, SecurityContext, decode_bin, encode_bin};
 use tonic::Status;
 use tonic::metadata::{MetadataMap, MetadataValue};
 
-/// Encode `SecurityContext` into gRPC metadata.
+/// Encode `SecurityContext` into gRPC metadata as an unsigned blob.
+///
+/// Only for calls inside a trusted network; servers in strict mode reject it.
 ///
 /// # Errors
 /// Returns `Status::internal` if encoding fails.
+#[deprecated(note = "sends an unsigned SecurityContext; use `attach_secctx_signed`")]
 pub fn attach_secctx(meta: &mut MetadataMap, ctx: &SecurityContext) -> Result<(), Status> {
     let encoded = encode_bin(ctx).map_err(|e| Status::internal(format!("secctx encode: {e}")))?;
 
@@ -27,16 +31,61 @@ pub fn attach_secctx(meta: &mut MetadataMap, ctx: &SecurityContext) -> Result<()
 
 /// Decode `SecurityContext` from gRPC metadata.
 ///
+/// This trusts whatever the caller sent, signed or not, so it is only safe when every
+/// caller is on a trusted network. Use [`extract_secctx_verified`] everywhere else.
+///
 /// # Errors
 /// Returns `Status::unauthenticated` if the metadata is missing or decoding fails.
+#[deprecated(note = "trusts unsigned SecurityContext blobs; use `extract_secctx_verified`")]
 pub fn extract_secctx(meta: &MetadataMap) -> Result<SecurityContext, Status> {
+    let bytes = secctx_bytes(meta)?;
+
+    decode_bin(bytes.as_ref()).map_err(|e| Status::unauthenticated(format!("secctx decode: {e}")))
+}
+
+/// Encode `SecurityContext` into gRPC metadata as an envelope addressed to `audience`.
+///
+/// The envelope is signed (and optionally encrypted) when `codec` has a signing key.
+///
+/// # Errors
+/// Returns `Status::internal` if encoding fails.
+pub fn attach_secctx_signed(
+    meta: &mut MetadataMap,
+    ctx: &SecurityContext,
+    codec: &SecCtxCodec,
+    audience: &str,
+) -> Result<(), Status> {
+    let encoded = codec
+        .encode(ctx, audience)
+        .map_err(|e| Status::internal(format!("secctx encode: {e}")))?;
+
+    meta.insert_bin(SECCTX_METADATA_KEY, MetadataValue::from_bytes(&encoded));
+    Ok(())
+}
+
+/// Decode and verify a `SecurityContext` addressed to `audience` from gRPC metadata.
+///
+/// # Errors
+/// Returns `Status::unauthenticated` if the metadata is missing, the envelope fails
+/// verification, or it is unsigned while `codec` is in strict mode.
+pub fn extract_secctx_verified(
+    meta: &MetadataMap,
+    codec: &SecCtxCodec,
+    audience: &str,
+) -> Result<SecurityContext, Status> {
+    let bytes = secctx_bytes(meta)?;
+
+    codec.decode(bytes.as_ref(), audience).map_err(|e| {
+        tracing::warn!(error = %e, audience, "rejected secctx metadata");
+        Status::unauthenticated(format!("secctx decode: {e}"))
+    })
+}
+
+fn secctx_bytes(meta: &MetadataMap) -> Result<tonic::codegen::Bytes, Status> {
     let raw = meta
         .get_bin(SECCTX_METADATA_KEY)
         .ok_or_else(|| Status::unauthenticated("missing secctx metadata"))?;
 
-    let bytes = raw
-        .to_bytes()
-        .map_err(|e| Status::unauthenticated(format!("invalid secctx metadata: {e}")))?;
-
-    decode_bin(bytes.as_ref()).map_err(|e| Status::unauthenticated(format!("secctx decode: {e}")))
+    raw.to_bytes()
+        .map_err(|e| Status::unauthenticated(format!("invalid secctx metadata: {e}")))
 }
@@ -0,0 +1,418 @@
+//! gRPC metrics recorded into the process-wide [`MetricsRegistry`].
+//!
+//! - [`GrpcServerMetricsLayer`] wraps a tonic server and records every call by
+//!   service, method and final status code.
+//! - The client side is recorded by [`crate::client::connect_with_stack`] and
+//!   [`crate::rpc_retry::call_with_retry`] unless metrics are disabled in their config.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock};
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, Instant};
+
+use http_body::{Body, Frame, SizeHint};
+use modkit_metrics::{Counter, DURATION_BUCKETS, Gauge, Histogram, MetricsRegistry};
+use pin_project_lite::pin_project;
+use tonic::Code;
+use tower::{Layer, Service};
+
+const GRPC_STATUS_HEADER: &str = "grpc-status";
+
+/// Canonical name of a gRPC status code, as used for the `grpc_code` label.
+#[must_use]
+pub fn code_name(code: Code) -> &'static str {
+    match code {
+        Code::Ok => "OK",
+        Code::Cancelled => "CANCELLED",
+        Code::Unknown => "UNKNOWN",
+        Code::InvalidArgument => "INVALID_ARGUMENT",
+        Code::DeadlineExceeded => "DEADLINE_EXCEEDED",
+        Code::NotFound => "NOT_FOUND",
+        Code::AlreadyExists => "ALREADY_EXISTS",
+        Code::PermissionDenied => "PERMISSION_DENIED",
+        Code::ResourceExhausted => "RESOURCE_EXHAUSTED",
+        Code::FailedPrecondition => "FAILED_PRECONDITION",
+        Code::Aborted => "ABORTED",
+        Code::OutOfRange => "OUT_OF_RANGE",
+        Code::Unimplemented => "UNIMPLEMENTED",
+        Code::Internal => "INTERNAL",
+        Code::Unavailable => "UNAVAILABLE",
+        Code::DataLoss => "DATA_LOSS",
+        Code::Unauthenticated => "UNAUTHENTICATED",
+    }
+}
+
+/// Split a gRPC request path (`/package.Service/Method`) into service and method.
+fn split_path(path: &str) -> (String, String) {
+    match path.trim_start_matches('/').split_once('/') {
+        Some((service, method)) => (service.to_owned(), method.to_owned()),
+        None => ("unknown".to_owned(), "unknown".to_owned()),
+    }
+}
+
+fn status_from_headers(headers: &http::HeaderMap) -> Option<Code> {
+    let value = headers.get(GRPC_STATUS_HEADER)?.to_str().ok()?;
+    value.parse::<i32>().ok().map(Code::from_i32)
+}
+
+/// Client-side instruments shared by the connect and call helpers.
+pub(crate) struct ClientMetrics {
+    connects: Counter,
+    connect_duration: Histogram,
+    handled: Counter,
+    handling_duration: Histogram,
+    retries: Counter,
+}
+
+static CLIENT_METRICS: LazyLock<ClientMetrics> = LazyLock::new(ClientMetrics::new);
+
+impl ClientMetrics {
+    /// Instruments registered in [`MetricsRegistry::global`].
+    pub(crate) fn global() -> &'static Self {
+        &CLIENT_METRICS
+    }
+
+    fn new() -> Self {
+        let registry = MetricsRegistry::global();
+        Self {
+            connects: registry.counter(
+                "grpc_client_connects_total",
+                "gRPC client connection attempts by target service and result",
+            ),
+            connect_duration: registry.histogram(
+                "grpc_client_connect_duration_seconds",
+                "Time to establish a gRPC client connection",
+                DURATION_BUCKETS,
+            ),
+            handled: registry.counter(
+                "grpc_client_handled_total",
+                "gRPC client calls completed, after retries, by operation and status code",
+            ),
+            handling_duration: registry.histogram(
+                "grpc_client_handling_seconds",
+                "gRPC client call latency including retries and backoff",
+                DURATION_BUCKETS,
+            ),
+            retries: registry.counter(
+                "grpc_client_retries_total",
+                "gRPC client call retries by operation",
+            ),
+        }
+    }
+
+    pub(crate) fn record_connect(&self, service: &str, ok: bool, elapsed: Duration) {
+        let result = if ok { "ok" } else { "error" };
+        self.connects
+            .inc(&[("grpc_service", service), ("result", result)]);
+        self.connect_duration
+            .observe(&[("grpc_service", service)], elapsed.as_secs_f64());
+    }
+
+    pub(crate) fn record_call(&self, op: &str, code: Code, elapsed: Duration) {
+        self.handled
+            .inc(&[("op", op), ("grpc_code", code_name(code))]);
+        self.handling_duration
+            .observe(&[("op", op)], elapsed.as_secs_f64());
+    }
+
+    pub(crate) fn record_retry(&self, op: &str) {
+        self.retries.inc(&[("op", op)]);
+    }
+}
+
+struct ServerInstruments {
+    handled: Counter,
+    handling_duration: Histogram,
+    in_flight: Gauge,
+}
+
+/// Tower layer recording gRPC server metrics:
+///
+/// - `grpc_server_handled_total{grpc_service, grpc_method, grpc_code}`
+/// - `grpc_server_handling_seconds{grpc_service, grpc_method}`
+/// - `grpc_server_in_flight{grpc_service, grpc_method}`
+///
+/// A call is complete when its response body ends; streams dropped before the
+/// final status are recorded as `CANCELLED`.
+#[derive(Clone)]
+pub struct GrpcServerMetricsLayer {
+    instruments: Arc<ServerInstruments>,
+}
+
+impl GrpcServerMetricsLayer {
+    /// Record into `registry`.
+    #[must_use]
+    pub fn new(registry: &MetricsRegistry) -> Self {
+        Self {
+            instruments: Arc::new(ServerInstruments {
+                handled: registry.counter(
+                    "grpc_server_handled_total",
+                    "gRPC server calls completed by service, method and status code",
+                ),
+                handling_duration: registry.histogram(
+                    "grpc_server_handling_seconds",
+                    "gRPC server call latency until the final status is sent",
+                    DURATION_BUCKETS,
+                ),
+                in_flight: registry.gauge(
+                    "grpc_server_in_flight",
+                    "gRPC server calls currently being handled",
+                ),
+            }),
+        }
+    }
+}
+
+impl Default for GrpcServerMetricsLayer {
+    /// Record into [`MetricsRegistry::global`].
+    fn default() -> Self {
+        Self::new(MetricsRegistry::global())
+    }
+}
+
+impl std::fmt::Debug for GrpcServerMetricsLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcServerMetricsLayer")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Layer<S> for GrpcServerMetricsLayer {
+    type Service = GrpcServerMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcServerMetrics {
+            inner,
+            instruments: Arc::clone(&self.instruments),
+        }
+    }
+}
+
+/// Service produced by [`GrpcServerMetricsLayer`].
+#[derive(Clone)]
+pub struct GrpcServerMetrics<S> {
+    inner: S,
+    instruments: Arc<ServerInstruments>,
+}
+
+impl<S> std::fmt::Debug for GrpcServerMetrics<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcServerMetrics").finish_non_exhaustive()
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for GrpcServerMetrics<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+{
+    type Response = http::Response<MeteredBody<ResBody>>;
+    type Error = S::Error;
+    type Future = MeteredFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let (service, method) = split_path(req.uri().path());
+        let guard = CallGuard::start(Arc::clone(&self.instruments), service, method);
+        MeteredFuture {
+            inner: self.inner.call(req),
+            guard: Some(guard),
+        }
+    }
+}
+
+/// Records one server call when dropped.
+struct CallGuard {
+    instruments: Arc<ServerInstruments>,
+    service: String,
+    method: String,
+    started: Instant,
+    code: Option<Code>,
+    body_finished: bool,
+}
+
+impl CallGuard {
+    fn start(instruments: Arc<ServerInstruments>, service: String, method: String) -> Self {
+        instruments.in_flight.inc(&[
+            ("grpc_service", service.as_str()),
+            ("grpc_method", method.as_str()),
+        ]);
+        Self {
+            instruments,
+            service,
+            method,
+            started: Instant::now(),
+            code: None,
+            body_finished: false,
+        }
+    }
+}
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        let code = self.code.unwrap_or(if self.body_finished {
+            Code::Ok
+        } else {
+            Code::Cancelled
+        });
+        let labels = [
+            ("grpc_service", self.service.as_str()),
+            ("grpc_method", self.method.as_str()),
+        ];
+        self.instruments.in_flight.dec(&labels);
+        self.instruments
+            .handling_duration
+            .observe(&labels, self.started.elapsed().as_secs_f64());
+        self.instruments
+            .handled
+            .inc(&[labels[0], labels[1], ("grpc_code", code_name(code))]);
+    }
+}
+
+pin_project! {
+    /// Response future of [`GrpcServerMetrics`].
+    pub struct MeteredFuture<F> {
+        #[pin]
+        inner: F,
+        guard: Option<CallGuard>,
+    }
+}
+
+impl<F, ResBody, E> Future for MeteredFuture<F>
+where
+    F: Future<Output = Result<http::Response<ResBody>, E>>,
+{
+    type Output = Result<http::Response<MeteredBody<ResBody>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = ready!(this.inner.poll(cx));
+        let mut guard = this.guard.take();
+        Poll::Ready(match result {
+            Ok(response) => {
+                // Trailers-only responses carry the status in the headers
+                if let Some(guard) = guard.as_mut() {
+                    guard.code = status_from_headers(response.headers());
+                }
+                Ok(response.map(|inner| MeteredBody { inner, guard }))
+            }
+            Err(err) => {
+                if let Some(guard) = guard.as_mut() {
+                    guard.code = Some(Code::Internal);
+                }
+                Err(err)
+            }
+        })
+    }
+}
+
+pin_project! {
+    /// Response body of [`GrpcServerMetrics`]; records the call once the final status is seen.
+    pub struct MeteredBody<B> {
+        #[pin]
+        inner: B,
+        guard: Option<CallGuard>,
+    }
+}
+
+impl<B: Body> Body for MeteredBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = ready!(this.inner.poll_frame(cx));
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(code) = frame.trailers_ref().and_then(status_from_headers)
+                    && let Some(mut guard) = this.guard.take()
+                {
+                    guard.code = Some(code);
+                }
+            }
+            Some(Err(_)) => {
+                if let Some(mut guard) = this.guard.take() {
+                    guard.code.get_or_insert(Code::Internal);
+                }
+            }
+            None => {
+                if let Some(mut guard) = this.guard.take() {
+                    guard.body_finished = true;
+                }
+            }
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    #[test]
+    fn split_path_extracts_service_and_method() {
+        assert_eq!(
+            split_path("/users.v1.UsersService/GetUser"),
+            ("users.v1.UsersService".to_owned(), "GetUser".to_owned())
+        );
+        assert_eq!(
+            split_path("/"),
+            ("unknown".to_owned(), "unknown".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn server_layer_records_trailers_only_status() {
+        let registry = MetricsRegistry::new();
+        let mut svc = GrpcServerMetricsLayer::new(&registry).layer(tower::service_fn(
+            |_req: http::Request<()>| async {
+                let response = http::Response::builder()
+                    .header(GRPC_STATUS_HEADER, "5")
+                    .body(String::new())
+                    .unwrap();
+                Ok::<_, Infallible>(response)
+            },
+        ));
+
+        let req = http::Request::builder()
+            .uri("/users.v1.UsersService/GetUser")
+            .body(())
+            .unwrap();
+        let response = svc.call(req).await.unwrap();
+        drop(response);
+
+        let handled = registry.counter("grpc_server_handled_total", "");
+        assert_eq!(
+            handled.get(&[
+                ("grpc_service", "users.v1.UsersService"),
+                ("grpc_method", "GetUser"),
+                ("grpc_code", "NOT_FOUND"),
+            ]),
+            1
+        );
+        let in_flight = registry.gauge("grpc_server_in_flight", "");
+        assert_eq!(
+            in_flight.get(&[
+                ("grpc_service", "users.v1.UsersService"),
+                ("grpc_method", "GetUser"),
+            ]),
+            0
+        );
+    }
+}
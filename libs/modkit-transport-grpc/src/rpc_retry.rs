@@ -42,12 +42,14 @@
 //! ```
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use tokio::time::sleep;
 use tonic::{Code, Status};
 use tracing::Instrument;
 
+use crate::metrics::ClientMetrics;
+
 fn duration_to_i64_ms(duration: Duration) -> i64 {
     i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
 }
@@ -70,6 +72,9 @@ pub struct RpcRetryConfig {
 
     /// Maximum duration for exponential backoff.
     pub max_backoff: Duration,
+
+    /// Record call, latency and retry metrics.
+    pub enable_metrics: bool,
 }
 
 impl Default for RpcRetryConfig {
@@ -78,6 +83,7 @@ impl Default for RpcRetryConfig {
             max_retries: 3,
             base_backoff: Duration::from_millis(100),
             max_backoff: Duration::from_secs(5),
+            enable_metrics: true,
         }
     }
 }
@@ -88,6 +94,7 @@ impl From<&crate::client::GrpcClientConfig> for RpcRetryConfig {
             max_retries: cfg.max_retries,
             base_backoff: cfg.base_backoff,
             max_backoff: cfg.max_backoff,
+            enable_metrics: cfg.enable_metrics,
         }
     }
 }
@@ -112,6 +119,12 @@ impl RpcRetryConfig {
         self.max_backoff = duration;
         self
     }
+
+    /// Disable metrics collection.
+    pub fn without_metrics(mut self) -> Self {
+        self.enable_metrics = false;
+        self
+    }
 }
 
 /// Generic helper for unary gRPC calls with retries.
@@ -125,7 +138,8 @@ impl RpcRetryConfig {
 /// * `cfg` - Shared retry configuration
 /// * `req` - Request payload (must implement `Clone` for retry attempts)
 /// * `call` - Closure that performs the actual RPC call
-/// * `op_name` - Static name of the operation for logging/tracing (e.g., `"my_service.my_method"`)
+/// * `op_name` - Static name of the operation for logging, tracing and metrics
+///   (e.g., `"my_service.my_method"`)
 ///
 /// # Type Parameters
 ///
@@ -167,6 +181,8 @@ where
     Req: Clone,
 {
     let mut attempt: u32 = 0;
+    let started = Instant::now();
+    let metrics = cfg.enable_metrics.then(ClientMetrics::global);
 
     loop {
         attempt += 1;
@@ -194,6 +210,9 @@ where
                 if attempt > 1 {
                     tracing::info!(op = op_name, attempt, "gRPC call succeeded after retries");
                 }
+                if let Some(metrics) = metrics {
+                    metrics.record_call(op_name, Code::Ok, started.elapsed());
+                }
                 return Ok(res);
             }
             Err(status) => {
@@ -209,6 +228,9 @@ where
                         code = ?code,
                         "gRPC call giving up"
                     );
+                    if let Some(metrics) = metrics {
+                        metrics.record_call(op_name, code, started.elapsed());
+                    }
                     return Err(status);
                 }
 
@@ -225,6 +247,9 @@ where
                     "Retrying gRPC call after backoff"
                 );
 
+                if let Some(metrics) = metrics {
+                    metrics.record_retry(op_name);
+                }
                 sleep(backoff).await;
             }
         }
@@ -428,4 +453,42 @@ mod tests {
             "Backoff should be capped; elapsed: {elapsed:?}"
         );
     }
+
+    #[tokio::test]
+    async fn test_call_with_retry_records_metrics() {
+        use modkit_metrics::MetricsRegistry;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let attempts = AtomicU32::new(0);
+        let mut client = ();
+        let cfg = Arc::new(RpcRetryConfig::new(3).with_base_backoff(Duration::from_millis(1)));
+
+        let result = call_with_retry(
+            &mut client,
+            cfg,
+            (),
+            |_c, _req| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if attempt < 2 {
+                        Err(Status::unavailable("temporarily unavailable"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+            "test.metrics_op",
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let registry = MetricsRegistry::global();
+        let handled = registry.counter("grpc_client_handled_total", "");
+        let retries = registry.counter("grpc_client_retries_total", "");
+        assert_eq!(
+            handled.get(&[("op", "test.metrics_op"), ("grpc_code", "OK")]),
+            1
+        );
+        assert_eq!(retries.get(&[("op", "test.metrics_op")]), 1);
+    }
 }
@@ -1,7 +1,8 @@
 use axum::extract::{FromRequestParts, Query};
 use axum::http::request::Parts;
-use modkit_odata::{CursorV1, Error as ODataError, ODataOrderBy, OrderKey, SortDir};
+use modkit_odata::{CursorV1, Error as ODataError, ODataLimits, ODataOrderBy, OrderKey, SortDir};
 use serde::Deserialize;
+use std::sync::Arc;
 
 // Re-export types from modkit-odata for convenience and better DX
 pub use modkit_odata::ODataQuery;
@@ -137,6 +138,7 @@ pub fn parse_orderby(raw: &str) -> Result<ODataOrderBy, modkit_odata::Error> {
 /// Extract and validate full `OData` query from request parts.
 /// - Parses $filter, $orderby, limit, cursor
 /// - Enforces budgets and validates formats
+/// - Verifies cursor signatures when an `Arc<ODataLimits>` request extension is present
 /// - Returns unified `ODataQuery`
 ///
 /// # Errors
@@ -153,7 +155,12 @@ where
         .await
         .unwrap_or_else(|_| Query(ODataParams::default()));
 
+    let limits = parts.extensions.get::<Arc<ODataLimits>>().cloned();
+
     let mut query = ODataQuery::new();
+    if let Some(limits) = limits.as_ref() {
+        query = query.with_limits(Arc::clone(limits));
+    }
 
     // Parse filter
     if let Some(raw_filter) = params.filter.as_ref() {
@@ -206,8 +213,16 @@ where
 
     // Parse cursor first (if present, skip orderby)
     if let Some(cursor_str) = params.cursor.as_ref() {
-        let cursor = CursorV1::decode(cursor_str).map_err(|_| {
-            crate::api::odata::odata_error_to_problem(&ODataError::InvalidCursor, "/", None)
+        let decoded = match limits.as_deref() {
+            Some(limits) => CursorV1::decode_with(cursor_str, limits),
+            None => CursorV1::decode(cursor_str),
+        };
+        let cursor = decoded.map_err(|e| {
+            let err = match e {
+                ODataError::CursorInvalidSignature => e,
+                _ => ODataError::InvalidCursor,
+            };
+            crate::api::odata::odata_error_to_problem(&err, "/", None)
         })?;
         query = query.with_cursor(cursor);
         // When cursor is present, order is empty (derived from cursor.s later)
@@ -128,6 +128,10 @@ impl<S> HandlerSlot<S> for Present {
 
 pub use state::{AuthNotSet, AuthSet, LicenseNotSet, LicenseSet, Missing, Present};
 
+/// Description of the `Last-Event-ID` header of resumable SSE operations.
+const RESUME_HEADER_DESCRIPTION: &str = "Id of the last event received. Later events are replayed \
+     first, preceded by a `gap` event if some of them are no longer retained.";
+
 /// Parameter specification for API operations
 #[derive(Clone, Debug)]
 pub struct ParamSpec {
@@ -572,6 +576,23 @@ where
         self
     }
 
+    /// Add a header parameter (string)
+    pub fn header_param(
+        mut self,
+        name: impl Into<String>,
+        required: bool,
+        description: impl Into<String>,
+    ) -> Self {
+        self.spec.params.push(ParamSpec {
+            name: name.into(),
+            location: ParamLocation::Header,
+            required,
+            description: Some(description.into()),
+            param_type: "string".to_owned(),
+        });
+        self
+    }
+
     /// Add a typed query parameter with explicit `OpenAPI` type
     pub fn query_param_typed(
         mut self,
@@ -1210,6 +1231,27 @@ where
         }
     }
 
+    /// First response: resumable SSE stream of JSON events, see
+    /// [`crate::http::sse_replay::ResumableSseBroadcaster`].
+    ///
+    /// Documents the optional `Last-Event-ID` request header in addition to [`Self::sse_json`].
+    pub fn sse_json_resumable<T>(
+        self,
+        openapi: &dyn OpenApiRegistry,
+        description: impl Into<String>,
+    ) -> OperationBuilder<H, Present, S, A, L>
+    where
+        T: utoipa::ToSchema + utoipa::PartialSchema + 'static,
+    {
+        ensure_schema::<crate::http::sse_replay::SseGap>(openapi);
+        self.header_param(
+            crate::http::sse_replay::LAST_EVENT_ID,
+            false,
+            RESUME_HEADER_DESCRIPTION,
+        )
+        .sse_json::<T>(openapi, description)
+    }
+
     /// First response: SSE stream of JSON events (`text/event-stream`).
     pub fn sse_json<T>(
         mut self,
@@ -1341,6 +1383,24 @@ where
         self
     }
 
+    /// Additional resumable SSE response; see the first-response variant.
+    pub fn sse_json_resumable<T>(
+        self,
+        openapi: &dyn OpenApiRegistry,
+        description: impl Into<String>,
+    ) -> Self
+    where
+        T: utoipa::ToSchema + utoipa::PartialSchema + 'static,
+    {
+        ensure_schema::<crate::http::sse_replay::SseGap>(openapi);
+        self.header_param(
+            crate::http::sse_replay::LAST_EVENT_ID,
+            false,
+            RESUME_HEADER_DESCRIPTION,
+        )
+        .sse_json::<T>(openapi, description)
+    }
+
     /// Additional SSE response (if the operation already has a response).
     pub fn sse_json<T>(
         mut self,
@@ -1971,6 +2031,35 @@ mod tests {
         assert!(validation_response.schema_name.is_some());
     }
 
+    #[test]
+    fn sse_json_resumable_documents_last_event_id() {
+        let registry = MockRegistry::new();
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/events")
+            .public()
+            .handler(test_handler)
+            .sse_json_resumable::<serde_json::Value>(&registry, "Event stream");
+
+        let header = builder
+            .spec
+            .params
+            .iter()
+            .find(|p| p.location == ParamLocation::Header)
+            .expect("Should document the Last-Event-ID header");
+        assert_eq!(header.name, "Last-Event-ID");
+        assert!(!header.required);
+
+        assert_eq!(builder.spec.responses.len(), 1);
+        assert_eq!(builder.spec.responses[0].content_type, "text/event-stream");
+        assert!(
+            registry
+                .schemas
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|name| name.contains("SseGap"))
+        );
+    }
+
     #[test]
     fn allow_content_types_with_existing_request_body() {
         let registry = MockRegistry::new();
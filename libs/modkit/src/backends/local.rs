@@ -5,16 +5,19 @@ use async_trait::async_trait;
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::path::Path;
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::process::{Child, Command};
+use tokio::sync::broadcast;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use super::log_forwarder::{StreamKind, spawn_stream_forwarder};
-use super::{BackendKind, InstanceHandle, ModuleRuntimeBackend, OopModuleConfig};
+use super::restart::{RestartDecision, RestartTracker};
+use super::{BackendKind, InstanceHandle, ModuleRuntimeBackend, OopInstanceEvent, OopModuleConfig};
+use crate::runtime::{InstanceState, MODKIT_INSTANCE_ID_ENV, ModuleManager};
 
 /// Grace period before force-killing processes on shutdown
 const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
@@ -25,6 +28,21 @@ const INSTANCE_STOP_GRACE_PERIOD: Duration = Duration::from_secs(2);
 /// Timeout for waiting on forwarder tasks during shutdown
 const FORWARDER_DRAIN_TIMEOUT: Duration = Duration::from_millis(100);
 
+/// Interval at which the supervisor polls child processes for unexpected exits
+const SUPERVISE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Capacity of the lifecycle event channel
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// How long a rolling restart waits by default for a replacement to become healthy in the directory
+const ROLLING_READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a replaced instance stays draining before it is stopped, so in-flight calls finish
+const ROLLING_DRAIN_PERIOD: Duration = Duration::from_secs(1);
+
+/// Interval at which a rolling restart polls the directory for the replacement's health
+const ROLLING_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Send graceful termination signal to a child process.
 ///
 /// # Returns
@@ -153,6 +171,10 @@ struct LocalInstance {
     stdout_forwarder: Option<JoinHandle<()>>,
     /// Task handle for stderr log forwarder
     stderr_forwarder: Option<JoinHandle<()>>,
+    /// Spawn configuration, kept to respawn the instance under its restart policy
+    config: OopModuleConfig,
+    /// Restart history inherited from the instances this one replaced
+    restarts: RestartTracker,
 }
 
 /// Map key type for instances - uses Uuid directly
@@ -160,13 +182,19 @@ type InstanceMap = HashMap<Uuid, LocalInstance>;
 
 /// Backend that spawns modules as local child processes and manages their lifecycle.
 ///
+/// A supervisor task watches for processes that exit without `stop_instance`, emits
+/// [`OopInstanceEvent`]s and respawns them according to the module's restart policy.
+///
 /// When the cancellation token is triggered, the backend will:
 /// 1. Send termination signal to all processes (SIGTERM on Unix, `TerminateProcess` on Windows)
 /// 2. Wait up to 5 seconds for graceful shutdown
 /// 3. Force kill any remaining processes
 pub struct LocalProcessBackend {
     instances: Arc<RwLock<InstanceMap>>,
+    events: broadcast::Sender<OopInstanceEvent>,
     cancel: CancellationToken,
+    /// How long a rolling restart waits for a replacement to become healthy
+    rolling_ready_timeout: Duration,
 }
 
 impl LocalProcessBackend {
@@ -175,11 +203,22 @@ impl LocalProcessBackend {
     /// When the token is cancelled, all spawned processes will be gracefully stopped.
     #[must_use]
     pub fn new(cancel: CancellationToken) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
         let backend = Self {
             instances: Arc::new(RwLock::new(HashMap::new())),
+            events,
             cancel: cancel.clone(),
+            rolling_ready_timeout: ROLLING_READY_TIMEOUT,
         };
 
+        // Spawn background task to supervise running processes
+        let supervisor = Supervisor {
+            instances: Arc::clone(&backend.instances),
+            events: backend.events.clone(),
+            cancel: cancel.clone(),
+        };
+        tokio::spawn(supervisor.run());
+
         // Spawn background task to handle shutdown
         let instances = Arc::clone(&backend.instances);
         tokio::spawn(async move {
@@ -191,51 +230,106 @@ impl LocalProcessBackend {
         backend
     }
 
-    /// Gracefully stop all tracked instances with timeout.
-    async fn shutdown_all_instances(instances: Arc<RwLock<InstanceMap>>) {
-        let mut all_instances: Vec<LocalInstance> = {
-            let mut guard = instances.write();
-            guard.drain().map(|(_, inst)| inst).collect()
-        };
+    /// Override how long a rolling restart waits for a replacement to become healthy (default 30s).
+    #[must_use]
+    pub fn with_rolling_ready_timeout(mut self, timeout: Duration) -> Self {
+        self.rolling_ready_timeout = timeout;
+        self
+    }
 
-        if all_instances.is_empty() {
-            return;
+    /// Subscribe to lifecycle events (exits, restarts, give-ups) of supervised instances.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<OopInstanceEvent> {
+        self.events.subscribe()
+    }
+
+    /// Replace every running instance of `module` one at a time.
+    ///
+    /// For each instance a replacement is spawned first; once it reports healthy in
+    /// `directory`, the old instance is marked draining (so it no longer receives new
+    /// calls), stopped after a short drain period and deregistered. If a replacement
+    /// does not become healthy in time it is stopped and deregistered and the restart is
+    /// aborted, leaving the remaining old instances untouched.
+    ///
+    /// # Errors
+    /// Returns an error if the module has no running instances, a replacement fails to
+    /// spawn, or a replacement does not become healthy.
+    pub async fn rolling_restart(&self, module: &str, directory: &ModuleManager) -> Result<()> {
+        let old: Vec<(InstanceHandle, OopModuleConfig)> = self
+            .instances
+            .read()
+            .values()
+            .filter(|inst| inst.handle.module == module)
+            .map(|inst| (inst.handle.clone(), inst.config.clone()))
+            .collect();
+        if old.is_empty() {
+            bail!("no running instances of module '{module}' to restart");
         }
 
-        tracing::info!(count = all_instances.len(), "Stopping OoP module processes");
+        tracing::info!(module = %module, instances = old.len(), "Starting rolling restart");
 
-        // Stop all processes with grace period
-        for inst in &mut all_instances {
-            stop_child_with_grace(
-                &mut inst.child,
-                &inst.handle,
-                SHUTDOWN_GRACE_PERIOD,
-                "shutdown",
-            )
-            .await;
-        }
+        for (old_handle, config) in old {
+            let replacement = Self::spawn_process(&config, &self.cancel)?;
+            let new_handle = replacement.handle.clone();
+            self.instances
+                .write()
+                .insert(new_handle.instance_id, replacement);
 
-        // Wait for forwarders to drain
-        for inst in all_instances {
-            wait_forwarder(inst.stdout_forwarder).await;
-            wait_forwarder(inst.stderr_forwarder).await;
+            if !self
+                .wait_until_serving(directory, module, new_handle.instance_id)
+                .await
+            {
+                let stopped = self.stop_instance(&new_handle).await;
+                directory.deregister(module, new_handle.instance_id);
+                stopped?;
+                bail!(
+                    "replacement instance {} of module '{module}' did not become healthy within {:?}; rolling restart aborted",
+                    new_handle.instance_id,
+                    self.rolling_ready_timeout
+                );
+            }
+
+            directory.mark_draining(module, old_handle.instance_id);
+            tokio::time::sleep(ROLLING_DRAIN_PERIOD).await;
+            self.stop_instance(&old_handle).await?;
+            directory.deregister(module, old_handle.instance_id);
+
+            tracing::info!(
+                module = %module,
+                old_instance_id = %old_handle.instance_id,
+                new_instance_id = %new_handle.instance_id,
+                "Replaced OoP module instance"
+            );
         }
 
-        tracing::info!("All OoP module processes stopped");
+        Ok(())
     }
-}
 
-#[async_trait]
-impl ModuleRuntimeBackend for LocalProcessBackend {
-    async fn spawn_instance(&self, cfg: &OopModuleConfig) -> Result<InstanceHandle> {
-        // Verify backend kind
-        if cfg.backend != BackendKind::LocalProcess {
-            bail!(
-                "LocalProcessBackend can only spawn LocalProcess instances, got {:?}",
-                cfg.backend
-            );
+    /// Wait until `instance_id` is healthy or ready in the directory.
+    async fn wait_until_serving(
+        &self,
+        directory: &ModuleManager,
+        module: &str,
+        instance_id: Uuid,
+    ) -> bool {
+        let deadline = Instant::now() + self.rolling_ready_timeout;
+        loop {
+            let serving = directory.instances_of(module).iter().any(|inst| {
+                inst.instance_id == instance_id
+                    && matches!(inst.state(), InstanceState::Healthy | InstanceState::Ready)
+            });
+            if serving {
+                return true;
+            }
+            if Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(ROLLING_POLL_INTERVAL).await;
         }
+    }
 
+    /// Spawn a process for `cfg` with log forwarding; the caller tracks the returned instance.
+    fn spawn_process(cfg: &OopModuleConfig, cancel: &CancellationToken) -> Result<LocalInstance> {
         // Ensure binary is set
         let binary = cfg
             .binary
@@ -245,10 +339,11 @@ impl ModuleRuntimeBackend for LocalProcessBackend {
         // Generate unique instance ID using UUID v7
         let instance_id = Uuid::now_v7();
 
-        // Build command
+        // Build command; the instance ID is passed down so the module registers under it
         let mut cmd = Command::new(binary);
         cmd.args(&cfg.args);
         cmd.envs(&cfg.env);
+        cmd.env(MODKIT_INSTANCE_ID_ENV, instance_id.to_string());
 
         // Pipe stdout/stderr for log forwarding
         cmd.stdout(Stdio::piped());
@@ -278,7 +373,6 @@ impl ModuleRuntimeBackend for LocalProcessBackend {
 
         // Spawn log forwarder tasks for stdout/stderr with cancellation support
         let module_name = cfg.name.clone();
-        let cancel = self.cancel.clone();
         let stdout_forwarder = child.stdout.take().map(|stdout| {
             spawn_stream_forwarder(
                 stdout,
@@ -305,29 +399,266 @@ impl ModuleRuntimeBackend for LocalProcessBackend {
             "Spawned OoP module with log forwarding"
         );
 
-        // Create handle
-        let handle = InstanceHandle {
-            module: cfg.name.clone(),
-            instance_id,
-            backend: BackendKind::LocalProcess,
-            pid,
-            created_at: std::time::Instant::now(),
+        Ok(LocalInstance {
+            handle: InstanceHandle {
+                module: cfg.name.clone(),
+                instance_id,
+                backend: BackendKind::LocalProcess,
+                pid,
+                created_at: Instant::now(),
+            },
+            child,
+            stdout_forwarder,
+            stderr_forwarder,
+            config: cfg.clone(),
+            restarts: RestartTracker::default(),
+        })
+    }
+
+    /// Gracefully stop all tracked instances with timeout.
+    async fn shutdown_all_instances(instances: Arc<RwLock<InstanceMap>>) {
+        let mut all_instances: Vec<LocalInstance> = {
+            let mut guard = instances.write();
+            guard.drain().map(|(_, inst)| inst).collect()
         };
 
-        // Store in instances map
-        {
-            let mut instances = self.instances.write();
-            instances.insert(
-                instance_id,
-                LocalInstance {
-                    handle: handle.clone(),
-                    child,
-                    stdout_forwarder,
-                    stderr_forwarder,
-                },
+        if all_instances.is_empty() {
+            return;
+        }
+
+        tracing::info!(count = all_instances.len(), "Stopping OoP module processes");
+
+        // Stop all processes with grace period
+        for inst in &mut all_instances {
+            stop_child_with_grace(
+                &mut inst.child,
+                &inst.handle,
+                SHUTDOWN_GRACE_PERIOD,
+                "shutdown",
+            )
+            .await;
+        }
+
+        // Wait for forwarders to drain
+        for inst in all_instances {
+            wait_forwarder(inst.stdout_forwarder).await;
+            wait_forwarder(inst.stderr_forwarder).await;
+        }
+
+        tracing::info!("All OoP module processes stopped");
+    }
+}
+
+/// Shared state of the supervisor task and the restart tasks it spawns.
+#[derive(Clone)]
+struct Supervisor {
+    instances: Arc<RwLock<InstanceMap>>,
+    events: broadcast::Sender<OopInstanceEvent>,
+    cancel: CancellationToken,
+}
+
+impl Supervisor {
+    /// Poll tracked processes and hand every unexpected exit to `handle_exit`.
+    async fn run(self) {
+        loop {
+            tokio::select! {
+                () = self.cancel.cancelled() => break,
+                () = tokio::time::sleep(SUPERVISE_INTERVAL) => {}
+            }
+
+            for (inst, status) in self.reap_exited() {
+                tokio::spawn(self.clone().handle_exit(inst, status));
+            }
+        }
+    }
+
+    fn emit(&self, event: OopInstanceEvent) {
+        // No subscribers is fine: events are informational
+        let _ = self.events.send(event);
+    }
+
+    /// Remove and return instances whose process has exited.
+    fn reap_exited(&self) -> Vec<(LocalInstance, ExitStatus)> {
+        let mut guard = self.instances.write();
+        let exited: Vec<(Uuid, ExitStatus)> = guard
+            .iter_mut()
+            .filter_map(|(id, inst)| match inst.child.try_wait() {
+                Ok(Some(status)) => Some((*id, status)),
+                Ok(None) => None,
+                Err(e) => {
+                    tracing::warn!(
+                        module = %inst.handle.module,
+                        instance_id = %id,
+                        error = %e,
+                        "Failed to poll OoP module process"
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        exited
+            .into_iter()
+            .filter_map(|(id, status)| guard.remove(&id).map(|inst| (inst, status)))
+            .collect()
+    }
+
+    /// Report an unexpected exit and respawn the instance if its restart policy allows.
+    async fn handle_exit(self, inst: LocalInstance, status: ExitStatus) {
+        let LocalInstance {
+            handle,
+            config,
+            mut restarts,
+            ..
+        } = inst;
+        let mut uptime = handle.created_at.elapsed();
+        let mut success = status.success();
+
+        tracing::warn!(
+            module = %handle.module,
+            instance_id = %handle.instance_id,
+            status = %status,
+            uptime_ms = uptime.as_millis(),
+            "OoP module process exited unexpectedly"
+        );
+        self.emit(OopInstanceEvent::Exited {
+            module: handle.module.clone(),
+            instance_id: handle.instance_id,
+            exit_code: status.code(),
+            success,
+        });
+
+        loop {
+            let (attempt, delay) =
+                match restarts.on_exit(&config.restart, success, uptime, Instant::now()) {
+                    RestartDecision::Restart { attempt, delay } => (attempt, delay),
+                    RestartDecision::Stop => {
+                        tracing::info!(
+                            module = %handle.module,
+                            instance_id = %handle.instance_id,
+                            mode = ?config.restart.mode,
+                            "Restart policy does not restart this exit"
+                        );
+                        return;
+                    }
+                    RestartDecision::GiveUp(reason) => {
+                        tracing::error!(
+                            module = %handle.module,
+                            instance_id = %handle.instance_id,
+                            reason = %reason,
+                            "Giving up restarting OoP module"
+                        );
+                        self.emit(OopInstanceEvent::GaveUp {
+                            module: handle.module.clone(),
+                            instance_id: handle.instance_id,
+                            reason: reason.to_string(),
+                        });
+                        return;
+                    }
+                };
+
+            tracing::info!(
+                module = %handle.module,
+                instance_id = %handle.instance_id,
+                attempt,
+                delay_ms = delay.as_millis(),
+                "Scheduling OoP module restart"
             );
+            self.emit(OopInstanceEvent::RestartScheduled {
+                module: handle.module.clone(),
+                instance_id: handle.instance_id,
+                attempt,
+                delay,
+            });
+
+            tokio::select! {
+                () = self.cancel.cancelled() => return,
+                () = tokio::time::sleep(delay) => {}
+            }
+
+            match self.respawn(&config, &restarts).await {
+                Ok(Some(new_instance_id)) => {
+                    tracing::info!(
+                        module = %handle.module,
+                        old_instance_id = %handle.instance_id,
+                        new_instance_id = %new_instance_id,
+                        attempt,
+                        "Restarted OoP module"
+                    );
+                    self.emit(OopInstanceEvent::Restarted {
+                        module: handle.module.clone(),
+                        old_instance_id: handle.instance_id,
+                        new_instance_id,
+                        attempt,
+                    });
+                    return;
+                }
+                Ok(None) => return,
+                Err(e) => {
+                    tracing::warn!(
+                        module = %handle.module,
+                        attempt,
+                        error = %e,
+                        "Failed to respawn OoP module"
+                    );
+                    success = false;
+                    uptime = Duration::ZERO;
+                }
+            }
+        }
+    }
+
+    /// Spawn and track a replacement instance carrying the restart history.
+    ///
+    /// Returns `None` if the backend shut down while the replacement was spawning.
+    async fn respawn(
+        &self,
+        config: &OopModuleConfig,
+        restarts: &RestartTracker,
+    ) -> Result<Option<Uuid>> {
+        let mut replacement = LocalProcessBackend::spawn_process(config, &self.cancel)?;
+        replacement.restarts = restarts.clone();
+        let instance_id = replacement.handle.instance_id;
+        self.instances.write().insert(instance_id, replacement);
+
+        // Shutdown may have drained the map before the replacement was inserted
+        let orphan = if self.cancel.is_cancelled() {
+            self.instances.write().remove(&instance_id)
+        } else {
+            None
+        };
+        if let Some(mut orphan) = orphan {
+            stop_child_with_grace(
+                &mut orphan.child,
+                &orphan.handle,
+                INSTANCE_STOP_GRACE_PERIOD,
+                "restart",
+            )
+            .await;
+            return Ok(None);
         }
 
+        Ok(Some(instance_id))
+    }
+}
+
+#[async_trait]
+impl ModuleRuntimeBackend for LocalProcessBackend {
+    async fn spawn_instance(&self, cfg: &OopModuleConfig) -> Result<InstanceHandle> {
+        // Verify backend kind
+        if cfg.backend != BackendKind::LocalProcess {
+            bail!(
+                "LocalProcessBackend can only spawn LocalProcess instances, got {:?}",
+                cfg.backend
+            );
+        }
+
+        let instance = Self::spawn_process(cfg, &self.cancel)?;
+        let handle = instance.handle.clone();
+
+        // Store in instances map
+        self.instances.write().insert(handle.instance_id, instance);
+
         Ok(handle)
     }
 
@@ -375,8 +706,9 @@ impl ModuleRuntimeBackend for LocalProcessBackend {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(unix)]
+    use crate::backends::{RestartMode, RestartPolicy};
     use std::path::PathBuf;
-    use std::time::Instant;
 
     fn test_backend() -> LocalProcessBackend {
         LocalProcessBackend::new(CancellationToken::new())
@@ -538,6 +870,287 @@ mod tests {
         assert_eq!(instances.len(), 0);
     }
 
+    #[cfg(unix)]
+    async fn next_event(rx: &mut broadcast::Receiver<OopInstanceEvent>) -> OopInstanceEvent {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("event should arrive in time")
+            .expect("channel should be open")
+    }
+
+    #[cfg(unix)]
+    fn failing_module(restart: RestartPolicy) -> OopModuleConfig {
+        let mut cfg = OopModuleConfig::new("crashy", BackendKind::LocalProcess);
+        cfg.binary = Some(PathBuf::from("/bin/sh"));
+        cfg.args = vec!["-c".to_owned(), "exit 3".to_owned()];
+        cfg.restart = restart;
+        cfg
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_unexpected_exit_without_restart_policy_is_reported() {
+        let backend = test_backend();
+        let mut events = backend.subscribe();
+
+        let handle = backend
+            .spawn_instance(&failing_module(RestartPolicy::default()))
+            .await
+            .expect("should spawn instance");
+
+        assert_eq!(
+            next_event(&mut events).await,
+            OopInstanceEvent::Exited {
+                module: "crashy".to_owned(),
+                instance_id: handle.instance_id,
+                exit_code: Some(3),
+                success: false,
+            }
+        );
+        assert!(backend.list_instances("crashy").await.unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_failing_instance_is_restarted_until_policy_gives_up() {
+        let backend = test_backend();
+        let mut events = backend.subscribe();
+
+        let handle = backend
+            .spawn_instance(&failing_module(RestartPolicy {
+                mode: RestartMode::OnFailure,
+                max_restarts: 1,
+                initial_backoff_ms: 10,
+                max_backoff_ms: 10,
+                ..Default::default()
+            }))
+            .await
+            .expect("should spawn instance");
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            OopInstanceEvent::Exited { instance_id, .. } if instance_id == handle.instance_id
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            OopInstanceEvent::RestartScheduled { attempt: 1, .. }
+        ));
+        let OopInstanceEvent::Restarted {
+            old_instance_id,
+            new_instance_id,
+            attempt: 1,
+            ..
+        } = next_event(&mut events).await
+        else {
+            panic!("expected Restarted event");
+        };
+        assert_eq!(old_instance_id, handle.instance_id);
+        assert_ne!(new_instance_id, handle.instance_id);
+
+        // The replacement crashes too and exhausts max_restarts.
+        assert!(matches!(
+            next_event(&mut events).await,
+            OopInstanceEvent::Exited { instance_id, .. } if instance_id == new_instance_id
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            OopInstanceEvent::GaveUp { instance_id, .. } if instance_id == new_instance_id
+        ));
+    }
+
+    #[cfg(unix)]
+    fn sleeping_module(name: &str) -> OopModuleConfig {
+        let mut cfg = OopModuleConfig::new(name, BackendKind::LocalProcess);
+        cfg.binary = Some(PathBuf::from("/bin/sleep"));
+        cfg.args = vec!["10".to_owned()];
+        cfg
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_spawn_starts_requested_number_of_replicas() {
+        use crate::backends::{OopBackend, OopSpawnConfig};
+
+        let cancel = CancellationToken::new();
+        let backend = LocalProcessBackend::new(cancel.clone());
+
+        OopBackend::spawn(
+            &backend,
+            OopSpawnConfig {
+                module_name: "replicated".to_owned(),
+                binary: PathBuf::from("/bin/sleep"),
+                args: vec!["10".to_owned()],
+                env: HashMap::new(),
+                working_directory: None,
+                restart: RestartPolicy::default(),
+                replicas: 3,
+            },
+        )
+        .await
+        .expect("should spawn replicas");
+
+        let instances = backend.list_instances("replicated").await.unwrap();
+        assert_eq!(instances.len(), 3);
+        let ids: std::collections::HashSet<Uuid> =
+            instances.iter().map(|h| h.instance_id).collect();
+        assert_eq!(ids.len(), 3, "each replica gets its own instance id");
+
+        cancel.cancel();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rolling_restart_replaces_instances_once_replacements_are_healthy() {
+        use crate::runtime::ModuleInstance;
+
+        const MODULE: &str = "rolling";
+        let cancel = CancellationToken::new();
+        let backend = Arc::new(LocalProcessBackend::new(cancel.clone()));
+        let directory = Arc::new(ModuleManager::new());
+
+        for _ in 0..2 {
+            backend
+                .spawn_instance(&sleeping_module(MODULE))
+                .await
+                .expect("should spawn instance");
+        }
+        let old_ids: std::collections::HashSet<Uuid> = backend
+            .list_instances(MODULE)
+            .await
+            .unwrap()
+            .iter()
+            .map(|h| h.instance_id)
+            .collect();
+
+        // Stand-in for the modules registering themselves and heartbeating on startup
+        let announcer = {
+            let backend = Arc::clone(&backend);
+            let directory = Arc::clone(&directory);
+            tokio::spawn(async move {
+                let mut seen = std::collections::HashSet::new();
+                loop {
+                    for handle in backend.list_instances(MODULE).await.unwrap() {
+                        if seen.insert(handle.instance_id) {
+                            directory.register_instance(Arc::new(ModuleInstance::new(
+                                MODULE,
+                                handle.instance_id,
+                            )));
+                            directory.update_heartbeat(MODULE, handle.instance_id, Instant::now());
+                        }
+                    }
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+            })
+        };
+
+        backend
+            .rolling_restart(MODULE, &directory)
+            .await
+            .expect("rolling restart should succeed");
+        announcer.abort();
+
+        let new_ids: std::collections::HashSet<Uuid> = backend
+            .list_instances(MODULE)
+            .await
+            .unwrap()
+            .iter()
+            .map(|h| h.instance_id)
+            .collect();
+        assert_eq!(new_ids.len(), 2);
+        assert!(new_ids.is_disjoint(&old_ids));
+
+        let registered: std::collections::HashSet<Uuid> = directory
+            .instances_of(MODULE)
+            .iter()
+            .map(|i| i.instance_id)
+            .collect();
+        assert_eq!(registered, new_ids);
+
+        cancel.cancel();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rolling_restart_deregisters_unhealthy_replacement() {
+        use crate::runtime::ModuleInstance;
+
+        const MODULE: &str = "rolling_unhealthy";
+        let cancel = CancellationToken::new();
+        let backend = Arc::new(
+            LocalProcessBackend::new(cancel.clone())
+                .with_rolling_ready_timeout(Duration::from_millis(300)),
+        );
+        let directory = Arc::new(ModuleManager::new());
+
+        let old = backend
+            .spawn_instance(&sleeping_module(MODULE))
+            .await
+            .expect("should spawn instance");
+        directory.register_instance(Arc::new(ModuleInstance::new(MODULE, old.instance_id)));
+        directory.update_heartbeat(MODULE, old.instance_id, Instant::now());
+
+        // The replacement registers but never reports healthy
+        let announcer = {
+            let backend = Arc::clone(&backend);
+            let directory = Arc::clone(&directory);
+            tokio::spawn(async move {
+                loop {
+                    for handle in backend.list_instances(MODULE).await.unwrap() {
+                        if directory
+                            .instances_of(MODULE)
+                            .iter()
+                            .all(|i| i.instance_id != handle.instance_id)
+                        {
+                            directory.register_instance(Arc::new(ModuleInstance::new(
+                                MODULE,
+                                handle.instance_id,
+                            )));
+                        }
+                    }
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+            })
+        };
+
+        let err = backend
+            .rolling_restart(MODULE, &directory)
+            .await
+            .expect_err("an unhealthy replacement should abort the restart");
+        announcer.abort();
+        assert!(err.to_string().contains("did not become healthy"));
+
+        let running: Vec<Uuid> = backend
+            .list_instances(MODULE)
+            .await
+            .unwrap()
+            .iter()
+            .map(|h| h.instance_id)
+            .collect();
+        assert_eq!(running, vec![old.instance_id]);
+        let registered: Vec<Uuid> = directory
+            .instances_of(MODULE)
+            .iter()
+            .map(|i| i.instance_id)
+            .collect();
+        assert_eq!(registered, vec![old.instance_id]);
+
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_rolling_restart_requires_running_instances() {
+        let backend = test_backend();
+        let result = backend
+            .rolling_restart("missing", &ModuleManager::new())
+            .await;
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("no running instances")
+        );
+    }
+
     mod send_terminate_signal_tests {
         #[cfg(unix)]
         use {super::send_terminate_signal, std::time::Duration};
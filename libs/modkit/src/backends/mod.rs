@@ -6,9 +6,12 @@ use anyhow::Result;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
 use uuid::Uuid;
 
+use crate::runtime::ModuleManager;
+
 /// The kind of backend used to spawn and manage module instances
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackendKind {
@@ -19,6 +22,7 @@ pub enum BackendKind {
 }
 
 /// Configuration for an out-of-process module
+#[derive(Clone)]
 pub struct OopModuleConfig {
     pub name: String,
     pub binary: Option<PathBuf>,
@@ -27,6 +31,8 @@ pub struct OopModuleConfig {
     pub working_directory: Option<String>,
     pub backend: BackendKind,
     pub version: Option<String>,
+    /// What to do when an instance exits without being stopped by the host.
+    pub restart: RestartPolicy,
 }
 
 impl OopModuleConfig {
@@ -39,6 +45,7 @@ impl OopModuleConfig {
             working_directory: None,
             backend,
             version: None,
+            restart: RestartPolicy::default(),
         }
     }
 }
@@ -65,6 +72,38 @@ impl std::fmt::Debug for InstanceHandle {
     }
 }
 
+/// Lifecycle events emitted by a backend while supervising `OoP` instances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OopInstanceEvent {
+    /// An instance exited without being stopped by the host.
+    Exited {
+        module: String,
+        instance_id: Uuid,
+        exit_code: Option<i32>,
+        success: bool,
+    },
+    /// A replacement for `instance_id` will be spawned after `delay`.
+    RestartScheduled {
+        module: String,
+        instance_id: Uuid,
+        attempt: u32,
+        delay: Duration,
+    },
+    /// A replacement instance was spawned.
+    Restarted {
+        module: String,
+        old_instance_id: Uuid,
+        new_instance_id: Uuid,
+        attempt: u32,
+    },
+    /// The restart policy is exhausted; the module stays down.
+    GaveUp {
+        module: String,
+        instance_id: Uuid,
+        reason: String,
+    },
+}
+
 /// Trait for backends that can spawn and manage module instances
 #[async_trait]
 pub trait ModuleRuntimeBackend: Send + Sync {
@@ -80,6 +119,9 @@ pub struct OopSpawnConfig {
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
     pub working_directory: Option<String>,
+    pub restart: RestartPolicy,
+    /// Number of instances to spawn; `0` is treated as 1.
+    pub replicas: u32,
 }
 
 /// A type-erased backend for spawning `OoP` modules.
@@ -87,17 +129,32 @@ pub struct OopSpawnConfig {
 /// This trait is used by `HostRuntime` to spawn `OoP` modules after the start phase.
 #[async_trait]
 pub trait OopBackend: Send + Sync {
-    /// Spawn an `OoP` module instance.
+    /// Spawn the configured number of `OoP` module instances.
     async fn spawn(&self, config: OopSpawnConfig) -> Result<()>;
 
     /// Shutdown all spawned instances (called during stop phase).
     async fn shutdown_all(&self);
+
+    /// Subscribe to instance lifecycle events, if the backend supervises its instances.
+    fn subscribe(&self) -> Option<broadcast::Receiver<OopInstanceEvent>> {
+        None
+    }
+
+    /// Replace every running instance of `module` one at a time.
+    ///
+    /// Each replacement must become healthy in `directory` before the instance it
+    /// replaces is drained and stopped, so the module keeps serving throughout.
+    async fn rolling_restart(&self, module: &str, _directory: &ModuleManager) -> Result<()> {
+        anyhow::bail!("rolling restart is not supported by this backend (module '{module}')")
+    }
 }
 
 pub mod local;
 pub mod log_forwarder;
+pub mod restart;
 
 pub use local::LocalProcessBackend;
+pub use restart::{RestartMode, RestartPolicy};
 
 /// Adapter that implements `OopBackend` trait for `LocalProcessBackend`.
 ///
@@ -110,8 +167,11 @@ impl OopBackend for LocalProcessBackend {
         oop_config.args = config.args;
         oop_config.env = config.env;
         oop_config.working_directory = config.working_directory;
+        oop_config.restart = config.restart;
 
-        self.spawn_instance(&oop_config).await?;
+        for _ in 0..config.replicas.max(1) {
+            self.spawn_instance(&oop_config).await?;
+        }
         Ok(())
     }
 
@@ -120,6 +180,14 @@ impl OopBackend for LocalProcessBackend {
         // when the token is triggered, it automatically stops all instances.
         // This method is a no-op because the backend's internal shutdown task handles it.
     }
+
+    fn subscribe(&self) -> Option<broadcast::Receiver<OopInstanceEvent>> {
+        Some(LocalProcessBackend::subscribe(self))
+    }
+
+    async fn rolling_restart(&self, module: &str, directory: &ModuleManager) -> Result<()> {
+        LocalProcessBackend::rolling_restart(self, module, directory).await
+    }
 }
 
 #[cfg(test)]
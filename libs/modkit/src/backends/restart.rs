@@ -0,0 +1,281 @@
+//! Restart policies and crash supervision bookkeeping for `OoP` module instances.
+//!
+//! A [`RestartPolicy`] is configured per module (`runtime.execution.restart`) and
+//! evaluated by the backend every time an instance exits without being stopped by the
+//! host. [`RestartTracker`] carries the per-module history across respawns so that
+//! backoff and crash-loop detection survive the change of instance id.
+//!
+//! ```yaml
+//! modules:
+//!   calculator:
+//!     runtime:
+//!       type: oop
+//!       execution:
+//!         executable_path: "~/.hyperspot/bin/calculator-oop"
+//!         restart:
+//!           mode: on_failure
+//!           max_restarts: 5
+//!           initial_backoff_ms: 500
+//!           max_backoff_ms: 30000
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// When an exited instance should be respawned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartMode {
+    /// Never respawn (the instance is only deregistered).
+    #[default]
+    Never,
+    /// Respawn only when the process exits with a non-zero status or is killed.
+    OnFailure,
+    /// Respawn on every exit, including a clean one.
+    Always,
+}
+
+/// Per-module restart policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct RestartPolicy {
+    pub mode: RestartMode,
+    /// Consecutive restarts allowed before giving up. Reset once an instance stays up
+    /// for `stable_after_secs`.
+    pub max_restarts: u32,
+    /// Delay before the first restart; doubled for every consecutive restart.
+    pub initial_backoff_ms: u64,
+    /// Upper bound for the restart delay.
+    pub max_backoff_ms: u64,
+    /// Uptime after which an instance is considered healthy again.
+    pub stable_after_secs: u64,
+    /// Number of exits within `crash_loop_window_secs` that is treated as a crash loop.
+    pub crash_loop_threshold: u32,
+    pub crash_loop_window_secs: u64,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            mode: RestartMode::Never,
+            max_restarts: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            stable_after_secs: 60,
+            crash_loop_threshold: 10,
+            crash_loop_window_secs: 60,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Delay before the given (1-based) consecutive restart attempt.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_backoff_ms
+            .saturating_mul(1u64 << exp)
+            .min(self.max_backoff_ms);
+        Duration::from_millis(delay)
+    }
+}
+
+/// Why the supervisor stopped restarting a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GiveUpReason {
+    /// `max_restarts` consecutive restarts did not produce a stable instance.
+    MaxRestarts(u32),
+    /// Too many exits within the crash-loop window.
+    CrashLoop { exits: u32, window: Duration },
+}
+
+impl std::fmt::Display for GiveUpReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MaxRestarts(n) => write!(f, "exceeded max_restarts ({n})"),
+            Self::CrashLoop { exits, window } => {
+                write!(f, "crash loop: {exits} exits within {}s", window.as_secs())
+            }
+        }
+    }
+}
+
+/// Outcome of evaluating a [`RestartPolicy`] for an exited instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestartDecision {
+    /// Respawn after `delay`; `attempt` is the consecutive restart number (1-based).
+    Restart { attempt: u32, delay: Duration },
+    /// The policy does not restart this kind of exit.
+    Stop,
+    /// Restarts are exhausted.
+    GiveUp(GiveUpReason),
+}
+
+/// Restart history of a single module, carried from one instance to its replacement.
+#[derive(Debug, Clone, Default)]
+pub struct RestartTracker {
+    consecutive: u32,
+    recent_exits: VecDeque<Instant>,
+}
+
+impl RestartTracker {
+    /// Record an exit and decide what to do next.
+    ///
+    /// `uptime` is how long the exited instance ran; `success` is whether it exited cleanly.
+    pub fn on_exit(
+        &mut self,
+        policy: &RestartPolicy,
+        success: bool,
+        uptime: Duration,
+        now: Instant,
+    ) -> RestartDecision {
+        let wants_restart = match policy.mode {
+            RestartMode::Never => false,
+            RestartMode::OnFailure => !success,
+            RestartMode::Always => true,
+        };
+        if !wants_restart {
+            return RestartDecision::Stop;
+        }
+
+        if uptime >= Duration::from_secs(policy.stable_after_secs) {
+            self.consecutive = 0;
+        }
+
+        let window = Duration::from_secs(policy.crash_loop_window_secs);
+        self.recent_exits.push_back(now);
+        while self
+            .recent_exits
+            .front()
+            .is_some_and(|t| now.saturating_duration_since(*t) > window)
+        {
+            self.recent_exits.pop_front();
+        }
+        let exits = u32::try_from(self.recent_exits.len()).unwrap_or(u32::MAX);
+        if policy.crash_loop_threshold > 0 && exits >= policy.crash_loop_threshold {
+            return RestartDecision::GiveUp(GiveUpReason::CrashLoop { exits, window });
+        }
+
+        if self.consecutive >= policy.max_restarts {
+            return RestartDecision::GiveUp(GiveUpReason::MaxRestarts(policy.max_restarts));
+        }
+
+        self.consecutive += 1;
+        RestartDecision::Restart {
+            attempt: self.consecutive,
+            delay: policy.backoff(self.consecutive),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn policy(mode: RestartMode) -> RestartPolicy {
+        RestartPolicy {
+            mode,
+            max_restarts: 3,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 250,
+            stable_after_secs: 10,
+            crash_loop_threshold: 0,
+            crash_loop_window_secs: 60,
+        }
+    }
+
+    #[test]
+    fn mode_controls_which_exits_restart() {
+        let now = Instant::now();
+        let short = Duration::from_secs(1);
+
+        let mut t = RestartTracker::default();
+        assert_eq!(
+            t.on_exit(&policy(RestartMode::Never), false, short, now),
+            RestartDecision::Stop
+        );
+        assert_eq!(
+            t.on_exit(&policy(RestartMode::OnFailure), true, short, now),
+            RestartDecision::Stop
+        );
+        assert!(matches!(
+            t.on_exit(&policy(RestartMode::OnFailure), false, short, now),
+            RestartDecision::Restart { attempt: 1, .. }
+        ));
+        assert!(matches!(
+            t.on_exit(&policy(RestartMode::Always), true, short, now),
+            RestartDecision::Restart { attempt: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_and_is_capped() {
+        let p = policy(RestartMode::OnFailure);
+        assert_eq!(p.backoff(1), Duration::from_millis(100));
+        assert_eq!(p.backoff(2), Duration::from_millis(200));
+        assert_eq!(p.backoff(3), Duration::from_millis(250));
+        assert_eq!(p.backoff(40), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn gives_up_after_max_restarts_unless_instance_was_stable() {
+        let p = policy(RestartMode::OnFailure);
+        let now = Instant::now();
+        let short = Duration::from_secs(1);
+        let mut t = RestartTracker::default();
+
+        for attempt in 1..=3 {
+            assert!(matches!(
+                t.on_exit(&p, false, short, now),
+                RestartDecision::Restart { attempt: a, .. } if a == attempt
+            ));
+        }
+        assert_eq!(
+            t.on_exit(&p, false, short, now),
+            RestartDecision::GiveUp(GiveUpReason::MaxRestarts(3))
+        );
+
+        // A stable run resets the consecutive counter.
+        assert!(matches!(
+            t.on_exit(&p, false, Duration::from_secs(30), now),
+            RestartDecision::Restart { attempt: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn detects_crash_loop_within_window() {
+        let mut p = policy(RestartMode::Always);
+        p.max_restarts = 100;
+        p.stable_after_secs = 0;
+        p.crash_loop_threshold = 3;
+        p.crash_loop_window_secs = 10;
+        let start = Instant::now();
+        let mut t = RestartTracker::default();
+
+        // Exits spread beyond the window never trip the detector.
+        for i in 0..5 {
+            let decision = t.on_exit(
+                &p,
+                false,
+                Duration::ZERO,
+                start + Duration::from_secs(i * 11),
+            );
+            assert!(matches!(decision, RestartDecision::Restart { .. }));
+        }
+
+        let base = start + Duration::from_secs(100);
+        for i in 1..=2 {
+            assert!(matches!(
+                t.on_exit(&p, false, Duration::ZERO, base + Duration::from_secs(i)),
+                RestartDecision::Restart { .. }
+            ));
+        }
+        assert!(matches!(
+            t.on_exit(&p, false, Duration::ZERO, base + Duration::from_secs(3)),
+            RestartDecision::GiveUp(GiveUpReason::CrashLoop { exits: 3, .. })
+        ));
+    }
+}
@@ -7,6 +7,7 @@ mod dump;
 use anyhow::{Context, Result};
 // Use DB config types from modkit-db
 pub use modkit_db::{DbConnConfig, GlobalDatabaseConfig, PoolCfg};
+use modkit_security::{PolicyConfig, SecCtxCodecConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -14,7 +15,8 @@ use tracing::Level;
 
 use super::host::paths::home_dir::resolve_home_dir;
 use crate::ConfigProvider;
-use crate::telemetry::TracingConfig;
+use crate::backends::RestartPolicy;
+use crate::telemetry::{MetricsConfig, TracingConfig};
 use url::Url;
 
 // Re-export dump functions
@@ -61,6 +63,16 @@ pub struct ExecutionConfig {
     /// Environment variables to set for the process.
     #[serde(default)]
     pub environment: HashMap<String, String>,
+    /// Restart policy applied when the process exits unexpectedly (default: never).
+    #[serde(default)]
+    pub restart: RestartPolicy,
+    /// Number of processes to spawn for this module (default: 1, `0` is treated as 1).
+    #[serde(default = "default_replicas")]
+    pub replicas: u32,
+}
+
+const fn default_replicas() -> u32 {
+    1
 }
 
 /// Module runtime kind.
@@ -85,6 +97,15 @@ pub struct AppConfig {
     pub logging: Option<LoggingConfig>,
     /// Tracing configuration (optional, disabled if None).
     pub tracing: Option<TracingConfig>,
+    /// OTLP metrics export (optional, `/metrics` scraping only if None).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<MetricsConfig>,
+    /// Rule-based policy engine configuration (optional, allow-all if None).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<PolicyConfig>,
+    /// Signing/encryption keys for `SecurityContext` propagation over gRPC (optional, unsigned if None).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secctx: Option<SecCtxCodecConfig>,
     /// Directory containing per-module YAML files (optional).
     #[serde(default)]
     pub modules_dir: Option<String>,
@@ -196,6 +217,9 @@ impl Default for AppConfig {
             }),
             logging: Some(default_logging_config()),
             tracing: None, // Disabled by default
+            metrics: None,
+            policy: None,
+            secctx: None,
             modules_dir: None,
             modules: HashMap::new(),
         }
@@ -221,6 +245,9 @@ impl AppConfig {
             database: None,
             logging: None,
             tracing: None,
+            metrics: None,
+            policy: None,
+            secctx: None,
             modules_dir: None,
             modules: HashMap::new(),
         };
@@ -990,6 +1017,12 @@ pub struct RenderedModuleConfig {
     /// Tracing configuration from master host for OTEL initialization
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tracing: Option<TracingConfig>,
+    /// OTLP metrics export settings from master host
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<MetricsConfig>,
+    /// Secctx key ring from master host so `OoP` modules can verify and sign envelopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secctx: Option<SecCtxCodecConfig>,
 }
 
 impl RenderedModuleConfig {
@@ -1057,12 +1090,18 @@ pub fn render_module_config_for_oop(
 
     // Pass tracing config from master host so OoP modules use the same OTEL settings
     let tracing = app.tracing.clone();
+    let metrics = app.metrics.clone();
+
+    // Pass the secctx key ring so OoP modules accept envelopes signed by the master
+    let secctx = app.secctx.clone();
 
     Ok(RenderedModuleConfig {
         database,
         config,
         logging,
         tracing,
+        metrics,
+        secctx,
     })
 }
 
@@ -8,6 +8,7 @@
 //! - Configuration loading using `modkit-bootstrap`
 //! - Logging initialization with tracing
 //! - gRPC connection to `DirectoryService`
+//! - Event bus bridged to the master host via `EventBusService`
 //! - Module instance registration
 //! - Heartbeat management
 //! - Module lifecycle execution
@@ -57,11 +58,14 @@ use super::config::{
     RenderedModuleConfig,
 };
 use crate::bootstrap::host::init_logging_unified;
+use crate::events::EventBus;
 use crate::runtime::{
-    ClientRegistration, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, RunOptions, ShutdownOptions, run,
-    shutdown,
+    ClientRegistration, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, MODKIT_INSTANCE_ID_ENV,
+    RunOptions, ShutdownOptions, run, shutdown,
 };
 use cf_system_sdks::directory::{DirectoryClient, DirectoryGrpcClient};
+use cf_system_sdks::events::EventBusGrpcClient;
+use modkit_security::SecCtxCodec;
 
 /// Configuration options for `OoP` module bootstrap
 #[derive(Debug, Clone)]
@@ -69,7 +73,7 @@ pub struct OopRunOptions {
     /// Logical module name (e.g., "`file_parser`")
     pub module_name: String,
 
-    /// Instance ID (defaults to a random UUID if None)
+    /// Instance ID (defaults to `MODKIT_INSTANCE_ID` set by the master host, else a random UUID)
     pub instance_id: Option<Uuid>,
 
     /// Directory service gRPC endpoint (e.g., "<http://127.0.0.1:50051>")
@@ -98,9 +102,14 @@ impl Default for OopRunOptions {
         let directory_endpoint = std::env::var(MODKIT_DIRECTORY_ENDPOINT_ENV)
             .unwrap_or_else(|_| "http://127.0.0.1:50051".to_owned());
 
+        // Instance ID assigned by the master host's backend, so restarts can be tracked
+        let instance_id = std::env::var(MODKIT_INSTANCE_ID_ENV)
+            .ok()
+            .and_then(|id| Uuid::parse_str(&id).ok());
+
         Self {
             module_name: String::new(),
-            instance_id: None,
+            instance_id,
             directory_endpoint,
             config_path,
             verbose: 0,
@@ -159,6 +168,11 @@ fn build_oop_config_and_db(
             // If local has "config", it already overrides - no action needed
         }
 
+        // Secctx keys: local section replaces master's entirely (no per-key merge)
+        if config.secctx.is_none() {
+            config.secctx.clone_from(&rendered.secctx);
+        }
+
         debug!(
             module = %module_name,
             has_rendered_db = %rendered.database.is_some(),
@@ -490,6 +504,7 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
             has_config = !rc.config.is_null(),
             has_logging = rc.logging.is_some(),
             has_tracing = rc.tracing.is_some(),
+            has_metrics = rc.metrics.is_some(),
             "Received rendered config from master host"
         );
     } else if std::env::var(MODKIT_MODULE_CONFIG_ENV).is_ok() {
@@ -517,6 +532,15 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         return Ok(());
     }
 
+    // Push metrics over OTLP when the master host does
+    #[cfg(feature = "otel")]
+    let meter_provider = rendered_config
+        .as_ref()
+        .and_then(|rc| rc.metrics.as_ref())
+        .filter(|m| m.enabled)
+        .map(crate::telemetry::init_metrics)
+        .transpose()?;
+
     // Connect to DirectoryService
     info!(
         "Connecting to directory service at {}",
@@ -527,6 +551,11 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
 
     info!("Successfully connected to directory service");
 
+    // The master host serves its event bus next to the directory
+    let event_bus = Arc::new(EventBus::new(Arc::new(
+        EventBusGrpcClient::connect(&opts.directory_endpoint).await?,
+    )));
+
     // Start heartbeat loop in background using a child token from the root.
     // This allows the heartbeat to be cancelled when the root token is cancelled.
     let heartbeat_directory = Arc::clone(&directory_api);
@@ -564,21 +593,29 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         }
     });
 
+    // Build the secctx codec so gRPC clients/servers in this process can sign and verify
+    // `SecurityContext` envelopes with the same keys as the master host.
+    let secctx_codec = Arc::new(SecCtxCodec::new(
+        &final_config.secctx.clone().unwrap_or_default(),
+    )?);
+
     // Build config provider for modules
     let config_provider = Arc::new(final_config);
 
     // Keep a reference to directory_api for deregistration after shutdown
     // Run the module lifecycle with the root cancellation token.
     // Shutdown is driven by the signal handler spawned above, not by ShutdownOptions::Signals.
-    // The DirectoryClient (gRPC client) is injected into the ClientHub so modules can access it.
+    // The DirectoryClient and EventBus (gRPC clients) are injected into the ClientHub so modules can access them.
     info!("Starting module lifecycle");
     let run_options = RunOptions {
         modules_cfg: config_provider,
         db: db_options,
         shutdown: ShutdownOptions::Token(cancel.clone()),
-        clients: vec![ClientRegistration::new::<dyn DirectoryClient>(
-            directory_api,
-        )],
+        clients: vec![
+            ClientRegistration::new::<dyn DirectoryClient>(directory_api),
+            ClientRegistration::new::<SecCtxCodec>(secctx_codec),
+            ClientRegistration::new::<EventBus>(event_bus),
+        ],
         instance_id,
         oop: None, // OoP modules don't spawn other OoP modules
     };
@@ -591,6 +628,13 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         info!("Module runtime completed successfully");
     }
 
+    #[cfg(feature = "otel")]
+    if let Some(provider) = meter_provider
+        && let Err(e) = provider.shutdown()
+    {
+        warn!(error = %e, "Failed to flush OTLP metrics");
+    }
+
     result
 }
 
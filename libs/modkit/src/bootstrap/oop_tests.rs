@@ -27,6 +27,9 @@ fn minimal_app_config() -> AppConfig {
         database: None,
         logging: None,
         tracing: None,
+        metrics: None,
+        policy: None,
+        secctx: None,
         modules_dir: None,
         modules: HashMap::new(),
     }
@@ -655,6 +658,8 @@ mod full_oop_config {
                 .into(),
             ),
             tracing: None,
+            metrics: None,
+            secctx: None,
         };
 
         let result = build_oop_config_and_db(&local_config, "test_module", Some(&rendered));
@@ -673,6 +678,35 @@ mod full_oop_config {
         );
     }
 
+    #[test]
+    fn test_build_oop_config_secctx_from_master_unless_local() {
+        let master_secctx = modkit_security::SecCtxCodecConfig {
+            strict: true,
+            ..Default::default()
+        };
+        let rendered = RenderedModuleConfig {
+            database: None,
+            config: json!({}),
+            logging: None,
+            tracing: None,
+            metrics: None,
+            secctx: Some(master_secctx.clone()),
+        };
+
+        let (final_config, _, _) =
+            build_oop_config_and_db(&minimal_app_config(), "test_module", Some(&rendered)).unwrap();
+        assert_eq!(final_config.secctx, Some(master_secctx));
+
+        let mut local_config = minimal_app_config();
+        local_config.secctx = Some(modkit_security::SecCtxCodecConfig::default());
+        let (final_config, _, _) =
+            build_oop_config_and_db(&local_config, "test_module", Some(&rendered)).unwrap();
+        assert_eq!(
+            final_config.secctx,
+            Some(modkit_security::SecCtxCodecConfig::default())
+        );
+    }
+
     #[test]
     fn test_build_oop_config_local_overrides_master_config() {
         // Local config section completely replaces master
@@ -694,6 +728,8 @@ mod full_oop_config {
             }),
             logging: None,
             tracing: None,
+            metrics: None,
+            secctx: None,
         };
 
         let result = build_oop_config_and_db(&local_config, "test_module", Some(&rendered));
@@ -743,6 +779,8 @@ mod full_oop_config {
                 .into(),
             ),
             tracing: None,
+            metrics: None,
+            secctx: None,
         };
 
         let result = build_oop_config_and_db(&local_config, "test_module", Some(&rendered));
@@ -792,6 +830,8 @@ mod full_oop_config {
             config: json!({"master_setting": "value"}),
             logging: None,
             tracing: None,
+            metrics: None,
+            secctx: None,
         };
 
         let result = build_oop_config_and_db(&local_config, "test_module", Some(&rendered));
@@ -820,6 +860,8 @@ mod full_oop_config {
             config: json!({"master_setting": "value"}),
             logging: None,
             tracing: None,
+            metrics: None,
+            secctx: None,
         };
 
         let result = build_oop_config_and_db(&local_config, "test_module", Some(&rendered));
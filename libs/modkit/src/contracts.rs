@@ -109,6 +109,31 @@ pub struct RegisterGrpcServiceFn {
     pub service_name: &'static str,
 }
 
+/// Events capability: modules that publish on the event bus.
+///
+/// The runtime declares the returned topics before `init`, so a topic declared with
+/// a conflicting capacity or backpressure policy fails start-up rather than the first publish.
+/// Subscribing does not require this capability.
+pub trait EventsCapability: Send + Sync {
+    /// Topics this module publishes, usually built from `Topic::spec()`.
+    fn event_topics(&self) -> Vec<crate::events::TopicSpec>;
+}
+
+/// Health capability: modules reporting their own liveness and readiness.
+///
+/// The runtime registers these checks in the [`crate::health::HealthRegistry`], which bounds
+/// each call with a timeout; a check should inspect cached state or do one cheap round-trip.
+#[async_trait]
+pub trait HealthCapability: Send + Sync {
+    /// Whether the module works at all; failing it means the process should be restarted.
+    async fn liveness(&self) -> crate::health::HealthCheck {
+        crate::health::HealthCheck::up()
+    }
+
+    /// Whether the module can serve traffic, e.g. its dependencies are reachable.
+    async fn readiness(&self) -> crate::health::HealthCheck;
+}
+
 /// gRPC Service capability: modules that export gRPC services.
 ///
 /// The runtime will call this during the gRPC registration phase to collect
@@ -108,6 +108,36 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_resolve_grpc_service_balances_across_healthy_replicas() {
+        let dir = Arc::new(ModuleManager::new());
+        let api = LocalDirectoryClient::new(dir.clone());
+
+        let replicas: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        for (port, id) in (9001..).zip(&replicas) {
+            dir.register_instance(Arc::new(
+                ModuleInstance::new("calc", *id)
+                    .with_grpc_service("calc.Service", Endpoint::http("127.0.0.1", port)),
+            ));
+            dir.update_heartbeat("calc", *id, std::time::Instant::now());
+        }
+        dir.mark_quarantined("calc", replicas[2]);
+        dir.mark_draining("calc", replicas[3]);
+
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..6 {
+            let ep = api.resolve_grpc_service("calc.Service").await.unwrap();
+            seen.insert(ep.uri);
+        }
+
+        let expected: std::collections::HashSet<String> = [
+            "http://127.0.0.1:9001".to_owned(),
+            "http://127.0.0.1:9002".to_owned(),
+        ]
+        .into();
+        assert_eq!(seen, expected);
+    }
+
     #[tokio::test]
     async fn test_register_instance_via_api() {
         let dir = Arc::new(ModuleManager::new());
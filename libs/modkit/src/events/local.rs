@@ -0,0 +1,251 @@
+//! In-process event bus backend
+//!
+//! `DropOldest` topics are backed by a `tokio::sync::broadcast` channel; `DropNewest`
+//! and `Block` topics keep a bounded `mpsc` queue per subscriber.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+
+use super::{BackpressurePolicy, EventBackend, EventError, EventStream, TopicSpec};
+
+/// Event bus backend for modules running in the same process.
+#[derive(Default)]
+pub struct LocalEventBackend {
+    topics: Mutex<HashMap<String, Arc<LocalTopic>>>,
+}
+
+struct LocalTopic {
+    spec: TopicSpec,
+    channel: TopicChannel,
+}
+
+enum TopicChannel {
+    Broadcast(broadcast::Sender<Bytes>),
+    Queues(Mutex<Vec<mpsc::Sender<Bytes>>>),
+}
+
+impl LocalEventBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn topic(&self, spec: &TopicSpec) -> Result<Arc<LocalTopic>, EventError> {
+        spec.validate()?;
+
+        let mut topics = self.topics.lock();
+        if let Some(topic) = topics.get(&spec.name) {
+            topic.spec.ensure_matches(spec)?;
+            return Ok(Arc::clone(topic));
+        }
+
+        let channel = match spec.policy {
+            BackpressurePolicy::DropOldest => {
+                TopicChannel::Broadcast(broadcast::channel(spec.capacity).0)
+            }
+            BackpressurePolicy::DropNewest | BackpressurePolicy::Block => {
+                TopicChannel::Queues(Mutex::new(Vec::new()))
+            }
+        };
+        let topic = Arc::new(LocalTopic {
+            spec: spec.clone(),
+            channel,
+        });
+        topics.insert(spec.name.clone(), Arc::clone(&topic));
+        Ok(topic)
+    }
+}
+
+impl LocalTopic {
+    async fn publish(&self, payload: Bytes) {
+        let queues = match &self.channel {
+            TopicChannel::Broadcast(sender) => {
+                // No subscribers is not an error
+                let _ = sender.send(payload);
+                return;
+            }
+            TopicChannel::Queues(queues) => queues,
+        };
+
+        let subscribers = queues.lock().clone();
+        let mut closed = false;
+        for subscriber in &subscribers {
+            let delivered = if self.spec.policy == BackpressurePolicy::Block {
+                subscriber.send(payload.clone()).await.is_ok()
+            } else {
+                match subscriber.try_send(payload.clone()) {
+                    Ok(()) => true,
+                    Err(mpsc::error::TrySendError::Full(_)) => {
+                        tracing::debug!(
+                            topic = %self.spec.name,
+                            "Subscriber buffer full, event dropped"
+                        );
+                        true
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => false,
+                }
+            };
+            closed |= !delivered;
+        }
+
+        if closed {
+            queues.lock().retain(|subscriber| !subscriber.is_closed());
+        }
+    }
+
+    fn subscribe(&self) -> EventStream {
+        match &self.channel {
+            TopicChannel::Broadcast(sender) => {
+                Box::pin(BroadcastStream::new(sender.subscribe()).map(|item| {
+                    item.map_err(|BroadcastStreamRecvError::Lagged(missed)| {
+                        EventError::Lagged(missed)
+                    })
+                }))
+            }
+            TopicChannel::Queues(queues) => {
+                let (tx, rx) = mpsc::channel(self.spec.capacity);
+                queues.lock().push(tx);
+                Box::pin(ReceiverStream::new(rx).map(Ok))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventBackend for LocalEventBackend {
+    async fn declare(&self, topic: &TopicSpec) -> Result<(), EventError> {
+        self.topic(topic).map(drop)
+    }
+
+    async fn publish(&self, topic: &TopicSpec, payload: Bytes) -> Result<(), EventError> {
+        self.topic(topic)?.publish(payload).await;
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &TopicSpec) -> Result<EventStream, EventError> {
+        Ok(self.topic(topic)?.subscribe())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn spec(policy: BackpressurePolicy) -> TopicSpec {
+        TopicSpec::new("test.topic", 2, policy)
+    }
+
+    async fn publish_all(backend: &LocalEventBackend, topic: &TopicSpec, payloads: &[&str]) {
+        for payload in payloads {
+            backend
+                .publish(topic, Bytes::copy_from_slice(payload.as_bytes()))
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn next(events: &mut EventStream) -> Result<Bytes, EventError> {
+        tokio::time::timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect("event expected")
+            .expect("stream ended")
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_reports_lag() {
+        let backend = LocalEventBackend::new();
+        let topic = spec(BackpressurePolicy::DropOldest);
+        let mut events = backend.subscribe(&topic).await.unwrap();
+
+        publish_all(&backend, &topic, &["1", "2", "3", "4"]).await;
+
+        assert_eq!(next(&mut events).await, Err(EventError::Lagged(2)));
+        assert_eq!(next(&mut events).await.unwrap(), "3");
+        assert_eq!(next(&mut events).await.unwrap(), "4");
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_keeps_buffered_events() {
+        let backend = LocalEventBackend::new();
+        let topic = spec(BackpressurePolicy::DropNewest);
+        let mut slow = backend.subscribe(&topic).await.unwrap();
+
+        publish_all(&backend, &topic, &["1", "2", "3"]).await;
+        assert_eq!(next(&mut slow).await.unwrap(), "1");
+        assert_eq!(next(&mut slow).await.unwrap(), "2");
+
+        publish_all(&backend, &topic, &["4"]).await;
+        assert_eq!(next(&mut slow).await.unwrap(), "4");
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_subscribers() {
+        let backend = Arc::new(LocalEventBackend::new());
+        let topic = spec(BackpressurePolicy::Block);
+        let mut events = backend.subscribe(&topic).await.unwrap();
+
+        publish_all(&backend, &topic, &["1", "2"]).await;
+
+        // The buffer is full, so the third publish waits for the subscriber
+        let publisher = {
+            let backend = Arc::clone(&backend);
+            let topic = topic.clone();
+            tokio::spawn(async move { publish_all(&backend, &topic, &["3"]).await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!publisher.is_finished());
+
+        assert_eq!(next(&mut events).await.unwrap(), "1");
+        publisher.await.unwrap();
+        assert_eq!(next(&mut events).await.unwrap(), "2");
+        assert_eq!(next(&mut events).await.unwrap(), "3");
+    }
+
+    #[tokio::test]
+    async fn test_dropped_subscribers_are_removed() {
+        let backend = LocalEventBackend::new();
+        let topic = spec(BackpressurePolicy::Block);
+        let events = backend.subscribe(&topic).await.unwrap();
+        drop(events);
+
+        // Would block forever if the closed subscriber were still awaited
+        publish_all(&backend, &topic, &["1", "2", "3"]).await;
+
+        let TopicChannel::Queues(queues) = &backend.topic(&topic).unwrap().channel else {
+            unreachable!("block topics use queues");
+        };
+        assert!(queues.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_and_conflicting_topics_are_rejected() {
+        let backend = LocalEventBackend::new();
+
+        let empty = TopicSpec::new("test.topic", 0, BackpressurePolicy::Block);
+        assert!(matches!(
+            backend.declare(&empty).await,
+            Err(EventError::InvalidTopic { .. })
+        ));
+
+        backend
+            .declare(&spec(BackpressurePolicy::Block))
+            .await
+            .unwrap();
+        assert!(matches!(
+            backend
+                .subscribe(&spec(BackpressurePolicy::DropOldest))
+                .await,
+            Err(EventError::TopicConflict { .. })
+        ));
+    }
+}
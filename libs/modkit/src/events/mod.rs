@@ -0,0 +1,291 @@
+//! Event bus for typed domain events between modules
+//!
+//! Topics are declared as typed constants, usually in the publishing module's SDK crate:
+//!
+//! ```rust,ignore
+//! use modkit::events::{BackpressurePolicy, Topic};
+//!
+//! pub const USER_CREATED: Topic<UserCreated> =
+//!     Topic::new("users_info.user_created").with_policy(BackpressurePolicy::DropNewest);
+//! ```
+//!
+//! The runtime registers an [`EventBus`] in the `ClientHub`: in-process on the host,
+//! bridged to the host over gRPC in out-of-process modules. Any module can publish
+//! or subscribe through it:
+//!
+//! ```rust,ignore
+//! let bus = ctx.client_hub().get::<EventBus>()?;
+//! bus.publish(&USER_CREATED, &UserCreated { id }).await?;
+//!
+//! let mut events = bus.subscribe(&USER_CREATED).await?;
+//! while let Some(event) = events.next().await { /* ... */ }
+//! ```
+//!
+//! Publishers should also declare their topics through the `events` capability
+//! (see [`crate::contracts::EventsCapability`]) so conflicting declarations fail at start-up.
+//!
+//! Events that must not be lost when the process dies right after a database commit
+//! go through the transactional outbox instead, see [`outbox`].
+
+mod local;
+pub mod outbox;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub use cf_system_sdks::events::{
+    BackpressurePolicy, EventBackend, EventError, EventStream, TopicSpec,
+};
+pub use local::LocalEventBackend;
+
+/// Default per-subscriber buffer size of a topic.
+pub const DEFAULT_TOPIC_CAPACITY: usize = 1024;
+
+/// A topic carrying events of type `E`, encoded as JSON on the bus.
+pub struct Topic<E> {
+    name: &'static str,
+    capacity: usize,
+    policy: BackpressurePolicy,
+    _event: PhantomData<fn() -> E>,
+}
+
+impl<E> Topic<E> {
+    /// A topic with [`DEFAULT_TOPIC_CAPACITY`] and the `DropOldest` policy.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            capacity: DEFAULT_TOPIC_CAPACITY,
+            policy: BackpressurePolicy::DropOldest,
+            _event: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_policy(mut self, policy: BackpressurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub fn spec(&self) -> TopicSpec {
+        TopicSpec::new(self.name, self.capacity, self.policy)
+    }
+}
+
+impl<E> Clone for Topic<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Topic<E> {}
+
+impl<E> fmt::Debug for Topic<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Topic")
+            .field("name", &self.name)
+            .field("capacity", &self.capacity)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+/// Stream of decoded events for one subscriber.
+pub type Subscription<E> = Pin<Box<dyn Stream<Item = Result<E, EventError>> + Send + 'static>>;
+
+/// Typed front-end over an [`EventBackend`], shared through the `ClientHub`.
+#[derive(Clone)]
+pub struct EventBus {
+    backend: Arc<dyn EventBackend>,
+}
+
+impl EventBus {
+    #[must_use]
+    pub fn new(backend: Arc<dyn EventBackend>) -> Self {
+        Self { backend }
+    }
+
+    /// A bus backed by a fresh [`LocalEventBackend`].
+    #[must_use]
+    pub fn in_process() -> Self {
+        Self::new(Arc::new(LocalEventBackend::new()))
+    }
+
+    /// The backend, e.g. to expose it to out-of-process modules over gRPC.
+    #[must_use]
+    pub fn backend(&self) -> Arc<dyn EventBackend> {
+        Arc::clone(&self.backend)
+    }
+
+    /// Create the topic, or check that it is already declared with the same spec.
+    ///
+    /// # Errors
+    /// Returns `EventError::TopicConflict` if the topic exists with another capacity or policy.
+    pub async fn declare<E>(&self, topic: &Topic<E>) -> Result<(), EventError> {
+        self.backend.declare(&topic.spec()).await
+    }
+
+    /// Publish an event to the topic's current subscribers.
+    ///
+    /// On `Block` topics this waits until every subscriber has room for the event.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be encoded or the backend rejects it.
+    pub async fn publish<E: Serialize>(
+        &self,
+        topic: &Topic<E>,
+        event: &E,
+    ) -> Result<(), EventError> {
+        let payload = serde_json::to_vec(event).map_err(|e| EventError::Codec(e.to_string()))?;
+        self.backend
+            .publish(&topic.spec(), Bytes::from(payload))
+            .await
+    }
+
+    /// Subscribe to events published to the topic from now on.
+    ///
+    /// Events that fail to decode are yielded as `EventError::Codec` without ending the stream.
+    ///
+    /// # Errors
+    /// Returns an error if the topic conflicts with its declaration or the backend is unavailable.
+    pub async fn subscribe<E>(&self, topic: &Topic<E>) -> Result<Subscription<E>, EventError>
+    where
+        E: DeserializeOwned + Send + 'static,
+    {
+        let payloads = self.backend.subscribe(&topic.spec()).await?;
+        Ok(Box::pin(payloads.map(|payload| {
+            serde_json::from_slice(&payload?).map_err(|e| EventError::Codec(e.to_string()))
+        })))
+    }
+
+    /// A publisher bound to one topic, convenient to hand to domain services.
+    #[must_use]
+    pub fn publisher<E>(&self, topic: Topic<E>) -> Publisher<E> {
+        Publisher {
+            bus: self.clone(),
+            topic,
+        }
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus").finish_non_exhaustive()
+    }
+}
+
+/// Publishes events of type `E` to a single topic.
+pub struct Publisher<E> {
+    bus: EventBus,
+    topic: Topic<E>,
+}
+
+impl<E: Serialize> Publisher<E> {
+    /// Publish an event; see [`EventBus::publish`].
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be encoded or the backend rejects it.
+    pub async fn publish(&self, event: &E) -> Result<(), EventError> {
+        self.bus.publish(&self.topic, event).await
+    }
+}
+
+impl<E> Clone for Publisher<E> {
+    fn clone(&self) -> Self {
+        Self {
+            bus: self.bus.clone(),
+            topic: self.topic,
+        }
+    }
+}
+
+impl<E> fmt::Debug for Publisher<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Publisher")
+            .field("topic", &self.topic)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct UserCreated {
+        id: u32,
+    }
+
+    const USER_CREATED: Topic<UserCreated> = Topic::new("users.created").with_capacity(8);
+
+    #[tokio::test]
+    async fn test_typed_round_trip() {
+        let bus = EventBus::in_process();
+        let mut events = bus.subscribe(&USER_CREATED).await.unwrap();
+
+        let publisher = bus.publisher(USER_CREATED);
+        publisher.publish(&UserCreated { id: 1 }).await.unwrap();
+        publisher.publish(&UserCreated { id: 2 }).await.unwrap();
+
+        assert_eq!(events.next().await.unwrap().unwrap(), UserCreated { id: 1 });
+        assert_eq!(events.next().await.unwrap().unwrap(), UserCreated { id: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_event_is_reported() {
+        const RAW: Topic<serde_json::Value> = Topic::new("users.created").with_capacity(8);
+
+        let bus = EventBus::in_process();
+        let mut events = bus.subscribe(&USER_CREATED).await.unwrap();
+
+        bus.publish(&RAW, &serde_json::json!({ "id": "one" }))
+            .await
+            .unwrap();
+        bus.publish(&USER_CREATED, &UserCreated { id: 2 })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            events.next().await.unwrap(),
+            Err(EventError::Codec(_))
+        ));
+        assert_eq!(events.next().await.unwrap().unwrap(), UserCreated { id: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_declaration_is_rejected() {
+        let bus = EventBus::in_process();
+        bus.declare(&USER_CREATED).await.unwrap();
+
+        let blocking = USER_CREATED.with_policy(BackpressurePolicy::Block);
+        assert!(matches!(
+            bus.declare(&blocking).await,
+            Err(EventError::TopicConflict { .. })
+        ));
+        assert!(matches!(
+            bus.publish(&blocking, &UserCreated { id: 1 }).await,
+            Err(EventError::TopicConflict { .. })
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+//! Reliable event publication through the transactional outbox
+//!
+//! Events are enqueued in the same transaction as the business change and relayed
+//! to the [`EventBus`] by a background [`OutboxRelay`]:
+//!
+//! ```rust,ignore
+//! use modkit::events::outbox::{self, EventBusSink, OutboxRelay};
+//! use modkit::lifecycle::WithLifecycle;
+//!
+//! // In a migration
+//! modkit_db::outbox::create_table(manager.get_connection()).await?;
+//!
+//! // In `init`
+//! let bus = ctx.client_hub().get::<EventBus>()?;
+//! let sink = EventBusSink::new(bus.as_ref().clone()).with_topic(USER_CREATED);
+//! let relay = WithLifecycle::new(OutboxRelay::new(ctx.db_required()?, "users_info", Arc::new(sink)));
+//!
+//! // In a domain service
+//! db.in_transaction(move |tx| Box::pin(async move {
+//!     let user = repo.create(tx, user).await?;
+//!     outbox::enqueue(tx, &USER_CREATED, &UserCreated { id: user.id }).await?;
+//!     Ok(user)
+//! }))
+//! ```
+//!
+//! Delivery is at-least-once, so subscribers of relayed topics should tolerate duplicates.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use modkit_db::DbConnTrait;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+pub use modkit_db::outbox::{OutboxMessage, OutboxRelay, OutboxSink, RelayConfig};
+
+use super::{EventBus, Topic, TopicSpec};
+use crate::lifecycle::Runnable;
+
+/// Enqueue an event for `topic` within the caller's transaction.
+///
+/// # Errors
+/// Returns an error if the event cannot be encoded or the insert fails.
+pub async fn enqueue<C, E>(conn: &C, topic: &Topic<E>, event: &E) -> modkit_db::Result<i64>
+where
+    C: DbConnTrait,
+    E: Serialize,
+{
+    modkit_db::outbox::enqueue_json(conn, topic.name(), event).await
+}
+
+/// Relays outbox messages to their topics on the [`EventBus`].
+///
+/// Only topics registered with [`EventBusSink::with_topic`] are delivered; the relay
+/// leaves messages of other topics in the outbox for the relays that own them.
+#[derive(Debug, Clone)]
+pub struct EventBusSink {
+    bus: EventBus,
+    topics: HashMap<&'static str, TopicSpec>,
+}
+
+impl EventBusSink {
+    #[must_use]
+    pub fn new(bus: EventBus) -> Self {
+        Self {
+            bus,
+            topics: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_topic<E>(mut self, topic: Topic<E>) -> Self {
+        self.topics.insert(topic.name(), topic.spec());
+        self
+    }
+}
+
+#[async_trait]
+impl OutboxSink for EventBusSink {
+    async fn deliver(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        let Some(spec) = self.topics.get(message.topic.as_str()) else {
+            anyhow::bail!("topic '{}' is not registered with the sink", message.topic);
+        };
+        self.bus
+            .backend()
+            .publish(spec, Bytes::copy_from_slice(&message.payload))
+            .await?;
+        Ok(())
+    }
+
+    fn topics(&self) -> Option<Vec<String>> {
+        Some(self.topics.keys().map(|&topic| topic.to_owned()).collect())
+    }
+}
+
+#[async_trait]
+impl Runnable for OutboxRelay {
+    async fn run(self: Arc<Self>, cancel: CancellationToken) -> anyhow::Result<()> {
+        OutboxRelay::run(&self, cancel).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct UserCreated {
+        id: u32,
+    }
+
+    const USER_CREATED: Topic<UserCreated> = Topic::new("users.created").with_capacity(8);
+
+    fn message(topic: &str, payload: &[u8]) -> OutboxMessage {
+        OutboxMessage {
+            id: 1,
+            topic: topic.to_owned(),
+            payload: payload.to_vec(),
+            attempts: 0,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sink_publishes_registered_topics() {
+        let bus = EventBus::in_process();
+        let mut events = bus.subscribe(&USER_CREATED).await.unwrap();
+        let sink = EventBusSink::new(bus).with_topic(USER_CREATED);
+
+        sink.deliver(&message("users.created", br#"{"id":7}"#))
+            .await
+            .unwrap();
+        assert_eq!(events.next().await.unwrap().unwrap(), UserCreated { id: 7 });
+
+        assert!(
+            sink.deliver(&message("users.deleted", b"{}"))
+                .await
+                .is_err()
+        );
+        assert_eq!(sink.topics(), Some(vec!["users.created".to_owned()]));
+    }
+}
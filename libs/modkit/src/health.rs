@@ -0,0 +1,339 @@
+//! Liveness and readiness checks aggregated across modules and runtime components.
+//!
+//! Modules report their own state through the `health` capability
+//! ([`crate::contracts::HealthCapability`]); the runtime adds checks for the start-up
+//! phase, databases, the gRPC hub and out-of-process instances. The process-wide
+//! [`HealthRegistry`] is registered in the `ClientHub`: the API gateway serves it on
+//! `/livez` and `/readyz`, and the gRPC hub as the standard `grpc.health.v1.Health` service.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::future::{BoxFuture, join_all};
+use parking_lot::RwLock;
+use serde::Serialize;
+
+use crate::contracts::HealthCapability;
+
+/// Default upper bound for a single check.
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Health of a component or of the whole process, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    /// Working with reduced capacity; the process still takes traffic.
+    Degraded,
+    Down,
+}
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthCheck {
+    pub status: HealthStatus,
+    pub details: Option<String>,
+}
+
+impl HealthCheck {
+    #[must_use]
+    pub fn up() -> Self {
+        Self {
+            status: HealthStatus::Up,
+            details: None,
+        }
+    }
+
+    #[must_use]
+    pub fn degraded(details: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Degraded,
+            details: Some(details.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn down(details: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Down,
+            details: Some(details.into()),
+        }
+    }
+}
+
+/// Kind of probe a check answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Probe {
+    /// The process works at all; failing it means it should be restarted.
+    Liveness,
+    /// The process can serve traffic.
+    Readiness,
+}
+
+/// Result of one component within a [`HealthReport`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Aggregated result of a probe: the worst component status wins.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Whether the probe passes (`up` or `degraded`).
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        self.status != HealthStatus::Down
+    }
+}
+
+/// A check answering some probes; `None` means the component does not take part in the probe.
+type CheckFn = Arc<dyn Fn(Probe) -> BoxFuture<'static, Option<HealthCheck>> + Send + Sync>;
+
+struct Component {
+    name: String,
+    check: CheckFn,
+}
+
+/// Registry of health checks, run concurrently and bounded by a per-check timeout.
+///
+/// Cheap to clone; clones share the same checks.
+#[derive(Clone)]
+pub struct HealthRegistry {
+    components: Arc<RwLock<Vec<Component>>>,
+    timeout: Duration,
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self {
+            components: Arc::default(),
+            timeout: DEFAULT_CHECK_TIMEOUT,
+        }
+    }
+}
+
+impl std::fmt::Debug for HealthRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self
+            .components
+            .read()
+            .iter()
+            .map(|c| c.name.clone())
+            .collect();
+        f.debug_struct("HealthRegistry")
+            .field("components", &names)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl HealthRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the upper bound for a single check; a check that takes longer reports `down`.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Register `check` as component `name` for the given probes.
+    ///
+    /// Registering an existing name replaces its check.
+    pub fn register<F, Fut>(&self, name: impl Into<String>, probes: &[Probe], check: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HealthCheck> + Send + 'static,
+    {
+        let probes = probes.to_vec();
+        let check = Arc::new(check);
+        self.insert(
+            name.into(),
+            Arc::new(move |probe| {
+                if !probes.contains(&probe) {
+                    return Box::pin(async { None });
+                }
+                let fut = check();
+                Box::pin(async move { Some(fut.await) })
+            }),
+        );
+    }
+
+    /// Register a module's liveness and readiness checks as component `name`.
+    pub fn register_module(&self, name: impl Into<String>, module: Arc<dyn HealthCapability>) {
+        self.insert(
+            name.into(),
+            Arc::new(move |probe| {
+                let module = Arc::clone(&module);
+                Box::pin(async move {
+                    Some(match probe {
+                        Probe::Liveness => module.liveness().await,
+                        Probe::Readiness => module.readiness().await,
+                    })
+                })
+            }),
+        );
+    }
+
+    fn insert(&self, name: String, check: CheckFn) {
+        let mut components = self.components.write();
+        if let Some(existing) = components.iter_mut().find(|c| c.name == name) {
+            existing.check = check;
+        } else {
+            components.push(Component { name, check });
+        }
+    }
+
+    /// Whether a component named `name` is registered.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.components.read().iter().any(|c| c.name == name)
+    }
+
+    /// Run every check taking part in `probe` and aggregate the results.
+    pub async fn check(&self, probe: Probe) -> HealthReport {
+        // Collected first so the lock is released before any check runs
+        let runs: Vec<_> = self
+            .components
+            .read()
+            .iter()
+            .map(|c| self.run(c.name.clone(), Arc::clone(&c.check), probe))
+            .collect();
+
+        let results = join_all(runs).await;
+        let components: Vec<ComponentHealth> = results.into_iter().flatten().collect();
+        let status = components
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(HealthStatus::Up);
+
+        HealthReport { status, components }
+    }
+
+    /// Run the check of component `name` for `probe`.
+    ///
+    /// Returns `None` if there is no such component or it does not take part in the probe.
+    pub async fn check_component(&self, name: &str, probe: Probe) -> Option<ComponentHealth> {
+        let check = self
+            .components
+            .read()
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| Arc::clone(&c.check))?;
+        self.run(name.to_owned(), check, probe).await
+    }
+
+    async fn run(&self, name: String, check: CheckFn, probe: Probe) -> Option<ComponentHealth> {
+        let started = Instant::now();
+        let result = match tokio::time::timeout(self.timeout, check(probe)).await {
+            Ok(result) => result?,
+            Err(_) => HealthCheck::down(format!(
+                "check timed out after {}ms",
+                self.timeout.as_millis()
+            )),
+        };
+        if result.status != HealthStatus::Up {
+            tracing::debug!(
+                component = %name,
+                ?probe,
+                status = ?result.status,
+                details = ?result.details,
+                "Health check not up"
+            );
+        }
+
+        Some(ComponentHealth {
+            name,
+            status: result.status,
+            details: result.details,
+            duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct FlakyModule;
+
+    #[async_trait]
+    impl HealthCapability for FlakyModule {
+        async fn readiness(&self) -> HealthCheck {
+            HealthCheck::degraded("cache cold")
+        }
+    }
+
+    #[tokio::test]
+    async fn aggregates_worst_status_per_probe() {
+        let registry = HealthRegistry::new();
+        registry.register("database", &[Probe::Readiness], || async {
+            HealthCheck::down("connection refused")
+        });
+        registry.register_module("users", Arc::new(FlakyModule));
+
+        let live = registry.check(Probe::Liveness).await;
+        assert_eq!(live.status, HealthStatus::Up);
+        assert_eq!(live.components.len(), 1, "database is readiness-only");
+
+        let ready = registry.check(Probe::Readiness).await;
+        assert_eq!(ready.status, HealthStatus::Down);
+        assert!(!ready.is_available());
+        assert_eq!(ready.components.len(), 2);
+
+        let users = registry
+            .check_component("users", Probe::Readiness)
+            .await
+            .unwrap();
+        assert_eq!(users.status, HealthStatus::Degraded);
+        assert_eq!(users.details.as_deref(), Some("cache cold"));
+    }
+
+    #[tokio::test]
+    async fn slow_check_is_reported_down() {
+        let registry = HealthRegistry::new().with_timeout(Duration::from_millis(20));
+        registry.register("slow", &[Probe::Liveness], || async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            HealthCheck::up()
+        });
+
+        let report = registry.check(Probe::Liveness).await;
+        assert_eq!(report.status, HealthStatus::Down);
+        assert!(
+            report.components[0]
+                .details
+                .as_deref()
+                .unwrap()
+                .contains("timed out")
+        );
+    }
+
+    #[tokio::test]
+    async fn registering_same_name_replaces_check() {
+        let registry = HealthRegistry::new();
+        registry.register("hub", &[Probe::Readiness], || async {
+            HealthCheck::down("not listening")
+        });
+        registry.register("hub", &[Probe::Readiness], || async { HealthCheck::up() });
+
+        let report = registry.check(Probe::Readiness).await;
+        assert_eq!(report.components.len(), 1);
+        assert_eq!(report.status, HealthStatus::Up);
+    }
+}
@@ -0,0 +1,619 @@
+//! Resumable SSE streams with `Last-Event-ID` replay
+//!
+//! [`ResumableSseBroadcaster`] numbers events with monotonically increasing ids and keeps
+//! the most recent ones in a [`ReplayStore`]. A browser reconnecting with the
+//! `Last-Event-ID` header receives the events it missed before the live stream resumes.
+//! When those events are no longer retained, a [`GAP_EVENT`] is sent first so the client
+//! knows to refetch its state instead of silently missing updates.
+//!
+//! ```rust,ignore
+//! let events = ResumableSseBroadcaster::<UserEvent>::new(1024);
+//!
+//! // Publisher
+//! events.send(&UserEvent::Created { id }).await?;
+//!
+//! // Handler, documented with `OperationBuilder::sse_json_resumable::<UserEvent>`
+//! async fn stream(Extension(events): Extension<ResumableSseBroadcaster<UserEvent>>, headers: HeaderMap)
+//!     -> impl IntoResponse
+//! {
+//!     events.sse_response(&headers).await
+//! }
+//! ```
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures_core::Stream;
+use futures_util::StreamExt;
+use modkit_db::DbHandle;
+use parking_lot::Mutex;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue, ConnectionTrait, QueryOrder, QuerySelect};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Request header carrying the id of the last event a reconnecting client received.
+pub const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+/// `event:` name of the notice sent when missed events can no longer be replayed.
+pub const GAP_EVENT: &str = "gap";
+
+/// An event as retained for replay, with its JSON payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredEvent {
+    pub id: u64,
+    pub data: Arc<str>,
+}
+
+/// Payload of a [`GAP_EVENT`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+pub struct SseGap {
+    /// Last event id the client had received.
+    pub last_event_id: u64,
+    /// Oldest event id that is still replayed, if any.
+    pub oldest_available_id: Option<u64>,
+}
+
+/// One item of a resumable subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayItem {
+    Event(StoredEvent),
+    Gap(SseGap),
+}
+
+/// Bounded log of recent events used to replay them to reconnecting clients.
+#[async_trait]
+pub trait ReplayStore: Send + Sync + 'static {
+    /// Retain `event`, evicting the oldest events beyond the store's capacity.
+    async fn append(&self, event: &StoredEvent) -> anyhow::Result<()>;
+
+    /// Retained events with an id greater than `after`, oldest first.
+    async fn after(&self, after: u64) -> anyhow::Result<Vec<StoredEvent>>;
+
+    /// Id of the newest retained event, to continue numbering after a restart.
+    async fn last_id(&self) -> anyhow::Result<Option<u64>>;
+}
+
+/// In-memory ring buffer of the most recent events.
+#[derive(Debug)]
+pub struct MemoryReplayStore {
+    capacity: usize,
+    events: Mutex<VecDeque<StoredEvent>>,
+}
+
+impl MemoryReplayStore {
+    /// A store retaining up to `capacity` events (at least one).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+}
+
+#[async_trait]
+impl ReplayStore for MemoryReplayStore {
+    async fn append(&self, event: &StoredEvent) -> anyhow::Result<()> {
+        let mut events = self.events.lock();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event.clone());
+        Ok(())
+    }
+
+    async fn after(&self, after: u64) -> anyhow::Result<Vec<StoredEvent>> {
+        let events = self.events.lock();
+        Ok(events.iter().filter(|e| e.id > after).cloned().collect())
+    }
+
+    async fn last_id(&self) -> anyhow::Result<Option<u64>> {
+        Ok(self.events.lock().back().map(|e| e.id))
+    }
+}
+
+mod replay_entity {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "modkit_sse_replay")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub stream: String,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: i64,
+        #[sea_orm(column_type = "Text")]
+        pub data: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// Database-backed replay store, so clients can resume across restarts of the publisher.
+///
+/// Several streams can share the `modkit_sse_replay` table; each keeps its own `capacity`.
+pub struct DbReplayStore {
+    db: Arc<DbHandle>,
+    stream: String,
+    capacity: u64,
+}
+
+impl DbReplayStore {
+    #[must_use]
+    pub fn new(db: Arc<DbHandle>, stream: impl Into<String>, capacity: u64) -> Self {
+        Self {
+            db,
+            stream: stream.into(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Create the replay table if it does not exist yet; call it from a module migration.
+    ///
+    /// # Errors
+    /// Returns an error if the DDL statement fails.
+    pub async fn create_table<C: ConnectionTrait>(conn: &C) -> Result<(), DbErr> {
+        conn.execute_unprepared(
+            r"
+CREATE TABLE IF NOT EXISTS modkit_sse_replay (
+    stream VARCHAR(255) NOT NULL,
+    id BIGINT NOT NULL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (stream, id)
+);
+            ",
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for DbReplayStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DbReplayStore")
+            .field("stream", &self.stream)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl ReplayStore for DbReplayStore {
+    async fn append(&self, event: &StoredEvent) -> anyhow::Result<()> {
+        let secure = self.db.sea_secure();
+        let conn = secure.conn();
+        let id = i64::try_from(event.id)?;
+
+        replay_entity::Entity::insert(replay_entity::ActiveModel {
+            stream: ActiveValue::Set(self.stream.clone()),
+            id: ActiveValue::Set(id),
+            data: ActiveValue::Set(event.data.to_string()),
+        })
+        .exec(conn)
+        .await?;
+
+        let evict_up_to = id.saturating_sub(i64::try_from(self.capacity)?);
+        replay_entity::Entity::delete_many()
+            .filter(replay_entity::Column::Stream.eq(self.stream.as_str()))
+            .filter(replay_entity::Column::Id.lte(evict_up_to))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn after(&self, after: u64) -> anyhow::Result<Vec<StoredEvent>> {
+        let rows = replay_entity::Entity::find()
+            .filter(replay_entity::Column::Stream.eq(self.stream.as_str()))
+            .filter(replay_entity::Column::Id.gt(i64::try_from(after).unwrap_or(i64::MAX)))
+            .order_by_asc(replay_entity::Column::Id)
+            .all(self.db.sea_secure().conn())
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(StoredEvent {
+                    id: u64::try_from(row.id)?,
+                    data: row.data.into(),
+                })
+            })
+            .collect()
+    }
+
+    async fn last_id(&self) -> anyhow::Result<Option<u64>> {
+        let last = replay_entity::Entity::find()
+            .filter(replay_entity::Column::Stream.eq(self.stream.as_str()))
+            .order_by_desc(replay_entity::Column::Id)
+            .limit(1)
+            .one(self.db.sea_secure().conn())
+            .await?;
+        Ok(last.map(|row| u64::try_from(row.id)).transpose()?)
+    }
+}
+
+/// Extract the `Last-Event-ID` of a reconnecting client; unparsable values are ignored.
+#[must_use]
+pub fn last_event_id(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(LAST_EVENT_ID)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// Typed SSE broadcaster with event ids and `Last-Event-ID` replay.
+///
+/// Unlike [`super::sse::SseBroadcaster`], a subscriber that lags behind the live channel
+/// catches up from the replay store instead of losing events.
+pub struct ResumableSseBroadcaster<T> {
+    inner: Arc<Inner>,
+    _event: PhantomData<fn(T)>,
+}
+
+struct Inner {
+    store: Arc<dyn ReplayStore>,
+    live: broadcast::Sender<StoredEvent>,
+    /// Id of the next event; held while an event is stored and broadcast to keep ids ordered.
+    next_id: tokio::sync::Mutex<u64>,
+    event_name: Option<String>,
+}
+
+impl<T> Clone for ResumableSseBroadcaster<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ResumableSseBroadcaster<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResumableSseBroadcaster")
+            .field("event_name", &self.inner.event_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Serialize + 'static> ResumableSseBroadcaster<T> {
+    /// A broadcaster replaying up to `capacity` events from memory.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::from_parts(Arc::new(MemoryReplayStore::new(capacity)), capacity, 1)
+    }
+
+    /// A broadcaster replaying from `store`, continuing the ids already stored in it.
+    ///
+    /// `capacity` bounds the live channel; lagging subscribers catch up from the store.
+    ///
+    /// # Errors
+    /// Returns an error if the store cannot be read.
+    pub async fn with_store(store: Arc<dyn ReplayStore>, capacity: usize) -> anyhow::Result<Self> {
+        let next_id = store.last_id().await?.map_or(1, |id| id.saturating_add(1));
+        Ok(Self::from_parts(store, capacity, next_id))
+    }
+
+    fn from_parts(store: Arc<dyn ReplayStore>, capacity: usize, next_id: u64) -> Self {
+        let (live, _) = broadcast::channel(capacity.max(1));
+        Self {
+            inner: Arc::new(Inner {
+                store,
+                live,
+                next_id: tokio::sync::Mutex::new(next_id),
+                event_name: None,
+            }),
+            _event: PhantomData,
+        }
+    }
+
+    /// Set a constant `event:` name for all events.
+    ///
+    /// # Panics
+    /// Panics if the broadcaster has already been cloned.
+    #[must_use]
+    pub fn with_event_name(mut self, name: impl Into<String>) -> Self {
+        #[allow(clippy::expect_used)]
+        let inner = Arc::get_mut(&mut self.inner)
+            .expect("set the event name before cloning the broadcaster");
+        inner.event_name = Some(name.into());
+        self
+    }
+
+    /// Store and broadcast an event, returning its id.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be encoded or stored; it is not broadcast then.
+    pub async fn send(&self, value: &T) -> anyhow::Result<u64> {
+        let data: Arc<str> = serde_json::to_string(value)?.into();
+        let mut next_id = self.inner.next_id.lock().await;
+        let event = StoredEvent { id: *next_id, data };
+
+        self.inner.store.append(&event).await?;
+        *next_id += 1;
+        let id = event.id;
+        // No subscribers is not an error
+        let _ = self.inner.live.send(event);
+        Ok(id)
+    }
+
+    /// Subscribe to events after `last_event_id`, or to new events only when `None`.
+    pub async fn subscribe(
+        &self,
+        last_event_id: Option<u64>,
+    ) -> impl Stream<Item = ReplayItem> + Send + use<T> {
+        let live = self.inner.live.subscribe();
+        let mut subscription = Subscription {
+            store: Arc::clone(&self.inner.store),
+            live,
+            pending: VecDeque::new(),
+            cursor: 0,
+        };
+
+        let next_id = *self.inner.next_id.lock().await;
+        match last_event_id {
+            // Events already numbered may still be in the live channel; skip them
+            None => subscription.cursor = next_id - 1,
+            Some(last) if last >= next_id => {
+                // The client saw ids this stream never issued (e.g. an in-memory store restarted)
+                subscription.cursor = next_id - 1;
+                subscription.pending.push_back(ReplayItem::Gap(SseGap {
+                    last_event_id: last,
+                    oldest_available_id: None,
+                }));
+            }
+            Some(last) => {
+                subscription.cursor = last;
+                subscription.catch_up().await;
+            }
+        }
+
+        futures_util::stream::unfold(subscription, |mut subscription| async move {
+            let item = subscription.next().await?;
+            Some((item, subscription))
+        })
+    }
+
+    /// SSE response resuming after the request's `Last-Event-ID`, with keepalive pings.
+    pub async fn sse_response(
+        &self,
+        headers: &HeaderMap,
+    ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + use<T>> {
+        let event_name = self.inner.event_name.clone();
+        let stream = self
+            .subscribe(last_event_id(headers))
+            .await
+            .map(move |item| Ok(to_sse_event(item, event_name.as_deref())));
+
+        Sse::new(stream).keep_alive(
+            KeepAlive::new()
+                .interval(Duration::from_secs(15))
+                .text("keepalive"),
+        )
+    }
+}
+
+struct Subscription {
+    store: Arc<dyn ReplayStore>,
+    live: broadcast::Receiver<StoredEvent>,
+    pending: VecDeque<ReplayItem>,
+    /// Id of the last event handed to the client.
+    cursor: u64,
+}
+
+impl Subscription {
+    async fn next(&mut self) -> Option<ReplayItem> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+            match self.live.recv().await {
+                Ok(event) if event.id > self.cursor => {
+                    self.cursor = event.id;
+                    return Some(ReplayItem::Event(event));
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(_)) => self.catch_up().await,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Queue the stored events after the cursor, preceded by a gap notice if some were evicted.
+    async fn catch_up(&mut self) {
+        let events = match self.store.after(self.cursor).await {
+            Ok(events) => events,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read SSE replay store");
+                self.pending.push_back(ReplayItem::Gap(SseGap {
+                    last_event_id: self.cursor,
+                    oldest_available_id: None,
+                }));
+                return;
+            }
+        };
+
+        // The store always retains the newest event, so nothing stored means nothing missed
+        if let Some(first) = events.first()
+            && first.id > self.cursor + 1
+        {
+            self.pending.push_back(ReplayItem::Gap(SseGap {
+                last_event_id: self.cursor,
+                oldest_available_id: Some(first.id),
+            }));
+        }
+        if let Some(last) = events.last() {
+            self.cursor = last.id;
+        }
+        self.pending
+            .extend(events.into_iter().map(ReplayItem::Event));
+    }
+}
+
+fn to_sse_event(item: ReplayItem, event_name: Option<&str>) -> Event {
+    match item {
+        ReplayItem::Event(event) => {
+            let sse = Event::default().id(event.id.to_string()).data(&*event.data);
+            match event_name {
+                Some(name) => sse.event(name),
+                None => sse,
+            }
+        }
+        ReplayItem::Gap(gap) => Event::default()
+            .event(GAP_EVENT)
+            .json_data(&gap)
+            .unwrap_or_else(|_| Event::default().event(GAP_EVENT).data("{}")),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use tokio::time::timeout;
+
+    async fn next(events: &mut (impl Stream<Item = ReplayItem> + Unpin)) -> ReplayItem {
+        timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect("item expected")
+            .expect("stream ended")
+    }
+
+    fn event(id: u64, data: &str) -> ReplayItem {
+        ReplayItem::Event(StoredEvent {
+            id,
+            data: data.into(),
+        })
+    }
+
+    async fn send_all(broadcaster: &ResumableSseBroadcaster<u32>, values: &[u32]) {
+        for value in values {
+            broadcaster.send(value).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_get_increasing_ids() {
+        let broadcaster = ResumableSseBroadcaster::<u32>::new(8);
+        let mut events = Box::pin(broadcaster.subscribe(None).await);
+
+        assert_eq!(broadcaster.send(&10).await.unwrap(), 1);
+        assert_eq!(broadcaster.send(&20).await.unwrap(), 2);
+
+        assert_eq!(next(&mut events).await, event(1, "10"));
+        assert_eq!(next(&mut events).await, event(2, "20"));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_missed_events_then_goes_live() {
+        let broadcaster = ResumableSseBroadcaster::<u32>::new(8);
+        send_all(&broadcaster, &[10, 20, 30]).await;
+
+        let mut events = Box::pin(broadcaster.subscribe(Some(1)).await);
+        send_all(&broadcaster, &[40]).await;
+
+        assert_eq!(next(&mut events).await, event(2, "20"));
+        assert_eq!(next(&mut events).await, event(3, "30"));
+        assert_eq!(next(&mut events).await, event(4, "40"));
+    }
+
+    #[tokio::test]
+    async fn test_evicted_events_are_reported_as_gap() {
+        let broadcaster = ResumableSseBroadcaster::<u32>::new(2);
+        send_all(&broadcaster, &[10, 20, 30, 40]).await;
+
+        let mut events = Box::pin(broadcaster.subscribe(Some(1)).await);
+        assert_eq!(
+            next(&mut events).await,
+            ReplayItem::Gap(SseGap {
+                last_event_id: 1,
+                oldest_available_id: Some(3),
+            })
+        );
+        assert_eq!(next(&mut events).await, event(3, "30"));
+        assert_eq!(next(&mut events).await, event(4, "40"));
+
+        // Ids from before a restart of an in-memory stream cannot be replayed at all
+        let mut events = Box::pin(broadcaster.subscribe(Some(99)).await);
+        assert_eq!(
+            next(&mut events).await,
+            ReplayItem::Gap(SseGap {
+                last_event_id: 99,
+                oldest_available_id: None,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_catches_up_from_store() {
+        let store = Arc::new(MemoryReplayStore::new(16));
+        // Live channel of 2 events, replay of 16
+        let broadcaster = ResumableSseBroadcaster::<u32>::with_store(store, 2)
+            .await
+            .unwrap();
+        let mut events = Box::pin(broadcaster.subscribe(None).await);
+
+        send_all(&broadcaster, &[1, 2, 3, 4, 5]).await;
+
+        for id in 1..=5 {
+            assert_eq!(next(&mut events).await, event(id, &id.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_db_store_continues_ids_after_restart() {
+        let opts = modkit_db::ConnectOpts {
+            max_conns: Some(1),
+            ..Default::default()
+        };
+        let db = Arc::new(DbHandle::connect("sqlite::memory:", opts).await.unwrap());
+        DbReplayStore::create_table(db.sea_secure().conn())
+            .await
+            .unwrap();
+
+        let store = Arc::new(DbReplayStore::new(Arc::clone(&db), "users", 2));
+        let broadcaster = ResumableSseBroadcaster::<u32>::with_store(store, 8)
+            .await
+            .unwrap();
+        send_all(&broadcaster, &[10, 20, 30]).await;
+
+        // A new publisher on the same store keeps numbering and replays retained events
+        let store = Arc::new(DbReplayStore::new(Arc::clone(&db), "users", 2));
+        let restarted = ResumableSseBroadcaster::<u32>::with_store(store, 8)
+            .await
+            .unwrap();
+        assert_eq!(restarted.send(&40).await.unwrap(), 4);
+
+        let mut events = Box::pin(restarted.subscribe(Some(1)).await);
+        assert_eq!(
+            next(&mut events).await,
+            ReplayItem::Gap(SseGap {
+                last_event_id: 1,
+                oldest_available_id: Some(3),
+            })
+        );
+        assert_eq!(next(&mut events).await, event(3, "30"));
+        assert_eq!(next(&mut events).await, event(4, "40"));
+    }
+
+    #[test]
+    fn test_last_event_id_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(last_event_id(&headers), None);
+
+        headers.insert(LAST_EVENT_ID, " 42 ".parse().unwrap());
+        assert_eq!(last_event_id(&headers), Some(42));
+
+        headers.insert(LAST_EVENT_ID, "abc".parse().unwrap());
+        assert_eq!(last_event_id(&headers), None);
+    }
+}
@@ -0,0 +1,22 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "modkit_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub scheduler: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub name: String,
+    pub status: String,
+    /// Epoch milliseconds; `None` once the job has nothing left to run.
+    pub next_run_at: Option<i64>,
+    pub attempts: i32,
+    pub last_run_at: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
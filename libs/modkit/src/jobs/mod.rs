@@ -0,0 +1,472 @@
+//! Background jobs: cron, interval and one-off delayed work
+//!
+//! A module declares its jobs on a [`JobScheduler`] instead of hand-rolling a lifecycle loop.
+//! Job state lives in the `modkit_jobs` table of the module's database, so schedules, retries
+//! and pauses survive restarts, and each run is leased with an advisory lock so only one
+//! replica executes it:
+//!
+//! ```rust,ignore
+//! use modkit::jobs::{self, Job, JobRegistry, JobScheduler, Schedule};
+//! use modkit::lifecycle::WithLifecycle;
+//!
+//! // In a migration
+//! jobs::create_table(manager.get_connection()).await?;
+//!
+//! // In `init`
+//! let svc = Arc::clone(&service);
+//! let scheduler = Arc::new(JobScheduler::new(ctx.db_required()?, "users_info").with_job(
+//!     Job::new("purge_sessions", Schedule::cron("0 */15 * * * *")?, move |_job| {
+//!         let svc = Arc::clone(&svc);
+//!         async move { svc.purge_sessions().await }
+//!     }),
+//! ));
+//! ctx.client_hub().get::<JobRegistry>()?.register(Arc::clone(&scheduler));
+//! let runner = WithLifecycle::from_arc(scheduler);
+//! ```
+//!
+//! - a failed run is retried with exponential backoff; after [`RetryPolicy::max_attempts`]
+//!   consecutive failures the job moves to [`JobStatus::DeadLetter`] until it is triggered again
+//! - paused jobs keep their schedule but are not run until resumed
+//! - the [`JobRegistry`] in the `ClientHub` lists the schedulers of the process; the API
+//!   gateway exposes it over REST to list, trigger, pause and resume jobs
+
+mod entity;
+mod scheduler;
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use sea_orm::{ConnectionTrait, DbErr};
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+use utoipa::ToSchema;
+
+pub use scheduler::{JobScheduler, SchedulerConfig};
+
+/// Name of the job state table.
+pub const JOBS_TABLE: &str = "modkit_jobs";
+
+/// Errors of job scheduling and management.
+#[derive(Debug, thiserror::Error)]
+pub enum JobError {
+    #[error("invalid cron expression '{expression}': {reason}")]
+    InvalidCron { expression: String, reason: String },
+    #[error("job '{scheduler}/{name}' not found")]
+    NotFound { scheduler: String, name: String },
+    #[error("job '{scheduler}/{name}' is paused")]
+    Paused { scheduler: String, name: String },
+    #[error(transparent)]
+    Database(#[from] modkit_db::DbError),
+}
+
+impl From<DbErr> for JobError {
+    fn from(e: DbErr) -> Self {
+        Self::Database(e.into())
+    }
+}
+
+/// When a job runs.
+#[derive(Debug, Clone)]
+pub enum Schedule {
+    /// On the occurrences of a cron expression (UTC).
+    Cron(Box<croner::Cron>),
+    /// Repeatedly, with the interval between the end of a run and the start of the next.
+    Interval(Duration),
+    /// Once, the given delay after the job was first registered.
+    Once(Duration),
+}
+
+impl Schedule {
+    /// Parse a cron expression with five (minute precision) or six (second precision) fields.
+    ///
+    /// # Errors
+    /// Returns [`JobError::InvalidCron`] if the expression cannot be parsed.
+    pub fn cron(expression: &str) -> Result<Self, JobError> {
+        croner::Cron::new(expression)
+            .with_seconds_optional()
+            .parse()
+            .map(|cron| Self::Cron(Box::new(cron)))
+            .map_err(|e| JobError::InvalidCron {
+                expression: expression.to_owned(),
+                reason: e.to_string(),
+            })
+    }
+
+    #[must_use]
+    pub const fn every(interval: Duration) -> Self {
+        Self::Interval(interval)
+    }
+
+    #[must_use]
+    pub const fn once_after(delay: Duration) -> Self {
+        Self::Once(delay)
+    }
+
+    /// Time of the first run of a newly registered job.
+    fn first_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Cron(cron) => cron.find_next_occurrence(&now, false).ok(),
+            Self::Interval(delay) | Self::Once(delay) => add(now, *delay),
+        }
+    }
+
+    /// Time of the run following one that finished at `now`, if any.
+    fn next_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Cron(cron) => cron.find_next_occurrence(&now, false).ok(),
+            Self::Interval(interval) => add(now, *interval),
+            Self::Once(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cron(cron) => write!(f, "cron {}", cron.as_str()),
+            Self::Interval(interval) => write!(f, "every {}", DisplayDuration(*interval)),
+            Self::Once(delay) => write!(f, "once after {}", DisplayDuration(*delay)),
+        }
+    }
+}
+
+/// Whole seconds, or milliseconds for sub-second precision.
+struct DisplayDuration(Duration);
+
+impl fmt::Display for DisplayDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.subsec_nanos() == 0 {
+            write!(f, "{}s", self.0.as_secs())
+        } else {
+            write!(f, "{}ms", self.0.as_millis())
+        }
+    }
+}
+
+fn add(time: DateTime<Utc>, delay: Duration) -> Option<DateTime<Utc>> {
+    time.checked_add_signed(chrono::Duration::from_std(delay).ok()?)
+}
+
+/// Retry behaviour of a failing job.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Consecutive failed runs after which the job is dead-lettered.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the retry delay.
+    pub max_backoff: Duration,
+    /// Factor applied to the retry delay after each failed attempt.
+    pub backoff_multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_mins(5),
+            backoff_multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Retry delay after the given number of failed attempts (at least 1).
+    fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = i32::try_from(attempts.saturating_sub(1)).unwrap_or(i32::MAX);
+        let factor = self.backoff_multiplier.powi(exponent);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+}
+
+/// Information handed to a running job.
+#[derive(Debug, Clone)]
+pub struct JobContext {
+    pub scheduler: String,
+    pub name: String,
+    /// 1 for a regular run, higher for retries.
+    pub attempt: u32,
+    /// Fires when the scheduler shuts down; long-running jobs should stop early.
+    pub cancel: CancellationToken,
+}
+
+/// The work of a job; implemented for async closures taking a [`JobContext`].
+#[async_trait]
+pub trait JobHandler: Send + Sync + 'static {
+    /// Run the job once.
+    ///
+    /// # Errors
+    /// Returning an error schedules a retry according to the job's [`RetryPolicy`].
+    async fn run(&self, ctx: JobContext) -> anyhow::Result<()>;
+}
+
+#[async_trait]
+impl<F, Fut> JobHandler for F
+where
+    F: Fn(JobContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+{
+    async fn run(&self, ctx: JobContext) -> anyhow::Result<()> {
+        self(ctx).await
+    }
+}
+
+/// A named job with its schedule, retry policy and handler.
+#[derive(Clone)]
+pub struct Job {
+    name: String,
+    schedule: Schedule,
+    retry: RetryPolicy,
+    handler: Arc<dyn JobHandler>,
+}
+
+impl Job {
+    /// A job with the default [`RetryPolicy`]. `name` must be unique within its scheduler.
+    pub fn new(name: impl Into<String>, schedule: Schedule, handler: impl JobHandler) -> Self {
+        Self {
+            name: name.into(),
+            schedule,
+            retry: RetryPolicy::default(),
+            handler: Arc::new(handler),
+        }
+    }
+
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+}
+
+impl fmt::Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Job")
+            .field("name", &self.name)
+            .field("schedule", &self.schedule)
+            .field("retry", &self.retry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Persisted state of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for its next run.
+    Scheduled,
+    /// Not run until resumed.
+    Paused,
+    /// A one-off job that ran successfully.
+    Completed,
+    /// Exhausted its retries; not run until triggered.
+    DeadLetter,
+}
+
+impl JobStatus {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::DeadLetter => "dead_letter",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "paused" => Self::Paused,
+            "completed" => Self::Completed,
+            "dead_letter" => Self::DeadLetter,
+            _ => Self::Scheduled,
+        }
+    }
+}
+
+/// Snapshot of a job for listings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobInfo {
+    pub scheduler: String,
+    pub name: String,
+    pub schedule: String,
+    pub status: JobStatus,
+    /// Whether this process is running the job right now.
+    pub running: bool,
+    /// Consecutive failed runs.
+    pub attempts: u32,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+/// The job schedulers of a process, registered in the `ClientHub` by the runtime.
+#[derive(Debug, Default)]
+pub struct JobRegistry {
+    schedulers: RwLock<Vec<Arc<JobScheduler>>>,
+}
+
+impl JobRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a scheduler, replacing a previously registered one with the same name.
+    pub fn register(&self, scheduler: Arc<JobScheduler>) {
+        let mut schedulers = self.schedulers.write();
+        schedulers.retain(|s| s.name() != scheduler.name());
+        schedulers.push(scheduler);
+    }
+
+    #[must_use]
+    pub fn schedulers(&self) -> Vec<Arc<JobScheduler>> {
+        self.schedulers.read().clone()
+    }
+
+    /// Jobs of all registered schedulers.
+    ///
+    /// # Errors
+    /// Returns an error if the state of a scheduler's jobs cannot be read.
+    pub async fn list(&self) -> Result<Vec<JobInfo>, JobError> {
+        let mut jobs = Vec::new();
+        for scheduler in self.schedulers() {
+            jobs.extend(scheduler.list().await?);
+        }
+        Ok(jobs)
+    }
+
+    /// Run a job as soon as possible; see [`JobScheduler::trigger`].
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs, [`JobError::Paused`] for paused ones.
+    pub async fn trigger(&self, scheduler: &str, name: &str) -> Result<(), JobError> {
+        self.find(scheduler, name)?.trigger(name).await
+    }
+
+    /// Stop running a job until it is resumed.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs.
+    pub async fn pause(&self, scheduler: &str, name: &str) -> Result<(), JobError> {
+        self.find(scheduler, name)?.pause(name).await
+    }
+
+    /// Resume a paused job.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs.
+    pub async fn resume(&self, scheduler: &str, name: &str) -> Result<(), JobError> {
+        self.find(scheduler, name)?.resume(name).await
+    }
+
+    fn find(&self, scheduler: &str, name: &str) -> Result<Arc<JobScheduler>, JobError> {
+        self.schedulers
+            .read()
+            .iter()
+            .find(|s| s.name() == scheduler)
+            .cloned()
+            .ok_or_else(|| JobError::NotFound {
+                scheduler: scheduler.to_owned(),
+                name: name.to_owned(),
+            })
+    }
+}
+
+/// Create the job state table if it does not exist yet.
+///
+/// Call it from a module migration; it is safe to run from several modules sharing a database.
+///
+/// # Errors
+/// Returns an error if the DDL statement fails.
+pub async fn create_table<C: ConnectionTrait>(conn: &C) -> Result<(), DbErr> {
+    conn.execute_unprepared(
+        r"
+CREATE TABLE IF NOT EXISTS modkit_jobs (
+    scheduler VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    next_run_at BIGINT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_run_at BIGINT NULL,
+    last_error TEXT NULL,
+    PRIMARY KEY (scheduler, name)
+);
+        ",
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+    }
+
+    #[test]
+    fn test_cron_schedule_accepts_minute_and_second_precision() {
+        let now = at("2026-01-01T10:07:30Z");
+
+        let hourly = Schedule::cron("0 * * * *").unwrap();
+        assert_eq!(hourly.first_run(now), Some(at("2026-01-01T11:00:00Z")));
+
+        let every_ten_seconds = Schedule::cron("*/10 * * * * *").unwrap();
+        assert_eq!(
+            every_ten_seconds.next_run(now),
+            Some(at("2026-01-01T10:07:40Z"))
+        );
+
+        assert!(matches!(
+            Schedule::cron("not a cron"),
+            Err(JobError::InvalidCron { .. })
+        ));
+    }
+
+    #[test]
+    fn test_interval_and_once_schedules() {
+        let now = at("2026-01-01T10:00:00Z");
+
+        let interval = Schedule::every(Duration::from_secs(90));
+        assert_eq!(interval.first_run(now), Some(at("2026-01-01T10:01:30Z")));
+        assert_eq!(interval.next_run(now), Some(at("2026-01-01T10:01:30Z")));
+
+        let once = Schedule::once_after(Duration::from_mins(1));
+        assert_eq!(once.first_run(now), Some(at("2026-01-01T10:01:00Z")));
+        assert_eq!(once.next_run(now), None);
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let retry = RetryPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            backoff_multiplier: 2.0,
+            ..RetryPolicy::default()
+        };
+
+        assert_eq!(retry.backoff(1), Duration::from_secs(1));
+        assert_eq!(retry.backoff(3), Duration::from_secs(4));
+        assert_eq!(retry.backoff(5), Duration::from_secs(10));
+        assert_eq!(retry.backoff(u32::MAX), Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,671 @@
+//! Execution of persisted jobs.
+
+use std::collections::HashSet;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
+use modkit_db::{DbHandle, LockConfig};
+use parking_lot::Mutex;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+use tokio::sync::Notify;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+
+use super::{Job, JobContext, JobError, JobInfo, JobStatus, entity};
+use crate::lifecycle::Runnable;
+
+/// Advisory lock namespace of job leases.
+const LOCK_NAMESPACE: &str = "jobs";
+
+/// Polling settings of a [`JobScheduler`].
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    /// Pause between checks for due jobs.
+    pub poll_interval: Duration,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Runs the jobs of one module against the job state in its database.
+///
+/// Several replicas may run a scheduler with the same name and jobs against one database;
+/// each run of a job is leased with an advisory lock, so only one of them executes it.
+pub struct JobScheduler {
+    db: Arc<DbHandle>,
+    name: String,
+    jobs: Vec<Job>,
+    config: SchedulerConfig,
+    running: Mutex<HashSet<String>>,
+    wake: Notify,
+}
+
+impl JobScheduler {
+    /// A scheduler without jobs. `name` scopes the job names, usually the module name.
+    pub fn new(db: Arc<DbHandle>, name: impl Into<String>) -> Self {
+        Self {
+            db,
+            name: name.into(),
+            jobs: Vec::new(),
+            config: SchedulerConfig::default(),
+            running: Mutex::new(HashSet::new()),
+            wake: Notify::new(),
+        }
+    }
+
+    /// Add a job, replacing a previously added one with the same name.
+    #[must_use]
+    pub fn with_job(mut self, job: Job) -> Self {
+        self.jobs.retain(|j| j.name != job.name);
+        self.jobs.push(job);
+        self
+    }
+
+    #[must_use]
+    pub fn with_config(mut self, config: SchedulerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Store the initial state of jobs that are not in the database yet.
+    ///
+    /// Jobs already known keep their state, so schedules and pauses survive restarts.
+    ///
+    /// # Errors
+    /// Returns an error if the job state cannot be written.
+    pub async fn register_jobs(&self) -> Result<(), JobError> {
+        for job in &self.jobs {
+            self.register_job(job).await?;
+        }
+        Ok(())
+    }
+
+    async fn register_job(&self, job: &Job) -> Result<(), JobError> {
+        let row = entity::ActiveModel {
+            scheduler: ActiveValue::Set(self.name.clone()),
+            name: ActiveValue::Set(job.name.clone()),
+            status: ActiveValue::Set(JobStatus::Scheduled.as_str().to_owned()),
+            next_run_at: ActiveValue::Set(job.schedule.first_run(Utc::now()).map(millis)),
+            attempts: ActiveValue::Set(0),
+            last_run_at: ActiveValue::Set(None),
+            last_error: ActiveValue::Set(None),
+        };
+        entity::Entity::insert(row)
+            .on_conflict(
+                OnConflict::columns([entity::Column::Scheduler, entity::Column::Name])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db.sea_secure().conn())
+            .await?;
+        Ok(())
+    }
+
+    /// Current state of this scheduler's jobs.
+    ///
+    /// # Errors
+    /// Returns an error if the job state cannot be read.
+    pub async fn list(&self) -> Result<Vec<JobInfo>, JobError> {
+        let rows = entity::Entity::find()
+            .filter(entity::Column::Scheduler.eq(self.name.as_str()))
+            .all(self.db.sea_secure().conn())
+            .await?;
+        let running = self.running.lock().clone();
+
+        Ok(self
+            .jobs
+            .iter()
+            .map(|job| {
+                let row = rows.iter().find(|row| row.name == job.name);
+                JobInfo {
+                    scheduler: self.name.clone(),
+                    name: job.name.clone(),
+                    schedule: job.schedule.to_string(),
+                    status: row.map_or(JobStatus::Scheduled, |row| JobStatus::parse(&row.status)),
+                    running: running.contains(&job.name),
+                    attempts: row.map_or(0, |row| u32::try_from(row.attempts).unwrap_or_default()),
+                    next_run_at: row.and_then(|row| row.next_run_at).and_then(from_millis),
+                    last_run_at: row.and_then(|row| row.last_run_at).and_then(from_millis),
+                    last_error: row.and_then(|row| row.last_error.clone()),
+                }
+            })
+            .collect())
+    }
+
+    /// Run a job as soon as possible, e.g. to retry a dead-lettered job or re-run a completed one.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs, [`JobError::Paused`] for paused ones.
+    pub async fn trigger(&self, name: &str) -> Result<(), JobError> {
+        self.run_at(name, Utc::now()).await?;
+        self.wake.notify_one();
+        Ok(())
+    }
+
+    /// Schedule the next run of a job at `at`, replacing its pending run.
+    ///
+    /// Combined with [`super::Schedule::Once`] this defers one-off work decided at runtime.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs, [`JobError::Paused`] for paused ones.
+    pub async fn run_at(&self, name: &str, at: DateTime<Utc>) -> Result<(), JobError> {
+        let row = self.state(name).await?;
+        if JobStatus::parse(&row.status) == JobStatus::Paused {
+            return Err(JobError::Paused {
+                scheduler: self.name.clone(),
+                name: name.to_owned(),
+            });
+        }
+
+        self.update(entity::ActiveModel {
+            status: ActiveValue::Set(JobStatus::Scheduled.as_str().to_owned()),
+            next_run_at: ActiveValue::Set(Some(millis(at))),
+            attempts: ActiveValue::Set(0),
+            ..key(&row)
+        })
+        .await
+    }
+
+    /// Stop running a job until it is resumed; a run in progress is not interrupted.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs.
+    pub async fn pause(&self, name: &str) -> Result<(), JobError> {
+        let row = self.state(name).await?;
+        self.update(entity::ActiveModel {
+            status: ActiveValue::Set(JobStatus::Paused.as_str().to_owned()),
+            ..key(&row)
+        })
+        .await
+    }
+
+    /// Resume a paused job; runs missed while paused are collapsed into one.
+    ///
+    /// # Errors
+    /// Returns [`JobError::NotFound`] for unknown jobs.
+    pub async fn resume(&self, name: &str) -> Result<(), JobError> {
+        let row = self.state(name).await?;
+        if JobStatus::parse(&row.status) != JobStatus::Paused {
+            return Ok(());
+        }
+
+        let status = if row.next_run_at.is_some() {
+            JobStatus::Scheduled
+        } else {
+            JobStatus::Completed
+        };
+        self.update(entity::ActiveModel {
+            status: ActiveValue::Set(status.as_str().to_owned()),
+            ..key(&row)
+        })
+        .await?;
+        self.wake.notify_one();
+        Ok(())
+    }
+
+    /// Run the due jobs one after another, regardless of what other replicas do.
+    ///
+    /// Returns the number of jobs run; jobs leased by another replica are skipped.
+    /// Job failures are recorded on the job and scheduled for retry; they are not returned
+    /// as errors.
+    ///
+    /// # Errors
+    /// Returns an error if the job state cannot be read or updated.
+    pub async fn run_pending(&self, cancel: &CancellationToken) -> Result<usize, JobError> {
+        let mut ran = 0;
+        for job in self.due_jobs().await? {
+            if self.run_job(job, cancel).await? {
+                ran += 1;
+            }
+        }
+        Ok(ran)
+    }
+
+    async fn due_jobs(&self) -> Result<Vec<&Job>, JobError> {
+        let rows = entity::Entity::find()
+            .filter(entity::Column::Scheduler.eq(self.name.as_str()))
+            .filter(entity::Column::Status.eq(JobStatus::Scheduled.as_str()))
+            .filter(entity::Column::NextRunAt.lte(millis(Utc::now())))
+            .all(self.db.sea_secure().conn())
+            .await?;
+        let running = self.running.lock();
+
+        Ok(self
+            .jobs
+            .iter()
+            .filter(|job| !running.contains(&job.name))
+            .filter(|job| rows.iter().any(|row| row.name == job.name))
+            .collect())
+    }
+
+    /// Lease and run one job if it is still due. Returns whether it ran.
+    async fn run_job(&self, job: &Job, cancel: &CancellationToken) -> Result<bool, JobError> {
+        let lock_config = LockConfig {
+            max_wait: None,
+            max_attempts: Some(1),
+            ..LockConfig::default()
+        };
+        let lease_key = format!("{}.{}", self.name, job.name);
+        let Some(lease) = self
+            .db
+            .try_lock(LOCK_NAMESPACE, &lease_key, lock_config)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let result = self.run_leased(job, cancel).await;
+        lease.release().await;
+        result
+    }
+
+    async fn run_leased(&self, job: &Job, cancel: &CancellationToken) -> Result<bool, JobError> {
+        // Another replica may have run it between the poll and the lease
+        let row = self.state(&job.name).await?;
+        let started = Utc::now();
+        let due = row.next_run_at.is_some_and(|at| at <= millis(started));
+        if JobStatus::parse(&row.status) != JobStatus::Scheduled || !due {
+            return Ok(false);
+        }
+
+        let attempt = u32::try_from(row.attempts)
+            .unwrap_or_default()
+            .saturating_add(1);
+        let ctx = JobContext {
+            scheduler: self.name.clone(),
+            name: job.name.clone(),
+            attempt,
+            cancel: cancel.clone(),
+        };
+        let outcome = match AssertUnwindSafe(job.handler.run(ctx)).catch_unwind().await {
+            Ok(outcome) => outcome,
+            Err(_) => Err(anyhow::anyhow!("job panicked")),
+        };
+
+        // Keep a pause requested while the job was running
+        let paused = JobStatus::parse(&self.state(&job.name).await?.status) == JobStatus::Paused;
+        let finished = Utc::now();
+        let (status, next_run_at, attempts, last_error) = match outcome {
+            Ok(()) => {
+                tracing::debug!(scheduler = %self.name, job = %job.name, "Job completed");
+                let next = job.schedule.next_run(finished);
+                let status = if next.is_some() {
+                    JobStatus::Scheduled
+                } else {
+                    JobStatus::Completed
+                };
+                (status, next, 0, None)
+            }
+            Err(e) if attempt >= job.retry.max_attempts => {
+                tracing::error!(
+                    scheduler = %self.name,
+                    job = %job.name,
+                    attempt,
+                    error = %format!("{e:#}"),
+                    "Job failed, moved to dead letter"
+                );
+                (JobStatus::DeadLetter, None, attempt, Some(format!("{e:#}")))
+            }
+            Err(e) => {
+                let retry_in = job.retry.backoff(attempt);
+                tracing::warn!(
+                    scheduler = %self.name,
+                    job = %job.name,
+                    attempt,
+                    ?retry_in,
+                    error = %format!("{e:#}"),
+                    "Job failed"
+                );
+                let next = super::add(finished, retry_in);
+                (JobStatus::Scheduled, next, attempt, Some(format!("{e:#}")))
+            }
+        };
+
+        self.update(entity::ActiveModel {
+            status: ActiveValue::Set(
+                if paused { JobStatus::Paused } else { status }
+                    .as_str()
+                    .to_owned(),
+            ),
+            next_run_at: ActiveValue::Set(next_run_at.map(millis)),
+            attempts: ActiveValue::Set(i32::try_from(attempts).unwrap_or(i32::MAX)),
+            last_run_at: ActiveValue::Set(Some(millis(started))),
+            last_error: ActiveValue::Set(last_error),
+            ..key(&row)
+        })
+        .await?;
+        Ok(true)
+    }
+
+    /// Persisted state of a job of this scheduler, registering it if needed.
+    async fn state(&self, name: &str) -> Result<entity::Model, JobError> {
+        let not_found = || JobError::NotFound {
+            scheduler: self.name.clone(),
+            name: name.to_owned(),
+        };
+        let job = self
+            .jobs
+            .iter()
+            .find(|job| job.name == name)
+            .ok_or_else(not_found)?;
+
+        if let Some(row) = self.find_state(name).await? {
+            return Ok(row);
+        }
+        self.register_job(job).await?;
+        self.find_state(name).await?.ok_or_else(not_found)
+    }
+
+    async fn find_state(&self, name: &str) -> Result<Option<entity::Model>, JobError> {
+        Ok(
+            entity::Entity::find_by_id((self.name.clone(), name.to_owned()))
+                .one(self.db.sea_secure().conn())
+                .await?,
+        )
+    }
+
+    async fn update(&self, row: entity::ActiveModel) -> Result<(), JobError> {
+        entity::Entity::update(row)
+            .exec(self.db.sea_secure().conn())
+            .await?;
+        Ok(())
+    }
+
+    /// Run due jobs until `cancel` fires, then wait for the runs in progress.
+    ///
+    /// Each due job runs in its own task, so a long job does not delay the others.
+    /// Database errors are logged and retried on the next poll.
+    pub async fn run(self: Arc<Self>, cancel: CancellationToken) {
+        let mut registered = false;
+        let mut runs = JoinSet::new();
+
+        loop {
+            if !registered {
+                match self.register_jobs().await {
+                    Ok(()) => registered = true,
+                    Err(e) => {
+                        tracing::warn!(
+                            scheduler = %self.name,
+                            error = %e,
+                            "Job registration failed"
+                        );
+                    }
+                }
+            }
+
+            if registered {
+                match self.due_jobs().await {
+                    Ok(due) => {
+                        let names: Vec<String> = due.iter().map(|job| job.name.clone()).collect();
+                        self.running.lock().extend(names.iter().cloned());
+                        for name in names {
+                            let scheduler = Arc::clone(&self);
+                            let cancel = cancel.clone();
+                            runs.spawn(async move { scheduler.spawned_run(&name, &cancel).await });
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(scheduler = %self.name, error = %e, "Job poll failed");
+                    }
+                }
+            }
+            while runs.try_join_next().is_some() {}
+
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                () = tokio::time::sleep(self.config.poll_interval) => {}
+                () = self.wake.notified() => {}
+            }
+        }
+
+        while runs.join_next().await.is_some() {}
+    }
+
+    async fn spawned_run(&self, name: &str, cancel: &CancellationToken) {
+        if let Some(job) = self.jobs.iter().find(|job| job.name == name)
+            && let Err(e) = self.run_job(job, cancel).await
+        {
+            tracing::warn!(scheduler = %self.name, job = %name, error = %e, "Job run failed");
+        }
+        self.running.lock().remove(name);
+    }
+}
+
+impl std::fmt::Debug for JobScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobScheduler")
+            .field("name", &self.name)
+            .field("jobs", &self.jobs)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Runnable for JobScheduler {
+    async fn run(self: Arc<Self>, cancel: CancellationToken) -> anyhow::Result<()> {
+        JobScheduler::run(self, cancel).await;
+        Ok(())
+    }
+}
+
+/// Primary key of a job row, as the base of partial updates.
+fn key(row: &entity::Model) -> entity::ActiveModel {
+    entity::ActiveModel {
+        scheduler: ActiveValue::Unchanged(row.scheduler.clone()),
+        name: ActiveValue::Unchanged(row.name.clone()),
+        ..Default::default()
+    }
+}
+
+fn millis(time: DateTime<Utc>) -> i64 {
+    time.timestamp_millis()
+}
+
+fn from_millis(millis: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::jobs::{JobRegistry, RetryPolicy, Schedule};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn setup(name: &str) -> Arc<DbHandle> {
+        let opts = modkit_db::ConnectOpts {
+            max_conns: Some(1),
+            ..Default::default()
+        };
+        let dsn = format!("sqlite:file:{name}_memdb?mode=memory&cache=shared");
+        let db = DbHandle::connect(&dsn, opts).await.unwrap();
+        crate::jobs::create_table(db.sea_secure().conn())
+            .await
+            .unwrap();
+        Arc::new(db)
+    }
+
+    /// A job counting its runs; fails while the run count is below `failures`.
+    fn counting_job(name: &str, schedule: Schedule, failures: usize) -> (Job, Arc<AtomicUsize>) {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&runs);
+        let job = Job::new(name, schedule, move |_ctx: JobContext| {
+            let run = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if run < failures {
+                    anyhow::bail!("run {run} failed");
+                }
+                Ok(())
+            }
+        });
+        (job, runs)
+    }
+
+    async fn info(scheduler: &JobScheduler, name: &str) -> JobInfo {
+        scheduler
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|job| job.name == name)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_jobs_run_when_due_and_keep_state_across_restarts() {
+        let db = setup("jobs_due").await;
+        let cancel = CancellationToken::new();
+        let (once, once_runs) = counting_job("once", Schedule::once_after(Duration::ZERO), 0);
+        let (hourly, hourly_runs) =
+            counting_job("hourly", Schedule::every(Duration::from_hours(1)), 0);
+        let scheduler = JobScheduler::new(Arc::clone(&db), "users")
+            .with_job(once.clone())
+            .with_job(hourly.clone());
+        scheduler.register_jobs().await.unwrap();
+
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        assert_eq!(once_runs.load(Ordering::SeqCst), 1);
+        assert_eq!(hourly_runs.load(Ordering::SeqCst), 0);
+        let done = info(&scheduler, "once").await;
+        assert_eq!(done.status, JobStatus::Completed);
+        assert_eq!(done.next_run_at, None);
+        assert!(done.last_run_at.is_some());
+
+        // A restarted scheduler does not re-run the completed one-off job
+        let restarted = JobScheduler::new(Arc::clone(&db), "users")
+            .with_job(once)
+            .with_job(hourly);
+        restarted.register_jobs().await.unwrap();
+        assert_eq!(restarted.run_pending(&cancel).await.unwrap(), 0);
+
+        // Triggering runs a job now, and a recurring job is rescheduled afterwards
+        restarted.trigger("hourly").await.unwrap();
+        assert_eq!(restarted.run_pending(&cancel).await.unwrap(), 1);
+        assert_eq!(hourly_runs.load(Ordering::SeqCst), 1);
+        let hourly = info(&restarted, "hourly").await;
+        assert_eq!(hourly.status, JobStatus::Scheduled);
+        assert!(hourly.next_run_at.unwrap() > Utc::now() + chrono::Duration::minutes(59));
+
+        assert!(matches!(
+            restarted.trigger("unknown").await,
+            Err(JobError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failing_job_is_retried_then_dead_lettered() {
+        let db = setup("jobs_retry").await;
+        let cancel = CancellationToken::new();
+        let (job, runs) = counting_job("sync", Schedule::once_after(Duration::ZERO), 3);
+        let job = job.with_retry(RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::ZERO,
+            ..RetryPolicy::default()
+        });
+        let scheduler = JobScheduler::new(db, "users").with_job(job);
+        scheduler.register_jobs().await.unwrap();
+
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        let retrying = info(&scheduler, "sync").await;
+        assert_eq!(retrying.status, JobStatus::Scheduled);
+        assert_eq!(retrying.attempts, 1);
+        assert_eq!(retrying.last_error.as_deref(), Some("run 0 failed"));
+
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        let dead = info(&scheduler, "sync").await;
+        assert_eq!(dead.status, JobStatus::DeadLetter);
+        assert_eq!(dead.attempts, 2);
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 0);
+
+        // Triggering a dead-lettered job starts a fresh series of attempts
+        scheduler.trigger("sync").await.unwrap();
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+        let done = info(&scheduler, "sync").await;
+        assert_eq!(done.status, JobStatus::Completed);
+        assert_eq!(done.attempts, 0);
+        assert_eq!(done.last_error, None);
+    }
+
+    #[tokio::test]
+    async fn test_paused_jobs_do_not_run_until_resumed() {
+        let db = setup("jobs_pause").await;
+        let cancel = CancellationToken::new();
+        let (job, runs) = counting_job("report", Schedule::once_after(Duration::ZERO), 0);
+        let scheduler = Arc::new(JobScheduler::new(db, "reports").with_job(job));
+        let registry = JobRegistry::new();
+        registry.register(Arc::clone(&scheduler));
+
+        registry.pause("reports", "report").await.unwrap();
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 0);
+        assert!(matches!(
+            registry.trigger("reports", "report").await,
+            Err(JobError::Paused { .. })
+        ));
+        assert_eq!(registry.list().await.unwrap()[0].status, JobStatus::Paused);
+
+        registry.resume("reports", "report").await.unwrap();
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 1);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            registry.pause("billing", "report").await,
+            Err(JobError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_leased_job_is_skipped_and_run_loop_executes_due_jobs() {
+        let db = setup("jobs_lease").await;
+        let cancel = CancellationToken::new();
+        let (job, runs) = counting_job("cleanup", Schedule::once_after(Duration::ZERO), 0);
+        let scheduler = Arc::new(
+            JobScheduler::new(Arc::clone(&db), "users")
+                .with_job(job)
+                .with_config(SchedulerConfig {
+                    poll_interval: Duration::from_millis(20),
+                }),
+        );
+        scheduler.register_jobs().await.unwrap();
+
+        // Another replica holds the lease
+        let lease = db
+            .try_lock(LOCK_NAMESPACE, "users.cleanup", LockConfig::default())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(scheduler.run_pending(&cancel).await.unwrap(), 0);
+        lease.release().await;
+
+        let task = tokio::spawn(Arc::clone(&scheduler).run(cancel.clone()));
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while runs.load(Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the run loop should execute the due job");
+
+        cancel.cancel();
+        task.await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(!info(&scheduler, "cleanup").await.running);
+    }
+}
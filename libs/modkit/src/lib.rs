@@ -109,6 +109,7 @@ pub use api::problem::{
 };
 pub use http::client::TracedClient;
 pub use http::sse::SseBroadcaster;
+pub use http::sse_replay::ResumableSseBroadcaster;
 
 // Telemetry utilities
 pub mod telemetry;
@@ -132,6 +133,17 @@ pub use directory::{
     ServiceInstanceInfo,
 };
 
+// Event bus for typed events between modules
+pub mod events;
+pub use events::{EventBus, Topic};
+
+// Background jobs with persisted schedules
+pub mod jobs;
+
+// Liveness and readiness checks
+pub mod health;
+pub use health::{HealthCheck, HealthRegistry, HealthStatus};
+
 // GTS schema support
 pub mod gts;
 
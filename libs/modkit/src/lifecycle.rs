@@ -9,6 +9,8 @@ use tokio::sync::{Notify, oneshot};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
+use crate::telemetry::MetricsRegistry;
+
 // ----- Results & aliases -----------------------------------------------------
 
 /// Public result for lifecycle-level operations.
@@ -58,6 +60,46 @@ impl Status {
             _ => Status::Stopped,
         }
     }
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Status::Stopped => "stopped",
+            Status::Starting => "starting",
+            Status::Running => "running",
+            Status::Stopping => "stopping",
+        }
+    }
+}
+
+/// Expose the status of a lifecycle as the one-hot `modkit_lifecycle_status` gauge,
+/// refreshed at scrape time until the lifecycle is dropped.
+fn export_status(name: &'static str, status: &Arc<AtomicU8>) {
+    const ALL: [Status; 4] = [
+        Status::Stopped,
+        Status::Starting,
+        Status::Running,
+        Status::Stopping,
+    ];
+
+    let metrics = MetricsRegistry::global();
+    let gauge = metrics.gauge(
+        "modkit_lifecycle_status",
+        "Status of module lifecycles, 1 for the current status",
+    );
+    let status = Arc::downgrade(status);
+    metrics.on_collect(move || {
+        let current = status
+            .upgrade()
+            .map(|s| Status::from_u8(s.load(Ordering::Acquire)));
+        for s in ALL {
+            let labels = [("lifecycle", name), ("status", s.as_str())];
+            match current {
+                Some(current) => gauge.set(&labels, i64::from(current == s)),
+                None => gauge.remove(&labels),
+            }
+        }
+        current.is_some()
+    });
 }
 
 /// Reason why a task stopped.
@@ -129,9 +171,11 @@ pub struct Lifecycle {
 impl Lifecycle {
     #[must_use]
     pub fn new_named(name: &'static str) -> Self {
+        let status = Arc::new(AtomicU8::new(Status::Stopped.as_u8()));
+        export_status(name, &status);
         Self {
             name,
-            status: Arc::new(AtomicU8::new(Status::Stopped.as_u8())),
+            status,
             handle: Mutex::new(None),
             cancel: Mutex::new(None),
             finished: Arc::new(AtomicBool::new(false)),
@@ -943,4 +987,29 @@ mod tests {
         ));
         assert_eq!(lc.status(), Status::Stopped);
     }
+
+    #[tokio::test]
+    async fn lifecycle_status_is_exported_as_metric() {
+        let metrics = MetricsRegistry::global();
+        let gauge = metrics.gauge("modkit_lifecycle_status", "");
+        let running = [("lifecycle", "metrics_test"), ("status", "running")];
+
+        let lc = Lifecycle::new_named("metrics_test");
+        lc.start(|cancel| async move {
+            cancel.cancelled().await;
+            Ok(())
+        })
+        .unwrap();
+        metrics.collect();
+        assert_eq!(gauge.get(&running), 1);
+        assert_eq!(
+            gauge.get(&[("lifecycle", "metrics_test"), ("status", "stopped")]),
+            0
+        );
+
+        lc.stop(Duration::from_millis(100)).await.unwrap();
+        drop(lc);
+        metrics.collect();
+        assert!(!metrics.encode().contains("lifecycle=\"metrics_test\""));
+    }
 }
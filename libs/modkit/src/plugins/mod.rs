@@ -1,6 +1,8 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use futures_core::Stream;
+use futures_util::StreamExt;
 use parking_lot::RwLock;
 use tokio::sync::Mutex;
 
@@ -83,6 +85,45 @@ impl GtsPluginSelector {
         let mut guard = self.cached.write();
         guard.take().is_some()
     }
+
+    /// Resets the selector as soon as `changes` yields its first item.
+    ///
+    /// Meant for a types-registry watch on the plugin instances, subscribed
+    /// before they were listed for resolution: any registration, update or
+    /// deprecation then makes the next call re-resolve the plugin. The spawned
+    /// task only holds a weak reference and does not keep the selector alive.
+    pub fn reset_on_change<S>(self: &Arc<Self>, changes: S)
+    where
+        S: Stream + Send + 'static,
+    {
+        on_first_change(self, changes, |selector| async move {
+            selector.reset().await;
+        });
+    }
+}
+
+/// Runs `reset` on `target` as soon as `changes` yields its first item.
+///
+/// The spawned task only holds a weak reference: if `target` is dropped
+/// before the first change, `reset` is never called. Plugin selectors and
+/// other caches of resolved plugin instances use it to re-resolve after a
+/// types-registry change.
+pub fn on_first_change<T, S, F, Fut>(target: &Arc<T>, changes: S, reset: F)
+where
+    T: Send + Sync + 'static,
+    S: Stream + Send + 'static,
+    F: FnOnce(Arc<T>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    let target = Arc::downgrade(target);
+    tokio::spawn(async move {
+        let mut changes = std::pin::pin!(changes);
+        if changes.next().await.is_some()
+            && let Some(target) = target.upgrade()
+        {
+            reset(target).await;
+        }
+    });
 }
 
 #[cfg(test)]
@@ -205,4 +246,28 @@ mod tests {
         // Resolve should have been called exactly once
         assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
+
+    #[tokio::test]
+    async fn reset_on_change_clears_cached_selection() {
+        let selector = Arc::new(GtsPluginSelector::new());
+
+        selector
+            .get_or_init(|| async {
+                Ok::<_, std::convert::Infallible>(
+                    "gts.x.core.modkit.plugin.v1~x.core.test.plugin.v1~a.test._.plugin.v1"
+                        .to_owned(),
+                )
+            })
+            .await
+            .unwrap();
+        selector.reset_on_change(futures_util::stream::once(async {}));
+
+        for _ in 0..100 {
+            if selector.cached.read().is_none() {
+                return;
+            }
+            tokio::task::yield_now().await;
+        }
+        panic!("selection was not reset");
+    }
 }
@@ -24,6 +24,8 @@ pub enum Capability {
     System(Arc<dyn contracts::SystemCapability>),
     GrpcHub(Arc<dyn contracts::GrpcHubCapability>),
     GrpcService(Arc<dyn contracts::GrpcServiceCapability>),
+    Events(Arc<dyn contracts::EventsCapability>),
+    Health(Arc<dyn contracts::HealthCapability>),
 }
 
 impl std::fmt::Debug for Capability {
@@ -36,6 +38,8 @@ impl std::fmt::Debug for Capability {
             Capability::System(_) => write!(f, "System(<impl SystemCapability>)"),
             Capability::GrpcHub(_) => write!(f, "GrpcHub(<impl GrpcHubCapability>)"),
             Capability::GrpcService(_) => write!(f, "GrpcService(<impl GrpcServiceCapability>)"),
+            Capability::Events(_) => write!(f, "Events(<impl EventsCapability>)"),
+            Capability::Health(_) => write!(f, "Health(<impl HealthCapability>)"),
         }
     }
 }
@@ -130,6 +134,30 @@ impl CapTag for GrpcServiceCap {
     }
 }
 
+/// Tag for querying `EventsCapability`.
+pub struct EventsCap;
+impl CapTag for EventsCap {
+    type Out = dyn contracts::EventsCapability;
+    fn try_get(cap: &Capability) -> Option<&Arc<Self::Out>> {
+        match cap {
+            Capability::Events(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Tag for querying `HealthCapability`.
+pub struct HealthCap;
+impl CapTag for HealthCap {
+    type Out = dyn contracts::HealthCapability;
+    fn try_get(cap: &Capability) -> Option<&Arc<Self::Out>> {
+        match cap {
+            Capability::Health(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
 /// A set of capabilities that a module provides.
 #[derive(Clone)]
 pub struct CapabilitySet {
@@ -194,6 +222,8 @@ impl std::fmt::Debug for ModuleEntry {
             .field("is_system", &self.caps.has::<SystemCap>())
             .field("is_grpc_hub", &self.caps.has::<GrpcHubCap>())
             .field("has_grpc_service", &self.caps.has::<GrpcServiceCap>())
+            .field("has_events", &self.caps.has::<EventsCap>())
+            .field("has_health", &self.caps.has::<HealthCap>())
             .finish_non_exhaustive()
     }
 }
@@ -391,6 +421,28 @@ impl RegistryBuilder {
             .push(Capability::GrpcService(m));
     }
 
+    pub fn register_events_with_meta(
+        &mut self,
+        name: &'static str,
+        m: Arc<dyn contracts::EventsCapability>,
+    ) {
+        self.capabilities
+            .entry(name)
+            .or_default()
+            .push(Capability::Events(m));
+    }
+
+    pub fn register_health_with_meta(
+        &mut self,
+        name: &'static str,
+        m: Arc<dyn contracts::HealthCapability>,
+    ) {
+        self.capabilities
+            .entry(name)
+            .or_default()
+            .push(Capability::Health(m));
+    }
+
     /// Detect cycles in the dependency graph using DFS with path tracking.
     /// Returns the cycle path if found, None otherwise.
     fn detect_cycle_with_path(
@@ -680,6 +732,12 @@ pub enum RegistryError {
         #[source]
         source: anyhow::Error,
     },
+    #[error("event topic declaration failed for module '{module}'")]
+    EventsDeclare {
+        module: &'static str,
+        #[source]
+        source: anyhow::Error,
+    },
     #[error("REST prepare failed for host module '{module}'")]
     RestPrepare {
         module: &'static str,
@@ -909,6 +967,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn events_capability_without_core_fails() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("core_a", &[], Arc::new(DummyCore));
+        // Register an events capability for a module that doesn't exist
+        b.register_events_with_meta("unknown_module", Arc::new(DummyEvents));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        match err {
+            RegistryError::UnknownModule(name) => {
+                assert_eq!(name, "unknown_module");
+            }
+            other => panic!("expected UnknownModule, got: {other:?}"),
+        }
+    }
+
     #[test]
     fn capability_query_works() {
         let mut b = RegistryBuilder::default();
@@ -986,6 +1060,14 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct DummyEvents;
+    impl contracts::EventsCapability for DummyEvents {
+        fn event_topics(&self) -> Vec<crate::events::TopicSpec> {
+            Vec::new()
+        }
+    }
+
     #[derive(Default)]
     struct DummyRestHost;
     impl contracts::ApiGatewayCapability for DummyRestHost {
@@ -6,6 +6,7 @@
 //! High-level phase order:
 //! - `pre_init` (system modules only)
 //! - DB migrations (modules with DB capability)
+//! - event topic declarations (modules with events capability)
 //! - `init` (all modules)
 //! - `post_init` (system modules only; runs after *all* `init` complete)
 //! - REST wiring (modules with REST capability; requires a single REST host)
@@ -16,18 +17,25 @@
 use axum::Router;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
-use crate::backends::OopSpawnConfig;
+use crate::backends::{OopBackend, OopInstanceEvent, OopSpawnConfig};
 use crate::client_hub::ClientHub;
 use crate::config::ConfigProvider;
 use crate::context::ModuleContextBuilder;
+use crate::events::EventBus;
+use crate::health::{HealthCheck, HealthRegistry, Probe};
+use crate::jobs::JobRegistry;
 use crate::registry::{
-    ApiGatewayCap, DatabaseCap, GrpcHubCap, ModuleEntry, ModuleRegistry, RegistryError, RestApiCap,
-    RunnableCap, SystemCap,
+    ApiGatewayCap, DatabaseCap, EventsCap, GrpcHubCap, HealthCap, ModuleEntry, ModuleRegistry,
+    RegistryError, RestApiCap, RunnableCap, SystemCap,
 };
-use crate::runtime::{GrpcInstallerStore, ModuleManager, OopSpawnOptions, SystemContext};
+use crate::runtime::{
+    GrpcInstallerStore, InstanceState, ModuleManager, OopSpawnOptions, SystemContext,
+};
+use crate::telemetry::MetricsRegistry;
 
 /// How the runtime should provide DBs to modules.
 #[derive(Clone)]
@@ -44,6 +52,12 @@ pub const MODKIT_DIRECTORY_ENDPOINT_ENV: &str = "MODKIT_DIRECTORY_ENDPOINT";
 /// Environment variable name for passing rendered module config to `OoP` modules.
 pub const MODKIT_MODULE_CONFIG_ENV: &str = "MODKIT_MODULE_CONFIG";
 
+/// Environment variable name for passing the backend-assigned instance ID to `OoP` modules.
+///
+/// The module registers in the directory under this ID, so the host can deregister it
+/// when the backend reports that the process exited.
+pub const MODKIT_INSTANCE_ID_ENV: &str = "MODKIT_INSTANCE_ID";
+
 /// `HostRuntime` owns the lifecycle orchestration for `ModKit`.
 ///
 /// It encapsulates all runtime state and drives modules through the full lifecycle (see module docs).
@@ -53,19 +67,24 @@ pub struct HostRuntime {
     instance_id: Uuid,
     module_manager: Arc<ModuleManager>,
     grpc_installers: Arc<GrpcInstallerStore>,
-    #[allow(dead_code)]
     client_hub: Arc<ClientHub>,
     cancel: CancellationToken,
     #[allow(dead_code)]
     db_options: DbOptions,
     /// `OoP` module spawn configuration and backend
     oop_options: Option<OopSpawnOptions>,
+    /// Set once the start phase completed; reported by the `runtime` readiness check
+    started: Arc<AtomicBool>,
 }
 
 impl HostRuntime {
     /// Create a new `HostRuntime` instance.
     ///
     /// This prepares all runtime components but does not start any lifecycle phases.
+    /// An in-process `EventBus` is registered in the `ClientHub` unless one was pre-registered
+    /// (e.g. the gRPC-bridged bus of an `OoP` module), and so are the process-wide `JobRegistry`,
+    /// `MetricsRegistry` and `HealthRegistry`. The health checks of modules with the `health`
+    /// capability and of the runtime components are registered right away.
     pub fn new(
         registry: ModuleRegistry,
         modules_cfg: Arc<dyn ConfigProvider>,
@@ -79,6 +98,19 @@ impl HostRuntime {
         let module_manager = Arc::new(ModuleManager::new());
         let grpc_installers = Arc::new(GrpcInstallerStore::new());
 
+        if client_hub.get::<EventBus>().is_err() {
+            client_hub.register::<EventBus>(Arc::new(EventBus::in_process()));
+        }
+        if client_hub.get::<JobRegistry>().is_err() {
+            client_hub.register::<JobRegistry>(Arc::new(JobRegistry::new()));
+        }
+        if client_hub.get::<MetricsRegistry>().is_err() {
+            client_hub.register::<MetricsRegistry>(Arc::new(MetricsRegistry::global().clone()));
+        }
+        if client_hub.get::<HealthRegistry>().is_err() {
+            client_hub.register::<HealthRegistry>(Arc::new(HealthRegistry::new()));
+        }
+
         // Build the context builder that will resolve per-module DbHandles
         let db_manager = match &db_options {
             DbOptions::Manager(mgr) => Some(mgr.clone()),
@@ -93,7 +125,7 @@ impl HostRuntime {
             db_manager,
         );
 
-        Self {
+        let runtime = Self {
             registry,
             ctx_builder,
             instance_id,
@@ -103,7 +135,92 @@ impl HostRuntime {
             cancel,
             db_options,
             oop_options,
+            started: Arc::new(AtomicBool::new(false)),
+        };
+        runtime.register_health_checks();
+        runtime
+    }
+
+    /// Register the health checks of modules and runtime components in the `HealthRegistry`.
+    ///
+    /// - `runtime`: ready once the start phase completed and until shutdown begins
+    /// - `database`: ready if every database opened so far answers a ping
+    /// - every module with the `health` capability, under its module name
+    fn register_health_checks(&self) {
+        let Ok(health) = self.client_hub.get::<HealthRegistry>() else {
+            return;
+        };
+
+        let started = Arc::clone(&self.started);
+        let cancel = self.cancel.clone();
+        health.register("runtime", &[Probe::Readiness], move || {
+            let check = if cancel.is_cancelled() {
+                HealthCheck::down("shutting down")
+            } else if started.load(Ordering::Acquire) {
+                HealthCheck::up()
+            } else {
+                HealthCheck::down("starting")
+            };
+            async move { check }
+        });
+
+        if let DbOptions::Manager(manager) = &self.db_options {
+            let manager = Arc::clone(manager);
+            health.register("database", &[Probe::Readiness], move || {
+                let handles = manager.handles();
+                async move {
+                    let mut failures = Vec::new();
+                    for (module, handle) in handles {
+                        if let Err(e) = handle.ping().await {
+                            failures.push(format!("{module}: {e}"));
+                        }
+                    }
+                    if failures.is_empty() {
+                        HealthCheck::up()
+                    } else {
+                        HealthCheck::down(failures.join("; "))
+                    }
+                }
+            });
         }
+
+        for entry in self.registry.modules() {
+            if let Some(module) = entry.caps.query::<HealthCap>() {
+                health.register_module(entry.name, module);
+            }
+        }
+    }
+
+    /// Register a readiness check for the instances of an `OoP` module, based on the
+    /// heartbeat-driven instance states tracked by the `ModuleManager`.
+    fn register_oop_health_check(&self, module: &str) {
+        let Ok(health) = self.client_hub.get::<HealthRegistry>() else {
+            return;
+        };
+
+        let module_manager = Arc::clone(&self.module_manager);
+        let module_name = module.to_owned();
+        health.register(format!("oop:{module}"), &[Probe::Readiness], move || {
+            let instances = module_manager.instances_of(&module_name);
+            let healthy = instances
+                .iter()
+                .filter(|i| matches!(i.state(), InstanceState::Ready | InstanceState::Healthy))
+                .count();
+            let check = if healthy == 0 {
+                HealthCheck::down(format!(
+                    "no healthy instances ({} registered)",
+                    instances.len()
+                ))
+            } else if healthy < instances.len() {
+                HealthCheck::degraded(format!(
+                    "{healthy} of {} instances healthy",
+                    instances.len()
+                ))
+            } else {
+                HealthCheck::up()
+            };
+            async move { check }
+        });
     }
 
     /// `PRE_INIT` phase: wire runtime internals into system modules.
@@ -207,6 +324,39 @@ impl HostRuntime {
         Ok(())
     }
 
+    /// EVENTS phase: declare the topics published by modules with the events capability.
+    ///
+    /// Runs before init so that conflicting topic declarations fail start-up.
+    async fn run_events_phase(&self) -> Result<(), RegistryError> {
+        tracing::info!("Phase: events (before init)");
+
+        for entry in self.registry.modules_by_system_priority() {
+            let Some(events_module) = entry.caps.query::<EventsCap>() else {
+                continue;
+            };
+            let bus =
+                self.client_hub
+                    .get::<EventBus>()
+                    .map_err(|e| RegistryError::EventsDeclare {
+                        module: entry.name,
+                        source: e.into(),
+                    })?;
+
+            for topic in events_module.event_topics() {
+                tracing::debug!(module = entry.name, topic = %topic.name, "Declaring event topic");
+                bus.backend()
+                    .declare(&topic)
+                    .await
+                    .map_err(|e| RegistryError::EventsDeclare {
+                        module: entry.name,
+                        source: e.into(),
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// INIT phase: initialize all modules in topological order.
     ///
     /// System modules initialize first, followed by user modules.
@@ -456,6 +606,7 @@ impl HostRuntime {
             }
         }
 
+        self.started.store(true, Ordering::Release);
         Ok(())
     }
 
@@ -503,6 +654,15 @@ impl HostRuntime {
         // Wait for grpc_hub to publish its endpoint (it runs async in start phase)
         let directory_endpoint = self.wait_for_grpc_hub_endpoint().await;
 
+        // Keep the directory in sync with instances the backend sees exit
+        if let Some(events) = oop_opts.backend.subscribe() {
+            tokio::spawn(Self::sync_directory_with_oop_events(
+                events,
+                Arc::clone(&self.module_manager),
+                self.cancel.clone(),
+            ));
+        }
+
         for module_cfg in &oop_opts.modules {
             // Build environment with directory endpoint and rendered config
             // Note: User controls --config via execution.args in master config
@@ -524,8 +684,11 @@ impl HostRuntime {
                 args,
                 env,
                 working_directory: module_cfg.working_directory.clone(),
+                restart: module_cfg.restart.clone(),
+                replicas: module_cfg.replicas,
             };
 
+            self.register_oop_health_check(&module_cfg.module_name);
             oop_opts
                 .backend
                 .spawn(spawn_config)
@@ -537,14 +700,115 @@ impl HostRuntime {
 
             tracing::info!(
                 module = %module_cfg.module_name,
+                replicas = module_cfg.replicas.max(1),
                 directory_endpoint = ?directory_endpoint,
                 "Spawned OoP module via backend"
             );
         }
 
+        #[cfg(unix)]
+        tokio::spawn(Self::rolling_restart_on_sighup(
+            Arc::clone(&oop_opts.backend),
+            oop_opts
+                .modules
+                .iter()
+                .map(|m| m.module_name.clone())
+                .collect(),
+            Arc::clone(&self.module_manager),
+            self.cancel.clone(),
+        ));
+
         Ok(())
     }
 
+    /// Roll every `OoP` module each time the host receives SIGHUP.
+    #[cfg(unix)]
+    async fn rolling_restart_on_sighup(
+        backend: Arc<dyn OopBackend>,
+        modules: Vec<String>,
+        module_manager: Arc<ModuleManager>,
+        cancel: CancellationToken,
+    ) {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut hangups = match signal(SignalKind::hangup()) {
+            Ok(hangups) => hangups,
+            Err(e) => {
+                tracing::warn!(error = %e, "Cannot listen for SIGHUP; OoP rolling restarts disabled");
+                return;
+            }
+        };
+
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                received = hangups.recv() => {
+                    if received.is_none() {
+                        return;
+                    }
+                }
+            }
+            tracing::info!("SIGHUP received, rolling restart of OoP modules");
+            Self::rolling_restart_all(backend.as_ref(), &modules, &module_manager).await;
+        }
+    }
+
+    /// Roll the `OoP` modules one after another.
+    ///
+    /// A failed module keeps its remaining old instances; the following modules are still rolled.
+    async fn rolling_restart_all(
+        backend: &dyn OopBackend,
+        modules: &[String],
+        module_manager: &ModuleManager,
+    ) {
+        for module in modules {
+            match backend.rolling_restart(module, module_manager).await {
+                Ok(()) => tracing::info!(module = %module, "Rolling restart completed"),
+                Err(e) => tracing::error!(
+                    module = %module,
+                    error = %format!("{e:#}"),
+                    "Rolling restart failed"
+                ),
+            }
+        }
+    }
+
+    /// Deregister `OoP` instances from the directory as soon as the backend reports their exit.
+    ///
+    /// Replacement instances register themselves through the directory service on startup.
+    async fn sync_directory_with_oop_events(
+        mut events: tokio::sync::broadcast::Receiver<OopInstanceEvent>,
+        module_manager: Arc<ModuleManager>,
+        cancel: CancellationToken,
+    ) {
+        loop {
+            let event = tokio::select! {
+                () = cancel.cancelled() => return,
+                event = events.recv() => event,
+            };
+
+            match event {
+                Ok(OopInstanceEvent::Exited {
+                    module,
+                    instance_id,
+                    ..
+                }) => {
+                    tracing::info!(
+                        module = %module,
+                        instance_id = %instance_id,
+                        "Deregistering exited OoP instance"
+                    );
+                    module_manager.deregister(&module, instance_id);
+                }
+                Ok(_) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Missed OoP lifecycle events");
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
     /// Wait for `grpc_hub` to publish its bound endpoint.
     ///
     /// Polls the `GrpcHubModule::bound_endpoint()` with a short interval until available or timeout.
@@ -585,7 +849,7 @@ impl HostRuntime {
         }
     }
 
-    /// Run the full lifecycle: `pre_init` → DB → init → `post_init` → REST → gRPC → start → `OoP` spawn → wait → stop.
+    /// Run the full lifecycle: `pre_init` → DB → events → init → `post_init` → REST → gRPC → start → `OoP` spawn → wait → stop.
     ///
     /// This is the main entry point for orchestrating the complete module lifecycle.
     ///
@@ -598,28 +862,31 @@ impl HostRuntime {
         // 2. DB migration phase (system modules first)
         self.run_db_phase().await?;
 
-        // 3. Init phase (system modules first)
+        // 3. Events phase (topic declarations before anyone publishes)
+        self.run_events_phase().await?;
+
+        // 4. Init phase (system modules first)
         self.run_init_phase().await?;
 
-        // 4. Post-init phase (barrier after ALL init; system modules only)
+        // 5. Post-init phase (barrier after ALL init; system modules only)
         self.run_post_init_phase().await?;
 
-        // 5. REST phase (synchronous router composition)
+        // 6. REST phase (synchronous router composition)
         let _router = self.run_rest_phase().await?;
 
-        // 6. gRPC registration phase
+        // 7. gRPC registration phase
         self.run_grpc_phase().await?;
 
-        // 7. Start phase
+        // 8. Start phase
         self.run_start_phase().await?;
 
-        // 8. OoP spawn phase (after grpc_hub is running)
+        // 9. OoP spawn phase (after grpc_hub is running)
         self.run_oop_spawn_phase().await?;
 
-        // 9. Wait for cancellation
+        // 10. Wait for cancellation
         self.cancel.cancelled().await;
 
-        // 10. Stop phase
+        // 11. Stop phase
         self.run_stop_phase().await?;
 
         Ok(())
@@ -806,6 +1073,122 @@ mod tests {
         assert_eq!(stopped.load(Ordering::SeqCst), 3);
     }
 
+    #[tokio::test]
+    async fn test_health_checks_follow_start_and_shutdown() {
+        use crate::contracts::HealthCapability;
+        use crate::health::{HealthCheck, HealthStatus};
+
+        struct Checked;
+
+        #[async_trait::async_trait]
+        impl Module for Checked {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl HealthCapability for Checked {
+            async fn readiness(&self) -> HealthCheck {
+                HealthCheck::degraded("cache cold")
+            }
+        }
+
+        let module = Arc::new(Checked);
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("checked", &[], module.clone() as Arc<dyn Module>);
+        builder.register_health_with_meta("checked", module as Arc<dyn HealthCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let client_hub = Arc::new(ClientHub::new());
+        let cancel = CancellationToken::new();
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            client_hub.clone(),
+            cancel.clone(),
+            Uuid::new_v4(),
+            None,
+        );
+        let health = client_hub.get::<HealthRegistry>().unwrap();
+
+        let report = health.check(Probe::Readiness).await;
+        assert_eq!(report.status, HealthStatus::Down, "not started yet");
+        assert_eq!(report.components.len(), 2);
+
+        runtime.run_start_phase().await.unwrap();
+        let report = health.check(Probe::Readiness).await;
+        assert_eq!(report.status, HealthStatus::Degraded);
+        let checked = health
+            .check_component("checked", Probe::Readiness)
+            .await
+            .unwrap();
+        assert_eq!(checked.details.as_deref(), Some("cache cold"));
+
+        cancel.cancel();
+        let report = health.check(Probe::Readiness).await;
+        assert_eq!(report.status, HealthStatus::Down, "shutting down");
+        assert_eq!(health.check(Probe::Liveness).await.status, HealthStatus::Up);
+    }
+
+    #[tokio::test]
+    async fn test_events_phase_declares_topics_and_rejects_conflicts() {
+        use crate::contracts::EventsCapability;
+        use crate::events::{BackpressurePolicy, TopicSpec};
+
+        struct TopicOwner(TopicSpec);
+
+        #[async_trait::async_trait]
+        impl Module for TopicOwner {
+            async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl EventsCapability for TopicOwner {
+            fn event_topics(&self) -> Vec<TopicSpec> {
+                vec![self.0.clone()]
+            }
+        }
+
+        let declared = TopicSpec::new("users.created", 8, BackpressurePolicy::DropOldest);
+        let module_a = Arc::new(TopicOwner(declared.clone()));
+        let module_b = Arc::new(TopicOwner(TopicSpec::new(
+            "users.created",
+            8,
+            BackpressurePolicy::Block,
+        )));
+
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("a", &[], module_a.clone() as Arc<dyn Module>);
+        builder.register_core_with_meta("b", &["a"], module_b.clone() as Arc<dyn Module>);
+        builder.register_events_with_meta("a", module_a as Arc<dyn EventsCapability>);
+        builder.register_events_with_meta("b", module_b as Arc<dyn EventsCapability>);
+        let registry = builder.build_topo_sorted().unwrap();
+
+        let client_hub = Arc::new(ClientHub::new());
+        let runtime = HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            client_hub.clone(),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        );
+
+        let err = runtime.run_events_phase().await.unwrap_err();
+        assert!(
+            matches!(err, RegistryError::EventsDeclare { module: "b", .. }),
+            "{err:?}"
+        );
+
+        // The first declaration is kept on the bus registered by the runtime
+        let bus = client_hub.get::<EventBus>().unwrap();
+        assert!(bus.backend().declare(&declared).await.is_ok());
+    }
+
     struct EmptyConfigProvider;
     impl ConfigProvider for EmptyConfigProvider {
         fn get_module_config(&self, _module_name: &str) -> Option<&serde_json::Value> {
@@ -907,4 +1290,41 @@ mod tests {
             ]
         );
     }
+
+    /// Records rolling restarts; fails the module named `broken`.
+    #[derive(Default)]
+    struct RecordingBackend {
+        restarted: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OopBackend for RecordingBackend {
+        async fn spawn(&self, _config: OopSpawnConfig) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn shutdown_all(&self) {}
+
+        async fn rolling_restart(
+            &self,
+            module: &str,
+            _directory: &ModuleManager,
+        ) -> anyhow::Result<()> {
+            self.restarted.lock().unwrap().push(module.to_owned());
+            if module == "broken" {
+                anyhow::bail!("replacement did not become healthy");
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rolling_restart_all_continues_after_a_failed_module() {
+        let backend = RecordingBackend::default();
+        let modules = ["broken".to_owned(), "healthy".to_owned()];
+
+        HostRuntime::rolling_restart_all(&backend, &modules, &ModuleManager::new()).await;
+
+        assert_eq!(*backend.restarted.lock().unwrap(), modules);
+    }
 }
@@ -14,7 +14,7 @@
 //! - `OoP` modules are spawned after the start phase so that `grpc_hub` is already running
 //!   and the real directory endpoint is known.
 
-use crate::backends::OopBackend;
+use crate::backends::{OopBackend, RestartPolicy};
 use crate::client_hub::ClientHub;
 use crate::config::ConfigProvider;
 use crate::registry::ModuleRegistry;
@@ -85,6 +85,10 @@ pub struct OopModuleSpawnConfig {
     pub working_directory: Option<String>,
     /// Rendered module config JSON (for `MODKIT_MODULE_CONFIG` env var)
     pub rendered_config_json: String,
+    /// Restart policy applied when the process exits unexpectedly
+    pub restart: RestartPolicy,
+    /// Number of instances to spawn
+    pub replicas: u32,
 }
 
 /// Options for spawning `OoP` modules.
@@ -92,7 +96,7 @@ pub struct OopSpawnOptions {
     /// List of `OoP` modules to spawn after the start phase
     pub modules: Vec<OopModuleSpawnConfig>,
     /// Backend for spawning `OoP` modules (e.g., `LocalProcessBackend`)
-    pub backend: Box<dyn OopBackend>,
+    pub backend: Arc<dyn OopBackend>,
 }
 
 /// Options for running the `ModKit` runner.
@@ -1,6 +1,7 @@
-//! OpenTelemetry tracing configuration types
+//! OpenTelemetry tracing and metrics configuration types
 //!
-//! These types define the configuration structure for OpenTelemetry distributed tracing.
+//! These types define the configuration structure for OpenTelemetry distributed tracing
+//! and OTLP metrics export.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -18,6 +19,18 @@ pub struct TracingConfig {
     pub logs_correlation: Option<LogsCorrelation>,
 }
 
+/// OTLP export of the metrics registry. The Prometheus `/metrics` endpoint does not
+/// depend on this and is always served by the API gateway.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    pub service_name: Option<String>,
+    pub exporter: Option<Exporter>,
+    /// Push interval; defaults to 60 seconds.
+    pub export_interval_ms: Option<u64>,
+    pub resource: Option<HashMap<String, String>>,
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum ExporterKind {
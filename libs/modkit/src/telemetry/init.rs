@@ -1,7 +1,8 @@
-//! OpenTelemetry tracing initialization utilities
+//! OpenTelemetry tracing and metrics initialization utilities
 //!
 //! This module sets up OpenTelemetry tracing and exports spans via OTLP
 //! (gRPC or HTTP) to collectors such as Jaeger, Uptrace, or the `OTel` Collector.
+//! Metrics recorded in the process-wide registry can be pushed the same way.
 
 #[cfg(feature = "otel")]
 use anyhow::Context;
@@ -17,12 +18,15 @@ use opentelemetry_otlp::{WithHttpConfig, WithTonicConfig};
 #[cfg(feature = "otel")]
 use opentelemetry_sdk::{
     Resource,
+    metrics::{PeriodicReader, SdkMeterProvider},
     propagation::TraceContextPropagator,
     trace::{Sampler, SdkTracerProvider},
 };
 
 #[cfg(feature = "otel")]
-use super::config::TracingConfig;
+use super::config::{Exporter, MetricsConfig, TracingConfig};
+#[cfg(feature = "otel")]
+use super::metrics::MetricsRegistry;
 #[cfg(feature = "otel")]
 use crate::telemetry::config::ExporterKind;
 #[cfg(feature = "otel")]
@@ -33,10 +37,18 @@ use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
 /// Build resource with service name and custom attributes
 #[cfg(feature = "otel")]
 fn build_resource(cfg: &TracingConfig) -> Resource {
-    let service_name = cfg.service_name.as_deref().unwrap_or("hyperspot");
+    resource_from(cfg.service_name.as_deref(), cfg.resource.as_ref())
+}
+
+#[cfg(feature = "otel")]
+fn resource_from(
+    service_name: Option<&str>,
+    resource: Option<&std::collections::HashMap<String, String>>,
+) -> Resource {
+    let service_name = service_name.unwrap_or("hyperspot");
     let mut attrs = vec![KeyValue::new("service.name", service_name.to_owned())];
 
-    if let Some(resource_map) = &cfg.resource {
+    if let Some(resource_map) = resource {
         for (k, v) in resource_map {
             attrs.push(KeyValue::new(k.clone(), v.clone()));
         }
@@ -67,7 +79,14 @@ fn build_sampler(cfg: &TracingConfig) -> Sampler {
 fn extract_exporter_config(
     cfg: &TracingConfig,
 ) -> (ExporterKind, String, Option<std::time::Duration>) {
-    let (kind, endpoint) = cfg.exporter.as_ref().map_or_else(
+    exporter_endpoint(cfg.exporter.as_ref())
+}
+
+#[cfg(feature = "otel")]
+fn exporter_endpoint(
+    exporter: Option<&Exporter>,
+) -> (ExporterKind, String, Option<std::time::Duration>) {
+    let (kind, endpoint) = exporter.map_or_else(
         || (ExporterKind::OtlpGrpc, "http://127.0.0.1:4317".into()),
         |e| {
             (
@@ -79,9 +98,7 @@ fn extract_exporter_config(
         },
     );
 
-    let timeout = cfg
-        .exporter
-        .as_ref()
+    let timeout = exporter
         .and_then(|e| e.timeout_ms)
         .map(std::time::Duration::from_millis);
 
@@ -187,12 +204,19 @@ pub fn init_tracing(
 #[cfg(feature = "otel")]
 fn build_headers_from_cfg_and_env(
     cfg: &TracingConfig,
+) -> Option<std::collections::HashMap<String, String>> {
+    headers_from_exporter_and_env(cfg.exporter.as_ref())
+}
+
+#[cfg(feature = "otel")]
+fn headers_from_exporter_and_env(
+    exporter: Option<&Exporter>,
 ) -> Option<std::collections::HashMap<String, String>> {
     use std::collections::HashMap;
     let mut out: HashMap<String, String> = HashMap::new();
 
     // From config file
-    if let Some(exp) = &cfg.exporter
+    if let Some(exp) = exporter
         && let Some(hdrs) = &exp.headers
     {
         for (k, v) in hdrs {
@@ -236,10 +260,15 @@ where
 
 #[cfg(feature = "otel")]
 fn build_metadata_from_cfg_and_env(cfg: &TracingConfig) -> Option<MetadataMap> {
+    metadata_from_exporter_and_env(cfg.exporter.as_ref())
+}
+
+#[cfg(feature = "otel")]
+fn metadata_from_exporter_and_env(exporter: Option<&Exporter>) -> Option<MetadataMap> {
     let mut md = MetadataMap::new();
 
     // From config file
-    if let Some(exp) = &cfg.exporter
+    if let Some(exp) = exporter
         && let Some(hdrs) = &exp.headers
     {
         let iter = hdrs.iter().map(|(k, v)| (k.as_str(), v.as_str()));
@@ -262,6 +291,69 @@ fn build_metadata_from_cfg_and_env(cfg: &TracingConfig) -> Option<MetadataMap> {
     if md.is_empty() { None } else { Some(md) }
 }
 
+// ===== init_metrics (feature = "otel") ========================================
+
+/// Initialize OTLP export of the process-wide [`MetricsRegistry`].
+///
+/// Installs a global meter provider pushing every `export_interval_ms` and forwards the
+/// registry to it. Keep the returned provider and call `shutdown()` on exit so the last
+/// interval is flushed.
+///
+/// # Errors
+/// Returns an error if metrics export is disabled or the exporter fails to build.
+#[cfg(feature = "otel")]
+pub fn init_metrics(cfg: &MetricsConfig) -> anyhow::Result<SdkMeterProvider> {
+    if !cfg.enabled {
+        return Err(anyhow::anyhow!("metrics export is disabled"));
+    }
+
+    let (kind, endpoint, timeout) = exporter_endpoint(cfg.exporter.as_ref());
+    tracing::info!(kind = ?kind, %endpoint, "OTLP metrics exporter config");
+
+    let exporter = if matches!(kind, ExporterKind::OtlpHttp) {
+        let mut b = opentelemetry_otlp::MetricExporter::builder()
+            .with_http()
+            .with_protocol(Protocol::HttpBinary)
+            .with_endpoint(endpoint);
+        if let Some(t) = timeout {
+            b = b.with_timeout(t);
+        }
+        if let Some(hmap) = headers_from_exporter_and_env(cfg.exporter.as_ref()) {
+            b = b.with_headers(hmap);
+        }
+        b.build().context("build OTLP HTTP metrics exporter")?
+    } else {
+        let mut b = opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .with_endpoint(endpoint);
+        if let Some(t) = timeout {
+            b = b.with_timeout(t);
+        }
+        if let Some(md) = metadata_from_exporter_and_env(cfg.exporter.as_ref()) {
+            b = b.with_metadata(md);
+        }
+        b.build().context("build OTLP gRPC metrics exporter")?
+    };
+
+    let interval = std::time::Duration::from_millis(cfg.export_interval_ms.unwrap_or(60_000));
+    let reader = PeriodicReader::builder(exporter)
+        .with_interval(interval)
+        .build();
+    let provider = SdkMeterProvider::builder()
+        .with_reader(reader)
+        .with_resource(resource_from(
+            cfg.service_name.as_deref(),
+            cfg.resource.as_ref(),
+        ))
+        .build();
+
+    global::set_meter_provider(provider.clone());
+    MetricsRegistry::global().export_to(global::meter("modkit"));
+
+    tracing::info!("OTLP metrics export initialized");
+    Ok(provider)
+}
+
 // ===== init_tracing (feature disabled) ========================================
 
 #[cfg(not(feature = "otel"))]
@@ -649,6 +741,36 @@ mod tests {
         assert_eq!(metadata.len(), 1);
     }
 
+    #[test]
+    #[cfg(feature = "otel")]
+    fn test_init_metrics_disabled() {
+        let cfg = crate::telemetry::config::MetricsConfig::default();
+        assert!(init_metrics(&cfg).is_err());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "otel")]
+    async fn test_init_metrics_with_http_exporter() {
+        let cfg = crate::telemetry::config::MetricsConfig {
+            enabled: true,
+            service_name: Some("test-service".to_owned()),
+            exporter: Some(Exporter {
+                kind: ExporterKind::OtlpHttp,
+                endpoint: Some("http://localhost:4318/v1/metrics".to_owned()),
+                headers: None,
+                timeout_ms: Some(1000),
+            }),
+            export_interval_ms: Some(3_600_000),
+            resource: None,
+        };
+
+        let provider = init_metrics(&cfg).unwrap();
+        MetricsRegistry::global()
+            .counter("modkit_test_exports", "Exported by the init test")
+            .inc(&[]);
+        let _ = provider.shutdown();
+    }
+
     #[test]
     fn test_shutdown_tracing_does_not_panic() {
         // Should not panic regardless of feature state
@@ -1,12 +1,20 @@
 //! Telemetry utilities for OpenTelemetry integration
 //!
 //! This module provides utilities for setting up and configuring
-//! OpenTelemetry tracing layers for distributed tracing.
+//! OpenTelemetry tracing layers for distributed tracing, and the process-wide
+//! [`metrics`] registry exposed on `/metrics` and optionally pushed over OTLP.
 
 pub mod config;
 pub mod init;
 pub mod throttled_log;
 
-pub use config::{Exporter, HttpOpts, LogsCorrelation, Propagation, Sampler, TracingConfig};
+pub use modkit_metrics as metrics;
+
+pub use config::{
+    Exporter, HttpOpts, LogsCorrelation, MetricsConfig, Propagation, Sampler, TracingConfig,
+};
+#[cfg(feature = "otel")]
+pub use init::init_metrics;
 pub use init::{init_tracing, shutdown_tracing};
+pub use metrics::MetricsRegistry;
 pub use throttled_log::ThrottledLog;
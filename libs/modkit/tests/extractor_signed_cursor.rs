@@ -0,0 +1,77 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use axum::{
+    Extension, Router,
+    body::to_bytes,
+    http::{Request, StatusCode},
+    routing::get,
+};
+use modkit::api::odata::OData;
+use modkit_odata::{CursorV1, ODataLimits, SortDir};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+async fn handler(OData(q): OData) -> String {
+    q.cursor.map(|c| c.k.join(",")).unwrap_or_default()
+}
+
+fn app(limits: ODataLimits) -> Router {
+    Router::new()
+        .route("/", get(handler))
+        .layer(Extension(Arc::new(limits)))
+}
+
+fn cursor() -> CursorV1 {
+    CursorV1 {
+        k: vec!["7".to_owned()],
+        o: SortDir::Asc,
+        s: "+id".to_owned(),
+        f: None,
+        d: "fwd".to_owned(),
+    }
+}
+
+async fn get_with_cursor(app: Router, token: &str) -> (StatusCode, String) {
+    let req = Request::builder()
+        .uri(format!("/?cursor={token}"))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8_lossy(&body).into_owned())
+}
+
+#[tokio::test]
+async fn signed_cursor_is_accepted() {
+    let limits = ODataLimits::default().with_signed_cursors(b"key".to_vec());
+    let token = cursor().encode_with(&limits).unwrap();
+
+    let (status, body) = get_with_cursor(app(limits), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "7");
+}
+
+#[tokio::test]
+async fn tampered_cursor_is_rejected_with_signature_error() {
+    let limits = ODataLimits::default().with_signed_cursors(b"key".to_vec());
+    let token = cursor().encode_with(&limits).unwrap();
+    let (_, signature) = token.split_once('.').unwrap();
+    let mut forged = cursor();
+    forged.k = vec!["8".to_owned()];
+    let forged = format!("{}.{signature}", forged.encode().unwrap());
+
+    let (status, body) = get_with_cursor(app(limits), &forged).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body.contains("invalid_cursor_signature"));
+}
+
+#[tokio::test]
+async fn unsigned_cursor_is_rejected_when_signing_is_required() {
+    let limits = ODataLimits::default().with_signed_cursors(b"key".to_vec());
+    let token = cursor().encode().unwrap();
+
+    let (status, body) = get_with_cursor(app(limits), &token).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body.contains("invalid_cursor_signature"));
+}
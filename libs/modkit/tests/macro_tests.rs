@@ -11,9 +11,11 @@ use modkit::{
     ModuleCtx,
     config::ConfigProvider,
     contracts::{
-        ApiGatewayCapability, DatabaseCapability, Module, OpenApiRegistry, RestApiCapability,
-        RunnableCapability,
+        ApiGatewayCapability, DatabaseCapability, EventsCapability, HealthCapability, Module,
+        OpenApiRegistry, RestApiCapability, RunnableCapability,
     },
+    events::{Topic, TopicSpec},
+    health::{HealthCheck, HealthStatus},
     module,
 };
 use std::sync::Arc;
@@ -226,6 +228,39 @@ impl RunnableCapability for StatefulOnlyModule {
     }
 }
 
+const ORDER_PLACED: Topic<serde_json::Value> = Topic::new("events_only.order_placed");
+
+#[derive(Default)]
+#[module(name = "events_only", capabilities = [events])]
+struct EventsOnlyModule;
+#[async_trait]
+impl Module for EventsOnlyModule {
+    async fn init(&self, _ctx: &modkit::context::ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+impl EventsCapability for EventsOnlyModule {
+    fn event_topics(&self) -> Vec<TopicSpec> {
+        vec![ORDER_PLACED.spec()]
+    }
+}
+
+#[derive(Default)]
+#[module(name = "health_only", capabilities = [health])]
+struct HealthOnlyModule;
+#[async_trait]
+impl Module for HealthOnlyModule {
+    async fn init(&self, _ctx: &modkit::context::ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+#[async_trait]
+impl HealthCapability for HealthOnlyModule {
+    async fn readiness(&self) -> HealthCheck {
+        HealthCheck::down("warming up")
+    }
+}
+
 // ---------- Tests ----------
 
 #[tokio::test]
@@ -282,3 +317,25 @@ fn test_capability_trait_markers() {
     assert_stateful(&FullFeaturedModule);
     assert_stateful(&StatefulOnlyModule);
 }
+
+#[test]
+fn test_events_capability() {
+    fn assert_events<T: EventsCapability>(_: &T) {}
+
+    assert_events(&EventsOnlyModule);
+    assert_eq!(EventsOnlyModule::MODULE_NAME, "events_only");
+    assert_eq!(EventsOnlyModule.event_topics(), vec![ORDER_PLACED.spec()]);
+}
+
+#[tokio::test]
+async fn test_health_capability() {
+    fn assert_health<T: HealthCapability>(_: &T) {}
+
+    assert_health(&HealthOnlyModule);
+    assert_eq!(HealthOnlyModule::MODULE_NAME, "health_only");
+    assert_eq!(HealthOnlyModule.liveness().await.status, HealthStatus::Up);
+    assert_eq!(
+        HealthOnlyModule.readiness().await.status,
+        HealthStatus::Down
+    );
+}
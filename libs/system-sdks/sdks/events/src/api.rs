@@ -0,0 +1,183 @@
+//! Event Bus API - contract for publishing and subscribing to event topics
+//!
+//! This module defines the core traits and types for the event bus backends.
+//! Payloads are opaque bytes here; typing and encoding live in `modkit::events`.
+
+use std::fmt;
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_core::Stream;
+
+/// What a topic does when a subscriber falls behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BackpressurePolicy {
+    /// Overwrite the oldest buffered events; the slow subscriber receives
+    /// [`EventError::Lagged`] with the number of events it missed.
+    #[default]
+    DropOldest,
+    /// Drop new events for subscribers whose buffer is full.
+    DropNewest,
+    /// Make publishers wait until every subscriber has room.
+    Block,
+}
+
+impl fmt::Display for BackpressurePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DropOldest => f.write_str("drop_oldest"),
+            Self::DropNewest => f.write_str("drop_newest"),
+            Self::Block => f.write_str("block"),
+        }
+    }
+}
+
+/// Describes a topic: its name, per-subscriber buffer size and backpressure policy.
+///
+/// All publishers and subscribers of a topic must agree on its spec.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopicSpec {
+    pub name: String,
+    pub capacity: usize,
+    pub policy: BackpressurePolicy,
+}
+
+impl TopicSpec {
+    pub fn new(name: impl Into<String>, capacity: usize, policy: BackpressurePolicy) -> Self {
+        Self {
+            name: name.into(),
+            capacity,
+            policy,
+        }
+    }
+
+    /// Check that the spec can be used to create a topic.
+    ///
+    /// # Errors
+    /// Returns [`EventError::InvalidTopic`] if the name is empty or the capacity is zero.
+    pub fn validate(&self) -> Result<(), EventError> {
+        let reason = if self.name.is_empty() {
+            "name must not be empty"
+        } else if self.capacity == 0 {
+            "capacity must be greater than zero"
+        } else {
+            return Ok(());
+        };
+        Err(EventError::InvalidTopic {
+            topic: self.name.clone(),
+            reason: reason.to_owned(),
+        })
+    }
+
+    /// Check that `other` describes the same topic as `self`.
+    ///
+    /// # Errors
+    /// Returns [`EventError::TopicConflict`] if the capacity or policy differ.
+    pub fn ensure_matches(&self, other: &TopicSpec) -> Result<(), EventError> {
+        if self == other {
+            return Ok(());
+        }
+        Err(EventError::TopicConflict {
+            topic: self.name.clone(),
+            reason: format!(
+                "declared with capacity {} and policy {}, got capacity {} and policy {}",
+                self.capacity, self.policy, other.capacity, other.policy
+            ),
+        })
+    }
+}
+
+/// Event bus errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EventError {
+    #[error("Invalid topic '{topic}': {reason}")]
+    InvalidTopic { topic: String, reason: String },
+
+    #[error("Topic '{topic}' conflicts with its declaration: {reason}")]
+    TopicConflict { topic: String, reason: String },
+
+    /// The subscriber fell behind a `DropOldest` topic and missed events.
+    /// The subscription stays open and continues with the oldest retained event.
+    #[error("Subscriber lagged behind: {0} events dropped")]
+    Lagged(u64),
+
+    #[error("Event encoding error: {0}")]
+    Codec(String),
+
+    #[error("Event transport error: {0}")]
+    Transport(String),
+}
+
+impl EventError {
+    /// Returns true if the subscriber missed events but can keep reading.
+    #[must_use]
+    pub const fn is_lagged(&self) -> bool {
+        matches!(self, Self::Lagged(_))
+    }
+}
+
+/// Stream of raw event payloads for one subscriber.
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<Bytes, EventError>> + Send + 'static>>;
+
+/// Event bus backend
+///
+/// Implemented by the in-process backend in `modkit` and by the gRPC client
+/// that bridges out-of-process modules to the host's bus. Topics are created on
+/// first use; every call carries the full [`TopicSpec`] so that mismatching
+/// declarations are detected wherever they happen.
+#[async_trait]
+pub trait EventBackend: Send + Sync {
+    /// Create the topic, or check that an existing topic has the same spec.
+    async fn declare(&self, topic: &TopicSpec) -> Result<(), EventError>;
+
+    /// Publish a payload to every current subscriber of the topic.
+    ///
+    /// Events published while a topic has no subscribers are discarded.
+    async fn publish(&self, topic: &TopicSpec, payload: Bytes) -> Result<(), EventError>;
+
+    /// Subscribe to events published from now on.
+    async fn subscribe(&self, topic: &TopicSpec) -> Result<EventStream, EventError>;
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_empty_name_and_zero_capacity() {
+        assert!(
+            TopicSpec::new("users.created", 16, BackpressurePolicy::Block)
+                .validate()
+                .is_ok()
+        );
+        assert!(matches!(
+            TopicSpec::new("", 16, BackpressurePolicy::Block).validate(),
+            Err(EventError::InvalidTopic { .. })
+        ));
+        assert!(matches!(
+            TopicSpec::new("users.created", 0, BackpressurePolicy::Block).validate(),
+            Err(EventError::InvalidTopic { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ensure_matches_reports_differences() {
+        let declared = TopicSpec::new("users.created", 16, BackpressurePolicy::DropOldest);
+        assert!(declared.ensure_matches(&declared.clone()).is_ok());
+
+        let err = declared
+            .ensure_matches(&TopicSpec::new(
+                "users.created",
+                16,
+                BackpressurePolicy::Block,
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Topic 'users.created' conflicts with its declaration: declared with capacity 16 \
+             and policy drop_oldest, got capacity 16 and policy block"
+        );
+    }
+}
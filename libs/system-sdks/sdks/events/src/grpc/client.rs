@@ -0,0 +1,139 @@
+//! gRPC client implementation of the event bus backend
+//!
+//! This client allows out-of-process modules to publish and subscribe to the
+//! host's event bus via gRPC.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio_stream::StreamExt;
+use tonic::transport::Channel;
+
+use crate::api::{EventBackend, EventError, EventStream, TopicSpec};
+use modkit_transport_grpc::client::{GrpcClientConfig, connect_with_retry};
+
+use super::{
+    DeclareRequest, EventBusServiceClient, PublishRequest, SubscribeItem, SubscribeRequest,
+    error_from_status,
+};
+
+/// gRPC client for the event bus
+///
+/// This client connects to a remote `EventBusService` and implements
+/// [`EventBackend`] on top of it, so a `modkit::events::EventBus` built on it
+/// behaves like the host's in-process bus:
+/// - `Block` topics apply backpressure through the `Publish` call and HTTP/2 flow control
+/// - lag notifications on `DropOldest` topics are forwarded as [`EventError::Lagged`]
+/// - host-side topic errors are mapped back to their [`EventError`] variants
+#[derive(Clone)]
+pub struct EventBusGrpcClient {
+    inner: EventBusServiceClient<Channel>,
+}
+
+impl EventBusGrpcClient {
+    /// Connect to an event bus service using default configuration with retries.
+    ///
+    /// This is the recommended method for `OoP` modules connecting to the master host.
+    ///
+    /// # Errors
+    /// It will return an error when it fails
+    pub async fn connect(uri: impl Into<String>) -> Result<Self> {
+        let cfg = GrpcClientConfig::new("events");
+        Self::connect_with_retry(uri, &cfg).await
+    }
+
+    /// Connect to an event bus service with custom configuration and retry logic.
+    ///
+    /// Note that `cfg.rpc_timeout` also bounds how long a `publish` to a `Block` topic may wait.
+    ///
+    /// # Errors
+    /// It will return an error when it fails
+    pub async fn connect_with_retry(
+        uri: impl Into<String>,
+        cfg: &GrpcClientConfig,
+    ) -> Result<Self> {
+        let channel: Channel = connect_with_retry(uri, cfg).await?;
+        Ok(Self::from_channel(channel))
+    }
+
+    /// Create from an existing channel (useful for testing or custom setup)
+    #[must_use]
+    pub fn from_channel(channel: Channel) -> Self {
+        Self {
+            inner: EventBusServiceClient::new(channel),
+        }
+    }
+}
+
+#[async_trait]
+impl EventBackend for EventBusGrpcClient {
+    async fn declare(&self, topic: &TopicSpec) -> Result<(), EventError> {
+        let mut client = self.inner.clone();
+        let request = tonic::Request::new(DeclareRequest {
+            topic: Some(topic.into()),
+        });
+
+        client
+            .declare(request)
+            .await
+            .map_err(|status| error_from_status(topic, &status))?;
+
+        Ok(())
+    }
+
+    async fn publish(&self, topic: &TopicSpec, payload: Bytes) -> Result<(), EventError> {
+        let mut client = self.inner.clone();
+        let request = tonic::Request::new(PublishRequest {
+            topic: Some(topic.into()),
+            payload: payload.to_vec(),
+        });
+
+        client
+            .publish(request)
+            .await
+            .map_err(|status| error_from_status(topic, &status))?;
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &TopicSpec) -> Result<EventStream, EventError> {
+        let mut client = self.inner.clone();
+        let request = tonic::Request::new(SubscribeRequest {
+            topic: Some(topic.into()),
+        });
+
+        let events = client
+            .subscribe(request)
+            .await
+            .map_err(|status| error_from_status(topic, &status))?
+            .into_inner();
+
+        let topic = topic.clone();
+        let stream = events.filter_map(move |item| match item {
+            Ok(response) => match response.item? {
+                SubscribeItem::Payload(payload) => Some(Ok(Bytes::from(payload))),
+                SubscribeItem::Lagged(missed) => Some(Err(EventError::Lagged(missed))),
+            },
+            Err(status) => Some(Err(error_from_status(&topic, &status))),
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_grpc_client_can_be_constructed() {
+        // Smoke test to ensure types compile and connect
+        let endpoint = tonic::transport::Endpoint::from_static("http://[::1]:50052");
+
+        // It's expected to fail since there's no server, but if it does somehow succeed:
+        if let Ok(channel) = endpoint.connect().await {
+            let _client = EventBusGrpcClient::from_channel(channel);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Event Bus gRPC Layer
+//!
+//! This module provides gRPC transport for the event bus.
+//! It includes generated protobuf types, proto ↔ domain conversions and the client backend.
+mod client;
+
+use tonic::Status;
+
+use crate::api::{BackpressurePolicy, EventError, TopicSpec};
+
+// Generated protobuf types for EventBusService
+#[allow(clippy::all, clippy::pedantic, clippy::nursery, warnings)] // protoc problem
+pub mod events {
+    tonic::include_proto!("module_orchestrator.v1.events");
+}
+
+// Re-export common types for EventBusService
+pub use events::event_bus_service_client::EventBusServiceClient;
+pub use events::event_bus_service_server::{EventBusService, EventBusServiceServer};
+pub use events::subscribe_response::Item as SubscribeItem;
+pub use events::{DeclareRequest, PublishRequest, SubscribeRequest, SubscribeResponse};
+
+// Re-export the gRPC client implementation
+pub use client::EventBusGrpcClient;
+
+/// Service name constant for `EventBusService`
+pub const EVENT_BUS_SERVICE_NAME: &str =
+    <EventBusServiceServer<()> as tonic::server::NamedService>::NAME;
+
+impl From<BackpressurePolicy> for events::Backpressure {
+    fn from(policy: BackpressurePolicy) -> Self {
+        match policy {
+            BackpressurePolicy::DropOldest => Self::DropOldest,
+            BackpressurePolicy::DropNewest => Self::DropNewest,
+            BackpressurePolicy::Block => Self::Block,
+        }
+    }
+}
+
+impl From<events::Backpressure> for BackpressurePolicy {
+    fn from(policy: events::Backpressure) -> Self {
+        match policy {
+            events::Backpressure::DropOldest => Self::DropOldest,
+            events::Backpressure::DropNewest => Self::DropNewest,
+            events::Backpressure::Block => Self::Block,
+        }
+    }
+}
+
+impl From<&TopicSpec> for events::Topic {
+    fn from(spec: &TopicSpec) -> Self {
+        Self {
+            name: spec.name.clone(),
+            capacity: u64::try_from(spec.capacity).unwrap_or(u64::MAX),
+            policy: events::Backpressure::from(spec.policy).into(),
+        }
+    }
+}
+
+impl TryFrom<events::Topic> for TopicSpec {
+    type Error = EventError;
+
+    fn try_from(topic: events::Topic) -> Result<Self, Self::Error> {
+        let invalid = |reason: &str| EventError::InvalidTopic {
+            topic: topic.name.clone(),
+            reason: reason.to_owned(),
+        };
+        let capacity =
+            usize::try_from(topic.capacity).map_err(|_| invalid("capacity is too large"))?;
+        let policy = events::Backpressure::try_from(topic.policy)
+            .map_err(|_| invalid("unknown backpressure policy"))?;
+
+        let spec = TopicSpec::new(topic.name.clone(), capacity, policy.into());
+        spec.validate()?;
+        Ok(spec)
+    }
+}
+
+impl From<EventError> for Status {
+    fn from(err: EventError) -> Self {
+        match err {
+            EventError::InvalidTopic { reason, .. } => Status::invalid_argument(reason),
+            EventError::TopicConflict { reason, .. } => Status::failed_precondition(reason),
+            EventError::Transport(message) => Status::unavailable(message),
+            other @ (EventError::Lagged(_) | EventError::Codec(_)) => {
+                Status::internal(other.to_string())
+            }
+        }
+    }
+}
+
+/// Map a status returned for `topic` back to the error the host backend reported.
+fn error_from_status(topic: &TopicSpec, status: &Status) -> EventError {
+    match status.code() {
+        tonic::Code::InvalidArgument => EventError::InvalidTopic {
+            topic: topic.name.clone(),
+            reason: status.message().to_owned(),
+        },
+        tonic::Code::FailedPrecondition => EventError::TopicConflict {
+            topic: topic.name.clone(),
+            reason: status.message().to_owned(),
+        },
+        _ => EventError::Transport(format!("gRPC call failed: {status}")),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_round_trip() {
+        for policy in [
+            BackpressurePolicy::DropOldest,
+            BackpressurePolicy::DropNewest,
+            BackpressurePolicy::Block,
+        ] {
+            let spec = TopicSpec::new("users.created", 64, policy);
+            let proto = events::Topic::from(&spec);
+            assert_eq!(TopicSpec::try_from(proto).unwrap(), spec);
+        }
+    }
+
+    #[test]
+    fn test_invalid_topic_is_rejected() {
+        let proto = events::Topic {
+            name: "users.created".to_owned(),
+            capacity: 16,
+            policy: 42,
+        };
+        assert!(matches!(
+            TopicSpec::try_from(proto),
+            Err(EventError::InvalidTopic { .. })
+        ));
+    }
+
+    #[test]
+    fn test_errors_survive_status_round_trip() {
+        let topic = TopicSpec::new("users.created", 16, BackpressurePolicy::Block);
+        let conflict = EventError::TopicConflict {
+            topic: topic.name.clone(),
+            reason: "declared with capacity 8".to_owned(),
+        };
+
+        let status = Status::from(conflict.clone());
+        assert_eq!(error_from_status(&topic, &status), conflict);
+
+        let status = Status::from(EventError::Transport("closed".to_owned()));
+        assert!(matches!(
+            error_from_status(&topic, &status),
+            EventError::Transport(_)
+        ));
+    }
+}
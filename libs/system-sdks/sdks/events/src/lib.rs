@@ -0,0 +1,14 @@
+//! Event Bus Contracts
+//!
+//! Transport contract for the `ModKit` event bus. This crate provides the
+//! `EventBackend` trait and the topic types shared by the in-process backend
+//! and the gRPC bridge used by out-of-process modules.
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+mod api;
+#[cfg(feature = "grpc")]
+mod grpc;
+
+pub use api::{BackpressurePolicy, EventBackend, EventError, EventStream, TopicSpec};
+#[cfg(feature = "grpc")]
+pub use grpc::*;
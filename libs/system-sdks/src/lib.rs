@@ -1,2 +1,5 @@
 #[cfg(feature = "directory")]
 pub use cf_system_sdk_directory as directory;
+
+#[cfg(feature = "events")]
+pub use cf_system_sdk_events as events;
@@ -6,7 +6,7 @@ use modkit_auth::{
     traits::{PrimaryAuthorizer, TokenValidator},
     types::{AuthRequirement, SecRequirement},
 };
-use modkit_security::{NoopPolicyEngine, PolicyEngineRef};
+use modkit_security::PolicyEngineRef;
 use std::{collections::HashMap, sync::Arc};
 
 #[derive(Clone)]
@@ -170,6 +170,7 @@ pub fn build_auth_state(
     cfg: &crate::config::ApiGatewayConfig,
     requirements: HashMap<(Method, String), Requirement>,
     public_routes: std::collections::HashSet<(Method, String)>,
+    policy_engine: PolicyEngineRef,
 ) -> Result<(AuthState, GatewayRoutePolicy), anyhow::Error> {
     // Build validator (TokenValidator trait implementation)
     let validator: Arc<dyn TokenValidator> = if cfg.auth_disabled {
@@ -179,10 +180,9 @@ pub fn build_auth_state(
         Arc::new(NoopValidator)
     } else {
         // Build AuthConfig for new dispatcher system
-        let jwks_uri = cfg
-            .jwks_uri
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("jwks_uri required when auth is enabled"))?;
+        if cfg.jwks_uri.is_none() && cfg.introspection.is_none() {
+            anyhow::bail!("jwks_uri or introspection required when auth is enabled");
+        }
 
         let mut plugins = HashMap::new();
         plugins.insert(
@@ -208,11 +208,12 @@ pub fn build_auth_state(
                 .as_ref()
                 .map(|a| vec![a.clone()])
                 .unwrap_or_default(),
-            jwks: Some(JwksConfig {
-                uri: jwks_uri,
+            jwks: cfg.jwks_uri.clone().map(|uri| JwksConfig {
+                uri,
                 refresh_interval_seconds: 300,
                 max_backoff_seconds: 3600,
             }),
+            introspection: cfg.introspection.clone(),
             plugins,
         };
 
@@ -256,7 +257,7 @@ pub fn build_auth_state(
 
     let auth_state = AuthState {
         validator,
-        policy_engine: Arc::new(NoopPolicyEngine),
+        policy_engine,
         authorizer,
     };
 
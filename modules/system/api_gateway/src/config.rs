@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use uuid::Uuid;
 
 fn default_require_auth_by_default() -> bool {
     true
 }
 
+fn default_enable_metrics() -> bool {
+    true
+}
+
 fn default_body_limit_bytes() -> usize {
     16 * 1024 * 1024
 }
@@ -50,9 +57,143 @@ pub struct ApiGatewayConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audience: Option<String>,
 
+    /// RFC 7662 introspection endpoint for opaque (non-JWT) tokens (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub introspection: Option<modkit_auth::IntrospectionConfig>,
+
     /// If true, routes without explicit role still require authentication (AuthN-only).
     #[serde(default = "default_require_auth_by_default")]
     pub require_auth_by_default: bool,
+
+    /// HMAC keys for signing `OData` pagination cursors (optional; cursors are unsigned if absent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_signing: Option<CursorSigningConfig>,
+
+    /// Serve HTTPS (and HTTP/2 via ALPN) instead of plain HTTP (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+
+    /// Record HTTP request metrics and serve the metrics registry on `/metrics` (default: true).
+    #[serde(default = "default_enable_metrics")]
+    pub enable_metrics: bool,
+}
+
+/// TLS termination configuration
+///
+/// Certificate, key and client CA files are re-read when they change on disk, so
+/// certificates can be rotated without a restart.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// PEM certificate chain, leaf first
+    pub cert_path: PathBuf,
+    /// PEM private key (PKCS#8, PKCS#1 or SEC1)
+    pub key_path: PathBuf,
+    /// PEM bundle of CAs trusted to issue client certificates; enables mTLS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ca_path: Option<PathBuf>,
+    /// Reject connections without a client certificate when mTLS is enabled (default: true)
+    #[serde(default = "default_require_client_cert")]
+    pub require_client_cert: bool,
+    /// Lowest accepted protocol version (default: 1.2)
+    #[serde(default)]
+    pub min_version: TlsVersion,
+    /// ALPN protocols in preference order (default: h2, http/1.1)
+    #[serde(default = "default_alpn")]
+    pub alpn: Vec<String>,
+    /// How often to check the files for changes, in seconds (0 disables reloading)
+    #[serde(default = "default_tls_reload_interval_secs")]
+    pub reload_interval_secs: u64,
+    /// Identities granted to verified client certificates, matched by subject common name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub client_identities: Vec<ClientCertIdentity>,
+}
+
+fn default_require_client_cert() -> bool {
+    true
+}
+
+fn default_alpn() -> Vec<String> {
+    vec!["h2".to_owned(), "http/1.1".to_owned()]
+}
+
+fn default_tls_reload_interval_secs() -> u64 {
+    30
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TlsVersion {
+    #[default]
+    #[serde(rename = "1.2")]
+    Tls12,
+    #[serde(rename = "1.3")]
+    Tls13,
+}
+
+/// `SecurityContext` for requests authenticated by a client certificate
+///
+/// Used when the request carries no bearer token; a bearer token always wins.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientCertIdentity {
+    /// Subject common name (CN) of the client certificate
+    pub common_name: String,
+    /// Accepted SHA-256 certificate fingerprints (hex, colons optional); empty accepts any
+    #[serde(default)]
+    pub fingerprints_sha256: Vec<String>,
+    /// Subject alternative names (DNS or URI) of which the certificate must carry at least
+    /// one; empty accepts any
+    #[serde(default)]
+    pub subject_alt_names: Vec<String>,
+    pub subject_id: Uuid,
+    pub tenant_id: Uuid,
+    /// Granted permissions, as `tenant_id:resource_pattern:resource_id:action`
+    #[serde(default)]
+    pub permissions: Vec<modkit_auth::claims::Permission>,
+}
+
+/// `OData` cursor signing configuration
+///
+/// The first key signs new cursors; all keys are accepted when verifying, so a key can be
+/// rotated by prepending the new one and dropping the old one once its cursors have expired.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CursorSigningConfig {
+    /// Base64-encoded HMAC-SHA256 keys, current key first
+    pub keys: Vec<String>,
+    /// Reject unsigned cursors (default: true)
+    #[serde(default = "default_require_signed_cursors")]
+    pub require_signed: bool,
+}
+
+fn default_require_signed_cursors() -> bool {
+    true
+}
+
+impl CursorSigningConfig {
+    /// Build `OData` limits carrying the configured cursor keys.
+    ///
+    /// # Errors
+    /// Returns an error if no key is configured or a key is not valid base64.
+    pub fn to_limits(&self) -> anyhow::Result<modkit_odata::ODataLimits> {
+        use base64::Engine;
+
+        let mut keys = self.keys.iter().map(|key| {
+            base64::engine::general_purpose::STANDARD
+                .decode(key.trim())
+                .map_err(|e| anyhow::anyhow!("invalid cursor_signing key: {e}"))
+        });
+        let Some(signing_key) = keys.next().transpose()? else {
+            anyhow::bail!("cursor_signing requires at least one key");
+        };
+
+        let mut limits = modkit_odata::ODataLimits::default().with_signed_cursors(signing_key);
+        limits.require_signed_cursors = self.require_signed;
+        for key in keys {
+            limits = limits.with_cursor_verification_key(key?);
+        }
+        Ok(limits)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -79,6 +220,14 @@ pub struct RateLimitDefaults {
     pub rps: u32,
     pub burst: u32,
     pub in_flight: u32,
+    /// What a request's token bucket is keyed by (in addition to the route)
+    pub key: RateLimitKey,
+    /// Header carrying the API key when `key` is `api_key`
+    pub api_key_header: String,
+    /// Maximum number of per-client buckets kept per route; least recently used are evicted
+    pub max_clients: usize,
+    /// Per-tenant quotas replacing the route quota for that tenant's requests
+    pub tenant_overrides: HashMap<Uuid, TenantRateLimit>,
 }
 
 impl Default for RateLimitDefaults {
@@ -87,10 +236,40 @@ impl Default for RateLimitDefaults {
             rps: 50,
             burst: 100,
             in_flight: 64,
+            key: RateLimitKey::default(),
+            api_key_header: "x-api-key".to_owned(),
+            max_clients: 10_000,
+            tenant_overrides: HashMap::new(),
         }
     }
 }
 
+/// Caller identity used to partition rate limit buckets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitKey {
+    /// One bucket per route shared by all callers
+    #[default]
+    Route,
+    /// Authenticated subject id
+    Subject,
+    /// Authenticated tenant id
+    Tenant,
+    /// Authenticated subject of requests carrying the API key header (falls back to the
+    /// client IP)
+    ApiKey,
+    /// Peer IP address of the connection
+    ClientIp,
+}
+
+/// Rate limit quota for a single tenant
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TenantRateLimit {
+    pub rps: u32,
+    pub burst: u32,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields, default)]
 pub struct CorsConfig {
@@ -147,3 +326,40 @@ impl Default for OpenApiConfig {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_signing_uses_first_key_and_verifies_all() {
+        let cfg: CursorSigningConfig = serde_json::from_value(serde_json::json!({
+            "keys": ["bmV3LWtleQ==", "b2xkLWtleQ=="]
+        }))
+        .unwrap();
+        let limits = cfg.to_limits().unwrap();
+
+        assert!(limits.require_signed_cursors);
+        assert_eq!(
+            limits.cursor_hmac_key.as_deref(),
+            Some(b"new-key".as_slice())
+        );
+        assert_eq!(limits.cursor_verification_keys, vec![b"old-key".to_vec()]);
+    }
+
+    #[test]
+    fn cursor_signing_rejects_missing_or_invalid_keys() {
+        let empty = CursorSigningConfig {
+            keys: vec![],
+            require_signed: true,
+        };
+        assert!(empty.to_limits().is_err());
+
+        let invalid = CursorSigningConfig {
+            keys: vec!["not base64!".to_owned()],
+            require_signed: true,
+        };
+        assert!(invalid.to_limits().is_err());
+    }
+}
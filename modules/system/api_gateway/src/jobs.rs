@@ -0,0 +1,257 @@
+//! REST endpoints to inspect and control the background jobs of the process.
+
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::response::Response;
+use axum::{Extension, Router};
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::{
+    AuthReqAction, AuthReqResource, LicenseFeature, OperationBuilder,
+};
+use modkit::api::prelude::*;
+use modkit::jobs::{JobError, JobInfo, JobRegistry, JobStatus};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+const TAG: &str = "jobs";
+
+enum Resource {
+    Jobs,
+}
+
+impl AsRef<str> for Resource {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Resource::Jobs => "jobs",
+        }
+    }
+}
+
+impl AuthReqResource for Resource {}
+
+enum Action {
+    Read,
+    Write,
+}
+
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Action::Read => "read",
+            Action::Write => "write",
+        }
+    }
+}
+
+impl AuthReqAction for Action {}
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
+/// Background job with its persisted state
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JobDto {
+    /// Scheduler owning the job, usually the module name
+    pub scheduler: String,
+    pub name: String,
+    pub schedule: String,
+    pub status: JobStatus,
+    /// Whether this instance is running the job right now
+    pub running: bool,
+    /// Consecutive failed runs
+    pub attempts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_run_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl From<JobInfo> for JobDto {
+    fn from(job: JobInfo) -> Self {
+        Self {
+            scheduler: job.scheduler,
+            name: job.name,
+            schedule: job.schedule,
+            status: job.status,
+            running: job.running,
+            attempts: job.attempts,
+            next_run_at: job.next_run_at,
+            last_run_at: job.last_run_at,
+            last_error: job.last_error,
+        }
+    }
+}
+
+fn job_problem(e: &JobError) -> Problem {
+    let (status, code, title) = match e {
+        JobError::NotFound { .. } => (StatusCode::NOT_FOUND, "JOBS_NOT_FOUND", "Job not found"),
+        JobError::Paused { .. } => (StatusCode::CONFLICT, "JOBS_PAUSED", "Job is paused"),
+        JobError::InvalidCron { .. } | JobError::Database(_) => {
+            tracing::error!(error = %e, "Job operation failed");
+            return Problem::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Internal Server Error",
+                "An internal error occurred",
+            )
+            .with_type("https://errors.hyperspot.com/JOBS_INTERNAL")
+            .with_code("JOBS_INTERNAL");
+        }
+    };
+
+    Problem::new(status, title, e.to_string())
+        .with_type(format!("https://errors.hyperspot.com/{code}"))
+        .with_code(code)
+}
+
+async fn list_jobs(Extension(jobs): Extension<Arc<JobRegistry>>) -> ApiResult<Json<Vec<JobDto>>> {
+    let list = jobs.list().await.map_err(|e| job_problem(&e))?;
+    Ok(Json(list.into_iter().map(Into::into).collect()))
+}
+
+async fn trigger_job(
+    Extension(jobs): Extension<Arc<JobRegistry>>,
+    Path((scheduler, name)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    jobs.trigger(&scheduler, &name)
+        .await
+        .map_err(|e| job_problem(&e))?;
+    Ok(no_content().into_response())
+}
+
+async fn pause_job(
+    Extension(jobs): Extension<Arc<JobRegistry>>,
+    Path((scheduler, name)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    jobs.pause(&scheduler, &name)
+        .await
+        .map_err(|e| job_problem(&e))?;
+    Ok(no_content().into_response())
+}
+
+async fn resume_job(
+    Extension(jobs): Extension<Arc<JobRegistry>>,
+    Path((scheduler, name)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    jobs.resume(&scheduler, &name)
+        .await
+        .map_err(|e| job_problem(&e))?;
+    Ok(no_content().into_response())
+}
+
+/// Register the job endpoints backed by the process' [`JobRegistry`].
+pub fn register_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+    jobs: Arc<JobRegistry>,
+) -> Router {
+    router = OperationBuilder::get("/api-gateway/v1/jobs")
+        .operation_id("api_gateway.list_jobs")
+        .summary("List background jobs")
+        .description("List the background jobs of all modules with their schedule and state.")
+        .tag(TAG)
+        .require_auth(&Resource::Jobs, &Action::Read)
+        .require_license_features::<License>([])
+        .handler(list_jobs)
+        .json_response_with_schema::<Vec<JobDto>>(openapi, StatusCode::OK, "List of jobs")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router = OperationBuilder::post("/api-gateway/v1/jobs/{scheduler}/{name}/trigger")
+        .operation_id("api_gateway.trigger_job")
+        .summary("Trigger a job")
+        .description(
+            "Run a job as soon as possible. Dead-lettered jobs start a fresh series of attempts.",
+        )
+        .tag(TAG)
+        .require_auth(&Resource::Jobs, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("scheduler", "Scheduler owning the job")
+        .path_param("name", "Job name")
+        .handler(trigger_job)
+        .json_response(StatusCode::NO_CONTENT, "Job scheduled to run now")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router = OperationBuilder::post("/api-gateway/v1/jobs/{scheduler}/{name}/pause")
+        .operation_id("api_gateway.pause_job")
+        .summary("Pause a job")
+        .description(
+            "Stop running a job until it is resumed. A run in progress is not interrupted.",
+        )
+        .tag(TAG)
+        .require_auth(&Resource::Jobs, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("scheduler", "Scheduler owning the job")
+        .path_param("name", "Job name")
+        .handler(pause_job)
+        .json_response(StatusCode::NO_CONTENT, "Job paused")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router = OperationBuilder::post("/api-gateway/v1/jobs/{scheduler}/{name}/resume")
+        .operation_id("api_gateway.resume_job")
+        .summary("Resume a job")
+        .description("Resume a paused job; runs missed while paused are collapsed into one.")
+        .tag(TAG)
+        .require_auth(&Resource::Jobs, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("scheduler", "Scheduler owning the job")
+        .path_param("name", "Job name")
+        .handler(resume_job)
+        .json_response(StatusCode::NO_CONTENT, "Job resumed")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router.layer(Extension(jobs))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn jobs_endpoints_are_documented_and_map_errors() {
+        let api = crate::ApiGateway::default();
+        let router = register_routes(Router::new(), &api, Arc::new(JobRegistry::new()));
+
+        let doc = serde_json::to_value(api.build_openapi().unwrap()).unwrap();
+        for path in ["/jobs", "/jobs/{scheduler}/{name}/pause"] {
+            let path = format!("/api-gateway/v1{path}").replace('/', "~1");
+            assert!(doc.pointer(&format!("/paths/{path}")).is_some(), "{path}");
+        }
+
+        let response = router
+            .clone()
+            .oneshot(
+                http::Request::get("/api-gateway/v1/jobs")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router
+            .oneshot(
+                http::Request::post("/api-gateway/v1/jobs/users/purge/trigger")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -14,9 +14,14 @@ mod auth;
 mod config;
 mod cors;
 pub mod error;
+mod jobs;
 pub mod middleware;
 mod router_cache;
+mod tls;
 mod web;
 
 // === RE-EXPORTS ===
-pub use config::{ApiGatewayConfig, CorsConfig};
+pub use config::{
+    ApiGatewayConfig, ClientCertIdentity, CorsConfig, CursorSigningConfig, TlsConfig, TlsVersion,
+};
+pub use tls::ClientCertificate;
@@ -0,0 +1,165 @@
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use modkit_auth::Claims;
+use modkit_auth::axum_ext::TransportIdentity;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::ClientCertIdentity;
+use crate::tls::ClientCertificate;
+
+/// Configured identities by client certificate common name
+///
+/// A certificate only maps to an identity when it also matches the identity's pinned
+/// fingerprints and subject alternative names, if any are configured.
+#[derive(Clone, Default)]
+pub struct ClientCertIdentityMap {
+    identities: Arc<HashMap<String, ClientCertIdentity>>,
+}
+
+impl ClientCertIdentityMap {
+    #[must_use]
+    pub fn new(identities: &[ClientCertIdentity]) -> Self {
+        Self {
+            identities: Arc::new(
+                identities
+                    .iter()
+                    .map(|identity| {
+                        let mut identity = identity.clone();
+                        for fingerprint in &mut identity.fingerprints_sha256 {
+                            *fingerprint = normalize_fingerprint(fingerprint);
+                        }
+                        (identity.common_name.clone(), identity)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn claims(&self, cert: &ClientCertificate) -> Option<Claims> {
+        let identity = self.identities.get(cert.common_name.as_deref()?)?;
+        if !identity.fingerprints_sha256.is_empty()
+            && !identity
+                .fingerprints_sha256
+                .contains(&normalize_fingerprint(&cert.fingerprint_sha256))
+        {
+            tracing::warn!(common_name = %identity.common_name, "client certificate fingerprint is not pinned");
+            return None;
+        }
+        if !identity.subject_alt_names.is_empty()
+            && !cert
+                .subject_alt_names
+                .iter()
+                .any(|name| identity.subject_alt_names.contains(name))
+        {
+            tracing::warn!(common_name = %identity.common_name, "client certificate subject alternative names don't match");
+            return None;
+        }
+        let mut extras = serde_json::Map::new();
+        extras.insert(
+            "x5t#S256".to_owned(),
+            serde_json::Value::String(cert.fingerprint_sha256.clone()),
+        );
+        Some(Claims {
+            issuer: "mtls".to_owned(),
+            subject: identity.subject_id,
+            audiences: Vec::new(),
+            expires_at: None,
+            not_before: None,
+            issued_at: None,
+            jwt_id: None,
+            tenant_id: identity.tenant_id,
+            permissions: identity.permissions.clone(),
+            extras,
+        })
+    }
+}
+
+/// Lowercase hex without `:` separators
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    fingerprint
+        .chars()
+        .filter(|c| *c != ':')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Hand the identity of a known client certificate to the auth layer
+pub async fn client_cert_identity_middleware(
+    map: ClientCertIdentityMap,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    if let Some(claims) = req
+        .extensions()
+        .get::<ClientCertificate>()
+        .and_then(|cert| map.claims(cert))
+    {
+        req.extensions_mut().insert(TransportIdentity(claims));
+    }
+    next.run(req).await
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn maps_known_common_name_to_claims() {
+        let identity = ClientCertIdentity {
+            common_name: "billing-service".to_owned(),
+            fingerprints_sha256: Vec::new(),
+            subject_alt_names: Vec::new(),
+            subject_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            permissions: vec![serde_json::from_str("\"*:invoices:*:read\"").unwrap()],
+        };
+        let map = ClientCertIdentityMap::new(std::slice::from_ref(&identity));
+
+        let cert = |cn: &str| ClientCertificate {
+            common_name: Some(cn.to_owned()),
+            subject_alt_names: Vec::new(),
+            fingerprint_sha256: "ab".repeat(32),
+        };
+        let claims = map.claims(&cert("billing-service")).unwrap();
+        assert_eq!(claims.subject, identity.subject_id);
+        assert_eq!(claims.tenant_id, identity.tenant_id);
+        assert_eq!(claims.permissions[0].resource_pattern(), "invoices");
+
+        assert!(map.claims(&cert("unknown")).is_none());
+    }
+
+    #[test]
+    fn enforces_pinned_fingerprints_and_subject_alt_names() {
+        let identity = ClientCertIdentity {
+            common_name: "billing-service".to_owned(),
+            fingerprints_sha256: vec![format!("AB:{}", "ab".repeat(31))],
+            subject_alt_names: vec!["billing.internal".to_owned()],
+            subject_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            permissions: Vec::new(),
+        };
+        let map = ClientCertIdentityMap::new(std::slice::from_ref(&identity));
+
+        let cert = |fingerprint: String, san: &str| ClientCertificate {
+            common_name: Some("billing-service".to_owned()),
+            subject_alt_names: vec![san.to_owned()],
+            fingerprint_sha256: fingerprint,
+        };
+        assert!(
+            map.claims(&cert("ab".repeat(32), "billing.internal"))
+                .is_some()
+        );
+        assert!(
+            map.claims(&cert("cd".repeat(32), "billing.internal"))
+                .is_none()
+        );
+        assert!(
+            map.claims(&cert("ab".repeat(32), "other.internal"))
+                .is_none()
+        );
+    }
+}
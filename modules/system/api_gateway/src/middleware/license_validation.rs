@@ -4,22 +4,29 @@ use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
 use dashmap::DashMap;
 use http::Method;
+use license_resolver_sdk::{BASE_FEATURE, LicenseResolverError, LicenseResolverGatewayClient};
+use modkit_security::SecurityContext;
 use std::sync::Arc;
 
 use modkit::api::{OperationSpec, Problem};
 
-const BASE_FEATURE: &str = "gts.x.core.lic.feat.v1~x.core.global.base.v1";
-
 type LicenseKey = (Method, String);
 
 #[derive(Clone)]
 pub struct LicenseRequirementMap {
     requirements: Arc<DashMap<LicenseKey, Vec<String>>>,
+    resolver: Option<Arc<dyn LicenseResolverGatewayClient>>,
 }
 
 impl LicenseRequirementMap {
+    /// Collect license requirements from `specs`.
+    ///
+    /// Without a `resolver` only the base feature can be satisfied.
     #[must_use]
-    pub fn from_specs(specs: &[OperationSpec]) -> Self {
+    pub fn from_specs(
+        specs: &[OperationSpec],
+        resolver: Option<Arc<dyn LicenseResolverGatewayClient>>,
+    ) -> Self {
         let requirements = DashMap::new();
 
         for spec in specs {
@@ -33,6 +40,7 @@ impl LicenseRequirementMap {
 
         Self {
             requirements: Arc::new(requirements),
+            resolver,
         }
     }
 
@@ -43,6 +51,10 @@ impl LicenseRequirementMap {
     }
 }
 
+fn forbidden(detail: String) -> Response {
+    Problem::new(StatusCode::FORBIDDEN, "Forbidden", detail).into_response()
+}
+
 pub async fn license_validation_middleware(
     map: LicenseRequirementMap,
     req: Request,
@@ -58,18 +70,49 @@ pub async fn license_validation_middleware(
         return next.run(req).await;
     };
 
-    // TODO: this is a stub implementation
-    // We need first to implement plugin and get its client from client_hub
-    // Plugin should provide an interface to get a list of global features (features that are not scoped to particular resource)
-    if required.iter().any(|r| r != BASE_FEATURE) {
-        return Problem::new(
-            StatusCode::FORBIDDEN,
-            "Forbidden",
-            format!(
-                "Endpoint requires unsupported license features '{required:?}'; only '{BASE_FEATURE}' is allowed",
-            ),
-        )
-        .into_response();
+    // The base feature is granted to everyone; only ask the provider about the rest
+    if required.iter().all(|r| r == BASE_FEATURE) {
+        return next.run(req).await;
+    }
+
+    let Some(resolver) = map.resolver.as_ref() else {
+        return forbidden(format!(
+            "Endpoint requires license features '{required:?}' but no license provider is configured; only '{BASE_FEATURE}' is allowed",
+        ));
+    };
+
+    let ctx = req
+        .extensions()
+        .get::<SecurityContext>()
+        .cloned()
+        .unwrap_or_else(SecurityContext::anonymous);
+
+    match resolver.get_features(&ctx).await {
+        Ok(features) => {
+            let missing = features.missing(&required);
+            if !missing.is_empty() {
+                return forbidden(format!("Tenant is not licensed for features '{missing:?}'"));
+            }
+        }
+        Err(
+            e @ (LicenseResolverError::LicenseExpired { .. }
+            | LicenseResolverError::InvalidLicense(_)
+            | LicenseResolverError::NoPluginAvailable),
+        ) => {
+            tracing::warn!(error = %e, "License check denied request");
+            return forbidden(format!(
+                "Endpoint requires license features '{required:?}': {e}"
+            ));
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "License provider unavailable");
+            return Problem::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Service Unavailable",
+                "License provider is unavailable",
+            )
+            .into_response();
+        }
     }
 
     next.run(req).await
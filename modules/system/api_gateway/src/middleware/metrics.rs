@@ -0,0 +1,115 @@
+use axum::extract::MatchedPath;
+use axum::http::{Method, Request, header};
+use axum::response::IntoResponse;
+use axum::routing::{MethodRouter, get};
+use axum::{body::Body, middleware::Next, response::Response};
+use modkit::telemetry::MetricsRegistry;
+use modkit::telemetry::metrics::{CONTENT_TYPE, Counter, DURATION_BUCKETS, Gauge, Histogram};
+use std::time::Instant;
+
+/// Route label for requests that did not match any route.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// HTTP server instruments, labelled by the matched route template (not the raw path)
+/// to keep cardinality bounded.
+#[derive(Clone)]
+pub struct HttpMetrics {
+    requests: Counter,
+    duration: Histogram,
+    active: Gauge,
+}
+
+impl HttpMetrics {
+    #[must_use]
+    pub fn new(registry: &MetricsRegistry) -> Self {
+        Self {
+            requests: registry.counter(
+                "http_server_requests_total",
+                "HTTP requests handled by method, route and status code",
+            ),
+            duration: registry.histogram(
+                "http_server_request_duration_seconds",
+                "HTTP request latency by method and route",
+                DURATION_BUCKETS,
+            ),
+            active: registry.gauge(
+                "http_server_active_requests",
+                "HTTP requests currently being handled",
+            ),
+        }
+    }
+}
+
+struct ActiveGuard<'a>(&'a Gauge);
+
+impl Drop for ActiveGuard<'_> {
+    fn drop(&mut self) {
+        self.0.dec(&[]);
+    }
+}
+
+fn method_label(method: &Method) -> &'static str {
+    match *method {
+        Method::GET => "GET",
+        Method::POST => "POST",
+        Method::PUT => "PUT",
+        Method::PATCH => "PATCH",
+        Method::DELETE => "DELETE",
+        Method::HEAD => "HEAD",
+        Method::OPTIONS => "OPTIONS",
+        Method::TRACE => "TRACE",
+        Method::CONNECT => "CONNECT",
+        _ => "OTHER",
+    }
+}
+
+/// Middleware recording request count, latency and in-flight requests
+pub async fn http_metrics_middleware(
+    metrics: HttpMetrics,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let method = method_label(req.method());
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| UNMATCHED_ROUTE.to_owned(), |p| p.as_str().to_owned());
+    let started = Instant::now();
+
+    metrics.active.inc(&[]);
+    let response = {
+        // Decrements on drop, so requests cancelled mid-flight are not leaked
+        let _active = ActiveGuard(&metrics.active);
+        next.run(req).await
+    };
+
+    let status = response.status();
+    metrics.requests.inc(&[
+        ("method", method),
+        ("route", route.as_str()),
+        ("status", status.as_str()),
+    ]);
+    metrics.duration.observe(
+        &[("method", method), ("route", route.as_str())],
+        started.elapsed().as_secs_f64(),
+    );
+
+    response
+}
+
+/// `GET` route serving the registry in the `OpenMetrics` text format
+pub fn metrics_route(registry: MetricsRegistry) -> MethodRouter {
+    get(move || {
+        let registry = registry.clone();
+        async move {
+            (
+                [
+                    (header::CONTENT_TYPE, CONTENT_TYPE),
+                    (header::CACHE_CONTROL, "no-store"),
+                ],
+                registry.encode(),
+            )
+                .into_response()
+        }
+    })
+}
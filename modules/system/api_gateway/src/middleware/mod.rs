@@ -1,4 +1,6 @@
+pub mod client_cert;
 pub mod license_validation;
+pub mod metrics;
 pub mod mime_validation;
 pub mod rate_limit;
 pub mod request_id;
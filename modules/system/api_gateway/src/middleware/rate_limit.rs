@@ -1,6 +1,7 @@
-use crate::config::ApiGatewayConfig;
+use crate::config::{ApiGatewayConfig, RateLimitKey, TenantRateLimit};
 use anyhow::{Context, Result, anyhow};
-use axum::http::{HeaderValue, Method, StatusCode, header};
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header};
 use axum::{
     extract::Request,
     middleware::Next,
@@ -9,19 +10,37 @@ use axum::{
 use governor::clock::Clock;
 use governor::middleware::StateInformationMiddleware;
 use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use hashlink::LruCache;
+use modkit_security::SecurityContext;
+use parking_lot::Mutex;
 use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use tokio::sync::Semaphore;
+use uuid::Uuid;
 
-type RateLimitKey = (Method, String);
-type BucketMap = Arc<HashMap<RateLimitKey, Arc<BucketMapEntry>>>;
-type InflightMap = Arc<HashMap<RateLimitKey, Arc<Semaphore>>>;
+type RouteKey = (Method, String);
+type BucketMap = Arc<HashMap<RouteKey, Arc<RouteBuckets>>>;
+type InflightMap = Arc<HashMap<RouteKey, Arc<Semaphore>>>;
+
+/// Which side of authentication a limiter runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitStage {
+    /// In front of auth: buckets keyed by peer IP (or the route alone) so unauthenticated
+    /// floods are capped before tokens are validated; also enforces in-flight limits
+    PreAuth,
+    /// Behind auth: buckets keyed by the validated principal, with tenant overrides
+    PostAuth,
+}
 
 #[derive(Default, Clone)]
 pub struct RateLimiterMap {
     buckets: BucketMap,
     inflight: InflightMap,
+    key: RateLimitKey,
+    api_key_header: Option<HeaderName>,
+    tenant_overrides: Arc<HashMap<Uuid, TenantRateLimit>>,
 }
 
 struct BucketMapEntry {
@@ -45,46 +64,208 @@ impl BucketMapEntry {
             burst: burst.into(),
         })
     }
+
+    fn write_headers(&self, headers: &mut HeaderMap, remaining: u32) {
+        headers.insert("RateLimit-Policy", self.policy.clone());
+        headers.insert("RateLimit-Limit", self.burst.clone());
+        headers.insert("RateLimit-Remaining", remaining.into());
+        headers.insert("X-RateLimit-Limit", self.burst.clone());
+        headers.insert("X-RateLimit-Remaining", remaining.into());
+    }
+}
+
+/// Caller a bucket belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ClientId {
+    Subject(Uuid),
+    Tenant(Uuid),
+    ApiKey(Uuid),
+    Ip(IpAddr),
+}
+
+/// Buckets of a single route: one shared bucket plus a bounded LRU of per-client buckets
+struct RouteBuckets {
+    rps: u32,
+    burst: u32,
+    shared: Arc<BucketMapEntry>,
+    clients: Mutex<LruCache<ClientId, Arc<BucketMapEntry>>>,
+}
+
+impl RouteBuckets {
+    fn new(rps: u32, burst: u32, max_clients: usize) -> Result<Self> {
+        Ok(Self {
+            rps,
+            burst,
+            shared: Arc::new(BucketMapEntry::new(rps, burst)?),
+            clients: Mutex::new(LruCache::new(max_clients.max(1))),
+        })
+    }
+
+    /// Bucket for `client`, created on first use with the tenant override (if any) or the
+    /// route quota. Requests without a client identity share the route bucket.
+    fn bucket(
+        &self,
+        client: Option<ClientId>,
+        quota: Option<TenantRateLimit>,
+    ) -> Arc<BucketMapEntry> {
+        let Some(client) = client else {
+            return self.shared.clone();
+        };
+
+        let mut clients = self.clients.lock();
+        if let Some(entry) = clients.get(&client) {
+            return entry.clone();
+        }
+        let (rps, burst) = quota.map_or((self.rps, self.burst), |q| (q.rps, q.burst));
+        // Quotas are validated when the map is built
+        let Ok(entry) = BucketMapEntry::new(rps, burst) else {
+            return self.shared.clone();
+        };
+        let entry = Arc::new(entry);
+        clients.insert(client, entry.clone());
+        entry
+    }
 }
 
 impl RateLimiterMap {
+    /// Build the limiter for `stage`, or `None` when that stage has nothing to enforce.
+    ///
+    /// The pre-auth stage always exists. Unless the key is `route` it keeps one bucket per
+    /// peer IP, sized to the most generous quota a caller could be granted after auth so
+    /// tenant overrides stay reachable. The post-auth stage only exists when buckets are
+    /// keyed by the caller's identity or tenant overrides are configured.
+    ///
     /// # Errors
-    /// Returns an error if any rate limit spec is 0.
+    /// Returns an error if any rate limit spec or tenant override is 0, or the API key
+    /// header name is invalid.
     pub fn from_specs(
         specs: &Vec<modkit::api::OperationSpec>,
         cfg: &ApiGatewayConfig,
-    ) -> Result<Self> {
+        stage: RateLimitStage,
+    ) -> Result<Option<Self>> {
+        let defaults = &cfg.defaults.rate_limit;
+        for (tenant, quota) in &defaults.tenant_overrides {
+            BucketMapEntry::new(quota.rps, quota.burst)
+                .with_context(|| anyhow!("Rate limit override for tenant {tenant} invalid"))?;
+        }
+        let api_key_header = HeaderName::try_from(defaults.api_key_header.as_str())
+            .with_context(|| anyhow!("Invalid api_key_header '{}'", defaults.api_key_header))?;
+
+        let (key, tenant_overrides, ceiling) = match stage {
+            RateLimitStage::PreAuth => {
+                let key = match defaults.key {
+                    RateLimitKey::Route => RateLimitKey::Route,
+                    _ => RateLimitKey::ClientIp,
+                };
+                let ceiling = defaults.tenant_overrides.values().fold(
+                    None,
+                    |acc: Option<TenantRateLimit>, q| {
+                        Some(acc.map_or(*q, |acc| TenantRateLimit {
+                            rps: acc.rps.max(q.rps),
+                            burst: acc.burst.max(q.burst),
+                        }))
+                    },
+                );
+                (key, HashMap::new(), ceiling)
+            }
+            RateLimitStage::PostAuth => {
+                let identity_keyed = matches!(
+                    defaults.key,
+                    RateLimitKey::Subject | RateLimitKey::Tenant | RateLimitKey::ApiKey
+                );
+                if !identity_keyed && defaults.tenant_overrides.is_empty() {
+                    return Ok(None);
+                }
+                (defaults.key, defaults.tenant_overrides.clone(), None)
+            }
+        };
+
         let mut buckets = HashMap::new();
         let mut inflight = HashMap::new();
-        // TODO: Add support for per-route rate limiting
         for spec in specs {
-            let (rps, burst, max_in_flight) = spec.rate_limit.as_ref().map_or(
-                (
-                    cfg.defaults.rate_limit.rps,
-                    cfg.defaults.rate_limit.burst,
-                    cfg.defaults.rate_limit.in_flight,
-                ),
-                |r| (r.rps, r.burst, r.in_flight),
-            );
+            let (rps, burst, max_in_flight) = spec
+                .rate_limit
+                .as_ref()
+                .map_or((defaults.rps, defaults.burst, defaults.in_flight), |r| {
+                    (r.rps, r.burst, r.in_flight)
+                });
+            let (rps, burst) =
+                ceiling.map_or((rps, burst), |q| (rps.max(q.rps), burst.max(q.burst)));
             let key = (spec.method.clone(), spec.path.clone());
             buckets.insert(
                 key.clone(),
                 Arc::new(
-                    BucketMapEntry::new(rps, burst)
+                    RouteBuckets::new(rps, burst, defaults.max_clients)
                         .with_context(|| anyhow!("RateLimit spec invalid {spec:?} invalid"))?,
                 ),
             );
-            inflight.insert(key, Arc::new(Semaphore::new(max_in_flight as usize)));
+            if stage == RateLimitStage::PreAuth {
+                inflight.insert(key, Arc::new(Semaphore::new(max_in_flight as usize)));
+            }
         }
-        Ok(Self {
+        Ok(Some(Self {
             buckets: Arc::new(buckets),
             inflight: Arc::new(inflight),
-        })
+            key,
+            api_key_header: Some(api_key_header),
+            tenant_overrides: Arc::new(tenant_overrides),
+        }))
     }
+
+    /// Resolve the caller identity and tenant override for `req`.
+    ///
+    /// Anonymous callers (nil subject/tenant) are keyed by their IP address instead. API key
+    /// buckets are keyed by the subject the request authenticated as, never by the raw
+    /// header value, which is unvalidated at this point and trivially rotated by a client.
+    fn client(&self, req: &Request) -> (Option<ClientId>, Option<TenantRateLimit>) {
+        let ctx = req
+            .extensions()
+            .get::<SecurityContext>()
+            .filter(|ctx| !ctx.tenant_id().is_nil());
+        let quota = ctx.and_then(|ctx| self.tenant_overrides.get(&ctx.tenant_id()).copied());
+        let ip = || {
+            req.extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| ClientId::Ip(addr.ip()))
+        };
+
+        let subject = || {
+            req.extensions()
+                .get::<SecurityContext>()
+                .map(SecurityContext::subject_id)
+                .filter(|id| !id.is_nil())
+        };
+
+        let client = match self.key {
+            RateLimitKey::Route => None,
+            RateLimitKey::Subject => subject().map(ClientId::Subject).or_else(ip),
+            RateLimitKey::Tenant => ctx.map(|ctx| ClientId::Tenant(ctx.tenant_id())).or_else(ip),
+            RateLimitKey::ApiKey => self
+                .api_key_header
+                .as_ref()
+                .filter(|name| req.headers().contains_key(*name))
+                .and_then(|_| subject())
+                .map(ClientId::ApiKey)
+                .or_else(ip),
+            RateLimitKey::ClientIp => ip(),
+        };
+
+        // A tenant with its own quota must not share the route bucket with everyone else
+        let client = match (client, quota, ctx) {
+            (None, Some(_), Some(ctx)) => Some(ClientId::Tenant(ctx.tenant_id())),
+            (client, _, _) => client,
+        };
+        (client, quota)
+    }
+}
+
+/// Whole seconds until `wait` has elapsed (rounded up)
+fn retry_after_secs(wait: std::time::Duration) -> u64 {
+    wait.as_secs() + u64::from(wait.subsec_nanos() > 0)
 }
 
 // TODO: Use tower-governor instead of own implementation (upd: https://github.com/benwis/tower-governor/issues/59 )
-pub async fn rate_limit_middleware(map: RateLimiterMap, mut req: Request, next: Next) -> Response {
+pub async fn rate_limit_middleware(map: RateLimiterMap, req: Request, next: Next) -> Response {
     let method = req.method().clone();
     // Use MatchedPath extension (set by Axum router) for accurate route matching
     let path = req
@@ -93,41 +274,113 @@ pub async fn rate_limit_middleware(map: RateLimiterMap, mut req: Request, next:
         .map_or_else(|| req.uri().path().to_owned(), |p| p.as_str().to_owned());
     let key = (method, path);
 
-    if let Some(bucker_map_entry) = map.buckets.get(&key) {
-        let headers = req.headers_mut();
-        headers.insert("RateLimit-Policy", bucker_map_entry.policy.clone());
-        match bucker_map_entry.bucket.check() {
-            Ok(state) => {
-                headers.insert("RateLimit-Limit", bucker_map_entry.burst.clone());
-                headers.insert(
-                    "RateLimit-Limit-Remaining",
-                    state.remaining_burst_capacity().into(),
-                );
-                headers.insert("X-RateLimit-Limit", bucker_map_entry.burst.clone());
-                headers.insert(
-                    "X-RateLimit-Remaining",
-                    state.remaining_burst_capacity().into(),
-                );
-            }
+    let mut granted = None;
+    if let Some(route) = map.buckets.get(&key) {
+        let (client, quota) = map.client(&req);
+        let entry = route.bucket(client, quota);
+        match entry.bucket.check() {
+            Ok(state) => granted = Some((entry, state.remaining_burst_capacity())),
             Err(not_until) => {
-                let wait = not_until.wait_time_from(bucker_map_entry.bucket.clock().now());
-                headers.insert(header::RETRY_AFTER, wait.as_secs().into());
-                return StatusCode::TOO_MANY_REQUESTS.into_response();
+                let wait = not_until.wait_time_from(entry.bucket.clock().now());
+                let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+                let headers = response.headers_mut();
+                entry.write_headers(headers, 0);
+                headers.insert(header::RETRY_AFTER, retry_after_secs(wait).into());
+                return response;
             }
         }
     }
 
-    if let Some(sem) = map.inflight.get(&key) {
+    let mut response = if let Some(sem) = map.inflight.get(&key) {
         match sem.clone().try_acquire_owned() {
-            Ok(_permit) => {
-                // Allow request; permit is dropped when response future completes
-                return next.run(req).await;
-            }
-            Err(_) => {
-                return StatusCode::SERVICE_UNAVAILABLE.into_response();
-            }
+            // Allow request; permit is dropped when response future completes
+            Ok(_permit) => next.run(req).await,
+            Err(_) => return StatusCode::SERVICE_UNAVAILABLE.into_response(),
         }
+    } else {
+        next.run(req).await
+    };
+
+    // An inner stage's (more specific) headers win over the outer ones
+    if let Some((entry, remaining)) = granted
+        && !response.headers().contains_key("RateLimit-Policy")
+    {
+        entry.write_headers(response.headers_mut(), remaining);
     }
+    response
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
 
-    next.run(req).await
+    #[test]
+    fn client_buckets_are_bounded_and_independent() {
+        let route = RouteBuckets::new(1, 1, 2).unwrap();
+        let a = ClientId::Subject(Uuid::new_v4());
+        let b = ClientId::Subject(Uuid::new_v4());
+
+        assert!(route.bucket(Some(a.clone()), None).bucket.check().is_ok());
+        assert!(route.bucket(Some(a.clone()), None).bucket.check().is_err());
+        // Another caller has its own quota
+        assert!(route.bucket(Some(b), None).bucket.check().is_ok());
+
+        // A third caller evicts the least recently used bucket
+        route.bucket(Some(ClientId::Ip(IpAddr::from([10, 0, 0, 1]))), None);
+        assert_eq!(route.clients.lock().len(), 2);
+        assert!(!route.clients.lock().contains_key(&a));
+    }
+
+    #[test]
+    fn tenant_override_replaces_route_quota() {
+        let route = RouteBuckets::new(1, 1, 10).unwrap();
+        let tenant = ClientId::Tenant(Uuid::new_v4());
+        let quota = TenantRateLimit { rps: 1, burst: 3 };
+
+        let entry = route.bucket(Some(tenant), Some(quota));
+        assert_eq!(entry.burst, HeaderValue::from(3u32));
+        for _ in 0..3 {
+            assert!(entry.bucket.check().is_ok());
+        }
+        assert!(entry.bucket.check().is_err());
+    }
+
+    #[test]
+    fn api_key_buckets_use_the_authenticated_subject() {
+        let map = RateLimiterMap {
+            key: RateLimitKey::ApiKey,
+            api_key_header: Some(HeaderName::from_static("x-api-key")),
+            ..RateLimiterMap::default()
+        };
+        let subject = Uuid::new_v4();
+        let request = |ctx: Option<SecurityContext>| {
+            let mut req = Request::builder()
+                .header("x-api-key", Uuid::new_v4().to_string())
+                .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 40000))))
+                .body(axum::body::Body::empty())
+                .unwrap();
+            if let Some(ctx) = ctx {
+                req.extensions_mut().insert(ctx);
+            }
+            req
+        };
+
+        // Unvalidated key: the client IP, however often the header value changes
+        let (client, _) = map.client(&request(None));
+        assert_eq!(client, Some(ClientId::Ip(IpAddr::from([10, 0, 0, 1]))));
+
+        let ctx = SecurityContext::builder()
+            .tenant_id(Uuid::new_v4())
+            .subject_id(subject)
+            .build();
+        let (client, _) = map.client(&request(Some(ctx)));
+        assert_eq!(client, Some(ClientId::ApiKey(subject)));
+    }
+
+    #[test]
+    fn retry_after_rounds_up() {
+        assert_eq!(retry_after_secs(std::time::Duration::from_millis(1)), 1);
+        assert_eq!(retry_after_secs(std::time::Duration::from_secs(2)), 2);
+    }
 }
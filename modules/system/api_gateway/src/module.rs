@@ -14,8 +14,10 @@ use axum::http::Method;
 use axum::middleware::from_fn_with_state;
 use axum::{Router, extract::DefaultBodyLimit, middleware::from_fn, routing::get};
 use modkit::api::{OpenApiRegistry, OpenApiRegistryImpl};
+use modkit::health::{HealthRegistry, Probe};
 use modkit::lifecycle::ReadySignal;
-use parking_lot::Mutex;
+use modkit::telemetry::MetricsRegistry;
+use parking_lot::{Mutex, RwLock};
 use std::net::SocketAddr;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -28,8 +30,9 @@ use tracing::debug;
 
 use crate::auth;
 use crate::config::ApiGatewayConfig;
+use license_resolver_sdk::LicenseResolverGatewayClient;
 use modkit_security::constants::{DEFAULT_SUBJECT_ID, DEFAULT_TENANT_ID};
-use modkit_security::{PolicyEngineRef, SecurityContext};
+use modkit_security::{PolicyEngine, PolicyEngineRef, RuleBasedPolicyEngine, SecurityContext};
 
 use crate::middleware;
 use crate::router_cache::RouterCache;
@@ -56,6 +59,18 @@ pub struct ApiGateway {
     // Duplicate detection (per (method, path) and per handler id)
     pub(crate) registered_routes: DashMap<(Method, String), ()>,
     pub(crate) registered_handlers: DashMap<String, ()>,
+
+    // Policy engine injected into requests (resolved from ClientHub during init)
+    pub(crate) policy_engine: RwLock<PolicyEngineRef>,
+
+    // License feature provider (resolved from ClientHub when the router is finalized)
+    pub(crate) license_resolver: RwLock<Option<Arc<dyn LicenseResolverGatewayClient>>>,
+
+    // Metrics registry served on /metrics (resolved from ClientHub during init)
+    pub(crate) metrics_registry: RwLock<MetricsRegistry>,
+
+    // Health checks served on /livez and /readyz (resolved from ClientHub during init)
+    pub(crate) health_registry: RwLock<HealthRegistry>,
 }
 
 impl Default for ApiGateway {
@@ -68,6 +83,10 @@ impl Default for ApiGateway {
             final_router: Mutex::new(None),
             registered_routes: DashMap::new(),
             registered_handlers: DashMap::new(),
+            policy_engine: RwLock::new(Arc::new(RuleBasedPolicyEngine::default())),
+            license_resolver: RwLock::new(None),
+            metrics_registry: RwLock::new(MetricsRegistry::global().clone()),
+            health_registry: RwLock::new(HealthRegistry::new()),
         }
     }
 }
@@ -84,6 +103,10 @@ impl ApiGateway {
             final_router: Mutex::new(None),
             registered_routes: DashMap::new(),
             registered_handlers: DashMap::new(),
+            policy_engine: RwLock::new(Arc::new(RuleBasedPolicyEngine::default())),
+            license_resolver: RwLock::new(None),
+            metrics_registry: RwLock::new(MetricsRegistry::global().clone()),
+            health_registry: RwLock::new(HealthRegistry::new()),
         }
     }
 
@@ -102,6 +125,41 @@ impl ApiGateway {
         self.router_cache.load()
     }
 
+    /// Pick up the license provider from `ClientHub`.
+    ///
+    /// Called when the router is finalized: every module is initialized by then,
+    /// so a provider is registered regardless of module init order.
+    fn resolve_license_provider(&self, ctx: &modkit::context::ModuleCtx) {
+        if let Ok(resolver) = ctx.client_hub().get::<dyn LicenseResolverGatewayClient>() {
+            *self.license_resolver.write() = Some(resolver);
+            debug!("Using license resolver from ClientHub");
+        }
+    }
+
+    /// Add the aggregated `/livez` and `/readyz` probes.
+    fn with_probe_endpoints(&self, router: Router) -> Router {
+        let live = self.health_registry.read().clone();
+        let ready = live.clone();
+        router
+            .route(
+                "/livez",
+                get(move || web::health_probe(live.clone(), Probe::Liveness)),
+            )
+            .route(
+                "/readyz",
+                get(move || web::health_probe(ready.clone(), Probe::Readiness)),
+            )
+    }
+
+    /// Add the `/metrics` endpoint unless metrics are disabled.
+    fn with_metrics_endpoint(&self, router: Router) -> Router {
+        if !self.get_cached_config().enable_metrics {
+            return router;
+        }
+        let registry = self.metrics_registry.read().clone();
+        router.route("/metrics", middleware::metrics::metrics_route(registry))
+    }
+
     /// Force rebuild and cache of the router.
     ///
     /// # Errors
@@ -120,8 +178,11 @@ impl ApiGateway {
         // Always mark built-in health check routes as public
         public_routes.insert((Method::GET, "/health".to_owned()));
         public_routes.insert((Method::GET, "/healthz".to_owned()));
+        public_routes.insert((Method::GET, "/livez".to_owned()));
+        public_routes.insert((Method::GET, "/readyz".to_owned()));
         public_routes.insert((Method::GET, "/docs".to_owned()));
         public_routes.insert((Method::GET, "/openapi.json".to_owned()));
+        public_routes.insert((Method::GET, "/metrics".to_owned()));
 
         for spec in &self.openapi_registry.operation_specs {
             let spec = spec.value();
@@ -146,7 +207,9 @@ impl ApiGateway {
         let requirements_count = req_map.len();
         let public_routes_count = public_routes.len();
 
-        let (auth_state, route_policy) = auth::build_auth_state(&config, req_map, public_routes)?;
+        let policy_engine = self.policy_engine.read().clone();
+        let (auth_state, route_policy) =
+            auth::build_auth_state(&config, req_map, public_routes, policy_engine)?;
 
         tracing::info!(
             auth_disabled = config.auth_disabled,
@@ -159,7 +222,7 @@ impl ApiGateway {
         Ok((auth_state, route_policy))
     }
 
-    /// Apply all middleware layers to a router (request ID, tracing, timeout, body limit, CORS, rate limiting, error mapping, auth)
+    /// Apply all middleware layers to a router (request ID, tracing, metrics, timeout, body limit, CORS, rate limiting, error mapping, auth)
     pub(crate) fn apply_middleware_stack(&self, mut router: Router) -> Result<Router> {
         // Build auth state and route policy once
         let (auth_state, route_policy) = self.build_auth_state_from_specs()?;
@@ -168,10 +231,12 @@ impl ApiGateway {
         // becomes the **outermost** layer and therefore runs **first** on the request path.
         //
         // Desired request execution order (outermost -> innermost):
-        // SetRequestId -> PropagateRequestId -> Trace -> push_req_id_to_extensions
-        // -> Timeout -> BodyLimit -> CORS -> MIME validation -> RateLimit -> ErrorMapping -> Auth -> Router
+        // SetRequestId -> PropagateRequestId -> Trace -> HttpMetrics -> push_req_id_to_extensions
+        // -> Timeout -> BodyLimit -> CORS -> MIME validation -> RateLimit (client) -> ErrorMapping -> Auth
+        // -> RateLimit (principal) -> Router
         //
         // Therefore we must add layers in the reverse order (innermost -> outermost) below.
+        // The steps are numbered in that order, starting from the innermost layer.
         // Due future refactoring, this order must be maintained.
 
         let config = self.get_cached_config();
@@ -184,8 +249,17 @@ impl ApiGateway {
             .map(|e| e.value().clone())
             .collect();
 
-        // 12) License validation
-        let license_map = middleware::license_validation::LicenseRequirementMap::from_specs(&specs);
+        // 1) OData cursor signing keys (read by the OData extractor and pagination helpers)
+        if let Some(cursor_signing) = config.cursor_signing.as_ref() {
+            let limits = Arc::new(cursor_signing.to_limits()?);
+            router = router.layer(axum::Extension(limits));
+        }
+
+        // 2) License validation
+        let license_map = middleware::license_validation::LicenseRequirementMap::from_specs(
+            &specs,
+            self.license_resolver.read().clone(),
+        );
         router = router.layer(from_fn(
             move |req: axum::extract::Request, next: axum::middleware::Next| {
                 let map = license_map.clone();
@@ -193,7 +267,7 @@ impl ApiGateway {
             },
         ));
 
-        // 11) Inject Policy Engine
+        // 3) Inject Policy Engine
         router = router.layer(from_fn_with_state(
             auth_state.policy_engine,
             |State(engine): State<PolicyEngineRef>,
@@ -204,7 +278,22 @@ impl ApiGateway {
             },
         ));
 
-        // 10) Auth
+        // 4) Per-subject/tenant rate limiting (inner to auth so buckets can be keyed by the
+        // caller's SecurityContext; an extra layer on top of the client limits in 7)
+        if let Some(rate_map) = middleware::rate_limit::RateLimiterMap::from_specs(
+            &specs,
+            &config,
+            middleware::rate_limit::RateLimitStage::PostAuth,
+        )? {
+            router = router.layer(from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = rate_map.clone();
+                    middleware::rate_limit::rate_limit_middleware(map, req, next)
+                },
+            ));
+        }
+
+        // 5) Auth
         if config.auth_disabled {
             // Build security contexts for compatibility during migration
             let default_security_context = SecurityContext::builder()
@@ -235,21 +324,43 @@ impl ApiGateway {
             router = router.layer(modkit_auth::axum_ext::AuthPolicyLayer::new(
                 validator, authorizer, policy,
             ));
+
+            // mTLS client identities (outer to auth so it can use them when no token is sent)
+            if let Some(tls) = config
+                .tls
+                .as_ref()
+                .filter(|t| !t.client_identities.is_empty())
+            {
+                let identities =
+                    middleware::client_cert::ClientCertIdentityMap::new(&tls.client_identities);
+                router = router.layer(from_fn(
+                    move |req: axum::extract::Request, next: axum::middleware::Next| {
+                        let map = identities.clone();
+                        middleware::client_cert::client_cert_identity_middleware(map, req, next)
+                    },
+                ));
+            }
         }
 
-        // 9) Error mapping (outer to auth so it can translate auth/handler errors)
+        // 6) Error mapping (outer to auth so it can translate auth/handler errors)
         router = router.layer(from_fn(modkit::api::error_layer::error_mapping_middleware));
 
-        // 8) Per-route rate limiting & in-flight limits
-        let rate_map = middleware::rate_limit::RateLimiterMap::from_specs(&specs, &config)?;
-        router = router.layer(from_fn(
-            move |req: axum::extract::Request, next: axum::middleware::Next| {
-                let map = rate_map.clone();
-                middleware::rate_limit::rate_limit_middleware(map, req, next)
-            },
-        ));
+        // 7) Per-route and per-client-IP rate limiting & in-flight limits (outer to auth so
+        // unauthenticated floods are rejected before any token is validated)
+        if let Some(rate_map) = middleware::rate_limit::RateLimiterMap::from_specs(
+            &specs,
+            &config,
+            middleware::rate_limit::RateLimitStage::PreAuth,
+        )? {
+            router = router.layer(from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = rate_map.clone();
+                    middleware::rate_limit::rate_limit_middleware(map, req, next)
+                },
+            ));
+        }
 
-        // 7) MIME type validation
+        // 8) MIME type validation
         let mime_map = middleware::mime_validation::build_mime_validation_map(&specs);
         router = router.layer(from_fn(
             move |req: axum::extract::Request, next: axum::middleware::Next| {
@@ -258,25 +369,36 @@ impl ApiGateway {
             },
         ));
 
-        // 6) CORS (must be outer to auth/limits so OPTIONS preflight short-circuits)
+        // 9) CORS (must be outer to auth/limits so OPTIONS preflight short-circuits)
         if config.cors_enabled {
             router = router.layer(crate::cors::build_cors_layer(&config));
         }
 
-        // 5) Body limit
+        // 10) Body limit
         router = router.layer(RequestBodyLimitLayer::new(config.defaults.body_limit_bytes));
         router = router.layer(DefaultBodyLimit::max(config.defaults.body_limit_bytes));
 
-        // 4) Timeout
+        // 11) Timeout
         router = router.layer(TimeoutLayer::with_status_code(
             axum::http::StatusCode::GATEWAY_TIMEOUT,
             Duration::from_secs(30),
         ));
 
-        // 3) Record request_id into span + extensions (requires span to exist first => must be inner to Trace)
+        // 12) Record request_id into span + extensions (requires span to exist first => must be inner to Trace)
         router = router.layer(from_fn(middleware::request_id::push_req_id_to_extensions));
 
-        // 2) Trace (outer to push_req_id_to_extensions)
+        // 13) HTTP metrics (inner to Trace, outer to everything that can reject a request)
+        if config.enable_metrics {
+            let metrics = middleware::metrics::HttpMetrics::new(&self.metrics_registry.read());
+            router = router.layer(from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let metrics = metrics.clone();
+                    middleware::metrics::http_metrics_middleware(metrics, req, next)
+                },
+            ));
+        }
+
+        // 14) Trace (outer to push_req_id_to_extensions)
         router = router.layer({
             use modkit::http::otel;
             use tower_http::trace::TraceLayer;
@@ -333,7 +455,7 @@ impl ApiGateway {
                 )
         });
 
-        // 1) Request ID handling
+        // 15) Request ID handling
         let x_request_id = crate::middleware::request_id::header();
         // If missing, generate x-request-id first; then propagate it to the response.
         router = router.layer(PropagateRequestIdLayer::new(x_request_id.clone()));
@@ -364,6 +486,8 @@ impl ApiGateway {
         let mut router = Router::new()
             .route("/health", get(web::health_check))
             .route("/healthz", get(|| async { "ok" }));
+        router = self.with_probe_endpoints(router);
+        router = self.with_metrics_endpoint(router);
 
         // Apply all middleware layers including auth, above the router
         router = self.apply_middleware_stack(router)?;
@@ -421,8 +545,23 @@ impl ApiGateway {
         let addr = Self::parse_bind_address(&cfg.bind_addr)?;
         let router = self.get_or_build_router()?;
 
+        // Load certificates before binding so a bad TLS config fails startup
+        let tls = cfg
+            .tls
+            .clone()
+            .map(crate::tls::TlsReloader::new)
+            .transpose()?
+            .map(Arc::new);
+
         // Bind the socket, only now consider the service "ready"
         let listener = tokio::net::TcpListener::bind(addr).await?;
+        if let Some(tls) = tls {
+            tracing::info!("HTTPS server bound on {}", addr);
+            ready.notify(); // Starting -> Running
+            crate::tls::serve(listener, router, tls, cancel).await;
+            tracing::info!("HTTPS server shut down");
+            return Ok(());
+        }
         tracing::info!("HTTP server bound on {}", addr);
         ready.notify(); // Starting -> Running
 
@@ -435,10 +574,14 @@ impl ApiGateway {
             }
         };
 
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await
-            .map_err(|e| anyhow::anyhow!(e))
+        // Connection info lets rate limiting key buckets by client IP
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
     }
 }
 
@@ -462,6 +605,22 @@ impl modkit::Module for ApiGateway {
             );
         }
 
+        // Use the host-provided policy engine when one is registered
+        if let Ok(engine) = ctx.client_hub().get::<dyn PolicyEngine>() {
+            *self.policy_engine.write() = engine;
+            debug!("Using policy engine from ClientHub");
+        }
+
+        if let Ok(registry) = ctx.client_hub().get::<MetricsRegistry>() {
+            *self.metrics_registry.write() = (*registry).clone();
+            debug!("Using metrics registry from ClientHub");
+        }
+
+        if let Ok(registry) = ctx.client_hub().get::<HealthRegistry>() {
+            *self.health_registry.write() = (*registry).clone();
+            debug!("Using health registry from ClientHub");
+        }
+
         Ok(())
     }
 }
@@ -476,9 +635,12 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
         // Add health check endpoints:
         // - /health: detailed JSON response with status and timestamp
         // - /healthz: simple "ok" liveness probe (Kubernetes-style)
+        // - /livez, /readyz: probes aggregated from the HealthRegistry, 503 when down
         let router = router
             .route("/health", get(web::health_check))
             .route("/healthz", get(|| async { "ok" }));
+        let router = self.with_probe_endpoints(router);
+        let router = self.with_metrics_endpoint(router);
 
         // You may attach global middlewares here (trace, compression, cors), but do not start server.
         tracing::debug!("REST host prepared base router with health check endpoints");
@@ -487,10 +649,11 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
 
     fn rest_finalize(
         &self,
-        _ctx: &modkit::context::ModuleCtx,
+        ctx: &modkit::context::ModuleCtx,
         mut router: axum::Router,
     ) -> anyhow::Result<axum::Router> {
         let config = self.get_cached_config();
+        self.resolve_license_provider(ctx);
 
         if config.enable_docs {
             // Build once, serve as static JSON (no per-request parsing)
@@ -544,13 +707,16 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
 impl modkit::contracts::RestApiCapability for ApiGateway {
     fn register_rest(
         &self,
-        _ctx: &modkit::context::ModuleCtx,
+        ctx: &modkit::context::ModuleCtx,
         router: axum::Router,
-        _openapi: &dyn modkit::contracts::OpenApiRegistry,
+        openapi: &dyn modkit::contracts::OpenApiRegistry,
     ) -> anyhow::Result<axum::Router> {
-        // This module acts as both rest_host and rest, but actual REST endpoints
-        // are handled in the host methods above.
-        Ok(router)
+        // This module acts as both rest_host and rest; besides the built-in endpoints
+        // handled in the host methods above, it exposes the jobs of the process.
+        match ctx.client_hub().get::<modkit::jobs::JobRegistry>() {
+            Ok(jobs) => Ok(crate::jobs::register_routes(router, openapi, jobs)),
+            Err(_) => Ok(router),
+        }
     }
 }
 
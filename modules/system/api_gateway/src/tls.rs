@@ -0,0 +1,451 @@
+//! HTTPS termination for the gateway server: certificate loading, hot reload and the
+//! accept loop serving HTTP/1.1 and HTTP/2 (negotiated via ALPN).
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use arc_swap::ArcSwap;
+use axum::Router;
+use axum::extract::ConnectInfo;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use hyper_util::server::graceful::GracefulShutdown;
+use hyper_util::service::TowerToHyperService;
+use parking_lot::Mutex;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use sha2::{Digest, Sha256};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::sync::CancellationToken;
+use tower::ServiceExt;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::FromDer;
+
+use crate::config::{TlsConfig, TlsVersion};
+
+/// Clients that don't finish the handshake in time are dropped
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Verified client certificate of an mTLS connection, available as a request extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// Subject common name (CN), if the certificate has one
+    pub common_name: Option<String>,
+    /// DNS names and URIs of the subject alternative name extension
+    pub subject_alt_names: Vec<String>,
+    /// Hex-encoded SHA-256 of the DER certificate
+    pub fingerprint_sha256: String,
+}
+
+impl ClientCertificate {
+    fn from_der(der: &CertificateDer<'_>) -> Self {
+        let parsed = X509Certificate::from_der(der.as_ref())
+            .ok()
+            .map(|(_, cert)| cert);
+        Self {
+            common_name: parsed.as_ref().and_then(subject_common_name),
+            subject_alt_names: parsed.as_ref().map(subject_alt_names).unwrap_or_default(),
+            fingerprint_sha256: hex::encode(Sha256::digest(der.as_ref())),
+        }
+    }
+}
+
+/// Build the rustls server configuration from the files named in `cfg`
+///
+/// # Errors
+/// Returns an error if a file cannot be read or parsed, or the key does not match the
+/// certificate.
+pub fn build_server_config(cfg: &TlsConfig) -> Result<ServerConfig> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let certs = CertificateDer::pem_file_iter(&cfg.cert_path)
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .with_context(|| format!("Failed to read TLS certificate {}", cfg.cert_path.display()))?;
+    let key = PrivateKeyDer::from_pem_file(&cfg.key_path)
+        .with_context(|| format!("Failed to read TLS key {}", cfg.key_path.display()))?;
+
+    let versions: &[&rustls::SupportedProtocolVersion] = match cfg.min_version {
+        TlsVersion::Tls12 => &[&rustls::version::TLS13, &rustls::version::TLS12],
+        TlsVersion::Tls13 => &[&rustls::version::TLS13],
+    };
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(versions)
+        .context("Unsupported TLS protocol versions")?;
+
+    let builder = if let Some(ca_path) = cfg.client_ca_path.as_ref() {
+        let mut roots = RootCertStore::empty();
+        for ca in CertificateDer::pem_file_iter(ca_path)
+            .with_context(|| format!("Failed to read client CA {}", ca_path.display()))?
+        {
+            roots
+                .add(ca.with_context(|| format!("Invalid client CA {}", ca_path.display()))?)
+                .with_context(|| format!("Invalid client CA {}", ca_path.display()))?;
+        }
+        let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+        let verifier = if cfg.require_client_cert {
+            verifier
+        } else {
+            verifier.allow_unauthenticated()
+        };
+        builder.with_client_cert_verifier(verifier.build().context("Invalid client CA")?)
+    } else {
+        builder.with_no_client_auth()
+    };
+
+    let mut config = builder
+        .with_single_cert(certs, key)
+        .context("TLS certificate and key do not match")?;
+    config.alpn_protocols = cfg.alpn.iter().map(|p| p.as_bytes().to_vec()).collect();
+    Ok(config)
+}
+
+/// Current server configuration, rebuilt when the certificate files change
+pub struct TlsReloader {
+    cfg: TlsConfig,
+    current: ArcSwap<ServerConfig>,
+    modified: Mutex<Vec<Option<SystemTime>>>,
+}
+
+impl TlsReloader {
+    /// # Errors
+    /// Returns an error if the initial configuration cannot be built.
+    pub(crate) fn new(cfg: TlsConfig) -> Result<Self> {
+        let current = ArcSwap::from_pointee(build_server_config(&cfg)?);
+        let modified = Mutex::new(Self::modified_times(&cfg));
+        Ok(Self {
+            cfg,
+            current,
+            modified,
+        })
+    }
+
+    fn modified_times(cfg: &TlsConfig) -> Vec<Option<SystemTime>> {
+        [
+            Some(&cfg.cert_path),
+            Some(&cfg.key_path),
+            cfg.client_ca_path.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|path| {
+            std::fs::metadata(Path::new(path))
+                .and_then(|m| m.modified())
+                .ok()
+        })
+        .collect()
+    }
+
+    fn acceptor(&self) -> TlsAcceptor {
+        TlsAcceptor::from(self.current.load_full())
+    }
+
+    /// Rebuild the configuration if any file changed since the last (attempted) load.
+    ///
+    /// On error the previous configuration stays in use.
+    ///
+    /// # Errors
+    /// Returns an error if the changed files cannot be loaded.
+    pub(crate) fn reload_if_changed(&self) -> Result<bool> {
+        let modified = Self::modified_times(&self.cfg);
+        {
+            let mut last = self.modified.lock();
+            if *last == modified {
+                return Ok(false);
+            }
+            *last = modified;
+        }
+        self.current
+            .store(Arc::new(build_server_config(&self.cfg)?));
+        Ok(true)
+    }
+
+    /// Poll the files every `reload_interval_secs` until cancelled
+    async fn watch(self: Arc<Self>, cancel: CancellationToken) {
+        if self.cfg.reload_interval_secs == 0 {
+            return;
+        }
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.cfg.reload_interval_secs));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                _ = interval.tick() => {}
+            }
+            match self.reload_if_changed() {
+                Ok(true) => tracing::info!("TLS certificates reloaded"),
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(error = %e, "TLS certificate reload failed; keeping previous certificates");
+                }
+            }
+        }
+    }
+}
+
+/// Serve `router` over TLS until `cancel` fires, then drain open connections.
+///
+/// Each request carries `ConnectInfo<SocketAddr>` and, for mTLS connections, the
+/// verified [`ClientCertificate`].
+pub async fn serve(
+    listener: TcpListener,
+    router: Router,
+    tls: Arc<TlsReloader>,
+    cancel: CancellationToken,
+) {
+    tokio::spawn(tls.clone().watch(cancel.clone()));
+
+    let builder = auto::Builder::new(TokioExecutor::new());
+    let graceful = GracefulShutdown::new();
+
+    loop {
+        let (tcp, peer) = tokio::select! {
+            () = cancel.cancelled() => break,
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to accept connection");
+                    continue;
+                }
+            },
+        };
+
+        let acceptor = tls.acceptor();
+        let builder = builder.clone();
+        let router = router.clone();
+        let watcher = graceful.watcher();
+        tokio::spawn(async move {
+            let stream = match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(tcp)).await {
+                Ok(Ok(stream)) => stream,
+                Ok(Err(e)) => {
+                    tracing::debug!(%peer, error = %e, "TLS handshake failed");
+                    return;
+                }
+                Err(_) => {
+                    tracing::debug!(%peer, "TLS handshake timed out");
+                    return;
+                }
+            };
+            let client_cert = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(<[_]>::first)
+                .map(ClientCertificate::from_der);
+
+            let service = tower::service_fn(move |mut req: axum::extract::Request<_>| {
+                req.extensions_mut().insert(ConnectInfo(peer));
+                if let Some(cert) = client_cert.clone() {
+                    req.extensions_mut().insert(cert);
+                }
+                router.clone().oneshot(req)
+            });
+            let conn = builder
+                .serve_connection_with_upgrades(
+                    TokioIo::new(stream),
+                    TowerToHyperService::new(service),
+                )
+                .into_owned();
+            if let Err(e) = watcher.watch(conn).await {
+                tracing::debug!(%peer, error = %e, "Connection closed with error");
+            }
+        });
+    }
+
+    graceful.shutdown().await;
+}
+
+/// Subject common name of an X.509 certificate
+fn subject_common_name(cert: &X509Certificate<'_>) -> Option<String> {
+    cert.subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+/// DNS names and URIs of the subject alternative name extension
+fn subject_alt_names(cert: &X509Certificate<'_>) -> Vec<String> {
+    let Ok(Some(san)) = cert.subject_alternative_name() else {
+        return Vec::new();
+    };
+    san.value
+        .general_names
+        .iter()
+        .filter_map(|name| match name {
+            GeneralName::DNSName(name) | GeneralName::URI(name) => Some((*name).to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use std::net::SocketAddr;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/tls")
+            .join(name)
+    }
+
+    fn tls_config(client_ca: bool) -> TlsConfig {
+        TlsConfig {
+            cert_path: fixture("server.pem"),
+            key_path: fixture("server.key"),
+            client_ca_path: client_ca.then(|| fixture("ca.pem")),
+            require_client_cert: true,
+            min_version: TlsVersion::Tls12,
+            alpn: vec!["h2".to_owned(), "http/1.1".to_owned()],
+            reload_interval_secs: 0,
+            client_identities: Vec::new(),
+        }
+    }
+
+    async fn start(cfg: TlsConfig) -> (SocketAddr, CancellationToken) {
+        let router = Router::new().route(
+            "/whoami",
+            get(|req: axum::extract::Request| async move {
+                req.extensions()
+                    .get::<ClientCertificate>()
+                    .and_then(|c| c.common_name.clone())
+                    .unwrap_or_default()
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let cancel = CancellationToken::new();
+        let tls = Arc::new(TlsReloader::new(cfg).unwrap());
+        tokio::spawn(serve(listener, router, tls, cancel.clone()));
+        (addr, cancel)
+    }
+
+    fn client(addr: SocketAddr, identity: bool) -> reqwest::Client {
+        let ca = std::fs::read(fixture("ca.pem")).unwrap();
+        let mut builder = reqwest::Client::builder()
+            .resolve("localhost", addr)
+            .use_rustls_tls()
+            .tls_built_in_root_certs(false)
+            .add_root_certificate(reqwest::Certificate::from_pem(&ca).unwrap());
+        if identity {
+            let mut pem = std::fs::read(fixture("client.pem")).unwrap();
+            pem.extend(std::fs::read(fixture("client.key")).unwrap());
+            builder = builder.identity(reqwest::Identity::from_pem(&pem).unwrap());
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn reads_subject_common_name() {
+        let pem = std::fs::read(fixture("client.pem")).unwrap();
+        let cert = CertificateDer::from_pem_slice(&pem).unwrap();
+        let cert = ClientCertificate::from_der(&cert);
+        assert_eq!(cert.common_name.as_deref(), Some("billing-service"));
+        assert!(cert.subject_alt_names.is_empty());
+        assert_eq!(cert.fingerprint_sha256.len(), 64);
+    }
+
+    #[test]
+    fn reads_subject_alt_names() {
+        let pem = std::fs::read(fixture("server.pem")).unwrap();
+        let cert = CertificateDer::from_pem_slice(&pem).unwrap();
+        let cert = ClientCertificate::from_der(&cert);
+        assert_eq!(cert.common_name.as_deref(), Some("localhost"));
+        assert_eq!(cert.subject_alt_names, vec!["localhost".to_owned()]);
+    }
+
+    #[test]
+    fn rejects_mismatched_key() {
+        let mut cfg = tls_config(false);
+        cfg.key_path = fixture("client.key");
+        assert!(build_server_config(&cfg).is_err());
+    }
+
+    #[test]
+    fn reloads_changed_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cfg = tls_config(false);
+        for (name, path) in [
+            ("server.pem", &mut cfg.cert_path),
+            ("server.key", &mut cfg.key_path),
+        ] {
+            let dest = dir.path().join(name);
+            std::fs::copy(fixture(name), &dest).unwrap();
+            *path = dest;
+        }
+        let reloader = TlsReloader::new(cfg.clone()).unwrap();
+        assert!(!reloader.reload_if_changed().unwrap());
+
+        let before = Arc::as_ptr(&reloader.current.load_full());
+        for (src, dest) in [
+            ("server2.pem", &cfg.cert_path),
+            ("server2.key", &cfg.key_path),
+        ] {
+            std::fs::copy(fixture(src), dest).unwrap();
+            let file = std::fs::File::options().write(true).open(dest).unwrap();
+            file.set_modified(SystemTime::now() + Duration::from_secs(5))
+                .unwrap();
+        }
+        assert!(reloader.reload_if_changed().unwrap());
+        assert_ne!(before, Arc::as_ptr(&reloader.current.load_full()));
+
+        // A broken file keeps the previous configuration
+        std::fs::write(&cfg.key_path, "garbage").unwrap();
+        let file = std::fs::File::options()
+            .write(true)
+            .open(&cfg.key_path)
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        let current = Arc::as_ptr(&reloader.current.load_full());
+        assert!(reloader.reload_if_changed().is_err());
+        assert_eq!(current, Arc::as_ptr(&reloader.current.load_full()));
+    }
+
+    #[tokio::test]
+    async fn serves_http2_over_tls() {
+        let (addr, cancel) = start(tls_config(false)).await;
+
+        let response = client(addr, false)
+            .get(format!("https://localhost:{}/whoami", addr.port()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.version(), reqwest::Version::HTTP_2);
+
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn exposes_verified_client_certificate() {
+        let (addr, cancel) = start(tls_config(true)).await;
+        let url = format!("https://localhost:{}/whoami", addr.port());
+
+        let body = client(addr, true)
+            .get(&url)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "billing-service");
+
+        // Client certificate is required
+        let anonymous = client(addr, false).get(&url).send().await;
+        assert!(anonymous.is_err());
+
+        cancel.cancel();
+    }
+}
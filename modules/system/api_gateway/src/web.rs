@@ -1,9 +1,10 @@
 use axum::{
-    http::StatusCode,
-    response::{Html, Json},
+    http::{StatusCode, header},
+    response::{Html, IntoResponse, Json, Response},
     routing::{MethodRouter, get},
 };
 use chrono::{SecondsFormat, Utc};
+use modkit::health::{HealthRegistry, Probe};
 use serde_json::{Value, json};
 
 /// Returns a 501 Not Implemented handler for operations without implementations
@@ -27,6 +28,17 @@ pub async fn health_check() -> Json<Value> {
     }))
 }
 
+/// Run a health probe and answer 200 with the report, or 503 if the process is down
+pub async fn health_probe(registry: HealthRegistry, probe: Probe) -> Response {
+    let report = registry.check(probe).await;
+    let status = if report.is_available() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, [(header::CACHE_CONTROL, "no-store")], Json(report)).into_response()
+}
+
 #[cfg(not(feature = "embed_elements"))]
 pub async fn serve_docs() -> Html<&'static str> {
     // External mode: load from CDN @latest
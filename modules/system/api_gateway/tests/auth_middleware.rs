@@ -55,6 +55,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
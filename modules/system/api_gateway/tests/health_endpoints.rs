@@ -0,0 +1,121 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for the aggregated `/livez` and `/readyz` probes
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use modkit::health::{HealthCheck, HealthRegistry, Probe};
+use modkit::{Module, ModuleCtx, config::ConfigProvider, contracts::ApiGatewayCapability};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tower::util::ServiceExt;
+use uuid::Uuid;
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        if module == "api_gateway" {
+            Some(&self.config)
+        } else {
+            None
+        }
+    }
+}
+
+async fn finalized_router(registry: HealthRegistry) -> Router {
+    let hub = Arc::new(modkit::ClientHub::new());
+    hub.register::<HealthRegistry>(Arc::new(registry));
+    let ctx = ModuleCtx::new(
+        "api_gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider {
+            config: serde_json::json!({
+                "config": { "bind_addr": "127.0.0.1:0", "auth_disabled": true }
+            }),
+        }),
+        hub,
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    );
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    api_gateway.init(&ctx).await.expect("Failed to init");
+    let router = api_gateway
+        .rest_prepare(&ctx, Router::new())
+        .expect("Failed to prepare router");
+    api_gateway
+        .rest_finalize(&ctx, router)
+        .expect("Failed to finalize router")
+}
+
+async fn probe(router: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn readyz_follows_registered_checks() {
+    let db_up = Arc::new(AtomicBool::new(false));
+    let registry = HealthRegistry::new();
+    let flag = Arc::clone(&db_up);
+    registry.register("database", &[Probe::Readiness], move || {
+        let up = flag.load(Ordering::SeqCst);
+        async move {
+            if up {
+                HealthCheck::up()
+            } else {
+                HealthCheck::down("connection refused")
+            }
+        }
+    });
+    registry.register("cache", &[Probe::Liveness, Probe::Readiness], || async {
+        HealthCheck::degraded("cold")
+    });
+    let router = finalized_router(registry).await;
+
+    let (status, body) = probe(&router, "/livez").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "degraded");
+    assert_eq!(body["components"].as_array().unwrap().len(), 1);
+
+    let (status, body) = probe(&router, "/readyz").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "down");
+    let database = body["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "database")
+        .unwrap();
+    assert_eq!(database["details"], "connection refused");
+
+    db_up.store(true, Ordering::SeqCst);
+    let (status, body) = probe(&router, "/readyz").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "degraded");
+}
+
+#[tokio::test]
+async fn probes_are_up_without_checks() {
+    let router = finalized_router(HealthRegistry::new()).await;
+
+    for uri in ["/livez", "/readyz"] {
+        let (status, body) = probe(&router, uri).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+        assert_eq!(body["status"], "up");
+    }
+}
@@ -8,6 +8,7 @@ use axum::{
     http::{Request, StatusCode},
     response::IntoResponse,
 };
+use license_resolver_sdk::{LicenseFeatureSet, LicenseResolverError, LicenseResolverGatewayClient};
 use modkit::{
     ClientHub, Module,
     api::OperationBuilder,
@@ -22,6 +23,7 @@ use tenant_resolver_sdk::{
 };
 
 use modkit_security::SecurityContext;
+use modkit_security::constants::DEFAULT_TENANT_ID;
 use serde_json::json;
 use std::sync::Arc;
 use tower::ServiceExt;
@@ -41,6 +43,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
@@ -63,6 +66,28 @@ impl TenantResolverGatewayClient for MockTenantResolver {
     }
 }
 
+/// Grants `some_other_feature` to the default (auth-disabled) tenant, or fails with a fixed error.
+enum MockLicenseResolver {
+    Grants,
+    Fails(fn() -> LicenseResolverError),
+}
+
+#[async_trait]
+impl LicenseResolverGatewayClient for MockLicenseResolver {
+    async fn get_features(
+        &self,
+        ctx: &SecurityContext,
+    ) -> std::result::Result<LicenseFeatureSet, LicenseResolverError> {
+        match self {
+            Self::Grants if ctx.tenant_id() == DEFAULT_TENANT_ID => {
+                Ok(LicenseFeatureSet::new(["some_other_feature"]))
+            }
+            Self::Grants => Ok(LicenseFeatureSet::default()),
+            Self::Fails(err) => Err(err()),
+        }
+    }
+}
+
 struct TestConfigProvider {
     config: serde_json::Value,
 }
@@ -323,3 +348,76 @@ async fn allows_no_license_requirement() {
 
     assert_eq!(response.status(), StatusCode::OK);
 }
+
+async fn license_status(resolver: MockLicenseResolver, uri: &str) -> StatusCode {
+    let config = json!({
+        "api_gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "enable_docs": false,
+                "cors_enabled": false,
+                "auth_disabled": true
+            }
+        }
+    });
+
+    let api_ctx = create_api_gateway_ctx(config);
+    api_ctx
+        .client_hub()
+        .register::<dyn LicenseResolverGatewayClient>(Arc::new(resolver));
+    let test_ctx = create_test_module_ctx();
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    api_gateway.init(&api_ctx).await.expect("Failed to init");
+
+    let router = TestLicenseModule
+        .register_rest(&test_ctx, Router::new(), &api_gateway)
+        .expect("Failed to register routes");
+    let router = api_gateway
+        .rest_finalize(&api_ctx, router)
+        .expect("Failed to finalize");
+
+    router
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .expect("Request failed")
+        .status()
+}
+
+#[tokio::test]
+async fn provider_grants_non_base_feature_to_licensed_tenant() {
+    let status = license_status(MockLicenseResolver::Grants, "/tests/v1/license/bad").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn provider_does_not_need_to_list_base_feature() {
+    let status = license_status(
+        MockLicenseResolver::Fails(|| LicenseResolverError::NoPluginAvailable),
+        "/tests/v1/license/good",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn expired_license_is_forbidden() {
+    let status = license_status(
+        MockLicenseResolver::Fails(|| LicenseResolverError::LicenseExpired {
+            expired_at: chrono::Utc::now(),
+        }),
+        "/tests/v1/license/bad",
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn unavailable_provider_is_service_unavailable() {
+    let status = license_status(
+        MockLicenseResolver::Fails(|| LicenseResolverError::ServiceUnavailable("down".to_owned())),
+        "/tests/v1/license/bad",
+    )
+    .await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+}
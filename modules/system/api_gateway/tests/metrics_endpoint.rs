@@ -0,0 +1,127 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for HTTP request metrics and the `/metrics` endpoint
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use modkit::telemetry::MetricsRegistry;
+use modkit::{Module, ModuleCtx, config::ConfigProvider, contracts::ApiGatewayCapability};
+use std::sync::Arc;
+use tower::util::ServiceExt;
+use uuid::Uuid;
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        if module == "api_gateway" {
+            Some(&self.config)
+        } else {
+            None
+        }
+    }
+}
+
+fn create_test_module_ctx(config: &serde_json::Value, registry: MetricsRegistry) -> ModuleCtx {
+    let hub = Arc::new(modkit::ClientHub::new());
+    hub.register::<MetricsRegistry>(Arc::new(registry));
+
+    ModuleCtx::new(
+        "api_gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider {
+            config: serde_json::json!({ "config": config }),
+        }),
+        hub,
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    )
+}
+
+async fn finalized_router(config: serde_json::Value, registry: MetricsRegistry) -> Router {
+    let api_gateway = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(&config, registry);
+    api_gateway.init(&ctx).await.expect("Failed to init");
+
+    let router = api_gateway
+        .rest_prepare(&ctx, Router::new())
+        .expect("Failed to prepare router");
+    api_gateway
+        .rest_finalize(&ctx, router)
+        .expect("Failed to finalize router")
+}
+
+async fn get(router: &Router, uri: &str) -> axum::response::Response {
+    router
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn metrics_endpoint_exposes_request_metrics() {
+    let registry = MetricsRegistry::new();
+    let router = finalized_router(
+        serde_json::json!({ "bind_addr": "127.0.0.1:0", "auth_disabled": true }),
+        registry.clone(),
+    )
+    .await;
+
+    assert_eq!(get(&router, "/healthz").await.status(), StatusCode::OK);
+    assert_eq!(
+        get(&router, "/no/such/route").await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let response = get(&router, "/metrics").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        modkit::telemetry::metrics::CONTENT_TYPE
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(
+        body.contains(
+            r#"http_server_requests_total{method="GET",route="/healthz",status="200"} 1"#
+        ),
+        "{body}"
+    );
+    assert!(
+        body.contains(
+            r#"http_server_requests_total{method="GET",route="unmatched",status="404"} 1"#
+        ),
+        "{body}"
+    );
+    assert!(body.contains("http_server_request_duration_seconds_count"));
+}
+
+#[tokio::test]
+async fn metrics_can_be_disabled() {
+    let registry = MetricsRegistry::new();
+    let router = finalized_router(
+        serde_json::json!({
+            "bind_addr": "127.0.0.1:0",
+            "auth_disabled": true,
+            "enable_metrics": false
+        }),
+        registry.clone(),
+    )
+    .await;
+
+    assert_eq!(get(&router, "/healthz").await.status(), StatusCode::OK);
+    assert_eq!(
+        get(&router, "/metrics").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert!(!registry.encode().contains("http_server_requests_total{"));
+}
@@ -4,7 +4,8 @@
 //!
 //! The intended order is documented in `modules/api_gateway/src/lib.rs`:
 //! set request id -> propagate request id -> trace -> push request id to extensions
-//! -> timeout -> body limit -> CORS -> MIME validation -> rate limit -> error mapping -> auth -> router
+//! -> timeout -> body limit -> CORS -> MIME validation -> client rate limit -> error mapping
+//! -> auth -> principal rate limit -> router
 //!
 use anyhow::Result;
 use async_trait::async_trait;
@@ -46,6 +47,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
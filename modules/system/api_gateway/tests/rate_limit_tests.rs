@@ -36,6 +36,7 @@ impl TenantResolverGatewayClient for MockTenantResolver {
             name: format!("Tenant {id}"),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         })
     }
 
@@ -291,3 +292,111 @@ async fn test_rate_limit_metadata_stored() {
     let test_op = json.pointer("/paths/~1tests~1v1~1test/get");
     assert!(test_op.is_some(), "Test endpoint should be in OpenAPI");
 }
+
+async fn finalize_with_config(config: &serde_json::Value) -> Router {
+    let api_gateway = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx_with_config(config);
+    api_gateway.init(&ctx).await.expect("Failed to init");
+
+    let router = RateLimitedModule
+        .register_rest(&ctx, Router::new(), &api_gateway)
+        .expect("Failed to register routes");
+    api_gateway
+        .rest_finalize(&ctx, router)
+        .expect("Failed to finalize router")
+}
+
+async fn get_limited_from(app: &Router, ip: [u8; 4]) -> axum::response::Response {
+    use axum::extract::ConnectInfo;
+    use tower::ServiceExt;
+
+    let addr = std::net::SocketAddr::from((ip, 40000));
+    app.clone()
+        .oneshot(
+            http::Request::builder()
+                .uri("/tests/v1/limited")
+                .extension(ConnectInfo(addr))
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_rate_limit_per_client_ip() {
+    let app = finalize_with_config(&serde_json::json!({
+        "bind_addr": "127.0.0.1:0",
+        "auth_disabled": true,
+        "defaults": { "rate_limit": { "key": "client_ip" } }
+    }))
+    .await;
+
+    let first = get_limited_from(&app, [10, 0, 0, 1]).await;
+    assert_eq!(first.status(), http::StatusCode::OK);
+    assert_eq!(first.headers()["RateLimit-Limit"], "1");
+    assert_eq!(first.headers()["RateLimit-Remaining"], "0");
+
+    let second = get_limited_from(&app, [10, 0, 0, 1]).await;
+    assert_eq!(second.status(), http::StatusCode::TOO_MANY_REQUESTS);
+    assert!(second.headers().contains_key(http::header::RETRY_AFTER));
+
+    // A different client still has its own quota
+    let other = get_limited_from(&app, [10, 0, 0, 2]).await;
+    assert_eq!(other.status(), http::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rate_limit_tenant_override() {
+    let tenant = modkit_security::constants::DEFAULT_TENANT_ID;
+    let app = finalize_with_config(&serde_json::json!({
+        "bind_addr": "127.0.0.1:0",
+        "auth_disabled": true,
+        "defaults": {
+            "rate_limit": {
+                "key": "tenant",
+                "tenant_overrides": { tenant.to_string(): { "rps": 1, "burst": 3 } }
+            }
+        }
+    }))
+    .await;
+
+    for _ in 0..3 {
+        let resp = get_limited_from(&app, [10, 0, 0, 1]).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(resp.headers()["RateLimit-Limit"], "3");
+    }
+    let resp = get_limited_from(&app, [10, 0, 0, 1]).await;
+    assert_eq!(resp.status(), http::StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_rate_limit_api_key_ignores_header_value() {
+    use axum::extract::ConnectInfo;
+    use tower::ServiceExt;
+
+    let app = finalize_with_config(&serde_json::json!({
+        "bind_addr": "127.0.0.1:0",
+        "auth_disabled": true,
+        "defaults": { "rate_limit": { "key": "api_key" } }
+    }))
+    .await;
+
+    let get = |ip: [u8; 4], api_key: &str| {
+        app.clone().oneshot(
+            http::Request::builder()
+                .uri("/tests/v1/limited")
+                .header("x-api-key", api_key)
+                .extension(ConnectInfo(std::net::SocketAddr::from((ip, 40000))))
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+    };
+
+    let first = get([10, 0, 0, 1], "key-1").await.unwrap();
+    assert_eq!(first.status(), http::StatusCode::OK);
+
+    // Same principal: a fresh header value from another address gets no fresh quota
+    let second = get([10, 0, 0, 2], "key-2").await.unwrap();
+    assert_eq!(second.status(), http::StatusCode::TOO_MANY_REQUESTS);
+}
@@ -0,0 +1,184 @@
+//! Standard `grpc.health.v1.Health` service backed by the process [`HealthRegistry`].
+//!
+//! The empty service name reports overall readiness. A hosted service name reports the
+//! readiness of the module that owns it when that module has the `health` capability,
+//! and overall readiness otherwise.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use modkit::health::{HealthRegistry, HealthStatus, Probe};
+use modkit::runtime::ModuleInstallers;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+pub use tonic_health::pb;
+
+pub use pb::health_check_response::ServingStatus;
+pub use pb::health_server::{Health, HealthServer};
+pub use pb::{HealthCheckRequest, HealthCheckResponse};
+
+/// How often `Watch` re-evaluates the checks.
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Fully-qualified name of the health service itself.
+const HEALTH_SERVICE_NAME: &str = "grpc.health.v1.Health";
+
+/// Health service answering for the services hosted by the hub.
+#[derive(Clone)]
+pub struct HealthService {
+    registry: HealthRegistry,
+    /// Hosted service name -> owning module name
+    services: Arc<HashMap<String, String>>,
+    watch_interval: Duration,
+}
+
+impl HealthService {
+    #[must_use]
+    pub fn new(registry: HealthRegistry, modules: &[ModuleInstallers]) -> Self {
+        let services = modules
+            .iter()
+            .flat_map(|m| {
+                m.installers
+                    .iter()
+                    .map(|i| (i.service_name.to_owned(), m.module_name.clone()))
+            })
+            .collect();
+        Self {
+            registry,
+            services: Arc::new(services),
+            watch_interval: WATCH_INTERVAL,
+        }
+    }
+
+    /// Override how often `Watch` re-evaluates the checks.
+    #[must_use]
+    pub fn with_watch_interval(mut self, interval: Duration) -> Self {
+        self.watch_interval = interval;
+        self
+    }
+
+    /// Serving status of `service`, or `None` if the hub does not host it.
+    async fn status(&self, service: &str) -> Option<ServingStatus> {
+        let available = if service.is_empty() || service == HEALTH_SERVICE_NAME {
+            self.registry.check(Probe::Readiness).await.is_available()
+        } else {
+            let module = self.services.get(service)?;
+            match self
+                .registry
+                .check_component(module, Probe::Readiness)
+                .await
+            {
+                Some(component) => component.status != HealthStatus::Down,
+                None => self.registry.check(Probe::Readiness).await.is_available(),
+            }
+        };
+
+        Some(if available {
+            ServingStatus::Serving
+        } else {
+            ServingStatus::NotServing
+        })
+    }
+}
+
+fn response(status: ServingStatus) -> HealthCheckResponse {
+    HealthCheckResponse {
+        status: status.into(),
+    }
+}
+
+#[tonic::async_trait]
+impl Health for HealthService {
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        let service = request.into_inner().service;
+        let status = self
+            .status(&service)
+            .await
+            .ok_or_else(|| Status::not_found(format!("unknown service: {service}")))?;
+        Ok(Response::new(response(status)))
+    }
+
+    type WatchStream = Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, Status>> + Send>>;
+
+    async fn watch(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let service = request.into_inner().service;
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let this = self.clone();
+
+        // Emits the current status, then every change until the client goes away
+        tokio::spawn(async move {
+            let mut last = None;
+            loop {
+                let status = this
+                    .status(&service)
+                    .await
+                    .unwrap_or(ServingStatus::ServiceUnknown);
+                if last != Some(status) {
+                    if tx.send(Ok(response(status))).await.is_err() {
+                        break;
+                    }
+                    last = Some(status);
+                }
+                tokio::select! {
+                    () = tx.closed() => break,
+                    () = tokio::time::sleep(this.watch_interval) => {}
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use modkit::health::HealthCheck;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio_stream::StreamExt;
+
+    async fn status(stream: &mut <HealthService as Health>::WatchStream) -> ServingStatus {
+        stream.next().await.unwrap().unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn watch_emits_on_change_only() {
+        let up = Arc::new(AtomicBool::new(true));
+        let registry = HealthRegistry::new();
+        let flag = Arc::clone(&up);
+        registry.register("db", &[Probe::Readiness], move || {
+            let up = flag.load(Ordering::SeqCst);
+            async move {
+                if up {
+                    HealthCheck::up()
+                } else {
+                    HealthCheck::down("connection refused")
+                }
+            }
+        });
+        let service =
+            HealthService::new(registry, &[]).with_watch_interval(Duration::from_millis(10));
+
+        let mut stream = service
+            .watch(Request::new(HealthCheckRequest::default()))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(status(&mut stream).await, ServingStatus::Serving);
+        up.store(false, Ordering::SeqCst);
+        assert_eq!(status(&mut stream).await, ServingStatus::NotServing);
+        up.store(true, Ordering::SeqCst);
+        assert_eq!(status(&mut stream).await, ServingStatus::Serving);
+    }
+}
@@ -7,11 +7,14 @@ use async_trait::async_trait;
 use modkit::{
     DirectoryClient,
     context::ModuleCtx,
-    contracts::{Module, SystemCapability},
+    contracts::{HealthCapability, Module, SystemCapability},
+    health::{HealthCheck, HealthRegistry},
     lifecycle::ReadySignal,
     runtime::{GrpcInstallerData, GrpcInstallerStore, ModuleInstallers},
 };
 
+use crate::health::{HealthServer, HealthService};
+use modkit_transport_grpc::metrics::GrpcServerMetricsLayer;
 use parking_lot::RwLock;
 use serde::Deserialize;
 #[cfg(unix)]
@@ -19,7 +22,10 @@ use std::path::PathBuf;
 use std::{
     collections::HashSet,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
-    sync::{Arc, OnceLock},
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 use tokio::net::TcpListener;
 use tokio_stream::wrappers::TcpListenerStream;
@@ -68,7 +74,7 @@ pub(crate) enum ListenConfig {
 /// This module is responsible for hosting the gRPC server and managing the gRPC services.
 #[modkit::module(
     name = "grpc_hub",
-    capabilities = [stateful, system, grpc_hub],
+    capabilities = [stateful, system, grpc_hub, health],
     lifecycle(entry = "serve", await_ready)
 )]
 pub struct GrpcHub {
@@ -77,6 +83,9 @@ pub struct GrpcHub {
     pub(crate) directory: OnceLock<Option<Arc<dyn DirectoryClient>>>,
     pub(crate) instance_id: OnceLock<String>,
     pub(crate) bound_endpoint: RwLock<Option<String>>,
+    pub(crate) health_registry: RwLock<HealthRegistry>,
+    /// Set when there are no services to serve, so no listener is needed
+    pub(crate) idle: AtomicBool,
 }
 
 impl Default for GrpcHub {
@@ -87,6 +96,8 @@ impl Default for GrpcHub {
             directory: OnceLock::new(),
             instance_id: OnceLock::new(),
             bound_endpoint: RwLock::new(None),
+            health_registry: RwLock::new(HealthRegistry::new()),
+            idle: AtomicBool::new(false),
         }
     }
 }
@@ -316,10 +327,13 @@ impl GrpcHub {
         Self::validate_unique_services(&data.modules)?;
 
         let Some(routes) = Self::build_routes_from_modules(&data.modules) else {
+            self.idle.store(true, Ordering::Release);
             ready.notify();
             cancel.cancelled().await;
             return Ok(());
         };
+        let health = HealthService::new(self.health_registry.read().clone(), &data.modules);
+        let routes = routes.add_service(HealthServer::new(health));
 
         let listen_cfg = self.listen_cfg.read().clone();
         let serve_result = match listen_cfg {
@@ -363,6 +377,7 @@ impl GrpcHub {
 
         let incoming = TcpListenerStream::new(listener);
         Server::builder()
+            .layer(GrpcServerMetricsLayer::default())
             .add_routes(routes)
             .serve_with_incoming_shutdown(incoming, async move {
                 cancel.cancelled().await;
@@ -402,6 +417,7 @@ impl GrpcHub {
 
         let incoming = UnixListenerStream::new(uds);
         Server::builder()
+            .layer(GrpcServerMetricsLayer::default())
             .add_routes(routes)
             .serve_with_incoming_shutdown(incoming, async move {
                 cancel.cancelled().await;
@@ -429,6 +445,7 @@ impl GrpcHub {
 
         let incoming = create_named_pipe_incoming(pipe_name, cancel.clone());
         Server::builder()
+            .layer(GrpcServerMetricsLayer::default())
             .add_routes(routes)
             .serve_with_incoming_shutdown(incoming, async move {
                 cancel.cancelled().await;
@@ -528,6 +545,17 @@ impl modkit::contracts::GrpcHubCapability for GrpcHub {
     }
 }
 
+#[async_trait]
+impl HealthCapability for GrpcHub {
+    async fn readiness(&self) -> HealthCheck {
+        if self.idle.load(Ordering::Acquire) || self.get_bound_endpoint().is_some() {
+            HealthCheck::up()
+        } else {
+            HealthCheck::down("not listening")
+        }
+    }
+}
+
 #[async_trait]
 impl Module for GrpcHub {
     async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
@@ -544,6 +572,11 @@ impl Module for GrpcHub {
             .set(dir)
             .map_err(|_| anyhow::anyhow!("DirectoryClient already set (init called twice?)"))?;
 
+        // Served as grpc.health.v1.Health alongside the module services
+        if let Ok(registry) = ctx.client_hub().get::<HealthRegistry>() {
+            *self.health_registry.write() = (*registry).clone();
+        }
+
         Ok(())
     }
 }
@@ -1018,4 +1051,77 @@ mod tests {
             .expect("task should join successfully")
             .expect("should exit cleanly with no services");
     }
+
+    #[tokio::test]
+    async fn test_health_service_reports_module_readiness() {
+        use crate::health::{HealthCheckRequest, ServingStatus, pb::health_client::HealthClient};
+        use modkit::health::{HealthCheck, Probe};
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let hub = Arc::new(GrpcHub::default());
+        hub.set_listen_addr_tcp("127.0.0.1:0".parse().unwrap());
+
+        let ready_flag = Arc::new(AtomicBool::new(false));
+        let registry = hub.health_registry.read().clone();
+        let flag = Arc::clone(&ready_flag);
+        registry.register("test", &[Probe::Readiness], move || {
+            let ready = flag.load(Ordering::SeqCst);
+            async move {
+                if ready {
+                    HealthCheck::up()
+                } else {
+                    HealthCheck::down("warming up")
+                }
+            }
+        });
+
+        let data = GrpcInstallerData {
+            modules: vec![ModuleInstallers {
+                module_name: "test".to_owned(),
+                installers: vec![installer_a()],
+            }],
+        };
+        let cancel = CancellationToken::new();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let ready = ReadySignal::from_sender(tx);
+        let hub_task = {
+            let hub = hub.clone();
+            let cancel = cancel.clone();
+            tokio::spawn(async move { hub.run_with_installers(data, cancel, ready).await })
+        };
+        rx.await.expect("ready signal should fire");
+
+        let endpoint = hub.get_bound_endpoint().expect("hub should be listening");
+        let channel = tonic::transport::Endpoint::from_shared(endpoint)
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        let mut client = HealthClient::new(channel);
+        let status = |service: &str| HealthCheckRequest {
+            service: service.to_owned(),
+        };
+
+        for service in ["", SERVICE_A] {
+            let resp = client.check(status(service)).await.unwrap().into_inner();
+            assert_eq!(resp.status(), ServingStatus::NotServing, "{service:?}");
+        }
+
+        ready_flag.store(true, Ordering::SeqCst);
+        for service in ["", SERVICE_A, "grpc.health.v1.Health"] {
+            let resp = client.check(status(service)).await.unwrap().into_inner();
+            assert_eq!(resp.status(), ServingStatus::Serving, "{service:?}");
+        }
+
+        let err = client.check(status(SERVICE_B)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let mut watch = client.watch(status(SERVICE_B)).await.unwrap().into_inner();
+        let first = watch.message().await.unwrap().unwrap();
+        assert_eq!(first.status(), ServingStatus::ServiceUnknown);
+
+        drop(watch);
+        cancel.cancel();
+        hub_task.await.unwrap().unwrap();
+    }
 }
@@ -0,0 +1,33 @@
+//! Configuration for the license resolver gateway.
+
+use serde::Deserialize;
+
+/// Gateway configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LicenseResolverGwConfig {
+    /// Vendor selector used to pick a plugin implementation.
+    ///
+    /// The gateway queries types-registry for plugin instances matching
+    /// this vendor and selects the one with lowest priority.
+    pub vendor: String,
+
+    /// How long a tenant's feature set is cached, in seconds (`0` disables caching).
+    ///
+    /// Entries never outlive the license expiry reported by the plugin.
+    pub cache_ttl_secs: u64,
+
+    /// Tenants whose feature sets are cached; the least recently used one is evicted
+    /// when full (`0` disables caching).
+    pub cache_max_entries: usize,
+}
+
+impl Default for LicenseResolverGwConfig {
+    fn default() -> Self {
+        Self {
+            vendor: "hyperspot".to_owned(),
+            cache_ttl_secs: 60,
+            cache_max_entries: 10_000,
+        }
+    }
+}
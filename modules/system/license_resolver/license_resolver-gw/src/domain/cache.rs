@@ -0,0 +1,117 @@
+//! Per-tenant cache of resolved feature sets.
+
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use license_resolver_sdk::LicenseFeatureSet;
+use lru::LruCache;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+struct CacheEntry {
+    features: LicenseFeatureSet,
+    expires_at: Instant,
+}
+
+/// Feature sets keyed by tenant ID (nil = anonymous).
+///
+/// An entry is served for at most `ttl` and never after the license
+/// `expires_at`; at most `max_entries` tenants are kept, evicting the least
+/// recently used one. A zero `ttl` or `max_entries` disables caching.
+pub struct FeatureCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<LruCache<Uuid, CacheEntry>>,
+}
+
+impl FeatureCache {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: Mutex::new(LruCache::new(
+                NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN),
+            )),
+        }
+    }
+
+    /// Cached feature set for `tenant`, if still fresh.
+    pub fn get(&self, tenant: Uuid) -> Option<LicenseFeatureSet> {
+        let mut entries = self.entries.lock();
+        let entry = entries.get(&tenant)?;
+        let license_expired = entry
+            .features
+            .expires_at
+            .is_some_and(|exp| exp <= Utc::now());
+        if entry.expires_at <= Instant::now() || license_expired {
+            entries.pop(&tenant);
+            return None;
+        }
+        Some(entry.features.clone())
+    }
+
+    pub fn insert(&self, tenant: Uuid, features: LicenseFeatureSet) {
+        if self.ttl.is_zero() || self.max_entries == 0 {
+            return;
+        }
+        self.entries.lock().put(
+            tenant,
+            CacheEntry {
+                features,
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_are_per_tenant() {
+        let cache = FeatureCache::new(Duration::from_mins(1), 10);
+        let a = Uuid::new_v4();
+        cache.insert(a, LicenseFeatureSet::new(["f1"]));
+
+        assert!(cache.get(a).unwrap().contains("f1"));
+        assert!(cache.get(Uuid::new_v4()).is_none());
+    }
+
+    #[test]
+    fn zero_ttl_disables_caching() {
+        let cache = FeatureCache::new(Duration::ZERO, 10);
+        let a = Uuid::new_v4();
+        cache.insert(a, LicenseFeatureSet::new(["f1"]));
+
+        assert!(cache.get(a).is_none());
+    }
+
+    #[test]
+    fn entries_do_not_outlive_license() {
+        let cache = FeatureCache::new(Duration::from_mins(1), 10);
+        let a = Uuid::new_v4();
+        let mut features = LicenseFeatureSet::new(["f1"]);
+        features.expires_at = Some(Utc::now() - chrono::Duration::seconds(1));
+        cache.insert(a, features);
+
+        assert!(cache.get(a).is_none());
+    }
+
+    #[test]
+    fn least_recently_used_tenant_is_evicted_when_full() {
+        let cache = FeatureCache::new(Duration::from_mins(1), 2);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        cache.insert(a, LicenseFeatureSet::new(["f1"]));
+        cache.insert(b, LicenseFeatureSet::new(["f1"]));
+        // Using `a` makes `b` the least recently used entry
+        assert!(cache.get(a).is_some());
+        cache.insert(c, LicenseFeatureSet::new(["f1"]));
+
+        assert!(cache.get(a).is_some());
+        assert!(cache.get(b).is_none());
+        assert!(cache.get(c).is_some());
+    }
+}
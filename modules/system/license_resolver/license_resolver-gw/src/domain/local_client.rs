@@ -0,0 +1,36 @@
+//! Local (in-process) client for the license resolver gateway.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use license_resolver_sdk::{LicenseFeatureSet, LicenseResolverError, LicenseResolverGatewayClient};
+use modkit_security::SecurityContext;
+
+use super::{DomainError, Service};
+
+/// Local client wrapping the gateway service.
+///
+/// Registered in `ClientHub` by the gateway module during `init()`.
+pub struct LicenseResolverGwLocalClient {
+    svc: Arc<Service>,
+}
+
+impl LicenseResolverGwLocalClient {
+    #[must_use]
+    pub fn new(svc: Arc<Service>) -> Self {
+        Self { svc }
+    }
+}
+
+#[async_trait]
+impl LicenseResolverGatewayClient for LicenseResolverGwLocalClient {
+    async fn get_features(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<LicenseFeatureSet, LicenseResolverError> {
+        self.svc.get_features(ctx).await.map_err(|e: DomainError| {
+            tracing::error!(error = ?e, "license_resolver gateway call failed");
+            e.into()
+        })
+    }
+}
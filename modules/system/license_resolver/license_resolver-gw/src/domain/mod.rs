@@ -0,0 +1,10 @@
+//! Domain layer for the license resolver gateway.
+
+mod cache;
+pub mod error;
+pub mod local_client;
+pub mod service;
+
+pub use error::DomainError;
+pub use local_client::LicenseResolverGwLocalClient;
+pub use service::Service;
@@ -0,0 +1,190 @@
+//! Domain service for the license resolver gateway.
+//!
+//! Plugin discovery is lazy: resolved on first API call after
+//! types-registry is ready, and again after plugin instances change.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use license_resolver_sdk::{
+    LicenseFeatureSet, LicenseProviderPluginClient, LicenseProviderPluginSpecV1,
+};
+use modkit::client_hub::{ClientHub, ClientScope};
+use modkit::gts::BaseModkitPluginV1;
+use modkit::plugins::GtsPluginSelector;
+use modkit::telemetry::ThrottledLog;
+use modkit_security::SecurityContext;
+use tracing::info;
+use types_registry_sdk::{GtsEntity, ListQuery, TypesRegistryClient};
+
+use super::cache::FeatureCache;
+use super::error::DomainError;
+
+/// Throttle interval for unavailable plugin warnings.
+const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
+
+/// License resolver gateway service.
+///
+/// Discovers plugins via types-registry, delegates API calls and caches
+/// the resolved feature sets per tenant.
+pub struct Service {
+    hub: Arc<ClientHub>,
+    vendor: String,
+    /// Shared selector for plugin instance IDs, reset when plugin instances change.
+    selector: Arc<GtsPluginSelector>,
+    /// Feature sets by tenant.
+    cache: FeatureCache,
+    /// Throttle for plugin unavailable warnings.
+    unavailable_log_throttle: ThrottledLog,
+}
+
+impl Service {
+    /// Creates a new service with lazy plugin resolution.
+    #[must_use]
+    pub fn new(
+        hub: Arc<ClientHub>,
+        vendor: String,
+        cache_ttl: Duration,
+        cache_max_entries: usize,
+    ) -> Self {
+        Self {
+            hub,
+            vendor,
+            selector: Arc::new(GtsPluginSelector::new()),
+            cache: FeatureCache::new(cache_ttl, cache_max_entries),
+            unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
+        }
+    }
+
+    /// Lazily resolves and returns the plugin client.
+    async fn get_plugin(&self) -> Result<Arc<dyn LicenseProviderPluginClient>, DomainError> {
+        let instance_id = self.selector.get_or_init(|| self.resolve_plugin()).await?;
+        let scope = ClientScope::gts_id(instance_id.as_ref());
+
+        if let Some(client) = self
+            .hub
+            .try_get_scoped::<dyn LicenseProviderPluginClient>(&scope)
+        {
+            Ok(client)
+        } else {
+            if self.unavailable_log_throttle.should_log() {
+                tracing::warn!(
+                    plugin_gts_id = %instance_id,
+                    vendor = %self.vendor,
+                    "Plugin client not registered yet"
+                );
+            }
+            Err(DomainError::PluginUnavailable {
+                gts_id: instance_id.to_string(),
+                reason: "client not registered yet".into(),
+            })
+        }
+    }
+
+    /// Resolves the plugin instance from types-registry.
+    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
+    async fn resolve_plugin(&self) -> Result<String, DomainError> {
+        info!("Resolving license provider plugin");
+
+        let registry = self
+            .hub
+            .get::<dyn TypesRegistryClient>()
+            .map_err(|e| DomainError::TypesRegistryUnavailable(e.to_string()))?;
+
+        let plugin_type_id = LicenseProviderPluginSpecV1::gts_schema_id().clone();
+
+        let query = ListQuery::new()
+            .with_pattern(format!("{plugin_type_id}*"))
+            .with_is_type(false);
+
+        // Subscribe first so that no change made after the listing is missed
+        let changes = registry.watch(query.clone());
+        let instances = registry.list_all(query).await?;
+
+        let gts_id = choose_plugin_instance(&self.vendor, &instances)?;
+        info!(plugin_gts_id = %gts_id, "Selected license provider plugin instance");
+        self.selector.reset_on_change(changes);
+
+        Ok(gts_id)
+    }
+
+    /// Get the global features licensed to the tenant in `ctx`.
+    ///
+    /// Results are cached per tenant; anonymous contexts share the nil-tenant entry.
+    ///
+    /// # Errors
+    ///
+    /// - `LicenseExpired` if the plugin reports an expired license
+    /// - Plugin resolution errors
+    #[tracing::instrument(skip_all, fields(tenant.id = %ctx.tenant_id()))]
+    pub async fn get_features(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<LicenseFeatureSet, DomainError> {
+        let tenant = ctx.tenant_id();
+        if let Some(features) = self.cache.get(tenant) {
+            return Ok(features);
+        }
+
+        let plugin = self.get_plugin().await?;
+        let features = plugin.get_features(ctx).await?;
+        if let Some(expired_at) = features.expires_at.filter(|exp| *exp <= Utc::now()) {
+            return Err(DomainError::LicenseExpired { expired_at });
+        }
+
+        self.cache.insert(tenant, features.clone());
+        Ok(features)
+    }
+}
+
+/// Selects the best plugin instance for the given vendor.
+///
+/// If multiple instances match, the one with lowest priority wins.
+#[tracing::instrument(skip_all, fields(vendor, instance_count = instances.len()))]
+fn choose_plugin_instance(vendor: &str, instances: &[GtsEntity]) -> Result<String, DomainError> {
+    let mut best: Option<(String, i16)> = None;
+
+    for ent in instances {
+        let content: BaseModkitPluginV1<LicenseProviderPluginSpecV1> =
+            serde_json::from_value(ent.content.clone()).map_err(|e| {
+                tracing::error!(
+                    gts_id = %ent.gts_id,
+                    error = %e,
+                    "Failed to deserialize plugin instance content"
+                );
+                DomainError::InvalidPluginInstance {
+                    gts_id: ent.gts_id.clone(),
+                    reason: e.to_string(),
+                }
+            })?;
+
+        if content.id != ent.gts_id {
+            return Err(DomainError::InvalidPluginInstance {
+                gts_id: ent.gts_id.clone(),
+                reason: format!(
+                    "content.id mismatch: expected {:?}, got {:?}",
+                    ent.gts_id, content.id
+                ),
+            });
+        }
+
+        if content.vendor != vendor {
+            continue;
+        }
+
+        match &best {
+            None => best = Some((ent.gts_id.clone(), content.priority)),
+            Some((_, cur_priority)) => {
+                if content.priority < *cur_priority {
+                    best = Some((ent.gts_id.clone(), content.priority));
+                }
+            }
+        }
+    }
+
+    best.map(|(gts_id, _)| gts_id)
+        .ok_or_else(|| DomainError::PluginNotFound {
+            vendor: vendor.to_owned(),
+        })
+}
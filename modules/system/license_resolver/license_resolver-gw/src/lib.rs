@@ -0,0 +1,13 @@
+//! License Resolver Gateway Module
+//!
+//! This module discovers license provider plugins via types-registry
+//! and routes API calls to the selected plugin based on vendor configuration.
+//! Resolved feature sets are cached per tenant.
+//!
+//! The gateway provides the `LicenseResolverGatewayClient` trait registered
+//! in `ClientHub` for consumption by other modules.
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+pub mod config;
+pub mod domain;
+pub mod module;
@@ -0,0 +1,86 @@
+//! License resolver gateway module.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use license_resolver_sdk::{LicenseProviderPluginSpecV1, LicenseResolverGatewayClient};
+use modkit::Module;
+use modkit::context::ModuleCtx;
+use tracing::info;
+use types_registry_sdk::TypesRegistryClient;
+
+use crate::config::LicenseResolverGwConfig;
+use crate::domain::{LicenseResolverGwLocalClient, Service};
+
+/// License Resolver Gateway module.
+///
+/// This module:
+/// 1. Registers the plugin schema in types-registry
+/// 2. Discovers plugin instances via types-registry
+/// 3. Routes requests to the selected plugin and caches the results per tenant
+///
+/// Plugin discovery is lazy: happens on first API call after types-registry
+/// is ready.
+#[modkit::module(
+    name = "license_resolver",
+    deps = ["types_registry"],
+    capabilities = []
+)]
+pub(crate) struct LicenseResolverGateway {
+    service: OnceLock<Arc<Service>>,
+}
+
+impl Default for LicenseResolverGateway {
+    fn default() -> Self {
+        Self {
+            service: OnceLock::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Module for LicenseResolverGateway {
+    #[tracing::instrument(skip_all, fields(vendor))]
+    async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+        let cfg: LicenseResolverGwConfig = ctx.config()?;
+        tracing::Span::current().record("vendor", cfg.vendor.as_str());
+        info!(
+            vendor = %cfg.vendor,
+            cache_ttl_secs = cfg.cache_ttl_secs,
+            cache_max_entries = cfg.cache_max_entries,
+            "Initializing license_resolver gateway"
+        );
+
+        // Register plugin schema in types-registry
+        let registry = ctx.client_hub().get::<dyn TypesRegistryClient>()?;
+        let schema_str = LicenseProviderPluginSpecV1::gts_schema_with_refs_as_string();
+        let schema_json: serde_json::Value = serde_json::from_str(&schema_str)?;
+        let _ = registry.register(vec![schema_json]).await?;
+        info!(
+            schema_id = %LicenseProviderPluginSpecV1::gts_schema_id(),
+            "Registered plugin schema in types-registry"
+        );
+
+        // Create service
+        let hub = ctx.client_hub();
+        let svc = Arc::new(Service::new(
+            hub,
+            cfg.vendor,
+            Duration::from_secs(cfg.cache_ttl_secs),
+            cfg.cache_max_entries,
+        ));
+
+        // Register gateway client in ClientHub
+        let api: Arc<dyn LicenseResolverGatewayClient> =
+            Arc::new(LicenseResolverGwLocalClient::new(svc.clone()));
+        ctx.client_hub()
+            .register::<dyn LicenseResolverGatewayClient>(api);
+
+        self.service
+            .set(svc)
+            .map_err(|_| anyhow::anyhow!("Service already initialized"))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+//! Public API trait for the license resolver gateway.
+//!
+//! This trait defines the interface that consumers use to query licensed
+//! features. The gateway implements this trait, caches results and
+//! delegates to the selected license provider plugin.
+
+use async_trait::async_trait;
+use modkit_security::SecurityContext;
+
+use crate::error::LicenseResolverError;
+use crate::models::LicenseFeatureSet;
+
+/// Public API trait for the license resolver gateway.
+///
+/// This trait is registered in `ClientHub` by the gateway module and
+/// can be consumed by other modules:
+///
+/// ```ignore
+/// let resolver = hub.get::<dyn LicenseResolverGatewayClient>()?;
+/// let features = resolver.get_features(&ctx).await?;
+/// ```
+///
+/// The tenant is always taken from `ctx.tenant_id()`.
+#[async_trait]
+pub trait LicenseResolverGatewayClient: Send + Sync {
+    /// Get the global features licensed to the current tenant.
+    ///
+    /// Global features are not scoped to a particular resource. Anonymous
+    /// contexts (nil tenant) receive only the features licensed to everyone.
+    ///
+    /// # Errors
+    ///
+    /// - `LicenseExpired` if the license is no longer valid
+    /// - `NoPluginAvailable` / `ServiceUnavailable` if no provider can answer
+    async fn get_features(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<LicenseFeatureSet, LicenseResolverError>;
+}
@@ -0,0 +1,31 @@
+//! Error types for the license resolver module.
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+/// Errors that can occur when using the license resolver API.
+#[derive(Debug, Error)]
+pub enum LicenseResolverError {
+    /// The license expired.
+    #[error("license expired at {expired_at}")]
+    LicenseExpired {
+        /// When the license stopped being valid.
+        expired_at: DateTime<Utc>,
+    },
+
+    /// The license could not be loaded or verified.
+    #[error("invalid license: {0}")]
+    InvalidLicense(String),
+
+    /// No plugin is available to handle the request.
+    #[error("no plugin available")]
+    NoPluginAvailable,
+
+    /// The plugin is not available yet.
+    #[error("service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    /// An internal error occurred.
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,38 @@
+//! License Resolver SDK
+//!
+//! This crate provides the public API for the `license_resolver` module:
+//!
+//! - [`LicenseResolverGatewayClient`] - Public API trait for consumers
+//! - [`LicenseProviderPluginClient`] - Plugin API trait for implementations
+//! - [`LicenseFeatureSet`] - Domain model
+//! - [`LicenseResolverError`] - Error types
+//! - [`LicenseProviderPluginSpecV1`] - GTS schema for plugin discovery
+//!
+//! ## Usage
+//!
+//! Consumers obtain the client from `ClientHub`:
+//!
+//! ```ignore
+//! use license_resolver_sdk::LicenseResolverGatewayClient;
+//!
+//! let resolver = hub.get::<dyn LicenseResolverGatewayClient>()?;
+//!
+//! // Global features licensed to the tenant in `ctx`
+//! let features = resolver.get_features(&ctx).await?;
+//! if features.contains("gts.x.core.lic.feat.v1~x.core.global.base.v1") {
+//!     // ...
+//! }
+//! ```
+
+pub mod api;
+pub mod error;
+pub mod gts;
+pub mod models;
+pub mod plugin_api;
+
+// Re-export main types at crate root
+pub use api::LicenseResolverGatewayClient;
+pub use error::LicenseResolverError;
+pub use gts::LicenseProviderPluginSpecV1;
+pub use models::{BASE_FEATURE, LicenseFeatureSet};
+pub use plugin_api::LicenseProviderPluginClient;
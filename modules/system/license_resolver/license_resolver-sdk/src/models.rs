@@ -0,0 +1,69 @@
+//! Domain models for the license resolver module.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Feature every deployment is licensed for.
+pub const BASE_FEATURE: &str = "gts.x.core.lic.feat.v1~x.core.global.base.v1";
+
+/// Global license features granted to a tenant.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LicenseFeatureSet {
+    /// Granted feature GTS ids.
+    pub features: BTreeSet<String>,
+
+    /// When the grant stops being valid (`None` = no expiry).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl LicenseFeatureSet {
+    /// Create a feature set without expiry.
+    pub fn new<I, S>(features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            features: features.into_iter().map(Into::into).collect(),
+            expires_at: None,
+        }
+    }
+
+    /// Whether `feature` is granted. The base feature is always granted.
+    #[must_use]
+    pub fn contains(&self, feature: &str) -> bool {
+        feature == BASE_FEATURE || self.features.contains(feature)
+    }
+
+    /// Required features that are not granted.
+    #[must_use]
+    pub fn missing<'a>(&self, required: &'a [String]) -> Vec<&'a str> {
+        required
+            .iter()
+            .map(String::as_str)
+            .filter(|feature| !self.contains(feature))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_feature_is_always_granted() {
+        let set = LicenseFeatureSet::default();
+        assert!(set.contains(BASE_FEATURE));
+        assert!(!set.contains("gts.x.core.lic.feat.v1~acme.reports.export.v1"));
+    }
+
+    #[test]
+    fn missing_lists_ungranted_features() {
+        let set = LicenseFeatureSet::new(["a"]);
+        let required = vec!["a".to_owned(), "b".to_owned(), BASE_FEATURE.to_owned()];
+        assert_eq!(set.missing(&required), vec!["b"]);
+    }
+}
@@ -0,0 +1,32 @@
+//! Plugin API trait for license provider implementations.
+//!
+//! Plugins implement this trait to provide licensed feature sets.
+//! The gateway discovers plugins via GTS types-registry and delegates
+//! API calls to the selected plugin.
+
+use async_trait::async_trait;
+use modkit_security::SecurityContext;
+
+use crate::error::LicenseResolverError;
+use crate::models::LicenseFeatureSet;
+
+/// Plugin API trait for license provider implementations.
+///
+/// Each plugin registers this trait with a scoped `ClientHub` entry
+/// using its GTS instance ID as the scope.
+#[async_trait]
+pub trait LicenseProviderPluginClient: Send + Sync {
+    /// Get the global features licensed to the tenant in `ctx`.
+    ///
+    /// The tenant is taken from `ctx.tenant_id()`; a nil tenant means the
+    /// caller is anonymous and only tenant-independent features apply.
+    ///
+    /// # Errors
+    ///
+    /// - `LicenseExpired` if the license is no longer valid
+    /// - `InvalidLicense` if the license cannot be verified
+    async fn get_features(
+        &self,
+        ctx: &SecurityContext,
+    ) -> Result<LicenseFeatureSet, LicenseResolverError>;
+}
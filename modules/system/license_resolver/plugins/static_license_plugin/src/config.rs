@@ -0,0 +1,33 @@
+//! Configuration for the static license plugin.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// Plugin configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaticLicensePluginConfig {
+    /// Vendor name for GTS instance registration.
+    pub vendor: String,
+
+    /// Plugin priority (lower = higher priority).
+    pub priority: i16,
+
+    /// Path to the signed license (compact JWS).
+    pub license_file: PathBuf,
+
+    /// Ed25519 public key (PEM) the license must be signed with.
+    pub public_key: String,
+}
+
+impl Default for StaticLicensePluginConfig {
+    fn default() -> Self {
+        Self {
+            vendor: "hyperspot".to_owned(),
+            priority: 100,
+            license_file: PathBuf::from("license.jws"),
+            public_key: String::new(),
+        }
+    }
+}
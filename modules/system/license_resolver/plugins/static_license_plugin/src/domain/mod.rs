@@ -0,0 +1,6 @@
+//! Domain layer for the static license plugin.
+
+mod client;
+pub mod service;
+
+pub use service::Service;
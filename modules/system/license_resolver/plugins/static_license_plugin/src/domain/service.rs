@@ -0,0 +1,102 @@
+//! Domain service for the static license plugin.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use license_resolver_sdk::LicenseResolverError;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Claims carried by a signed license.
+#[derive(Debug, Deserialize)]
+struct LicenseClaims {
+    /// Licensee name.
+    #[serde(default)]
+    sub: Option<String>,
+
+    /// Expiry (seconds since epoch); absent = perpetual license.
+    #[serde(default)]
+    exp: Option<i64>,
+
+    /// Features granted to every tenant.
+    #[serde(default)]
+    features: Vec<String>,
+
+    /// Additional features per tenant.
+    #[serde(default)]
+    tenants: HashMap<Uuid, Vec<String>>,
+}
+
+/// Static license service.
+///
+/// Holds the feature grants of a license verified at load time.
+pub struct Service {
+    /// Features granted to every tenant (including anonymous callers).
+    pub(super) global: BTreeSet<String>,
+
+    /// Additional features by tenant.
+    pub(super) tenants: HashMap<Uuid, BTreeSet<String>>,
+
+    /// License expiry.
+    pub(super) expires_at: Option<DateTime<Utc>>,
+
+    licensee: Option<String>,
+}
+
+impl Service {
+    /// Verifies `token` against the Ed25519 `public_key_pem` and loads its grants.
+    ///
+    /// Expiry is not checked here: an expired license still loads and every
+    /// lookup reports `LicenseExpired`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLicense` if the key cannot be parsed, the signature does not
+    /// verify or the claims are malformed.
+    pub fn load(token: &str, public_key_pem: &str) -> Result<Self, LicenseResolverError> {
+        let key = DecodingKey::from_ed_pem(public_key_pem.as_bytes())
+            .map_err(|e| LicenseResolverError::InvalidLicense(format!("public key: {e}")))?;
+
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.required_spec_claims = HashSet::new();
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+
+        let claims = jsonwebtoken::decode::<LicenseClaims>(token, &key, &validation)
+            .map_err(|e| LicenseResolverError::InvalidLicense(e.to_string()))?
+            .claims;
+
+        let expires_at = claims
+            .exp
+            .map(|exp| {
+                DateTime::from_timestamp(exp, 0).ok_or_else(|| {
+                    LicenseResolverError::InvalidLicense(format!("exp out of range: {exp}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            global: claims.features.into_iter().collect(),
+            tenants: claims
+                .tenants
+                .into_iter()
+                .map(|(tenant, features)| (tenant, features.into_iter().collect()))
+                .collect(),
+            expires_at,
+            licensee: claims.sub,
+        })
+    }
+
+    /// Licensee named in the license.
+    #[must_use]
+    pub fn licensee(&self) -> Option<&str> {
+        self.licensee.as_deref()
+    }
+
+    /// License expiry (`None` = perpetual).
+    #[must_use]
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+}
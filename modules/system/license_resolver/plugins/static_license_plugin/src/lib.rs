@@ -0,0 +1,39 @@
+//! Static License Plugin
+//!
+//! This plugin serves license features from an offline, signed license file.
+//! The license is a compact JWS (`alg: EdDSA`) whose claims list the features
+//! granted to every tenant plus per-tenant additions:
+//!
+//! ```json
+//! {
+//!   "sub": "Acme Corp",
+//!   "exp": 1798761600,
+//!   "features": ["gts.x.core.lic.feat.v1~acme.reports.basic.v1"],
+//!   "tenants": {
+//!     "550e8400-e29b-41d4-a716-446655440001": ["gts.x.core.lic.feat.v1~acme.reports.export.v1"]
+//!   }
+//! }
+//! ```
+//!
+//! ## Configuration
+//!
+//! ```yaml
+//! modules:
+//!   static_license_plugin:
+//!     config:
+//!       vendor: "hyperspot"
+//!       priority: 100
+//!       license_file: "/etc/hyperspot/license.jws"
+//!       public_key: |
+//!         -----BEGIN PUBLIC KEY-----
+//!         ...
+//!         -----END PUBLIC KEY-----
+//! ```
+
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+pub mod config;
+pub mod domain;
+pub mod module;
+
+pub use module::StaticLicensePlugin;
@@ -0,0 +1,93 @@
+//! Static license plugin module.
+
+use std::sync::{Arc, OnceLock};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use license_resolver_sdk::{LicenseProviderPluginClient, LicenseProviderPluginSpecV1};
+use modkit::Module;
+use modkit::client_hub::ClientScope;
+use modkit::context::ModuleCtx;
+use modkit::gts::BaseModkitPluginV1;
+use tracing::info;
+use types_registry_sdk::TypesRegistryClient;
+
+use crate::config::StaticLicensePluginConfig;
+use crate::domain::Service;
+
+/// Static license plugin module.
+///
+/// Serves license features from a signed license file verified at startup.
+///
+/// **Plugin registration pattern:**
+/// - Gateway registers the plugin schema (GTS type definition)
+/// - This plugin registers its instance (implementation metadata)
+/// - This plugin registers its scoped client (implementation in `ClientHub`)
+#[modkit::module(
+    name = "static_license_plugin",
+    deps = ["types_registry"]
+)]
+pub struct StaticLicensePlugin {
+    service: OnceLock<Arc<Service>>,
+}
+
+impl Default for StaticLicensePlugin {
+    fn default() -> Self {
+        Self {
+            service: OnceLock::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Module for StaticLicensePlugin {
+    async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+        info!("Initializing static_license_plugin");
+
+        // Load and verify the license
+        let cfg: StaticLicensePluginConfig = ctx.config()?;
+        let token = std::fs::read_to_string(&cfg.license_file).with_context(|| {
+            format!("Failed to read license file {}", cfg.license_file.display())
+        })?;
+        let service = Arc::new(Service::load(token.trim(), &cfg.public_key)?);
+        info!(
+            vendor = %cfg.vendor,
+            priority = cfg.priority,
+            licensee = service.licensee().unwrap_or("<unnamed>"),
+            expires_at = ?service.expires_at(),
+            "Loaded license"
+        );
+
+        // Generate plugin instance ID
+        let instance_id = LicenseProviderPluginSpecV1::gts_make_instance_id(
+            "hyperspot.builtin.static_license_resolver.plugin.v1",
+        );
+
+        // Register plugin instance in types-registry
+        let registry = ctx.client_hub().get::<dyn TypesRegistryClient>()?;
+        let instance = BaseModkitPluginV1::<LicenseProviderPluginSpecV1> {
+            id: instance_id.clone(),
+            vendor: cfg.vendor.clone(),
+            priority: cfg.priority,
+            properties: LicenseProviderPluginSpecV1,
+        };
+        let instance_json = serde_json::to_value(&instance)?;
+
+        let _ = registry.register(vec![instance_json]).await?;
+
+        self.service
+            .set(service.clone())
+            .map_err(|_| anyhow::anyhow!("Service already initialized"))?;
+
+        // Register scoped client in ClientHub
+        let api: Arc<dyn LicenseProviderPluginClient> = service;
+        ctx.client_hub()
+            .register_scoped::<dyn LicenseProviderPluginClient>(
+                ClientScope::gts_id(&instance_id),
+                api,
+            );
+
+        info!(instance_id = %instance_id, "Static license plugin initialized");
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+//! gRPC server implementation for `EventBusService`
+//!
+//! This module exposes the host's event bus to out-of-process modules.
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+use cf_system_sdks::events::{
+    DeclareRequest, EventBackend, EventBusService, EventBusServiceServer, EventError,
+    PublishRequest, SubscribeItem, SubscribeRequest, SubscribeResponse, TopicSpec, events::Topic,
+};
+
+type SubscribeStream = Pin<Box<dyn Stream<Item = Result<SubscribeResponse, Status>> + Send>>;
+
+/// gRPC service implementation of the event bus
+#[derive(Clone)]
+pub struct EventBusServiceImpl {
+    backend: Arc<dyn EventBackend>,
+}
+
+impl EventBusServiceImpl {
+    pub fn new(backend: Arc<dyn EventBackend>) -> Self {
+        Self { backend }
+    }
+}
+
+fn topic_spec(topic: Option<Topic>) -> Result<TopicSpec, Status> {
+    let topic = topic.ok_or_else(|| Status::invalid_argument("topic is required"))?;
+    Ok(TopicSpec::try_from(topic)?)
+}
+
+#[tonic::async_trait]
+impl EventBusService for EventBusServiceImpl {
+    type SubscribeStream = SubscribeStream;
+
+    async fn declare(&self, request: Request<DeclareRequest>) -> Result<Response<()>, Status> {
+        let topic = topic_spec(request.into_inner().topic)?;
+        self.backend.declare(&topic).await?;
+        Ok(Response::new(()))
+    }
+
+    async fn publish(&self, request: Request<PublishRequest>) -> Result<Response<()>, Status> {
+        let req = request.into_inner();
+        let topic = topic_spec(req.topic)?;
+        self.backend.publish(&topic, req.payload.into()).await?;
+        Ok(Response::new(()))
+    }
+
+    async fn subscribe(
+        &self,
+        request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let topic = topic_spec(request.into_inner().topic)?;
+        let events = self.backend.subscribe(&topic).await?;
+
+        let stream = events.map(|event| {
+            let item = match event {
+                Ok(payload) => SubscribeItem::Payload(payload.to_vec()),
+                Err(EventError::Lagged(missed)) => SubscribeItem::Lagged(missed),
+                Err(e) => return Err(Status::from(e)),
+            };
+            Ok(SubscribeResponse { item: Some(item) })
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// Helper to create a `EventBusServiceServer` with the given backend
+pub fn make_event_bus_service(
+    backend: Arc<dyn EventBackend>,
+) -> EventBusServiceServer<EventBusServiceImpl> {
+    EventBusServiceServer::new(EventBusServiceImpl::new(backend))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use cf_system_sdks::events::BackpressurePolicy;
+    use modkit::events::LocalEventBackend;
+
+    fn topic() -> Topic {
+        Topic::from(&TopicSpec::new(
+            "users.created",
+            2,
+            BackpressurePolicy::DropOldest,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_streams_payloads_and_lag() {
+        let backend = Arc::new(LocalEventBackend::new());
+        let service = EventBusServiceImpl::new(backend);
+
+        let mut events = service
+            .subscribe(Request::new(SubscribeRequest {
+                topic: Some(topic()),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        for payload in ["1", "2", "3"] {
+            service
+                .publish(Request::new(PublishRequest {
+                    topic: Some(topic()),
+                    payload: payload.as_bytes().to_vec(),
+                }))
+                .await
+                .unwrap();
+        }
+
+        let mut items = Vec::new();
+        for _ in 0..3 {
+            items.push(events.next().await.unwrap().unwrap().item.unwrap());
+        }
+        assert_eq!(
+            items,
+            vec![
+                SubscribeItem::Lagged(1),
+                SubscribeItem::Payload(b"2".to_vec()),
+                SubscribeItem::Payload(b"3".to_vec()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_or_conflicting_topic_is_rejected() {
+        let service = EventBusServiceImpl::new(Arc::new(LocalEventBackend::new()));
+
+        let status = service
+            .declare(Request::new(DeclareRequest { topic: None }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        service
+            .declare(Request::new(DeclareRequest {
+                topic: Some(topic()),
+            }))
+            .await
+            .unwrap();
+        let mut conflicting = topic();
+        conflicting.capacity = 4;
+        let status = service
+            .declare(Request::new(DeclareRequest {
+                topic: Some(conflicting),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    }
+}
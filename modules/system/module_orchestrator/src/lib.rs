@@ -1,7 +1,8 @@
 //! Module Orchestrator
 //!
 //! System module for service discovery.
-//! This module provides `DirectoryService` for gRPC service registration and discovery.
+//! This module provides `DirectoryService` for gRPC service registration and discovery,
+//! and `EventBusService` to bridge the host's event bus to out-of-process modules.
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 // === MODULE DEFINITION ===
@@ -9,6 +10,7 @@ pub mod module;
 pub use module::{ModuleOrchestrator, ModuleOrchestratorConfig};
 
 // === INTERNAL MODULES ===
+mod event_bus_server;
 mod server;
 
 // === RE-EXPORTS ===
@@ -9,11 +9,13 @@ use modkit::DirectoryClient;
 use modkit::context::ModuleCtx;
 use modkit::contracts::{GrpcServiceCapability, RegisterGrpcServiceFn, SystemCapability};
 use modkit::directory::LocalDirectoryClient;
+use modkit::events::EventBus;
 use modkit::runtime::ModuleManager;
 
 use cf_system_sdks::directory::DIRECTORY_SERVICE_NAME;
+use cf_system_sdks::events::EVENT_BUS_SERVICE_NAME;
 
-use crate::server;
+use crate::{event_bus_server, server};
 
 /// Configuration for the module orchestrator
 #[derive(Clone, Debug, Default, serde::Deserialize)]
@@ -24,6 +26,7 @@ pub struct ModuleOrchestratorConfig;
 /// This module:
 /// - Provides `DirectoryClient` to the `ClientHub` for in-process modules
 /// - Exposes `DirectoryService` gRPC service via `grpc_hub`
+/// - Exposes the host's `EventBus` as `EventBusService` for `OoP` modules
 /// - Tracks module instances and provides service resolution
 #[modkit::module(
     name = "module_orchestrator",
@@ -88,7 +91,7 @@ impl modkit::Module for ModuleOrchestrator {
 /// Export gRPC services to `grpc_hub`
 #[async_trait]
 impl GrpcServiceCapability for ModuleOrchestrator {
-    async fn get_grpc_services(&self, _ctx: &ModuleCtx) -> Result<Vec<RegisterGrpcServiceFn>> {
+    async fn get_grpc_services(&self, ctx: &ModuleCtx) -> Result<Vec<RegisterGrpcServiceFn>> {
         let api = self
             .directory_api
             .get()
@@ -98,11 +101,23 @@ impl GrpcServiceCapability for ModuleOrchestrator {
         // Build DirectoryService
         let directory_svc = server::make_directory_service(api);
 
-        Ok(vec![RegisterGrpcServiceFn {
-            service_name: DIRECTORY_SERVICE_NAME,
-            register: Box::new(move |routes| {
-                routes.add_service(directory_svc.clone());
-            }),
-        }])
+        // Build EventBusService over the host's in-process bus
+        let bus = ctx.client_hub().get::<EventBus>()?;
+        let event_bus_svc = event_bus_server::make_event_bus_service(bus.backend());
+
+        Ok(vec![
+            RegisterGrpcServiceFn {
+                service_name: DIRECTORY_SERVICE_NAME,
+                register: Box::new(move |routes| {
+                    routes.add_service(directory_svc.clone());
+                }),
+            },
+            RegisterGrpcServiceFn {
+                service_name: EVENT_BUS_SERVICE_NAME,
+                register: Box::new(move |routes| {
+                    routes.add_service(event_bus_svc.clone());
+                }),
+            },
+        ])
     }
 }
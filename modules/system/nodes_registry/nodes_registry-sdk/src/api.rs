@@ -1,5 +1,5 @@
 use crate::error::NodesRegistryError;
-use crate::{Node, NodeSysCap, NodeSysInfo};
+use crate::{Node, NodeStatus, NodeSysCap, NodeSysInfo};
 
 /// Client trait for accessing nodes registry functionality
 #[async_trait::async_trait]
@@ -18,4 +18,7 @@ pub trait NodesRegistryClient: Send + Sync {
 
     /// Get system capabilities for a node
     async fn get_node_syscap(&self, node_id: uuid::Uuid) -> Result<NodeSysCap, NodesRegistryError>;
+
+    /// Get the liveness of a node
+    async fn get_node_status(&self, node_id: uuid::Uuid) -> Result<NodeStatus, NodesRegistryError>;
 }
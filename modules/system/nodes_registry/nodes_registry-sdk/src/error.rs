@@ -10,9 +10,15 @@ pub enum NodesRegistryError {
     #[error("Failed to collect system capabilities: {0}")]
     SysCapCollectionFailed(String),
 
+    #[error("Node {0} has not reported {1} yet")]
+    NotReported(uuid::Uuid, String),
+
     #[error("Invalid input: {0}")]
     Validation(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("An internal error occurred")]
     Internal,
 }
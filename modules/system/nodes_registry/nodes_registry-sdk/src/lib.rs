@@ -3,9 +3,11 @@
 
 pub mod api;
 pub mod error;
+pub mod models;
 
 pub use api::NodesRegistryClient;
 pub use error::NodesRegistryError;
+pub use models::NodeStatus;
 
 pub use modkit_node_info::{
     BatteryInfo, CpuInfo, GpuInfo, HostInfo, MemoryInfo, Node, NodeSysCap, NodeSysInfo, OsInfo,
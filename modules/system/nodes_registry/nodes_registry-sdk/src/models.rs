@@ -0,0 +1,8 @@
+/// Liveness of a node as seen by the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeStatus {
+    /// The node hosts the registry, or it has reported within the node TTL
+    Online,
+    /// The node has not reported for longer than the node TTL
+    Offline,
+}
@@ -11,6 +11,9 @@ pub struct NodeDto {
     pub ip_address: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub status: NodeStatusDto,
+    /// Last heartbeat or report from the node
+    pub last_seen_at: chrono::DateTime<chrono::Utc>,
     /// System information (included when details=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sysinfo: Option<NodeSysInfoDto>,
@@ -19,6 +22,23 @@ pub struct NodeDto {
     pub syscap: Option<NodeSysCapDto>,
 }
 
+/// Node liveness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeStatusDto {
+    Online,
+    Offline,
+}
+
+/// Node registration request, sent by node agents
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RegisterNodeRequest {
+    pub id: Uuid,
+    pub hostname: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+}
+
 /// System information response DTO
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NodeSysInfoDto {
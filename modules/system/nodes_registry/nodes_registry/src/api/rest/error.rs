@@ -33,12 +33,24 @@ pub fn domain_error_to_problem(err: DomainError, instance: &str) -> Problem {
         .with_type("https://errors.hyperspot.com/SYSCAP_COLLECTION_FAILED")
         .with_code("SYSCAP_COLLECTION_FAILED")
         .with_instance(instance),
+        DomainError::NotReported(id, what) => Problem::new(
+            StatusCode::NOT_FOUND,
+            "Not reported",
+            format!("Node {id} has not reported {what} yet"),
+        )
+        .with_type("https://errors.hyperspot.com/NODES_NOT_REPORTED")
+        .with_code("NODES_NOT_REPORTED")
+        .with_instance(instance),
         DomainError::InvalidInput(msg) => {
             Problem::new(StatusCode::BAD_REQUEST, "Validation error", msg)
                 .with_type("https://errors.hyperspot.com/VALIDATION_ERROR")
                 .with_code("VALIDATION_ERROR")
                 .with_instance(instance)
         }
+        DomainError::Forbidden(msg) => Problem::new(StatusCode::FORBIDDEN, "Forbidden", msg)
+            .with_type("https://errors.hyperspot.com/NODES_FORBIDDEN")
+            .with_code("NODES_FORBIDDEN")
+            .with_instance(instance),
         DomainError::Internal(msg) => Problem::new(
             StatusCode::INTERNAL_SERVER_ERROR,
             "Internal server error",
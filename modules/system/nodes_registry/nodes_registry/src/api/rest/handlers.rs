@@ -1,12 +1,15 @@
 use axum::{
     Extension,
     extract::{Path, Query},
+    response::Response,
 };
 use modkit::api::prelude::*;
+use modkit_auth::axum_ext::Authz;
+use nodes_registry_sdk::{Node, NodeStatus};
 use serde::Deserialize;
 use std::sync::Arc;
 
-use super::dto::{NodeDto, NodeSysCapDto, NodeSysInfoDto};
+use super::dto::{NodeDto, NodeSysCapDto, NodeSysInfoDto, RegisterNodeRequest};
 use crate::domain::service::Service;
 
 #[derive(Debug, Deserialize)]
@@ -42,14 +45,16 @@ pub async fn list_nodes(
                 .ok()
                 .map(Into::into);
 
-            let mut node_dto: NodeDto = node.into();
+            let mut node_dto = node_dto(&svc, node);
             node_dto.sysinfo = sysinfo;
             node_dto.syscap = syscap;
             detailed_nodes.push(node_dto);
         }
         Ok(Json(detailed_nodes))
     } else {
-        Ok(Json(nodes.into_iter().map(Into::into).collect()))
+        Ok(Json(
+            nodes.into_iter().map(|node| node_dto(&svc, node)).collect(),
+        ))
     }
 }
 
@@ -68,12 +73,12 @@ pub async fn get_node(
             .ok()
             .map(Into::into);
 
-        let mut node_dto: NodeDto = node.into();
+        let mut node_dto = node_dto(&svc, node);
         node_dto.sysinfo = sysinfo;
         node_dto.syscap = syscap;
         Ok(Json(node_dto))
     } else {
-        Ok(Json(node.into()))
+        Ok(Json(node_dto(&svc, node)))
     }
 }
 
@@ -95,3 +100,51 @@ pub async fn get_node_syscap(
     let syscap = svc.get_node_syscap(node_id, query.force_refresh)?;
     Ok(Json(syscap.into()))
 }
+
+/// Register a node (called by node agents)
+pub async fn register_node(
+    Authz(ctx): Authz,
+    Extension(svc): Extension<Arc<Service>>,
+    Json(req): Json<RegisterNodeRequest>,
+) -> ApiResult<Json<NodeDto>> {
+    let node = svc.register_node(&ctx, req.into()).await?;
+    Ok(Json(node_dto(&svc, node)))
+}
+
+/// Record a heartbeat from a node
+pub async fn heartbeat(
+    Authz(ctx): Authz,
+    Extension(svc): Extension<Arc<Service>>,
+    Path(node_id): Path<uuid::Uuid>,
+) -> ApiResult<Response> {
+    svc.heartbeat(&ctx, node_id).await?;
+    Ok(no_content().into_response())
+}
+
+/// Store system information reported by a node
+pub async fn report_sysinfo(
+    Authz(ctx): Authz,
+    Extension(svc): Extension<Arc<Service>>,
+    Path(node_id): Path<uuid::Uuid>,
+    Json(req): Json<NodeSysInfoDto>,
+) -> ApiResult<Response> {
+    svc.report_sysinfo(&ctx, node_id, req.into()).await?;
+    Ok(no_content().into_response())
+}
+
+/// Store system capabilities reported by a node
+pub async fn report_syscap(
+    Authz(ctx): Authz,
+    Extension(svc): Extension<Arc<Service>>,
+    Path(node_id): Path<uuid::Uuid>,
+    Json(req): Json<NodeSysCapDto>,
+) -> ApiResult<Response> {
+    svc.report_syscap(&ctx, node_id, req.into()).await?;
+    Ok(no_content().into_response())
+}
+
+fn node_dto(svc: &Service, node: Node) -> NodeDto {
+    let status = svc.node_status(node.id).unwrap_or(NodeStatus::Offline);
+    let last_seen_at = svc.last_seen_at(node.id).unwrap_or(node.updated_at);
+    NodeDto::from_node(node, status, last_seen_at)
+}
@@ -1,27 +1,66 @@
 use super::dto::{
-    BatteryInfoDto, CpuInfoDto, GpuInfoDto, HostInfoDto, MemoryInfoDto, NodeDto, NodeSysCapDto,
-    NodeSysInfoDto, OsInfoDto, SysCapDto,
+    BatteryInfoDto, CpuInfoDto, GpuInfoDto, HostInfoDto, MemoryInfoDto, NodeDto, NodeStatusDto,
+    NodeSysCapDto, NodeSysInfoDto, OsInfoDto, RegisterNodeRequest, SysCapDto,
 };
 use nodes_registry_sdk::{
-    BatteryInfo, CpuInfo, GpuInfo, HostInfo, MemoryInfo, Node, NodeSysCap, NodeSysInfo, OsInfo,
-    SysCap,
+    BatteryInfo, CpuInfo, GpuInfo, HostInfo, MemoryInfo, Node, NodeStatus, NodeSysCap, NodeSysInfo,
+    OsInfo, SysCap,
 };
 
 // Node mappings
-impl From<Node> for NodeDto {
-    fn from(node: Node) -> Self {
+impl NodeDto {
+    #[must_use]
+    pub fn from_node(
+        node: Node,
+        status: NodeStatus,
+        last_seen_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
         Self {
             id: node.id,
             hostname: node.hostname,
             ip_address: node.ip_address,
             created_at: node.created_at,
             updated_at: node.updated_at,
+            status: status.into(),
+            last_seen_at,
             sysinfo: None,
             syscap: None,
         }
     }
 }
 
+impl From<NodeStatus> for NodeStatusDto {
+    fn from(status: NodeStatus) -> Self {
+        match status {
+            NodeStatus::Online => Self::Online,
+            NodeStatus::Offline => Self::Offline,
+        }
+    }
+}
+
+impl From<RegisterNodeRequest> for Node {
+    fn from(req: RegisterNodeRequest) -> Self {
+        let now = chrono::Utc::now();
+        Self {
+            id: req.id,
+            hostname: req.hostname,
+            ip_address: req.ip_address,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl From<&Node> for RegisterNodeRequest {
+    fn from(node: &Node) -> Self {
+        Self {
+            id: node.id,
+            hostname: node.hostname.clone(),
+            ip_address: node.ip_address.clone(),
+        }
+    }
+}
+
 // SysInfo mappings
 impl From<NodeSysInfo> for NodeSysInfoDto {
     fn from(info: NodeSysInfo) -> Self {
@@ -128,3 +167,109 @@ impl From<SysCap> for SysCapDto {
         }
     }
 }
+
+// Mappings of reports pushed by node agents
+impl From<NodeSysInfoDto> for NodeSysInfo {
+    fn from(info: NodeSysInfoDto) -> Self {
+        Self {
+            node_id: info.node_id,
+            os: info.os.into(),
+            cpu: info.cpu.into(),
+            memory: info.memory.into(),
+            host: info.host.into(),
+            gpus: info.gpus.into_iter().map(Into::into).collect(),
+            battery: info.battery.map(Into::into),
+            collected_at: info.collected_at,
+        }
+    }
+}
+
+impl From<OsInfoDto> for OsInfo {
+    fn from(info: OsInfoDto) -> Self {
+        Self {
+            name: info.name,
+            version: info.version,
+            arch: info.arch,
+        }
+    }
+}
+
+impl From<CpuInfoDto> for CpuInfo {
+    fn from(info: CpuInfoDto) -> Self {
+        Self {
+            model: info.model,
+            num_cpus: info.num_cpus,
+            cores: info.cores,
+            frequency_mhz: info.frequency_mhz,
+        }
+    }
+}
+
+impl From<MemoryInfoDto> for MemoryInfo {
+    fn from(info: MemoryInfoDto) -> Self {
+        Self {
+            total_bytes: info.total_bytes,
+            available_bytes: info.available_bytes,
+            used_bytes: info.used_bytes,
+            used_percent: info.used_percent,
+        }
+    }
+}
+
+impl From<HostInfoDto> for HostInfo {
+    fn from(info: HostInfoDto) -> Self {
+        Self {
+            hostname: info.hostname,
+            uptime_seconds: info.uptime_seconds,
+            ip_addresses: info.ip_addresses,
+        }
+    }
+}
+
+impl From<GpuInfoDto> for GpuInfo {
+    fn from(info: GpuInfoDto) -> Self {
+        Self {
+            model: info.model,
+            cores: info.cores,
+            total_memory_mb: info.total_memory_mb,
+            used_memory_mb: info.used_memory_mb,
+        }
+    }
+}
+
+impl From<BatteryInfoDto> for BatteryInfo {
+    fn from(info: BatteryInfoDto) -> Self {
+        Self {
+            on_battery: info.on_battery,
+            percentage: info.percentage,
+        }
+    }
+}
+
+impl From<NodeSysCapDto> for NodeSysCap {
+    fn from(cap: NodeSysCapDto) -> Self {
+        Self {
+            node_id: cap.node_id,
+            capabilities: cap.capabilities.into_iter().map(Into::into).collect(),
+            collected_at: cap.collected_at,
+        }
+    }
+}
+
+impl From<SysCapDto> for SysCap {
+    fn from(cap: SysCapDto) -> Self {
+        Self {
+            key: cap.key,
+            category: cap.category,
+            name: cap.name,
+            display_name: cap.display_name,
+            present: cap.present,
+            version: cap.version,
+            amount: cap.amount,
+            amount_dimension: cap.amount_dimension,
+            details: cap.details,
+            cache_ttl_secs: cap.cache_ttl_secs,
+            fetched_at_secs: cap.fetched_at_secs,
+        }
+    }
+}
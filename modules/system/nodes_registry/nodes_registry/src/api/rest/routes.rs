@@ -1,12 +1,44 @@
 use axum::http;
 use axum::{Extension, Router};
+use modkit::api::operation_builder::{AuthReqAction, AuthReqResource, LicenseFeature};
 use modkit::api::{Missing, OpenApiRegistry, OperationBuilder};
 use std::sync::Arc;
 
-use super::dto::{NodeDto, NodeSysCapDto, NodeSysInfoDto};
+use super::dto::{NodeDto, NodeSysCapDto, NodeSysInfoDto, RegisterNodeRequest};
 use super::handlers;
 use crate::domain::service::Service;
 
+struct Nodes;
+
+impl AsRef<str> for Nodes {
+    fn as_ref(&self) -> &'static str {
+        "nodes"
+    }
+}
+
+impl AuthReqResource for Nodes {}
+
+/// Reporting is the only write access node agents need
+struct Report;
+
+impl AsRef<str> for Report {
+    fn as_ref(&self) -> &'static str {
+        "report"
+    }
+}
+
+impl AuthReqAction for Report {}
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
 /// Register all REST routes for the nodes registry module
 pub fn register_routes(
     mut router: Router,
@@ -76,6 +108,64 @@ pub fn register_routes(
         .error_500(openapi)
         .register(router, openapi);
 
+    // POST /nodes - Register a node (node agents)
+    router = OperationBuilder::<Missing, Missing, ()>::post("/nodes-registry/v1/nodes")
+        .operation_id("nodes_registry.register_node")
+        .summary("Register node")
+        .description("Register a node running in node-agent mode, or refresh its hostname and IP address. Registering also counts as a heartbeat. The caller becomes the owner of a new node; a node owned by another subject cannot be registered again.")
+        .tag("nodes")
+        .require_auth(&Nodes, &Report)
+        .require_license_features::<License>([])
+        .json_request::<RegisterNodeRequest>(openapi, "Node to register")
+        .handler(handlers::register_node)
+        .json_response_with_schema::<NodeDto>(openapi, http::StatusCode::OK, "Registered node")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // POST /nodes/{id}/heartbeat - Keep a node online (node agents)
+    router = OperationBuilder::<Missing, Missing, ()>::post("/nodes-registry/v1/nodes/{id}/heartbeat")
+        .operation_id("nodes_registry.heartbeat")
+        .summary("Node heartbeat")
+        .description("Mark a registered node as seen. Nodes without a heartbeat or report within the node TTL are reported offline. Only the subject that registered the node may send heartbeats.")
+        .tag("nodes")
+        .require_auth(&Nodes, &Report)
+        .require_license_features::<License>([])
+        .path_param("id", "Node UUID")
+        .handler(handlers::heartbeat)
+        .json_response(http::StatusCode::NO_CONTENT, "Heartbeat recorded")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // PUT /nodes/{id}/sysinfo - Report system information (node agents)
+    router = OperationBuilder::<Missing, Missing, ()>::put("/nodes-registry/v1/nodes/{id}/sysinfo")
+        .operation_id("nodes_registry.report_node_sysinfo")
+        .summary("Report node system information")
+        .description("Replace the system information of a registered node with the one collected by its agent. Only the subject that registered the node may report it.")
+        .tag("nodes")
+        .require_auth(&Nodes, &Report)
+        .require_license_features::<License>([])
+        .path_param("id", "Node UUID")
+        .json_request::<NodeSysInfoDto>(openapi, "System information")
+        .handler(handlers::report_sysinfo)
+        .json_response(http::StatusCode::NO_CONTENT, "System information stored")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // PUT /nodes/{id}/syscap - Report system capabilities (node agents)
+    router = OperationBuilder::<Missing, Missing, ()>::put("/nodes-registry/v1/nodes/{id}/syscap")
+        .operation_id("nodes_registry.report_node_syscap")
+        .summary("Report node system capabilities")
+        .description("Replace the system capabilities of a registered node with the ones collected by its agent. Custom capabilities are kept. Only the subject that registered the node may report them.")
+        .tag("nodes")
+        .require_auth(&Nodes, &Report)
+        .require_license_features::<License>([])
+        .path_param("id", "Node UUID")
+        .json_request::<NodeSysCapDto>(openapi, "System capabilities")
+        .handler(handlers::report_syscap)
+        .json_response(http::StatusCode::NO_CONTENT, "System capabilities stored")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
     // Attach service to router as extension
     router = router.layer(Extension(service));
 
@@ -7,16 +7,66 @@ pub struct NodesRegistryConfig {
     /// Enable/disable the nodes registry module
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+
+    /// Seconds without a heartbeat or report after which a remote node is reported offline
+    #[serde(default = "default_node_ttl_secs")]
+    pub node_ttl_secs: u64,
+
+    /// Register this node with a remote registry (node-agent mode)
+    #[serde(default)]
+    pub agent: Option<AgentConfig>,
+}
+
+/// Node-agent settings: where to register and how often to report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AgentConfig {
+    /// Base URL of the instance hosting the registry, e.g. `http://registry:8080`
+    pub registry_url: String,
+
+    /// Bearer token sent with every request
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Seconds between heartbeats; keep it well below the registry's `node_ttl_secs`
+    #[serde(default = "default_heartbeat_interval_secs")]
+    pub heartbeat_interval_secs: u64,
+
+    /// Seconds between sysinfo and syscap reports
+    #[serde(default = "default_report_interval_secs")]
+    pub report_interval_secs: u64,
+
+    /// Timeout of each request to the registry, in seconds
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
 }
 
 fn default_enabled() -> bool {
     true
 }
 
+fn default_node_ttl_secs() -> u64 {
+    crate::domain::service::DEFAULT_NODE_TTL.as_secs()
+}
+
+fn default_heartbeat_interval_secs() -> u64 {
+    30
+}
+
+fn default_report_interval_secs() -> u64 {
+    300
+}
+
+fn default_request_timeout_secs() -> u64 {
+    10
+}
+
 impl Default for NodesRegistryConfig {
     fn default() -> Self {
         Self {
             enabled: default_enabled(),
+            node_ttl_secs: default_node_ttl_secs(),
+            agent: None,
         }
     }
 }
@@ -10,9 +10,15 @@ pub enum DomainError {
     #[error("Failed to collect system capabilities: {0}")]
     SysCapCollectionFailed(String),
 
+    #[error("Node {0} has not reported {1} yet")]
+    NotReported(uuid::Uuid, &'static str),
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -46,7 +52,9 @@ impl From<DomainError> for nodes_registry_sdk::NodesRegistryError {
             DomainError::NodeNotFound(id) => Self::NodeNotFound(id),
             DomainError::SysInfoCollectionFailed(msg) => Self::SysInfoCollectionFailed(msg),
             DomainError::SysCapCollectionFailed(msg) => Self::SysCapCollectionFailed(msg),
+            DomainError::NotReported(id, what) => Self::NotReported(id, what.to_owned()),
             DomainError::InvalidInput(msg) => Self::Validation(msg),
+            DomainError::Forbidden(msg) => Self::Forbidden(msg),
             DomainError::Internal(_) => Self::Internal,
         }
     }
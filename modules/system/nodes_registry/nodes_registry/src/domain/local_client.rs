@@ -1,5 +1,7 @@
 use crate::domain::service::Service;
-use nodes_registry_sdk::{Node, NodeSysCap, NodeSysInfo, NodesRegistryClient, NodesRegistryError};
+use nodes_registry_sdk::{
+    Node, NodeStatus, NodeSysCap, NodeSysInfo, NodesRegistryClient, NodesRegistryError,
+};
 use std::sync::Arc;
 
 /// Local client implementation for the nodes registry
@@ -36,4 +38,8 @@ impl NodesRegistryClient for NodesRegistryLocalClient {
             .get_node_syscap(node_id, false)
             .map_err(Into::into)
     }
+
+    async fn get_node_status(&self, node_id: uuid::Uuid) -> Result<NodeStatus, NodesRegistryError> {
+        self.service.node_status(node_id).map_err(Into::into)
+    }
 }
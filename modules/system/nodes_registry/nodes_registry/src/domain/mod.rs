@@ -1,4 +1,5 @@
 pub mod error;
 pub mod local_client;
 pub mod node_storage;
+pub mod repo;
 pub mod service;
@@ -1,9 +1,13 @@
+use chrono::{DateTime, Utc};
 use nodes_registry_sdk::{Node, NodeSysCap, NodeSysInfo, SysCap};
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::sync::RwLock;
 use tracing::warn;
 use uuid::Uuid;
 
+use crate::domain::error::DomainError;
+
 /// Cached node data with timestamps
 #[derive(Debug, Clone)]
 struct CachedNodeData {
@@ -13,6 +17,26 @@ struct CachedNodeData {
     syscap_system: Option<NodeSysCap>,
     /// Custom capabilities set through service interface
     syscap_custom: HashMap<String, SysCap>,
+    /// When the node was last heard from
+    last_seen_at: DateTime<Utc>,
+    /// Subject that registered the node; `None` for the node hosting the registry
+    owner_id: Option<Uuid>,
+    /// Restored from the repository: sysinfo and syscap are missing until reported
+    restored: bool,
+}
+
+impl CachedNodeData {
+    fn new(node: Node, owner_id: Option<Uuid>, last_seen_at: DateTime<Utc>) -> Self {
+        Self {
+            node,
+            sysinfo: None,
+            syscap_system: None,
+            syscap_custom: HashMap::new(),
+            last_seen_at,
+            owner_id,
+            restored: false,
+        }
+    }
 }
 
 /// In-memory storage for nodes and their metadata
@@ -32,15 +56,7 @@ impl NodeStorage {
     pub fn upsert_node(&self, node: Node) {
         match self.nodes.write() {
             Ok(mut nodes) => {
-                nodes.insert(
-                    node.id,
-                    CachedNodeData {
-                        node,
-                        sysinfo: None,
-                        syscap_system: None,
-                        syscap_custom: HashMap::new(),
-                    },
-                );
+                nodes.insert(node.id, CachedNodeData::new(node, None, Utc::now()));
             }
             Err(_) => {
                 warn!("RwLock is poisoned in upsert_node, cannot update node");
@@ -48,6 +64,121 @@ impl NodeStorage {
         }
     }
 
+    /// Register a node reported by its agent, keeping its creation time and cached data
+    /// when it is already known. Returns the stored node.
+    ///
+    /// The first subject to register a node owns it.
+    ///
+    /// # Errors
+    /// Returns `Forbidden` if the node is owned by another subject.
+    pub fn register_node(
+        &self,
+        mut node: Node,
+        owner_id: Uuid,
+        seen_at: DateTime<Utc>,
+    ) -> Result<Node, DomainError> {
+        let Ok(mut nodes) = self.nodes.write() else {
+            warn!("RwLock is poisoned in register_node, cannot update node");
+            return Err(DomainError::Internal(
+                "Node storage is unavailable".to_owned(),
+            ));
+        };
+        match nodes.entry(node.id) {
+            Entry::Occupied(mut entry) => {
+                let data = entry.get_mut();
+                if data.owner_id != Some(owner_id) {
+                    return Err(not_owner(node.id));
+                }
+                node.created_at = data.node.created_at;
+                data.node = node.clone();
+                data.last_seen_at = seen_at;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(CachedNodeData::new(node.clone(), Some(owner_id), seen_at));
+            }
+        }
+        Ok(node)
+    }
+
+    /// Insert a persisted node unless it is already known
+    pub fn restore_node(
+        &self,
+        node: Node,
+        owner_id: Option<Uuid>,
+        last_seen_at: DateTime<Utc>,
+    ) -> bool {
+        if let Ok(mut nodes) = self.nodes.write() {
+            match nodes.entry(node.id) {
+                Entry::Occupied(_) => false,
+                Entry::Vacant(entry) => {
+                    let mut data = CachedNodeData::new(node, owner_id, last_seen_at);
+                    data.restored = true;
+                    entry.insert(data);
+                    true
+                }
+            }
+        } else {
+            warn!("RwLock is poisoned in restore_node, cannot update node");
+            false
+        }
+    }
+
+    /// Check that a node exists and was registered by `owner_id`
+    ///
+    /// # Errors
+    /// Returns `NodeNotFound` for an unknown node and `Forbidden` if the node is owned
+    /// by another subject.
+    pub fn ensure_owner(&self, node_id: Uuid, owner_id: Uuid) -> Result<(), DomainError> {
+        let Ok(nodes) = self.nodes.read() else {
+            warn!("RwLock is poisoned in ensure_owner, cannot access node");
+            return Err(DomainError::Internal(
+                "Node storage is unavailable".to_owned(),
+            ));
+        };
+        match nodes.get(&node_id) {
+            None => Err(DomainError::NodeNotFound(node_id)),
+            Some(data) if data.owner_id == Some(owner_id) => Ok(()),
+            Some(_) => Err(not_owner(node_id)),
+        }
+    }
+
+    /// Record that a node was heard from
+    pub fn touch(&self, node_id: Uuid, seen_at: DateTime<Utc>) -> bool {
+        if let Ok(mut nodes) = self.nodes.write() {
+            if let Some(data) = nodes.get_mut(&node_id) {
+                data.last_seen_at = seen_at;
+                true
+            } else {
+                false
+            }
+        } else {
+            warn!("RwLock is poisoned in touch, cannot update node");
+            false
+        }
+    }
+
+    /// Check whether a restored node still lacks its sysinfo or system syscap
+    pub fn awaits_reports(&self, node_id: Uuid) -> bool {
+        if let Ok(nodes) = self.nodes.read() {
+            nodes.get(&node_id).is_some_and(|data| {
+                data.restored && (data.sysinfo.is_none() || data.syscap_system.is_none())
+            })
+        } else {
+            warn!("RwLock is poisoned in awaits_reports, cannot access node");
+            false
+        }
+    }
+
+    /// Get when a node was last heard from
+    pub fn last_seen(&self, node_id: Uuid) -> Option<DateTime<Utc>> {
+        if let Ok(nodes) = self.nodes.read() {
+            nodes.get(&node_id).map(|data| data.last_seen_at)
+        } else {
+            warn!("RwLock is poisoned in last_seen, cannot access node");
+            None
+        }
+    }
+
     /// Get a node by ID
     pub fn get_node(&self, id: Uuid) -> Option<Node> {
         if let Ok(nodes) = self.nodes.read() {
@@ -259,6 +390,10 @@ impl Default for NodeStorage {
     }
 }
 
+fn not_owner(node_id: Uuid) -> DomainError {
+    DomainError::Forbidden(format!("Node {node_id} is registered by another subject"))
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
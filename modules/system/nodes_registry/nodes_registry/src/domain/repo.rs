@@ -0,0 +1,33 @@
+//! Storage abstraction for persisted node records.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use nodes_registry_sdk::Node;
+use uuid::Uuid;
+
+use super::error::DomainError;
+
+/// A node as persisted between restarts.
+///
+/// Only the node metadata survives a restart: sysinfo and syscap are reported again
+/// by the node's agent, whose heartbeats are refused until it does.
+#[derive(Debug, Clone)]
+pub struct NodeRecord {
+    pub node: Node,
+    /// Subject that registered the node; `None` for the node hosting the registry
+    pub owner_id: Option<Uuid>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+/// Persistence of node records.
+#[async_trait]
+pub trait NodeRepository: Send + Sync {
+    /// Returns all stored nodes.
+    async fn list(&self) -> Result<Vec<NodeRecord>, DomainError>;
+
+    /// Inserts a node record, or replaces the stored one.
+    async fn upsert(&self, record: &NodeRecord) -> Result<(), DomainError>;
+
+    /// Updates when a stored node was last seen.
+    async fn touch(&self, id: Uuid, last_seen_at: DateTime<Utc>) -> Result<(), DomainError>;
+}
@@ -1,8 +1,15 @@
 use crate::domain::error::DomainError;
 use crate::domain::node_storage::NodeStorage;
+use crate::domain::repo::{NodeRecord, NodeRepository};
+use chrono::{DateTime, TimeDelta, Utc};
 use modkit_node_info::NodeInfoCollector;
-use nodes_registry_sdk::{Node, NodeSysCap, NodeSysInfo, SysCap};
+use modkit_security::SecurityContext;
+use nodes_registry_sdk::{Node, NodeStatus, NodeSysCap, NodeSysInfo, SysCap};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How long a remote node may stay silent before it is reported offline
+pub const DEFAULT_NODE_TTL: Duration = Duration::from_secs(90);
 
 /// Check if a UUID is a fallback UUID (hardware detection failed)
 /// Fallback UUIDs have zeros in the first 8 bytes: 00000000-0000-0000-xxxx-xxxxxxxxxxxx
@@ -16,6 +23,10 @@ fn is_fallback_uuid(id: &uuid::Uuid) -> bool {
 pub struct Service {
     storage: Arc<NodeStorage>,
     node_info_collector: Arc<NodeInfoCollector>,
+    /// The node hosting this registry; its data is collected locally
+    local_node_id: uuid::Uuid,
+    node_ttl: TimeDelta,
+    repo: Option<Arc<dyn NodeRepository>>,
 }
 
 impl Service {
@@ -41,11 +52,174 @@ impl Service {
             );
         }
 
+        let local_node_id = current_node.id;
         storage.upsert_node(current_node);
 
         Self {
             storage,
             node_info_collector,
+            local_node_id,
+            node_ttl: ttl_delta(DEFAULT_NODE_TTL),
+            repo: None,
+        }
+    }
+
+    /// Report remote nodes offline after `ttl` without a heartbeat or report
+    #[must_use]
+    pub fn with_node_ttl(mut self, ttl: Duration) -> Self {
+        self.node_ttl = ttl_delta(ttl);
+        self
+    }
+
+    /// Persist node records in `repo`
+    #[must_use]
+    pub fn with_repository(mut self, repo: Arc<dyn NodeRepository>) -> Self {
+        self.repo = Some(repo);
+        self
+    }
+
+    /// ID of the node hosting this registry
+    #[must_use]
+    pub fn local_node_id(&self) -> uuid::Uuid {
+        self.local_node_id
+    }
+
+    /// Load the persisted nodes and persist the local one.
+    ///
+    /// Returns the number of nodes restored from the repository.
+    pub async fn restore(&self) -> Result<usize, DomainError> {
+        let Some(repo) = &self.repo else {
+            return Ok(0);
+        };
+
+        let mut restored = 0;
+        for record in repo.list().await? {
+            if self
+                .storage
+                .restore_node(record.node, record.owner_id, record.last_seen_at)
+            {
+                restored += 1;
+            }
+        }
+
+        let local = self.get_node(self.local_node_id)?;
+        repo.upsert(&NodeRecord {
+            node: local,
+            owner_id: None,
+            last_seen_at: Utc::now(),
+        })
+        .await?;
+
+        Ok(restored)
+    }
+
+    /// Register a remote node, or refresh the metadata of a known one.
+    ///
+    /// The subject of `ctx` becomes the owner of a new node; only the owner may
+    /// register it again or report for it. The timestamps of `node` are ignored: a
+    /// known node keeps its creation time.
+    pub async fn register_node(
+        &self,
+        ctx: &SecurityContext,
+        mut node: Node,
+    ) -> Result<Node, DomainError> {
+        if node.id.is_nil() {
+            return Err(DomainError::InvalidInput(
+                "Node ID must not be nil".to_owned(),
+            ));
+        }
+        if node.hostname.trim().is_empty() {
+            return Err(DomainError::InvalidInput(
+                "Hostname must not be empty".to_owned(),
+            ));
+        }
+        self.ensure_remote(node.id)?;
+
+        let now = Utc::now();
+        node.created_at = now;
+        node.updated_at = now;
+        let owner_id = ctx.subject_id();
+        let node = self.storage.register_node(node, owner_id, now)?;
+
+        if let Some(repo) = &self.repo {
+            repo.upsert(&NodeRecord {
+                node: node.clone(),
+                owner_id: Some(owner_id),
+                last_seen_at: now,
+            })
+            .await?;
+        }
+
+        tracing::info!(node_id = %node.id, hostname = %node.hostname, "Registered node");
+        Ok(node)
+    }
+
+    /// Record a heartbeat from a remote node.
+    ///
+    /// A node restored after a restart is answered as unknown until it reports its
+    /// sysinfo and syscap again, so its agent registers and reports right away
+    /// instead of after its report interval.
+    pub async fn heartbeat(
+        &self,
+        ctx: &SecurityContext,
+        node_id: uuid::Uuid,
+    ) -> Result<(), DomainError> {
+        self.ensure_reporter(ctx, node_id)?;
+        if self.storage.awaits_reports(node_id) {
+            return Err(DomainError::NodeNotFound(node_id));
+        }
+        self.mark_seen(node_id).await
+    }
+
+    /// Store system information reported by a remote node
+    pub async fn report_sysinfo(
+        &self,
+        ctx: &SecurityContext,
+        node_id: uuid::Uuid,
+        mut sysinfo: NodeSysInfo,
+    ) -> Result<(), DomainError> {
+        self.ensure_reporter(ctx, node_id)?;
+        sysinfo.node_id = node_id;
+        if !self.storage.update_sysinfo(node_id, sysinfo) {
+            return Err(DomainError::NodeNotFound(node_id));
+        }
+        self.mark_seen(node_id).await
+    }
+
+    /// Store system capabilities reported by a remote node
+    pub async fn report_syscap(
+        &self,
+        ctx: &SecurityContext,
+        node_id: uuid::Uuid,
+        mut syscap: NodeSysCap,
+    ) -> Result<(), DomainError> {
+        self.ensure_reporter(ctx, node_id)?;
+        syscap.node_id = node_id;
+        if !self.storage.update_syscap_system(node_id, syscap) {
+            return Err(DomainError::NodeNotFound(node_id));
+        }
+        self.mark_seen(node_id).await
+    }
+
+    /// Get when a node was last heard from; the local node is always current
+    pub fn last_seen_at(&self, node_id: uuid::Uuid) -> Result<DateTime<Utc>, DomainError> {
+        let last_seen_at = self
+            .storage
+            .last_seen(node_id)
+            .ok_or(DomainError::NodeNotFound(node_id))?;
+        if node_id == self.local_node_id {
+            return Ok(Utc::now());
+        }
+        Ok(last_seen_at)
+    }
+
+    /// Get the liveness of a node
+    pub fn node_status(&self, node_id: uuid::Uuid) -> Result<NodeStatus, DomainError> {
+        let last_seen_at = self.last_seen_at(node_id)?;
+        if node_id == self.local_node_id || Utc::now() - last_seen_at < self.node_ttl {
+            Ok(NodeStatus::Online)
+        } else {
+            Ok(NodeStatus::Offline)
         }
     }
 
@@ -74,6 +248,11 @@ impl Service {
             return Ok(cached);
         }
 
+        // Remote nodes report their own sysinfo
+        if node_id != self.local_node_id {
+            return Err(DomainError::NotReported(node_id, "system information"));
+        }
+
         // Collect fresh sysinfo
         let sysinfo = self
             .node_info_collector
@@ -97,6 +276,14 @@ impl Service {
             return Err(DomainError::NodeNotFound(node_id));
         }
 
+        // Remote nodes report their own syscap, there is nothing to refresh here
+        if node_id != self.local_node_id {
+            return self
+                .storage
+                .get_syscap(node_id)
+                .ok_or(DomainError::NotReported(node_id, "system capabilities"));
+        }
+
         // Check if we need to refresh system capabilities
         let expired_keys = self.storage.get_expired_syscap_keys(node_id);
         let needs_refresh =
@@ -152,6 +339,40 @@ impl Service {
         }
         Ok(())
     }
+
+    fn ensure_remote(&self, node_id: uuid::Uuid) -> Result<(), DomainError> {
+        if node_id == self.local_node_id {
+            return Err(DomainError::InvalidInput(format!(
+                "Node {node_id} hosts this registry and cannot be reported by an agent"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Only the subject that registered a remote node may report for it
+    fn ensure_reporter(
+        &self,
+        ctx: &SecurityContext,
+        node_id: uuid::Uuid,
+    ) -> Result<(), DomainError> {
+        self.ensure_remote(node_id)?;
+        self.storage.ensure_owner(node_id, ctx.subject_id())
+    }
+
+    async fn mark_seen(&self, node_id: uuid::Uuid) -> Result<(), DomainError> {
+        let now = Utc::now();
+        if !self.storage.touch(node_id, now) {
+            return Err(DomainError::NodeNotFound(node_id));
+        }
+        if let Some(repo) = &self.repo {
+            repo.touch(node_id, now).await?;
+        }
+        Ok(())
+    }
+}
+
+fn ttl_delta(ttl: Duration) -> TimeDelta {
+    TimeDelta::from_std(ttl).unwrap_or(TimeDelta::MAX)
 }
 
 impl Default for Service {
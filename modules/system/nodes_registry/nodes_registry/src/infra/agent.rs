@@ -0,0 +1,171 @@
+//! Node agent: registers this node with a remote registry and keeps it up to date.
+//!
+//! The agent talks to the registry's REST API. It registers the node, then sends a
+//! heartbeat every `heartbeat_interval_secs` and fresh sysinfo and syscap every
+//! `report_interval_secs`. When the registry no longer knows the node (e.g. it was
+//! restarted without a database), the agent registers it again.
+
+use std::time::{Duration, Instant};
+
+use modkit_node_info::{NodeInfoCollector, NodeInfoError};
+use nodes_registry_sdk::Node;
+use reqwest::StatusCode;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+
+use crate::api::rest::dto::{NodeSysCapDto, NodeSysInfoDto, RegisterNodeRequest};
+use crate::config::AgentConfig;
+
+const NODES_PATH: &str = "/nodes-registry/v1/nodes";
+
+#[derive(Debug, thiserror::Error)]
+enum AgentError {
+    #[error("registry does not know this node")]
+    UnknownNode,
+    #[error("registration rejected by the registry: {0}")]
+    Registration(#[source] reqwest::Error),
+    #[error("request to registry failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Collect(#[from] NodeInfoError),
+}
+
+#[derive(Default)]
+struct AgentState {
+    registered: bool,
+    last_report: Option<Instant>,
+}
+
+/// Reports the local node to a remote nodes registry.
+pub struct NodeAgent {
+    client: reqwest::Client,
+    nodes_url: String,
+    token: Option<String>,
+    heartbeat_interval: Duration,
+    report_interval: Duration,
+    collector: NodeInfoCollector,
+    node: Node,
+}
+
+impl NodeAgent {
+    /// Create an agent reporting `node` as configured by `cfg`.
+    ///
+    /// # Errors
+    /// Returns an error if the registry URL is invalid or the HTTP client cannot be built.
+    pub fn new(cfg: &AgentConfig, node: Node) -> anyhow::Result<Self> {
+        let base = reqwest::Url::parse(&cfg.registry_url)
+            .map_err(|e| anyhow::anyhow!("invalid registry_url '{}': {e}", cfg.registry_url))?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(cfg.request_timeout_secs))
+            .build()?;
+
+        Ok(Self {
+            client,
+            nodes_url: format!("{}{NODES_PATH}", base.as_str().trim_end_matches('/')),
+            token: cfg.token.clone(),
+            heartbeat_interval: Duration::from_secs(cfg.heartbeat_interval_secs.max(1)),
+            report_interval: Duration::from_secs(cfg.report_interval_secs),
+            collector: NodeInfoCollector::new(),
+            node,
+        })
+    }
+
+    /// Report to the registry until `cancel` fires. Failures are logged and retried
+    /// on the next heartbeat.
+    pub async fn run(&self, cancel: CancellationToken) {
+        info!(node_id = %self.node.id, registry = %self.nodes_url, "Node agent started");
+        let mut state = AgentState::default();
+
+        loop {
+            if let Err(e) = self.step(&mut state).await {
+                self.handle_failure(&mut state, &e);
+            }
+
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                () = tokio::time::sleep(self.heartbeat_interval) => {}
+            }
+        }
+
+        info!(node_id = %self.node.id, "Node agent stopped");
+    }
+
+    async fn step(&self, state: &mut AgentState) -> Result<(), AgentError> {
+        if !state.registered {
+            self.send(
+                self.client
+                    .post(&self.nodes_url)
+                    .json(&RegisterNodeRequest::from(&self.node)),
+            )
+            .await?
+            .error_for_status()
+            .map_err(AgentError::Registration)?;
+            state.registered = true;
+            state.last_report = None;
+            info!(node_id = %self.node.id, "Registered with the nodes registry");
+        }
+
+        // A report counts as a heartbeat
+        if state
+            .last_report
+            .is_none_or(|at| at.elapsed() >= self.report_interval)
+        {
+            self.report().await?;
+            state.last_report = Some(Instant::now());
+        } else {
+            self.send_for_node(self.client.post(self.node_url("heartbeat")))
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn handle_failure(&self, state: &mut AgentState, error: &AgentError) {
+        match error {
+            AgentError::UnknownNode => {
+                debug!(node_id = %self.node.id, "Registry lost this node, registering again");
+                state.registered = false;
+            }
+            // A 404 here means the registry URL is wrong or the module is disabled
+            AgentError::Registration(e) => {
+                error!(node_id = %self.node.id, registry = %self.nodes_url, error = %e, "Node registration failed");
+            }
+            _ => warn!(node_id = %self.node.id, error = %error, "Node agent update failed"),
+        }
+    }
+
+    async fn report(&self) -> Result<(), AgentError> {
+        let sysinfo = NodeSysInfoDto::from(self.collector.collect_sysinfo(self.node.id)?);
+        self.send_for_node(self.client.put(self.node_url("sysinfo")).json(&sysinfo))
+            .await?;
+
+        let syscap = NodeSysCapDto::from(self.collector.collect_syscap(self.node.id)?);
+        self.send_for_node(self.client.put(self.node_url("syscap")).json(&syscap))
+            .await
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let request = match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        request.send().await
+    }
+
+    /// Send a request to one of this node's endpoints; a 404 there means the registry
+    /// no longer knows the node
+    async fn send_for_node(&self, request: reqwest::RequestBuilder) -> Result<(), AgentError> {
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AgentError::UnknownNode);
+        }
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    fn node_url(&self, resource: &str) -> String {
+        format!("{}/{}/{resource}", self.nodes_url, self.node.id)
+    }
+}
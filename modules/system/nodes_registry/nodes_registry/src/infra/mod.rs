@@ -0,0 +1,7 @@
+//! Infrastructure layer for the nodes registry.
+
+pub mod agent;
+pub mod storage;
+
+pub use agent::NodeAgent;
+pub use storage::DbNodeRepository;
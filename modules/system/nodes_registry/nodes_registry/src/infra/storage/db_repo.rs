@@ -0,0 +1,99 @@
+//! Database-backed repository implementation using `modkit-db`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use modkit_db::secure::SecureConn;
+use nodes_registry_sdk::Node;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use super::entity;
+use crate::domain::error::DomainError;
+use crate::domain::repo::{NodeRecord, NodeRepository};
+
+/// Database-backed repository for node records.
+///
+/// The table is global (`#[secure(unrestricted)]`): nodes are shared by the whole
+/// deployment, so there is no access scope to apply to its queries.
+pub struct DbNodeRepository {
+    db: SecureConn,
+}
+
+impl DbNodeRepository {
+    #[must_use]
+    pub fn new(db: SecureConn) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl NodeRepository for DbNodeRepository {
+    #[allow(clippy::disallowed_methods)]
+    async fn list(&self) -> Result<Vec<NodeRecord>, DomainError> {
+        let models = entity::Entity::find()
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(models.into_iter().map(node_record).collect())
+    }
+
+    async fn upsert(&self, record: &NodeRecord) -> Result<(), DomainError> {
+        let node = &record.node;
+        let model = entity::ActiveModel {
+            id: ActiveValue::Set(node.id),
+            hostname: ActiveValue::Set(node.hostname.clone()),
+            ip_address: ActiveValue::Set(node.ip_address.clone()),
+            owner_id: ActiveValue::Set(record.owner_id),
+            created_at: ActiveValue::Set(node.created_at),
+            updated_at: ActiveValue::Set(node.updated_at),
+            last_seen_at: ActiveValue::Set(record.last_seen_at),
+        };
+        entity::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(entity::Column::Id)
+                    .update_columns([
+                        entity::Column::Hostname,
+                        entity::Column::IpAddress,
+                        entity::Column::OwnerId,
+                        entity::Column::UpdatedAt,
+                        entity::Column::LastSeenAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn touch(&self, id: Uuid, last_seen_at: DateTime<Utc>) -> Result<(), DomainError> {
+        entity::Entity::update_many()
+            .col_expr(entity::Column::LastSeenAt, Expr::value(last_seen_at))
+            .filter(entity::Column::Id.eq(id))
+            .exec(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+}
+
+fn storage_error(e: sea_orm::DbErr) -> DomainError {
+    anyhow::Error::from(e).into()
+}
+
+fn node_record(model: entity::Model) -> NodeRecord {
+    NodeRecord {
+        node: Node {
+            id: model.id,
+            hostname: model.hostname,
+            ip_address: model.ip_address,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        },
+        owner_id: model.owner_id,
+        last_seen_at: model.last_seen_at,
+    }
+}
@@ -0,0 +1,27 @@
+//! `SeaORM` entity for node records.
+
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// A node known to the registry.
+///
+/// Nodes belong to the deployment rather than to a tenant, hence `unrestricted`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "nodes")]
+#[secure(unrestricted)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub hostname: String,
+    pub ip_address: Option<String>,
+    pub owner_id: Option<Uuid>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    pub last_seen_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres => {
+                r"
+CREATE TABLE IF NOT EXISTS nodes (
+    id UUID PRIMARY KEY,
+    hostname VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(64),
+    owner_id UUID,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    last_seen_at TIMESTAMPTZ NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS nodes (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    hostname VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(64),
+    owner_id VARCHAR(36),
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL,
+    last_seen_at TIMESTAMP NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS nodes (
+    id TEXT NOT NULL PRIMARY KEY,
+    hostname TEXT NOT NULL,
+    ip_address TEXT,
+    owner_id TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    last_seen_at TEXT NOT NULL
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        conn.execute_unprepared("DROP TABLE IF EXISTS nodes;")
+            .await?;
+        Ok(())
+    }
+}
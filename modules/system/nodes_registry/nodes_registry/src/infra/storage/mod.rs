@@ -0,0 +1,7 @@
+//! Database storage for node records.
+
+mod db_repo;
+mod entity;
+pub mod migrations;
+
+pub use db_repo::DbNodeRepository;
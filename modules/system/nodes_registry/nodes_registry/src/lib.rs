@@ -13,11 +13,12 @@
 //! - Get node information by ID
 //! - Access node sysinfo via /nodes/{id}/sysinfo
 //! - Access node syscap via /nodes/{id}/syscap
+//! - Register nodes running in node-agent mode, and receive their heartbeats and reports
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 // === PUBLIC CONTRACT ===
 pub use nodes_registry_sdk::{
-    BatteryInfo, CpuInfo, GpuInfo, HostInfo, MemoryInfo, Node, NodeSysCap, NodeSysInfo,
+    BatteryInfo, CpuInfo, GpuInfo, HostInfo, MemoryInfo, Node, NodeStatus, NodeSysCap, NodeSysInfo,
     NodesRegistryClient, NodesRegistryError, OsInfo, SysCap,
 };
 
@@ -32,3 +33,5 @@ pub mod api;
 pub mod config;
 #[doc(hidden)]
 pub mod domain;
+#[doc(hidden)]
+pub mod infra;
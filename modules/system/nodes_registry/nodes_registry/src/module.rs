@@ -1,13 +1,17 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
 use modkit::Module;
 use modkit::context::ModuleCtx;
 use modkit::contracts::{OpenApiRegistry, RestApiCapability};
 
+use crate::config::NodesRegistryConfig;
 use crate::domain::local_client::NodesRegistryLocalClient;
 use crate::domain::service::Service;
+use crate::infra::{DbNodeRepository, NodeAgent};
 use nodes_registry_sdk::NodesRegistryClient;
 
 /// Nodes Registry Module
@@ -18,30 +22,72 @@ use nodes_registry_sdk::NodesRegistryClient;
 /// - Getting node details
 /// - Accessing node system information (sysinfo)
 /// - Accessing node system capabilities (syscap)
+/// - Registering, heartbeating and reporting nodes running in node-agent mode
+///
+/// With a database, node records are persisted and restored on startup.
+/// With an `agent` section in the config, this node also reports itself to a remote registry.
 #[modkit::module(
     name = "nodes_registry",
-    capabilities = [rest],
-    client = nodes_registry_sdk::NodesRegistryClient
+    capabilities = [db, rest, stateful],
+    client = nodes_registry_sdk::NodesRegistryClient,
+    lifecycle(entry = "serve")
 )]
 pub struct NodesRegistry {
     service: arc_swap::ArcSwapOption<Service>,
+    agent: OnceLock<NodeAgent>,
 }
 
 impl Default for NodesRegistry {
     fn default() -> Self {
         Self {
             service: arc_swap::ArcSwapOption::empty(),
+            agent: OnceLock::new(),
+        }
+    }
+}
+
+impl NodesRegistry {
+    async fn serve(self: Arc<Self>, cancel: CancellationToken) -> Result<()> {
+        match self.agent.get() {
+            Some(agent) => agent.run(cancel).await,
+            None => cancel.cancelled().await,
         }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl modkit::contracts::DatabaseCapability for NodesRegistry {
+    async fn migrate(&self, db: &modkit_db::DbHandle) -> Result<()> {
+        use sea_orm_migration::MigratorTrait;
+
+        tracing::info!("Running nodes_registry database migrations");
+        let conn = db.sea_secure();
+        crate::infra::storage::migrations::Migrator::up(conn.conn(), None).await?;
+        Ok(())
     }
 }
 
 #[async_trait]
 impl Module for NodesRegistry {
     async fn init(&self, ctx: &ModuleCtx) -> Result<()> {
-        // let cfg: NodesRegistryConfig = ctx.config()?; not needed for now
+        let cfg: NodesRegistryConfig = ctx.config()?;
 
         // Create the service
-        let service = Service::new();
+        let mut service = Service::new().with_node_ttl(Duration::from_secs(cfg.node_ttl_secs));
+        if let Some(db) = ctx.db_optional() {
+            service = service.with_repository(Arc::new(DbNodeRepository::new(db.sea_secure())));
+            let restored = service.restore().await?;
+            tracing::info!(restored, "Restored persisted nodes");
+        }
+
+        if let Some(agent_cfg) = &cfg.agent {
+            let node = service.get_node(service.local_node_id())?;
+            self.agent
+                .set(NodeAgent::new(agent_cfg, node)?)
+                .map_err(|_| anyhow::anyhow!("Node agent already initialized"))?;
+        }
+
         self.service.store(Some(Arc::new(service.clone())));
 
         // Expose the client to the ClientHub
@@ -0,0 +1,95 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Tests for the node agent against a registry served over REST
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use modkit::api::OpenApiRegistryImpl;
+use modkit_security::SecurityContext;
+use nodes_registry::config::AgentConfig;
+use nodes_registry::domain::service::Service;
+use nodes_registry::infra::NodeAgent;
+use nodes_registry::{Node, NodeStatus};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+async fn serve_registry(service: Arc<Service>) -> String {
+    let openapi = OpenApiRegistryImpl::new();
+    // Stands in for the gateway's auth middleware
+    let agent = SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .build();
+    let router =
+        nodes_registry::api::rest::routes::register_routes(axum::Router::new(), &openapi, service)
+            .layer(axum::Extension(agent));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    format!("http://{addr}")
+}
+
+fn agent_config(registry_url: String) -> AgentConfig {
+    AgentConfig {
+        registry_url,
+        token: None,
+        heartbeat_interval_secs: 1,
+        report_interval_secs: 300,
+        request_timeout_secs: 5,
+    }
+}
+
+#[tokio::test]
+async fn agent_registers_and_reports() {
+    let service = Arc::new(Service::new());
+    let registry_url = serve_registry(service.clone()).await;
+
+    // The registry already owns this host's ID, so the agent reports under another one
+    let now = chrono::Utc::now();
+    let node = Node {
+        id: Uuid::new_v4(),
+        hostname: "agent-node".to_owned(),
+        ip_address: None,
+        created_at: now,
+        updated_at: now,
+    };
+    let agent = NodeAgent::new(&agent_config(registry_url), node.clone()).unwrap();
+    let cancel = CancellationToken::new();
+    let run = tokio::spawn({
+        let cancel = cancel.clone();
+        async move { agent.run(cancel).await }
+    });
+
+    let mut reported = false;
+    for _ in 0..100 {
+        if service.get_node_syscap(node.id, false).is_ok() {
+            reported = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(reported, "Agent should register and report its node");
+
+    assert_eq!(service.get_node(node.id).unwrap().hostname, "agent-node");
+    assert_eq!(service.get_node_sysinfo(node.id).unwrap().node_id, node.id);
+    assert_eq!(service.node_status(node.id).unwrap(), NodeStatus::Online);
+
+    cancel.cancel();
+    tokio::time::timeout(Duration::from_secs(5), run)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[test]
+fn agent_rejects_invalid_registry_url() {
+    let now = chrono::Utc::now();
+    let node = Node {
+        id: Uuid::new_v4(),
+        hostname: "agent-node".to_owned(),
+        ip_address: None,
+        created_at: now,
+        updated_at: now,
+    };
+    assert!(NodeAgent::new(&agent_config("not a url".to_owned()), node).is_err());
+}
@@ -36,6 +36,13 @@ fn test_error_conversion_mapping() {
             "GPU detection failed".to_owned(),
             "/test/syscap",
         ),
+        (
+            DomainError::NotReported(test_id, "system information"),
+            StatusCode::NOT_FOUND,
+            "NODES_NOT_REPORTED",
+            test_id.to_string(),
+            "/test/reported",
+        ),
         (
             DomainError::InvalidInput("Invalid capability key format".to_owned()),
             StatusCode::BAD_REQUEST,
@@ -43,6 +50,13 @@ fn test_error_conversion_mapping() {
             "Invalid capability key format".to_owned(),
             "/test/validate",
         ),
+        (
+            DomainError::Forbidden(format!("Node {test_id} is registered by another subject")),
+            StatusCode::FORBIDDEN,
+            "NODES_FORBIDDEN",
+            test_id.to_string(),
+            "/test/forbidden",
+        ),
         (
             DomainError::Internal("Database connection lost".to_owned()),
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,163 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Tests for node records persisted in the module database
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use modkit_db::{ConnectOpts, DbHandle};
+use modkit_security::SecurityContext;
+use nodes_registry::domain::error::DomainError;
+use nodes_registry::domain::repo::NodeRepository;
+use nodes_registry::domain::service::Service;
+use nodes_registry::infra::DbNodeRepository;
+use nodes_registry::infra::storage::migrations::Migrator;
+use nodes_registry::{Node, NodeStatus};
+use sea_orm_migration::MigratorTrait;
+use uuid::Uuid;
+
+/// Subject the test agents authenticate as
+fn agent() -> SecurityContext {
+    SecurityContext::builder()
+        .subject_id(Uuid::from_u128(0xa6e7))
+        .build()
+}
+
+async fn repository() -> Arc<DbNodeRepository> {
+    // A single connection keeps the in-memory database alive and shared
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = DbHandle::connect("sqlite::memory:", opts).await.unwrap();
+    Migrator::up(db.sea_secure().conn(), None).await.unwrap();
+    Arc::new(DbNodeRepository::new(db.sea_secure()))
+}
+
+fn remote_node(hostname: &str) -> Node {
+    let now = chrono::Utc::now();
+    Node {
+        id: Uuid::new_v4(),
+        hostname: hostname.to_owned(),
+        ip_address: Some("10.0.0.7".to_owned()),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+#[tokio::test]
+async fn registered_nodes_survive_restart() {
+    let repo = repository().await;
+
+    let before = Service::new().with_repository(repo.clone());
+    assert_eq!(before.restore().await.unwrap(), 0);
+    let node = before
+        .register_node(&agent(), remote_node("worker-1"))
+        .await
+        .unwrap();
+    before.heartbeat(&agent(), node.id).await.unwrap();
+    let last_seen_at = before.last_seen_at(node.id).unwrap();
+
+    // A fresh service over the same database plays the restarted registry
+    let after = Service::new().with_repository(repo.clone());
+    assert_eq!(after.list_nodes().len(), 1);
+    assert_eq!(after.restore().await.unwrap(), 1);
+
+    let restored = after.get_node(node.id).unwrap();
+    assert_eq!(restored.hostname, "worker-1");
+    assert_eq!(restored.ip_address.as_deref(), Some("10.0.0.7"));
+    assert_eq!(restored.created_at, node.created_at);
+    assert_eq!(after.last_seen_at(node.id).unwrap(), last_seen_at);
+    assert_eq!(after.list_nodes().len(), 2);
+}
+
+#[tokio::test]
+async fn restored_nodes_keep_their_liveness() {
+    let repo = repository().await;
+    let node = Service::new()
+        .with_repository(repo.clone())
+        .register_node(&agent(), remote_node("worker-1"))
+        .await
+        .unwrap();
+
+    let after = Service::new()
+        .with_repository(repo.clone())
+        .with_node_ttl(Duration::ZERO);
+    after.restore().await.unwrap();
+
+    assert_eq!(after.node_status(node.id).unwrap(), NodeStatus::Offline);
+    // Sysinfo is not persisted: the agent reports it again
+    assert!(after.get_node_sysinfo(node.id).is_err());
+}
+
+#[tokio::test]
+async fn restore_persists_the_local_node() {
+    let repo = repository().await;
+    let service = Service::new().with_repository(repo.clone());
+    service.restore().await.unwrap();
+
+    let records = repo.list().await.unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].node.id, service.local_node_id());
+
+    // Restoring again updates the record instead of duplicating it
+    service.restore().await.unwrap();
+    assert_eq!(repo.list().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn restored_nodes_keep_their_owner() {
+    let repo = repository().await;
+    let node = Service::new()
+        .with_repository(repo.clone())
+        .register_node(&agent(), remote_node("worker-1"))
+        .await
+        .unwrap();
+
+    let after = Service::new().with_repository(repo.clone());
+    after.restore().await.unwrap();
+
+    let intruder = SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .build();
+    assert!(matches!(
+        after.register_node(&intruder, node.clone()).await,
+        Err(DomainError::Forbidden(_))
+    ));
+    after.register_node(&agent(), node).await.unwrap();
+}
+
+#[tokio::test]
+async fn restored_nodes_must_report_before_heartbeats() {
+    let repo = repository().await;
+    let node = Service::new()
+        .with_repository(repo.clone())
+        .register_node(&agent(), remote_node("worker-1"))
+        .await
+        .unwrap();
+
+    let after = Service::new().with_repository(repo.clone());
+    after.restore().await.unwrap();
+
+    // Answering as unknown makes the agent register and report again at once
+    assert!(matches!(
+        after.heartbeat(&agent(), node.id).await,
+        Err(DomainError::NodeNotFound(id)) if id == node.id
+    ));
+
+    let local_id = after.local_node_id();
+    let sysinfo = after.get_node_sysinfo(local_id).unwrap();
+    after
+        .report_sysinfo(&agent(), node.id, sysinfo)
+        .await
+        .unwrap();
+    assert!(after.heartbeat(&agent(), node.id).await.is_err());
+
+    let syscap = after.get_node_syscap(local_id, false).unwrap();
+    after
+        .report_syscap(&agent(), node.id, syscap)
+        .await
+        .unwrap();
+    after.heartbeat(&agent(), node.id).await.unwrap();
+    assert_eq!(after.get_node_sysinfo(node.id).unwrap().node_id, node.id);
+}
@@ -4,11 +4,31 @@
 //!
 //! These tests verify service methods, error handling, and business logic.
 
-use nodes_registry::SysCap;
+use modkit_security::SecurityContext;
 use nodes_registry::domain::error::DomainError;
 use nodes_registry::domain::service::Service;
+use nodes_registry::{Node, NodeStatus, SysCap};
+use std::time::Duration;
 use uuid::Uuid;
 
+/// Subject the test agents authenticate as
+fn agent() -> SecurityContext {
+    SecurityContext::builder()
+        .subject_id(Uuid::from_u128(0xa6e7))
+        .build()
+}
+
+fn remote_node(hostname: &str) -> Node {
+    let now = chrono::Utc::now();
+    Node {
+        id: Uuid::new_v4(),
+        hostname: hostname.to_owned(),
+        ip_address: Some("10.0.0.7".to_owned()),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
 #[test]
 fn test_service_initialization_creates_current_node() {
     let service = Service::new();
@@ -439,3 +459,193 @@ fn test_service_default_trait() {
         "Default service should have valid node"
     );
 }
+
+#[tokio::test]
+async fn test_register_node_adds_online_remote_node() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+
+    let registered = service.register_node(&agent(), node.clone()).await.unwrap();
+    assert_eq!(registered.id, node.id);
+    assert_eq!(registered.hostname, "worker-1");
+
+    assert_eq!(service.list_nodes().len(), 2);
+    assert_eq!(service.node_status(node.id).unwrap(), NodeStatus::Online);
+}
+
+#[tokio::test]
+async fn test_register_node_again_keeps_created_at() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+    let first = service.register_node(&agent(), node.clone()).await.unwrap();
+
+    let mut renamed = node.clone();
+    renamed.hostname = "worker-1-renamed".to_owned();
+    let second = service.register_node(&agent(), renamed).await.unwrap();
+
+    assert_eq!(second.created_at, first.created_at);
+    assert!(second.updated_at >= first.updated_at);
+    assert_eq!(
+        service.get_node(node.id).unwrap().hostname,
+        "worker-1-renamed"
+    );
+    assert_eq!(service.list_nodes().len(), 2);
+}
+
+#[tokio::test]
+async fn test_register_node_rejects_invalid_nodes() {
+    let service = Service::new();
+
+    let mut nil = remote_node("worker-1");
+    nil.id = Uuid::nil();
+    let mut unnamed = remote_node(" ");
+    unnamed.id = Uuid::new_v4();
+    let mut local = remote_node("impostor");
+    local.id = service.local_node_id();
+
+    for node in [nil, unnamed, local] {
+        assert!(matches!(
+            service.register_node(&agent(), node).await,
+            Err(DomainError::InvalidInput(_))
+        ));
+    }
+    assert_eq!(service.list_nodes().len(), 1);
+}
+
+#[tokio::test]
+async fn test_heartbeat_requires_registration() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+
+    assert!(matches!(
+        service.heartbeat(&agent(), node.id).await,
+        Err(DomainError::NodeNotFound(id)) if id == node.id
+    ));
+
+    service.register_node(&agent(), node.clone()).await.unwrap();
+    service.heartbeat(&agent(), node.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_only_the_owner_reports_for_a_node() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+    service.register_node(&agent(), node.clone()).await.unwrap();
+
+    let intruder = SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .build();
+    let mut hijacked = node.clone();
+    hijacked.hostname = "hijacked".to_owned();
+    assert!(matches!(
+        service.register_node(&intruder, hijacked).await,
+        Err(DomainError::Forbidden(_))
+    ));
+    assert!(matches!(
+        service.heartbeat(&intruder, node.id).await,
+        Err(DomainError::Forbidden(_))
+    ));
+    let sysinfo = service.get_node_sysinfo(service.local_node_id()).unwrap();
+    assert!(matches!(
+        service.report_sysinfo(&intruder, node.id, sysinfo).await,
+        Err(DomainError::Forbidden(_))
+    ));
+
+    assert_eq!(service.get_node(node.id).unwrap().hostname, "worker-1");
+    service.heartbeat(&agent(), node.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_remote_node_goes_offline_after_ttl() {
+    let service = Service::new().with_node_ttl(Duration::ZERO);
+    let node = remote_node("worker-1");
+    service.register_node(&agent(), node.clone()).await.unwrap();
+
+    assert_eq!(service.node_status(node.id).unwrap(), NodeStatus::Offline);
+    // The node hosting the registry is always online
+    assert_eq!(
+        service.node_status(service.local_node_id()).unwrap(),
+        NodeStatus::Online
+    );
+    // Offline nodes are still listed
+    assert_eq!(service.list_nodes().len(), 2);
+}
+
+#[tokio::test]
+async fn test_remote_node_sysinfo_comes_from_reports() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+    service.register_node(&agent(), node.clone()).await.unwrap();
+
+    assert!(matches!(
+        service.get_node_sysinfo(node.id),
+        Err(DomainError::NotReported(id, _)) if id == node.id
+    ));
+
+    let sysinfo = service.get_node_sysinfo(service.local_node_id()).unwrap();
+    service
+        .report_sysinfo(&agent(), node.id, sysinfo.clone())
+        .await
+        .unwrap();
+
+    let reported = service.get_node_sysinfo(node.id).unwrap();
+    assert_eq!(reported.node_id, node.id, "Node ID should follow the path");
+    assert_eq!(reported.host.hostname, sysinfo.host.hostname);
+}
+
+#[tokio::test]
+async fn test_remote_node_syscap_merges_reports_with_custom() {
+    let service = Service::new();
+    let node = remote_node("worker-1");
+    service.register_node(&agent(), node.clone()).await.unwrap();
+
+    assert!(matches!(
+        service.get_node_syscap(node.id, true),
+        Err(DomainError::NotReported(id, _)) if id == node.id
+    ));
+
+    let syscap = service
+        .get_node_syscap(service.local_node_id(), false)
+        .unwrap();
+    let reported_count = syscap.capabilities.len();
+    service
+        .report_syscap(&agent(), node.id, syscap)
+        .await
+        .unwrap();
+
+    let custom = SysCap {
+        key: "software:custom-remote".to_owned(),
+        category: "software".to_owned(),
+        name: "custom-remote".to_owned(),
+        display_name: "Custom Remote".to_owned(),
+        present: true,
+        version: None,
+        amount: None,
+        amount_dimension: None,
+        details: None,
+        cache_ttl_secs: 60,
+        fetched_at_secs: chrono::Utc::now().timestamp(),
+    };
+    service.set_custom_syscap(node.id, vec![custom]).unwrap();
+
+    // force_refresh has nothing to collect for a remote node
+    let merged = service.get_node_syscap(node.id, true).unwrap();
+    assert_eq!(merged.node_id, node.id);
+    assert_eq!(merged.capabilities.len(), reported_count + 1);
+}
+
+#[tokio::test]
+async fn test_reports_on_local_node_are_rejected() {
+    let service = Service::new();
+    let local_id = service.local_node_id();
+    let sysinfo = service.get_node_sysinfo(local_id).unwrap();
+
+    assert!(matches!(
+        service.heartbeat(&agent(), local_id).await,
+        Err(DomainError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        service.report_sysinfo(&agent(), local_id, sysinfo).await,
+        Err(DomainError::InvalidInput(_))
+    ));
+}
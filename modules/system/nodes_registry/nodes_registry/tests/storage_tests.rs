@@ -4,6 +4,7 @@
 //!
 //! These tests verify storage operations, concurrency, and edge cases.
 
+use nodes_registry::domain::error::DomainError;
 use nodes_registry::domain::node_storage::NodeStorage;
 use nodes_registry::{Node, SysCap};
 use std::sync::Arc;
@@ -286,3 +287,146 @@ fn test_storage_clear_custom_syscap_preserves_system() {
         "Custom capability should be removed"
     );
 }
+
+#[test]
+fn test_storage_register_node_keeps_created_at_and_cached_data() {
+    let storage = NodeStorage::new();
+    let node_id = Uuid::new_v4();
+    let created_at = chrono::Utc::now() - chrono::TimeDelta::hours(1);
+
+    let node = Node {
+        id: node_id,
+        hostname: "worker".to_owned(),
+        ip_address: None,
+        created_at,
+        updated_at: created_at,
+    };
+    let owner_id = Uuid::new_v4();
+    storage
+        .register_node(node.clone(), owner_id, created_at)
+        .unwrap();
+    storage.set_custom_syscap(
+        node_id,
+        vec![SysCap {
+            key: "custom.test".to_owned(),
+            category: "custom".to_owned(),
+            name: "test".to_owned(),
+            display_name: "Custom Test".to_owned(),
+            present: true,
+            version: None,
+            amount: None,
+            amount_dimension: None,
+            details: None,
+            cache_ttl_secs: 3600,
+            fetched_at_secs: chrono::Utc::now().timestamp(),
+        }],
+    );
+
+    let now = chrono::Utc::now();
+    let renamed = Node {
+        hostname: "worker-renamed".to_owned(),
+        created_at: now,
+        updated_at: now,
+        ..node
+    };
+    let stored = storage.register_node(renamed, owner_id, now).unwrap();
+
+    assert_eq!(stored.created_at, created_at);
+    assert_eq!(stored.hostname, "worker-renamed");
+    assert_eq!(storage.last_seen(node_id), Some(now));
+    assert!(
+        storage.get_syscap(node_id).is_some(),
+        "Custom capabilities should survive re-registration"
+    );
+}
+
+#[test]
+fn test_storage_register_node_is_bound_to_its_owner() {
+    let storage = NodeStorage::new();
+    let now = chrono::Utc::now();
+    let node = Node {
+        id: Uuid::new_v4(),
+        hostname: "worker".to_owned(),
+        ip_address: None,
+        created_at: now,
+        updated_at: now,
+    };
+    let owner_id = Uuid::new_v4();
+    let intruder_id = Uuid::new_v4();
+    storage.register_node(node.clone(), owner_id, now).unwrap();
+
+    let hijacked = Node {
+        hostname: "hijacked".to_owned(),
+        ..node.clone()
+    };
+    assert!(matches!(
+        storage.register_node(hijacked, intruder_id, now),
+        Err(DomainError::Forbidden(_))
+    ));
+    assert_eq!(storage.get_node(node.id).unwrap().hostname, "worker");
+
+    storage.ensure_owner(node.id, owner_id).unwrap();
+    assert!(matches!(
+        storage.ensure_owner(node.id, intruder_id),
+        Err(DomainError::Forbidden(_))
+    ));
+    assert!(matches!(
+        storage.ensure_owner(Uuid::new_v4(), owner_id),
+        Err(DomainError::NodeNotFound(_))
+    ));
+}
+
+#[test]
+fn test_storage_restore_node_does_not_overwrite() {
+    let storage = NodeStorage::new();
+    let node_id = Uuid::new_v4();
+    let now = chrono::Utc::now();
+
+    let node = Node {
+        id: node_id,
+        hostname: "live".to_owned(),
+        ip_address: None,
+        created_at: now,
+        updated_at: now,
+    };
+    storage.upsert_node(node.clone());
+
+    let persisted = Node {
+        hostname: "persisted".to_owned(),
+        ..node
+    };
+    assert!(!storage.restore_node(persisted.clone(), None, now));
+    assert_eq!(storage.get_node(node_id).unwrap().hostname, "live");
+
+    let other = Node {
+        id: Uuid::new_v4(),
+        ..persisted
+    };
+    let last_seen = now - chrono::TimeDelta::minutes(5);
+    assert!(storage.restore_node(other.clone(), Some(Uuid::new_v4()), last_seen));
+    assert_eq!(storage.last_seen(other.id), Some(last_seen));
+}
+
+#[test]
+fn test_storage_touch_updates_last_seen() {
+    let storage = NodeStorage::new();
+    let node_id = Uuid::new_v4();
+    let now = chrono::Utc::now();
+
+    assert!(
+        !storage.touch(node_id, now),
+        "Unknown node cannot be touched"
+    );
+    assert_eq!(storage.last_seen(node_id), None);
+
+    storage.upsert_node(Node {
+        id: node_id,
+        hostname: "test".to_owned(),
+        ip_address: None,
+        created_at: now,
+        updated_at: now,
+    });
+    let later = now + chrono::TimeDelta::seconds(30);
+    assert!(storage.touch(node_id, later));
+    assert_eq!(storage.last_seen(node_id), Some(later));
+}
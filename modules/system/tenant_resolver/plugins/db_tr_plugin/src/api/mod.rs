@@ -0,0 +1,3 @@
+//! API layer for the database tenant resolver plugin.
+
+pub mod rest;
@@ -0,0 +1,177 @@
+//! REST DTOs for the database tenant resolver plugin.
+
+use serde::{Deserialize, Serialize};
+use tenant_resolver_sdk::{TenantInfo, TenantStatus};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::AccessGrant;
+
+/// Tenant lifecycle status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TenantStatusDto {
+    #[default]
+    Active,
+    Suspended,
+    Deleted,
+}
+
+impl From<TenantStatus> for TenantStatusDto {
+    fn from(status: TenantStatus) -> Self {
+        match status {
+            TenantStatus::Active => Self::Active,
+            TenantStatus::Suspended => Self::Suspended,
+            TenantStatus::Deleted => Self::Deleted,
+        }
+    }
+}
+
+impl From<TenantStatusDto> for TenantStatus {
+    fn from(status: TenantStatusDto) -> Self {
+        match status {
+            TenantStatusDto::Active => Self::Active,
+            TenantStatusDto::Suspended => Self::Suspended,
+            TenantStatusDto::Deleted => Self::Deleted,
+        }
+    }
+}
+
+/// A tenant.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TenantDto {
+    #[schema(value_type = String)]
+    pub id: Uuid,
+    pub name: String,
+    pub status: TenantStatusDto,
+    /// Tenant type classification.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub tenant_type: Option<String>,
+    /// Parent tenant (absent for a root tenant).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub parent_id: Option<Uuid>,
+}
+
+impl From<TenantInfo> for TenantDto {
+    fn from(tenant: TenantInfo) -> Self {
+        Self {
+            id: tenant.id,
+            name: tenant.name,
+            status: tenant.status.into(),
+            tenant_type: tenant.tenant_type,
+            parent_id: tenant.parent_id,
+        }
+    }
+}
+
+/// Request to create a tenant.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateTenantRequest {
+    /// Tenant ID; generated if absent.
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub id: Option<Uuid>,
+    pub name: String,
+    #[serde(default)]
+    pub status: TenantStatusDto,
+    #[serde(rename = "type", default)]
+    pub tenant_type: Option<String>,
+    /// Parent tenant; the tenant is a root if absent.
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub parent_id: Option<Uuid>,
+}
+
+impl From<CreateTenantRequest> for TenantInfo {
+    fn from(req: CreateTenantRequest) -> Self {
+        Self {
+            id: req.id.unwrap_or_else(Uuid::new_v4),
+            name: req.name,
+            status: req.status.into(),
+            tenant_type: req.tenant_type,
+            parent_id: req.parent_id,
+        }
+    }
+}
+
+/// Request to replace a tenant.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTenantRequest {
+    pub name: String,
+    pub status: TenantStatusDto,
+    #[serde(rename = "type", default)]
+    pub tenant_type: Option<String>,
+    /// New parent tenant; the tenant becomes a root if absent.
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub parent_id: Option<Uuid>,
+}
+
+impl UpdateTenantRequest {
+    /// The tenant `id` as described by this request.
+    #[must_use]
+    pub fn into_tenant(self, id: Uuid) -> TenantInfo {
+        TenantInfo {
+            id,
+            name: self.name,
+            status: self.status.into(),
+            tenant_type: self.tenant_type,
+            parent_id: self.parent_id,
+        }
+    }
+}
+
+/// Access of `source_id` to the data of `target_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccessGrantDto {
+    #[schema(value_type = String)]
+    pub source_id: Uuid,
+    #[schema(value_type = String)]
+    pub target_id: Uuid,
+    /// Whether access extends to the descendants of the target.
+    pub include_descendants: bool,
+}
+
+impl From<AccessGrant> for AccessGrantDto {
+    fn from(grant: AccessGrant) -> Self {
+        Self {
+            source_id: grant.source_id,
+            target_id: grant.target_id,
+            include_descendants: grant.include_descendants,
+        }
+    }
+}
+
+/// Request to grant a tenant access to another one.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAccessGrantRequest {
+    #[schema(value_type = String)]
+    pub source_id: Uuid,
+    #[schema(value_type = String)]
+    pub target_id: Uuid,
+    /// Whether access extends to the descendants of the target (defaults to false).
+    #[serde(default)]
+    pub include_descendants: bool,
+}
+
+impl From<CreateAccessGrantRequest> for AccessGrant {
+    fn from(req: CreateAccessGrantRequest) -> Self {
+        Self {
+            source_id: req.source_id,
+            target_id: req.target_id,
+            include_descendants: req.include_descendants,
+        }
+    }
+}
+
+/// Query parameters for listing access grants.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListAccessGrantsQuery {
+    /// Only the grants of this source tenant.
+    #[serde(default)]
+    pub source_id: Option<Uuid>,
+}
@@ -0,0 +1,107 @@
+//! REST error mapping for the database tenant resolver plugin.
+
+use modkit::api::prelude::StatusCode;
+use modkit::api::problem::Problem;
+
+use crate::domain::error::DomainError;
+
+impl From<DomainError> for Problem {
+    fn from(e: DomainError) -> Self {
+        let trace_id = tracing::Span::current()
+            .id()
+            .map(|id| id.into_u64().to_string());
+
+        let (status, code, title) = match &e {
+            DomainError::TenantNotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "TENANT_RESOLVER_TENANT_NOT_FOUND",
+                "Tenant not found",
+            ),
+            DomainError::TenantAlreadyExists(_) => (
+                StatusCode::CONFLICT,
+                "TENANT_RESOLVER_TENANT_ALREADY_EXISTS",
+                "Tenant already exists",
+            ),
+            DomainError::TenantHasChildren(_) => (
+                StatusCode::CONFLICT,
+                "TENANT_RESOLVER_TENANT_HAS_CHILDREN",
+                "Tenant has child tenants",
+            ),
+            DomainError::GrantNotFound { .. } => (
+                StatusCode::NOT_FOUND,
+                "TENANT_RESOLVER_GRANT_NOT_FOUND",
+                "Access grant not found",
+            ),
+            DomainError::GrantAlreadyExists { .. } => (
+                StatusCode::CONFLICT,
+                "TENANT_RESOLVER_GRANT_ALREADY_EXISTS",
+                "Access grant already exists",
+            ),
+            DomainError::Validation(_) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "TENANT_RESOLVER_VALIDATION_FAILED",
+                "Validation failed",
+            ),
+            DomainError::Internal(e) => {
+                tracing::error!(error = ?e, "Internal error in db_tr_plugin");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "TENANT_RESOLVER_INTERNAL",
+                    "Internal Server Error",
+                )
+            }
+        };
+
+        let detail = match e {
+            DomainError::Internal(_) => "An internal error occurred".to_owned(),
+            other => other.to_string(),
+        };
+
+        let mut problem = Problem::new(status, title, detail)
+            .with_type(format!("https://errors.hyperspot.com/{code}"))
+            .with_code(code);
+
+        if let Some(id) = trace_id {
+            problem = problem.with_trace_id(id);
+        }
+
+        problem
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_domain_error_to_problem_status() {
+        let id = Uuid::nil();
+        let cases = [
+            (DomainError::TenantNotFound(id), StatusCode::NOT_FOUND),
+            (DomainError::TenantAlreadyExists(id), StatusCode::CONFLICT),
+            (DomainError::TenantHasChildren(id), StatusCode::CONFLICT),
+            (
+                DomainError::GrantNotFound {
+                    source_id: id,
+                    target_id: id,
+                },
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                DomainError::validation("bad parent"),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                DomainError::Internal(anyhow::anyhow!("db down")),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+
+        for (err, status) in cases {
+            let problem: Problem = err.into();
+            assert_eq!(problem.status, status);
+        }
+    }
+}
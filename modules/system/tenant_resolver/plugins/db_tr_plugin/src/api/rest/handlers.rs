@@ -0,0 +1,102 @@
+//! REST handlers for the database tenant resolver plugin.
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Path, Query};
+use axum::http::Uri;
+use axum::response::Response;
+use modkit::api::prelude::*;
+use modkit_security::SecurityContext;
+use uuid::Uuid;
+
+use super::dto::{
+    AccessGrantDto, CreateAccessGrantRequest, CreateTenantRequest, ListAccessGrantsQuery,
+    TenantDto, UpdateTenantRequest,
+};
+use crate::domain::Service;
+
+/// GET /db-tr-plugin/v1/tenants
+pub async fn list_tenants(
+    Extension(svc): Extension<Arc<Service>>,
+) -> ApiResult<JsonBody<Vec<TenantDto>>> {
+    let tenants = svc.list_tenants().await?;
+    Ok(Json(tenants.into_iter().map(Into::into).collect()))
+}
+
+/// GET /db-tr-plugin/v1/tenants/{id}
+pub async fn get_tenant(
+    Extension(svc): Extension<Arc<Service>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<JsonBody<TenantDto>> {
+    let tenant = svc.get_tenant(id).await?;
+    Ok(Json(tenant.into()))
+}
+
+/// POST /db-tr-plugin/v1/tenants
+pub async fn create_tenant(
+    uri: Uri,
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<Arc<Service>>,
+    Json(req): Json<CreateTenantRequest>,
+) -> ApiResult<Response> {
+    let tenant = svc.create_tenant(req.into()).await?;
+    svc.invalidate_gateway_cache(&ctx).await;
+    let id = tenant.id.to_string();
+    Ok(created_json(TenantDto::from(tenant), &uri, &id).into_response())
+}
+
+/// PUT /db-tr-plugin/v1/tenants/{id}
+pub async fn update_tenant(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<Arc<Service>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateTenantRequest>,
+) -> ApiResult<JsonBody<TenantDto>> {
+    let tenant = svc.update_tenant(req.into_tenant(id)).await?;
+    svc.invalidate_gateway_cache(&ctx).await;
+    Ok(Json(tenant.into()))
+}
+
+/// DELETE /db-tr-plugin/v1/tenants/{id}
+pub async fn delete_tenant(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<Arc<Service>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Response> {
+    svc.delete_tenant(id).await?;
+    svc.invalidate_gateway_cache(&ctx).await;
+    Ok(no_content().into_response())
+}
+
+/// GET /db-tr-plugin/v1/access-grants
+pub async fn list_grants(
+    Extension(svc): Extension<Arc<Service>>,
+    Query(query): Query<ListAccessGrantsQuery>,
+) -> ApiResult<JsonBody<Vec<AccessGrantDto>>> {
+    let grants = svc.list_grants(query.source_id).await?;
+    Ok(Json(grants.into_iter().map(Into::into).collect()))
+}
+
+/// POST /db-tr-plugin/v1/access-grants
+pub async fn create_grant(
+    uri: Uri,
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<Arc<Service>>,
+    Json(req): Json<CreateAccessGrantRequest>,
+) -> ApiResult<Response> {
+    let grant = svc.create_grant(req.into()).await?;
+    svc.invalidate_gateway_cache(&ctx).await;
+    let id = format!("{}/{}", grant.source_id, grant.target_id);
+    Ok(created_json(AccessGrantDto::from(grant), &uri, &id).into_response())
+}
+
+/// DELETE /db-tr-plugin/v1/access-grants/{source_id}/{target_id}
+pub async fn delete_grant(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<Arc<Service>>,
+    Path((source_id, target_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Response> {
+    svc.delete_grant(source_id, target_id).await?;
+    svc.invalidate_gateway_cache(&ctx).await;
+    Ok(no_content().into_response())
+}
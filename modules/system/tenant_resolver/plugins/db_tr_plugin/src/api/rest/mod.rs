@@ -0,0 +1,6 @@
+//! REST API for managing tenants and access grants.
+
+pub mod dto;
+pub mod error;
+pub mod handlers;
+pub mod routes;
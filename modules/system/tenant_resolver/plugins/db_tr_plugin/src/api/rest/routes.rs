@@ -0,0 +1,189 @@
+//! REST route registration for the database tenant resolver plugin.
+
+use std::sync::Arc;
+
+use axum::{Extension, Router};
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::{
+    AuthReqAction, AuthReqResource, LicenseFeature, OperationBuilder,
+};
+use modkit::api::prelude::StatusCode;
+
+use super::dto::{
+    AccessGrantDto, CreateAccessGrantRequest, CreateTenantRequest, TenantDto, UpdateTenantRequest,
+};
+use super::handlers;
+use crate::domain::Service;
+
+const TAG: &str = "tenants";
+
+enum Resource {
+    Tenants,
+    AccessGrants,
+}
+
+enum Action {
+    Read,
+    Write,
+}
+
+impl AsRef<str> for Resource {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Resource::Tenants => "tenants",
+            Resource::AccessGrants => "tenant_access_grants",
+        }
+    }
+}
+
+impl AuthReqResource for Resource {}
+
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Action::Read => "read",
+            Action::Write => "write",
+        }
+    }
+}
+
+impl AuthReqAction for Action {}
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
+/// Registers the tenant and access grant management routes.
+#[allow(clippy::needless_pass_by_value)]
+pub fn register_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+    service: Arc<Service>,
+) -> Router {
+    // GET /db-tr-plugin/v1/tenants - List tenants
+    router = OperationBuilder::get("/db-tr-plugin/v1/tenants")
+        .operation_id("db_tr_plugin.list_tenants")
+        .summary("List tenants")
+        .description("List all tenants managed by the plugin, ordered by name.")
+        .tag(TAG)
+        .require_auth(&Resource::Tenants, &Action::Read)
+        .require_license_features::<License>([])
+        .handler(handlers::list_tenants)
+        .json_response_with_schema::<Vec<TenantDto>>(openapi, StatusCode::OK, "Tenants")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // POST /db-tr-plugin/v1/tenants - Create a tenant
+    router = OperationBuilder::post("/db-tr-plugin/v1/tenants")
+        .operation_id("db_tr_plugin.create_tenant")
+        .summary("Create tenant")
+        .description("Create a root tenant, or a child of an existing tenant.")
+        .tag(TAG)
+        .require_auth(&Resource::Tenants, &Action::Write)
+        .require_license_features::<License>([])
+        .json_request::<CreateTenantRequest>(openapi, "Tenant to create")
+        .handler(handlers::create_tenant)
+        .json_response_with_schema::<TenantDto>(openapi, StatusCode::CREATED, "Created tenant")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // GET /db-tr-plugin/v1/tenants/{id} - Get a tenant
+    router = OperationBuilder::get("/db-tr-plugin/v1/tenants/{id}")
+        .operation_id("db_tr_plugin.get_tenant")
+        .summary("Get tenant")
+        .description("Retrieve a tenant by its ID.")
+        .tag(TAG)
+        .require_auth(&Resource::Tenants, &Action::Read)
+        .require_license_features::<License>([])
+        .path_param("id", "Tenant UUID")
+        .handler(handlers::get_tenant)
+        .json_response_with_schema::<TenantDto>(openapi, StatusCode::OK, "Tenant found")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // PUT /db-tr-plugin/v1/tenants/{id} - Replace a tenant
+    router = OperationBuilder::put("/db-tr-plugin/v1/tenants/{id}")
+        .operation_id("db_tr_plugin.update_tenant")
+        .summary("Update tenant")
+        .description("Replace the name, status, type and parent of a tenant.")
+        .tag(TAG)
+        .require_auth(&Resource::Tenants, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("id", "Tenant UUID")
+        .json_request::<UpdateTenantRequest>(openapi, "New tenant data")
+        .handler(handlers::update_tenant)
+        .json_response_with_schema::<TenantDto>(openapi, StatusCode::OK, "Updated tenant")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // DELETE /db-tr-plugin/v1/tenants/{id} - Delete a tenant
+    router = OperationBuilder::delete("/db-tr-plugin/v1/tenants/{id}")
+        .operation_id("db_tr_plugin.delete_tenant")
+        .summary("Delete tenant")
+        .description("Delete a tenant without child tenants, together with its access grants.")
+        .tag(TAG)
+        .require_auth(&Resource::Tenants, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("id", "Tenant UUID")
+        .handler(handlers::delete_tenant)
+        .json_response(StatusCode::NO_CONTENT, "Tenant deleted")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // GET /db-tr-plugin/v1/access-grants - List access grants
+    router = OperationBuilder::get("/db-tr-plugin/v1/access-grants")
+        .operation_id("db_tr_plugin.list_access_grants")
+        .summary("List access grants")
+        .description("List access grants, optionally only those of one source tenant.")
+        .tag(TAG)
+        .require_auth(&Resource::AccessGrants, &Action::Read)
+        .require_license_features::<License>([])
+        .query_param("source_id", false, "Only the grants of this source tenant")
+        .handler(handlers::list_grants)
+        .json_response_with_schema::<Vec<AccessGrantDto>>(openapi, StatusCode::OK, "Grants")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // POST /db-tr-plugin/v1/access-grants - Grant access
+    router = OperationBuilder::post("/db-tr-plugin/v1/access-grants")
+        .operation_id("db_tr_plugin.create_access_grant")
+        .summary("Create access grant")
+        .description(
+            "Grant the source tenant access to the target tenant and, with include_descendants, to its descendants.",
+        )
+        .tag(TAG)
+        .require_auth(&Resource::AccessGrants, &Action::Write)
+        .require_license_features::<License>([])
+        .json_request::<CreateAccessGrantRequest>(openapi, "Grant to create")
+        .handler(handlers::create_grant)
+        .json_response_with_schema::<AccessGrantDto>(
+            openapi,
+            StatusCode::CREATED,
+            "Created grant",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // DELETE /db-tr-plugin/v1/access-grants/{source_id}/{target_id} - Revoke access
+    router = OperationBuilder::delete("/db-tr-plugin/v1/access-grants/{source_id}/{target_id}")
+        .operation_id("db_tr_plugin.delete_access_grant")
+        .summary("Delete access grant")
+        .description("Revoke the access of the source tenant to the target tenant.")
+        .tag(TAG)
+        .require_auth(&Resource::AccessGrants, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param("source_id", "Source tenant UUID")
+        .path_param("target_id", "Target tenant UUID")
+        .handler(handlers::delete_grant)
+        .json_response(StatusCode::NO_CONTENT, "Grant deleted")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router.layer(Extension(service))
+}
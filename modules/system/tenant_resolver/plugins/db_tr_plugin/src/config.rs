@@ -0,0 +1,26 @@
+//! Configuration for the database tenant resolver plugin.
+
+use serde::Deserialize;
+
+/// Plugin configuration.
+///
+/// Tenants and access grants live in the module database and are managed
+/// through the REST API.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DbTrPluginConfig {
+    /// Vendor name for GTS instance registration.
+    pub vendor: String,
+
+    /// Plugin priority (lower = higher priority).
+    pub priority: i16,
+}
+
+impl Default for DbTrPluginConfig {
+    fn default() -> Self {
+        Self {
+            vendor: "hyperspot".to_owned(),
+            priority: 50,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Client implementation for the database tenant resolver plugin.
+//!
+//! Implements `TenantResolverPluginClient` using the domain service.
+
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use modkit_security::SecurityContext;
+use tenant_resolver_sdk::{
+    AccessOptions, TenantFilter, TenantId, TenantInfo, TenantResolverError,
+    TenantResolverPluginClient,
+};
+
+use super::service::Service;
+
+#[async_trait]
+impl TenantResolverPluginClient for Service {
+    async fn get_tenant(
+        &self,
+        _ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<TenantInfo, TenantResolverError> {
+        // The inherent method, not this one
+        Ok(Service::get_tenant(self, id).await?)
+    }
+
+    async fn can_access(
+        &self,
+        ctx: &SecurityContext,
+        target: TenantId,
+        _options: Option<&AccessOptions>,
+    ) -> Result<bool, TenantResolverError> {
+        let source = ctx.tenant_id();
+
+        // Also checks that the target exists
+        let ancestors = self.ancestors(target).await?;
+
+        // Self-access is always allowed
+        if source == target {
+            return Ok(true);
+        }
+
+        let grants = self.repo.list_grants(Some(source)).await?;
+        if grants.iter().any(|g| g.target_id == target) {
+            return Ok(true);
+        }
+
+        // Access to an ancestor (own or granted with descendants) covers the target
+        Ok(ancestors.iter().any(|a| {
+            a.id == source
+                || grants
+                    .iter()
+                    .any(|g| g.include_descendants && g.target_id == a.id)
+        }))
+    }
+
+    async fn get_accessible_tenants(
+        &self,
+        ctx: &SecurityContext,
+        filter: Option<&TenantFilter>,
+        _options: Option<&AccessOptions>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        let source = ctx.tenant_id();
+
+        // Self first, then the own subtree, then the granted tenants with their subtrees
+        let mut reachable = Vec::new();
+        reachable.extend(self.repo.find_tenant(source).await?);
+        reachable.extend(self.subtree(source).await?);
+
+        let grants = self.repo.list_grants(Some(source)).await?;
+        let targets: Vec<TenantId> = grants.iter().map(|g| g.target_id).collect();
+        let mut targets: HashMap<TenantId, TenantInfo> = self
+            .repo
+            .find_tenants(&targets)
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+        for grant in &grants {
+            if let Some(target) = targets.remove(&grant.target_id) {
+                reachable.push(target);
+            }
+            if grant.include_descendants {
+                reachable.extend(self.subtree(grant.target_id).await?);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        Ok(reachable
+            .into_iter()
+            .filter(|t| seen.insert(t.id) && matches_filter(t, filter))
+            .collect())
+    }
+
+    async fn get_ancestors(
+        &self,
+        _ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Ok(self.ancestors(id).await?)
+    }
+
+    async fn get_descendants(
+        &self,
+        _ctx: &SecurityContext,
+        id: TenantId,
+        filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Ok(self
+            .descendants(id)
+            .await?
+            .into_iter()
+            .filter(|t| matches_filter(t, filter))
+            .collect())
+    }
+}
+
+/// Checks if a tenant matches the filter criteria.
+fn matches_filter(tenant: &TenantInfo, filter: Option<&TenantFilter>) -> bool {
+    let Some(filter) = filter else {
+        return true;
+    };
+
+    (filter.id.is_empty() || filter.id.contains(&tenant.id))
+        && (filter.status.is_empty() || filter.status.contains(&tenant.status))
+}
@@ -0,0 +1,59 @@
+//! Domain error types for the database tenant resolver plugin.
+
+use tenant_resolver_sdk::{TenantId, TenantResolverError};
+use thiserror::Error;
+
+/// Domain-level errors for the database tenant resolver plugin.
+#[derive(Error, Debug)]
+pub enum DomainError {
+    /// The requested tenant was not found.
+    #[error("Tenant not found: {0}")]
+    TenantNotFound(TenantId),
+
+    /// A tenant with the same ID already exists.
+    #[error("Tenant already exists: {0}")]
+    TenantAlreadyExists(TenantId),
+
+    /// The tenant cannot be deleted while it still has child tenants.
+    #[error("Tenant {0} has child tenants")]
+    TenantHasChildren(TenantId),
+
+    /// The requested access grant was not found.
+    #[error("Access grant not found: {source_id} -> {target_id}")]
+    GrantNotFound {
+        source_id: TenantId,
+        target_id: TenantId,
+    },
+
+    /// An access grant between the same tenants already exists.
+    #[error("Access grant already exists: {source_id} -> {target_id}")]
+    GrantAlreadyExists {
+        source_id: TenantId,
+        target_id: TenantId,
+    },
+
+    /// The request is invalid (e.g. unknown parent or a cycle in the hierarchy).
+    #[error("Validation failed: {0}")]
+    Validation(String),
+
+    /// An internal error occurred.
+    #[error("Internal error: {0}")]
+    Internal(#[from] anyhow::Error),
+}
+
+impl DomainError {
+    /// Creates a validation error.
+    #[must_use]
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation(message.into())
+    }
+}
+
+impl From<DomainError> for TenantResolverError {
+    fn from(e: DomainError) -> Self {
+        match e {
+            DomainError::TenantNotFound(tenant_id) => Self::TenantNotFound { tenant_id },
+            other => Self::Internal(other.to_string()),
+        }
+    }
+}
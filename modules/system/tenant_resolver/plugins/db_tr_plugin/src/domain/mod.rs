@@ -0,0 +1,12 @@
+//! Domain layer for the database tenant resolver plugin.
+
+mod client;
+pub mod error;
+pub mod model;
+pub mod repo;
+pub mod service;
+
+pub use error::DomainError;
+pub use model::AccessGrant;
+pub use repo::TenantRepository;
+pub use service::Service;
@@ -0,0 +1,14 @@
+//! Domain models for the database tenant resolver plugin.
+
+use tenant_resolver_sdk::TenantId;
+
+/// Access of `source_id` to the data of `target_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessGrant {
+    /// Tenant being granted access.
+    pub source_id: TenantId,
+    /// Tenant whose data becomes accessible.
+    pub target_id: TenantId,
+    /// Whether access extends to the descendants of `target_id`.
+    pub include_descendants: bool,
+}
@@ -0,0 +1,56 @@
+//! Storage abstraction for tenants and access grants.
+
+use async_trait::async_trait;
+use tenant_resolver_sdk::{TenantId, TenantInfo};
+
+use super::error::DomainError;
+use super::model::AccessGrant;
+
+/// Persistence of tenants and access grants.
+#[async_trait]
+pub trait TenantRepository: Send + Sync {
+    /// Returns the tenant with the given ID, if any.
+    async fn find_tenant(&self, id: TenantId) -> Result<Option<TenantInfo>, DomainError>;
+
+    /// Returns the tenants with the given IDs; unknown IDs are skipped.
+    async fn find_tenants(&self, ids: &[TenantId]) -> Result<Vec<TenantInfo>, DomainError>;
+
+    /// Returns the direct children of the given tenants.
+    async fn find_children(&self, parents: &[TenantId]) -> Result<Vec<TenantInfo>, DomainError>;
+
+    /// Returns all tenants, ordered by name.
+    async fn list_tenants(&self) -> Result<Vec<TenantInfo>, DomainError>;
+
+    /// Stores a new tenant.
+    async fn insert_tenant(&self, tenant: &TenantInfo) -> Result<(), DomainError>;
+
+    /// Replaces a stored tenant; returns `false` if it does not exist.
+    async fn update_tenant(&self, tenant: &TenantInfo) -> Result<bool, DomainError>;
+
+    /// Deletes a tenant together with the grants it takes part in;
+    /// returns `false` if it does not exist.
+    async fn delete_tenant(&self, id: TenantId) -> Result<bool, DomainError>;
+
+    /// Returns the grant from `source_id` to `target_id`, if any.
+    async fn find_grant(
+        &self,
+        source_id: TenantId,
+        target_id: TenantId,
+    ) -> Result<Option<AccessGrant>, DomainError>;
+
+    /// Returns the grants of `source_id`, or all grants if `None`.
+    async fn list_grants(
+        &self,
+        source_id: Option<TenantId>,
+    ) -> Result<Vec<AccessGrant>, DomainError>;
+
+    /// Stores a new grant.
+    async fn insert_grant(&self, grant: &AccessGrant) -> Result<(), DomainError>;
+
+    /// Deletes a grant; returns `false` if it does not exist.
+    async fn delete_grant(
+        &self,
+        source_id: TenantId,
+        target_id: TenantId,
+    ) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,258 @@
+//! Domain service for the database tenant resolver plugin.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use modkit::client_hub::ClientHub;
+use modkit_security::SecurityContext;
+use tenant_resolver_sdk::{TenantId, TenantInfo, TenantResolverGatewayClient};
+
+use super::error::DomainError;
+use super::model::AccessGrant;
+use super::repo::TenantRepository;
+
+/// Database tenant resolver service.
+///
+/// Backs both the plugin client used by the gateway and the management REST API.
+pub struct Service {
+    pub(super) repo: Arc<dyn TenantRepository>,
+    /// Where the tenant resolver gateway is looked up to invalidate its cache.
+    hub: Option<Arc<ClientHub>>,
+}
+
+impl Service {
+    /// Creates a new service on top of the given repository.
+    #[must_use]
+    pub fn new(repo: Arc<dyn TenantRepository>) -> Self {
+        Self { repo, hub: None }
+    }
+
+    /// Invalidates the tenant resolver gateway cache found in `hub` after changes.
+    #[must_use]
+    pub fn with_client_hub(mut self, hub: Arc<ClientHub>) -> Self {
+        self.hub = Some(hub);
+        self
+    }
+
+    /// Drops the gateway's cached results after tenants or access grants changed.
+    ///
+    /// Best effort: the change is already stored, and cached results expire anyway.
+    pub async fn invalidate_gateway_cache(&self, ctx: &SecurityContext) {
+        let Some(gateway) = self
+            .hub
+            .as_ref()
+            .and_then(|hub| hub.get::<dyn TenantResolverGatewayClient>().ok())
+        else {
+            return;
+        };
+        if let Err(e) = gateway.invalidate_cache(ctx, None).await {
+            tracing::warn!(error = %e, "Failed to invalidate tenant resolver gateway cache");
+        }
+    }
+
+    /// Returns a tenant by ID.
+    ///
+    /// # Errors
+    /// `TenantNotFound` if there is no such tenant.
+    pub async fn get_tenant(&self, id: TenantId) -> Result<TenantInfo, DomainError> {
+        self.repo
+            .find_tenant(id)
+            .await?
+            .ok_or(DomainError::TenantNotFound(id))
+    }
+
+    /// Returns all tenants, ordered by name.
+    ///
+    /// # Errors
+    /// Storage errors.
+    pub async fn list_tenants(&self) -> Result<Vec<TenantInfo>, DomainError> {
+        self.repo.list_tenants().await
+    }
+
+    /// Creates a tenant below an existing parent (or as a root).
+    ///
+    /// # Errors
+    /// `TenantAlreadyExists` for a duplicate ID, `Validation` for an empty name
+    /// or an unknown parent.
+    pub async fn create_tenant(&self, tenant: TenantInfo) -> Result<TenantInfo, DomainError> {
+        Self::validate_name(&tenant)?;
+        if self.repo.find_tenant(tenant.id).await?.is_some() {
+            return Err(DomainError::TenantAlreadyExists(tenant.id));
+        }
+        if let Some(parent_id) = tenant.parent_id {
+            self.require_parent(parent_id).await?;
+        }
+
+        self.repo.insert_tenant(&tenant).await?;
+        Ok(tenant)
+    }
+
+    /// Replaces a tenant, possibly moving it to another parent.
+    ///
+    /// # Errors
+    /// `TenantNotFound` if there is no such tenant, `Validation` for an empty name,
+    /// an unknown parent or a move below one of its own descendants.
+    pub async fn update_tenant(&self, tenant: TenantInfo) -> Result<TenantInfo, DomainError> {
+        Self::validate_name(&tenant)?;
+        if let Some(parent_id) = tenant.parent_id {
+            self.require_parent(parent_id).await?;
+            if parent_id == tenant.id
+                || self
+                    .ancestors(parent_id)
+                    .await?
+                    .iter()
+                    .any(|a| a.id == tenant.id)
+            {
+                return Err(DomainError::validation(format!(
+                    "moving tenant {} below {parent_id} would create a cycle",
+                    tenant.id
+                )));
+            }
+        }
+
+        if !self.repo.update_tenant(&tenant).await? {
+            return Err(DomainError::TenantNotFound(tenant.id));
+        }
+        Ok(tenant)
+    }
+
+    /// Deletes a tenant without children, together with its access grants.
+    ///
+    /// # Errors
+    /// `TenantNotFound` if there is no such tenant, `TenantHasChildren` if it
+    /// still has child tenants.
+    pub async fn delete_tenant(&self, id: TenantId) -> Result<(), DomainError> {
+        if !self.repo.find_children(&[id]).await?.is_empty() {
+            return Err(DomainError::TenantHasChildren(id));
+        }
+        if !self.repo.delete_tenant(id).await? {
+            return Err(DomainError::TenantNotFound(id));
+        }
+        Ok(())
+    }
+
+    /// Returns the grants of `source_id`, or all grants if `None`.
+    ///
+    /// # Errors
+    /// Storage errors.
+    pub async fn list_grants(
+        &self,
+        source_id: Option<TenantId>,
+    ) -> Result<Vec<AccessGrant>, DomainError> {
+        self.repo.list_grants(source_id).await
+    }
+
+    /// Grants `source_id` access to `target_id`.
+    ///
+    /// # Errors
+    /// `TenantNotFound` for an unknown tenant, `GrantAlreadyExists` for a duplicate,
+    /// `Validation` for a grant of a tenant to itself.
+    pub async fn create_grant(&self, grant: AccessGrant) -> Result<AccessGrant, DomainError> {
+        if grant.source_id == grant.target_id {
+            return Err(DomainError::validation(
+                "a tenant always has access to itself",
+            ));
+        }
+        self.get_tenant(grant.source_id).await?;
+        self.get_tenant(grant.target_id).await?;
+        if self
+            .repo
+            .find_grant(grant.source_id, grant.target_id)
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::GrantAlreadyExists {
+                source_id: grant.source_id,
+                target_id: grant.target_id,
+            });
+        }
+
+        self.repo.insert_grant(&grant).await?;
+        Ok(grant)
+    }
+
+    /// Revokes the grant from `source_id` to `target_id`.
+    ///
+    /// # Errors
+    /// `GrantNotFound` if there is no such grant.
+    pub async fn delete_grant(
+        &self,
+        source_id: TenantId,
+        target_id: TenantId,
+    ) -> Result<(), DomainError> {
+        if !self.repo.delete_grant(source_id, target_id).await? {
+            return Err(DomainError::GrantNotFound {
+                source_id,
+                target_id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the ancestors of a tenant, from its parent up to the root.
+    ///
+    /// # Errors
+    /// `TenantNotFound` if there is no such tenant.
+    pub async fn ancestors(&self, id: TenantId) -> Result<Vec<TenantInfo>, DomainError> {
+        let mut parent_id = self.get_tenant(id).await?.parent_id;
+        let mut seen = HashSet::from([id]);
+        let mut ancestors = Vec::new();
+
+        // The seen set guards against cycles written to the tables directly
+        while let Some(current) = parent_id.filter(|p| seen.insert(*p)) {
+            let Some(parent) = self.repo.find_tenant(current).await? else {
+                break;
+            };
+            parent_id = parent.parent_id;
+            ancestors.push(parent);
+        }
+
+        Ok(ancestors)
+    }
+
+    /// Returns the subtree below a tenant, level by level (parents before children).
+    ///
+    /// # Errors
+    /// `TenantNotFound` if there is no such tenant.
+    pub async fn descendants(&self, id: TenantId) -> Result<Vec<TenantInfo>, DomainError> {
+        self.get_tenant(id).await?;
+        self.subtree(id).await
+    }
+
+    /// Like [`Self::descendants`], but an unknown tenant simply has none.
+    pub(super) async fn subtree(&self, id: TenantId) -> Result<Vec<TenantInfo>, DomainError> {
+        let mut seen = HashSet::from([id]);
+        let mut level = vec![id];
+        let mut descendants = Vec::new();
+
+        while !level.is_empty() {
+            let children: Vec<TenantInfo> = self
+                .repo
+                .find_children(&level)
+                .await?
+                .into_iter()
+                .filter(|c| seen.insert(c.id))
+                .collect();
+            level = children.iter().map(|c| c.id).collect();
+            descendants.extend(children);
+        }
+
+        Ok(descendants)
+    }
+
+    async fn require_parent(&self, parent_id: TenantId) -> Result<(), DomainError> {
+        if self.repo.find_tenant(parent_id).await?.is_none() {
+            return Err(DomainError::validation(format!(
+                "parent tenant {parent_id} does not exist"
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_name(tenant: &TenantInfo) -> Result<(), DomainError> {
+        if tenant.name.trim().is_empty() {
+            return Err(DomainError::validation("tenant name must not be empty"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+//! Infrastructure layer for the database tenant resolver plugin.
+
+pub mod storage;
+
+pub use storage::DbTenantRepository;
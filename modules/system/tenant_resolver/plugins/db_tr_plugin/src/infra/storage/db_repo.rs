@@ -0,0 +1,229 @@
+//! Database-backed repository implementation using `modkit-db`.
+
+use async_trait::async_trait;
+use modkit_db::secure::SecureConn;
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use tenant_resolver_sdk::{TenantId, TenantInfo, TenantStatus};
+
+use super::entity::{access_grant, tenant};
+use crate::domain::error::DomainError;
+use crate::domain::model::AccessGrant;
+use crate::domain::repo::TenantRepository;
+
+/// Database-backed repository for tenants and access grants.
+///
+/// Both tables are global (`#[secure(unrestricted)]`): the plugin answers for every
+/// tenant, so there is no access scope to apply to its queries.
+pub struct DbTenantRepository {
+    db: SecureConn,
+}
+
+impl DbTenantRepository {
+    #[must_use]
+    pub fn new(db: SecureConn) -> Self {
+        Self { db }
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn select_tenants(
+        &self,
+        condition: Option<sea_orm::Condition>,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        let mut query = tenant::Entity::find();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        query
+            .order_by_asc(tenant::Column::Name)
+            .order_by_asc(tenant::Column::Id)
+            .all(self.db.conn())
+            .await
+            .map_err(storage_error)?
+            .into_iter()
+            .map(tenant_info)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TenantRepository for DbTenantRepository {
+    async fn find_tenant(&self, id: TenantId) -> Result<Option<TenantInfo>, DomainError> {
+        let condition = sea_orm::Condition::all().add(tenant::Column::Id.eq(id));
+        Ok(self.select_tenants(Some(condition)).await?.pop())
+    }
+
+    async fn find_tenants(&self, ids: &[TenantId]) -> Result<Vec<TenantInfo>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let condition = sea_orm::Condition::all().add(tenant::Column::Id.is_in(ids.to_vec()));
+        self.select_tenants(Some(condition)).await
+    }
+
+    async fn find_children(&self, parents: &[TenantId]) -> Result<Vec<TenantInfo>, DomainError> {
+        if parents.is_empty() {
+            return Ok(Vec::new());
+        }
+        let condition =
+            sea_orm::Condition::all().add(tenant::Column::ParentId.is_in(parents.to_vec()));
+        self.select_tenants(Some(condition)).await
+    }
+
+    async fn list_tenants(&self) -> Result<Vec<TenantInfo>, DomainError> {
+        self.select_tenants(None).await
+    }
+
+    async fn insert_tenant(&self, tenant: &TenantInfo) -> Result<(), DomainError> {
+        tenant::Entity::insert(tenant_model(tenant))
+            .exec_without_returning(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    async fn update_tenant(&self, tenant: &TenantInfo) -> Result<bool, DomainError> {
+        match tenant::Entity::update(tenant_model(tenant))
+            .exec(self.db.conn())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(sea_orm::DbErr::RecordNotUpdated) => Ok(false),
+            Err(e) => Err(storage_error(e)),
+        }
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn delete_tenant(&self, id: TenantId) -> Result<bool, DomainError> {
+        let deleted = self
+            .db
+            .transaction(move |txn| {
+                Box::pin(async move {
+                    access_grant::Entity::delete_many()
+                        .filter(
+                            sea_orm::Condition::any()
+                                .add(access_grant::Column::SourceId.eq(id))
+                                .add(access_grant::Column::TargetId.eq(id)),
+                        )
+                        .exec(txn)
+                        .await?;
+                    let result = tenant::Entity::delete_many()
+                        .filter(tenant::Column::Id.eq(id))
+                        .exec(txn)
+                        .await?;
+                    Ok(result.rows_affected > 0)
+                })
+            })
+            .await?;
+        Ok(deleted)
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn find_grant(
+        &self,
+        source_id: TenantId,
+        target_id: TenantId,
+    ) -> Result<Option<AccessGrant>, DomainError> {
+        let grant = access_grant::Entity::find_by_id((source_id, target_id))
+            .one(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(grant.as_ref().map(access_grant))
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn list_grants(
+        &self,
+        source_id: Option<TenantId>,
+    ) -> Result<Vec<AccessGrant>, DomainError> {
+        let mut query = access_grant::Entity::find();
+        if let Some(source_id) = source_id {
+            query = query.filter(access_grant::Column::SourceId.eq(source_id));
+        }
+        let grants = query
+            .order_by_asc(access_grant::Column::SourceId)
+            .order_by_asc(access_grant::Column::TargetId)
+            .all(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(grants.iter().map(access_grant).collect())
+    }
+
+    async fn insert_grant(&self, grant: &AccessGrant) -> Result<(), DomainError> {
+        let model = access_grant::ActiveModel {
+            source_id: ActiveValue::Set(grant.source_id),
+            target_id: ActiveValue::Set(grant.target_id),
+            include_descendants: ActiveValue::Set(grant.include_descendants),
+        };
+        access_grant::Entity::insert(model)
+            .exec_without_returning(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn delete_grant(
+        &self,
+        source_id: TenantId,
+        target_id: TenantId,
+    ) -> Result<bool, DomainError> {
+        let result = access_grant::Entity::delete_many()
+            .filter(access_grant::Column::SourceId.eq(source_id))
+            .filter(access_grant::Column::TargetId.eq(target_id))
+            .exec(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn storage_error(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Internal(e.into())
+}
+
+fn tenant_model(tenant: &TenantInfo) -> tenant::ActiveModel {
+    tenant::ActiveModel {
+        id: ActiveValue::Set(tenant.id),
+        name: ActiveValue::Set(tenant.name.clone()),
+        status: ActiveValue::Set(status_to_str(tenant.status).to_owned()),
+        tenant_type: ActiveValue::Set(tenant.tenant_type.clone()),
+        parent_id: ActiveValue::Set(tenant.parent_id),
+    }
+}
+
+fn tenant_info(model: tenant::Model) -> Result<TenantInfo, DomainError> {
+    Ok(TenantInfo {
+        id: model.id,
+        status: status_from_str(&model.status)?,
+        name: model.name,
+        tenant_type: model.tenant_type,
+        parent_id: model.parent_id,
+    })
+}
+
+fn access_grant(model: &access_grant::Model) -> AccessGrant {
+    AccessGrant {
+        source_id: model.source_id,
+        target_id: model.target_id,
+        include_descendants: model.include_descendants,
+    }
+}
+
+fn status_to_str(status: TenantStatus) -> &'static str {
+    match status {
+        TenantStatus::Active => "active",
+        TenantStatus::Suspended => "suspended",
+        TenantStatus::Deleted => "deleted",
+    }
+}
+
+fn status_from_str(status: &str) -> Result<TenantStatus, DomainError> {
+    match status {
+        "active" => Ok(TenantStatus::Active),
+        "suspended" => Ok(TenantStatus::Suspended),
+        "deleted" => Ok(TenantStatus::Deleted),
+        other => Err(DomainError::Internal(anyhow::anyhow!(
+            "unknown tenant status in storage: {other}"
+        ))),
+    }
+}
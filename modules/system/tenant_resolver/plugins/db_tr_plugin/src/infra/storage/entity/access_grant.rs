@@ -0,0 +1,25 @@
+//! `SeaORM` entity for access grants.
+
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Access of `source_id` to the data of `target_id`.
+///
+/// Grants span tenants, hence `unrestricted`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "access_grants")]
+#[secure(unrestricted)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub source_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub target_id: Uuid,
+    /// Whether the grant extends to the descendants of the target.
+    pub include_descendants: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,4 @@
+//! `SeaORM` entities for the database tenant resolver plugin.
+
+pub mod access_grant;
+pub mod tenant;
@@ -0,0 +1,26 @@
+//! `SeaORM` entity for tenants.
+
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// A tenant managed by the plugin.
+///
+/// The tenant resolver answers across tenants, hence `unrestricted`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "tenants")]
+#[secure(unrestricted)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    /// Lifecycle status in its `snake_case` serialized form.
+    pub status: String,
+    pub tenant_type: Option<String>,
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
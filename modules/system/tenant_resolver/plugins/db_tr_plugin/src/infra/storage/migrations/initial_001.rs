@@ -0,0 +1,91 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        // One statement per call: MySQL does not accept several in one query
+        let statements: &[&str] = match backend {
+            sea_orm::DatabaseBackend::Postgres => &[
+                r"
+CREATE TABLE IF NOT EXISTS tenants (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    tenant_type VARCHAR(255),
+    parent_id UUID
+);
+                ",
+                "CREATE INDEX IF NOT EXISTS idx_tenants_parent_id ON tenants (parent_id);",
+                r"
+CREATE TABLE IF NOT EXISTS access_grants (
+    source_id UUID NOT NULL,
+    target_id UUID NOT NULL,
+    include_descendants BOOLEAN NOT NULL,
+    PRIMARY KEY (source_id, target_id)
+);
+                ",
+            ],
+            sea_orm::DatabaseBackend::MySql => &[
+                r"
+CREATE TABLE IF NOT EXISTS tenants (
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    tenant_type VARCHAR(255),
+    parent_id VARCHAR(36),
+    INDEX idx_tenants_parent_id (parent_id)
+);
+                ",
+                r"
+CREATE TABLE IF NOT EXISTS access_grants (
+    source_id VARCHAR(36) NOT NULL,
+    target_id VARCHAR(36) NOT NULL,
+    include_descendants BOOLEAN NOT NULL,
+    PRIMARY KEY (source_id, target_id)
+);
+                ",
+            ],
+            sea_orm::DatabaseBackend::Sqlite => &[
+                r"
+CREATE TABLE IF NOT EXISTS tenants (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    status TEXT NOT NULL,
+    tenant_type TEXT,
+    parent_id TEXT
+);
+                ",
+                "CREATE INDEX IF NOT EXISTS idx_tenants_parent_id ON tenants (parent_id);",
+                r"
+CREATE TABLE IF NOT EXISTS access_grants (
+    source_id TEXT NOT NULL,
+    target_id TEXT NOT NULL,
+    include_descendants BOOLEAN NOT NULL,
+    PRIMARY KEY (source_id, target_id)
+);
+                ",
+            ],
+        };
+
+        for sql in statements {
+            conn.execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        conn.execute_unprepared("DROP TABLE IF EXISTS access_grants;")
+            .await?;
+        conn.execute_unprepared("DROP TABLE IF EXISTS tenants;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! Storage implementation for the database tenant resolver plugin.
+
+mod db_repo;
+mod entity;
+pub mod migrations;
+
+pub use db_repo::DbTenantRepository;
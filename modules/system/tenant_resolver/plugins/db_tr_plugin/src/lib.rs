@@ -0,0 +1,33 @@
+//! Database Tenant Resolver Plugin
+//!
+//! This plugin stores tenants and access grants in the module database and
+//! exposes a REST API to manage them, so tenants can be added without
+//! restarting the server.
+//!
+//! ## Configuration
+//!
+//! ```yaml
+//! modules:
+//!   db_tr_plugin:
+//!     database:
+//!       server: "sqlite_users"
+//!       file: "tenants.db"
+//!     config:
+//!       vendor: "hyperspot"
+//!       priority: 50
+//! ```
+
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+pub mod config;
+pub mod module;
+
+pub use module::DbTrPlugin;
+
+// === INTERNAL MODULES ===
+#[doc(hidden)]
+pub mod api;
+#[doc(hidden)]
+pub mod domain;
+#[doc(hidden)]
+pub mod infra;
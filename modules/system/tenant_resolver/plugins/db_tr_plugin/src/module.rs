@@ -0,0 +1,125 @@
+//! Database tenant resolver plugin module.
+
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use axum::Router;
+use modkit::Module;
+use modkit::api::OpenApiRegistry;
+use modkit::client_hub::ClientScope;
+use modkit::context::ModuleCtx;
+use modkit::gts::BaseModkitPluginV1;
+use tenant_resolver_sdk::{TenantResolverPluginClient, TenantResolverPluginSpecV1};
+use tracing::info;
+use types_registry_sdk::TypesRegistryClient;
+
+use crate::api::rest::routes;
+use crate::config::DbTrPluginConfig;
+use crate::domain::Service;
+use crate::infra::DbTenantRepository;
+
+/// Database tenant resolver plugin module.
+///
+/// Provides tenant data and access grants from the module database and a REST API
+/// to manage them.
+///
+/// **Plugin registration pattern:**
+/// - Gateway registers the plugin schema (GTS type definition)
+/// - This plugin registers its instance (implementation metadata)
+/// - This plugin registers its scoped client (implementation in `ClientHub`)
+#[modkit::module(
+    name = "db_tr_plugin",
+    deps = ["types_registry"],
+    capabilities = [db, rest]
+)]
+pub struct DbTrPlugin {
+    service: OnceLock<Arc<Service>>,
+}
+
+impl Default for DbTrPlugin {
+    fn default() -> Self {
+        Self {
+            service: OnceLock::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl modkit::contracts::DatabaseCapability for DbTrPlugin {
+    async fn migrate(&self, db: &modkit_db::DbHandle) -> anyhow::Result<()> {
+        use sea_orm_migration::MigratorTrait;
+
+        info!("Running db_tr_plugin database migrations");
+        let conn = db.sea_secure();
+        crate::infra::storage::migrations::Migrator::up(conn.conn(), None).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Module for DbTrPlugin {
+    async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+        info!("Initializing db_tr_plugin");
+
+        // Load configuration
+        let cfg: DbTrPluginConfig = ctx.config()?;
+        info!(
+            vendor = %cfg.vendor,
+            priority = cfg.priority,
+            "Loaded plugin configuration"
+        );
+
+        // Generate plugin instance ID
+        let instance_id = TenantResolverPluginSpecV1::gts_make_instance_id(
+            "hyperspot.builtin.db_tenant_resolver.plugin.v1",
+        );
+
+        // Register plugin instance in types-registry
+        let registry = ctx.client_hub().get::<dyn TypesRegistryClient>()?;
+        let instance = BaseModkitPluginV1::<TenantResolverPluginSpecV1> {
+            id: instance_id.clone(),
+            vendor: cfg.vendor.clone(),
+            priority: cfg.priority,
+            properties: TenantResolverPluginSpecV1,
+        };
+        let instance_json = serde_json::to_value(&instance)?;
+
+        let _ = registry.register(vec![instance_json]).await?;
+
+        // Create service on top of the module database
+        let db = ctx.db_required()?;
+        let repo = Arc::new(DbTenantRepository::new(db.sea_secure()));
+        let service = Arc::new(Service::new(repo).with_client_hub(ctx.client_hub()));
+        self.service
+            .set(service.clone())
+            .map_err(|_| anyhow::anyhow!("Service already initialized"))?;
+
+        // Register scoped client in ClientHub
+        let api: Arc<dyn TenantResolverPluginClient> = service;
+        ctx.client_hub()
+            .register_scoped::<dyn TenantResolverPluginClient>(
+                ClientScope::gts_id(&instance_id),
+                api,
+            );
+
+        info!(instance_id = %instance_id, "Database plugin initialized");
+        Ok(())
+    }
+}
+
+impl modkit::contracts::RestApiCapability for DbTrPlugin {
+    fn register_rest(
+        &self,
+        _ctx: &ModuleCtx,
+        router: Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> anyhow::Result<Router> {
+        let service = self
+            .service
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("Service not initialized"))?
+            .clone();
+
+        Ok(routes::register_routes(router, openapi, service))
+    }
+}
@@ -0,0 +1,216 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for the database tenant resolver plugin
+
+use std::sync::Arc;
+
+use db_tr_plugin::domain::{AccessGrant, DomainError, Service};
+use db_tr_plugin::infra::DbTenantRepository;
+use db_tr_plugin::infra::storage::migrations::Migrator;
+use modkit_db::{ConnectOpts, DbHandle};
+use modkit_security::SecurityContext;
+use sea_orm_migration::MigratorTrait;
+use tenant_resolver_sdk::{
+    TenantFilter, TenantId, TenantInfo, TenantResolverError, TenantResolverPluginClient,
+    TenantStatus,
+};
+use uuid::Uuid;
+
+const RESELLER: TenantId = Uuid::from_u128(0x44);
+const CUSTOMER: TenantId = Uuid::from_u128(0x55);
+const SUB_ACCOUNT: TenantId = Uuid::from_u128(0x66);
+const PARTNER: TenantId = Uuid::from_u128(0x77);
+
+async fn service() -> Service {
+    // A single connection keeps the in-memory database alive and shared
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = DbHandle::connect("sqlite::memory:", opts).await.unwrap();
+    Migrator::up(db.sea_secure().conn(), None).await.unwrap();
+    Service::new(Arc::new(DbTenantRepository::new(db.sea_secure())))
+}
+
+fn tenant(id: TenantId, name: &str, parent_id: Option<TenantId>) -> TenantInfo {
+    TenantInfo {
+        id,
+        name: name.to_owned(),
+        status: TenantStatus::Active,
+        tenant_type: None,
+        parent_id,
+    }
+}
+
+fn grant(source_id: TenantId, target_id: TenantId, include_descendants: bool) -> AccessGrant {
+    AccessGrant {
+        source_id,
+        target_id,
+        include_descendants,
+    }
+}
+
+fn ctx(tenant_id: TenantId) -> SecurityContext {
+    SecurityContext::builder().tenant_id(tenant_id).build()
+}
+
+fn ids(tenants: &[TenantInfo]) -> Vec<TenantId> {
+    tenants.iter().map(|t| t.id).collect()
+}
+
+/// Reseller -> Customer -> Sub-account, plus an unrelated Partner.
+async fn hierarchy() -> Service {
+    let svc = service().await;
+    svc.create_tenant(tenant(RESELLER, "Reseller", None))
+        .await
+        .unwrap();
+    svc.create_tenant(tenant(CUSTOMER, "Customer", Some(RESELLER)))
+        .await
+        .unwrap();
+    svc.create_tenant(tenant(SUB_ACCOUNT, "Sub-account", Some(CUSTOMER)))
+        .await
+        .unwrap();
+    svc.create_tenant(tenant(PARTNER, "Partner", None))
+        .await
+        .unwrap();
+    svc
+}
+
+#[tokio::test]
+async fn tenant_crud_validates_hierarchy() {
+    let svc = hierarchy().await;
+
+    let err = svc
+        .create_tenant(tenant(CUSTOMER, "Duplicate", None))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::TenantAlreadyExists(id) if id == CUSTOMER));
+
+    let err = svc
+        .create_tenant(tenant(Uuid::new_v4(), "Orphan", Some(Uuid::new_v4())))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Validation(_)));
+
+    // Moving a tenant below its own descendant would create a cycle
+    let err = svc
+        .update_tenant(tenant(RESELLER, "Reseller", Some(SUB_ACCOUNT)))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Validation(_)));
+
+    let mut suspended = tenant(PARTNER, "Partner Ltd", Some(RESELLER));
+    suspended.status = TenantStatus::Suspended;
+    svc.update_tenant(suspended.clone()).await.unwrap();
+    assert_eq!(svc.get_tenant(PARTNER).await.unwrap(), suspended);
+
+    let err = svc.delete_tenant(CUSTOMER).await.unwrap_err();
+    assert!(matches!(err, DomainError::TenantHasChildren(id) if id == CUSTOMER));
+
+    svc.create_grant(grant(PARTNER, SUB_ACCOUNT, true))
+        .await
+        .unwrap();
+    svc.delete_tenant(SUB_ACCOUNT).await.unwrap();
+    assert!(svc.list_grants(None).await.unwrap().is_empty());
+    assert!(matches!(
+        svc.get_tenant(SUB_ACCOUNT).await.unwrap_err(),
+        DomainError::TenantNotFound(_)
+    ));
+}
+
+#[tokio::test]
+async fn grant_crud() {
+    let svc = hierarchy().await;
+
+    svc.create_grant(grant(PARTNER, CUSTOMER, false))
+        .await
+        .unwrap();
+    let err = svc
+        .create_grant(grant(PARTNER, CUSTOMER, true))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::GrantAlreadyExists { .. }));
+    assert!(matches!(
+        svc.create_grant(grant(PARTNER, PARTNER, true))
+            .await
+            .unwrap_err(),
+        DomainError::Validation(_)
+    ));
+    assert!(matches!(
+        svc.create_grant(grant(PARTNER, Uuid::new_v4(), true))
+            .await
+            .unwrap_err(),
+        DomainError::TenantNotFound(_)
+    ));
+
+    assert_eq!(
+        svc.list_grants(Some(PARTNER)).await.unwrap(),
+        vec![grant(PARTNER, CUSTOMER, false)]
+    );
+    assert!(svc.list_grants(Some(CUSTOMER)).await.unwrap().is_empty());
+
+    svc.delete_grant(PARTNER, CUSTOMER).await.unwrap();
+    assert!(matches!(
+        svc.delete_grant(PARTNER, CUSTOMER).await.unwrap_err(),
+        DomainError::GrantNotFound { .. }
+    ));
+}
+
+#[tokio::test]
+async fn resolves_hierarchy_and_access() {
+    let svc = hierarchy().await;
+
+    let ancestors = svc.get_ancestors(&ctx(PARTNER), SUB_ACCOUNT).await.unwrap();
+    assert_eq!(ids(&ancestors), vec![CUSTOMER, RESELLER]);
+    let descendants = svc
+        .get_descendants(&ctx(PARTNER), RESELLER, None)
+        .await
+        .unwrap();
+    assert_eq!(ids(&descendants), vec![CUSTOMER, SUB_ACCOUNT]);
+
+    // A tenant accesses its own subtree, but not its parent
+    let reseller = ctx(RESELLER);
+    assert!(svc.can_access(&reseller, SUB_ACCOUNT, None).await.unwrap());
+    assert!(
+        !svc.can_access(&ctx(CUSTOMER), RESELLER, None)
+            .await
+            .unwrap()
+    );
+
+    // A grant covers the target's subtree only with include_descendants
+    let partner = ctx(PARTNER);
+    svc.create_grant(grant(PARTNER, CUSTOMER, false))
+        .await
+        .unwrap();
+    assert!(svc.can_access(&partner, CUSTOMER, None).await.unwrap());
+    assert!(!svc.can_access(&partner, SUB_ACCOUNT, None).await.unwrap());
+
+    svc.delete_grant(PARTNER, CUSTOMER).await.unwrap();
+    svc.create_grant(grant(PARTNER, CUSTOMER, true))
+        .await
+        .unwrap();
+    assert!(svc.can_access(&partner, SUB_ACCOUNT, None).await.unwrap());
+    assert!(!svc.can_access(&partner, RESELLER, None).await.unwrap());
+
+    let accessible = svc
+        .get_accessible_tenants(&partner, None, None)
+        .await
+        .unwrap();
+    assert_eq!(ids(&accessible), vec![PARTNER, CUSTOMER, SUB_ACCOUNT]);
+
+    let filter = TenantFilter {
+        id: vec![SUB_ACCOUNT],
+        ..Default::default()
+    };
+    let accessible = svc
+        .get_accessible_tenants(&partner, Some(&filter), None)
+        .await
+        .unwrap();
+    assert_eq!(ids(&accessible), vec![SUB_ACCOUNT]);
+
+    let err = svc
+        .can_access(&partner, Uuid::new_v4(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TenantResolverError::TenantNotFound { .. }));
+}
@@ -28,6 +28,7 @@ impl TenantResolverPluginClient for Service {
                 name: TENANT_NAME.to_owned(),
                 status: TenantStatus::Active,
                 tenant_type: None,
+                parent_id: None,
             })
         } else {
             Err(TenantResolverError::TenantNotFound { tenant_id: id })
@@ -60,6 +61,7 @@ impl TenantResolverPluginClient for Service {
             name: TENANT_NAME.to_owned(),
             status: TenantStatus::Active,
             tenant_type: None,
+            parent_id: None,
         };
 
         // Apply filter
@@ -74,6 +76,31 @@ impl TenantResolverPluginClient for Service {
 
         Ok(vec![self_info])
     }
+
+    async fn get_ancestors(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        // The only tenant is a root
+        if id == ctx.tenant_id() {
+            return Ok(vec![]);
+        }
+        Err(TenantResolverError::TenantNotFound { tenant_id: id })
+    }
+
+    async fn get_descendants(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+        _filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        // The only tenant has no children
+        if id == ctx.tenant_id() {
+            return Ok(vec![]);
+        }
+        Err(TenantResolverError::TenantNotFound { tenant_id: id })
+    }
 }
 
 #[cfg(test)]
@@ -170,4 +197,31 @@ mod tests {
         assert_eq!(items[0].name, TENANT_NAME);
         assert_eq!(items[0].status, TenantStatus::Active);
     }
+
+    #[tokio::test]
+    async fn hierarchy_is_flat() {
+        let service = Service;
+        let tenant_id = Uuid::parse_str(TENANT_A).unwrap();
+        let other = Uuid::parse_str(TENANT_B).unwrap();
+        let ctx = ctx_for_tenant(tenant_id);
+
+        assert!(
+            service
+                .get_ancestors(&ctx, tenant_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            service
+                .get_descendants(&ctx, tenant_id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            service.get_ancestors(&ctx, other).await,
+            Err(TenantResolverError::TenantNotFound { .. })
+        ));
+    }
 }
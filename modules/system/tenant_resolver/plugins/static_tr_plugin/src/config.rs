@@ -1,5 +1,7 @@
 //! Configuration for the static tenant resolver plugin.
 
+use std::collections::{HashMap, HashSet};
+
 use serde::Deserialize;
 use tenant_resolver_sdk::TenantStatus;
 use uuid::Uuid;
@@ -49,11 +51,16 @@ pub struct TenantConfig {
     /// Tenant type classification.
     #[serde(rename = "type", default)]
     pub tenant_type: Option<String>,
+
+    /// Parent tenant ID (`None` for a root tenant).
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
 }
 
 /// Configuration for an access rule.
 ///
-/// Defines that `source` tenant can access `target` tenant's data.
+/// Defines that `source` tenant can access `target` tenant's data and,
+/// with `include_descendants`, the data of every tenant below `target`.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct AccessRuleConfig {
@@ -62,4 +69,89 @@ pub struct AccessRuleConfig {
 
     /// Target tenant ID (the one being accessed).
     pub target: Uuid,
+
+    /// Whether access extends to the descendants of `target` (defaults to false).
+    #[serde(default)]
+    pub include_descendants: bool,
+}
+
+impl StaticTrPluginConfig {
+    /// Check that every parent is a configured tenant and that the hierarchy has no cycles.
+    ///
+    /// # Errors
+    /// Returns an error describing the first invalid tenant.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let parents: HashMap<Uuid, Option<Uuid>> =
+            self.tenants.iter().map(|t| (t.id, t.parent_id)).collect();
+
+        for tenant in &self.tenants {
+            let mut seen = HashSet::from([tenant.id]);
+            let mut current = tenant.parent_id;
+            while let Some(parent) = current {
+                let Some(next) = parents.get(&parent) else {
+                    anyhow::bail!("tenant {} has unknown parent {parent}", tenant.id);
+                };
+                if !seen.insert(parent) {
+                    anyhow::bail!("tenant {} is part of a parent cycle", tenant.id);
+                }
+                current = *next;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn tenant(id: u128, parent: Option<u128>) -> TenantConfig {
+        TenantConfig {
+            id: Uuid::from_u128(id),
+            name: format!("T{id}"),
+            status: TenantStatus::Active,
+            tenant_type: None,
+            parent_id: parent.map(Uuid::from_u128),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_tree() {
+        let cfg = StaticTrPluginConfig {
+            tenants: vec![tenant(1, None), tenant(2, Some(1)), tenant(3, Some(2))],
+            ..Default::default()
+        };
+        cfg.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_unknown_parent_and_cycles() {
+        let cfg = StaticTrPluginConfig {
+            tenants: vec![tenant(1, Some(9))],
+            ..Default::default()
+        };
+        assert!(
+            cfg.validate()
+                .unwrap_err()
+                .to_string()
+                .contains("unknown parent")
+        );
+
+        let cfg = StaticTrPluginConfig {
+            tenants: vec![tenant(1, Some(2)), tenant(2, Some(1))],
+            ..Default::default()
+        };
+        assert!(cfg.validate().unwrap_err().to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn access_rules_exclude_descendants_by_default() {
+        let rule: AccessRuleConfig = serde_json::from_value(serde_json::json!({
+            "source": Uuid::from_u128(1),
+            "target": Uuid::from_u128(2),
+        }))
+        .unwrap();
+        assert!(!rule.include_descendants);
+    }
 }
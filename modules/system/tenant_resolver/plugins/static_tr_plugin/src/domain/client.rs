@@ -2,6 +2,8 @@
 //!
 //! Implements `TenantResolverPluginClient` using the domain service.
 
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 use modkit_security::SecurityContext;
 use tenant_resolver_sdk::{
@@ -43,7 +45,15 @@ impl TenantResolverPluginClient for Service {
         }
 
         // Check if access rule exists
-        Ok(self.access_rules.contains(&(source, target)))
+        if self.access_rules.contains(&(source, target)) {
+            return Ok(true);
+        }
+
+        // Access to an ancestor (own or granted with descendants) covers the target
+        Ok(self
+            .ancestor_ids(target)
+            .into_iter()
+            .any(|ancestor| ancestor == source || self.subtree_rules.contains(&(source, ancestor))))
     }
 
     async fn get_accessible_tenants(
@@ -63,26 +73,60 @@ impl TenantResolverPluginClient for Service {
             items.push(self_info.clone());
         }
 
-        // Get all targets accessible by this source
-        let accessible_ids = self.accessible_by.get(&source);
-
-        // Add accessible tenants (if any) that match the filter
-        if let Some(ids) = accessible_ids {
-            for id in ids {
-                // Skip self (already added)
-                if *id == source {
-                    continue;
-                }
-                if let Some(info) = self.tenants.get(id)
-                    && Self::matches_filter(info, filter)
-                {
-                    items.push(info.clone());
-                }
+        // Own subtree first, then the targets of the access rules (subtrees pre-expanded)
+        let own_subtree = self.descendant_ids(source);
+        let granted = self.accessible_by.get(&source).into_iter().flatten();
+
+        let mut seen = HashSet::from([source]);
+        for id in own_subtree.iter().chain(granted) {
+            // Skip self (already added) and tenants reachable twice
+            if !seen.insert(*id) {
+                continue;
+            }
+            if let Some(info) = self.tenants.get(id)
+                && Self::matches_filter(info, filter)
+            {
+                items.push(info.clone());
             }
         }
 
         Ok(items)
     }
+
+    async fn get_ancestors(
+        &self,
+        _ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        if !self.tenants.contains_key(&id) {
+            return Err(TenantResolverError::TenantNotFound { tenant_id: id });
+        }
+
+        Ok(self
+            .ancestor_ids(id)
+            .iter()
+            .filter_map(|a| self.tenants.get(a).cloned())
+            .collect())
+    }
+
+    async fn get_descendants(
+        &self,
+        _ctx: &SecurityContext,
+        id: TenantId,
+        filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        if !self.tenants.contains_key(&id) {
+            return Err(TenantResolverError::TenantNotFound { tenant_id: id });
+        }
+
+        Ok(self
+            .descendant_ids(id)
+            .iter()
+            .filter_map(|d| self.tenants.get(d))
+            .filter(|info| Self::matches_filter(info, filter))
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +144,7 @@ mod tests {
             name: name.to_owned(),
             status,
             tenant_type: None,
+            parent_id: None,
         }
     }
 
@@ -108,6 +153,7 @@ mod tests {
         AccessRuleConfig {
             source: Uuid::parse_str(source).unwrap(),
             target: Uuid::parse_str(target).unwrap(),
+            include_descendants: true,
         }
     }
 
@@ -511,4 +557,152 @@ mod tests {
         assert_eq!(items.len(), 1);
         assert_eq!(items[0].id, Uuid::parse_str(TENANT_B).unwrap());
     }
+
+    // ==================== hierarchy tests ====================
+
+    const RESELLER: &str = "44444444-4444-4444-4444-444444444444";
+    const CUSTOMER: &str = "55555555-5555-5555-5555-555555555555";
+    const SUB_ACCOUNT: &str = "66666666-6666-6666-6666-666666666666";
+    const OTHER_CUSTOMER: &str = "77777777-7777-7777-7777-777777777777";
+
+    fn child(id: &str, name: &str, parent: &str) -> TenantConfig {
+        TenantConfig {
+            parent_id: Some(Uuid::parse_str(parent).unwrap()),
+            ..tenant(id, name, TenantStatus::Active)
+        }
+    }
+
+    fn id(s: &str) -> Uuid {
+        Uuid::parse_str(s).unwrap()
+    }
+
+    // reseller -> customer -> sub-account, reseller -> other customer; plus unrelated A
+    fn hierarchy_config(access_rules: Vec<AccessRuleConfig>) -> StaticTrPluginConfig {
+        StaticTrPluginConfig {
+            tenants: vec![
+                tenant(RESELLER, "Reseller", TenantStatus::Active),
+                child(CUSTOMER, "Customer", RESELLER),
+                child(SUB_ACCOUNT, "Sub-account", CUSTOMER),
+                TenantConfig {
+                    status: TenantStatus::Suspended,
+                    ..child(OTHER_CUSTOMER, "Other customer", RESELLER)
+                },
+                tenant(TENANT_A, "A", TenantStatus::Active),
+            ],
+            access_rules,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn ancestors_and_descendants() {
+        let service = Service::from_config(&hierarchy_config(vec![]));
+        let ctx = ctx_for_tenant(TENANT_A);
+
+        let ancestors = service.get_ancestors(&ctx, id(SUB_ACCOUNT)).await.unwrap();
+        let ids: Vec<_> = ancestors.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![id(CUSTOMER), id(RESELLER)]);
+        assert!(
+            service
+                .get_ancestors(&ctx, id(RESELLER))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let descendants = service
+            .get_descendants(&ctx, id(RESELLER), None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = descendants.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![id(CUSTOMER), id(OTHER_CUSTOMER), id(SUB_ACCOUNT)]);
+
+        let active = service
+            .get_descendants(&ctx, id(RESELLER), Some(&active_filter()))
+            .await
+            .unwrap();
+        assert_eq!(active.len(), 2);
+
+        assert!(matches!(
+            service.get_descendants(&ctx, id(NONEXISTENT), None).await,
+            Err(TenantResolverError::TenantNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn parent_accesses_its_subtree() {
+        let service = Service::from_config(&hierarchy_config(vec![]));
+
+        let ctx = ctx_for_tenant(RESELLER);
+        assert!(
+            service
+                .can_access(&ctx, id(SUB_ACCOUNT), None)
+                .await
+                .unwrap()
+        );
+        assert!(!service.can_access(&ctx, id(TENANT_A), None).await.unwrap());
+
+        // Not upwards
+        let ctx = ctx_for_tenant(CUSTOMER);
+        assert!(!service.can_access(&ctx, id(RESELLER), None).await.unwrap());
+
+        let items = service
+            .get_accessible_tenants(&ctx_for_tenant(RESELLER), None, None)
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 4);
+        assert_eq!(items[0].id, id(RESELLER));
+    }
+
+    #[tokio::test]
+    async fn rule_to_parent_covers_descendants() {
+        let service =
+            Service::from_config(&hierarchy_config(vec![access_rule(TENANT_A, CUSTOMER)]));
+        let ctx = ctx_for_tenant(TENANT_A);
+
+        assert!(service.can_access(&ctx, id(CUSTOMER), None).await.unwrap());
+        assert!(
+            service
+                .can_access(&ctx, id(SUB_ACCOUNT), None)
+                .await
+                .unwrap()
+        );
+        assert!(!service.can_access(&ctx, id(RESELLER), None).await.unwrap());
+        assert!(
+            !service
+                .can_access(&ctx, id(OTHER_CUSTOMER), None)
+                .await
+                .unwrap()
+        );
+
+        let items = service
+            .get_accessible_tenants(&ctx, None, None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = items.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![id(TENANT_A), id(CUSTOMER), id(SUB_ACCOUNT)]);
+    }
+
+    #[tokio::test]
+    async fn rule_without_descendants_is_flat() {
+        let service = Service::from_config(&hierarchy_config(vec![AccessRuleConfig {
+            include_descendants: false,
+            ..access_rule(TENANT_A, CUSTOMER)
+        }]));
+        let ctx = ctx_for_tenant(TENANT_A);
+
+        assert!(service.can_access(&ctx, id(CUSTOMER), None).await.unwrap());
+        assert!(
+            !service
+                .can_access(&ctx, id(SUB_ACCOUNT), None)
+                .await
+                .unwrap()
+        );
+
+        let items = service
+            .get_accessible_tenants(&ctx, None, None)
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 2);
+    }
 }
@@ -1,6 +1,6 @@
 //! Domain service for the static tenant resolver plugin.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use tenant_resolver_sdk::{TenantFilter, TenantId, TenantInfo};
 
@@ -13,10 +13,17 @@ pub struct Service {
     /// Tenant info by ID.
     pub(super) tenants: HashMap<TenantId, TenantInfo>,
 
+    /// Children of each tenant, in configuration order.
+    pub(super) children: HashMap<TenantId, Vec<TenantId>>,
+
     /// Access rules: set of (source, target) pairs.
     pub(super) access_rules: HashSet<(TenantId, TenantId)>,
 
-    /// Reverse index: target -> list of sources that can access it.
+    /// Access rules whose target's descendants are accessible too.
+    pub(super) subtree_rules: HashSet<(TenantId, TenantId)>,
+
+    /// Index: source -> every tenant it can access through its rules,
+    /// with the subtrees of `include_descendants` targets already expanded.
     /// Used for efficient `get_accessible_tenants`.
     pub(super) accessible_by: HashMap<TenantId, Vec<TenantId>>,
 }
@@ -36,28 +43,89 @@ impl Service {
                         name: t.name.clone(),
                         status: t.status,
                         tenant_type: t.tenant_type.clone(),
+                        parent_id: t.parent_id,
                     },
                 )
             })
             .collect();
 
+        let mut children: HashMap<TenantId, Vec<TenantId>> = HashMap::new();
+        for t in &cfg.tenants {
+            if let Some(parent) = t.parent_id {
+                children.entry(parent).or_default().push(t.id);
+            }
+        }
+
         let access_rules: HashSet<(TenantId, TenantId)> = cfg
             .access_rules
             .iter()
             .map(|r| (r.source, r.target))
             .collect();
+        let subtree_rules: HashSet<(TenantId, TenantId)> = cfg
+            .access_rules
+            .iter()
+            .filter(|r| r.include_descendants)
+            .map(|r| (r.source, r.target))
+            .collect();
 
-        // Build reverse index: for each source, which targets can it access?
+        let mut service = Self {
+            tenants,
+            children,
+            access_rules,
+            subtree_rules,
+            accessible_by: HashMap::new(),
+        };
+
+        // Build the index: for each source, which tenants can it access?
         let mut accessible_by: HashMap<TenantId, Vec<TenantId>> = HashMap::new();
-        for (source, target) in &access_rules {
-            accessible_by.entry(*source).or_default().push(*target);
+        let mut seen: HashMap<TenantId, HashSet<TenantId>> = HashMap::new();
+        for rule in &cfg.access_rules {
+            let targets = accessible_by.entry(rule.source).or_default();
+            let seen = seen.entry(rule.source).or_default();
+            if seen.insert(rule.target) {
+                targets.push(rule.target);
+            }
+            if rule.include_descendants {
+                for id in service.descendant_ids(rule.target) {
+                    if seen.insert(id) {
+                        targets.push(id);
+                    }
+                }
+            }
         }
+        service.accessible_by = accessible_by;
+        service
+    }
 
-        Self {
-            tenants,
-            access_rules,
-            accessible_by,
+    /// IDs of every tenant below `id`, parents before their children.
+    pub(super) fn descendant_ids(&self, id: TenantId) -> Vec<TenantId> {
+        let mut out = Vec::new();
+        let mut queue = VecDeque::from([id]);
+        let mut visited = HashSet::from([id]);
+        while let Some(current) = queue.pop_front() {
+            for child in self.children.get(&current).into_iter().flatten() {
+                // The visited set only matters for an unvalidated config with a cycle
+                if visited.insert(*child) {
+                    out.push(*child);
+                    queue.push_back(*child);
+                }
+            }
         }
+        out
+    }
+
+    /// IDs of the ancestors of `id`, from its parent up to the root.
+    pub(super) fn ancestor_ids(&self, id: TenantId) -> Vec<TenantId> {
+        let mut out = Vec::new();
+        let mut current = self.tenants.get(&id).and_then(|t| t.parent_id);
+        while let Some(parent) = current {
+            if parent == id || out.contains(&parent) {
+                break;
+            }
+            out.push(parent);
+            current = self.tenants.get(&parent).and_then(|t| t.parent_id);
+        }
+        out
     }
 
     /// Check if a tenant matches the filter criteria.
@@ -95,6 +163,7 @@ mod tests {
             name: name.to_owned(),
             status,
             tenant_type: None,
+            parent_id: None,
         }
     }
 
@@ -103,6 +172,7 @@ mod tests {
         AccessRuleConfig {
             source: Uuid::parse_str(source).unwrap(),
             target: Uuid::parse_str(target).unwrap(),
+            include_descendants: true,
         }
     }
 
@@ -198,6 +268,7 @@ mod tests {
                 name: "Enterprise".to_owned(),
                 status: TenantStatus::Active,
                 tenant_type: Some("enterprise".to_owned()),
+                parent_id: None,
             }],
             ..Default::default()
         };
@@ -209,4 +280,29 @@ mod tests {
             .unwrap();
         assert_eq!(a.tenant_type, Some("enterprise".to_owned()));
     }
+
+    #[test]
+    fn from_config_expands_subtrees_in_index() {
+        let a_id = Uuid::parse_str(TENANT_A).unwrap();
+        let b_id = Uuid::parse_str(TENANT_B).unwrap();
+        let c_id = Uuid::parse_str(TENANT_C).unwrap();
+        let cfg = StaticTrPluginConfig {
+            tenants: vec![
+                tenant(TENANT_A, "A", TenantStatus::Active),
+                tenant(TENANT_B, "B", TenantStatus::Active),
+                TenantConfig {
+                    parent_id: Some(b_id),
+                    ..tenant(TENANT_C, "C", TenantStatus::Active)
+                },
+            ],
+            access_rules: vec![access_rule(TENANT_A, TENANT_B)],
+            ..Default::default()
+        };
+        let service = Service::from_config(&cfg);
+
+        assert_eq!(service.children.get(&b_id), Some(&vec![c_id]));
+        assert_eq!(service.accessible_by.get(&a_id), Some(&vec![b_id, c_id]));
+        assert_eq!(service.ancestor_ids(c_id), vec![b_id]);
+        assert_eq!(service.descendant_ids(b_id), vec![c_id]);
+    }
 }
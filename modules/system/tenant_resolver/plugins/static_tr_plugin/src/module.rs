@@ -45,6 +45,7 @@ impl Module for StaticTrPlugin {
 
         // Load configuration
         let cfg: StaticTrPluginConfig = ctx.config()?;
+        cfg.validate()?;
         info!(
             vendor = %cfg.vendor,
             priority = cfg.priority,
@@ -11,12 +11,101 @@ pub struct TenantResolverGwConfig {
     /// The gateway queries types-registry for plugin instances matching
     /// this vendor and selects the one with lowest priority.
     pub vendor: String,
+
+    /// Asks several plugins in turn instead of only the selected one.
+    ///
+    /// `None` (the default) keeps the single-plugin mode.
+    pub chain: Option<ChainConfig>,
+
+    /// Caching of `can_access` and `get_accessible_tenants` results.
+    pub cache: CacheConfig,
 }
 
 impl Default for TenantResolverGwConfig {
     fn default() -> Self {
         Self {
             vendor: "hyperspot".to_owned(),
+            chain: None,
+            cache: CacheConfig::default(),
+        }
+    }
+}
+
+/// How the answers of chained plugins are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainMode {
+    /// The first plugin that knows the tenant answers.
+    #[default]
+    FirstHit,
+    /// Lists are merged across plugins and access is granted if any plugin grants it;
+    /// single-tenant lookups still take the first hit.
+    Merge,
+}
+
+/// Plugin chaining configuration.
+///
+/// A plugin that is unavailable, times out or has its circuit open is skipped
+/// in favour of the next one; other errors are returned as they are.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChainConfig {
+    /// Plugin instance GTS IDs in the order they are asked.
+    ///
+    /// Empty means every instance of `vendor`, by priority.
+    pub plugins: Vec<String>,
+
+    /// How the answers are combined.
+    pub mode: ChainMode,
+
+    /// Upper bound for a single plugin call, in milliseconds.
+    pub plugin_timeout_ms: u64,
+
+    /// Consecutive unavailable-class failures that open a plugin's circuit
+    /// (`0` disables circuit breaking).
+    pub failure_threshold: u32,
+
+    /// How long an open circuit skips the plugin before a trial call, in seconds.
+    pub open_circuit_secs: u64,
+}
+
+impl Default for ChainConfig {
+    fn default() -> Self {
+        Self {
+            plugins: Vec::new(),
+            mode: ChainMode::FirstHit,
+            plugin_timeout_ms: 2000,
+            failure_threshold: 5,
+            open_circuit_secs: 30,
+        }
+    }
+}
+
+/// Result cache configuration.
+///
+/// Results are kept per subject, source tenant and query; each cached
+/// operation holds up to `max_entries`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// How long a result is served from the cache, in seconds (`0` disables caching).
+    pub ttl_secs: u64,
+
+    /// How long denials and `TenantNotFound` answers are cached, in seconds
+    /// (`0` does not cache them).
+    pub negative_ttl_secs: u64,
+
+    /// Entries per cached operation; the least recently used one is evicted when full
+    /// (`0` disables caching).
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 30,
+            negative_ttl_secs: 5,
+            max_entries: 10_000,
         }
     }
 }
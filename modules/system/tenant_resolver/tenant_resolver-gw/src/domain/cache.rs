@@ -0,0 +1,588 @@
+//! Cache of access resolution results.
+//!
+//! `can_access` and `get_accessible_tenants` sit on the hot path of every
+//! tenant-scoped request, so their results are kept per subject, source
+//! tenant and query. Concurrent misses for the same key share one plugin call.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+use modkit::telemetry::MetricsRegistry;
+use modkit::telemetry::metrics::{Counter, Gauge};
+use modkit_security::SecurityContext;
+use parking_lot::Mutex;
+use tenant_resolver_sdk::{AccessOptions, TenantFilter, TenantId, TenantInfo, TenantStatus};
+use uuid::Uuid;
+
+use super::error::DomainError;
+use crate::config::CacheConfig;
+
+/// Identifies one cached answer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    subject: Uuid,
+    tenant: TenantId,
+    query: Query,
+    /// Sorted and deduplicated: permissions have AND semantics.
+    permissions: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Query {
+    CanAccess {
+        target: TenantId,
+    },
+    AccessibleTenants {
+        ids: Vec<TenantId>,
+        statuses: Vec<TenantStatus>,
+    },
+}
+
+impl CacheKey {
+    /// Key of a `can_access` call.
+    pub fn can_access(
+        ctx: &SecurityContext,
+        target: TenantId,
+        options: Option<&AccessOptions>,
+    ) -> Self {
+        Self::new(ctx, Query::CanAccess { target }, options)
+    }
+
+    /// Key of a `get_accessible_tenants` call.
+    pub fn accessible_tenants(
+        ctx: &SecurityContext,
+        filter: Option<&TenantFilter>,
+        options: Option<&AccessOptions>,
+    ) -> Self {
+        let (mut ids, mut statuses) = filter
+            .map(|f| (f.id.clone(), f.status.clone()))
+            .unwrap_or_default();
+        ids.sort_unstable();
+        ids.dedup();
+        statuses.sort_unstable();
+        statuses.dedup();
+        Self::new(ctx, Query::AccessibleTenants { ids, statuses }, options)
+    }
+
+    fn new(ctx: &SecurityContext, query: Query, options: Option<&AccessOptions>) -> Self {
+        let mut permissions = options.map(|o| o.permission.clone()).unwrap_or_default();
+        permissions.sort_unstable();
+        permissions.dedup();
+        Self {
+            subject: ctx.subject_id(),
+            tenant: ctx.tenant_id(),
+            query,
+            permissions,
+        }
+    }
+
+    fn involves(&self, tenant: TenantId) -> bool {
+        self.tenant == tenant
+            || match &self.query {
+                Query::CanAccess { target } => *target == tenant,
+                Query::AccessibleTenants { ids, .. } => ids.contains(&tenant),
+            }
+    }
+}
+
+/// A result type the cache can hold.
+pub trait CachedValue: Clone {
+    /// Whether this is a negative answer, kept only for the negative TTL.
+    fn is_negative(&self) -> bool;
+
+    /// Whether the answer mentions `tenant`.
+    fn mentions(&self, tenant: TenantId) -> bool;
+}
+
+impl CachedValue for bool {
+    fn is_negative(&self) -> bool {
+        !*self
+    }
+
+    fn mentions(&self, _tenant: TenantId) -> bool {
+        false
+    }
+}
+
+impl CachedValue for Vec<TenantInfo> {
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn mentions(&self, tenant: TenantId) -> bool {
+        self.iter().any(|t| t.id == tenant)
+    }
+}
+
+struct Entry<V> {
+    /// `Err` holds the tenant a `TenantNotFound` answer was about.
+    value: Result<V, TenantId>,
+    expires_at: Instant,
+}
+
+/// Results of one gateway operation, bounded by TTL and size.
+///
+/// Denials and `TenantNotFound` answers are negative entries with their own,
+/// usually shorter, TTL. Other errors are never cached. Expired entries are
+/// dropped when looked up; a full cache evicts its least recently used entry.
+/// A zero TTL or `max_entries` disables caching.
+pub struct ResolutionCache<V> {
+    op: &'static str,
+    ttl: Duration,
+    negative_ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<LruCache<CacheKey, Entry<V>>>,
+    /// One lock per key being loaded, so concurrent misses share a plugin call.
+    inflight: Mutex<HashMap<CacheKey, Arc<tokio::sync::Mutex<()>>>>,
+    /// Bumped by every invalidation; loads started before it are not stored.
+    generation: AtomicU64,
+    metrics: CacheMetrics,
+}
+
+impl<V: CachedValue> ResolutionCache<V> {
+    /// Creates a cache for the operation `op`, reporting to `registry`.
+    pub fn new(op: &'static str, config: &CacheConfig, registry: &MetricsRegistry) -> Self {
+        Self {
+            op,
+            ttl: Duration::from_secs(config.ttl_secs),
+            negative_ttl: Duration::from_secs(config.negative_ttl_secs),
+            max_entries: config.max_entries,
+            entries: Mutex::new(LruCache::new(
+                NonZeroUsize::new(config.max_entries).unwrap_or(NonZeroUsize::MIN),
+            )),
+            inflight: Mutex::default(),
+            generation: AtomicU64::new(0),
+            metrics: CacheMetrics::new(registry),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.max_entries > 0
+    }
+
+    /// Returns the cached answer for `key`, or stores and returns the one from `load`.
+    ///
+    /// # Errors
+    ///
+    /// `TenantNotFound` if that is the cached answer; otherwise the error of `load`.
+    pub async fn get_or_load<F, Fut>(&self, key: CacheKey, load: F) -> Result<V, DomainError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, DomainError>>,
+    {
+        if !self.is_enabled() {
+            return load().await;
+        }
+        if let Some(hit) = self.lookup(&key) {
+            return hit;
+        }
+
+        let flight = Arc::clone(self.inflight.lock().entry(key.clone()).or_default());
+        let result = {
+            let _flight_guard = flight.lock().await;
+            // Whoever held the flight before us has likely stored the answer
+            if let Some(hit) = self.lookup(&key) {
+                hit
+            } else {
+                self.metrics
+                    .lookups
+                    .inc(&[("op", self.op), ("result", "miss")]);
+
+                let generation = self.generation.load(Ordering::Acquire);
+                let result = load().await;
+                if generation == self.generation.load(Ordering::Acquire) {
+                    self.store(&key, &result);
+                }
+                result
+            }
+        };
+
+        // Remove our flight on every path, or `inflight` keeps it until the key misses again
+
+        let mut inflight = self.inflight.lock();
+        if inflight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &flight))
+        {
+            inflight.remove(&key);
+        }
+        result
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<Result<V, DomainError>> {
+        let mut entries = self.entries.lock();
+        let entry = entries.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.pop(key);
+            self.record_evictions("expired", 1);
+            self.metrics.set_size(self.op, entries.len());
+            return None;
+        }
+        self.metrics
+            .lookups
+            .inc(&[("op", self.op), ("result", "hit")]);
+        Some(
+            entry
+                .value
+                .clone()
+                .map_err(|tenant_id| DomainError::TenantNotFound { tenant_id }),
+        )
+    }
+
+    fn store(&self, key: &CacheKey, result: &Result<V, DomainError>) {
+        let (value, ttl) = match result {
+            Ok(value) if value.is_negative() => (Ok(value.clone()), self.negative_ttl),
+            Ok(value) => (Ok(value.clone()), self.ttl),
+            Err(DomainError::TenantNotFound { tenant_id }) => (Err(*tenant_id), self.negative_ttl),
+            Err(_) => return,
+        };
+        if ttl.is_zero() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        let evicted = entries.push(
+            key.clone(),
+            Entry {
+                value,
+                expires_at: now + ttl,
+            },
+        );
+        if let Some((evicted_key, evicted)) = evicted
+            && evicted_key != *key
+        {
+            let reason = if evicted.expires_at > now {
+                "capacity"
+            } else {
+                "expired"
+            };
+            self.record_evictions(reason, 1);
+        }
+        self.metrics.set_size(self.op, entries.len());
+    }
+
+    /// Drops the entries involving `tenant`, or every entry for `None`.
+    ///
+    /// An entry involves a tenant if it was resolved for it, asked about it
+    /// or lists it. Loads in flight are not stored.
+    pub fn invalidate(&self, tenant: Option<TenantId>) -> usize {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+
+        let mut entries = self.entries.lock();
+        let removed = match tenant {
+            None => {
+                let removed = entries.len();
+                entries.clear();
+                removed
+            }
+            Some(tenant) => {
+                let stale: Vec<CacheKey> = entries
+                    .iter()
+                    .filter(|(key, entry)| {
+                        key.involves(tenant)
+                            || entry
+                                .value
+                                .as_ref()
+                                .is_ok_and(|value| value.mentions(tenant))
+                    })
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in &stale {
+                    entries.pop(key);
+                }
+                stale.len()
+            }
+        };
+        self.record_evictions("invalidated", removed);
+        self.metrics.set_size(self.op, entries.len());
+        removed
+    }
+
+    fn record_evictions(&self, reason: &str, count: usize) {
+        if count > 0 {
+            self.metrics.evictions.inc_by(
+                &[("op", self.op), ("reason", reason)],
+                u64::try_from(count).unwrap_or(u64::MAX),
+            );
+        }
+    }
+}
+
+struct CacheMetrics {
+    lookups: Counter,
+    evictions: Counter,
+    size: Gauge,
+}
+
+impl CacheMetrics {
+    fn new(registry: &MetricsRegistry) -> Self {
+        Self {
+            lookups: registry.counter(
+                "tenant_resolver_cache_lookups_total",
+                "Tenant resolver cache lookups by operation and result (hit or miss)",
+            ),
+            evictions: registry.counter(
+                "tenant_resolver_cache_evictions_total",
+                "Tenant resolver cache entries dropped by operation and reason",
+            ),
+            size: registry.gauge(
+                "tenant_resolver_cache_entries",
+                "Tenant resolver cache entries by operation",
+            ),
+        }
+    }
+
+    fn set_size(&self, op: &str, len: usize) {
+        self.size
+            .set(&[("op", op)], i64::try_from(len).unwrap_or(i64::MAX));
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    fn cache<V: CachedValue>(configure: impl FnOnce(&mut CacheConfig)) -> ResolutionCache<V> {
+        let mut config = CacheConfig::default();
+        configure(&mut config);
+        ResolutionCache::new("test", &config, &MetricsRegistry::new())
+    }
+
+    fn ctx(tenant: TenantId) -> SecurityContext {
+        SecurityContext::builder()
+            .tenant_id(tenant)
+            .subject_id(Uuid::new_v4())
+            .build()
+    }
+
+    fn lookups(cache: &ResolutionCache<impl CachedValue>, result: &str) -> u64 {
+        cache
+            .metrics
+            .lookups
+            .get(&[("op", "test"), ("result", result)])
+    }
+
+    #[tokio::test]
+    async fn second_call_is_served_from_cache() {
+        let cache = cache::<bool>(|_| {});
+        let ctx = ctx(Uuid::new_v4());
+        let target = Uuid::new_v4();
+        let read = AccessOptions {
+            permission: vec!["write".to_owned(), "read".to_owned()],
+        };
+        let read_reordered = AccessOptions {
+            permission: vec!["read".to_owned(), "write".to_owned()],
+        };
+
+        let first = cache
+            .get_or_load(CacheKey::can_access(&ctx, target, Some(&read)), || async {
+                Ok(true)
+            })
+            .await;
+        let second = cache
+            .get_or_load(
+                CacheKey::can_access(&ctx, target, Some(&read_reordered)),
+                || async { panic!("should be cached") },
+            )
+            .await;
+
+        assert!(first.unwrap() && second.unwrap());
+        assert_eq!((lookups(&cache, "miss"), lookups(&cache, "hit")), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn not_found_is_cached_but_other_errors_are_not() {
+        let cache = cache::<bool>(|_| {});
+        let ctx = ctx(Uuid::new_v4());
+        let (missing, flaky) = (Uuid::new_v4(), Uuid::new_v4());
+
+        for _ in 0..2 {
+            let result = cache
+                .get_or_load(CacheKey::can_access(&ctx, missing, None), || async {
+                    Err(DomainError::TenantNotFound { tenant_id: missing })
+                })
+                .await;
+            assert!(matches!(result, Err(DomainError::TenantNotFound { .. })));
+        }
+        assert_eq!(lookups(&cache, "miss"), 1);
+
+        let loads = AtomicUsize::new(0);
+        for _ in 0..2 {
+            let result = cache
+                .get_or_load(CacheKey::can_access(&ctx, flaky, None), || async {
+                    loads.fetch_add(1, Ordering::SeqCst);
+                    Err(DomainError::Internal("boom".to_owned()))
+                })
+                .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn zero_negative_ttl_skips_denials() {
+        let cache = cache::<bool>(|c| c.negative_ttl_secs = 0);
+        let ctx = ctx(Uuid::new_v4());
+        let key = CacheKey::can_access(&ctx, Uuid::new_v4(), None);
+
+        cache
+            .get_or_load(key.clone(), || async { Ok(false) })
+            .await
+            .unwrap();
+        assert!(cache.lookup(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_share_one_load() {
+        let cache = cache::<Vec<TenantInfo>>(|_| {});
+        let ctx = ctx(Uuid::new_v4());
+        let loads = AtomicUsize::new(0);
+
+        let calls = (0..8).map(|_| {
+            cache.get_or_load(CacheKey::accessible_tenants(&ctx, None, None), || async {
+                loads.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok(Vec::new())
+            })
+        });
+        for result in futures_util::future::join_all(calls).await {
+            result.unwrap();
+        }
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert!(cache.inflight.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn answer_stored_while_waiting_releases_the_flight() {
+        let cache = cache::<bool>(|_| {});
+        let ctx = ctx(Uuid::new_v4());
+        let key = CacheKey::can_access(&ctx, Uuid::new_v4(), None);
+
+        // Another loader holds the flight and stores the answer while we wait for it
+        let flight = Arc::clone(cache.inflight.lock().entry(key.clone()).or_default());
+        let held = flight.lock().await;
+        let waiting = cache.get_or_load(key.clone(), || async { panic!("should be cached") });
+        let release = async {
+            tokio::task::yield_now().await;
+            cache.store(&key, &Ok(true));
+            drop(held);
+        };
+        let (result, ()) = tokio::join!(waiting, release);
+
+        assert!(result.unwrap());
+        assert!(cache.inflight.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn full_cache_evicts_least_recently_used_entry() {
+        let cache = cache::<bool>(|c| c.max_entries = 2);
+        let ctx = ctx(Uuid::new_v4());
+        let keys: Vec<CacheKey> = (0..3)
+            .map(|_| CacheKey::can_access(&ctx, Uuid::new_v4(), None))
+            .collect();
+
+        for key in &keys[..2] {
+            cache
+                .get_or_load(key.clone(), || async { Ok(true) })
+                .await
+                .unwrap();
+        }
+        // Using the first entry makes the second one the least recently used
+        assert!(cache.lookup(&keys[0]).is_some());
+        cache
+            .get_or_load(keys[2].clone(), || async { Ok(true) })
+            .await
+            .unwrap();
+
+        assert!(cache.lookup(&keys[0]).is_some());
+        assert!(cache.lookup(&keys[1]).is_none());
+        assert!(cache.lookup(&keys[2]).is_some());
+        assert_eq!(
+            cache
+                .metrics
+                .evictions
+                .get(&[("op", "test"), ("reason", "capacity")]),
+            1
+        );
+    }
+
+    #[test]
+    fn accessible_tenants_key_ignores_filter_order_and_duplicates() {
+        let ctx = ctx(Uuid::new_v4());
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let filter = TenantFilter {
+            id: vec![a, b],
+            status: vec![TenantStatus::Suspended, TenantStatus::Active],
+        };
+        let reordered = TenantFilter {
+            id: vec![b, a, b],
+            status: vec![
+                TenantStatus::Active,
+                TenantStatus::Suspended,
+                TenantStatus::Active,
+            ],
+        };
+
+        assert_eq!(
+            CacheKey::accessible_tenants(&ctx, Some(&filter), None),
+            CacheKey::accessible_tenants(&ctx, Some(&reordered), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn invalidate_drops_entries_involving_tenant() {
+        let cache = cache::<Vec<TenantInfo>>(|_| {});
+        let (a, b, listed) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let tenant = TenantInfo {
+            id: listed,
+            name: "listed".to_owned(),
+            status: TenantStatus::Active,
+            tenant_type: None,
+            parent_id: None,
+        };
+        let (ctx_a, ctx_b) = (ctx(a), ctx(b));
+        let key_a = CacheKey::accessible_tenants(&ctx_a, None, None);
+        let key_b = CacheKey::accessible_tenants(&ctx_b, None, None);
+        cache
+            .get_or_load(key_a.clone(), || async { Ok(Vec::new()) })
+            .await
+            .unwrap();
+        cache
+            .get_or_load(key_b.clone(), || async move { Ok(vec![tenant]) })
+            .await
+            .unwrap();
+
+        assert_eq!(cache.invalidate(Some(listed)), 1);
+        assert!(cache.lookup(&key_a).is_some());
+        assert!(cache.lookup(&key_b).is_none());
+
+        assert_eq!(cache.invalidate(Some(a)), 1);
+        assert!(cache.lookup(&key_a).is_none());
+    }
+
+    #[tokio::test]
+    async fn load_racing_invalidation_is_not_stored() {
+        let cache = cache::<bool>(|_| {});
+        let ctx = ctx(Uuid::new_v4());
+        let key = CacheKey::can_access(&ctx, Uuid::new_v4(), None);
+
+        cache
+            .get_or_load(key.clone(), || async {
+                cache.invalidate(None);
+                Ok(true)
+            })
+            .await
+            .unwrap();
+
+        assert!(cache.lookup(&key).is_none());
+    }
+}
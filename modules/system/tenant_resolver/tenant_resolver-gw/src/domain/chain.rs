@@ -0,0 +1,729 @@
+//! Plugin chaining for the tenant resolver gateway.
+//!
+//! In chaining mode the gateway asks an ordered list of plugins instead of a
+//! single one. Each call is bounded by a timeout and guarded by a per-plugin
+//! circuit breaker, so a slow or failing plugin is skipped rather than stalling
+//! every request.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::stream::Stream;
+use modkit::client_hub::{ClientHub, ClientScope};
+use modkit::plugins::on_first_change;
+use modkit::telemetry::ThrottledLog;
+use modkit_security::SecurityContext;
+use parking_lot::{Mutex, RwLock};
+use tenant_resolver_sdk::{
+    AccessOptions, TenantFilter, TenantId, TenantInfo, TenantResolverError,
+    TenantResolverPluginClient, TenantResolverPluginSpecV1,
+};
+use tracing::info;
+use types_registry_sdk::{GtsEntity, ListQuery, TypesRegistryClient};
+
+use super::error::DomainError;
+use super::service::parse_plugin_instances;
+use crate::config::{ChainConfig, ChainMode};
+
+/// Throttle interval for plugin fallback warnings.
+const FALLBACK_LOG_THROTTLE: Duration = Duration::from_secs(10);
+
+/// Plugin instance IDs in chain order.
+type PluginIds = Arc<[String]>;
+
+/// Ordered plugins asked in turn, with per-plugin timeouts and circuit breakers.
+pub struct PluginChain {
+    hub: Arc<ClientHub>,
+    vendor: String,
+    config: ChainConfig,
+    /// Resolved chain, cleared when plugin instances change.
+    resolved: Arc<RwLock<Option<PluginIds>>>,
+    /// Single-flight guard for resolution.
+    resolve_lock: tokio::sync::Mutex<()>,
+    /// Breakers by plugin instance ID; kept across re-resolutions.
+    breakers: Mutex<HashMap<String, Arc<CircuitBreaker>>>,
+    fallback_log_throttle: ThrottledLog,
+}
+
+impl PluginChain {
+    #[must_use]
+    pub fn new(hub: Arc<ClientHub>, vendor: String, config: ChainConfig) -> Self {
+        Self {
+            hub,
+            vendor,
+            config,
+            resolved: Arc::default(),
+            resolve_lock: tokio::sync::Mutex::new(()),
+            breakers: Mutex::default(),
+            fallback_log_throttle: ThrottledLog::new(FALLBACK_LOG_THROTTLE),
+        }
+    }
+
+    /// Get tenant information from the first plugin that knows the tenant.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::first_hit`].
+    pub async fn get_tenant(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<TenantInfo, DomainError> {
+        self.first_hit(|plugin| async move { plugin.get_tenant(ctx, id).await })
+            .await
+    }
+
+    /// Check access through the first plugin that knows the target, or
+    /// through any plugin in merge mode.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::first_hit`].
+    pub async fn can_access(
+        &self,
+        ctx: &SecurityContext,
+        target: TenantId,
+        options: Option<&AccessOptions>,
+    ) -> Result<bool, DomainError> {
+        let op = |plugin: Arc<dyn TenantResolverPluginClient>| async move {
+            plugin.can_access(ctx, target, options).await
+        };
+        match self.config.mode {
+            ChainMode::FirstHit => self.first_hit(op).await,
+            ChainMode::Merge => Ok(self.all_hits(op).await?.into_iter().any(|can| can)),
+        }
+    }
+
+    /// Get the accessible tenants from the first answering plugin, or the
+    /// union across plugins in merge mode.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::first_hit`].
+    pub async fn get_accessible_tenants(
+        &self,
+        ctx: &SecurityContext,
+        filter: Option<&TenantFilter>,
+        options: Option<&AccessOptions>,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        let op = |plugin: Arc<dyn TenantResolverPluginClient>| async move {
+            plugin.get_accessible_tenants(ctx, filter, options).await
+        };
+        match self.config.mode {
+            ChainMode::FirstHit => self.first_hit(op).await,
+            ChainMode::Merge => Ok(merge_tenants(self.all_hits(op).await?)),
+        }
+    }
+
+    /// Get the ancestors of a tenant from the first plugin that knows it.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::first_hit`].
+    pub async fn get_ancestors(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        self.first_hit(|plugin| async move { plugin.get_ancestors(ctx, id).await })
+            .await
+    }
+
+    /// Get the descendants of a tenant from the first plugin that knows it,
+    /// or the union across plugins in merge mode.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::first_hit`].
+    pub async fn get_descendants(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+        filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        let op = |plugin: Arc<dyn TenantResolverPluginClient>| async move {
+            plugin.get_descendants(ctx, id, filter).await
+        };
+        match self.config.mode {
+            ChainMode::FirstHit => self.first_hit(op).await,
+            ChainMode::Merge => Ok(merge_tenants(self.all_hits(op).await?)),
+        }
+    }
+
+    /// Returns the answer of the first plugin that has one.
+    ///
+    /// Plugins that do not know the tenant or are unavailable are skipped.
+    ///
+    /// # Errors
+    ///
+    /// - The first error that is neither `TenantNotFound` nor unavailable-class
+    /// - `PluginUnavailable` if no plugin answered and one of them was unavailable
+    /// - `TenantNotFound` if every plugin answered that it does not know the tenant
+    async fn first_hit<T, F, Fut>(&self, op: F) -> Result<T, DomainError>
+    where
+        F: Fn(Arc<dyn TenantResolverPluginClient>) -> Fut,
+        Fut: Future<Output = Result<T, TenantResolverError>>,
+    {
+        let mut misses = Misses::default();
+        for gts_id in self.plugins().await?.iter() {
+            match self.call(gts_id, &op).await {
+                Ok(answer) => return Ok(answer),
+                Err(e) => misses.record(self, gts_id, e)?,
+            }
+        }
+        Err(misses.into_error(&self.vendor))
+    }
+
+    /// Returns the answers of every plugin that has one, in chain order.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::first_hit`]; misses only fail the call if no plugin answered.
+    async fn all_hits<T, F, Fut>(&self, op: F) -> Result<Vec<T>, DomainError>
+    where
+        F: Fn(Arc<dyn TenantResolverPluginClient>) -> Fut,
+        Fut: Future<Output = Result<T, TenantResolverError>>,
+    {
+        let mut misses = Misses::default();
+        let mut answers = Vec::new();
+        for gts_id in self.plugins().await?.iter() {
+            match self.call(gts_id, &op).await {
+                Ok(answer) => answers.push(answer),
+                Err(e) => misses.record(self, gts_id, e)?,
+            }
+        }
+        if answers.is_empty() {
+            return Err(misses.into_error(&self.vendor));
+        }
+        Ok(answers)
+    }
+
+    /// Calls one plugin through its circuit breaker and timeout.
+    async fn call<T, F, Fut>(&self, gts_id: &str, op: &F) -> Result<T, DomainError>
+    where
+        F: Fn(Arc<dyn TenantResolverPluginClient>) -> Fut,
+        Fut: Future<Output = Result<T, TenantResolverError>>,
+    {
+        let unavailable = |reason: String| DomainError::PluginUnavailable {
+            gts_id: gts_id.to_owned(),
+            reason,
+        };
+
+        let breaker = self.breaker(gts_id);
+        if !breaker.allow() {
+            return Err(unavailable("circuit open".to_owned()));
+        }
+
+        let Some(plugin) = self
+            .hub
+            .try_get_scoped::<dyn TenantResolverPluginClient>(&ClientScope::gts_id(gts_id))
+        else {
+            return Err(unavailable("client not registered yet".to_owned()));
+        };
+
+        let timeout = Duration::from_millis(self.config.plugin_timeout_ms);
+        let result = match tokio::time::timeout(timeout, op(plugin)).await {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(TenantResolverError::ServiceUnavailable(reason))) => Err(unavailable(reason)),
+            Ok(Err(e)) => Err(DomainError::from(e)),
+            Err(_) => Err(unavailable(format!(
+                "timed out after {}ms",
+                timeout.as_millis()
+            ))),
+        };
+
+        match &result {
+            Err(e) if e.is_unavailable() => breaker.record_failure(),
+            _ => breaker.record_success(),
+        }
+        result
+    }
+
+    fn breaker(&self, gts_id: &str) -> Arc<CircuitBreaker> {
+        let mut breakers = self.breakers.lock();
+        if let Some(breaker) = breakers.get(gts_id) {
+            return Arc::clone(breaker);
+        }
+        let breaker = Arc::new(CircuitBreaker::new(
+            self.config.failure_threshold,
+            Duration::from_secs(self.config.open_circuit_secs),
+        ));
+        breakers.insert(gts_id.to_owned(), Arc::clone(&breaker));
+        breaker
+    }
+
+    /// Lazily resolves and returns the plugin instance IDs, in chain order.
+    async fn plugins(&self) -> Result<PluginIds, DomainError> {
+        if let Some(plugins) = self.resolved.read().clone() {
+            return Ok(plugins);
+        }
+
+        let _resolve_guard = self.resolve_lock.lock().await;
+        if let Some(plugins) = self.resolved.read().clone() {
+            return Ok(plugins);
+        }
+
+        let plugins = self.resolve().await?;
+        *self.resolved.write() = Some(Arc::clone(&plugins));
+        Ok(plugins)
+    }
+
+    /// Resolves the chain from types-registry.
+    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
+    async fn resolve(&self) -> Result<PluginIds, DomainError> {
+        info!("Resolving tenant resolver plugin chain");
+
+        let registry = self
+            .hub
+            .get::<dyn TypesRegistryClient>()
+            .map_err(|e| DomainError::TypesRegistryUnavailable(e.to_string()))?;
+
+        let plugin_type_id = TenantResolverPluginSpecV1::gts_schema_id().clone();
+
+        let query = ListQuery::new()
+            .with_pattern(format!("{plugin_type_id}*"))
+            .with_is_type(false);
+
+        // Subscribe first so that no change made after the listing is missed
+        let changes = registry.watch(query.clone());
+        let instances = registry.list_all(query).await?;
+
+        let plugins = choose_plugin_chain(&self.vendor, &self.config.plugins, &instances)?;
+        info!(plugins = ?plugins, "Selected tenant resolver plugin chain");
+        self.reset_on_change(changes);
+
+        Ok(plugins.into())
+    }
+
+    /// Clears the resolved chain as soon as `changes` yields its first item.
+    fn reset_on_change<S>(&self, changes: S)
+    where
+        S: Stream + Send + 'static,
+    {
+        on_first_change(&self.resolved, changes, |resolved| async move {
+            resolved.write().take();
+        });
+    }
+}
+
+/// Why the plugins of a chain did not answer.
+#[derive(Default)]
+struct Misses {
+    not_found: Option<DomainError>,
+    unavailable: Option<DomainError>,
+}
+
+impl Misses {
+    /// Records a skipped plugin, or returns the error if it must not be skipped.
+    fn record(
+        &mut self,
+        chain: &PluginChain,
+        gts_id: &str,
+        e: DomainError,
+    ) -> Result<(), DomainError> {
+        if e.is_unavailable() {
+            if chain.fallback_log_throttle.should_log() {
+                tracing::warn!(
+                    plugin_gts_id = %gts_id,
+                    error = %e,
+                    "Tenant resolver plugin unavailable, falling back to the next one"
+                );
+            }
+            self.unavailable.get_or_insert(e);
+            Ok(())
+        } else if matches!(e, DomainError::TenantNotFound { .. }) {
+            self.not_found.get_or_insert(e);
+            Ok(())
+        } else {
+            Err(e)
+        }
+    }
+
+    fn into_error(self, vendor: &str) -> DomainError {
+        // Unavailable wins: a plugin that did not answer may know the tenant
+        self.unavailable
+            .or(self.not_found)
+            .unwrap_or_else(|| DomainError::PluginNotFound {
+                vendor: vendor.to_owned(),
+            })
+    }
+}
+
+/// Concatenates tenant lists, keeping the first occurrence of each tenant.
+fn merge_tenants(lists: Vec<Vec<TenantInfo>>) -> Vec<TenantInfo> {
+    let mut seen = HashSet::new();
+    lists
+        .into_iter()
+        .flatten()
+        .filter(|t| seen.insert(t.id))
+        .collect()
+}
+
+/// Orders the plugin instances of a chain.
+///
+/// Configured instance IDs keep their order, and those without a registered
+/// instance are skipped; without configured IDs, every instance of `vendor`
+/// is taken by priority.
+fn choose_plugin_chain(
+    vendor: &str,
+    configured: &[String],
+    instances: &[GtsEntity],
+) -> Result<Vec<String>, DomainError> {
+    let mut available = parse_plugin_instances(instances)?;
+
+    let plugins: Vec<String> = if configured.is_empty() {
+        available.retain(|content| content.vendor == vendor);
+        available.sort_by_key(|content| content.priority);
+        available
+            .into_iter()
+            .map(|content| content.id.to_string())
+            .collect()
+    } else {
+        configured
+            .iter()
+            .filter(|id| {
+                let found = available.iter().any(|content| &content.id == *id);
+                if !found {
+                    tracing::warn!(plugin_gts_id = %id, "Configured plugin instance not registered");
+                }
+                found
+            })
+            .cloned()
+            .collect()
+    };
+
+    if plugins.is_empty() {
+        return Err(DomainError::PluginNotFound {
+            vendor: vendor.to_owned(),
+        });
+    }
+    Ok(plugins)
+}
+
+/// Consecutive-failure circuit breaker.
+///
+/// After `threshold` consecutive failures the circuit opens and calls are
+/// rejected for `open_for`; then a single trial call is let through, which
+/// closes the circuit on success and reopens it on failure.
+struct CircuitBreaker {
+    threshold: u32,
+    open_for: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32, open_for: Duration) -> Self {
+        Self {
+            threshold,
+            open_for,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Whether a call may go through.
+    fn allow(&self) -> bool {
+        let mut state = self.state.lock();
+        match state.open_until {
+            None => true,
+            Some(until) if Instant::now() < until => false,
+            Some(_) => {
+                // Half-open: let this call through and hold the others back until it reports
+                state.open_until = Some(Instant::now() + self.open_for);
+                true
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        *self.state.lock() = BreakerState::default();
+    }
+
+    fn record_failure(&self) {
+        if self.threshold == 0 {
+            return;
+        }
+        let mut state = self.state.lock();
+        state.failures = state.failures.saturating_add(1);
+        if state.failures >= self.threshold {
+            state.open_until = Some(Instant::now() + self.open_for);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tenant_resolver_sdk::TenantStatus;
+    use uuid::Uuid;
+
+    const PLUGIN_A: &str =
+        "gts.x.core.modkit.plugin.v1~x.core.tenant_resolver.plugin.v1~a.test._.plugin.v1";
+    const PLUGIN_B: &str =
+        "gts.x.core.modkit.plugin.v1~x.core.tenant_resolver.plugin.v1~b.test._.plugin.v1";
+    const PLUGIN_C: &str =
+        "gts.x.core.modkit.plugin.v1~x.core.tenant_resolver.plugin.v1~c.test._.plugin.v1";
+
+    enum Behavior {
+        Answer,
+        Unavailable,
+        Slow,
+    }
+
+    /// Plugin that knows `tenants` and grants access to all of them.
+    struct MockPlugin {
+        tenants: Vec<TenantInfo>,
+        behavior: Behavior,
+        calls: AtomicUsize,
+    }
+
+    impl MockPlugin {
+        fn new(tenants: &[TenantId], behavior: Behavior) -> Arc<Self> {
+            Arc::new(Self {
+                tenants: tenants
+                    .iter()
+                    .map(|id| TenantInfo {
+                        id: *id,
+                        name: id.to_string(),
+                        status: TenantStatus::Active,
+                        tenant_type: None,
+                        parent_id: None,
+                    })
+                    .collect(),
+                behavior,
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        async fn find(&self, id: TenantId) -> Result<TenantInfo, TenantResolverError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match self.behavior {
+                Behavior::Answer => {}
+                Behavior::Unavailable => {
+                    return Err(TenantResolverError::ServiceUnavailable("down".to_owned()));
+                }
+                Behavior::Slow => tokio::time::sleep(Duration::from_secs(5)).await,
+            }
+            self.tenants
+                .iter()
+                .find(|t| t.id == id)
+                .cloned()
+                .ok_or(TenantResolverError::TenantNotFound { tenant_id: id })
+        }
+    }
+
+    #[async_trait]
+    impl TenantResolverPluginClient for MockPlugin {
+        async fn get_tenant(
+            &self,
+            _ctx: &SecurityContext,
+            id: TenantId,
+        ) -> Result<TenantInfo, TenantResolverError> {
+            self.find(id).await
+        }
+
+        async fn can_access(
+            &self,
+            _ctx: &SecurityContext,
+            target: TenantId,
+            _options: Option<&AccessOptions>,
+        ) -> Result<bool, TenantResolverError> {
+            self.find(target).await.map(|_| true)
+        }
+
+        async fn get_accessible_tenants(
+            &self,
+            _ctx: &SecurityContext,
+            _filter: Option<&TenantFilter>,
+            _options: Option<&AccessOptions>,
+        ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+            self.find(self.tenants[0].id).await?;
+            Ok(self.tenants.clone())
+        }
+
+        async fn get_ancestors(
+            &self,
+            _ctx: &SecurityContext,
+            id: TenantId,
+        ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+            self.find(id).await.map(|_| Vec::new())
+        }
+
+        async fn get_descendants(
+            &self,
+            _ctx: &SecurityContext,
+            id: TenantId,
+            _filter: Option<&TenantFilter>,
+        ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+            self.find(id).await.map(|_| Vec::new())
+        }
+    }
+
+    fn chain_of(
+        plugins: &[(&str, Arc<MockPlugin>)],
+        configure: impl FnOnce(&mut ChainConfig),
+    ) -> PluginChain {
+        let hub = Arc::new(ClientHub::new());
+        for (gts_id, plugin) in plugins {
+            let client: Arc<dyn TenantResolverPluginClient> = plugin.clone();
+            hub.register_scoped::<dyn TenantResolverPluginClient>(
+                ClientScope::gts_id(gts_id),
+                client,
+            );
+        }
+        let mut config = ChainConfig {
+            plugin_timeout_ms: 50,
+            ..ChainConfig::default()
+        };
+        configure(&mut config);
+
+        let chain = PluginChain::new(hub, "test".to_owned(), config);
+        let ids: Vec<String> = plugins.iter().map(|(id, _)| (*id).to_owned()).collect();
+        *chain.resolved.write() = Some(ids.into());
+        chain
+    }
+
+    fn ctx() -> SecurityContext {
+        SecurityContext::builder().tenant_id(Uuid::new_v4()).build()
+    }
+
+    #[tokio::test]
+    async fn first_hit_skips_plugins_that_miss_or_fail() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let first = MockPlugin::new(&[a], Behavior::Answer);
+        let second = MockPlugin::new(&[b], Behavior::Answer);
+        let chain = chain_of(&[(PLUGIN_A, first), (PLUGIN_B, second)], |_| {});
+
+        assert_eq!(chain.get_tenant(&ctx(), a).await.unwrap().id, a);
+        assert_eq!(chain.get_tenant(&ctx(), b).await.unwrap().id, b);
+        assert!(matches!(
+            chain.get_tenant(&ctx(), Uuid::new_v4()).await.unwrap_err(),
+            DomainError::TenantNotFound { .. }
+        ));
+
+        let down = MockPlugin::new(&[a], Behavior::Unavailable);
+        let slow = MockPlugin::new(&[a], Behavior::Slow);
+        let up = MockPlugin::new(&[a], Behavior::Answer);
+        let chain = chain_of(
+            &[(PLUGIN_A, down), (PLUGIN_B, slow), (PLUGIN_C, up)],
+            |_| {},
+        );
+        assert!(chain.can_access(&ctx(), a, None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn unavailable_wins_over_not_found() {
+        let a = Uuid::new_v4();
+        let down = MockPlugin::new(&[a], Behavior::Unavailable);
+        let up = MockPlugin::new(&[Uuid::new_v4()], Behavior::Answer);
+        let chain = chain_of(&[(PLUGIN_A, down), (PLUGIN_B, up)], |_| {});
+
+        let err = chain.get_tenant(&ctx(), a).await.unwrap_err();
+        assert!(
+            matches!(&err, DomainError::PluginUnavailable { gts_id, .. } if gts_id == PLUGIN_A)
+        );
+    }
+
+    #[tokio::test]
+    async fn merge_mode_unions_lists() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let first = MockPlugin::new(&[a, b], Behavior::Answer);
+        let second = MockPlugin::new(&[b], Behavior::Answer);
+        let down = MockPlugin::new(&[a], Behavior::Unavailable);
+        let chain = chain_of(
+            &[(PLUGIN_A, first), (PLUGIN_B, second), (PLUGIN_C, down)],
+            |c| c.mode = ChainMode::Merge,
+        );
+
+        let tenants = chain
+            .get_accessible_tenants(&ctx(), None, None)
+            .await
+            .unwrap();
+        let ids: Vec<TenantId> = tenants.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![a, b]);
+        assert!(chain.can_access(&ctx(), b, None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn open_circuit_skips_plugin() {
+        let a = Uuid::new_v4();
+        let down = MockPlugin::new(&[a], Behavior::Unavailable);
+        let up = MockPlugin::new(&[a], Behavior::Answer);
+        let chain = chain_of(&[(PLUGIN_A, down.clone()), (PLUGIN_B, up)], |c| {
+            c.failure_threshold = 2;
+        });
+
+        for _ in 0..4 {
+            chain.get_tenant(&ctx(), a).await.unwrap();
+        }
+        assert_eq!(down.calls.load(Ordering::SeqCst), 2);
+    }
+
+    fn instance(gts_id: &str, vendor: &str, priority: i16) -> GtsEntity {
+        GtsEntity::new(
+            Uuid::new_v4(),
+            gts_id,
+            vec![],
+            false,
+            serde_json::json!({
+                "id": gts_id,
+                "vendor": vendor,
+                "priority": priority,
+                "properties": {}
+            }),
+            None,
+        )
+    }
+
+    #[test]
+    fn chain_order_follows_config_or_priority() {
+        let instances = [
+            instance(PLUGIN_A, "test", 20),
+            instance(PLUGIN_B, "test", 10),
+            instance(PLUGIN_C, "other", 0),
+        ];
+
+        let by_priority = choose_plugin_chain("test", &[], &instances).unwrap();
+        assert_eq!(by_priority, vec![PLUGIN_B, PLUGIN_A]);
+
+        let configured = [
+            PLUGIN_C.to_owned(),
+            "gts.missing".to_owned(),
+            PLUGIN_A.to_owned(),
+        ];
+        let explicit = choose_plugin_chain("test", &configured, &instances).unwrap();
+        assert_eq!(explicit, vec![PLUGIN_C, PLUGIN_A]);
+
+        assert!(matches!(
+            choose_plugin_chain("none", &[], &instances).unwrap_err(),
+            DomainError::PluginNotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn breaker_lets_one_trial_through_after_open_period() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        breaker.record_failure();
+        assert!(breaker.allow(), "trial call after the open period");
+        breaker.record_success();
+        assert!(breaker.allow());
+
+        let breaker = CircuitBreaker::new(1, Duration::from_mins(1));
+        breaker.record_failure();
+        assert!(!breaker.allow());
+
+        let disabled = CircuitBreaker::new(0, Duration::from_mins(1));
+        disabled.record_failure();
+        assert!(disabled.allow());
+    }
+}
@@ -27,10 +27,21 @@ pub enum DomainError {
     #[error("unauthorized")]
     Unauthorized,
 
+    #[error("operation not supported: {0}")]
+    Unsupported(String),
+
     #[error("internal error: {0}")]
     Internal(String),
 }
 
+impl DomainError {
+    /// Whether the error means the plugin could not answer, rather than answered with an error.
+    #[must_use]
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::PluginUnavailable { .. })
+    }
+}
+
 impl From<types_registry_sdk::TypesRegistryError> for DomainError {
     fn from(e: types_registry_sdk::TypesRegistryError) -> Self {
         Self::Internal(e.to_string())
@@ -57,6 +68,7 @@ impl From<TenantResolverError> for DomainError {
                 Self::AccessDenied { target_tenant }
             }
             TenantResolverError::Unauthorized => Self::Unauthorized,
+            TenantResolverError::Unsupported(operation) => Self::Unsupported(operation),
             TenantResolverError::NoPluginAvailable => Self::PluginNotFound {
                 vendor: "unknown".to_owned(),
             },
@@ -82,6 +94,7 @@ impl From<DomainError> for TenantResolverError {
             DomainError::TenantNotFound { tenant_id } => Self::TenantNotFound { tenant_id },
             DomainError::AccessDenied { target_tenant } => Self::AccessDenied { target_tenant },
             DomainError::Unauthorized => Self::Unauthorized,
+            DomainError::Unsupported(operation) => Self::Unsupported(operation),
             DomainError::TypesRegistryUnavailable(reason) | DomainError::Internal(reason) => {
                 Self::Internal(reason)
             }
@@ -70,4 +70,46 @@ impl TenantResolverGatewayClient for TenantResolverGwLocalClient {
                 e.into()
             })
     }
+
+    async fn get_ancestors(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        self.svc
+            .get_ancestors(ctx, id)
+            .await
+            .map_err(|e: DomainError| {
+                tracing::error!(error = ?e, "tenant_resolver gateway call failed");
+                e.into()
+            })
+    }
+
+    async fn get_descendants(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+        filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        self.svc
+            .get_descendants(ctx, id, filter)
+            .await
+            .map_err(|e: DomainError| {
+                tracing::error!(error = ?e, "tenant_resolver gateway call failed");
+                e.into()
+            })
+    }
+
+    async fn invalidate_cache(
+        &self,
+        ctx: &SecurityContext,
+        tenant: Option<TenantId>,
+    ) -> Result<(), TenantResolverError> {
+        self.svc
+            .invalidate_cache(ctx, tenant)
+            .map_err(|e: DomainError| {
+                tracing::error!(error = ?e, "tenant_resolver gateway call failed");
+                e.into()
+            })
+    }
 }
@@ -1,5 +1,7 @@
 //! Domain layer for the tenant resolver gateway.
 
+mod cache;
+mod chain;
 pub mod error;
 pub mod local_client;
 pub mod service;
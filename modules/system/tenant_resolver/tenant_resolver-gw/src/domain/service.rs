@@ -1,7 +1,7 @@
 //! Domain service for the tenant resolver gateway.
 //!
 //! Plugin discovery is lazy: resolved on first API call after
-//! types-registry is ready.
+//! types-registry is ready, and again after plugin instances change.
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,7 +9,7 @@ use std::time::Duration;
 use modkit::client_hub::{ClientHub, ClientScope};
 use modkit::gts::BaseModkitPluginV1;
 use modkit::plugins::GtsPluginSelector;
-use modkit::telemetry::ThrottledLog;
+use modkit::telemetry::{MetricsRegistry, ThrottledLog};
 use modkit_security::SecurityContext;
 use tenant_resolver_sdk::{
     AccessOptions, TenantFilter, TenantId, TenantInfo, TenantResolverPluginClient,
@@ -19,35 +19,67 @@ use tracing::info;
 use types_registry_sdk::{GtsEntity, ListQuery, TypesRegistryClient};
 use uuid::Uuid;
 
+use super::cache::{CacheKey, CachedValue, ResolutionCache};
+use super::chain::PluginChain;
 use super::error::DomainError;
+use crate::config::{CacheConfig, ChainConfig};
 
 /// Throttle interval for unavailable plugin warnings.
 const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
 
 /// Tenant resolver gateway service.
 ///
-/// Discovers plugins via types-registry and delegates API calls.
+/// Discovers plugins via types-registry, delegates API calls and caches
+/// access resolution results.
 pub struct Service {
     hub: Arc<ClientHub>,
     vendor: String,
-    /// Shared selector for plugin instance IDs.
-    selector: GtsPluginSelector,
+    /// Shared selector for plugin instance IDs, reset when plugin instances change.
+    selector: Arc<GtsPluginSelector>,
     /// Throttle for plugin unavailable warnings.
     unavailable_log_throttle: ThrottledLog,
+    /// Plugin chain asked instead of the selected plugin, when configured.
+    chain: Option<PluginChain>,
+    /// `can_access` results.
+    access_cache: ResolutionCache<bool>,
+    /// `get_accessible_tenants` results.
+    accessible_cache: ResolutionCache<Vec<TenantInfo>>,
 }
 
 impl Service {
-    /// Creates a new service with lazy plugin resolution.
+    /// Creates a new service with lazy plugin resolution and the default cache.
     #[must_use]
     pub fn new(hub: Arc<ClientHub>, vendor: String) -> Self {
         Self {
             hub,
             vendor,
-            selector: GtsPluginSelector::new(),
+            selector: Arc::new(GtsPluginSelector::new()),
             unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
+            chain: None,
+            access_cache: new_cache("can_access", &CacheConfig::default()),
+            accessible_cache: new_cache("get_accessible_tenants", &CacheConfig::default()),
         }
     }
 
+    /// Caches results as configured by `config`.
+    #[must_use]
+    pub fn with_cache(mut self, config: &CacheConfig) -> Self {
+        self.access_cache = new_cache("can_access", config);
+        self.accessible_cache = new_cache("get_accessible_tenants", config);
+        self
+    }
+
+    /// Asks the plugins of `config` in turn instead of a single selected plugin.
+    #[must_use]
+    pub fn with_chain(mut self, config: ChainConfig) -> Self {
+        self.chain = Some(PluginChain::new(
+            Arc::clone(&self.hub),
+            self.vendor.clone(),
+            config,
+        ));
+        self
+    }
+
     /// Lazily resolves and returns the plugin client.
     async fn get_plugin(&self) -> Result<Arc<dyn TenantResolverPluginClient>, DomainError> {
         let instance_id = self.selector.get_or_init(|| self.resolve_plugin()).await?;
@@ -85,16 +117,17 @@ impl Service {
 
         let plugin_type_id = TenantResolverPluginSpecV1::gts_schema_id().clone();
 
-        let instances = registry
-            .list(
-                ListQuery::new()
-                    .with_pattern(format!("{plugin_type_id}*"))
-                    .with_is_type(false),
-            )
-            .await?;
+        let query = ListQuery::new()
+            .with_pattern(format!("{plugin_type_id}*"))
+            .with_is_type(false);
+
+        // Subscribe first so that no change made after the listing is missed
+        let changes = registry.watch(query.clone());
+        let instances = registry.list_all(query).await?;
 
         let gts_id = choose_plugin_instance(&self.vendor, &instances)?;
         info!(plugin_gts_id = %gts_id, "Selected tenant resolver plugin instance");
+        self.selector.reset_on_change(changes);
 
         Ok(gts_id)
     }
@@ -116,6 +149,9 @@ impl Service {
         id: TenantId,
     ) -> Result<TenantInfo, DomainError> {
         require_tenant_context(ctx)?;
+        if let Some(chain) = &self.chain {
+            return chain.get_tenant(ctx, id).await;
+        }
         let plugin = self.get_plugin().await?;
         plugin.get_tenant(ctx, id).await.map_err(DomainError::from)
     }
@@ -123,7 +159,7 @@ impl Service {
     /// Check if current tenant can access target tenant.
     ///
     /// Access rules (including self-access, status-based, and permission-based)
-    /// are plugin-determined.
+    /// are plugin-determined. Results, including denials, are cached.
     ///
     /// # Returns
     ///
@@ -141,15 +177,25 @@ impl Service {
         options: Option<&AccessOptions>,
     ) -> Result<bool, DomainError> {
         require_tenant_context(ctx)?;
-        let plugin = self.get_plugin().await?;
-        plugin
-            .can_access(ctx, target, options)
+        let key = CacheKey::can_access(ctx, target, options);
+        self.access_cache
+            .get_or_load(key, || async {
+                if let Some(chain) = &self.chain {
+                    return chain.can_access(ctx, target, options).await;
+                }
+                let plugin = self.get_plugin().await?;
+                plugin
+                    .can_access(ctx, target, options)
+                    .await
+                    .map_err(DomainError::from)
+            })
             .await
-            .map_err(DomainError::from)
     }
 
     /// Get all tenants accessible by the current tenant.
     ///
+    /// Results are cached per filter and access options.
+    ///
     /// # Errors
     ///
     /// - `Unauthorized` if security context has no tenant
@@ -161,12 +207,90 @@ impl Service {
         options: Option<&AccessOptions>,
     ) -> Result<Vec<TenantInfo>, DomainError> {
         require_tenant_context(ctx)?;
+        let key = CacheKey::accessible_tenants(ctx, filter, options);
+        self.accessible_cache
+            .get_or_load(key, || async {
+                if let Some(chain) = &self.chain {
+                    return chain.get_accessible_tenants(ctx, filter, options).await;
+                }
+                let plugin = self.get_plugin().await?;
+                plugin
+                    .get_accessible_tenants(ctx, filter, options)
+                    .await
+                    .map_err(DomainError::from)
+            })
+            .await
+    }
+
+    /// Get the ancestors of a tenant, from its parent up to the root.
+    ///
+    /// # Errors
+    ///
+    /// - `Unauthorized` if security context has no tenant
+    /// - `TenantNotFound` if tenant doesn't exist
+    /// - Plugin resolution errors
+    #[tracing::instrument(skip_all, fields(tenant.id = %id))]
+    pub async fn get_ancestors(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        require_tenant_context(ctx)?;
+        if let Some(chain) = &self.chain {
+            return chain.get_ancestors(ctx, id).await;
+        }
         let plugin = self.get_plugin().await?;
         plugin
-            .get_accessible_tenants(ctx, filter, options)
+            .get_ancestors(ctx, id)
             .await
             .map_err(DomainError::from)
     }
+
+    /// Get the descendants of a tenant, parents before their children.
+    ///
+    /// # Errors
+    ///
+    /// - `Unauthorized` if security context has no tenant
+    /// - `TenantNotFound` if tenant doesn't exist
+    /// - Plugin resolution errors
+    #[tracing::instrument(skip_all, fields(tenant.id = %id))]
+    pub async fn get_descendants(
+        &self,
+        ctx: &SecurityContext,
+        id: TenantId,
+        filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, DomainError> {
+        require_tenant_context(ctx)?;
+        if let Some(chain) = &self.chain {
+            return chain.get_descendants(ctx, id, filter).await;
+        }
+        let plugin = self.get_plugin().await?;
+        plugin
+            .get_descendants(ctx, id, filter)
+            .await
+            .map_err(DomainError::from)
+    }
+
+    /// Drop cached results involving `tenant`, or all cached results for `None`.
+    ///
+    /// # Errors
+    ///
+    /// - `Unauthorized` if security context has no tenant
+    pub fn invalidate_cache(
+        &self,
+        ctx: &SecurityContext,
+        tenant: Option<TenantId>,
+    ) -> Result<(), DomainError> {
+        require_tenant_context(ctx)?;
+        let removed =
+            self.access_cache.invalidate(tenant) + self.accessible_cache.invalidate(tenant);
+        tracing::debug!(tenant = ?tenant, removed, "Invalidated tenant resolver cache");
+        Ok(())
+    }
+}
+
+fn new_cache<V: CachedValue>(op: &'static str, config: &CacheConfig) -> ResolutionCache<V> {
+    ResolutionCache::new(op, config, MetricsRegistry::global())
 }
 
 /// Validates that the security context has a tenant ID.
@@ -184,50 +308,49 @@ fn require_tenant_context(ctx: &SecurityContext) -> Result<(), DomainError> {
 /// If multiple instances match, the one with lowest priority wins.
 #[tracing::instrument(skip_all, fields(vendor, instance_count = instances.len()))]
 fn choose_plugin_instance(vendor: &str, instances: &[GtsEntity]) -> Result<String, DomainError> {
-    let mut best: Option<(String, i16)> = None;
-
-    for ent in instances {
-        let content: BaseModkitPluginV1<TenantResolverPluginSpecV1> =
-            serde_json::from_value(ent.content.clone()).map_err(|e| {
-                tracing::error!(
-                    gts_id = %ent.gts_id,
-                    error = %e,
-                    "Failed to deserialize plugin instance content"
-                );
-                DomainError::InvalidPluginInstance {
-                    gts_id: ent.gts_id.clone(),
-                    reason: e.to_string(),
-                }
-            })?;
-
-        if content.id != ent.gts_id {
-            return Err(DomainError::InvalidPluginInstance {
-                gts_id: ent.gts_id.clone(),
-                reason: format!(
-                    "content.id mismatch: expected {:?}, got {:?}",
-                    ent.gts_id, content.id
-                ),
-            });
-        }
-
-        if content.vendor != vendor {
-            continue;
-        }
+    parse_plugin_instances(instances)?
+        .into_iter()
+        .filter(|content| content.vendor == vendor)
+        .min_by_key(|content| content.priority)
+        .map(|content| content.id.to_string())
+        .ok_or_else(|| DomainError::PluginNotFound {
+            vendor: vendor.to_owned(),
+        })
+}
 
-        match &best {
-            None => best = Some((ent.gts_id.clone(), content.priority)),
-            Some((_, cur_priority)) => {
-                if content.priority < *cur_priority {
-                    best = Some((ent.gts_id.clone(), content.priority));
-                }
+/// Deserializes plugin instances, checking that each one describes itself.
+pub(super) fn parse_plugin_instances(
+    instances: &[GtsEntity],
+) -> Result<Vec<BaseModkitPluginV1<TenantResolverPluginSpecV1>>, DomainError> {
+    instances
+        .iter()
+        .map(|ent| {
+            let content: BaseModkitPluginV1<TenantResolverPluginSpecV1> =
+                serde_json::from_value(ent.content.clone()).map_err(|e| {
+                    tracing::error!(
+                        gts_id = %ent.gts_id,
+                        error = %e,
+                        "Failed to deserialize plugin instance content"
+                    );
+                    DomainError::InvalidPluginInstance {
+                        gts_id: ent.gts_id.clone(),
+                        reason: e.to_string(),
+                    }
+                })?;
+
+            if content.id != ent.gts_id {
+                return Err(DomainError::InvalidPluginInstance {
+                    gts_id: ent.gts_id.clone(),
+                    reason: format!(
+                        "content.id mismatch: expected {:?}, got {:?}",
+                        ent.gts_id, content.id
+                    ),
+                });
             }
-        }
-    }
 
-    best.map(|(gts_id, _)| gts_id)
-        .ok_or_else(|| DomainError::PluginNotFound {
-            vendor: vendor.to_owned(),
+            Ok(content)
         })
+        .collect()
 }
 
 #[cfg(test)]
@@ -305,5 +428,27 @@ mod tests {
             assert!(result.is_err());
             assert!(matches!(result.unwrap_err(), DomainError::Unauthorized));
         }
+
+        #[tokio::test]
+        async fn hierarchy_queries_reject_anonymous_context() {
+            let service = create_service();
+            let ctx = anonymous_ctx();
+            let tenant_id = Uuid::new_v4();
+
+            let result = service.get_ancestors(&ctx, tenant_id).await;
+            assert!(matches!(result.unwrap_err(), DomainError::Unauthorized));
+
+            let result = service.get_descendants(&ctx, tenant_id, None).await;
+            assert!(matches!(result.unwrap_err(), DomainError::Unauthorized));
+        }
+
+        #[test]
+        fn invalidate_cache_rejects_anonymous_context() {
+            let service = create_service();
+
+            let result = service.invalidate_cache(&anonymous_ctx(), None);
+
+            assert!(matches!(result.unwrap_err(), DomainError::Unauthorized));
+        }
     }
 }
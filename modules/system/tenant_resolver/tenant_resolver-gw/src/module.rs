@@ -58,7 +58,12 @@ impl Module for TenantResolverGateway {
 
         // Create service
         let hub = ctx.client_hub();
-        let svc = Arc::new(Service::new(hub, cfg.vendor));
+        let mut svc = Service::new(hub, cfg.vendor).with_cache(&cfg.cache);
+        if let Some(chain) = cfg.chain {
+            info!(mode = ?chain.mode, plugins = ?chain.plugins, "Plugin chaining enabled");
+            svc = svc.with_chain(chain);
+        }
+        let svc = Arc::new(svc);
 
         // Register gateway client in ClientHub
         let api: Arc<dyn TenantResolverGatewayClient> =
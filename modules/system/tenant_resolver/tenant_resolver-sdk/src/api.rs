@@ -70,6 +70,8 @@ pub trait TenantResolverGatewayClient: Send + Sync {
     /// - Self-access: A tenant can always access its own data
     /// - Non-transitive: A→B and B→C does NOT imply A→C
     /// - Non-symmetric: A→B does NOT imply B→A
+    /// - Hierarchical: access to a tenant may extend to its descendants
+    ///   (plugin-determined; see `get_descendants`)
     ///
     /// # Arguments
     ///
@@ -100,4 +102,71 @@ pub trait TenantResolverGatewayClient: Send + Sync {
         filter: Option<&TenantFilter>,
         options: Option<&AccessOptions>,
     ) -> Result<Vec<TenantInfo>, TenantResolverError>;
+
+    /// Get the ancestors of a tenant, from its parent up to the root.
+    ///
+    /// Returns an empty list for a root tenant.
+    ///
+    /// # Errors
+    ///
+    /// - `TenantNotFound` if the tenant does not exist
+    /// - `Unsupported` if the implementation has no tenant hierarchy (the default)
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Security context
+    /// * `id` - The tenant whose ancestors to return
+    async fn get_ancestors(
+        &self,
+        _ctx: &SecurityContext,
+        _id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Err(TenantResolverError::Unsupported("get_ancestors".to_owned()))
+    }
+
+    /// Get the descendants of a tenant (its whole subtree, excluding itself).
+    ///
+    /// Parents are listed before their children.
+    ///
+    /// # Errors
+    ///
+    /// - `TenantNotFound` if the tenant does not exist
+    /// - `Unsupported` if the implementation has no tenant hierarchy (the default)
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Security context
+    /// * `id` - The root of the subtree
+    /// * `filter` - Optional filter criteria (e.g., id, status)
+    async fn get_descendants(
+        &self,
+        _ctx: &SecurityContext,
+        _id: TenantId,
+        _filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Err(TenantResolverError::Unsupported(
+            "get_descendants".to_owned(),
+        ))
+    }
+
+    /// Drop cached resolution results involving `tenant`, or all of them for `None`.
+    ///
+    /// The gateway caches `can_access` and `get_accessible_tenants` results.
+    /// Whoever changes tenants or access rules calls this so the change takes
+    /// effect before the cached results expire. A result involves a tenant if
+    /// it was resolved for it, asked about it or lists it.
+    ///
+    /// Implementations without a cache keep the default, which does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Security context
+    /// * `tenant` - The tenant whose results to drop; `None` drops everything
+    async fn invalidate_cache(
+        &self,
+        _ctx: &SecurityContext,
+        _tenant: Option<TenantId>,
+    ) -> Result<(), TenantResolverError> {
+        Ok(())
+    }
 }
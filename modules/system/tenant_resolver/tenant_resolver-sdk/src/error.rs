@@ -30,6 +30,10 @@ pub enum TenantResolverError {
     #[error("no plugin available")]
     NoPluginAvailable,
 
+    /// The operation is not supported by the implementation.
+    #[error("operation not supported: {0}")]
+    Unsupported(String),
+
     /// The plugin is not available yet.
     #[error("service unavailable: {0}")]
     ServiceUnavailable(String),
@@ -26,6 +26,10 @@
 //!
 //! // Get all accessible tenants
 //! let accessible = resolver.get_accessible_tenants(&ctx, query).await?;
+//!
+//! // Walk the tenant hierarchy
+//! let ancestors = resolver.get_ancestors(&ctx, tenant_id).await?;
+//! let subtree = resolver.get_descendants(&ctx, tenant_id, None).await?;
 //! ```
 
 pub mod api;
@@ -18,10 +18,15 @@ pub struct TenantInfo {
     /// Tenant type classification.
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub tenant_type: Option<String>,
+    /// Parent tenant in the hierarchy (`None` for a root tenant).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<TenantId>,
 }
 
 /// Tenant lifecycle status.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum TenantStatus {
     /// Tenant is active and operational.
@@ -87,4 +87,49 @@ pub trait TenantResolverPluginClient: Send + Sync {
         filter: Option<&TenantFilter>,
         options: Option<&AccessOptions>,
     ) -> Result<Vec<TenantInfo>, TenantResolverError>;
+
+    /// Get the ancestors of a tenant, ordered from its parent up to the root.
+    ///
+    /// # Errors
+    ///
+    /// - `TenantNotFound` if the tenant doesn't exist in the plugin's data source
+    /// - `Unsupported` if the plugin has no tenant hierarchy (the default)
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Security context
+    /// * `id` - The tenant whose ancestors to return
+    async fn get_ancestors(
+        &self,
+        _ctx: &SecurityContext,
+        _id: TenantId,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Err(TenantResolverError::Unsupported("get_ancestors".to_owned()))
+    }
+
+    /// Get the descendants of a tenant, parents before their children.
+    ///
+    /// The tenant itself is not included. Filtering applies to the returned
+    /// tenants only: a filtered-out tenant's children are still returned.
+    ///
+    /// # Errors
+    ///
+    /// - `TenantNotFound` if the tenant doesn't exist in the plugin's data source
+    /// - `Unsupported` if the plugin has no tenant hierarchy (the default)
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Security context
+    /// * `id` - The root of the subtree
+    /// * `filter` - Optional filter criteria (e.g., id, status)
+    async fn get_descendants(
+        &self,
+        _ctx: &SecurityContext,
+        _id: TenantId,
+        _filter: Option<&TenantFilter>,
+    ) -> Result<Vec<TenantInfo>, TenantResolverError> {
+        Err(TenantResolverError::Unsupported(
+            "get_descendants".to_owned(),
+        ))
+    }
 }
@@ -3,10 +3,19 @@
 //! This trait defines the public API for the `types-registry` module.
 //! GTS schemas and instances are global resources, so no security context is required.
 
+use std::pin::Pin;
+
 use async_trait::async_trait;
+use futures::{Stream, TryStreamExt};
+use modkit_odata::{ODataQuery, Page};
+use modkit_sdk::pager::{CursorPager, PagerError};
 
 use crate::error::TypesRegistryError;
-use crate::models::{GtsEntity, ListQuery, RegisterResult};
+use crate::models::{EntityChange, EntityUpdate, GtsEntity, ListQuery, RegisterResult};
+
+/// Stream of changes returned by [`TypesRegistryClient::watch`].
+pub type EntityChangeStream =
+    Pin<Box<dyn Stream<Item = Result<EntityChange, TypesRegistryError>> + Send + 'static>>;
 
 /// Public API trait for the `types-registry` module.
 ///
@@ -25,6 +34,11 @@ pub trait TypesRegistryClient: Send + Sync {
     /// Each JSON value in the input should contain a valid GTS entity
     /// with a `$id` field containing the GTS identifier.
     ///
+    /// Once the registry is ready, a schema that is a new minor version of a
+    /// registered one (e.g. `...v1.1~` after `...v1~`) must be compatible with
+    /// that version: it may not drop required properties or narrow types,
+    /// enums or bounds.
+    ///
     /// # Arguments
     ///
     /// * `entities` - JSON values representing GTS entities to register
@@ -33,7 +47,9 @@ pub trait TypesRegistryClient: Send + Sync {
     ///
     /// A vector of `RegisterResult` for each input entity, preserving order.
     /// Each result indicates success (with the registered entity) or failure
-    /// (with the error and attempted GTS ID if available).
+    /// (with the error and attempted GTS ID if available). For a new minor
+    /// version of a schema, a successful result also reports every instance of
+    /// the previous version re-validated against the new one.
     ///
     /// Use `RegisterSummary::from_results(&results)` for aggregate counts.
     ///
@@ -46,7 +62,7 @@ pub trait TypesRegistryClient: Send + Sync {
     ///
     /// for result in results {
     ///     match result {
-    ///         RegisterResult::Ok(entity) => println!("OK: {}", entity.gts_id),
+    ///         RegisterResult::Ok { entity, .. } => println!("OK: {}", entity.gts_id),
     ///         RegisterResult::Err { gts_id, error } => {
     ///             eprintln!("FAIL {}: {}", gts_id.as_deref().unwrap_or("?"), error);
     ///         }
@@ -63,16 +79,47 @@ pub trait TypesRegistryClient: Send + Sync {
         entities: Vec<serde_json::Value>,
     ) -> Result<Vec<RegisterResult>, TypesRegistryError>;
 
-    /// List GTS entities with optional filtering.
+    /// List one page of GTS entities.
     ///
     /// # Arguments
     ///
-    /// * `query` - Query parameters for filtering results
+    /// * `query` - Pattern and segment filters
+    /// * `odata` - `$filter`, `$orderby`, page size and cursor, using the fields
+    ///   in [`crate::odata`]. Entities are ordered by GTS ID unless another
+    ///   order is requested.
     ///
     /// # Returns
     ///
-    /// A vector of `GtsEntity` objects matching the query.
-    async fn list(&self, query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError>;
+    /// A page of `GtsEntity` objects matching both queries, with cursors for
+    /// the neighbouring pages.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidQuery` - If the filter, order, or cursor is invalid
+    async fn list(
+        &self,
+        query: ListQuery,
+        odata: ODataQuery,
+    ) -> Result<Page<GtsEntity>, TypesRegistryError>;
+
+    /// List all GTS entities matching `query`, following cursors page by page.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by [`Self::list`].
+    async fn list_all(&self, query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError> {
+        CursorPager::new(ODataQuery::default(), |odata| {
+            self.list(query.clone(), odata)
+        })
+        .map_err(|e| match e {
+            PagerError::Fetch(e) => e,
+            PagerError::InvalidCursor(cursor) => {
+                TypesRegistryError::internal(format!("Invalid cursor: {cursor}"))
+            }
+        })
+        .try_collect()
+        .await
+    }
 
     /// Retrieve a single GTS entity by its identifier.
     ///
@@ -89,4 +136,43 @@ pub trait TypesRegistryClient: Send + Sync {
     /// * `NotFound` - If no entity with the given GTS ID exists
     /// * `InvalidGtsId` - If the GTS ID format is invalid
     async fn get(&self, gts_id: &str) -> Result<GtsEntity, TypesRegistryError>;
+
+    /// Replace the content of a registered GTS entity.
+    ///
+    /// The GTS ID is taken from the entity content, as for `register`.
+    /// A schema update is subject to the same compatibility rules as a new
+    /// minor version, and every registered instance of the schema is
+    /// re-validated against the new content; the results are returned in
+    /// [`EntityUpdate::revalidated`].
+    ///
+    /// # Errors
+    ///
+    /// * `NotFound` - If no entity with the GTS ID exists
+    /// * `ValidationFailed` - If the new content is invalid or breaks compatibility
+    /// * `NotInReadyMode` - If the registry is still in configuration mode
+    async fn update(&self, entity: serde_json::Value) -> Result<EntityUpdate, TypesRegistryError>;
+
+    /// Mark a GTS entity as deprecated.
+    ///
+    /// Deprecated entities remain readable; a deprecated schema rejects new
+    /// instances. Deprecating an already deprecated entity is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// * `NotFound` - If no entity with the given GTS ID exists
+    /// * `NotInReadyMode` - If the registry is still in configuration mode
+    async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, TypesRegistryError>;
+
+    /// Subscribe to changes of GTS entities matching `query`.
+    ///
+    /// The stream yields every registration, update and deprecation made after
+    /// the call, in order, and never ends on its own; drop it to unsubscribe.
+    /// Deprecation is how entities are withdrawn from the registry, so
+    /// consumers that cache a selection (such as a plugin instance) should
+    /// re-resolve on any change.
+    ///
+    /// A subscriber that falls too far behind gets a `Lagged` error with the
+    /// number of changes it missed, then the stream resumes with the most
+    /// recent changes.
+    fn watch(&self, query: ListQuery) -> EntityChangeStream;
 }
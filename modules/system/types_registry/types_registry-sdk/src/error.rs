@@ -27,6 +27,14 @@ pub enum TypesRegistryError {
     #[error("Not in ready mode")]
     NotInReadyMode,
 
+    /// The listing query (`$filter`, `$orderby`, cursor or limit) is invalid.
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    /// A change subscription fell behind and missed the given number of changes.
+    #[error("Watch lagged behind: {0} changes dropped")]
+    Lagged(u64),
+
     /// An internal error occurred.
     #[error("Internal error: {0}")]
     Internal(String),
@@ -63,6 +71,18 @@ impl TypesRegistryError {
         Self::NotInReadyMode
     }
 
+    /// Creates an `InvalidQuery` error.
+    #[must_use]
+    pub fn invalid_query(message: impl Into<String>) -> Self {
+        Self::InvalidQuery(message.into())
+    }
+
+    /// Creates a `Lagged` error.
+    #[must_use]
+    pub const fn lagged(skipped: u64) -> Self {
+        Self::Lagged(skipped)
+    }
+
     /// Creates an `Internal` error.
     #[must_use]
     pub fn internal(message: impl Into<String>) -> Self {
@@ -92,6 +112,18 @@ impl TypesRegistryError {
     pub const fn is_invalid_gts_id(&self) -> bool {
         matches!(self, Self::InvalidGtsId(_))
     }
+
+    /// Returns `true` if this is an invalid query error.
+    #[must_use]
+    pub const fn is_invalid_query(&self) -> bool {
+        matches!(self, Self::InvalidQuery(_))
+    }
+
+    /// Returns `true` if a change subscription missed changes.
+    #[must_use]
+    pub const fn is_lagged(&self) -> bool {
+        matches!(self, Self::Lagged(_))
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +148,13 @@ mod tests {
         let err = TypesRegistryError::not_in_ready_mode();
         assert!(matches!(err, TypesRegistryError::NotInReadyMode));
 
+        let err = TypesRegistryError::invalid_query("unsupported $orderby field: content");
+        assert!(err.is_invalid_query());
+
+        let err = TypesRegistryError::lagged(3);
+        assert!(err.is_lagged());
+        assert_eq!(err.to_string(), "Watch lagged behind: 3 changes dropped");
+
         let err = TypesRegistryError::internal("database error");
         assert!(matches!(err, TypesRegistryError::Internal(_)));
     }
@@ -3,7 +3,8 @@
 //! This crate provides the public API for the `types-registry` module:
 //! - `TypesRegistryApi` trait for inter-module communication
 //! - `GtsEntity` model representing registered GTS entities
-//! - `ListQuery` for filtering entity listings
+//! - `ListQuery` and the `odata` filter fields for paginated entity listings
+//! - `EntityChange` events streamed by `TypesRegistryClient::watch`
 //! - `TypesRegistryError` for error handling
 //!
 //! ## Usage
@@ -18,12 +19,22 @@
 //! // Register entities
 //! let entities = client.register(&ctx, json_values).await?;
 //!
-//! // List entities with filtering
+//! // List a page of entities with filtering
 //! let query = ListQuery::default().with_vendor("acme");
-//! let entities = client.list(&ctx, query).await?;
+//! let page = client.list(query, ODataQuery::default().with_limit(20)).await?;
+//!
+//! // Or follow the cursors to fetch every match
+//! let entities = client.list_all(ListQuery::default().with_vendor("acme")).await?;
 //!
 //! // Get a single entity
 //! let entity = client.get(&ctx, "gts.acme.core.events.user_created.v1~").await?;
+//!
+//! // Follow registrations, updates and deprecations of matching entities
+//! let mut changes = client.watch(ListQuery::default().with_pattern("gts.acme.*"));
+//! while let Some(change) = changes.next().await {
+//!     let change = change?;
+//!     println!("{:?} {}", change.kind, change.entity.gts_id);
+//! }
 //! ```
 
 #![forbid(unsafe_code)]
@@ -32,11 +43,14 @@
 pub mod api;
 pub mod error;
 pub mod models;
+pub mod odata;
 
 // Re-export main types at crate root for convenience
-pub use api::TypesRegistryClient;
+pub use api::{EntityChangeStream, TypesRegistryClient};
 pub use error::TypesRegistryError;
 pub use models::{
-    DynGtsEntity, DynRegisterResult, GtsEntity, GtsInstanceEntity, GtsTypeEntity, InstanceObject,
-    ListQuery, RegisterResult, RegisterSummary, SegmentMatchScope, TypeSchema,
+    DynGtsEntity, DynRegisterResult, EntityChange, EntityChangeKind, EntityUpdate, GtsEntity,
+    GtsInstanceEntity, GtsTypeEntity, InstanceObject, InstanceValidation, ListQuery,
+    RegisterResult, RegisterSummary, SegmentMatchScope, TypeSchema,
 };
+pub use modkit_odata::{ODataQuery, Page, PageInfo};
@@ -3,7 +3,7 @@
 //! These are transport-agnostic data structures that define the contract
 //! between the `types-registry` module and its consumers.
 
-use gts::GtsIdSegment;
+use gts::{GtsID, GtsIdSegment, GtsWildcard};
 use uuid::Uuid;
 
 /// A registered GTS entity.
@@ -58,6 +58,12 @@ pub struct GtsEntity<C = serde_json::Value> {
 
     /// Optional description of the entity.
     pub description: Option<String>,
+
+    /// Whether the entity has been deprecated.
+    ///
+    /// Deprecated entities remain readable, but a deprecated schema no longer
+    /// accepts new instances.
+    pub deprecated: bool,
 }
 
 /// Type alias for dynamic GTS entities using `serde_json::Value` as content.
@@ -276,7 +282,7 @@ pub type GtsInstanceEntity = GtsEntity<InstanceObject>;
 /// let results = registry.register(&ctx, entities).await?;
 /// for (index, result) in results.iter().enumerate() {
 ///     match result {
-///         RegisterResult::Ok(entity) => println!("Registered: {}", entity.gts_id),
+///         RegisterResult::Ok { entity, .. } => println!("Registered: {}", entity.gts_id),
 ///         RegisterResult::Err { gts_id, error } => {
 ///             eprintln!("Failed to register {}: {}", gts_id.as_deref().unwrap_or("unknown"), error);
 ///         }
@@ -286,7 +292,13 @@ pub type GtsInstanceEntity = GtsEntity<InstanceObject>;
 #[derive(Debug, Clone)]
 pub enum RegisterResult<C = serde_json::Value> {
     /// Successfully registered entity.
-    Ok(GtsEntity<C>),
+    Ok {
+        /// The registered entity.
+        entity: GtsEntity<C>,
+        /// For a new minor version of a schema, every instance of the previous
+        /// version re-validated against the new one. Empty otherwise.
+        revalidated: Vec<InstanceValidation>,
+    },
     /// Failed to register entity.
     Err {
         /// The GTS ID that was attempted, if it could be extracted from the input.
@@ -300,7 +312,7 @@ impl<C> RegisterResult<C> {
     /// Returns `true` if the registration was successful.
     #[must_use]
     pub const fn is_ok(&self) -> bool {
-        matches!(self, Self::Ok(_))
+        matches!(self, Self::Ok { .. })
     }
 
     /// Returns `true` if the registration failed.
@@ -316,7 +328,7 @@ impl<C> RegisterResult<C> {
     /// Returns `Err` with a reference to the error if this is a failed registration.
     pub fn as_result(&self) -> Result<&GtsEntity<C>, &crate::TypesRegistryError> {
         match self {
-            Self::Ok(entity) => Ok(entity),
+            Self::Ok { entity, .. } => Ok(entity),
             Self::Err { error, .. } => Err(error),
         }
     }
@@ -328,7 +340,7 @@ impl<C> RegisterResult<C> {
     /// Returns `Err` with the error if this is a failed registration.
     pub fn into_result(self) -> Result<GtsEntity<C>, crate::TypesRegistryError> {
         match self {
-            Self::Ok(entity) => Ok(entity),
+            Self::Ok { entity, .. } => Ok(entity),
             Self::Err { error, .. } => Err(error),
         }
     }
@@ -337,16 +349,26 @@ impl<C> RegisterResult<C> {
     #[must_use]
     pub fn ok(self) -> Option<GtsEntity<C>> {
         match self {
-            Self::Ok(entity) => Some(entity),
+            Self::Ok { entity, .. } => Some(entity),
             Self::Err { .. } => None,
         }
     }
 
+    /// Returns the instances that no longer validate against a newly registered
+    /// minor version of their schema.
+    pub fn invalid_instances(&self) -> impl Iterator<Item = &InstanceValidation> {
+        let revalidated = match self {
+            Self::Ok { revalidated, .. } => revalidated.as_slice(),
+            Self::Err { .. } => &[],
+        };
+        revalidated.iter().filter(|v| !v.is_valid())
+    }
+
     /// Returns the error if failed, `None` otherwise.
     #[must_use]
     pub fn err(self) -> Option<crate::TypesRegistryError> {
         match self {
-            Self::Ok(_) => None,
+            Self::Ok { .. } => None,
             Self::Err { error, .. } => Some(error),
         }
     }
@@ -394,6 +416,65 @@ impl RegisterSummary {
     }
 }
 
+/// Outcome of re-validating a registered instance against a changed schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceValidation {
+    /// GTS ID of the instance.
+    pub gts_id: String,
+    /// Why the instance no longer validates, or `None` if it does.
+    pub error: Option<String>,
+}
+
+impl InstanceValidation {
+    /// Returns `true` if the instance is valid against the new schema.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Result of updating (or registering) a GTS entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityUpdate {
+    /// The entity as stored after the update.
+    pub entity: GtsEntity,
+    /// For schema updates, every registered instance of the schema re-validated
+    /// against the new content; for a new minor version of a schema, every
+    /// instance of the previous version. Empty otherwise.
+    pub revalidated: Vec<InstanceValidation>,
+}
+
+impl EntityUpdate {
+    /// Returns the instances that no longer validate against the updated schema.
+    pub fn invalid_instances(&self) -> impl Iterator<Item = &InstanceValidation> {
+        self.revalidated.iter().filter(|v| !v.is_valid())
+    }
+}
+
+/// What happened to a GTS entity, as reported by a change subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityChangeKind {
+    /// The entity was registered.
+    Registered,
+    /// The content of the entity was replaced.
+    Updated,
+    /// The entity was deprecated.
+    ///
+    /// Entities are never deleted from the registry, so this is how they are
+    /// withdrawn: consumers selecting among entities (e.g. plugin instances)
+    /// should treat it as a removal.
+    Deprecated,
+}
+
+/// A change to a registered GTS entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityChange {
+    /// What happened to the entity.
+    pub kind: EntityChangeKind,
+    /// The entity as stored after the change.
+    pub entity: GtsEntity,
+}
+
 impl<C> GtsEntity<C> {
     /// Creates a new `GtsEntity` with the given components.
     #[must_use]
@@ -412,6 +493,7 @@ impl<C> GtsEntity<C> {
             is_schema,
             content,
             description,
+            deprecated: false,
         }
     }
 
@@ -610,6 +692,53 @@ impl ListQuery {
             && self.package.is_none()
             && self.namespace.is_none()
     }
+
+    /// Returns `true` if `entity` passes every filter of this query.
+    #[must_use]
+    pub fn matches<C>(&self, entity: &GtsEntity<C>) -> bool {
+        if let Some(ref pattern) = self.pattern
+            && let Ok(wildcard) = GtsWildcard::new(pattern)
+        {
+            if let Ok(gts_id) = GtsID::new(&entity.gts_id) {
+                if !gts_id.wildcard_match(&wildcard) {
+                    return false;
+                }
+            } else {
+                return false;
+            }
+        }
+
+        if let Some(is_type) = self.is_type
+            && entity.is_type() != is_type
+        {
+            return false;
+        }
+
+        let segments_to_check: Vec<&GtsIdSegment> = match self.segment_scope {
+            SegmentMatchScope::Primary => entity.segments.first().into_iter().collect(),
+            SegmentMatchScope::Any => entity.segments.iter().collect(),
+        };
+
+        if let Some(ref vendor) = self.vendor
+            && !segments_to_check.iter().any(|s| s.vendor == *vendor)
+        {
+            return false;
+        }
+
+        if let Some(ref package) = self.package
+            && !segments_to_check.iter().any(|s| s.package == *package)
+        {
+            return false;
+        }
+
+        if let Some(ref namespace) = self.namespace
+            && !segments_to_check.iter().any(|s| s.namespace == *namespace)
+        {
+            return false;
+        }
+
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,62 @@
+//! `OData` filter field definitions for GTS entities.
+//!
+//! These fields can be used in `$filter` and `$orderby` when listing entities,
+//! both over REST and through [`TypesRegistryClient::list`](crate::TypesRegistryClient::list).
+//! `vendor`, `package`, `namespace` and `type_name` refer to the primary (first)
+//! segment of the GTS ID.
+
+use modkit_odata_macros::ODataFilterable;
+use modkit_sdk::odata::{FieldRef, Schema};
+
+use modkit_odata::filter::FilterField as _;
+
+/// GTS entity filterable fields schema.
+#[derive(ODataFilterable)]
+pub struct GtsEntityQuery {
+    #[odata(filter(kind = "String"))]
+    pub gts_id: String,
+
+    #[odata(filter(kind = "String"))]
+    pub vendor: String,
+
+    #[odata(filter(kind = "String"))]
+    pub package: String,
+
+    #[odata(filter(kind = "String"))]
+    pub namespace: String,
+
+    #[odata(filter(kind = "String"))]
+    pub type_name: String,
+
+    #[odata(filter(kind = "Bool"))]
+    pub is_schema: bool,
+
+    #[odata(filter(kind = "Bool"))]
+    pub deprecated: bool,
+}
+
+/// Type alias for the generated filter field enum.
+pub use GtsEntityQueryFilterField as GtsEntityFilterField;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GtsEntitySchema;
+
+impl Schema for GtsEntitySchema {
+    type Field = GtsEntityFilterField;
+
+    fn field_name(field: Self::Field) -> &'static str {
+        field.name()
+    }
+}
+
+pub const GTS_ID: FieldRef<GtsEntitySchema, String> = FieldRef::new(GtsEntityFilterField::GtsId);
+pub const VENDOR: FieldRef<GtsEntitySchema, String> = FieldRef::new(GtsEntityFilterField::Vendor);
+pub const PACKAGE: FieldRef<GtsEntitySchema, String> = FieldRef::new(GtsEntityFilterField::Package);
+pub const NAMESPACE: FieldRef<GtsEntitySchema, String> =
+    FieldRef::new(GtsEntityFilterField::Namespace);
+pub const TYPE_NAME: FieldRef<GtsEntitySchema, String> =
+    FieldRef::new(GtsEntityFilterField::TypeName);
+pub const IS_SCHEMA: FieldRef<GtsEntitySchema, bool> =
+    FieldRef::new(GtsEntityFilterField::IsSchema);
+pub const DEPRECATED: FieldRef<GtsEntitySchema, bool> =
+    FieldRef::new(GtsEntityFilterField::Deprecated);
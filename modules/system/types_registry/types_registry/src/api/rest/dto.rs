@@ -5,7 +5,10 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use gts::GtsIdSegment;
-use types_registry_sdk::{GtsEntity, RegisterResult, RegisterSummary, SegmentMatchScope};
+use types_registry_sdk::{
+    EntityChange, EntityChangeKind, EntityUpdate, GtsEntity, InstanceValidation, RegisterResult,
+    RegisterSummary, SegmentMatchScope,
+};
 
 /// DTO for a GTS ID segment.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -55,6 +58,8 @@ pub struct GtsEntityDto {
     /// Optional description of the entity.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Whether the entity is deprecated. A deprecated type accepts no new instances.
+    pub deprecated: bool,
 }
 
 impl From<GtsEntity> for GtsEntityDto {
@@ -66,6 +71,88 @@ impl From<GtsEntity> for GtsEntityDto {
             is_schema: entity.is_schema,
             content: entity.content.clone(),
             description: entity.description.clone(),
+            deprecated: entity.deprecated,
+        }
+    }
+}
+
+/// Result of re-validating one instance against an updated type.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceValidationDto {
+    /// GTS ID of the instance.
+    pub gts_id: String,
+    /// Whether the instance is valid against the updated type.
+    pub valid: bool,
+    /// Validation error, if the instance is no longer valid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<InstanceValidation> for InstanceValidationDto {
+    fn from(validation: InstanceValidation) -> Self {
+        let valid = validation.is_valid();
+        Self {
+            gts_id: validation.gts_id,
+            valid,
+            error: validation.error,
+        }
+    }
+}
+
+/// Response DTO for an entity update.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityUpdateDto {
+    /// The updated entity.
+    pub entity: GtsEntityDto,
+    /// For type updates, the re-validation result of every registered instance.
+    pub revalidated: Vec<InstanceValidationDto>,
+}
+
+impl From<EntityUpdate> for EntityUpdateDto {
+    fn from(update: EntityUpdate) -> Self {
+        Self {
+            entity: update.entity.into(),
+            revalidated: update.revalidated.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// What happened to an entity in a change event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EntityChangeKindDto {
+    Registered,
+    Updated,
+    Deprecated,
+}
+
+impl From<EntityChangeKind> for EntityChangeKindDto {
+    fn from(kind: EntityChangeKind) -> Self {
+        match kind {
+            EntityChangeKind::Registered => Self::Registered,
+            EntityChangeKind::Updated => Self::Updated,
+            EntityChangeKind::Deprecated => Self::Deprecated,
+        }
+    }
+}
+
+/// Event DTO for a change to a GTS entity.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityChangeDto {
+    /// What happened to the entity.
+    pub kind: EntityChangeKindDto,
+    /// The entity as stored after the change.
+    pub entity: GtsEntityDto,
+}
+
+impl From<EntityChange> for EntityChangeDto {
+    fn from(change: EntityChange) -> Self {
+        Self {
+            kind: change.kind.into(),
+            entity: change.entity.into(),
         }
     }
 }
@@ -77,6 +164,13 @@ pub struct RegisterEntitiesRequest {
     pub entities: Vec<serde_json::Value>,
 }
 
+/// Request DTO for updating a GTS entity.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateEntityRequest {
+    /// The new entity content, including its GTS ID.
+    pub entity: serde_json::Value,
+}
+
 /// Result of registering a single entity.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", tag = "status")]
@@ -86,6 +180,10 @@ pub enum RegisterResultDto {
     Ok {
         /// The registered entity.
         entity: GtsEntityDto,
+        /// For a new minor version of a type, the re-validation result of every
+        /// instance of the previous version.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        revalidated: Vec<InstanceValidationDto>,
     },
     /// Failed to register entity.
     #[serde(rename = "error")]
@@ -101,8 +199,12 @@ pub enum RegisterResultDto {
 impl From<RegisterResult> for RegisterResultDto {
     fn from(result: RegisterResult) -> Self {
         match result {
-            RegisterResult::Ok(entity) => Self::Ok {
+            RegisterResult::Ok {
+                entity,
+                revalidated,
+            } => Self::Ok {
                 entity: entity.into(),
+                revalidated: revalidated.into_iter().map(Into::into).collect(),
             },
             RegisterResult::Err { gts_id, error } => Self::Error {
                 gts_id,
@@ -206,16 +308,6 @@ impl ListEntitiesQuery {
     }
 }
 
-/// Response DTO for listing GTS entities.
-#[derive(Debug, Clone, Serialize, ToSchema)]
-#[serde(rename_all = "camelCase")]
-pub struct ListEntitiesResponse {
-    /// The list of entities.
-    pub entities: Vec<GtsEntityDto>,
-    /// Total count of entities returned.
-    pub count: usize,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,6 +409,30 @@ mod tests {
         assert_eq!(dto.segments[1].ver_major, 1);
     }
 
+    #[test]
+    fn test_entity_change_dto_serialization() {
+        let mut entity = GtsEntity::new(
+            Uuid::nil(),
+            "gts.acme.core.events.user_created.v1~",
+            vec![],
+            true, // is_schema
+            serde_json::json!({"type": "object"}),
+            None,
+        );
+        entity.deprecated = true;
+        let change = EntityChange {
+            kind: EntityChangeKind::Deprecated,
+            entity,
+        };
+
+        let json = serde_json::to_value(EntityChangeDto::from(change)).unwrap();
+        assert_eq!(json["kind"], "deprecated");
+        assert_eq!(
+            json["entity"]["gtsId"],
+            "gts.acme.core.events.user_created.v1~"
+        );
+    }
+
     #[test]
     fn test_gts_id_segment_dto_serialization() {
         let segment = GtsIdSegment::new(0, 0, "acme.billing.invoices.invoice.v2~").unwrap();
@@ -425,7 +541,10 @@ mod tests {
             serde_json::json!({}),
             None,
         );
-        let result = RegisterResult::Ok(entity);
+        let result = RegisterResult::Ok {
+            entity,
+            revalidated: vec![],
+        };
         let dto: RegisterResultDto = result.into();
         assert!(matches!(dto, RegisterResultDto::Ok { .. }));
     }
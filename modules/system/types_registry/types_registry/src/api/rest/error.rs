@@ -42,6 +42,20 @@ impl From<DomainError> for Problem {
                 "Service not ready",
                 "The types registry is not yet ready".to_owned(),
             ),
+            DomainError::InvalidQuery(e) => {
+                // Same problem types as the `OData` errors of other modules
+                let problem = Self::from(e.clone());
+                return match trace_id {
+                    Some(id) => problem.with_trace_id(id),
+                    None => problem,
+                };
+            }
+            DomainError::Lagged(skipped) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "TYPES_REGISTRY_WATCH_LAGGED",
+                "Change subscription lagged",
+                format!("The subscriber fell behind and missed {skipped} changes"),
+            ),
             DomainError::ReadyCommitFailed(errors) => {
                 let error_strings: Vec<String> = errors
                     .iter()
@@ -120,6 +134,20 @@ mod tests {
         assert_eq!(problem.status, StatusCode::SERVICE_UNAVAILABLE);
     }
 
+    #[test]
+    fn test_domain_error_to_problem_invalid_query() {
+        let err = DomainError::from(modkit_odata::Error::InvalidFilter("bad".to_owned()));
+        let problem: Problem = err.into();
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn test_domain_error_to_problem_lagged() {
+        let err = DomainError::Lagged(3);
+        let problem: Problem = err.into();
+        assert_eq!(problem.status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     #[test]
     fn test_domain_error_to_problem_ready_commit_failed() {
         use crate::domain::error::ValidationError;
@@ -1,16 +1,22 @@
 //! REST handlers for the Types Registry module.
 
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::Json;
 use axum::extract::{Extension, Path, Query};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::{Stream, StreamExt};
+use modkit::api::odata::OData;
 use modkit::api::prelude::*;
 use modkit::api::problem::Problem;
+use modkit::api::select::page_to_projected_json;
 use types_registry_sdk::RegisterSummary;
 
 use super::dto::{
-    GtsEntityDto, ListEntitiesQuery, ListEntitiesResponse, RegisterEntitiesRequest,
-    RegisterEntitiesResponse, RegisterResultDto, RegisterSummaryDto,
+    EntityChangeDto, EntityUpdateDto, GtsEntityDto, ListEntitiesQuery, RegisterEntitiesRequest,
+    RegisterEntitiesResponse, RegisterResultDto, RegisterSummaryDto, UpdateEntityRequest,
 };
 use crate::domain::error::DomainError;
 use crate::domain::service::TypesRegistryService;
@@ -28,7 +34,7 @@ pub async fn register_entities(
         return Err(DomainError::NotInReadyMode.into());
     }
 
-    let results = service.register_validated(req.entities);
+    let results = service.register_validated(req.entities).await;
 
     let summary = RegisterSummary::from_results(&results);
     let result_dtos: Vec<RegisterResultDto> = results.into_iter().map(Into::into).collect();
@@ -43,26 +49,57 @@ pub async fn register_entities(
 
 /// GET /api/v1/types-registry/entities
 ///
-/// List GTS entities with optional filtering.
+/// List GTS entities with cursor pagination, optional filtering and field
+/// projection via `$select`.
 pub async fn list_entities(
     Extension(service): Extension<Arc<TypesRegistryService>>,
     Query(query): Query<ListEntitiesQuery>,
-) -> ApiResult<Json<ListEntitiesResponse>> {
+    OData(odata): OData,
+) -> ApiResult<JsonPage<serde_json::Value>> {
     if !service.is_ready() {
         return Err(DomainError::NotInReadyMode.into());
     }
 
     let list_query = query.to_list_query();
 
-    let entities = service.list(&list_query).map_err(Problem::from)?;
+    let page = service
+        .list_page(&list_query, &odata)
+        .await
+        .map_err(Problem::from)?
+        .map_items(GtsEntityDto::from);
 
-    let entity_dtos: Vec<GtsEntityDto> = entities.into_iter().map(Into::into).collect();
-    let count = entity_dtos.len();
+    Ok(Json(page_to_projected_json(&page, odata.selected_fields())))
+}
 
-    Ok(Json(ListEntitiesResponse {
-        entities: entity_dtos,
-        count,
-    }))
+/// GET /api/v1/types-registry/entities/events
+///
+/// Stream changes to matching GTS entities as Server-Sent Events.
+/// Each change is an `entity_change` event; a subscriber that falls behind gets
+/// an `error` event with the problem details and then continues.
+pub async fn watch_entities(
+    Extension(service): Extension<Arc<TypesRegistryService>>,
+    Query(query): Query<ListEntitiesQuery>,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    if !service.is_ready() {
+        return Err(DomainError::NotInReadyMode.into());
+    }
+
+    let events = service.watch(query.to_list_query()).map(|change| {
+        let event = match change {
+            Ok(change) => Event::default()
+                .event("entity_change")
+                .json_data(EntityChangeDto::from(change)),
+            Err(e) => Event::default().event("error").json_data(Problem::from(e)),
+        };
+        // Fall back to a tiny text marker instead of breaking the stream
+        Ok(event.unwrap_or_else(|_| Event::default().data("serialization_error")))
+    });
+
+    Ok(Sse::new(events).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("keepalive"),
+    ))
 }
 
 /// GET /api/v1/types-registry/entities/{gts_id}
@@ -76,7 +113,47 @@ pub async fn get_entity(
         return Err(DomainError::NotInReadyMode.into());
     }
 
-    let entity = service.get(&gts_id).map_err(Problem::from)?;
+    let entity = service.get(&gts_id).await.map_err(Problem::from)?;
+
+    Ok(Json(entity.into()))
+}
+
+/// PUT /api/v1/types-registry/entities/{gts_id}
+///
+/// Replace the content of a GTS entity. The GTS ID in the body must match the path.
+pub async fn update_entity(
+    Extension(service): Extension<Arc<TypesRegistryService>>,
+    Path(gts_id): Path<String>,
+    Json(req): Json<UpdateEntityRequest>,
+) -> ApiResult<Json<EntityUpdateDto>> {
+    if !service.is_ready() {
+        return Err(DomainError::NotInReadyMode.into());
+    }
+
+    if service.extract_gts_id(&req.entity).as_deref() != Some(gts_id.as_str()) {
+        return Err(DomainError::invalid_gts_id(format!(
+            "Entity GTS ID does not match the path: {gts_id}"
+        ))
+        .into());
+    }
+
+    let update = service.update(req.entity).await.map_err(Problem::from)?;
+
+    Ok(Json(update.into()))
+}
+
+/// POST /api/v1/types-registry/entities/{gts_id}/deprecate
+///
+/// Mark a GTS entity as deprecated.
+pub async fn deprecate_entity(
+    Extension(service): Extension<Arc<TypesRegistryService>>,
+    Path(gts_id): Path<String>,
+) -> ApiResult<Json<GtsEntityDto>> {
+    if !service.is_ready() {
+        return Err(DomainError::NotInReadyMode.into());
+    }
+
+    let entity = service.deprecate(&gts_id).await.map_err(Problem::from)?;
 
     Ok(Json(entity.into()))
 }
@@ -86,7 +163,9 @@ mod tests {
     use super::*;
     use crate::infra::InMemoryGtsRepository;
     use gts::GtsConfig;
+    use modkit_odata::{ODataOrderBy, ODataQuery, OrderKey, SortDir};
     use serde_json::json;
+    use types_registry_sdk::odata::VENDOR;
 
     const JSON_SCHEMA_DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
 
@@ -125,7 +204,12 @@ mod tests {
         // Service is not ready yet
 
         let query = ListEntitiesQuery::default();
-        let result = list_entities(Extension(service), Query(query)).await;
+        let result = list_entities(
+            Extension(service),
+            Query(query),
+            OData(ODataQuery::default()),
+        )
+        .await;
         assert!(result.is_err());
     }
 
@@ -142,10 +226,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_watch_entities_returns_503_when_not_ready() {
+        let service = create_service();
+        // Service is not ready yet
+
+        let result = watch_entities(Extension(service), Query(ListEntitiesQuery::default())).await;
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_register_entities_handler_when_ready() {
         let service = create_service();
-        service.switch_to_ready().unwrap();
+        service.switch_to_ready().await.unwrap();
 
         let req = RegisterEntitiesRequest {
             entities: vec![json!({
@@ -170,26 +263,70 @@ mod tests {
         let service = create_service();
 
         // Register entities via internal API (before ready)
-        let _ = service.register(vec![
-            json!({
-                "$id": "gts://gts.acme.core.events.user_created.v1~",
-                "$schema": JSON_SCHEMA_DRAFT_07,
-                "type": "object"
-            }),
-            json!({
-                "$id": "gts://gts.globex.core.events.order_placed.v1~",
-                "$schema": JSON_SCHEMA_DRAFT_07,
-                "type": "object"
-            }),
-        ]);
-        service.switch_to_ready().unwrap();
+        let _ = service
+            .register(vec![
+                json!({
+                    "$id": "gts://gts.acme.core.events.user_created.v1~",
+                    "$schema": JSON_SCHEMA_DRAFT_07,
+                    "type": "object"
+                }),
+                json!({
+                    "$id": "gts://gts.globex.core.events.order_placed.v1~",
+                    "$schema": JSON_SCHEMA_DRAFT_07,
+                    "type": "object"
+                }),
+            ])
+            .await;
+        service.switch_to_ready().await.unwrap();
 
         let query = ListEntitiesQuery::default();
-        let result = list_entities(Extension(service), Query(query)).await;
+        let result = list_entities(
+            Extension(service.clone()),
+            Query(query),
+            OData(ODataQuery::default()),
+        )
+        .await;
         assert!(result.is_ok());
 
         let Json(response) = result.unwrap();
-        assert_eq!(response.count, 2);
+        assert_eq!(response.items.len(), 2);
+        assert!(response.page_info.next_cursor.is_none());
+
+        // Filtered, one per page, projected to the GTS ID
+        let odata = ODataQuery::default()
+            .with_filter(VENDOR.ne("globex"))
+            .with_limit(1)
+            .with_select(vec!["gtsId".to_owned()]);
+        let Json(response) = list_entities(
+            Extension(service),
+            Query(ListEntitiesQuery::default()),
+            OData(odata),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            response.items,
+            vec![json!({ "gtsId": "gts.acme.core.events.user_created.v1~" })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_entities_rejects_unknown_order_field() {
+        let service = create_service();
+        service.switch_to_ready().await.unwrap();
+
+        let odata = ODataQuery::default().with_order(ODataOrderBy(vec![OrderKey {
+            field: "content".to_owned(),
+            dir: SortDir::Asc,
+        }]));
+        let result = list_entities(
+            Extension(service),
+            Query(ListEntitiesQuery::default()),
+            OData(odata),
+        )
+        .await;
+        let problem = result.unwrap_err();
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
     }
 
     #[tokio::test]
@@ -197,12 +334,14 @@ mod tests {
         let service = create_service();
 
         // Register entity via internal API (before ready)
-        let _ = service.register(vec![json!({
-            "$id": "gts://gts.acme.core.events.user_created.v1~",
-            "$schema": JSON_SCHEMA_DRAFT_07,
-            "type": "object"
-        })]);
-        service.switch_to_ready().unwrap();
+        let _ = service
+            .register(vec![json!({
+                "$id": "gts://gts.acme.core.events.user_created.v1~",
+                "$schema": JSON_SCHEMA_DRAFT_07,
+                "type": "object"
+            })])
+            .await;
+        service.switch_to_ready().await.unwrap();
 
         let result = get_entity(
             Extension(service),
@@ -218,7 +357,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_entity_not_found() {
         let service = create_service();
-        service.switch_to_ready().unwrap();
+        service.switch_to_ready().await.unwrap();
 
         let result = get_entity(
             Extension(service),
@@ -227,4 +366,43 @@ mod tests {
         .await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_watch_entities_streams_changes() {
+        let service = create_service();
+        service.switch_to_ready().await.unwrap();
+
+        let query = ListEntitiesQuery {
+            pattern: Some("gts.acme.*".to_owned()),
+            ..Default::default()
+        };
+        let sse = watch_entities(Extension(service.clone()), Query(query))
+            .await
+            .unwrap();
+        let mut body = sse.into_response().into_body().into_data_stream();
+
+        let _ = service
+            .register_validated(vec![
+                json!({
+                    "$id": "gts://gts.globex.core.events.order_placed.v1~",
+                    "$schema": JSON_SCHEMA_DRAFT_07,
+                    "type": "object"
+                }),
+                json!({
+                    "$id": "gts://gts.acme.core.events.user_created.v1~",
+                    "$schema": JSON_SCHEMA_DRAFT_07,
+                    "type": "object"
+                }),
+            ])
+            .await;
+
+        let frame = body.next().await.unwrap().unwrap();
+        let frame = std::str::from_utf8(&frame).unwrap();
+        assert!(frame.starts_with("event: entity_change\n"), "{frame}");
+        assert!(frame.contains(r#""kind":"registered""#), "{frame}");
+        assert!(
+            frame.contains(r#""gtsId":"gts.acme.core.events.user_created.v1~""#),
+            "{frame}"
+        );
+    }
 }
@@ -5,12 +5,14 @@ use std::sync::Arc;
 use axum::{Extension, Router};
 use modkit::api::OpenApiRegistry;
 use modkit::api::operation_builder::{
-    AuthReqAction, AuthReqResource, LicenseFeature, OperationBuilder,
+    AuthReqAction, AuthReqResource, LicenseFeature, OperationBuilder, OperationBuilderODataExt,
 };
 use modkit::api::prelude::StatusCode;
+use types_registry_sdk::odata::GtsEntityFilterField;
 
 use super::dto::{
-    GtsEntityDto, ListEntitiesResponse, RegisterEntitiesRequest, RegisterEntitiesResponse,
+    EntityChangeDto, EntityUpdateDto, GtsEntityDto, RegisterEntitiesRequest,
+    RegisterEntitiesResponse, UpdateEntityRequest,
 };
 use super::handlers;
 use crate::domain::service::TypesRegistryService;
@@ -89,7 +91,7 @@ pub fn register_routes(
         .operation_id("types_registry.list")
         .summary("List GTS entities")
         .description(
-            "List registered GTS entities with optional filtering by pattern, kind, vendor, package, or namespace.",
+            "List registered GTS entities with cursor pagination, optional filtering by pattern, kind, vendor, package, or namespace, and OData $filter, $orderby and $select.",
         )
         .tag(TAG)
         .require_auth(&Resource::TypesRegistry, &Action::Read)
@@ -100,12 +102,43 @@ pub fn register_routes(
         .query_param("package", false, "Filter by package")
         .query_param("namespace", false, "Filter by namespace")
         .query_param("segmentScope", false, "Segment match scope: 'primary' or 'any' (default)")
+        .query_param_typed(
+            "limit",
+            false,
+            "Maximum number of entities to return (default 50, max 1000)",
+            "integer",
+        )
+        .query_param("cursor", false, "Cursor for pagination")
         .handler(handlers::list_entities)
-        .json_response_with_schema::<ListEntitiesResponse>(
+        .json_response_with_schema::<modkit_odata::Page<GtsEntityDto>>(
             openapi,
             StatusCode::OK,
-            "List of entities",
+            "Paginated list of entities",
         )
+        .with_odata_filter::<GtsEntityFilterField>()
+        .with_odata_select()
+        .with_odata_orderby::<GtsEntityFilterField>()
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // GET /types-registry/v1/entities/events - Stream entity changes (SSE)
+    router = OperationBuilder::get("/types-registry/v1/entities/events")
+        .operation_id("types_registry.events")
+        .summary("GTS entity changes stream (SSE)")
+        .description(
+            "Stream registrations, updates and deprecations of GTS entities matching the filters as Server-Sent Events.",
+        )
+        .tag(TAG)
+        .require_auth(&Resource::TypesRegistry, &Action::Read)
+        .require_license_features::<License>([])
+        .query_param("pattern", false, "Wildcard pattern for GTS ID matching (e.g., gts.acme.*)")
+        .query_param("kind", false, "Filter by entity kind: 'type' or 'instance'")
+        .query_param("vendor", false, "Filter by vendor")
+        .query_param("package", false, "Filter by package")
+        .query_param("namespace", false, "Filter by namespace")
+        .query_param("segmentScope", false, "Segment match scope: 'primary' or 'any' (default)")
+        .handler(handlers::watch_entities)
+        .sse_json::<EntityChangeDto>(openapi, "SSE stream of EntityChange")
         .standard_errors(openapi)
         .register(router, openapi);
 
@@ -127,5 +160,48 @@ pub fn register_routes(
         .standard_errors(openapi)
         .register(router, openapi);
 
+    // PUT /types-registry/v1/entities/{gts_id} - Update GTS entity
+    router = OperationBuilder::put("/types-registry/v1/entities/{gts_id}")
+        .operation_id("types_registry.update")
+        .summary("Update GTS entity")
+        .description(
+            "Replace the content of a GTS entity. Type updates must stay compatible with the current version; registered instances are re-validated and reported.",
+        )
+        .tag(TAG)
+        .require_auth(&Resource::TypesRegistry, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param(
+            "gts_id",
+            "The GTS identifier (e.g., gts.acme.core.events.user_created.v1~)",
+        )
+        .json_request::<UpdateEntityRequest>(openapi, "New entity content")
+        .handler(handlers::update_entity)
+        .json_response_with_schema::<EntityUpdateDto>(
+            openapi,
+            StatusCode::OK,
+            "The updated entity and instance re-validation results",
+        )
+        .problem_response(openapi, StatusCode::NOT_FOUND, "Entity not found")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    // POST /types-registry/v1/entities/{gts_id}/deprecate - Deprecate GTS entity
+    router = OperationBuilder::post("/types-registry/v1/entities/{gts_id}/deprecate")
+        .operation_id("types_registry.deprecate")
+        .summary("Deprecate GTS entity")
+        .description("Mark a GTS entity as deprecated. Deprecated types accept no new instances.")
+        .tag(TAG)
+        .require_auth(&Resource::TypesRegistry, &Action::Write)
+        .require_license_features::<License>([])
+        .path_param(
+            "gts_id",
+            "The GTS identifier (e.g., gts.acme.core.events.user_created.v1~)",
+        )
+        .handler(handlers::deprecate_entity)
+        .json_response_with_schema::<GtsEntityDto>(openapi, StatusCode::OK, "The deprecated entity")
+        .problem_response(openapi, StatusCode::NOT_FOUND, "Entity not found")
+        .standard_errors(openapi)
+        .register(router, openapi);
+
     router.layer(Extension(service))
 }
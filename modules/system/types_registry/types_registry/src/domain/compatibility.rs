@@ -0,0 +1,379 @@
+//! Compatibility rules for GTS schema evolution.
+//!
+//! A new minor version of a schema (or an in-place update of a schema) must stay
+//! compatible in both directions:
+//!
+//! - it must accept every instance the previous version accepted, so it may not
+//!   make types, values, bounds or `additionalProperties` stricter nor add required
+//!   properties;
+//! - it must keep every guarantee readers of the previous version rely on, so it may
+//!   not remove required properties or make them optional.
+//!
+//! The checks are structural and conservative: they compare keywords of the two
+//! JSON Schemas and report every change that violates either rule.
+
+use gts::{GtsEntityCastResult, GtsID, GtsIdSegment};
+use serde_json::Value;
+
+const LOWER_BOUNDS: [&str; 5] = [
+    "minimum",
+    "exclusiveMinimum",
+    "minLength",
+    "minItems",
+    "minProperties",
+];
+
+const UPPER_BOUNDS: [&str; 5] = [
+    "maximum",
+    "exclusiveMaximum",
+    "maxLength",
+    "maxItems",
+    "maxProperties",
+];
+
+/// Returns the changes in `new` that break instances or readers of `old`.
+///
+/// Both schemas are expected to have their `$ref`s resolved. An empty result means
+/// the new schema is compatible.
+#[must_use]
+pub fn breaking_changes(old: &Value, new: &Value) -> Vec<String> {
+    let mut changes = Vec::new();
+    compare("$", old, new, &mut changes);
+    changes
+}
+
+/// Finds the latest registered schema that `gts_id` is a new minor version of.
+///
+/// Candidates match when every segment but the last is identical and the last
+/// segment names the same type and major version with a lower minor version
+/// (a missing minor version counts as `0`).
+#[must_use]
+pub fn previous_minor_version<'a>(
+    gts_id: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let parsed = GtsID::new(gts_id).ok()?;
+    let (last, prefix) = parsed.gts_id_segments.split_last()?;
+    if !last.is_type {
+        return None;
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let other = GtsID::new(candidate).ok()?;
+            let (other_last, other_prefix) = other.gts_id_segments.split_last()?;
+            let same_prefix = prefix.len() == other_prefix.len()
+                && prefix
+                    .iter()
+                    .zip(other_prefix)
+                    .all(|(a, b)| a.segment == b.segment);
+            let minor = other_last.ver_minor.unwrap_or(0);
+            (same_prefix
+                && other_last.is_type
+                && same_type(last, other_last)
+                && minor < last.ver_minor.unwrap_or(0))
+            .then_some((minor, candidate))
+        })
+        .max_by_key(|(minor, _)| *minor)
+        .map(|(_, candidate)| candidate)
+}
+
+fn same_type(a: &GtsIdSegment, b: &GtsIdSegment) -> bool {
+    a.vendor == b.vendor
+        && a.package == b.package
+        && a.namespace == b.namespace
+        && a.type_name == b.type_name
+        && a.ver_major == b.ver_major
+}
+
+fn compare(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    compare_types(path, old, new, changes);
+    compare_values(path, old, new, changes);
+    compare_bounds(path, old, new, changes);
+
+    if is_object_schema(old) || is_object_schema(new) {
+        compare_properties(path, old, new, changes);
+    }
+
+    if let (Some(old_items), Some(new_items)) = (old.get("items"), new.get("items"))
+        && old_items.is_object()
+        && new_items.is_object()
+    {
+        compare(&format!("{path}[]"), old_items, new_items, changes);
+    }
+}
+
+fn compare_types(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    let Some(new_types) = types(new) else {
+        return;
+    };
+    let Some(old_types) = types(old) else {
+        changes.push(format!("{path}: type restricted to {new_types:?}"));
+        return;
+    };
+
+    for old_type in old_types {
+        let accepted = new_types.contains(&old_type)
+            || (old_type == "integer" && new_types.contains(&"number"));
+        if !accepted {
+            changes.push(format!("{path}: type '{old_type}' is no longer accepted"));
+        }
+    }
+}
+
+fn compare_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    if let Some(new_enum) = new.get("enum").and_then(Value::as_array) {
+        match old.get("enum").and_then(Value::as_array) {
+            Some(old_enum) => {
+                for value in old_enum.iter().filter(|v| !new_enum.contains(v)) {
+                    changes.push(format!("{path}: enum value {value} removed"));
+                }
+            }
+            None => changes.push(format!("{path}: enum added")),
+        }
+    }
+
+    if let Some(new_const) = new.get("const")
+        && old.get("const") != Some(new_const)
+    {
+        changes.push(format!("{path}: const changed to {new_const}"));
+    }
+
+    for keyword in ["pattern", "format"] {
+        if let Some(value) = new.get(keyword)
+            && old.get(keyword) != Some(value)
+        {
+            changes.push(format!("{path}: {keyword} changed to {value}"));
+        }
+    }
+}
+
+fn compare_bounds(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    for keyword in LOWER_BOUNDS {
+        if let Some(bound) = new.get(keyword).and_then(Value::as_f64)
+            && old
+                .get(keyword)
+                .and_then(Value::as_f64)
+                .is_none_or(|previous| bound > previous)
+        {
+            changes.push(format!("{path}: {keyword} raised to {bound}"));
+        }
+    }
+
+    for keyword in UPPER_BOUNDS {
+        if let Some(bound) = new.get(keyword).and_then(Value::as_f64)
+            && old
+                .get(keyword)
+                .and_then(Value::as_f64)
+                .is_none_or(|previous| bound < previous)
+        {
+            changes.push(format!("{path}: {keyword} lowered to {bound}"));
+        }
+    }
+}
+
+fn compare_properties(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    let old = GtsEntityCastResult::flatten_schema(old);
+    let new = GtsEntityCastResult::flatten_schema(new);
+    let old_required = required(&old);
+    let new_required = required(&new);
+    let empty = serde_json::Map::new();
+    let old_properties = old["properties"].as_object().unwrap_or(&empty);
+    let new_properties = new["properties"].as_object().unwrap_or(&empty);
+
+    for name in &old_required {
+        if !new_properties.contains_key(*name) {
+            changes.push(format!("{path}: required property '{name}' removed"));
+        } else if !new_required.contains(name) {
+            changes.push(format!("{path}: property '{name}' is no longer required"));
+        }
+    }
+
+    for name in new_required.iter().filter(|n| !old_required.contains(n)) {
+        changes.push(format!("{path}: property '{name}' is now required"));
+    }
+
+    if new.get("additionalProperties") == Some(&Value::Bool(false))
+        && old.get("additionalProperties") != Some(&Value::Bool(false))
+    {
+        changes.push(format!(
+            "{path}: additional properties are no longer allowed"
+        ));
+    }
+
+    for (name, new_property) in new_properties {
+        if let Some(old_property) = old_properties.get(name) {
+            compare(
+                &format!("{path}.{name}"),
+                old_property,
+                new_property,
+                changes,
+            );
+        }
+    }
+}
+
+fn is_object_schema(schema: &Value) -> bool {
+    ["properties", "required", "allOf", "additionalProperties"]
+        .iter()
+        .any(|keyword| schema.get(keyword).is_some())
+}
+
+fn types(schema: &Value) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(t) => Some(vec![t.as_str()]),
+        Value::Array(ts) => Some(ts.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+fn required(schema: &Value) -> Vec<&str> {
+    schema["required"]
+        .as_array()
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn person_v1() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "integer", "minimum": 0 },
+                "role": { "type": "string", "enum": ["admin", "user"] }
+            },
+            "required": ["name"]
+        })
+    }
+
+    #[test]
+    fn test_adding_optional_property_is_compatible() {
+        let mut v1_1 = person_v1();
+        v1_1["properties"]["email"] = json!({ "type": "string" });
+
+        assert!(breaking_changes(&person_v1(), &v1_1).is_empty());
+    }
+
+    #[test]
+    fn test_widening_is_compatible() {
+        let mut v1_1 = person_v1();
+        v1_1["properties"]["age"] = json!({ "type": "number" });
+        v1_1["properties"]["role"]["enum"] = json!(["admin", "user", "guest"]);
+
+        assert!(breaking_changes(&person_v1(), &v1_1).is_empty());
+    }
+
+    #[test]
+    fn test_removed_required_property_is_breaking() {
+        let mut v1_1 = person_v1();
+        v1_1["properties"].as_object_mut().unwrap().remove("name");
+        v1_1["required"] = json!([]);
+
+        let changes = breaking_changes(&person_v1(), &v1_1);
+        assert_eq!(changes, vec!["$: required property 'name' removed"]);
+    }
+
+    #[test]
+    fn test_newly_required_property_is_breaking() {
+        let mut v1_1 = person_v1();
+        v1_1["required"] = json!(["name", "age"]);
+
+        let changes = breaking_changes(&person_v1(), &v1_1);
+        assert_eq!(changes, vec!["$: property 'age' is now required"]);
+    }
+
+    #[test]
+    fn test_narrowed_types_are_breaking() {
+        let mut v1_1 = person_v1();
+        v1_1["properties"]["name"] = json!({ "type": "string", "maxLength": 10 });
+        v1_1["properties"]["age"] = json!({ "type": "string" });
+        v1_1["properties"]["role"]["enum"] = json!(["admin"]);
+
+        let changes = breaking_changes(&person_v1(), &v1_1);
+        assert_eq!(changes.len(), 3, "{changes:?}");
+        assert!(changes.contains(&"$.name: maxLength lowered to 10".to_owned()));
+        assert!(changes.contains(&"$.age: type 'integer' is no longer accepted".to_owned()));
+        assert!(changes.contains(&"$.role: enum value \"user\" removed".to_owned()));
+    }
+
+    #[test]
+    fn test_nested_and_inherited_properties_are_compared() {
+        let old = json!({
+            "allOf": [
+                { "type": "object", "properties": { "id": { "type": "string" } }, "required": ["id"] },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tags": { "type": "array", "items": { "type": "object", "properties": { "key": { "type": "string" } } } }
+                    }
+                }
+            ]
+        });
+        let new = json!({
+            "allOf": [
+                { "type": "object", "properties": { "id": { "type": "string" } } },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tags": { "type": "array", "items": { "type": "object", "properties": { "key": { "type": "boolean" } } } }
+                    }
+                }
+            ]
+        });
+
+        let changes = breaking_changes(&old, &new);
+        assert_eq!(changes.len(), 2, "{changes:?}");
+        assert!(changes.contains(&"$: property 'id' is no longer required".to_owned()));
+        assert!(changes.contains(&"$.tags[].key: type 'string' is no longer accepted".to_owned()));
+    }
+
+    #[test]
+    fn test_previous_minor_version() {
+        let registered = [
+            "gts.acme.core.models.person.v1~",
+            "gts.acme.core.models.person.v1.1~",
+            "gts.acme.core.models.person.v2~",
+            "gts.acme.core.models.company.v1~",
+            "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+        ];
+
+        assert_eq!(
+            previous_minor_version("gts.acme.core.models.person.v1.2~", registered),
+            Some("gts.acme.core.models.person.v1.1~")
+        );
+        assert_eq!(
+            previous_minor_version("gts.acme.core.models.person.v1.1~", registered),
+            Some("gts.acme.core.models.person.v1~")
+        );
+        assert_eq!(
+            previous_minor_version("gts.acme.core.models.person.v1~", registered),
+            None
+        );
+        assert_eq!(
+            previous_minor_version("gts.acme.core.models.person.v2.1~", registered),
+            Some("gts.acme.core.models.person.v2~")
+        );
+    }
+
+    #[test]
+    fn test_previous_minor_version_of_derived_schema() {
+        let registered = [
+            "gts.acme.core.events.base.v1~acme.core.events.created.v1~",
+            "gts.acme.core.events.base.v2~acme.core.events.created.v1~",
+        ];
+
+        assert_eq!(
+            previous_minor_version(
+                "gts.acme.core.events.base.v1~acme.core.events.created.v1.1~",
+                registered
+            ),
+            Some("gts.acme.core.events.base.v1~acme.core.events.created.v1~")
+        );
+    }
+}
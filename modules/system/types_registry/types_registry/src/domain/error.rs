@@ -63,6 +63,14 @@ pub enum DomainError {
     #[error("Not in ready mode")]
     NotInReadyMode,
 
+    /// The listing query (`$filter`, `$orderby`, cursor or limit) is invalid.
+    #[error("Invalid query: {0}")]
+    InvalidQuery(#[from] modkit_odata::Error),
+
+    /// A change subscription fell behind and missed the given number of changes.
+    #[error("Watch lagged behind: {0} changes dropped")]
+    Lagged(u64),
+
     /// Multiple validation errors occurred during `switch_to_ready`.
     #[error("Ready commit failed with {} errors", .0.len())]
     ReadyCommitFailed(Vec<ValidationError>),
@@ -115,6 +123,8 @@ impl From<DomainError> for TypesRegistryError {
             DomainError::AlreadyExists(id) => TypesRegistryError::already_exists(id),
             DomainError::ValidationFailed(msg) => TypesRegistryError::validation_failed(msg),
             DomainError::NotInReadyMode => TypesRegistryError::not_in_ready_mode(),
+            DomainError::InvalidQuery(e) => TypesRegistryError::invalid_query(e.to_string()),
+            DomainError::Lagged(skipped) => TypesRegistryError::lagged(skipped),
             DomainError::ReadyCommitFailed(errors) => {
                 let error_strings: Vec<String> = errors
                     .iter()
@@ -176,6 +186,25 @@ mod tests {
         assert!(matches!(sdk_err, TypesRegistryError::NotInReadyMode));
     }
 
+    #[test]
+    fn test_domain_to_sdk_error_invalid_query() {
+        let domain_err = DomainError::from(modkit_odata::Error::InvalidOrderByField(
+            "content".to_owned(),
+        ));
+        let sdk_err: TypesRegistryError = domain_err.into();
+        assert!(sdk_err.is_invalid_query());
+        assert_eq!(
+            sdk_err.to_string(),
+            "Invalid query: unsupported $orderby field: content"
+        );
+    }
+
+    #[test]
+    fn test_domain_to_sdk_error_lagged() {
+        let sdk_err: TypesRegistryError = DomainError::Lagged(7).into();
+        assert!(matches!(sdk_err, TypesRegistryError::Lagged(7)));
+    }
+
     #[test]
     fn test_domain_to_sdk_error_ready_commit_failed() {
         let errors = vec![
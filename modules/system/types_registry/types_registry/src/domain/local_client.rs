@@ -3,8 +3,10 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use futures::StreamExt;
 use types_registry_sdk::{
-    GtsEntity, ListQuery, RegisterResult, TypesRegistryClient, TypesRegistryError,
+    EntityChangeStream, EntityUpdate, GtsEntity, ListQuery, ODataQuery, Page, RegisterResult,
+    TypesRegistryClient, TypesRegistryError,
 };
 
 use crate::domain::service::TypesRegistryService;
@@ -32,15 +34,46 @@ impl TypesRegistryClient for TypesRegistryLocalClient {
         &self,
         entities: Vec<serde_json::Value>,
     ) -> Result<Vec<RegisterResult>, TypesRegistryError> {
-        Ok(self.service.register(entities))
+        Ok(self.service.register(entities).await)
     }
 
-    async fn list(&self, query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError> {
-        self.service.list(&query).map_err(TypesRegistryError::from)
+    async fn list(
+        &self,
+        query: ListQuery,
+        odata: ODataQuery,
+    ) -> Result<Page<GtsEntity>, TypesRegistryError> {
+        self.service
+            .list_page(&query, &odata)
+            .await
+            .map_err(TypesRegistryError::from)
     }
 
     async fn get(&self, gts_id: &str) -> Result<GtsEntity, TypesRegistryError> {
-        self.service.get(gts_id).map_err(TypesRegistryError::from)
+        self.service
+            .get(gts_id)
+            .await
+            .map_err(TypesRegistryError::from)
+    }
+
+    async fn update(&self, entity: serde_json::Value) -> Result<EntityUpdate, TypesRegistryError> {
+        self.service
+            .update(entity)
+            .await
+            .map_err(TypesRegistryError::from)
+    }
+
+    async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, TypesRegistryError> {
+        self.service
+            .deprecate(gts_id)
+            .await
+            .map_err(TypesRegistryError::from)
+    }
+
+    fn watch(&self, query: ListQuery) -> EntityChangeStream {
+        self.service
+            .watch(query)
+            .map(|change| change.map_err(TypesRegistryError::from))
+            .boxed()
     }
 }
 
@@ -83,7 +116,7 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert!(results[0].is_ok());
 
-        client.service.switch_to_ready().unwrap();
+        client.service.switch_to_ready().await.unwrap();
 
         let retrieved = client
             .get("gts.acme.core.events.user_created.v1~")
@@ -108,24 +141,53 @@ mod tests {
         });
 
         client.register(vec![type1, type2]).await.unwrap();
-        client.service.switch_to_ready().unwrap();
+        client.service.switch_to_ready().await.unwrap();
 
-        let all = client.list(ListQuery::default()).await.unwrap();
+        let all = client.list_all(ListQuery::default()).await.unwrap();
         assert_eq!(all.len(), 2);
 
         let acme_only = client
-            .list(ListQuery::default().with_vendor("acme"))
+            .list_all(ListQuery::default().with_vendor("acme"))
             .await
             .unwrap();
         assert_eq!(acme_only.len(), 1);
         assert_eq!(acme_only[0].vendor(), Some("acme"));
+
+        let page = client
+            .list(ListQuery::default(), ODataQuery::default().with_limit(1))
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert!(page.page_info.next_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_all_follows_cursors() {
+        let client = create_client();
+
+        let types: Vec<_> = (0..120)
+            .map(|i| {
+                json!({
+                    "$id": format!("gts://gts.acme.core.events.event_{i:03}.v1~"),
+                    "$schema": JSON_SCHEMA_DRAFT_07,
+                    "type": "object"
+                })
+            })
+            .collect();
+        client.register(types).await.unwrap();
+        client.service.switch_to_ready().await.unwrap();
+
+        let all = client.list_all(ListQuery::default()).await.unwrap();
+        assert_eq!(all.len(), 120);
+        assert_eq!(all[0].gts_id, "gts.acme.core.events.event_000.v1~");
+        assert_eq!(all[119].gts_id, "gts.acme.core.events.event_119.v1~");
     }
 
     #[tokio::test]
     async fn test_get_not_found() {
         let client = create_client();
 
-        client.service.switch_to_ready().unwrap();
+        client.service.switch_to_ready().await.unwrap();
 
         let result = client.get("gts.unknown.pkg.ns.type.v1~").await;
         assert!(result.is_err());
@@ -2,7 +2,9 @@
 //!
 //! Contains business logic, error types, and repository traits.
 
+pub mod compatibility;
 pub mod error;
+pub mod paging;
 pub mod repo;
 pub mod service;
 // === LOCAL CLIENT ===
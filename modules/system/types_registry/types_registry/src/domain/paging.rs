@@ -0,0 +1,417 @@
+//! Cursor pagination of GTS entity listings.
+//!
+//! Registered entities live in the validation index rather than in a table, so
+//! listings are filtered, ordered and paged in memory. The semantics follow
+//! `modkit_db::odata::paginate_with_odata`: the same limits, effective order,
+//! tiebreaker handling and cursor format, so that clients (and `CursorPager`)
+//! can treat this endpoint like any other `OData` listing.
+
+use std::cmp::Ordering;
+
+use modkit_odata::filter::{
+    FilterField, FilterNode, FilterOp, ODataValue, convert_expr_to_filter_node,
+};
+use modkit_odata::{
+    CursorV1, Error as ODataError, ODataOrderBy, ODataQuery, Page, PageInfo, SortDir,
+};
+use types_registry_sdk::GtsEntity;
+use types_registry_sdk::odata::GtsEntityFilterField;
+
+/// Page size used when the query does not set one.
+pub const DEFAULT_LIMIT: u64 = 50;
+
+/// Largest page size a query may request.
+pub const MAX_LIMIT: u64 = 1000;
+
+/// Order applied after the requested one so that cursors are unambiguous.
+const TIEBREAKER: (&str, SortDir) = ("gts_id", SortDir::Asc);
+
+/// An entity with the values of the effective order fields.
+struct Keyed {
+    keys: Vec<String>,
+    entity: GtsEntity,
+}
+
+/// Returns the page of `entities` selected by `query`.
+///
+/// # Errors
+///
+/// Returns an `OData` error if the filter, order or cursor is invalid, or if the
+/// cursor was issued for a different filter.
+pub fn paginate(
+    entities: Vec<GtsEntity>,
+    query: &ODataQuery,
+) -> Result<Page<GtsEntity>, ODataError> {
+    let limit = clamp_limit(query.limit);
+
+    let effective_order = match &query.cursor {
+        Some(cursor) => {
+            ODataOrderBy::from_signed_tokens(&cursor.s).map_err(|_| ODataError::InvalidCursor)?
+        }
+        None => query
+            .order
+            .clone()
+            .ensure_tiebreaker(TIEBREAKER.0, TIEBREAKER.1),
+    };
+    let order = order_fields(&effective_order)?;
+
+    if let Some(cursor) = &query.cursor
+        && let (Some(h), Some(cf)) = (query.filter_hash.as_deref(), cursor.f.as_deref())
+        && h != cf
+    {
+        return Err(ODataError::FilterMismatch);
+    }
+
+    let filter = query
+        .filter
+        .as_deref()
+        .map(convert_expr_to_filter_node::<GtsEntityFilterField>)
+        .transpose()
+        .map_err(|e| ODataError::InvalidFilter(e.to_string()))?;
+
+    let mut rows: Vec<Keyed> = entities
+        .into_iter()
+        .filter(|entity| filter.as_ref().is_none_or(|f| matches(f, entity)))
+        .map(|entity| Keyed {
+            keys: order
+                .iter()
+                .map(|(field, _)| key(&entity, *field))
+                .collect(),
+            entity,
+        })
+        .collect();
+    rows.sort_by(|a, b| compare(&a.keys, &b.keys, &order));
+
+    let is_backward = query.cursor.as_ref().is_some_and(|c| c.d == "bwd");
+    if let Some(cursor) = &query.cursor {
+        if cursor.k.len() != order.len() {
+            return Err(ODataError::InvalidCursor);
+        }
+        let past = if is_backward {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        rows.retain(|row| compare(&row.keys, &cursor.k, &order) == past);
+    }
+
+    let limit_len = usize::try_from(limit).unwrap_or(usize::MAX);
+    let has_more = rows.len() > limit_len;
+    if is_backward {
+        // The page ends right before the cursor
+        rows.drain(..rows.len().saturating_sub(limit_len));
+    } else {
+        rows.truncate(limit_len);
+    }
+
+    let next_cursor = if is_backward || has_more {
+        build_cursor(rows.last(), &effective_order, query, "fwd")?
+    } else {
+        None
+    };
+    let prev_cursor = if (is_backward && has_more) || (!is_backward && query.cursor.is_some()) {
+        build_cursor(rows.first(), &effective_order, query, "bwd")?
+    } else {
+        None
+    };
+
+    Ok(Page {
+        items: rows.into_iter().map(|row| row.entity).collect(),
+        page_info: PageInfo {
+            next_cursor,
+            prev_cursor,
+            limit,
+        },
+    })
+}
+
+fn clamp_limit(requested: Option<u64>) -> u64 {
+    requested.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+fn order_fields(order: &ODataOrderBy) -> Result<Vec<(GtsEntityFilterField, SortDir)>, ODataError> {
+    order
+        .0
+        .iter()
+        .map(|key| {
+            GtsEntityFilterField::from_name(&key.field)
+                .map(|field| (field, key.dir))
+                .ok_or_else(|| ODataError::InvalidOrderByField(key.field.clone()))
+        })
+        .collect()
+}
+
+/// The value of `field` for `entity`, as compared and stored in cursors.
+///
+/// Booleans are rendered as `false`/`true`, which sort the same way as strings.
+fn key(entity: &GtsEntity, field: GtsEntityFilterField) -> String {
+    let segment = entity.primary_segment();
+    let segment_part =
+        |part: fn(&gts::GtsIdSegment) -> &String| segment.map(part).cloned().unwrap_or_default();
+    match field {
+        GtsEntityFilterField::GtsId => entity.gts_id.clone(),
+        GtsEntityFilterField::Vendor => segment_part(|s| &s.vendor),
+        GtsEntityFilterField::Package => segment_part(|s| &s.package),
+        GtsEntityFilterField::Namespace => segment_part(|s| &s.namespace),
+        GtsEntityFilterField::TypeName => segment_part(|s| &s.type_name),
+        GtsEntityFilterField::IsSchema => entity.is_schema.to_string(),
+        GtsEntityFilterField::Deprecated => entity.deprecated.to_string(),
+    }
+}
+
+fn compare(a: &[String], b: &[String], order: &[(GtsEntityFilterField, SortDir)]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .zip(order)
+        .map(|((a, b), (_, dir))| match dir {
+            SortDir::Asc => a.cmp(b),
+            SortDir::Desc => b.cmp(a),
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn matches(node: &FilterNode<GtsEntityFilterField>, entity: &GtsEntity) -> bool {
+    match node {
+        FilterNode::Binary { field, op, value } => {
+            let actual = key(entity, *field);
+            let expected = match value {
+                ODataValue::String(s) => s.clone(),
+                ODataValue::Bool(b) => b.to_string(),
+                // No field is nullable
+                ODataValue::Null => return *op == FilterOp::Ne,
+                _ => return false,
+            };
+            compare_value(&actual, *op, &expected)
+        }
+        FilterNode::Composite {
+            op: FilterOp::And,
+            children,
+        } => children.iter().all(|child| matches(child, entity)),
+        FilterNode::Composite {
+            op: FilterOp::Or,
+            children,
+        } => children.iter().any(|child| matches(child, entity)),
+        FilterNode::Composite { .. } => false,
+        FilterNode::Not(inner) => !matches(inner, entity),
+    }
+}
+
+fn compare_value(actual: &str, op: FilterOp, expected: &str) -> bool {
+    match op {
+        FilterOp::Eq => actual == expected,
+        FilterOp::Ne => actual != expected,
+        FilterOp::Gt => actual > expected,
+        FilterOp::Ge => actual >= expected,
+        FilterOp::Lt => actual < expected,
+        FilterOp::Le => actual <= expected,
+        FilterOp::Contains => actual.contains(expected),
+        FilterOp::StartsWith => actual.starts_with(expected),
+        FilterOp::EndsWith => actual.ends_with(expected),
+        FilterOp::And | FilterOp::Or => false,
+    }
+}
+
+fn build_cursor(
+    row: Option<&Keyed>,
+    effective_order: &ODataOrderBy,
+    query: &ODataQuery,
+    direction: &str,
+) -> Result<Option<String>, ODataError> {
+    row.map(|row| {
+        query.encode_cursor(&CursorV1 {
+            k: row.keys.clone(),
+            o: TIEBREAKER.1,
+            s: effective_order.to_signed_tokens(),
+            f: query.filter_hash.clone(),
+            d: direction.to_owned(),
+        })
+    })
+    .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gts::GtsID;
+    use modkit_odata::OrderKey;
+    use types_registry_sdk::odata::{DEPRECATED, IS_SCHEMA, TYPE_NAME, VENDOR};
+    use uuid::Uuid;
+
+    fn entity(gts_id: &str) -> GtsEntity {
+        GtsEntity::new(
+            Uuid::nil(),
+            gts_id,
+            GtsID::new(gts_id).unwrap().gts_id_segments,
+            gts_id.ends_with('~'),
+            serde_json::json!({}),
+            None,
+        )
+    }
+
+    fn entities() -> Vec<GtsEntity> {
+        let mut deprecated = entity("gts.globex.core.events.order_placed.v1~");
+        deprecated.deprecated = true;
+        vec![
+            entity("gts.acme.core.events.user_created.v1~"),
+            deprecated,
+            entity("gts.acme.core.models.person.v1~"),
+            entity("gts.acme.core.models.person.v1~acme.core.instances.alice.v1"),
+            entity("gts.acme.core.models.person.v1~acme.core.instances.bob.v1"),
+        ]
+    }
+
+    fn ids(page: &Page<GtsEntity>) -> Vec<&str> {
+        page.items.iter().map(|e| e.gts_id.as_str()).collect()
+    }
+
+    fn follow(page: &Page<GtsEntity>, next: bool, query: ODataQuery) -> ODataQuery {
+        let cursor = if next {
+            &page.page_info.next_cursor
+        } else {
+            &page.page_info.prev_cursor
+        };
+        query.with_cursor(CursorV1::decode(cursor.as_deref().unwrap()).unwrap())
+    }
+
+    #[test]
+    fn test_default_order_is_by_gts_id() {
+        let page = paginate(entities(), &ODataQuery::default()).unwrap();
+
+        assert_eq!(
+            ids(&page),
+            vec![
+                "gts.acme.core.events.user_created.v1~",
+                "gts.acme.core.models.person.v1~",
+                "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+                "gts.acme.core.models.person.v1~acme.core.instances.bob.v1",
+                "gts.globex.core.events.order_placed.v1~",
+            ]
+        );
+        assert_eq!(page.page_info.limit, DEFAULT_LIMIT);
+        assert!(page.page_info.next_cursor.is_none());
+        assert!(page.page_info.prev_cursor.is_none());
+    }
+
+    #[test]
+    fn test_filter() {
+        let query = ODataQuery::default().with_filter(VENDOR.eq("acme").and(IS_SCHEMA.eq(true)));
+        let page = paginate(entities(), &query).unwrap();
+        assert_eq!(
+            ids(&page),
+            vec![
+                "gts.acme.core.events.user_created.v1~",
+                "gts.acme.core.models.person.v1~"
+            ]
+        );
+
+        let query = ODataQuery::default()
+            .with_filter(TYPE_NAME.startswith("order").or(DEPRECATED.eq(true).not()));
+        assert_eq!(paginate(entities(), &query).unwrap().items.len(), 5);
+
+        let query = ODataQuery::default().with_filter(DEPRECATED.eq(true));
+        assert_eq!(
+            ids(&paginate(entities(), &query).unwrap()),
+            vec!["gts.globex.core.events.order_placed.v1~"]
+        );
+    }
+
+    #[test]
+    fn test_order_by_field_then_tiebreaker() {
+        let query = ODataQuery::default().with_order(ODataOrderBy(vec![OrderKey {
+            field: "type_name".to_owned(),
+            dir: SortDir::Asc,
+        }]));
+        let page = paginate(entities(), &query).unwrap();
+
+        assert_eq!(
+            ids(&page),
+            vec![
+                "gts.globex.core.events.order_placed.v1~",
+                "gts.acme.core.models.person.v1~",
+                "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+                "gts.acme.core.models.person.v1~acme.core.instances.bob.v1",
+                "gts.acme.core.events.user_created.v1~",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cursors_page_forward_and_back() {
+        let query = ODataQuery::default().with_limit(2);
+
+        let first = paginate(entities(), &query).unwrap();
+        assert_eq!(first.items.len(), 2);
+        assert!(first.page_info.prev_cursor.is_none());
+
+        let second = paginate(entities(), &follow(&first, true, query.clone())).unwrap();
+        assert_eq!(
+            ids(&second),
+            vec![
+                "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+                "gts.acme.core.models.person.v1~acme.core.instances.bob.v1",
+            ]
+        );
+
+        let third = paginate(entities(), &follow(&second, true, query.clone())).unwrap();
+        assert_eq!(ids(&third), vec!["gts.globex.core.events.order_placed.v1~"]);
+        assert!(third.page_info.next_cursor.is_none());
+
+        let back = paginate(entities(), &follow(&third, false, query.clone())).unwrap();
+        assert_eq!(ids(&back), ids(&second));
+        let back = paginate(entities(), &follow(&back, false, query)).unwrap();
+        assert_eq!(ids(&back), ids(&first));
+        assert!(back.page_info.prev_cursor.is_none());
+        assert!(back.page_info.next_cursor.is_some());
+    }
+
+    #[test]
+    fn test_limit_is_clamped() {
+        let page = paginate(entities(), &ODataQuery::default().with_limit(0)).unwrap();
+        assert_eq!(page.items.len(), 1);
+
+        let page = paginate(entities(), &ODataQuery::default().with_limit(u64::MAX)).unwrap();
+        assert_eq!(page.page_info.limit, MAX_LIMIT);
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        let query = ODataQuery::default().with_order(ODataOrderBy(vec![OrderKey {
+            field: "content".to_owned(),
+            dir: SortDir::Asc,
+        }]));
+        assert!(matches!(
+            paginate(entities(), &query),
+            Err(ODataError::InvalidOrderByField(_))
+        ));
+
+        let query = ODataQuery::default().with_filter(modkit_odata::ast::Expr::Compare(
+            Box::new(modkit_odata::ast::Expr::Identifier("content".to_owned())),
+            modkit_odata::ast::CompareOperator::Eq,
+            Box::new(modkit_odata::ast::Expr::Value(ODataValue::String(
+                "x".to_owned(),
+            ))),
+        ));
+        assert!(matches!(
+            paginate(entities(), &query),
+            Err(ODataError::InvalidFilter(_))
+        ));
+
+        let first = paginate(
+            entities(),
+            &ODataQuery::default()
+                .with_limit(2)
+                .with_filter_hash("a".to_owned()),
+        )
+        .unwrap();
+        let query = follow(
+            &first,
+            true,
+            ODataQuery::default().with_filter_hash("b".to_owned()),
+        );
+        assert!(matches!(
+            paginate(entities(), &query),
+            Err(ODataError::FilterMismatch)
+        ));
+    }
+}
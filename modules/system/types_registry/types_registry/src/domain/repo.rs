@@ -1,6 +1,8 @@
 //! Repository trait for GTS entity storage.
 
-use types_registry_sdk::{GtsEntity, ListQuery};
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+use types_registry_sdk::{EntityChange, EntityUpdate, GtsEntity, ListQuery};
 
 use super::error::DomainError;
 
@@ -8,6 +10,7 @@ use super::error::DomainError;
 ///
 /// This trait defines the storage interface used by the domain service.
 /// Implementations handle the actual storage mechanism (in-memory, database, etc.).
+#[async_trait]
 pub trait GtsRepository: Send + Sync {
     /// Registers a GTS entity in the repository.
     ///
@@ -16,16 +19,19 @@ pub trait GtsRepository: Send + Sync {
     /// * `entity` - The entity to register
     /// * `validate` - Whether to perform full validation (ready mode)
     ///
+    /// When a validated schema is a new minor version of a registered one, the
+    /// instances of the previous version are re-validated and reported in the result.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The entity already exists
     /// - Validation fails (when `validate` is true)
-    fn register(
+    async fn register(
         &self,
         entity: &serde_json::Value,
         validate: bool,
-    ) -> Result<GtsEntity, DomainError>;
+    ) -> Result<EntityUpdate, DomainError>;
 
     /// Retrieves a GTS entity by its identifier.
     ///
@@ -36,21 +42,48 @@ pub trait GtsRepository: Send + Sync {
     /// # Errors
     ///
     /// Returns `NotFound` if the entity doesn't exist.
-    fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError>;
+    async fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError>;
+
+    /// Replaces the content of an existing GTS entity (ready mode only).
+    ///
+    /// Schema updates must be compatible with the current content; all instances
+    /// of an updated schema are re-validated and reported in the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The repository is not in ready mode
+    /// - The entity doesn't exist
+    /// - The new content is invalid or incompatible
+    async fn update(&self, entity: &serde_json::Value) -> Result<EntityUpdate, DomainError>;
+
+    /// Marks a GTS entity as deprecated (ready mode only).
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotInReadyMode` before ready mode and `NotFound` if the entity
+    /// doesn't exist.
+    async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, DomainError>;
 
     /// Lists GTS entities matching the given query.
     ///
     /// # Arguments
     ///
     /// * `query` - Query parameters for filtering
-    fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError>;
+    async fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError>;
 
     /// Checks if an entity with the given GTS ID exists.
-    fn exists(&self, gts_id: &str) -> bool;
+    async fn exists(&self, gts_id: &str) -> bool;
 
     /// Returns whether the repository is in ready mode.
     fn is_ready(&self) -> bool;
 
+    /// Subscribes to changes of entities in ready-mode storage.
+    ///
+    /// The receiver gets every change committed after the call, in order.
+    /// Entities committed by `switch_to_ready` are reported as registered.
+    fn subscribe(&self) -> broadcast::Receiver<EntityChange>;
+
     /// Switches the repository from configuration mode to ready mode.
     ///
     /// This validates all entities in temporary storage and moves them
@@ -59,5 +92,5 @@ pub trait GtsRepository: Send + Sync {
     /// # Errors
     ///
     /// Returns a list of validation errors if any entity fails validation.
-    fn switch_to_ready(&self) -> Result<(), Vec<String>>;
+    async fn switch_to_ready(&self) -> Result<(), Vec<String>>;
 }
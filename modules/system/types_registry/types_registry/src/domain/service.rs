@@ -2,7 +2,11 @@
 
 use std::sync::Arc;
 
-use types_registry_sdk::{GtsEntity, ListQuery, RegisterResult};
+use futures::{Stream, StreamExt, future};
+use modkit_odata::{ODataQuery, Page};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use types_registry_sdk::{EntityChange, EntityUpdate, GtsEntity, ListQuery, RegisterResult};
 
 use super::error::DomainError;
 use super::repo::GtsRepository;
@@ -32,9 +36,9 @@ impl TypesRegistryService {
     ///
     /// Returns a `RegisterResult` for each input entity, preserving order.
     #[must_use]
-    pub fn register(&self, entities: Vec<serde_json::Value>) -> Vec<RegisterResult> {
+    pub async fn register(&self, entities: Vec<serde_json::Value>) -> Vec<RegisterResult> {
         let validate = self.repo.is_ready();
-        self.register_internal(entities, validate)
+        self.register_internal(entities, validate).await
     }
 
     /// Registers GTS entities in batch with forced validation.
@@ -44,12 +48,15 @@ impl TypesRegistryService {
     ///
     /// Returns a `RegisterResult` for each input entity, preserving order.
     #[must_use]
-    pub fn register_validated(&self, entities: Vec<serde_json::Value>) -> Vec<RegisterResult> {
-        self.register_internal(entities, true)
+    pub async fn register_validated(
+        &self,
+        entities: Vec<serde_json::Value>,
+    ) -> Vec<RegisterResult> {
+        self.register_internal(entities, true).await
     }
 
     /// Internal registration method with explicit validation control.
-    fn register_internal(
+    async fn register_internal(
         &self,
         entities: Vec<serde_json::Value>,
         validate: bool,
@@ -58,8 +65,11 @@ impl TypesRegistryService {
 
         for entity in entities {
             let gts_id = self.extract_gts_id(&entity);
-            let result = match self.repo.register(&entity, validate) {
-                Ok(registered) => RegisterResult::Ok(registered),
+            let result = match self.repo.register(&entity, validate).await {
+                Ok(registered) => RegisterResult::Ok {
+                    entity: registered.entity,
+                    revalidated: registered.revalidated,
+                },
                 Err(e) => RegisterResult::Err {
                     gts_id,
                     error: e.into(),
@@ -72,13 +82,57 @@ impl TypesRegistryService {
     }
 
     /// Retrieves a single GTS entity by its identifier.
-    pub fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
-        self.repo.get(gts_id)
+    pub async fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        self.repo.get(gts_id).await
+    }
+
+    /// Replaces the content of a registered GTS entity.
+    ///
+    /// For schemas, the result reports every instance re-validated against the
+    /// new content.
+    pub async fn update(&self, entity: serde_json::Value) -> Result<EntityUpdate, DomainError> {
+        self.repo.update(&entity).await
+    }
+
+    /// Marks a GTS entity as deprecated.
+    pub async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        self.repo.deprecate(gts_id).await
     }
 
     /// Lists GTS entities matching the given query.
-    pub fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
-        self.repo.list(query)
+    pub async fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
+        self.repo.list(query).await
+    }
+
+    /// Lists one page of the GTS entities matching both queries.
+    ///
+    /// See [`paging::paginate`](super::paging::paginate) for the `OData` semantics.
+    pub async fn list_page(
+        &self,
+        query: &ListQuery,
+        odata: &ODataQuery,
+    ) -> Result<Page<GtsEntity>, DomainError> {
+        let entities = self.repo.list(query).await?;
+        Ok(super::paging::paginate(entities, odata)?)
+    }
+
+    /// Streams changes to entities matching `query`, starting now.
+    ///
+    /// A subscriber that falls behind gets a `Lagged` error with the number of
+    /// changes it missed and then continues with the most recent ones.
+    pub fn watch(
+        &self,
+        query: ListQuery,
+    ) -> impl Stream<Item = Result<EntityChange, DomainError>> + Send + use<> {
+        BroadcastStream::new(self.repo.subscribe()).filter_map(move |change| {
+            future::ready(match change {
+                Ok(change) if query.matches(&change.entity) => Some(Ok(change)),
+                Ok(_) => None,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    Some(Err(DomainError::Lagged(skipped)))
+                }
+            })
+        })
     }
 
     /// Switches the registry from configuration mode to ready mode.
@@ -90,9 +144,9 @@ impl TypesRegistryService {
     ///
     /// Returns `ReadyCommitFailed` with typed `ValidationError` structs
     /// containing the GTS ID and error message for each failing entity.
-    pub fn switch_to_ready(&self) -> Result<(), DomainError> {
+    pub async fn switch_to_ready(&self) -> Result<(), DomainError> {
         use crate::domain::error::ValidationError;
-        self.repo.switch_to_ready().map_err(|errors| {
+        self.repo.switch_to_ready().await.map_err(|errors| {
             let typed_errors: Vec<ValidationError> = errors
                 .into_iter()
                 .map(|s| ValidationError::from_string(&s))
@@ -110,7 +164,8 @@ impl TypesRegistryService {
     /// Extracts the GTS ID from an entity JSON value.
     ///
     /// Strips the `gts://` URI prefix from `$id` fields for JSON Schema compatibility (gts-rust v0.6.0+).
-    fn extract_gts_id(&self, entity: &serde_json::Value) -> Option<String> {
+    #[must_use]
+    pub fn extract_gts_id(&self, entity: &serde_json::Value) -> Option<String> {
         if let Some(obj) = entity.as_object() {
             for field in &self.config.entity_id_fields {
                 if let Some(id) = obj.get(field.as_str()).and_then(|v| v.as_str()) {
@@ -133,11 +188,14 @@ mod tests {
     use super::*;
     use serde_json::json;
     use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::sync::broadcast;
+    use types_registry_sdk::EntityChangeKind;
     use uuid::Uuid;
 
     struct MockRepo {
         is_ready: AtomicBool,
         fail_switch: bool,
+        changes: broadcast::Sender<EntityChange>,
     }
 
     impl MockRepo {
@@ -145,23 +203,25 @@ mod tests {
             Self {
                 is_ready: AtomicBool::new(false),
                 fail_switch: false,
+                changes: broadcast::channel(4).0,
             }
         }
 
         fn with_fail_switch() -> Self {
             Self {
-                is_ready: AtomicBool::new(false),
                 fail_switch: true,
+                ..Self::new()
             }
         }
     }
 
+    #[async_trait::async_trait]
     impl GtsRepository for MockRepo {
-        fn register(
+        async fn register(
             &self,
             entity: &serde_json::Value,
             _validate: bool,
-        ) -> Result<GtsEntity, DomainError> {
+        ) -> Result<EntityUpdate, DomainError> {
             let gts_id = entity
                 .get("$id")
                 .and_then(|v| v.as_str())
@@ -171,17 +231,20 @@ mod tests {
                 return Err(DomainError::validation_failed("Test failure"));
             }
 
-            Ok(GtsEntity::new(
-                Uuid::nil(),
-                gts_id.to_owned(),
-                vec![],
-                true, // is_schema
-                entity.clone(),
-                None,
-            ))
+            Ok(EntityUpdate {
+                entity: GtsEntity::new(
+                    Uuid::nil(),
+                    gts_id.to_owned(),
+                    vec![],
+                    true, // is_schema
+                    entity.clone(),
+                    None,
+                ),
+                revalidated: vec![],
+            })
         }
 
-        fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        async fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
             if gts_id.contains("notfound") {
                 return Err(DomainError::not_found(gts_id));
             }
@@ -195,7 +258,17 @@ mod tests {
             ))
         }
 
-        fn list(&self, _query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
+        async fn update(&self, entity: &serde_json::Value) -> Result<EntityUpdate, DomainError> {
+            self.register(entity, true).await
+        }
+
+        async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+            let mut entity = self.get(gts_id).await?;
+            entity.deprecated = true;
+            Ok(entity)
+        }
+
+        async fn list(&self, _query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
             Ok(vec![GtsEntity::new(
                 Uuid::nil(),
                 "gts.test.pkg.ns.type.v1~".to_owned(),
@@ -206,7 +279,7 @@ mod tests {
             )])
         }
 
-        fn exists(&self, _gts_id: &str) -> bool {
+        async fn exists(&self, _gts_id: &str) -> bool {
             true
         }
 
@@ -214,7 +287,11 @@ mod tests {
             self.is_ready.load(Ordering::SeqCst)
         }
 
-        fn switch_to_ready(&self) -> Result<(), Vec<String>> {
+        fn subscribe(&self) -> broadcast::Receiver<EntityChange> {
+            self.changes.subscribe()
+        }
+
+        async fn switch_to_ready(&self) -> Result<(), Vec<String>> {
             if self.fail_switch {
                 // Return errors in "gts_id: message" format for ValidationError::from_string
                 return Err(vec![
@@ -259,8 +336,8 @@ mod tests {
         assert_eq!(service.extract_gts_id(&entity), None);
     }
 
-    #[test]
-    fn test_register_success() {
+    #[tokio::test]
+    async fn test_register_success() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
@@ -271,14 +348,14 @@ mod tests {
             json!({"$id": "gts://gts.acme.core.events.test2.v1~"}),
         ];
 
-        let results = service.register(entities);
+        let results = service.register(entities).await;
         assert_eq!(results.len(), 2);
         assert!(results[0].is_ok());
         assert!(results[1].is_ok());
     }
 
-    #[test]
-    fn test_register_with_failures() {
+    #[tokio::test]
+    async fn test_register_with_failures() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
@@ -290,64 +367,64 @@ mod tests {
             json!({"other": "no id"}),
         ];
 
-        let results = service.register(entities);
+        let results = service.register(entities).await;
         assert_eq!(results.len(), 3);
         assert!(results[0].is_ok());
         assert!(results[1].is_err());
         assert!(results[2].is_err());
     }
 
-    #[test]
-    fn test_get_success() {
+    #[tokio::test]
+    async fn test_get_success() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
         );
-        let result = service.get("gts.acme.core.events.test.v1~");
+        let result = service.get("gts.acme.core.events.test.v1~").await;
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn test_get_not_found() {
+    #[tokio::test]
+    async fn test_get_not_found() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
         );
-        let result = service.get("gts.notfound.pkg.ns.type.v1~");
+        let result = service.get("gts.notfound.pkg.ns.type.v1~").await;
         assert!(result.is_err());
     }
 
-    #[test]
-    fn test_list() {
+    #[tokio::test]
+    async fn test_list() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
         );
-        let result = service.list(&ListQuery::default());
+        let result = service.list(&ListQuery::default()).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 1);
     }
 
-    #[test]
-    fn test_switch_to_ready_success() {
+    #[tokio::test]
+    async fn test_switch_to_ready_success() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::new()),
             crate::config::TypesRegistryConfig::default(),
         );
         assert!(!service.is_ready());
 
-        let result = service.switch_to_ready();
+        let result = service.switch_to_ready().await;
         assert!(result.is_ok());
         assert!(service.is_ready());
     }
 
-    #[test]
-    fn test_switch_to_ready_failure() {
+    #[tokio::test]
+    async fn test_switch_to_ready_failure() {
         let service = TypesRegistryService::new(
             Arc::new(MockRepo::with_fail_switch()),
             crate::config::TypesRegistryConfig::default(),
         );
-        let result = service.switch_to_ready();
+        let result = service.switch_to_ready().await;
         assert!(result.is_err());
         match result.unwrap_err() {
             DomainError::ReadyCommitFailed(errors) => {
@@ -369,4 +446,56 @@ mod tests {
         );
         assert!(!service.is_ready());
     }
+
+    fn change(gts_id: &str) -> EntityChange {
+        EntityChange {
+            kind: EntityChangeKind::Registered,
+            entity: GtsEntity::new(
+                Uuid::nil(),
+                gts_id.to_owned(),
+                vec![],
+                true, // is_schema
+                json!({}),
+                None,
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_filters_changes() {
+        let repo = Arc::new(MockRepo::new());
+        let service =
+            TypesRegistryService::new(repo.clone(), crate::config::TypesRegistryConfig::default());
+        let mut changes = Box::pin(service.watch(ListQuery::default().with_pattern("gts.acme.*")));
+
+        repo.changes
+            .send(change("gts.globex.core.events.a.v1~"))
+            .unwrap();
+        repo.changes
+            .send(change("gts.acme.core.events.b.v1~"))
+            .unwrap();
+
+        let received = changes.next().await.unwrap().unwrap();
+        assert_eq!(received.entity.gts_id, "gts.acme.core.events.b.v1~");
+    }
+
+    #[tokio::test]
+    async fn test_watch_reports_lag() {
+        let repo = Arc::new(MockRepo::new());
+        let service =
+            TypesRegistryService::new(repo.clone(), crate::config::TypesRegistryConfig::default());
+        let mut changes = Box::pin(service.watch(ListQuery::default()));
+
+        // Two more than the mock channel holds
+        for i in 0..6 {
+            repo.changes
+                .send(change(&format!("gts.acme.core.events.e{i}.v1~")))
+                .unwrap();
+        }
+
+        let err = changes.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, DomainError::Lagged(2)), "{err:?}");
+        let received = changes.next().await.unwrap().unwrap();
+        assert_eq!(received.entity.gts_id, "gts.acme.core.events.e2.v1~");
+    }
 }
@@ -0,0 +1,509 @@
+//! Database-backed repository implementation using `modkit-db`.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use gts::GtsConfig;
+use modkit_db::secure::SecureConn;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder};
+use tokio::sync::{Mutex, broadcast};
+use tracing::debug;
+use types_registry_sdk::{EntityChange, EntityUpdate, GtsEntity, ListQuery};
+
+use super::entity::{self, Entity as GtsEntityRow};
+use super::in_memory_repo::{InMemoryGtsRepository, PendingChange};
+use crate::domain::error::DomainError;
+use crate::domain::repo::GtsRepository;
+
+/// Database-backed repository for GTS entities.
+///
+/// Keeps the two-phase semantics of [`InMemoryGtsRepository`], which it uses as
+/// the validation index:
+/// - **Configuration phase**: Entities are held in memory only
+/// - **Ready phase**: `switch_to_ready` merges previously persisted entities,
+///   validates everything and writes the result to the database; entities
+///   registered afterwards are written as they are accepted
+///
+/// Entities committed by other replicas sharing the database are picked up
+/// lazily, before registrations and on lookup misses. Every write of a changed
+/// entity (update, deprecation, start-up merge) re-inserts the row so that it
+/// gets a new `seq` and reaches other replicas the same way. Subscribers are
+/// notified of such changes when they are picked up, not when the other replica
+/// commits them.
+///
+/// Local writes are applied to the index, written to the database and only then
+/// published; a change the database rejects is reverted in the index.
+pub struct DbGtsRepository {
+    db: SecureConn,
+    index: InMemoryGtsRepository,
+    synced: Mutex<SyncState>,
+    /// Serializes local writes so that a reverted change can't undo a later one.
+    writes: Mutex<()>,
+}
+
+/// How long a `seq` missing below the highest loaded one is re-queried.
+///
+/// `seq` is assigned on insert but becomes visible on commit, so concurrent
+/// transactions can commit out of order. Values that stay missing for longer
+/// belong to rolled-back or replaced rows.
+const GAP_GRACE: Duration = Duration::from_mins(1);
+
+/// Upper bound on the missing `seq` values tracked below a newly loaded row.
+const MAX_GAPS: i64 = 1024;
+
+/// Position of a replica in the `seq` order of `gts_entities`.
+#[derive(Debug, Default)]
+struct SyncState {
+    /// Highest `seq` loaded into the index from the database.
+    high: i64,
+    /// `seq` values below `high` that were not loaded yet, with when they went missing.
+    gaps: BTreeMap<i64, Instant>,
+}
+
+impl SyncState {
+    /// Records that the row with `seq` was loaded.
+    fn observe(&mut self, seq: i64, now: Instant) {
+        if seq <= self.high {
+            self.gaps.remove(&seq);
+            return;
+        }
+        for missing in (self.high + 1).max(seq - MAX_GAPS)..seq {
+            self.gaps.insert(missing, now);
+        }
+        self.high = seq;
+    }
+
+    /// Stops re-querying `seq` values missing for longer than [`GAP_GRACE`].
+    fn expire(&mut self, now: Instant) {
+        self.gaps
+            .retain(|_, missing_since| now.duration_since(*missing_since) < GAP_GRACE);
+    }
+
+    /// Rows not loaded yet: above `high` or in a gap.
+    fn pending(&self) -> Condition {
+        Condition::any()
+            .add(entity::Column::Seq.gt(self.high))
+            .add(entity::Column::Seq.is_in(self.gaps.keys().copied()))
+    }
+}
+
+impl DbGtsRepository {
+    /// Creates a new database-backed repository with the given GTS configuration.
+    #[must_use]
+    pub fn new(db: SecureConn, config: GtsConfig) -> Self {
+        Self {
+            db,
+            index: InMemoryGtsRepository::new(config),
+            synced: Mutex::new(SyncState::default()),
+            writes: Mutex::new(()),
+        }
+    }
+
+    /// Publishes `change` once `written` succeeded, reverts it otherwise.
+    fn settle<T>(
+        &self,
+        change: PendingChange,
+        written: Result<(), DomainError>,
+        value: T,
+    ) -> Result<T, DomainError> {
+        match written {
+            Ok(()) => {
+                self.index.publish(change);
+                Ok(value)
+            }
+            Err(e) => {
+                self.index.revert(change);
+                Err(e)
+            }
+        }
+    }
+
+    /// Fetches the persisted entities matching `pending`, in `seq` order.
+    // `gts_entities` is global (`#[secure(unrestricted)]`): there is no access scope to apply
+    #[allow(clippy::disallowed_methods)]
+    async fn fetch(&self, pending: Condition) -> Result<Vec<entity::Model>, DomainError> {
+        GtsEntityRow::find()
+            .filter(pending)
+            .order_by_asc(entity::Column::Seq)
+            .all(self.db.conn())
+            .await
+            .map_err(storage_error)
+    }
+
+    /// Loads entities committed by other replicas since the last sync into the index.
+    async fn sync(&self) -> Result<(), DomainError> {
+        let mut synced = self.synced.lock().await;
+        let now = Instant::now();
+        synced.expire(now);
+        for row in self.fetch(synced.pending()).await? {
+            if self.index.restore(&row_content(&row)?, row.deprecated) {
+                debug!(gts_id = %row.gts_id, "Loaded GTS entity committed by another replica");
+            }
+            synced.observe(row.seq, now);
+        }
+        Ok(())
+    }
+
+    /// Inserts a newly accepted entity, treating an identical stored copy as success.
+    // `gts_entities` is global (`#[secure(unrestricted)]`): there is no access scope to apply
+    #[allow(clippy::disallowed_methods)]
+    async fn insert(&self, entity: &GtsEntity) -> Result<(), DomainError> {
+        let inserted = GtsEntityRow::insert(active_model(entity)?)
+            .on_conflict(
+                OnConflict::column(entity::Column::GtsId)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        if inserted > 0 {
+            return Ok(());
+        }
+
+        // Another replica committed the same GTS ID first
+        let existing = GtsEntityRow::find()
+            .filter(entity::Column::GtsId.eq(&entity.gts_id))
+            .one(self.db.conn())
+            .await
+            .map_err(storage_error)?;
+        match existing {
+            Some(row) if row_content(&row)? == entity.content => Ok(()),
+            _ => Err(DomainError::already_exists(&entity.gts_id)),
+        }
+    }
+
+    /// Re-inserts a changed entity so that it is assigned a new `seq`.
+    // `gts_entities` is global (`#[secure(unrestricted)]`): there is no access scope to apply
+    #[allow(clippy::disallowed_methods)]
+    async fn replace(&self, entity: &GtsEntity) -> Result<(), DomainError> {
+        let gts_id = entity.gts_id.clone();
+        let model = active_model(entity)?;
+
+        self.db
+            .transaction(move |txn| {
+                Box::pin(async move {
+                    GtsEntityRow::delete_many()
+                        .filter(entity::Column::GtsId.eq(gts_id))
+                        .exec(txn)
+                        .await?;
+                    GtsEntityRow::insert(model)
+                        .exec_without_returning(txn)
+                        .await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(DomainError::Internal)
+    }
+
+    /// Writes all committed entities in one transaction.
+    ///
+    /// Stored copies that differ are re-inserted so that they get a new `seq`.
+    // `gts_entities` is global (`#[secure(unrestricted)]`): there is no access scope to apply
+    #[allow(clippy::disallowed_methods)]
+    async fn persist_all(&self, entities: &[GtsEntity]) -> Result<(), DomainError> {
+        let models = entities
+            .iter()
+            .map(active_model)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.db
+            .transaction(move |txn| {
+                Box::pin(async move {
+                    for model in models {
+                        let gts_id = model.gts_id.as_ref().clone();
+                        let stored = GtsEntityRow::find()
+                            .filter(entity::Column::GtsId.eq(&gts_id))
+                            .one(txn)
+                            .await?;
+                        if stored.is_some_and(|row| is_stored_as(&row, &model)) {
+                            continue;
+                        }
+                        GtsEntityRow::delete_many()
+                            .filter(entity::Column::GtsId.eq(gts_id))
+                            .exec(txn)
+                            .await?;
+                        GtsEntityRow::insert(model)
+                            .exec_without_returning(txn)
+                            .await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(DomainError::Internal)
+    }
+
+    /// Merges persisted entities into the configuration-phase set.
+    ///
+    /// Entities registered during this start-up take precedence over stored copies
+    /// with the same GTS ID. Returns the sync position reached and the GTS IDs of
+    /// deprecated entities.
+    async fn merge_persisted(&self) -> Result<(SyncState, Vec<String>), Vec<String>> {
+        let mut synced = SyncState::default();
+        let rows = self
+            .fetch(synced.pending())
+            .await
+            .map_err(|e| vec![format!("gts_entities: {e}")])?;
+
+        let mut errors = Vec::new();
+        let mut deprecated = Vec::new();
+        let now = Instant::now();
+        for row in rows {
+            synced.observe(row.seq, now);
+            if row.deprecated {
+                deprecated.push(row.gts_id.clone());
+            }
+            let content = match row_content(&row) {
+                Ok(content) => content,
+                Err(e) => {
+                    errors.push(format!("{}: {e}", row.gts_id));
+                    continue;
+                }
+            };
+            match self.index.register(&content, false).await {
+                Ok(_) => {}
+                Err(DomainError::AlreadyExists(gts_id)) => {
+                    debug!(gts_id = %gts_id, "Registered GTS entity replaces the stored copy");
+                }
+                Err(e) => errors.push(format!("{}: {e}", row.gts_id)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok((synced, deprecated))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[async_trait]
+impl GtsRepository for DbGtsRepository {
+    async fn register(
+        &self,
+        entity: &serde_json::Value,
+        validate: bool,
+    ) -> Result<EntityUpdate, DomainError> {
+        if !self.index.is_ready() {
+            return self.index.register(entity, validate).await;
+        }
+
+        let _write = self.writes.lock().await;
+        // Referenced schemas may have been registered on another replica
+        self.sync().await?;
+        let (registered, change) = self.index.register_pending(entity, validate)?;
+        let written = self.insert(&registered.entity).await;
+        self.settle(change, written, registered)
+    }
+
+    async fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        match self.index.get(gts_id).await {
+            Err(DomainError::NotFound(_)) if self.index.is_ready() => {
+                self.sync().await?;
+                self.index.get(gts_id).await
+            }
+            result => result,
+        }
+    }
+
+    async fn update(&self, entity: &serde_json::Value) -> Result<EntityUpdate, DomainError> {
+        if !self.index.is_ready() {
+            return self.index.update(entity).await;
+        }
+
+        let _write = self.writes.lock().await;
+        self.sync().await?;
+        let (update, change) = self.index.update_pending(entity)?;
+        let written = self.replace(&update.entity).await;
+        self.settle(change, written, update)
+    }
+
+    async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        if !self.index.is_ready() {
+            return self.index.deprecate(gts_id).await;
+        }
+
+        let _write = self.writes.lock().await;
+        self.sync().await?;
+        let (deprecated, change) = self.index.deprecate_pending(gts_id)?;
+        let written = self.replace(&deprecated).await;
+        self.settle(change, written, deprecated)
+    }
+
+    async fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
+        if self.index.is_ready() {
+            self.sync().await?;
+        }
+        self.index.list(query).await
+    }
+
+    async fn exists(&self, gts_id: &str) -> bool {
+        if self.index.exists(gts_id).await {
+            return true;
+        }
+        self.index.is_ready() && self.sync().await.is_ok() && self.index.exists(gts_id).await
+    }
+
+    fn is_ready(&self) -> bool {
+        self.index.is_ready()
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<EntityChange> {
+        self.index.subscribe()
+    }
+
+    async fn switch_to_ready(&self) -> Result<(), Vec<String>> {
+        let (synced, deprecated) = self.merge_persisted().await?;
+        self.index.switch_to_ready().await?;
+        for gts_id in deprecated {
+            self.index
+                .deprecate(&gts_id)
+                .await
+                .map_err(|e| vec![format!("{gts_id}: {e}")])?;
+        }
+
+        let committed = self
+            .index
+            .list(&ListQuery::default())
+            .await
+            .map_err(|e| vec![format!("gts_entities: {e}")])?;
+        self.persist_all(&committed)
+            .await
+            .map_err(|e| vec![format!("gts_entities: {e}")])?;
+
+        *self.synced.lock().await = synced;
+        Ok(())
+    }
+}
+
+fn storage_error(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Internal(e.into())
+}
+
+fn active_model(entity: &GtsEntity) -> Result<entity::ActiveModel, DomainError> {
+    let content =
+        serde_json::to_string(&entity.content).map_err(|e| DomainError::Internal(e.into()))?;
+    Ok(entity::ActiveModel {
+        seq: ActiveValue::NotSet,
+        gts_id: ActiveValue::Set(entity.gts_id.clone()),
+        is_schema: ActiveValue::Set(entity.is_schema),
+        content: ActiveValue::Set(content),
+        deprecated: ActiveValue::Set(entity.deprecated),
+    })
+}
+
+/// Returns `true` if `row` already holds what `model` would write.
+fn is_stored_as(row: &entity::Model, model: &entity::ActiveModel) -> bool {
+    row.is_schema == *model.is_schema.as_ref()
+        && row.deprecated == *model.deprecated.as_ref()
+        && row.content == *model.content.as_ref()
+}
+
+fn row_content(row: &entity::Model) -> Result<serde_json::Value, DomainError> {
+    serde_json::from_str(&row.content).map_err(|e| DomainError::Internal(e.into()))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn late_commits_fill_gaps() {
+        let now = Instant::now();
+        let mut synced = SyncState::default();
+
+        synced.observe(1, now);
+        // seq 2 and 3 are still uncommitted when 4 becomes visible
+        synced.observe(4, now);
+        assert_eq!(synced.high, 4);
+        assert_eq!(synced.gaps.keys().copied().collect::<Vec<_>>(), [2, 3]);
+
+        synced.observe(3, now);
+        assert_eq!(synced.high, 4);
+        assert_eq!(synced.gaps.keys().copied().collect::<Vec<_>>(), [2]);
+    }
+
+    #[test]
+    fn gaps_expire_after_grace_period() {
+        let now = Instant::now();
+        let mut synced = SyncState::default();
+        synced.observe(3, now);
+
+        synced.expire(now + GAP_GRACE / 2);
+        assert_eq!(synced.gaps.len(), 2);
+
+        synced.expire(now + GAP_GRACE);
+        assert!(synced.gaps.is_empty());
+        assert_eq!(synced.high, 3);
+    }
+
+    #[test]
+    fn tracked_gaps_are_bounded() {
+        let mut synced = SyncState::default();
+        synced.observe(10 * MAX_GAPS, Instant::now());
+
+        assert_eq!(synced.gaps.len(), usize::try_from(MAX_GAPS).unwrap());
+        assert_eq!(synced.gaps.keys().next(), Some(&(9 * MAX_GAPS)));
+    }
+
+    #[tokio::test]
+    async fn rejected_write_is_reverted_and_not_published() {
+        use crate::config::TypesRegistryConfig;
+        use crate::infra::storage::migrations::Migrator;
+        use modkit_db::{ConnectOpts, DbHandle};
+        use sea_orm_migration::MigratorTrait;
+        use serde_json::json;
+
+        let opts = ConnectOpts {
+            max_conns: Some(1),
+            ..Default::default()
+        };
+        let db = DbHandle::connect("sqlite::memory:", opts).await.unwrap();
+        Migrator::up(db.sea_secure().conn(), None).await.unwrap();
+        let replica = || {
+            DbGtsRepository::new(
+                db.sea_secure(),
+                TypesRegistryConfig::default().to_gts_config(),
+            )
+        };
+        let person = |name: &str| {
+            json!({
+                "id": "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+                "name": name
+            })
+        };
+
+        let repo = replica();
+        let schema = json!({
+            "$id": "gts://gts.acme.core.models.person.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": { "name": { "type": "string" } }
+        });
+        repo.register(&schema, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
+
+        // Another replica commits the same GTS ID right after `repo` last synced
+        let other = replica();
+        other.switch_to_ready().await.unwrap();
+        other.register(&person("alice"), true).await.unwrap();
+        repo.synced.lock().await.high = i64::MAX;
+
+        let mut changes = repo.subscribe();
+        let err = repo.register(&person("mallory"), true).await.unwrap_err();
+        assert!(matches!(err, DomainError::AlreadyExists(_)));
+        assert!(changes.try_recv().is_err());
+
+        *repo.synced.lock().await = SyncState::default();
+        let alice = repo
+            .get("gts.acme.core.models.person.v1~acme.core.instances.alice.v1")
+            .await
+            .unwrap();
+        assert_eq!(alice.content["name"], "alice");
+    }
+}
@@ -0,0 +1,29 @@
+//! `SeaORM` entity for persisted GTS entities.
+
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+
+/// A GTS schema or instance committed to the registry.
+///
+/// GTS entities are global (not tenant-scoped), hence `unrestricted`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "gts_entities")]
+#[secure(unrestricted)]
+pub struct Model {
+    /// Insert order; a changed entity is re-inserted with a new `seq`, and replicas
+    /// load the rows whose `seq` they have not seen.
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+    #[sea_orm(unique)]
+    pub gts_id: String,
+    pub is_schema: bool,
+    /// Entity JSON as registered.
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+    pub deprecated: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,17 +1,28 @@
 //! In-memory repository implementation using gts-rust.
 
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use gts::{GtsConfig, GtsID, GtsIdSegment, GtsOps, GtsWildcard};
+use async_trait::async_trait;
+use gts::{GtsConfig, GtsID, GtsIdSegment, GtsOps};
+use jsonschema::JSONSchema;
 use parking_lot::Mutex;
-use types_registry_sdk::{GtsEntity, ListQuery, SegmentMatchScope};
+use serde_json::Value;
+use tokio::sync::broadcast;
+use types_registry_sdk::{
+    EntityChange, EntityChangeKind, EntityUpdate, GtsEntity, InstanceValidation, ListQuery,
+};
 
 use super::debug_diagnostics::{
     log_instance_validation_failure, log_registration_failure, log_schema_validation_failure,
 };
+use crate::domain::compatibility;
 use crate::domain::error::DomainError;
 use crate::domain::repo::GtsRepository;
 
+/// Number of changes buffered per subscriber before it starts lagging.
+const CHANGES_CAPACITY: usize = 1024;
+
 /// In-memory repository for GTS entities using gts-rust.
 ///
 /// Implements two-phase storage:
@@ -25,10 +36,40 @@ pub struct InMemoryGtsRepository {
     temporary: Mutex<GtsOps>,
     /// Persistent storage after ready commit.
     persistent: Mutex<GtsOps>,
+    /// GTS IDs of deprecated entities.
+    ///
+    /// Lock order: always acquire after `persistent`.
+    deprecated: Mutex<HashSet<String>>,
     /// Flag indicating ready mode.
     is_ready: AtomicBool,
     /// GTS configuration.
     config: GtsConfig,
+    /// Changes to persistent storage, published while holding its lock so that
+    /// subscribers see them in commit order.
+    changes: broadcast::Sender<EntityChange>,
+}
+
+/// A change applied to persistent storage whose event is not published yet.
+///
+/// Lets a caller write the change elsewhere first and then either
+/// [`publish`](InMemoryGtsRepository::publish) or [`revert`](InMemoryGtsRepository::revert) it.
+#[must_use]
+pub struct PendingChange {
+    /// `None` if the operation left storage unchanged.
+    kind: Option<EntityChangeKind>,
+    entity: GtsEntity,
+    /// Content an update replaced.
+    previous: Option<Value>,
+}
+
+impl PendingChange {
+    fn unchanged(entity: &GtsEntity) -> Self {
+        Self {
+            kind: None,
+            entity: entity.clone(),
+            previous: None,
+        }
+    }
 }
 
 impl InMemoryGtsRepository {
@@ -38,11 +79,29 @@ impl InMemoryGtsRepository {
         Self {
             temporary: Mutex::new(GtsOps::new(None, None, 0)),
             persistent: Mutex::new(GtsOps::new(None, None, 0)),
+            deprecated: Mutex::new(HashSet::new()),
             is_ready: AtomicBool::new(false),
             config,
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
         }
     }
 
+    /// Publishes a change to subscribers, if there are any.
+    fn notify(&self, kind: EntityChangeKind, entity: &GtsEntity) {
+        let _ = self.changes.send(EntityChange {
+            kind,
+            entity: entity.clone(),
+        });
+    }
+
+    /// Wraps a registered entity that triggered no re-validation.
+    fn registered(gts_id: &str, content: &serde_json::Value) -> Result<EntityUpdate, DomainError> {
+        Ok(EntityUpdate {
+            entity: Self::to_gts_entity(gts_id, content)?,
+            revalidated: Vec::new(),
+        })
+    }
+
     /// Converts a gts-rust entity result to our SDK `GtsEntity`.
     fn to_gts_entity(gts_id: &str, content: &serde_json::Value) -> Result<GtsEntity, DomainError> {
         let parsed = GtsID::new(gts_id).map_err(|e| DomainError::invalid_gts_id(e.to_string()))?;
@@ -68,6 +127,15 @@ impl InMemoryGtsRepository {
         ))
     }
 
+    /// Converts stored content to an SDK `GtsEntity`, applying the deprecation flag.
+    ///
+    /// Must not be called while holding the `deprecated` lock.
+    fn entity(&self, gts_id: &str, content: &serde_json::Value) -> Result<GtsEntity, DomainError> {
+        let mut entity = Self::to_gts_entity(gts_id, content)?;
+        entity.deprecated = self.deprecated.lock().contains(gts_id);
+        Ok(entity)
+    }
+
     /// Extracts the GTS ID from an entity JSON value using configured fields.
     ///
     /// Strips the `gts://` URI prefix from `$id` fields for JSON Schema compatibility (gts-rust v0.7.0+).
@@ -88,98 +156,332 @@ impl InMemoryGtsRepository {
         None
     }
 
-    /// Checks if an entity matches the given query filters.
-    fn matches_query(entity: &GtsEntity, query: &ListQuery) -> bool {
-        if let Some(ref pattern) = query.pattern
-            && let Ok(wildcard) = GtsWildcard::new(pattern)
-        {
-            if let Ok(gts_id) = GtsID::new(&entity.gts_id) {
-                if !gts_id.wildcard_match(&wildcard) {
-                    return false;
-                }
+    /// Writes an already-validated entity straight to persistent storage.
+    ///
+    /// Used to load entities committed by another repository instance (e.g. from a
+    /// database) without re-validating them. Replaces any stored copy with the same
+    /// GTS ID. Returns `false` if the GTS ID is missing or nothing changed.
+    pub fn restore(&self, entity: &serde_json::Value, deprecated: bool) -> bool {
+        let Some(gts_id) = self.extract_gts_id(entity) else {
+            return false;
+        };
+
+        let mut persistent = self.persistent.lock();
+        let existed = persistent.store.get(&gts_id).is_some();
+        let content_changed = match persistent.store.get(&gts_id) {
+            Some(existing) if existing.content == *entity => false,
+            _ => persistent.add_entity(entity, false).ok,
+        };
+
+        let flag_changed = {
+            let mut flags = self.deprecated.lock();
+            if deprecated {
+                flags.insert(gts_id.clone())
             } else {
-                return false;
+                flags.remove(&gts_id)
             }
-        }
-
-        if let Some(is_type) = query.is_type
-            && entity.is_type() != is_type
-        {
+        };
+        if !content_changed && !flag_changed {
             return false;
         }
+        if !existed && !content_changed {
+            // Nothing was stored, so there is no entity to report
+            return true;
+        }
 
-        let segments_to_check: Vec<&GtsIdSegment> = match query.segment_scope {
-            SegmentMatchScope::Primary => entity.segments.first().into_iter().collect(),
-            SegmentMatchScope::Any => entity.segments.iter().collect(),
+        let kind = if !existed {
+            EntityChangeKind::Registered
+        } else if deprecated && flag_changed {
+            EntityChangeKind::Deprecated
+        } else {
+            EntityChangeKind::Updated
         };
+        if let Ok(entity) = self.entity(&gts_id, entity) {
+            self.notify(kind, &entity);
+        }
+        true
+    }
 
-        if let Some(ref vendor) = query.vendor
-            && !segments_to_check.iter().any(|s| s.vendor == *vendor)
-        {
-            return false;
+    /// Publishes the event of a pending change.
+    pub fn publish(&self, change: PendingChange) {
+        let _persistent = self.persistent.lock();
+        self.publish_locked(change);
+    }
+
+    /// Publishes the event of a pending change; the caller holds the `persistent` lock.
+    fn publish_locked(&self, change: PendingChange) {
+        let PendingChange { kind, entity, .. } = change;
+        if let Some(kind) = kind {
+            self.notify(kind, &entity);
+        }
+    }
+
+    /// Undoes a pending change without publishing anything.
+    pub fn revert(&self, change: PendingChange) {
+        let PendingChange {
+            kind,
+            entity,
+            previous,
+        } = change;
+        let gts_id = &entity.gts_id;
+        let mut persistent = self.persistent.lock();
+        match kind {
+            Some(EntityChangeKind::Registered) => {
+                // The store can't remove entries: rebuild it without the entity
+                let kept: Vec<Value> = persistent
+                    .store
+                    .items()
+                    .filter(|(id, _)| *id != gts_id)
+                    .map(|(_, e)| e.content.clone())
+                    .collect();
+                *persistent = GtsOps::new(None, None, 0);
+                for content in &kept {
+                    persistent.add_entity(content, false);
+                }
+            }
+            Some(EntityChangeKind::Updated) => {
+                if let Some(previous) = &previous {
+                    persistent.add_entity(previous, false);
+                }
+            }
+            Some(EntityChangeKind::Deprecated) => {
+                self.deprecated.lock().remove(gts_id);
+            }
+            None => {}
+        }
+    }
+
+    /// Registers an entity in ready mode without publishing the change.
+    ///
+    /// # Errors
+    /// Same as [`GtsRepository::register`]; `NotInReadyMode` before `switch_to_ready`.
+    pub fn register_pending(
+        &self,
+        entity: &serde_json::Value,
+        validate: bool,
+    ) -> Result<(EntityUpdate, PendingChange), DomainError> {
+        if !self.is_ready.load(Ordering::SeqCst) {
+            return Err(DomainError::NotInReadyMode);
+        }
+        let gts_id = self.gts_id(entity)?;
+        self.register_ready(&mut self.persistent.lock(), &gts_id, entity, validate)
+    }
+
+    /// Updates an entity without publishing the change.
+    ///
+    /// # Errors
+    /// Same as [`GtsRepository::update`].
+    pub fn update_pending(
+        &self,
+        entity: &serde_json::Value,
+    ) -> Result<(EntityUpdate, PendingChange), DomainError> {
+        self.update_ready(&mut self.persistent.lock(), entity)
+    }
+
+    /// Deprecates an entity without publishing the change.
+    ///
+    /// # Errors
+    /// Same as [`GtsRepository::deprecate`].
+    pub fn deprecate_pending(
+        &self,
+        gts_id: &str,
+    ) -> Result<(GtsEntity, PendingChange), DomainError> {
+        self.deprecate_ready(&mut self.persistent.lock(), gts_id)
+    }
+
+    /// Extracts and validates the GTS ID of an entity.
+    fn gts_id(&self, entity: &serde_json::Value) -> Result<String, DomainError> {
+        let gts_id = self
+            .extract_gts_id(entity)
+            .ok_or_else(|| DomainError::invalid_gts_id("No GTS ID field found in entity"))?;
+
+        GtsID::new(&gts_id).map_err(|e| DomainError::invalid_gts_id(e.to_string()))?;
+        Ok(gts_id)
+    }
+
+    /// Registers an entity in ready mode, enforcing deprecation and compatibility.
+    fn register_ready(
+        &self,
+        persistent: &mut GtsOps,
+        gts_id: &str,
+        entity: &serde_json::Value,
+        validate: bool,
+    ) -> Result<(EntityUpdate, PendingChange), DomainError> {
+        if let Some(existing) = persistent.store.get(gts_id) {
+            if existing.content == *entity {
+                let existing = self.entity(gts_id, entity)?;
+                let unchanged = PendingChange::unchanged(&existing);
+                return Ok((
+                    EntityUpdate {
+                        entity: existing,
+                        revalidated: Vec::new(),
+                    },
+                    unchanged,
+                ));
+            }
+            return Err(DomainError::already_exists(gts_id));
         }
 
-        if let Some(ref package) = query.package
-            && !segments_to_check.iter().any(|s| s.package == *package)
+        if let Some(schema_id) = instance_schema_id(gts_id)
+            && self.deprecated.lock().contains(schema_id)
         {
-            return false;
+            return Err(DomainError::validation_failed(format!(
+                "Schema {schema_id} is deprecated"
+            )));
         }
 
-        if let Some(ref namespace) = query.namespace
-            && !segments_to_check.iter().any(|s| s.namespace == *namespace)
+        // A new minor version must accept everything the previous one accepted
+        let previous = if validate && gts_id.ends_with('~') {
+            compatibility::previous_minor_version(
+                gts_id,
+                persistent.store.items().map(|(id, _)| id.as_str()),
+            )
+            .map(ToOwned::to_owned)
+        } else {
+            None
+        };
+        if let Some(previous) = &previous
+            && let Some(old) = persistent.store.get(previous).map(|e| e.content.clone())
         {
-            return false;
+            check_compatibility(persistent, previous, &old, entity)?;
         }
 
-        true
+        let result = persistent.add_entity(entity, validate);
+        if !result.ok {
+            // Debug logging for registration failure
+            if gts_id.ends_with('~') {
+                log_schema_validation_failure(gts_id, entity, &result.error);
+            } else {
+                log_instance_validation_failure(gts_id, entity, &result.error, persistent);
+            }
+            return Err(DomainError::validation_failed(result.error));
+        }
+        let registered = self.entity(gts_id, entity)?;
+
+        let revalidated = previous
+            .map(|previous| revalidate_instances(persistent, &previous, entity))
+            .unwrap_or_default();
+
+        let change = PendingChange {
+            kind: Some(EntityChangeKind::Registered),
+            entity: registered.clone(),
+            previous: None,
+        };
+        Ok((
+            EntityUpdate {
+                entity: registered,
+                revalidated,
+            },
+            change,
+        ))
     }
-}
 
-impl GtsRepository for InMemoryGtsRepository {
-    fn register(
+    /// Updates an entity in ready mode, enforcing schema compatibility.
+    fn update_ready(
         &self,
+        persistent: &mut GtsOps,
         entity: &serde_json::Value,
-        validate: bool,
-    ) -> Result<GtsEntity, DomainError> {
+    ) -> Result<(EntityUpdate, PendingChange), DomainError> {
+        if !self.is_ready.load(Ordering::SeqCst) {
+            return Err(DomainError::NotInReadyMode);
+        }
+
         let gts_id = self
             .extract_gts_id(entity)
             .ok_or_else(|| DomainError::invalid_gts_id("No GTS ID field found in entity"))?;
 
-        GtsID::new(&gts_id).map_err(|e| DomainError::invalid_gts_id(e.to_string()))?;
+        let current = persistent
+            .store
+            .get(&gts_id)
+            .map(|e| e.content.clone())
+            .ok_or_else(|| DomainError::not_found(&gts_id))?;
 
-        if self.is_ready.load(Ordering::SeqCst) {
-            let mut persistent = self.persistent.lock();
+        let is_schema = gts_id.ends_with('~');
+        if is_schema {
+            check_compatibility(persistent, &gts_id, &current, entity)?;
+        }
 
-            if let Some(existing) = persistent.store.get(&gts_id) {
-                if existing.content == *entity {
-                    return Self::to_gts_entity(&gts_id, entity);
-                }
-                return Err(DomainError::already_exists(&gts_id));
-            }
+        let result = persistent.add_entity(entity, true);
+        if !result.ok {
+            // gts-rust stores the entity before validating it: put the current content back
+            persistent.add_entity(&current, false);
+            log_registration_failure(Some(&gts_id), entity, &result.error);
+            return Err(DomainError::validation_failed(result.error));
+        }
 
-            let result = persistent.add_entity(entity, validate);
-            if !result.ok {
-                // Debug logging for registration failure
-                if gts_id.ends_with('~') {
-                    log_schema_validation_failure(&gts_id, entity, &result.error);
-                } else {
-                    log_instance_validation_failure(
-                        &gts_id,
-                        entity,
-                        &result.error,
-                        &mut persistent,
-                    );
-                }
-                return Err(DomainError::validation_failed(result.error));
+        let revalidated = if is_schema {
+            revalidate_instances(persistent, &gts_id, entity)
+        } else {
+            Vec::new()
+        };
+
+        let updated = self.entity(&gts_id, entity)?;
+        let change = PendingChange {
+            kind: Some(EntityChangeKind::Updated),
+            entity: updated.clone(),
+            previous: Some(current),
+        };
+        Ok((
+            EntityUpdate {
+                entity: updated,
+                revalidated,
+            },
+            change,
+        ))
+    }
+
+    /// Marks an entity as deprecated in ready mode.
+    fn deprecate_ready(
+        &self,
+        persistent: &mut GtsOps,
+        gts_id: &str,
+    ) -> Result<(GtsEntity, PendingChange), DomainError> {
+        if !self.is_ready.load(Ordering::SeqCst) {
+            return Err(DomainError::NotInReadyMode);
+        }
+
+        let content = persistent
+            .store
+            .get(gts_id)
+            .map(|e| e.content.clone())
+            .ok_or_else(|| DomainError::not_found(gts_id))?;
+
+        let newly_deprecated = self.deprecated.lock().insert(gts_id.to_owned());
+        let deprecated = self.entity(gts_id, &content)?;
+        let change = if newly_deprecated {
+            PendingChange {
+                kind: Some(EntityChangeKind::Deprecated),
+                entity: deprecated.clone(),
+                previous: None,
             }
+        } else {
+            PendingChange::unchanged(&deprecated)
+        };
+        Ok((deprecated, change))
+    }
+}
+
+#[async_trait]
+impl GtsRepository for InMemoryGtsRepository {
+    async fn register(
+        &self,
+        entity: &serde_json::Value,
+        validate: bool,
+    ) -> Result<EntityUpdate, DomainError> {
+        let gts_id = self.gts_id(entity)?;
 
-            Self::to_gts_entity(&gts_id, entity)
+        if self.is_ready.load(Ordering::SeqCst) {
+            let mut persistent = self.persistent.lock();
+            let (registered, change) =
+                self.register_ready(&mut persistent, &gts_id, entity, validate)?;
+            self.publish_locked(change);
+            Ok(registered)
         } else {
             let mut temporary = self.temporary.lock();
 
             if let Some(existing) = temporary.store.get(&gts_id) {
                 if existing.content == *entity {
-                    return Self::to_gts_entity(&gts_id, entity);
+                    return Self::registered(&gts_id, entity);
                 }
                 return Err(DomainError::already_exists(&gts_id));
             }
@@ -191,27 +493,41 @@ impl GtsRepository for InMemoryGtsRepository {
                 return Err(DomainError::validation_failed(result.error));
             }
 
-            Self::to_gts_entity(&gts_id, entity)
+            Self::registered(&gts_id, entity)
         }
     }
 
-    fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+    async fn get(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
         let mut persistent = self.persistent.lock();
 
         if let Some(entity) = persistent.store.get(gts_id) {
-            return Self::to_gts_entity(gts_id, &entity.content);
+            return self.entity(gts_id, &entity.content);
         }
 
         Err(DomainError::not_found(gts_id))
     }
 
-    fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
+    async fn update(&self, entity: &serde_json::Value) -> Result<EntityUpdate, DomainError> {
+        let mut persistent = self.persistent.lock();
+        let (updated, change) = self.update_ready(&mut persistent, entity)?;
+        self.publish_locked(change);
+        Ok(updated)
+    }
+
+    async fn deprecate(&self, gts_id: &str) -> Result<GtsEntity, DomainError> {
+        let mut persistent = self.persistent.lock();
+        let (deprecated, change) = self.deprecate_ready(&mut persistent, gts_id)?;
+        self.publish_locked(change);
+        Ok(deprecated)
+    }
+
+    async fn list(&self, query: &ListQuery) -> Result<Vec<GtsEntity>, DomainError> {
         let persistent = self.persistent.lock();
         let mut results = Vec::new();
 
         for (gts_id, gts_entity) in persistent.store.items() {
-            if let Ok(entity) = Self::to_gts_entity(gts_id, &gts_entity.content)
-                && Self::matches_query(&entity, query)
+            if let Ok(entity) = self.entity(gts_id, &gts_entity.content)
+                && query.matches(&entity)
             {
                 results.push(entity);
             }
@@ -220,7 +536,7 @@ impl GtsRepository for InMemoryGtsRepository {
         Ok(results)
     }
 
-    fn exists(&self, gts_id: &str) -> bool {
+    async fn exists(&self, gts_id: &str) -> bool {
         let mut persistent = self.persistent.lock();
         persistent.store.get(gts_id).is_some()
     }
@@ -229,7 +545,11 @@ impl GtsRepository for InMemoryGtsRepository {
         self.is_ready.load(Ordering::SeqCst)
     }
 
-    fn switch_to_ready(&self) -> Result<(), Vec<String>> {
+    fn subscribe(&self) -> broadcast::Receiver<EntityChange> {
+        self.changes.subscribe()
+    }
+
+    async fn switch_to_ready(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
 
         // Collect all GTS IDs, separating schemas (ending with ~) from instances
@@ -313,16 +633,93 @@ impl GtsRepository for InMemoryGtsRepository {
             return Err(errors);
         }
 
+        // Publish the committed entities before any ready-mode change can be made
+        {
+            let mut persistent = self.persistent.lock();
+            for gts_id in schema_ids.iter().chain(&instance_ids) {
+                if let Some(content) = persistent.store.get(gts_id).map(|e| e.content.clone())
+                    && let Ok(entity) = self.entity(gts_id, &content)
+                {
+                    self.notify(EntityChangeKind::Registered, &entity);
+                }
+            }
+        }
+
         self.is_ready.store(true, Ordering::SeqCst);
 
         Ok(())
     }
 }
 
+/// Returns the schema an instance GTS ID belongs to, or `None` for schemas.
+fn instance_schema_id(gts_id: &str) -> Option<&str> {
+    if gts_id.ends_with('~') {
+        return None;
+    }
+    gts_id.rfind('~').map(|i| &gts_id[..=i])
+}
+
+/// Inlines `$ref`s of a schema so it can be compared or compiled on its own.
+fn resolve_schema(ops: &GtsOps, schema: &Value) -> Value {
+    let mut resolved = ops.store.resolve_schema_refs(schema);
+    if let Value::Object(ref mut map) = resolved {
+        map.remove("$id");
+        map.remove("$schema");
+    }
+    resolved
+}
+
+/// Rejects `new` if it breaks instances that `old` (the schema `previous_id`) accepts.
+fn check_compatibility(
+    ops: &GtsOps,
+    previous_id: &str,
+    old: &Value,
+    new: &Value,
+) -> Result<(), DomainError> {
+    let changes =
+        compatibility::breaking_changes(&resolve_schema(ops, old), &resolve_schema(ops, new));
+    if changes.is_empty() {
+        Ok(())
+    } else {
+        Err(DomainError::validation_failed(format!(
+            "Incompatible with {previous_id}: {}",
+            changes.join("; ")
+        )))
+    }
+}
+
+/// Validates every stored instance of `schema_id` against `schema`, ordered by GTS ID.
+fn revalidate_instances(ops: &GtsOps, schema_id: &str, schema: &Value) -> Vec<InstanceValidation> {
+    let resolved = resolve_schema(ops, schema);
+    let compiled = JSONSchema::compile(&resolved).map_err(|e| format!("Invalid schema: {e}"));
+
+    let mut results: Vec<InstanceValidation> = ops
+        .store
+        .items()
+        .filter(|(id, _)| instance_schema_id(id) == Some(schema_id))
+        .map(|(id, instance)| {
+            let error = match &compiled {
+                Ok(compiled) => compiled
+                    .validate(&instance.content)
+                    .err()
+                    .map(|errors| errors.map(|e| e.to_string()).collect::<Vec<_>>().join(", ")),
+                Err(e) => Some(e.clone()),
+            };
+            InstanceValidation {
+                gts_id: id.clone(),
+                error,
+            }
+        })
+        .collect();
+    results.sort_by(|a, b| a.gts_id.cmp(&b.gts_id));
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
+    use types_registry_sdk::SegmentMatchScope;
 
     const JSON_SCHEMA_DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
 
@@ -330,8 +727,8 @@ mod tests {
         crate::config::TypesRegistryConfig::default().to_gts_config()
     }
 
-    #[test]
-    fn test_register_in_configuration_mode() {
+    #[tokio::test]
+    async fn test_register_in_configuration_mode() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -343,16 +740,16 @@ mod tests {
             }
         });
 
-        let result = repo.register(&entity, false);
+        let result = repo.register(&entity, false).await;
         assert!(result.is_ok());
 
-        let registered = result.unwrap();
+        let registered = result.unwrap().entity;
         assert_eq!(registered.gts_id, "gts.acme.core.events.user_created.v1~");
         assert!(registered.is_type());
     }
 
-    #[test]
-    fn test_register_duplicate_identical_succeeds() {
+    #[tokio::test]
+    async fn test_register_duplicate_identical_succeeds() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -361,15 +758,15 @@ mod tests {
             "type": "object"
         });
 
-        let result1 = repo.register(&entity, false);
+        let result1 = repo.register(&entity, false).await;
         assert!(result1.is_ok());
 
-        let result2 = repo.register(&entity, false);
+        let result2 = repo.register(&entity, false).await;
         assert!(result2.is_ok(), "Idempotent registration should succeed");
     }
 
-    #[test]
-    fn test_register_duplicate_different_content_fails() {
+    #[tokio::test]
+    async fn test_register_duplicate_different_content_fails() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity1 = json!({
@@ -385,15 +782,15 @@ mod tests {
             "description": "Different content"
         });
 
-        let result1 = repo.register(&entity1, false);
+        let result1 = repo.register(&entity1, false).await;
         assert!(result1.is_ok());
 
-        let result2 = repo.register(&entity2, false);
+        let result2 = repo.register(&entity2, false).await;
         assert!(matches!(result2, Err(DomainError::AlreadyExists(_))));
     }
 
-    #[test]
-    fn test_register_invalid_gts_id_fails() {
+    #[tokio::test]
+    async fn test_register_invalid_gts_id_fails() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -402,12 +799,12 @@ mod tests {
             "type": "object"
         });
 
-        let result = repo.register(&entity, false);
+        let result = repo.register(&entity, false).await;
         assert!(matches!(result, Err(DomainError::InvalidGtsId(_))));
     }
 
-    #[test]
-    fn test_register_missing_gts_id_fails() {
+    #[tokio::test]
+    async fn test_register_missing_gts_id_fails() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -415,12 +812,12 @@ mod tests {
             "type": "object"
         });
 
-        let result = repo.register(&entity, false);
+        let result = repo.register(&entity, false).await;
         assert!(matches!(result, Err(DomainError::InvalidGtsId(_))));
     }
 
-    #[test]
-    fn test_switch_to_ready() {
+    #[tokio::test]
+    async fn test_switch_to_ready() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -432,20 +829,20 @@ mod tests {
             }
         });
 
-        repo.register(&entity, false).unwrap();
+        repo.register(&entity, false).await.unwrap();
 
         assert!(!repo.is_ready());
 
-        let result = repo.switch_to_ready();
+        let result = repo.switch_to_ready().await;
         assert!(result.is_ok());
         assert!(repo.is_ready());
 
-        let get_result = repo.get("gts.acme.core.events.user_created.v1~");
+        let get_result = repo.get("gts.acme.core.events.user_created.v1~").await;
         assert!(get_result.is_ok());
     }
 
-    #[test]
-    fn test_list_with_filters() {
+    #[tokio::test]
+    async fn test_list_with_filters() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let type1 = json!({
@@ -459,33 +856,33 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&type1, false).unwrap();
-        repo.register(&type2, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&type1, false).await.unwrap();
+        repo.register(&type2, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default().with_vendor("acme");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].vendor(), Some("acme"));
 
         let query = ListQuery::default();
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 2);
     }
 
-    #[test]
-    fn test_get_not_found() {
+    #[tokio::test]
+    async fn test_get_not_found() {
         let repo = InMemoryGtsRepository::new(default_config());
-        repo.switch_to_ready().unwrap();
+        repo.switch_to_ready().await.unwrap();
 
-        let result = repo.get("gts.unknown.pkg.ns.type.v1~");
+        let result = repo.get("gts.unknown.pkg.ns.type.v1~").await;
         assert!(matches!(result, Err(DomainError::NotFound(_))));
     }
 
-    #[test]
-    fn test_register_in_ready_mode() {
+    #[tokio::test]
+    async fn test_register_in_ready_mode() {
         let repo = InMemoryGtsRepository::new(default_config());
-        repo.switch_to_ready().unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let entity = json!({
             "$id": "gts://gts.acme.core.events.user_created.v1~",
@@ -493,17 +890,17 @@ mod tests {
             "type": "object"
         });
 
-        let result = repo.register(&entity, true);
+        let result = repo.register(&entity, true).await;
         assert!(result.is_ok());
 
-        let get_result = repo.get("gts.acme.core.events.user_created.v1~");
+        let get_result = repo.get("gts.acme.core.events.user_created.v1~").await;
         assert!(get_result.is_ok());
     }
 
-    #[test]
-    fn test_register_duplicate_identical_in_ready_mode_succeeds() {
+    #[tokio::test]
+    async fn test_register_duplicate_identical_in_ready_mode_succeeds() {
         let repo = InMemoryGtsRepository::new(default_config());
-        repo.switch_to_ready().unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let entity = json!({
             "$id": "gts://gts.acme.core.events.user_created.v1~",
@@ -511,18 +908,18 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, true).unwrap();
-        let result = repo.register(&entity, true);
+        repo.register(&entity, true).await.unwrap();
+        let result = repo.register(&entity, true).await;
         assert!(
             result.is_ok(),
             "Idempotent registration should succeed in ready mode"
         );
     }
 
-    #[test]
-    fn test_register_duplicate_different_content_in_ready_mode_fails() {
+    #[tokio::test]
+    async fn test_register_duplicate_different_content_in_ready_mode_fails() {
         let repo = InMemoryGtsRepository::new(default_config());
-        repo.switch_to_ready().unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let entity1 = json!({
             "$id": "gts://gts.acme.core.events.user_created.v1~",
@@ -537,13 +934,13 @@ mod tests {
             "description": "Different content"
         });
 
-        repo.register(&entity1, true).unwrap();
-        let result = repo.register(&entity2, true);
+        repo.register(&entity1, true).await.unwrap();
+        let result = repo.register(&entity2, true).await;
         assert!(matches!(result, Err(DomainError::AlreadyExists(_))));
     }
 
-    #[test]
-    fn test_exists() {
+    #[tokio::test]
+    async fn test_exists() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -552,15 +949,15 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
-        assert!(repo.exists("gts.acme.core.events.user_created.v1~"));
-        assert!(!repo.exists("gts.unknown.pkg.ns.type.v1~"));
+        assert!(repo.exists("gts.acme.core.events.user_created.v1~").await);
+        assert!(!repo.exists("gts.unknown.pkg.ns.type.v1~").await);
     }
 
-    #[test]
-    fn test_list_with_is_type_filter() {
+    #[tokio::test]
+    async fn test_list_with_is_type_filter() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let type_entity = json!({
@@ -569,20 +966,20 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&type_entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&type_entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default().with_is_type(true);
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
 
         let query = ListQuery::default().with_is_type(false);
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 0);
     }
 
-    #[test]
-    fn test_list_with_package_filter() {
+    #[tokio::test]
+    async fn test_list_with_package_filter() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -591,20 +988,20 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default().with_package("core");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
 
         let query = ListQuery::default().with_package("other");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 0);
     }
 
-    #[test]
-    fn test_list_with_namespace_filter() {
+    #[tokio::test]
+    async fn test_list_with_namespace_filter() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -613,20 +1010,20 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default().with_namespace("events");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
 
         let query = ListQuery::default().with_namespace("other");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 0);
     }
 
-    #[test]
-    fn test_list_with_pattern_filter() {
+    #[tokio::test]
+    async fn test_list_with_pattern_filter() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -635,20 +1032,20 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default().with_pattern("gts.acme.*");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
 
         let query = ListQuery::default().with_pattern("gts.other.*");
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 0);
     }
 
-    #[test]
-    fn test_list_with_segment_scope_primary() {
+    #[tokio::test]
+    async fn test_list_with_segment_scope_primary() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -657,18 +1054,18 @@ mod tests {
             "type": "object"
         });
 
-        repo.register(&entity, false).unwrap();
-        repo.switch_to_ready().unwrap();
+        repo.register(&entity, false).await.unwrap();
+        repo.switch_to_ready().await.unwrap();
 
         let query = ListQuery::default()
             .with_vendor("acme")
             .with_segment_scope(SegmentMatchScope::Primary);
-        let results = repo.list(&query).unwrap();
+        let results = repo.list(&query).await.unwrap();
         assert_eq!(results.len(), 1);
     }
 
-    #[test]
-    fn test_register_with_description() {
+    #[tokio::test]
+    async fn test_register_with_description() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -678,12 +1075,12 @@ mod tests {
             "description": "A user created event"
         });
 
-        let result = repo.register(&entity, false).unwrap();
+        let result = repo.register(&entity, false).await.unwrap().entity;
         assert_eq!(result.description, Some("A user created event".to_owned()));
     }
 
-    #[test]
-    fn test_register_instance() {
+    #[tokio::test]
+    async fn test_register_instance() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -691,12 +1088,12 @@ mod tests {
             "data": "value"
         });
 
-        let result = repo.register(&entity, false).unwrap();
+        let result = repo.register(&entity, false).await.unwrap().entity;
         assert!(result.is_instance());
     }
 
-    #[test]
-    fn test_extract_gts_id_with_gtsid_field() {
+    #[tokio::test]
+    async fn test_extract_gts_id_with_gtsid_field() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -705,12 +1102,12 @@ mod tests {
             "type": "object"
         });
 
-        let result = repo.register(&entity, false);
+        let result = repo.register(&entity, false).await;
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn test_extract_gts_id_with_id_field() {
+    #[tokio::test]
+    async fn test_extract_gts_id_with_id_field() {
         let repo = InMemoryGtsRepository::new(default_config());
 
         let entity = json!({
@@ -719,7 +1116,7 @@ mod tests {
             "type": "object"
         });
 
-        let result = repo.register(&entity, false);
+        let result = repo.register(&entity, false).await;
         assert!(result.is_ok());
     }
 }
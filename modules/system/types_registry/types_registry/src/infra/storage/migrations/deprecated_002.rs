@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres | sea_orm::DatabaseBackend::MySql => {
+                "ALTER TABLE gts_entities ADD COLUMN deprecated BOOLEAN NOT NULL DEFAULT FALSE;"
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                "ALTER TABLE gts_entities ADD COLUMN deprecated INTEGER NOT NULL DEFAULT 0;"
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let sql = "ALTER TABLE gts_entities DROP COLUMN deprecated;";
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres => {
+                r"
+CREATE TABLE IF NOT EXISTS gts_entities (
+    seq BIGSERIAL PRIMARY KEY,
+    gts_id TEXT NOT NULL UNIQUE,
+    is_schema BOOLEAN NOT NULL,
+    content TEXT NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS gts_entities (
+    seq BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    gts_id VARCHAR(512) NOT NULL UNIQUE,
+    is_schema BOOLEAN NOT NULL,
+    content LONGTEXT NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS gts_entities (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    gts_id TEXT NOT NULL UNIQUE,
+    is_schema INTEGER NOT NULL,
+    content TEXT NOT NULL
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let sql = "DROP TABLE IF EXISTS gts_entities;";
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+}
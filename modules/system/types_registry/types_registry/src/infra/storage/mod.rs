@@ -1,6 +1,10 @@
 //! Storage implementations for the Types Registry module.
 
+mod db_repo;
 mod debug_diagnostics;
+mod entity;
 mod in_memory_repo;
+pub mod migrations;
 
+pub use db_repo::DbGtsRepository;
 pub use in_memory_repo::InMemoryGtsRepository;
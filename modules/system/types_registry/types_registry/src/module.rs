@@ -12,8 +12,9 @@ use types_registry_sdk::TypesRegistryClient;
 
 use crate::config::TypesRegistryConfig;
 use crate::domain::local_client::TypesRegistryLocalClient;
+use crate::domain::repo::GtsRepository;
 use crate::domain::service::TypesRegistryService;
-use crate::infra::InMemoryGtsRepository;
+use crate::infra::{DbGtsRepository, InMemoryGtsRepository};
 
 /// Types Registry module.
 ///
@@ -23,6 +24,8 @@ use crate::infra::InMemoryGtsRepository;
 ///
 /// - `system` — Core infrastructure module, initialized early in startup
 /// - `rest` — Exposes REST API endpoints
+/// - `db` — Persists registered entities when a database is configured for the
+///   module; without one, entities are kept in memory only
 ///
 /// ## Core GTS Types
 ///
@@ -33,7 +36,7 @@ use crate::infra::InMemoryGtsRepository;
 /// NOTE: This is temprorary logic until <https://github.com/hypernetix/hyperspot/issues/156> resolved
 #[modkit::module(
     name = "types_registry",
-    capabilities = [system, rest]
+    capabilities = [system, rest, db]
 )]
 pub struct TypesRegistryModule {
     service: arc_swap::ArcSwapOption<TypesRegistryService>,
@@ -55,6 +58,19 @@ impl Clone for TypesRegistryModule {
     }
 }
 
+#[async_trait]
+impl modkit::contracts::DatabaseCapability for TypesRegistryModule {
+    async fn migrate(&self, db: &modkit_db::DbHandle) -> anyhow::Result<()> {
+        use sea_orm_migration::MigratorTrait;
+
+        info!("Running types_registry database migrations");
+        let conn = db.sea_secure();
+        crate::infra::storage::migrations::Migrator::up(conn.conn(), None).await?;
+        info!("Types registry database migrations completed");
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl Module for TypesRegistryModule {
     async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
@@ -67,7 +83,12 @@ impl Module for TypesRegistryModule {
         );
 
         let gts_config = cfg.to_gts_config();
-        let repo = Arc::new(InMemoryGtsRepository::new(gts_config));
+        let repo: Arc<dyn GtsRepository> = if let Some(db) = ctx.db_optional() {
+            info!("Using database-backed GTS repository");
+            Arc::new(DbGtsRepository::new(db.sea_secure(), gts_config))
+        } else {
+            Arc::new(InMemoryGtsRepository::new(gts_config))
+        };
         let service = Arc::new(TypesRegistryService::new(repo, cfg));
 
         self.service.store(Some(service.clone()));
@@ -106,11 +127,11 @@ impl SystemCapability for TypesRegistryModule {
             .ok_or_else(|| anyhow::anyhow!("Service not initialized"))?
             .clone();
 
-        service.switch_to_ready().map_err(|e| {
+        if let Err(e) = service.switch_to_ready().await {
             if let Some(errors) = e.validation_errors() {
                 for err in errors {
                     // Try to get the entity content for debugging
-                    let entity_content = match service.get(&err.gts_id) {
+                    let entity_content = match service.get(&err.gts_id).await {
                         Ok(entity) => serde_json::to_string_pretty(&entity.content)
                             .unwrap_or_else(|_| "Failed to serialize".to_owned()),
                         _ => "Entity not found or failed to retrieve".to_owned(),
@@ -124,8 +145,8 @@ impl SystemCapability for TypesRegistryModule {
                     );
                 }
             }
-            anyhow::anyhow!("Failed to switch to ready mode: {e}")
-        })?;
+            return Err(anyhow::anyhow!("Failed to switch to ready mode: {e}"));
+        }
 
         info!("types_registry switched to ready mode successfully");
         Ok(())
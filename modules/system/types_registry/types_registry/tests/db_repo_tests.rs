@@ -0,0 +1,202 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for the database-backed repository
+
+use std::sync::Arc;
+
+use modkit_db::{ConnectOpts, DbHandle};
+use sea_orm_migration::MigratorTrait;
+use serde_json::json;
+use types_registry::{
+    config::TypesRegistryConfig, domain::service::TypesRegistryService, infra::DbGtsRepository,
+    infra::storage::migrations::Migrator,
+};
+use types_registry_sdk::ListQuery;
+
+async fn connect() -> Arc<DbHandle> {
+    // A single connection keeps every repository on the same in-memory database
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = DbHandle::connect("sqlite::memory:", opts).await.unwrap();
+    Migrator::up(db.sea_secure().conn(), None).await.unwrap();
+    Arc::new(db)
+}
+
+/// A service as created by one process (or replica) on start-up.
+fn create_service(db: &DbHandle) -> TypesRegistryService {
+    let repo = Arc::new(DbGtsRepository::new(
+        db.sea_secure(),
+        TypesRegistryConfig::default().to_gts_config(),
+    ));
+    TypesRegistryService::new(repo, TypesRegistryConfig::default())
+}
+
+fn person_schema() -> serde_json::Value {
+    json!({
+        "$id": "gts://gts.acme.core.models.person.v1~",
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": { "name": { "type": "string" } },
+        "required": ["name"]
+    })
+}
+
+fn person(name: &str) -> serde_json::Value {
+    json!({
+        "id": format!("gts.acme.core.models.person.v1~acme.core.instances.{name}.v1"),
+        "name": name
+    })
+}
+
+#[tokio::test]
+async fn test_registrations_survive_restart() {
+    let db = connect().await;
+
+    let first = create_service(&db);
+    assert!(first.register(vec![person_schema()]).await[0].is_ok());
+    first.switch_to_ready().await.unwrap();
+    assert!(first.register_validated(vec![person("alice")]).await[0].is_ok());
+    drop(first);
+
+    let restarted = create_service(&db);
+    restarted.switch_to_ready().await.unwrap();
+
+    let alice = restarted
+        .get("gts.acme.core.models.person.v1~acme.core.instances.alice.v1")
+        .await
+        .unwrap();
+    assert_eq!(alice.content["name"], "alice");
+    assert_eq!(
+        restarted.list(&ListQuery::default()).await.unwrap().len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_configuration_phase_is_not_persisted() {
+    let db = connect().await;
+
+    let first = create_service(&db);
+    assert!(first.register(vec![person_schema()]).await[0].is_ok());
+    drop(first);
+
+    let restarted = create_service(&db);
+    restarted.switch_to_ready().await.unwrap();
+    assert!(
+        restarted
+            .get("gts.acme.core.models.person.v1~")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_persisted_instances_are_revalidated_on_ready() {
+    let db = connect().await;
+
+    let first = create_service(&db);
+    assert!(first.register(vec![person_schema()]).await[0].is_ok());
+    first.switch_to_ready().await.unwrap();
+    assert!(first.register_validated(vec![person("alice")]).await[0].is_ok());
+
+    // The schema now requires a field the stored instance lacks
+    let mut stricter = person_schema();
+    stricter["properties"]["age"] = json!({ "type": "integer" });
+    stricter["required"] = json!(["name", "age"]);
+
+    let restarted = create_service(&db);
+    assert!(restarted.register(vec![stricter]).await[0].is_ok());
+    let err = restarted.switch_to_ready().await.unwrap_err();
+    let errors = err.validation_errors().unwrap();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.gts_id.contains("acme.core.instances.alice")),
+        "{errors:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_replicas_share_registrations() {
+    let db = connect().await;
+
+    let a = create_service(&db);
+    assert!(a.register(vec![person_schema()]).await[0].is_ok());
+    a.switch_to_ready().await.unwrap();
+
+    let b = create_service(&db);
+    b.switch_to_ready().await.unwrap();
+
+    // Registered on A, visible and usable on B
+    assert!(a.register_validated(vec![person("alice")]).await[0].is_ok());
+    assert!(
+        b.get("gts.acme.core.models.person.v1~acme.core.instances.alice.v1")
+            .await
+            .is_ok()
+    );
+
+    // Conflicting content for the same GTS ID is rejected across replicas
+    let mut other = person("alice");
+    other["name"] = json!("mallory");
+    let results = b.register_validated(vec![other]).await;
+    assert!(results[0].is_err());
+}
+
+#[tokio::test]
+async fn test_updates_and_deprecations_are_shared() {
+    let db = connect().await;
+
+    let a = create_service(&db);
+    assert!(a.register(vec![person_schema(), person("alice")]).await[0].is_ok());
+    a.switch_to_ready().await.unwrap();
+
+    let b = create_service(&db);
+    b.switch_to_ready().await.unwrap();
+
+    let mut widened = person_schema();
+    widened["properties"]["age"] = json!({ "type": "integer" });
+    a.update(widened.clone()).await.unwrap();
+    a.deprecate("gts.acme.core.models.person.v1~")
+        .await
+        .unwrap();
+
+    // Another replica picks both up before registering against the schema
+    let results = b.register_validated(vec![person("bob")]).await;
+    assert!(results[0].is_err());
+    let schema = b.get("gts.acme.core.models.person.v1~").await.unwrap();
+    assert_eq!(schema.content, widened);
+    assert!(schema.deprecated);
+
+    // And a restarted one keeps them
+    let restarted = create_service(&db);
+    restarted.switch_to_ready().await.unwrap();
+    let schema = restarted
+        .get("gts.acme.core.models.person.v1~")
+        .await
+        .unwrap();
+    assert_eq!(schema.content, widened);
+    assert!(schema.deprecated);
+}
+
+#[tokio::test]
+async fn test_startup_changes_are_shared() {
+    let db = connect().await;
+
+    let a = create_service(&db);
+    assert!(a.register(vec![person_schema()]).await[0].is_ok());
+    a.switch_to_ready().await.unwrap();
+
+    // B starts with a changed copy of the schema, which takes precedence
+    let mut widened = person_schema();
+    widened["properties"]["age"] = json!({ "type": "integer" });
+    let b = create_service(&db);
+    assert!(b.register(vec![widened.clone()]).await[0].is_ok());
+    b.switch_to_ready().await.unwrap();
+
+    // A picks the new copy up on its next sync
+    assert!(a.register_validated(vec![person("alice")]).await[0].is_ok());
+    let schema = a.get("gts.acme.core.models.person.v1~").await.unwrap();
+    assert_eq!(schema.content, widened);
+}
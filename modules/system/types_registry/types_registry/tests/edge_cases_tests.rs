@@ -14,9 +14,9 @@ use serde_json::json;
 #[tokio::test]
 async fn test_get_nonexistent_entity() {
     let service = create_service();
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
-    let result = service.get("gts.nonexistent.pkg.ns.type.v1~");
+    let result = service.get("gts.nonexistent.pkg.ns.type.v1~").await;
     assert!(result.is_err());
 }
 
@@ -32,7 +32,7 @@ async fn test_invalid_gts_id_formats() {
         json!({ "$id": "", "type": "object" }),
     ];
 
-    let results = service.register(invalid_entities);
+    let results = service.register(invalid_entities).await;
 
     // All should fail due to invalid GTS ID format
     for result in results {
@@ -56,13 +56,13 @@ async fn test_gts_id_extraction_priority() {
         "type": "object"
     });
 
-    let results = service.register(vec![entity]);
+    let results = service.register(vec![entity]).await;
     assert!(results[0].is_ok());
 
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     // Verify the entity was registered with the $id value
-    let retrieved = service.get("gts.acme.core.events.from_dollar_id.v1~");
+    let retrieved = service.get("gts.acme.core.events.from_dollar_id.v1~").await;
     assert!(retrieved.is_ok());
     assert_eq!(
         retrieved.unwrap().gts_id,
@@ -98,11 +98,12 @@ async fn test_entity_content_preserved() {
         "description": "Test entity with custom content"
     });
 
-    let _ = service.register(vec![original_content]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![original_content]).await;
+    service.switch_to_ready().await.unwrap();
 
     let retrieved = service
         .get("gts.acme.core.events.content_test.v1~")
+        .await
         .unwrap();
 
     // Verify description is extracted
@@ -126,11 +127,12 @@ async fn test_entity_segments_parsed_correctly() {
         "type": "object"
     });
 
-    let _ = service.register(vec![entity]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![entity]).await;
+    service.switch_to_ready().await.unwrap();
 
     let retrieved = service
         .get("gts.myvendor.mypackage.mynamespace.mytype.v2~")
+        .await
         .unwrap();
 
     assert_eq!(retrieved.vendor(), Some("myvendor"));
@@ -161,7 +163,7 @@ async fn test_gts_id_with_special_segments() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
     assert!(
         results[0].is_ok(),
         "Underscores should be valid: {:?}",
@@ -173,8 +175,10 @@ async fn test_gts_id_with_special_segments() {
         results[1]
     );
 
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
-    let e1 = service.get("gts.acme_corp.core_v2.events_ns.my_type_123.v1~");
+    let e1 = service
+        .get("gts.acme_corp.core_v2.events_ns.my_type_123.v1~")
+        .await;
     assert!(e1.is_ok());
 }
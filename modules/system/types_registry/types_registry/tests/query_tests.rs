@@ -6,9 +6,14 @@ mod common;
 
 use axum::extract::Json;
 use common::create_service;
+use modkit::api::odata::OData;
+use modkit_odata::ast::{CompareOperator, Expr, Value};
+use modkit_odata::{CursorV1, ODataOrderBy, ODataQuery, OrderKey, SortDir};
 use serde_json::json;
 use types_registry::api::rest::dto::ListEntitiesQuery;
+use types_registry::domain::DomainError;
 use types_registry_sdk::ListQuery;
+use types_registry_sdk::odata::NAMESPACE;
 
 // =============================================================================
 // List and Query Tests
@@ -26,18 +31,18 @@ async fn test_list_entities_with_vendor_filter() {
         json!({ "$id": "gts://gts.initech.core.events.type4.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     // Filter by vendor "acme"
     let query = ListQuery::default().with_vendor("acme");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 2);
     assert!(results.iter().all(|e| e.vendor() == Some("acme")));
 
     // Filter by vendor "globex"
     let query = ListQuery::default().with_vendor("globex");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].vendor(), Some("globex"));
 }
@@ -52,16 +57,16 @@ async fn test_list_entities_with_package_filter() {
         json!({ "$id": "gts://gts.acme.core.events.type3.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     let query = ListQuery::default().with_package("core");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 2);
     assert!(results.iter().all(|e| e.package() == Some("core")));
 
     let query = ListQuery::default().with_package("billing");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 1);
 }
 
@@ -75,15 +80,15 @@ async fn test_list_entities_with_namespace_filter() {
         json!({ "$id": "gts://gts.acme.core.events.type3.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     let query = ListQuery::default().with_namespace("events");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 2);
 
     let query = ListQuery::default().with_namespace("commands");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 1);
 }
 
@@ -97,14 +102,14 @@ async fn test_list_entities_with_combined_filters() {
         json!({ "$id": "gts://gts.globex.core.events.type3.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     // Combined filter: vendor=acme AND package=core
     let query = ListQuery::default()
         .with_vendor("acme")
         .with_package("core");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].gts_id, "gts.acme.core.events.type1.v1~");
 }
@@ -120,12 +125,12 @@ async fn test_list_with_pattern_filter() {
         json!({ "$id": "gts://gts.acme.core.commands.create_user.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     // Pattern matching for "user" in the name
     let query = ListQuery::default().with_pattern("user");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     // Should match user_created, user_updated, create_user
     assert!(
         results.len() >= 2,
@@ -145,8 +150,8 @@ async fn test_list_with_is_type_filter() {
         "properties": { "name": { "type": "string" } }
     });
 
-    let _ = service.register(vec![type_schema]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![type_schema]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Register instances
     let instances = vec![
@@ -160,11 +165,12 @@ async fn test_list_with_is_type_filter() {
         }),
     ];
 
-    let _ = service.register(instances);
+    let _ = service.register(instances).await;
 
     // Filter for types only
     let types = service
         .list(&ListQuery::default().with_is_type(true))
+        .await
         .unwrap();
     assert_eq!(types.len(), 1);
     assert!(types[0].is_type());
@@ -172,12 +178,13 @@ async fn test_list_with_is_type_filter() {
     // Filter for instances only
     let instances = service
         .list(&ListQuery::default().with_is_type(false))
+        .await
         .unwrap();
     assert_eq!(instances.len(), 2);
     assert!(instances.iter().all(types_registry::GtsEntity::is_instance));
 
     // No filter - get all
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 3);
 }
 
@@ -193,13 +200,14 @@ async fn test_multiple_vendors_isolation() {
         json!({ "$id": "gts://gts.vendor_c.pkg.ns.type1.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     // Each vendor filter should return correct count
     assert_eq!(
         service
             .list(&ListQuery::default().with_vendor("vendor_a"))
+            .await
             .unwrap()
             .len(),
         2
@@ -207,6 +215,7 @@ async fn test_multiple_vendors_isolation() {
     assert_eq!(
         service
             .list(&ListQuery::default().with_vendor("vendor_b"))
+            .await
             .unwrap()
             .len(),
         1
@@ -214,6 +223,7 @@ async fn test_multiple_vendors_isolation() {
     assert_eq!(
         service
             .list(&ListQuery::default().with_vendor("vendor_c"))
+            .await
             .unwrap()
             .len(),
         1
@@ -221,6 +231,7 @@ async fn test_multiple_vendors_isolation() {
     assert_eq!(
         service
             .list(&ListQuery::default().with_vendor("vendor_d"))
+            .await
             .unwrap()
             .len(),
         0
@@ -238,15 +249,15 @@ async fn test_combined_vendor_package_namespace_filter() {
         json!({ "$id": "gts://gts.globex.billing.invoices.invoice.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
     ];
 
-    let _ = service.register(entities);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
 
     // Triple filter: vendor + package + namespace
     let query = ListQuery::default()
         .with_vendor("acme")
         .with_package("billing")
         .with_namespace("invoices");
-    let results = service.list(&query).unwrap();
+    let results = service.list(&query).await.unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].gts_id, "gts.acme.billing.invoices.invoice.v1~");
 }
@@ -266,8 +277,8 @@ async fn test_rest_list_handler_integration() {
     let _ = service.register(vec![
         json!({ "$id": "gts://gts.acme.core.events.list_test1.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
         json!({ "$id": "gts://gts.acme.core.events.list_test2.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }),
-    ]);
-    service.switch_to_ready().unwrap();
+    ]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Test list handler (now service is ready)
     let query = ListEntitiesQuery {
@@ -275,11 +286,16 @@ async fn test_rest_list_handler_integration() {
         ..Default::default()
     };
 
-    let result = list_entities(Extension(service), Query(query)).await;
+    let result = list_entities(
+        Extension(service),
+        Query(query),
+        OData(ODataQuery::default()),
+    )
+    .await;
     assert!(result.is_ok());
 
     let Json(response) = result.unwrap();
-    assert_eq!(response.count, 2);
+    assert_eq!(response.items.len(), 2);
 }
 
 #[tokio::test]
@@ -288,7 +304,7 @@ async fn test_rest_list_empty_results() {
     use types_registry::api::rest::handlers::list_entities;
 
     let service = create_service();
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     // Query with filter that matches nothing
     let query = ListEntitiesQuery {
@@ -296,12 +312,79 @@ async fn test_rest_list_empty_results() {
         ..Default::default()
     };
 
-    let result = list_entities(Extension(service), Query(query)).await;
+    let result = list_entities(
+        Extension(service),
+        Query(query),
+        OData(ODataQuery::default()),
+    )
+    .await;
     assert!(result.is_ok());
 
     let Json(response) = result.unwrap();
-    assert_eq!(response.count, 0);
-    assert!(response.entities.is_empty());
+    assert!(response.items.is_empty());
+    assert!(response.page_info.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_list_page_combines_list_query_and_odata() {
+    let service = create_service();
+
+    let mut entities: Vec<_> = (0..5)
+        .map(|i| json!({ "$id": format!("gts://gts.acme.core.events.event{i}.v1~"), "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }))
+        .collect();
+    entities.push(json!({ "$id": "gts://gts.acme.core.models.person.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }));
+    entities.push(json!({ "$id": "gts://gts.globex.core.events.order.v1~", "$schema": "http://json-schema.org/draft-07/schema#", "type": "object" }));
+    let _ = service.register(entities).await;
+    service.switch_to_ready().await.unwrap();
+
+    // Vendor from the list query, namespace from $filter, newest type name first
+    let list_query = ListQuery::default().with_vendor("acme");
+    let odata = ODataQuery::default()
+        .with_filter(NAMESPACE.eq("events"))
+        .with_order(ODataOrderBy(vec![OrderKey {
+            field: "type_name".to_owned(),
+            dir: SortDir::Desc,
+        }]))
+        .with_limit(2);
+
+    let mut seen = Vec::new();
+    let mut page = service.list_page(&list_query, &odata).await.unwrap();
+    loop {
+        seen.extend(page.items.iter().map(|e| e.gts_id.clone()));
+        let Some(cursor) = page.page_info.next_cursor.as_deref() else {
+            break;
+        };
+        let next = ODataQuery::default()
+            .with_filter(NAMESPACE.eq("events"))
+            .with_limit(2)
+            .with_cursor(CursorV1::decode(cursor).unwrap());
+        page = service.list_page(&list_query, &next).await.unwrap();
+    }
+
+    assert_eq!(
+        seen,
+        (0..5)
+            .rev()
+            .map(|i| format!("gts.acme.core.events.event{i}.v1~"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_list_page_rejects_unknown_filter_field() {
+    let service = create_service();
+    service.switch_to_ready().await.unwrap();
+
+    let odata = ODataQuery::default().with_filter(Expr::Compare(
+        Box::new(Expr::Identifier("content".to_owned())),
+        CompareOperator::Eq,
+        Box::new(Expr::Value(Value::String("x".to_owned()))),
+    ));
+    let err = service
+        .list_page(&ListQuery::default(), &odata)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::InvalidQuery(_)), "{err:?}");
 }
 
 // =============================================================================
@@ -316,13 +399,15 @@ async fn test_rest_get_handler_integration() {
     let service = create_service();
 
     // Register entity via internal API (before ready)
-    let _ = service.register(vec![json!({
-        "$id": "gts://gts.acme.core.events.get_test.v1~",
-        "$schema": "http://json-schema.org/draft-07/schema#",
-        "type": "object",
-        "description": "Test entity for GET handler"
-    })]);
-    service.switch_to_ready().unwrap();
+    let _ = service
+        .register(vec![json!({
+            "$id": "gts://gts.acme.core.events.get_test.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "description": "Test entity for GET handler"
+        })])
+        .await;
+    service.switch_to_ready().await.unwrap();
 
     // Test get handler (now service is ready)
     let result = get_entity(
@@ -346,7 +431,7 @@ async fn test_rest_get_handler_not_found() {
     use types_registry::api::rest::handlers::get_entity;
 
     let service = create_service();
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     let result = get_entity(
         Extension(service),
@@ -17,7 +17,7 @@ async fn test_ready_mode_validates_immediately_with_correct_order() {
     let service = create_service();
 
     // First, switch to ready mode
-    let _ = service.switch_to_ready();
+    let _ = service.switch_to_ready().await;
 
     // Register parent type FIRST, then instances in a single call
     // In ready mode, validation happens immediately so order matters
@@ -54,7 +54,7 @@ async fn test_ready_mode_validates_immediately_with_correct_order() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
 
     // All should succeed when parent type is registered first
     assert_eq!(results.len(), 4);
@@ -63,17 +63,19 @@ async fn test_ready_mode_validates_immediately_with_correct_order() {
     }
 
     // Verify all entities are immediately available (ready mode)
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 4, "All 4 entities should be registered");
 
     // Verify we have 1 type and 3 instances
     let types = service
         .list(&ListQuery::default().with_is_type(true))
+        .await
         .unwrap();
     assert_eq!(types.len(), 1);
 
     let instances = service
         .list(&ListQuery::default().with_is_type(false))
+        .await
         .unwrap();
     assert_eq!(instances.len(), 3);
 }
@@ -83,7 +85,7 @@ async fn test_ready_mode_fails_when_instance_before_parent() {
     let service = create_service();
 
     // Switch to ready mode
-    let _ = service.switch_to_ready();
+    let _ = service.switch_to_ready().await;
 
     // Try to register instance BEFORE parent type - should fail
     // In ready mode, validation is immediate so parent must exist
@@ -107,7 +109,7 @@ async fn test_ready_mode_fails_when_instance_before_parent() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
 
     // Instance should fail - parent type not found
     assert!(
@@ -129,7 +131,7 @@ async fn test_ready_mode_validates_invalid_instance_immediately() {
     let service = create_service();
 
     // Switch to ready mode first
-    let _ = service.switch_to_ready();
+    let _ = service.switch_to_ready().await;
 
     // Register parent type and an INVALID instance in one call
     // The instance is missing required "age" field
@@ -156,7 +158,7 @@ async fn test_ready_mode_validates_invalid_instance_immediately() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
 
     // Parent type should succeed
     assert!(
@@ -178,7 +180,7 @@ async fn test_ready_mode_batch_with_valid_and_invalid_instances() {
     let service = create_service();
 
     // Switch to ready mode
-    let _ = service.switch_to_ready();
+    let _ = service.switch_to_ready().await;
 
     // Register type with mix of valid and invalid instances
     let entities = vec![
@@ -214,7 +216,7 @@ async fn test_ready_mode_batch_with_valid_and_invalid_instances() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
 
     assert_eq!(results.len(), 4);
 
@@ -253,7 +255,7 @@ async fn test_configuration_mode_defers_validation() {
         "required": ["requiredField"]
     });
 
-    let _ = service.register(vec![type_schema]);
+    let _ = service.register(vec![type_schema]).await;
 
     // Register an instance that would fail validation (missing required field)
     // In configuration mode, this should succeed (deferred validation)
@@ -262,7 +264,7 @@ async fn test_configuration_mode_defers_validation() {
         // Missing "requiredField"
     });
 
-    let result = service.register(vec![invalid_instance]);
+    let result = service.register(vec![invalid_instance]).await;
     // In configuration mode, registration succeeds (validation deferred)
     assert!(
         result[0].is_ok(),
@@ -289,10 +291,10 @@ async fn test_switch_to_production_validates_all_entities() {
         }),
     ];
 
-    let _ = service.register(entities);
+    let _ = service.register(entities).await;
 
     // Switch to ready should succeed with valid entities
-    let result = service.switch_to_ready();
+    let result = service.switch_to_ready().await;
     assert!(
         result.is_ok(),
         "Switch to ready should succeed with valid entities"
@@ -309,19 +311,21 @@ async fn test_switch_to_ready_is_idempotent() {
     let service = create_service();
 
     // Register something first
-    let _ = service.register(vec![json!({
-        "$id": "gts://gts.acme.core.events.state_test.v1~",
-        "$schema": "http://json-schema.org/draft-07/schema#",
-        "type": "object"
-    })]);
+    let _ = service
+        .register(vec![json!({
+            "$id": "gts://gts.acme.core.events.state_test.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        })])
+        .await;
 
     // First switch should succeed
-    let first_switch = service.switch_to_ready();
+    let first_switch = service.switch_to_ready().await;
     assert!(first_switch.is_ok());
     assert!(service.is_ready());
 
     // Second switch is idempotent (already in ready, no-op)
-    let second_switch = service.switch_to_ready();
+    let second_switch = service.switch_to_ready().await;
     assert!(second_switch.is_ok(), "Second switch should be idempotent");
     assert!(service.is_ready());
 }
@@ -331,14 +335,16 @@ async fn test_list_before_ready_returns_empty() {
     let service = create_service();
 
     // Register entities in configuration mode
-    let _ = service.register(vec![json!({
-        "$id": "gts://gts.acme.core.events.not_visible.v1~",
-        "$schema": "http://json-schema.org/draft-07/schema#",
-        "type": "object"
-    })]);
+    let _ = service
+        .register(vec![json!({
+            "$id": "gts://gts.acme.core.events.not_visible.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        })])
+        .await;
 
     // List should return empty before switching to ready
-    let results = service.list(&ListQuery::default()).unwrap();
+    let results = service.list(&ListQuery::default()).await.unwrap();
     assert!(results.is_empty(), "List should be empty before ready mode");
 }
 
@@ -347,14 +353,16 @@ async fn test_get_before_ready_fails() {
     let service = create_service();
 
     // Register entity in configuration mode
-    let _ = service.register(vec![json!({
-        "$id": "gts://gts.acme.core.events.not_accessible.v1~",
-        "$schema": "http://json-schema.org/draft-07/schema#",
-        "type": "object"
-    })]);
+    let _ = service
+        .register(vec![json!({
+            "$id": "gts://gts.acme.core.events.not_accessible.v1~",
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object"
+        })])
+        .await;
 
     // Get should fail before switching to ready
-    let result = service.get("gts.acme.core.events.not_accessible.v1~");
+    let result = service.get("gts.acme.core.events.not_accessible.v1~").await;
     assert!(result.is_err(), "Get should fail before ready mode");
 }
 
@@ -379,7 +387,7 @@ async fn test_switch_to_ready_returns_errors_as_list() {
         "required": ["productId", "name", "price"]
     });
 
-    let _ = service.register(vec![parent_type]);
+    let _ = service.register(vec![parent_type]).await;
 
     // Register multiple invalid child instances in configuration mode
     // Child 1: Missing all required fields
@@ -405,7 +413,9 @@ async fn test_switch_to_ready_returns_errors_as_list() {
     });
 
     // All should succeed in configuration mode (validation deferred)
-    let results = service.register(vec![invalid_child1, invalid_child2, invalid_child3]);
+    let results = service
+        .register(vec![invalid_child1, invalid_child2, invalid_child3])
+        .await;
     assert!(
         results[0].is_ok(),
         "Config mode should accept invalid child 1"
@@ -420,7 +430,7 @@ async fn test_switch_to_ready_returns_errors_as_list() {
     );
 
     // Switch to ready should fail
-    let switch_result = service.switch_to_ready();
+    let switch_result = service.switch_to_ready().await;
     assert!(
         switch_result.is_err(),
         "Switch to ready should fail with invalid children"
@@ -477,7 +487,7 @@ async fn test_switch_to_ready_error_contains_gts_ids() {
         "required": ["name"]
     });
 
-    let _ = service.register(vec![type_schema]);
+    let _ = service.register(vec![type_schema]).await;
 
     // Register an invalid instance
     let invalid_instance = json!({
@@ -485,10 +495,10 @@ async fn test_switch_to_ready_error_contains_gts_ids() {
         // Missing required "name" field
     });
 
-    let _ = service.register(vec![invalid_instance]);
+    let _ = service.register(vec![invalid_instance]).await;
 
     // Switch to ready should fail
-    let switch_result = service.switch_to_ready();
+    let switch_result = service.switch_to_ready().await;
     assert!(switch_result.is_err());
 
     let error = switch_result.unwrap_err();
@@ -536,10 +546,10 @@ async fn test_switch_to_ready_success_with_valid_types_only() {
         }
     });
 
-    let _ = service.register(vec![type_schema1, type_schema2]);
+    let _ = service.register(vec![type_schema1, type_schema2]).await;
 
     // Switch to ready should succeed with valid type schemas
-    let switch_result = service.switch_to_ready();
+    let switch_result = service.switch_to_ready().await;
     assert!(
         switch_result.is_ok(),
         "Switch should succeed with valid types: {switch_result:?}"
@@ -547,7 +557,7 @@ async fn test_switch_to_ready_success_with_valid_types_only() {
     assert!(service.is_ready());
 
     // Verify entities are accessible
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 2);
 }
 
@@ -570,7 +580,7 @@ async fn test_concurrent_registrations() {
                 "$schema": "http://json-schema.org/draft-07/schema#",
                 "type": "object"
             });
-            svc.register(vec![entity])
+            svc.register(vec![entity]).await
         });
         handles.push(handle);
     }
@@ -584,8 +594,8 @@ async fn test_concurrent_registrations() {
     }
 
     // Switch to ready and verify all entities
-    let _ = service.switch_to_ready();
+    let _ = service.switch_to_ready().await;
 
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 10);
 }
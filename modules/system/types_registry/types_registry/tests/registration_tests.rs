@@ -26,7 +26,7 @@ async fn test_anonymous_entity_rejected_immediately() {
         }
     });
 
-    let results = service.register(vec![anonymous_entity]);
+    let results = service.register(vec![anonymous_entity]).await;
     assert_eq!(results.len(), 1);
     assert!(results[0].is_err(), "Anonymous entity should be rejected");
 
@@ -65,7 +65,9 @@ async fn test_batch_with_anonymous_entities_rejected_immediately() {
         }
     });
 
-    let results = service.register(vec![valid_entity, anonymous_entity1, anonymous_entity2]);
+    let results = service
+        .register(vec![valid_entity, anonymous_entity1, anonymous_entity2])
+        .await;
     assert_eq!(results.len(), 3);
 
     // Valid entity should succeed
@@ -86,8 +88,8 @@ async fn test_anonymous_entity_rejected_in_ready_mode() {
         "$schema": "http://json-schema.org/draft-07/schema#",
         "type": "object"
     });
-    let _ = service.register(vec![valid_entity]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![valid_entity]).await;
+    service.switch_to_ready().await.unwrap();
     assert!(service.is_ready());
 
     // Try to register anonymous entity in ready mode
@@ -98,7 +100,7 @@ async fn test_anonymous_entity_rejected_in_ready_mode() {
         }
     });
 
-    let results = service.register(vec![anonymous_entity]);
+    let results = service.register(vec![anonymous_entity]).await;
     assert_eq!(results.len(), 1);
     assert!(
         results[0].is_err(),
@@ -130,18 +132,19 @@ async fn test_full_registration_flow_configuration_to_ready() {
         "description": "Event emitted when a new user is created"
     });
 
-    let results = service.register(vec![type_schema]);
+    let results = service.register(vec![type_schema]).await;
     assert_eq!(results.len(), 1);
     assert!(results[0].is_ok());
 
     // Phase 2: Switch to ready mode
-    let switch_result = service.switch_to_ready();
+    let switch_result = service.switch_to_ready().await;
     assert!(switch_result.is_ok());
     assert!(service.is_ready());
 
     // Phase 3: Verify entity is accessible
     let entity = service
         .get("gts.acme.core.events.user_created.v1~")
+        .await
         .unwrap();
     assert_eq!(entity.gts_id, "gts.acme.core.events.user_created.v1~");
     assert!(entity.is_type());
@@ -174,7 +177,7 @@ async fn test_batch_registration_with_mixed_results() {
         }),
     ];
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
     assert_eq!(results.len(), 4);
 
     // First entity should succeed
@@ -208,11 +211,11 @@ async fn test_duplicate_registration_fails() {
     });
 
     // First registration should succeed
-    let results1 = service.register(vec![entity]);
+    let results1 = service.register(vec![entity]).await;
     assert!(results1[0].is_ok());
 
     // Second registration with different content should fail
-    let results2 = service.register(vec![entity_modified]);
+    let results2 = service.register(vec![entity_modified]).await;
     assert!(results2[0].is_err());
 }
 
@@ -221,7 +224,7 @@ async fn test_registration_in_ready_mode() {
     let service = create_service();
 
     // Switch to ready first
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
     assert!(service.is_ready());
 
     // Register in ready mode (with validation)
@@ -234,11 +237,11 @@ async fn test_registration_in_ready_mode() {
         }
     });
 
-    let results = service.register(vec![entity]);
+    let results = service.register(vec![entity]).await;
     assert!(results[0].is_ok());
 
     // Entity should be immediately accessible
-    let retrieved = service.get("gts.acme.core.events.ready_type.v1~");
+    let retrieved = service.get("gts.acme.core.events.ready_type.v1~").await;
     assert!(retrieved.is_ok());
 }
 
@@ -246,7 +249,7 @@ async fn test_registration_in_ready_mode() {
 async fn test_empty_batch_registration() {
     let service = create_service();
 
-    let results = service.register(vec![]);
+    let results = service.register(vec![]).await;
     assert!(results.is_empty());
 }
 
@@ -265,13 +268,13 @@ async fn test_large_batch_registration() {
         })
         .collect();
 
-    let results = service.register(entities);
+    let results = service.register(entities).await;
     assert_eq!(results.len(), 100);
     assert!(results.iter().all(types_registry::RegisterResult::is_ok));
 
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 100);
 }
 
@@ -286,7 +289,7 @@ async fn test_rest_register_handler_integration() {
 
     let service = create_service();
     // Switch to ready first so REST API works
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     let request = RegisterEntitiesRequest {
         entities: vec![json!({
@@ -314,7 +317,7 @@ async fn test_rest_register_empty_request() {
 
     let service = create_service();
     // Switch to ready first so REST API works
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     let request = RegisterEntitiesRequest { entities: vec![] };
 
@@ -335,7 +338,7 @@ async fn test_rest_register_with_invalid_entities() {
 
     let service = create_service();
     // Switch to ready first so REST API works
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     let request = RegisterEntitiesRequest {
         entities: vec![
@@ -0,0 +1,232 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for schema evolution: minor versions, updates and deprecation
+
+mod common;
+
+use common::create_service;
+use serde_json::json;
+use types_registry::domain::DomainError;
+use types_registry_sdk::RegisterResult;
+
+fn person_schema(id: &str) -> serde_json::Value {
+    json!({
+        "$id": format!("gts://{id}"),
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "age": { "type": "number" }
+        },
+        "required": ["name"]
+    })
+}
+
+fn person(name: &str, age: &serde_json::Value) -> serde_json::Value {
+    json!({
+        "id": format!("gts.acme.core.models.person.v1~acme.core.instances.{name}.v1"),
+        "name": name,
+        "age": age
+    })
+}
+
+#[tokio::test]
+async fn test_compatible_minor_version_is_accepted() {
+    let service = create_service();
+    let _ = service
+        .register(vec![
+            person_schema("gts.acme.core.models.person.v1~"),
+            person("alice", &json!(30)),
+        ])
+        .await;
+    service.switch_to_ready().await.unwrap();
+
+    let mut v1_1 = person_schema("gts.acme.core.models.person.v1.1~");
+    v1_1["properties"]["email"] = json!({ "type": "string" });
+
+    let results = service.register_validated(vec![v1_1]).await;
+    let RegisterResult::Ok { revalidated, .. } = &results[0] else {
+        panic!("{results:?}");
+    };
+    let revalidated: Vec<_> = revalidated.iter().map(|v| v.gts_id.as_str()).collect();
+    assert_eq!(
+        revalidated,
+        ["gts.acme.core.models.person.v1~acme.core.instances.alice.v1"]
+    );
+    assert_eq!(results[0].invalid_instances().count(), 0);
+}
+
+#[tokio::test]
+async fn test_breaking_minor_version_is_rejected() {
+    let service = create_service();
+    let _ = service
+        .register(vec![person_schema("gts.acme.core.models.person.v1~")])
+        .await;
+    service.switch_to_ready().await.unwrap();
+
+    // Drops the required `name` and narrows `age`
+    let mut v1_1 = person_schema("gts.acme.core.models.person.v1.1~");
+    v1_1["properties"] = json!({ "age": { "type": "integer" } });
+    v1_1["required"] = json!([]);
+
+    let results = service.register_validated(vec![v1_1]).await;
+    let error = results[0].as_result().unwrap_err().to_string();
+    assert!(
+        error.contains("Incompatible with gts.acme.core.models.person.v1~"),
+        "{error}"
+    );
+    assert!(error.contains("'name'"), "{error}");
+    assert!(error.contains("$.age"), "{error}");
+}
+
+#[tokio::test]
+async fn test_update_revalidates_instances() {
+    let service = create_service();
+    let _ = service
+        .register(vec![
+            person_schema("gts.acme.core.models.person.v1~"),
+            person("alice", &json!(30)),
+            person("bob", &json!(41.5)),
+        ])
+        .await;
+    service.switch_to_ready().await.unwrap();
+
+    // Allowing more values is compatible
+    let mut widened = person_schema("gts.acme.core.models.person.v1~");
+    widened["properties"]["age"] = json!({ "type": ["number", "string"] });
+
+    let update = service.update(widened.clone()).await.unwrap();
+    assert_eq!(update.entity.content, widened);
+    let ids: Vec<_> = update
+        .revalidated
+        .iter()
+        .map(|v| v.gts_id.as_str())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            "gts.acme.core.models.person.v1~acme.core.instances.alice.v1",
+            "gts.acme.core.models.person.v1~acme.core.instances.bob.v1",
+        ]
+    );
+    assert_eq!(update.invalid_instances().count(), 0);
+
+    // Narrowing it back is not
+    let narrowed = person_schema("gts.acme.core.models.person.v1~");
+    let err = service.update(narrowed).await.unwrap_err();
+    assert!(matches!(err, DomainError::ValidationFailed(_)), "{err:?}");
+    assert_eq!(
+        service
+            .get("gts.acme.core.models.person.v1~")
+            .await
+            .unwrap()
+            .content,
+        widened
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_instance_update_keeps_current_content() {
+    let service = create_service();
+    let _ = service
+        .register(vec![
+            person_schema("gts.acme.core.models.person.v1~"),
+            person("alice", &json!(30)),
+        ])
+        .await;
+    service.switch_to_ready().await.unwrap();
+
+    let err = service
+        .update(person("alice", &json!("thirty")))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::ValidationFailed(_)), "{err:?}");
+
+    let alice = service
+        .get("gts.acme.core.models.person.v1~acme.core.instances.alice.v1")
+        .await
+        .unwrap();
+    assert_eq!(alice.content["age"], 30);
+
+    let update = service.update(person("alice", &json!(31))).await.unwrap();
+    assert_eq!(update.entity.content["age"], 31);
+    assert!(update.revalidated.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_unknown_entity_fails() {
+    let service = create_service();
+    service.switch_to_ready().await.unwrap();
+
+    let err = service
+        .update(person_schema("gts.acme.core.models.person.v1~"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::NotFound(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_update_and_deprecate_require_ready_mode() {
+    let service = create_service();
+    let _ = service
+        .register(vec![person_schema("gts.acme.core.models.person.v1~")])
+        .await;
+
+    assert!(matches!(
+        service
+            .update(person_schema("gts.acme.core.models.person.v1~"))
+            .await,
+        Err(DomainError::NotInReadyMode)
+    ));
+    assert!(matches!(
+        service.deprecate("gts.acme.core.models.person.v1~").await,
+        Err(DomainError::NotInReadyMode)
+    ));
+}
+
+#[tokio::test]
+async fn test_deprecated_schema_rejects_new_instances() {
+    let service = create_service();
+    let _ = service
+        .register(vec![
+            person_schema("gts.acme.core.models.person.v1~"),
+            person("alice", &json!(30)),
+        ])
+        .await;
+    service.switch_to_ready().await.unwrap();
+
+    let deprecated = service
+        .deprecate("gts.acme.core.models.person.v1~")
+        .await
+        .unwrap();
+    assert!(deprecated.deprecated);
+    assert!(
+        service
+            .get("gts.acme.core.models.person.v1~")
+            .await
+            .unwrap()
+            .deprecated
+    );
+
+    // Existing instances stay readable, new ones are rejected
+    assert!(
+        service
+            .get("gts.acme.core.models.person.v1~acme.core.instances.alice.v1")
+            .await
+            .is_ok()
+    );
+    let results = service
+        .register_validated(vec![person("bob", &json!(41))])
+        .await;
+    let error = results[0].as_result().unwrap_err().to_string();
+    assert!(error.contains("deprecated"), "{error}");
+
+    // Deprecating twice is a no-op
+    assert!(
+        service
+            .deprecate("gts.acme.core.models.person.v1~")
+            .await
+            .unwrap()
+            .deprecated
+    );
+}
@@ -32,7 +32,7 @@ async fn test_type_with_valid_instances() {
         "description": "User entity type definition"
     });
 
-    let type_result = service.register(vec![user_type]);
+    let type_result = service.register(vec![user_type]).await;
     assert!(
         type_result[0].is_ok(),
         "Type registration should succeed: {:?}",
@@ -40,7 +40,7 @@ async fn test_type_with_valid_instances() {
     );
 
     // Switch to ready to enable validation
-    service.switch_to_ready().unwrap();
+    service.switch_to_ready().await.unwrap();
 
     // Register valid instances that conform to the schema
     // Instances have format: parent~instance (at least 2 segments)
@@ -60,7 +60,9 @@ async fn test_type_with_valid_instances() {
         // age and isActive are optional
     });
 
-    let instance_results = service.register(vec![valid_instance1, valid_instance2]);
+    let instance_results = service
+        .register(vec![valid_instance1, valid_instance2])
+        .await;
 
     // Both instances should be registered successfully
     assert!(
@@ -75,11 +77,15 @@ async fn test_type_with_valid_instances() {
     );
 
     // Verify instances are retrievable
-    let i1 = service.get("gts.acme.core.models.user.v1~acme.core.instances.user1.v1");
+    let i1 = service
+        .get("gts.acme.core.models.user.v1~acme.core.instances.user1.v1")
+        .await;
     assert!(i1.is_ok());
     assert!(i1.unwrap().is_instance());
 
-    let i2 = service.get("gts.acme.core.models.user.v1~acme.core.instances.user2.v1");
+    let i2 = service
+        .get("gts.acme.core.models.user.v1~acme.core.instances.user2.v1")
+        .await;
     assert!(i2.is_ok());
 }
 
@@ -101,8 +107,8 @@ async fn test_type_with_invalid_instance_missing_required_field() {
         "description": "Order entity type"
     });
 
-    let _ = service.register(vec![order_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![order_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Try to register an instance missing required "total" field
     // Note: "type" field is not needed - schema ID is derived from $id
@@ -113,7 +119,7 @@ async fn test_type_with_invalid_instance_missing_required_field() {
         // Missing required "total" field
     });
 
-    let result = service.register(vec![invalid_instance]);
+    let result = service.register(vec![invalid_instance]).await;
 
     // Instance should fail validation due to missing required field
     assert!(
@@ -142,8 +148,8 @@ async fn test_type_with_invalid_instance_wrong_field_type() {
         "description": "Product entity type"
     });
 
-    let _ = service.register(vec![product_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![product_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Try to register an instance with wrong type for "price" (string instead of number)
     // Note: "type" field is not needed - schema ID is derived from $id
@@ -155,7 +161,7 @@ async fn test_type_with_invalid_instance_wrong_field_type() {
         "quantity": 10
     });
 
-    let result = service.register(vec![invalid_instance]);
+    let result = service.register(vec![invalid_instance]).await;
 
     // Instance should fail validation due to type mismatch
     assert!(
@@ -184,8 +190,8 @@ async fn test_multiple_instances_of_same_type() {
         "description": "User action event"
     });
 
-    let _ = service.register(vec![event_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![event_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Register multiple instances of the same type (parent~instance format)
     // Note: "type" field is not needed - schema ID is derived from $id
@@ -213,7 +219,7 @@ async fn test_multiple_instances_of_same_type() {
         }),
     ];
 
-    let results = service.register(instances);
+    let results = service.register(instances).await;
 
     // All instances should succeed
     for (i, result) in results.iter().enumerate() {
@@ -221,18 +227,20 @@ async fn test_multiple_instances_of_same_type() {
     }
 
     // Verify we can list all entities (1 type + 3 instances)
-    let all = service.list(&ListQuery::default()).unwrap();
+    let all = service.list(&ListQuery::default()).await.unwrap();
     assert_eq!(all.len(), 4);
 
     // Filter to get only instances (not types)
     let instances_only = service
         .list(&ListQuery::default().with_is_type(false))
+        .await
         .unwrap();
     assert_eq!(instances_only.len(), 3);
 
     // Filter to get only the type
     let types_only = service
         .list(&ListQuery::default().with_is_type(true))
+        .await
         .unwrap();
     assert_eq!(types_only.len(), 1);
 }
@@ -263,8 +271,8 @@ async fn test_nested_object_type_with_instance() {
         "description": "Customer with nested address"
     });
 
-    let _ = service.register(vec![customer_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![customer_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Register a valid customer instance with nested address
     // Note: "type" field is not needed - schema ID is derived from $id
@@ -279,7 +287,7 @@ async fn test_nested_object_type_with_instance() {
         }
     });
 
-    let result = service.register(vec![valid_customer]);
+    let result = service.register(vec![valid_customer]).await;
     assert!(
         result[0].is_ok(),
         "Customer with nested address should succeed: {:?}",
@@ -287,7 +295,9 @@ async fn test_nested_object_type_with_instance() {
     );
 
     // Verify the instance
-    let customer = service.get("gts.acme.core.models.customer.v1~acme.core.instances.cust1.v1");
+    let customer = service
+        .get("gts.acme.core.models.customer.v1~acme.core.instances.cust1.v1")
+        .await;
     assert!(customer.is_ok());
     assert!(customer.unwrap().is_instance());
 }
@@ -319,8 +329,8 @@ async fn test_array_type_with_instance() {
         "description": "Shopping cart with array of items"
     });
 
-    let _ = service.register(vec![cart_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![cart_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Register a valid cart instance with array items
     // Note: "type" field is not needed - schema ID is derived from $id
@@ -333,7 +343,7 @@ async fn test_array_type_with_instance() {
         ]
     });
 
-    let result = service.register(vec![valid_cart]);
+    let result = service.register(vec![valid_cart]).await;
     assert!(
         result[0].is_ok(),
         "Cart with array items should succeed: {:?}",
@@ -370,8 +380,8 @@ async fn test_instance_with_mismatched_type_field_is_ignored_for_well_known_inst
         "description": "Product type"
     });
 
-    let _ = service.register(vec![user_type, product_type]);
-    service.switch_to_ready().unwrap();
+    let _ = service.register(vec![user_type, product_type]).await;
+    service.switch_to_ready().await.unwrap();
 
     // Register an instance where:
     // - Instance ID indicates parent is "user" type (gts.acme.core.models.user.v1~)
@@ -384,7 +394,7 @@ async fn test_instance_with_mismatched_type_field_is_ignored_for_well_known_inst
         "name": "Alice"
     });
 
-    let result = service.register(vec![mismatched_instance]);
+    let result = service.register(vec![mismatched_instance]).await;
 
     // The chained GTS ID takes priority over the explicit "type" field.
     // Since the instance has user fields and is validated against user schema (from chain),
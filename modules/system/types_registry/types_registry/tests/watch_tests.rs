@@ -0,0 +1,149 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for change notifications
+
+mod common;
+
+use std::sync::Arc;
+
+use common::create_service;
+use futures::StreamExt;
+use modkit_db::{ConnectOpts, DbHandle};
+use sea_orm_migration::MigratorTrait;
+use serde_json::json;
+use types_registry::{
+    config::TypesRegistryConfig, domain::service::TypesRegistryService, infra::DbGtsRepository,
+    infra::storage::migrations::Migrator,
+};
+use types_registry_sdk::{EntityChangeKind, ListQuery};
+
+fn person_schema() -> serde_json::Value {
+    json!({
+        "$id": "gts://gts.acme.core.models.person.v1~",
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": { "name": { "type": "string" } },
+        "required": ["name"]
+    })
+}
+
+fn person(name: &str) -> serde_json::Value {
+    json!({
+        "id": format!("gts.acme.core.models.person.v1~acme.core.instances.{name}.v1"),
+        "name": name
+    })
+}
+
+#[tokio::test]
+async fn test_watch_reports_registrations_updates_and_deprecations() {
+    let service = create_service();
+    let _ = service.register(vec![person_schema()]).await;
+    let mut changes = Box::pin(service.watch(ListQuery::default()));
+
+    // Entities committed on the switch to ready mode count as registered
+    service.switch_to_ready().await.unwrap();
+    let change = changes.next().await.unwrap().unwrap();
+    assert_eq!(change.kind, EntityChangeKind::Registered);
+    assert_eq!(change.entity.gts_id, "gts.acme.core.models.person.v1~");
+
+    assert!(service.register_validated(vec![person("alice")]).await[0].is_ok());
+    let change = changes.next().await.unwrap().unwrap();
+    assert_eq!(change.kind, EntityChangeKind::Registered);
+    assert!(change.entity.gts_id.ends_with("instances.alice.v1"));
+
+    // Registering the same content again changes nothing
+    assert!(service.register_validated(vec![person("alice")]).await[0].is_ok());
+
+    let mut widened = person_schema();
+    widened["properties"]["age"] = json!({ "type": "integer" });
+    service.update(widened.clone()).await.unwrap();
+    let change = changes.next().await.unwrap().unwrap();
+    assert_eq!(change.kind, EntityChangeKind::Updated);
+    assert_eq!(change.entity.content, widened);
+
+    // Deprecating twice is reported once
+    service
+        .deprecate("gts.acme.core.models.person.v1~")
+        .await
+        .unwrap();
+    service
+        .deprecate("gts.acme.core.models.person.v1~")
+        .await
+        .unwrap();
+    let change = changes.next().await.unwrap().unwrap();
+    assert_eq!(change.kind, EntityChangeKind::Deprecated);
+    assert!(change.entity.deprecated);
+
+    assert!(
+        tokio::time::timeout(std::time::Duration::from_millis(50), changes.next())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_watch_filters_by_query() {
+    let service = create_service();
+    let _ = service.register(vec![person_schema()]).await;
+    service.switch_to_ready().await.unwrap();
+
+    let mut instances = Box::pin(
+        service.watch(
+            ListQuery::default()
+                .with_pattern("gts.acme.core.models.person.v1~*")
+                .with_is_type(false),
+        ),
+    );
+
+    let _ = service
+        .register_validated(vec![
+            json!({
+                "$id": "gts://gts.acme.core.models.address.v1~",
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object"
+            }),
+            person("bob"),
+        ])
+        .await;
+
+    let change = instances.next().await.unwrap().unwrap();
+    assert_eq!(
+        change.entity.gts_id,
+        "gts.acme.core.models.person.v1~acme.core.instances.bob.v1"
+    );
+}
+
+#[tokio::test]
+async fn test_watch_reports_changes_from_other_replicas() {
+    // A single connection keeps both replicas on the same in-memory database
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = DbHandle::connect("sqlite::memory:", opts).await.unwrap();
+    Migrator::up(db.sea_secure().conn(), None).await.unwrap();
+    let db = Arc::new(db);
+
+    let create = || {
+        let repo = Arc::new(DbGtsRepository::new(
+            db.sea_secure(),
+            TypesRegistryConfig::default().to_gts_config(),
+        ));
+        TypesRegistryService::new(repo, TypesRegistryConfig::default())
+    };
+
+    let a = create();
+    let _ = a.register(vec![person_schema()]).await;
+    a.switch_to_ready().await.unwrap();
+    let b = create();
+    b.switch_to_ready().await.unwrap();
+
+    let mut changes = Box::pin(b.watch(ListQuery::default().with_is_type(false)));
+    assert!(a.register_validated(vec![person("carol")]).await[0].is_ok());
+
+    // Reported once B picks the change up
+    b.list(&ListQuery::default()).await.unwrap();
+    let change = changes.next().await.unwrap().unwrap();
+    assert_eq!(change.kind, EntityChangeKind::Registered);
+    assert!(change.entity.gts_id.ends_with("instances.carol.v1"));
+}